@@ -1,3 +1,18 @@
+#[path = "src/extensions/registry.rs"]
+mod registry;
+
 fn main() {
-    tauri_build::build()
+    println!("cargo:rerun-if-changed=src/extensions/registry.rs");
+    let mut attributes = tauri_build::Attributes::new();
+    for registration in registry::enabled(registry::REGISTRATIONS, |variable| {
+        std::env::var_os(variable).is_some()
+    }) {
+        attributes = attributes.plugin(
+            registration.name,
+            tauri_build::InlinedPlugin::new()
+                .commands(registration.commands)
+                .default_permission(tauri_build::DefaultPermissionRule::AllowAllCommands),
+        );
+    }
+    tauri_build::try_build(attributes).expect("failed to run tauri build script");
 }
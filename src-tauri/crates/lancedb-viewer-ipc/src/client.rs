@@ -0,0 +1,371 @@
+//! Thin typed wrapper over the v1 IPC. A `Transport` sends a command name and its
+//! JSON arguments (Tauri `invoke`, an HTTP bridge, a test double); `Client` pairs
+//! each request type with its command and response type.
+
+use std::future::Future;
+
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+
+use crate::v1::{
+    AckScanStreamRequestV1, AddColumnsRequestV1, AddColumnsResponseV1, AlterColumnsRequestV1,
+    AlterColumnsResponseV1, BackupTableRequestV1, BackupTableResponseV1, CancelJobRequestV1,
+    CancelScanStreamRequestV1, CheckExportTargetRequestV1, CheckExportTargetResponseV1,
+    CheckoutTableLatestRequestV1, CheckoutTableLatestResponseV1, CheckoutTableVersionRequestV1,
+    CheckoutTableVersionResponseV1, CloneTableRequestV1, CloneTableResponseV1,
+    CombinedSearchRequestV1, CompareQueryVersionsRequestV1, CompareQueryVersionsResponseV1,
+    CompletionMetadataRequestV1, CompletionMetadataResponseV1, ConfigResponseV1, ConnectRequestV1,
+    ConnectResponseV1, CreateIndexRequestV1, CreateIndexResponseV1, CreateTableRequestV1,
+    CreateTableResponseV1, CreateTagRequestV1, DeleteExportTemplateRequestV1,
+    DeleteExportTemplateResponseV1, DeleteProjectionPresetRequestV1,
+    DeleteProjectionPresetResponseV1, DeleteRowsRequestV1, DeleteRowsResponseV1,
+    DeleteScheduleRequestV1, DeleteScheduleResponseV1, DeleteTagRequestV1, DeleteTagResponseV1,
+    DeleteWatchFolderRequestV1, DeleteWatchFolderResponseV1, DisconnectRequestV1,
+    DisconnectResponseV1, DropColumnsRequestV1, DropColumnsResponseV1, DropIndexRequestV1,
+    DropIndexResponseV1, DropTableRequestV1, DropTableResponseV1, EstimateEmbeddingJobRequestV1,
+    EstimateEmbeddingJobResponseV1, EstimateQueryRequestV1, EstimateQueryResponseV1,
+    EvaluateRecallRequestV1, EvaluateRecallResponseV1, ExportBundleRequestV1,
+    ExportBundleResponseV1, ExportDataRequestV1, ExportDataResponseV1, ExportDiagnosticsRequestV1,
+    ExportDiagnosticsResponseV1, ExportReproSampleRequestV1, ExportReproSampleResponseV1,
+    ExportRowsRequestV1, FtsSearchRequestV1, GetAccessLogRequestV1, GetAccessLogResponseV1,
+    GetJobStatusRequestV1, GetRetentionPolicyRequestV1, GetRowCountRequestV1, GetSchemaRequestV1,
+    GetSearchDefaultsRequestV1, GetTableDescriptionRequestV1, GetTableVersionRequestV1,
+    GetTableVersionResponseV1, HealthResponseV1, ImportBundleRequestV1, ImportBundleResponseV1,
+    ImportDataRequestV1, ImportDataResponseV1, IndexCapabilitiesResponseV1, IndexStatsRequestV1,
+    IndexStatsResponseV1, JobStatusV1, ListEncryptedColumnsRequestV1,
+    ListEncryptedColumnsResponseV1, ListExportTemplatesResponseV1, ListExtensionsResponseV1,
+    ListIndexesRequestV1, ListIndexesResponseV1, ListMaskingRulesRequestV1,
+    ListMaskingRulesResponseV1, ListProjectionPresetsRequestV1, ListProjectionPresetsResponseV1,
+    ListSchedulesRequestV1, ListSchedulesResponseV1, ListSharesResponseV1, ListTablesRequestV1,
+    ListTablesResponseV1, ListTagsRequestV1, ListTagsResponseV1, ListVersionsRequestV1,
+    ListVersionsResponseV1, ListWatchFoldersRequestV1, ListWatchFoldersResponseV1,
+    MaterializeQueryRequestV1, MaterializeQueryResponseV1, MemoryStatsResponseV1,
+    MigrateTableFormatRequestV1, MigrateTableFormatResponseV1, OpenDatasetRequestV1,
+    OpenDatasetResponseV1, OpenTableRequestV1, OpenTablesRequestV1, OpenTablesResponseV1,
+    OptimizeTableRequestV1, OptimizeTableResponseV1, OverviewRequestV1, OverviewResponseV1,
+    PivotRequestV1, PivotResponseV1, PreviewUpdateRequestV1, PreviewUpdateResponseV1,
+    QueryFilterRequestV1, QueryResponseV1, RecentFiltersRequestV1, RecentFiltersResponseV1,
+    RenameTableRequestV1, RenameTableResponseV1, ReorderColumnsRequestV1, ReorderColumnsResponseV1,
+    RestoreBackupRequestV1, RestoreBackupResponseV1, RestoreTableVersionRequestV1,
+    RestoreTableVersionResponseV1, ResultEnvelope, RetentionPolicyResponseV1,
+    RotateSecretRequestV1, RotateSecretResponseV1, RowCountResponseV1, RunExportTemplateRequestV1,
+    RunRetentionRequestV1, RunRetentionResponseV1, SaveExportTemplateRequestV1,
+    SaveExportTemplateResponseV1, SaveProjectionPresetRequestV1, SaveProjectionPresetResponseV1,
+    ScanRequestV1, ScanResponseV1, ScanStreamControlResponseV1, SchemaDefinition,
+    SearchDefaultsResponseV1, SetConfigRequestV1, SetEncryptedColumnsRequestV1,
+    SetEncryptedColumnsResponseV1, SetEncryptionKeyRequestV1, SetEncryptionKeyResponseV1,
+    SetLogLevelRequestV1, SetLogLevelResponseV1, SetMaskingRulesRequestV1,
+    SetMaskingRulesResponseV1, SetRetentionPolicyRequestV1, SetScheduleRequestV1,
+    SetScheduleResponseV1, SetSearchDefaultsRequestV1, SetTableDescriptionRequestV1,
+    SetWatchFolderRequestV1, SetWatchFolderResponseV1, ShareSessionV1, StartShareRequestV1,
+    StatsHistoryRequestV1, StatsHistoryResponseV1, StopShareRequestV1, StopShareResponseV1,
+    TableDescriptionResponseV1, TableFormatInfoRequestV1, TableFormatInfoV1, TableHandle,
+    TableLocationRequestV1, TableLocationV1, TagResponseV1, UpdateRowsRequestV1,
+    UpdateRowsResponseV1, UpdateTagRequestV1, ValidateFilterRequestV1, ValidateFilterResponseV1,
+    VectorSearchRequestV1, WriteRowsRequestV1, WriteRowsResponseV1,
+};
+
+/// Sends one command invocation and returns the raw JSON result.
+pub trait Transport {
+    type Error;
+
+    fn invoke(
+        &self,
+        command: &'static str,
+        args: Value,
+    ) -> impl Future<Output = Result<Value, Self::Error>> + Send;
+}
+
+/// A request that maps to exactly one v1 command.
+pub trait Command {
+    const NAME: &'static str;
+    type Response: DeserializeOwned;
+
+    /// Arguments object passed to the command.
+    fn args(&self) -> Result<Value, serde_json::Error>;
+}
+
+#[derive(Debug)]
+pub enum ClientError<E> {
+    Transport(E),
+    Encode(serde_json::Error),
+    Decode(serde_json::Error),
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for ClientError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::Transport(error) => write!(f, "transport error: {error}"),
+            ClientError::Encode(error) => write!(f, "failed to encode request: {error}"),
+            ClientError::Decode(error) => write!(f, "failed to decode response: {error}"),
+        }
+    }
+}
+
+pub struct Client<T> {
+    transport: T,
+}
+
+impl<T: Transport> Client<T> {
+    pub fn new(transport: T) -> Self {
+        Self { transport }
+    }
+
+    pub async fn call<C: Command>(
+        &self,
+        request: &C,
+    ) -> Result<ResultEnvelope<C::Response>, ClientError<T::Error>> {
+        let args = request.args().map_err(ClientError::Encode)?;
+        let value = self
+            .transport
+            .invoke(C::NAME, args)
+            .await
+            .map_err(ClientError::Transport)?;
+        serde_json::from_value(value).map_err(ClientError::Decode)
+    }
+}
+
+/// `health_v1`, which takes no arguments.
+pub struct Health;
+
+impl Command for Health {
+    const NAME: &'static str = "health_v1";
+    type Response = HealthResponseV1;
+
+    fn args(&self) -> Result<Value, serde_json::Error> {
+        Ok(json!({}))
+    }
+}
+
+/// `get_config_v1`, which takes no arguments.
+pub struct GetConfig;
+
+impl Command for GetConfig {
+    const NAME: &'static str = "get_config_v1";
+    type Response = ConfigResponseV1;
+
+    fn args(&self) -> Result<Value, serde_json::Error> {
+        Ok(json!({}))
+    }
+}
+
+/// `get_index_capabilities_v1`, which takes no arguments.
+pub struct GetIndexCapabilities;
+
+impl Command for GetIndexCapabilities {
+    const NAME: &'static str = "get_index_capabilities_v1";
+    type Response = IndexCapabilitiesResponseV1;
+
+    fn args(&self) -> Result<Value, serde_json::Error> {
+        Ok(json!({}))
+    }
+}
+
+/// `list_export_templates_v1`, which takes no arguments.
+pub struct ListExportTemplates;
+
+impl Command for ListExportTemplates {
+    const NAME: &'static str = "list_export_templates_v1";
+    type Response = ListExportTemplatesResponseV1;
+
+    fn args(&self) -> Result<Value, serde_json::Error> {
+        Ok(json!({}))
+    }
+}
+
+/// `list_shares_v1`, which takes no arguments.
+pub struct ListShares;
+
+impl Command for ListShares {
+    const NAME: &'static str = "list_shares_v1";
+    type Response = ListSharesResponseV1;
+
+    fn args(&self) -> Result<Value, serde_json::Error> {
+        Ok(json!({}))
+    }
+}
+
+/// `list_extensions_v1`, which takes no arguments.
+pub struct ListExtensions;
+
+impl Command for ListExtensions {
+    const NAME: &'static str = "list_extensions_v1";
+    type Response = ListExtensionsResponseV1;
+
+    fn args(&self) -> Result<Value, serde_json::Error> {
+        Ok(json!({}))
+    }
+}
+
+/// `memory_stats_v1`, which takes no arguments.
+pub struct MemoryStats;
+
+impl Command for MemoryStats {
+    const NAME: &'static str = "memory_stats_v1";
+    type Response = MemoryStatsResponseV1;
+
+    fn args(&self) -> Result<Value, serde_json::Error> {
+        Ok(json!({}))
+    }
+}
+
+macro_rules! commands {
+    ($($request:ty => $name:literal, $response:ty;)*) => {
+        $(
+            impl Command for $request {
+                const NAME: &'static str = $name;
+                type Response = $response;
+
+                fn args(&self) -> Result<Value, serde_json::Error> {
+                    Ok(json!({ "request": serde_json::to_value(self)? }))
+                }
+            }
+        )*
+    };
+}
+
+commands! {
+    ConnectRequestV1 => "connect_v1", ConnectResponseV1;
+    DisconnectRequestV1 => "disconnect_v1", DisconnectResponseV1;
+    RotateSecretRequestV1 => "rotate_secret_v1", RotateSecretResponseV1;
+    ListTablesRequestV1 => "list_tables_v1", ListTablesResponseV1;
+    DropTableRequestV1 => "drop_table_v1", DropTableResponseV1;
+    RenameTableRequestV1 => "rename_table_v1", RenameTableResponseV1;
+    ListIndexesRequestV1 => "list_indexes_v1", ListIndexesResponseV1;
+    IndexStatsRequestV1 => "index_stats_v1", IndexStatsResponseV1;
+    CreateIndexRequestV1 => "create_index_v1", CreateIndexResponseV1;
+    DropIndexRequestV1 => "drop_index_v1", DropIndexResponseV1;
+    CreateTableRequestV1 => "create_table_v1", CreateTableResponseV1;
+    OpenTableRequestV1 => "open_table_v1", TableHandle;
+    OpenTablesRequestV1 => "open_tables_v1", OpenTablesResponseV1;
+    GetSchemaRequestV1 => "get_schema_v1", SchemaDefinition;
+    ListVersionsRequestV1 => "list_versions_v1", ListVersionsResponseV1;
+    GetTableVersionRequestV1 => "get_table_version_v1", GetTableVersionResponseV1;
+    CheckoutTableVersionRequestV1 => "checkout_table_version_v1", CheckoutTableVersionResponseV1;
+    CheckoutTableLatestRequestV1 => "checkout_table_latest_v1", CheckoutTableLatestResponseV1;
+    RestoreTableVersionRequestV1 => "restore_table_version_v1", RestoreTableVersionResponseV1;
+    ListTagsRequestV1 => "list_tags_v1", ListTagsResponseV1;
+    CreateTagRequestV1 => "create_tag_v1", TagResponseV1;
+    UpdateTagRequestV1 => "update_tag_v1", TagResponseV1;
+    DeleteTagRequestV1 => "delete_tag_v1", DeleteTagResponseV1;
+    CloneTableRequestV1 => "clone_table_v1", CloneTableResponseV1;
+    AddColumnsRequestV1 => "add_columns_v1", AddColumnsResponseV1;
+    AlterColumnsRequestV1 => "alter_columns_v1", AlterColumnsResponseV1;
+    DropColumnsRequestV1 => "drop_columns_v1", DropColumnsResponseV1;
+    WriteRowsRequestV1 => "write_rows_v1", WriteRowsResponseV1;
+    UpdateRowsRequestV1 => "update_rows_v1", UpdateRowsResponseV1;
+    DeleteRowsRequestV1 => "delete_rows_v1", DeleteRowsResponseV1;
+    ImportDataRequestV1 => "import_data_v1", ImportDataResponseV1;
+    ExportDataRequestV1 => "export_data_v1", ExportDataResponseV1;
+    CheckExportTargetRequestV1 => "check_export_target_v1", CheckExportTargetResponseV1;
+    ExportBundleRequestV1 => "export_bundle_v1", ExportBundleResponseV1;
+    ExportReproSampleRequestV1 => "export_repro_sample_v1", ExportReproSampleResponseV1;
+    ImportBundleRequestV1 => "import_bundle_v1", ImportBundleResponseV1;
+    OptimizeTableRequestV1 => "optimize_table_v1", OptimizeTableResponseV1;
+    BackupTableRequestV1 => "backup_table_v1", BackupTableResponseV1;
+    RestoreBackupRequestV1 => "restore_backup_v1", RestoreBackupResponseV1;
+    GetJobStatusRequestV1 => "get_job_status_v1", JobStatusV1;
+    ListSchedulesRequestV1 => "list_schedules_v1", ListSchedulesResponseV1;
+    SetScheduleRequestV1 => "set_schedule_v1", SetScheduleResponseV1;
+    DeleteScheduleRequestV1 => "delete_schedule_v1", DeleteScheduleResponseV1;
+    ListWatchFoldersRequestV1 => "list_watch_folders_v1", ListWatchFoldersResponseV1;
+    SetWatchFolderRequestV1 => "set_watch_folder_v1", SetWatchFolderResponseV1;
+    DeleteWatchFolderRequestV1 => "delete_watch_folder_v1", DeleteWatchFolderResponseV1;
+    ListMaskingRulesRequestV1 => "list_masking_rules_v1", ListMaskingRulesResponseV1;
+    SetMaskingRulesRequestV1 => "set_masking_rules_v1", SetMaskingRulesResponseV1;
+    ListEncryptedColumnsRequestV1 => "list_encrypted_columns_v1", ListEncryptedColumnsResponseV1;
+    SetEncryptedColumnsRequestV1 => "set_encrypted_columns_v1", SetEncryptedColumnsResponseV1;
+    SetEncryptionKeyRequestV1 => "set_encryption_key_v1", SetEncryptionKeyResponseV1;
+    GetRetentionPolicyRequestV1 => "get_retention_policy_v1", RetentionPolicyResponseV1;
+    SetRetentionPolicyRequestV1 => "set_retention_policy_v1", RetentionPolicyResponseV1;
+    RunRetentionRequestV1 => "run_retention_v1", RunRetentionResponseV1;
+    ScanRequestV1 => "scan_v1", ScanResponseV1;
+    AckScanStreamRequestV1 => "ack_scan_stream_v1", ScanStreamControlResponseV1;
+    CancelScanStreamRequestV1 => "cancel_scan_stream_v1", ScanStreamControlResponseV1;
+    QueryFilterRequestV1 => "query_filter_v1", QueryResponseV1;
+    CombinedSearchRequestV1 => "combined_search_v1", QueryResponseV1;
+    VectorSearchRequestV1 => "vector_search_v1", QueryResponseV1;
+    EvaluateRecallRequestV1 => "evaluate_recall_v1", EvaluateRecallResponseV1;
+    EstimateEmbeddingJobRequestV1 => "estimate_embedding_job_v1", EstimateEmbeddingJobResponseV1;
+    FtsSearchRequestV1 => "fts_search_v1", QueryResponseV1;
+    SetLogLevelRequestV1 => "set_log_level_v1", SetLogLevelResponseV1;
+    ExportDiagnosticsRequestV1 => "export_diagnostics_v1", ExportDiagnosticsResponseV1;
+    GetAccessLogRequestV1 => "get_access_log_v1", GetAccessLogResponseV1;
+    SetConfigRequestV1 => "set_config_v1", ConfigResponseV1;
+    OpenDatasetRequestV1 => "open_dataset_v1", OpenDatasetResponseV1;
+    TableFormatInfoRequestV1 => "table_format_info_v1", TableFormatInfoV1;
+    TableLocationRequestV1 => "table_location_v1", TableLocationV1;
+    MigrateTableFormatRequestV1 => "migrate_table_format_v1", MigrateTableFormatResponseV1;
+    GetTableDescriptionRequestV1 => "get_table_description_v1", TableDescriptionResponseV1;
+    SetTableDescriptionRequestV1 => "set_table_description_v1", TableDescriptionResponseV1;
+    PivotRequestV1 => "pivot_v1", PivotResponseV1;
+    PreviewUpdateRequestV1 => "preview_update_v1", PreviewUpdateResponseV1;
+    CancelJobRequestV1 => "cancel_job_v1", JobStatusV1;
+    MaterializeQueryRequestV1 => "materialize_query_v1", MaterializeQueryResponseV1;
+    CompareQueryVersionsRequestV1 => "compare_query_versions_v1", CompareQueryVersionsResponseV1;
+    GetRowCountRequestV1 => "get_row_count_v1", RowCountResponseV1;
+    GetSearchDefaultsRequestV1 => "get_search_defaults_v1", SearchDefaultsResponseV1;
+    SetSearchDefaultsRequestV1 => "set_search_defaults_v1", SearchDefaultsResponseV1;
+    ListProjectionPresetsRequestV1 => "list_projection_presets_v1", ListProjectionPresetsResponseV1;
+    SaveProjectionPresetRequestV1 => "save_projection_preset_v1", SaveProjectionPresetResponseV1;
+    DeleteProjectionPresetRequestV1 => "delete_projection_preset_v1", DeleteProjectionPresetResponseV1;
+    StatsHistoryRequestV1 => "stats_history_v1", StatsHistoryResponseV1;
+    OverviewRequestV1 => "overview_v1", OverviewResponseV1;
+    SaveExportTemplateRequestV1 => "save_export_template_v1", SaveExportTemplateResponseV1;
+    DeleteExportTemplateRequestV1 => "delete_export_template_v1", DeleteExportTemplateResponseV1;
+    RunExportTemplateRequestV1 => "run_export_template_v1", ExportDataResponseV1;
+    StartShareRequestV1 => "start_share_v1", ShareSessionV1;
+    StopShareRequestV1 => "stop_share_v1", StopShareResponseV1;
+    EstimateQueryRequestV1 => "estimate_query_v1", EstimateQueryResponseV1;
+    ReorderColumnsRequestV1 => "reorder_columns_v1", ReorderColumnsResponseV1;
+    RecentFiltersRequestV1 => "recent_filters_v1", RecentFiltersResponseV1;
+    CompletionMetadataRequestV1 => "completion_metadata_v1", CompletionMetadataResponseV1;
+    ValidateFilterRequestV1 => "validate_filter_v1", ValidateFilterResponseV1;
+    ExportRowsRequestV1 => "export_rows_v1", ExportDataResponseV1;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::v1::{ApiVersion, GetTableVersionRequestV1};
+
+    #[derive(Default)]
+    struct Recorder {
+        calls: Mutex<Vec<(&'static str, Value)>>,
+    }
+
+    impl Transport for Recorder {
+        type Error = String;
+
+        fn invoke(
+            &self,
+            command: &'static str,
+            args: Value,
+        ) -> impl Future<Output = Result<Value, Self::Error>> + Send {
+            self.calls.lock().expect("calls").push((command, args));
+            let response = json!({
+                "apiVersion": ApiVersion::V1,
+                "ok": true,
+                "data": { "tableId": "t1", "version": 4 },
+            });
+            async move { Ok(response) }
+        }
+    }
+
+    #[tokio::test]
+    async fn wraps_requests_and_decodes_envelopes() {
+        let client = Client::new(Recorder::default());
+        let request = GetTableVersionRequestV1 {
+            table_id: "t1".to_string(),
+        };
+
+        let envelope = client.call(&request).await.expect("call");
+        assert_eq!(envelope.data.expect("data").version, 4);
+
+        let calls = client.transport.calls.lock().expect("calls");
+        assert_eq!(calls[0].0, "get_table_version_v1");
+        assert_eq!(calls[0].1, json!({ "request": { "tableId": "t1" } }));
+    }
+}
@@ -0,0 +1,116 @@
+//! Serde adapters for `u64` versions and counts. JavaScript reads JSON numbers as
+//! doubles, so values past `Number.MAX_SAFE_INTEGER` are written as decimal
+//! strings instead. Both forms are accepted when reading, so a version can be sent
+//! back exactly as it was received.
+
+use std::fmt;
+
+use serde::de::{self, Visitor};
+use serde::{Deserializer, Serializer};
+
+/// `2^53 - 1`, the largest integer a double holds exactly.
+pub const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+pub fn serialize<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+    if *value <= MAX_SAFE_INTEGER {
+        serializer.serialize_u64(*value)
+    } else {
+        serializer.collect_str(value)
+    }
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    deserializer.deserialize_any(U64Visitor)
+}
+
+struct U64Visitor;
+
+impl Visitor<'_> for U64Visitor {
+    type Value = u64;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an unsigned integer or a decimal string")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<u64, E> {
+        Ok(value)
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<u64, E> {
+        u64::try_from(value).map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<u64, E> {
+        value
+            .parse()
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+/// The same encoding for `Option<u64>`; `null` stays `None`.
+pub mod option {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    #[derive(Deserialize)]
+    struct Wrapped(#[serde(with = "super")] u64);
+
+    pub fn serialize<S: Serializer>(value: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => super::serialize(value, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<u64>, D::Error> {
+        Ok(Option::<Wrapped>::deserialize(deserializer)?.map(|Wrapped(value)| value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use super::MAX_SAFE_INTEGER;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Counts {
+        #[serde(with = "super")]
+        version: u64,
+        #[serde(default, with = "super::option")]
+        rows: Option<u64>,
+    }
+
+    #[test]
+    fn writes_large_values_as_strings() {
+        let small = Counts {
+            version: 7,
+            rows: Some(MAX_SAFE_INTEGER),
+        };
+        let value = serde_json::to_value(&small).expect("serialize");
+        assert_eq!(value, json!({"version": 7, "rows": 9007199254740991u64}));
+
+        let large = Counts {
+            version: u64::MAX,
+            rows: None,
+        };
+        let value = serde_json::to_value(&large).expect("serialize");
+        assert_eq!(
+            value,
+            json!({"version": "18446744073709551615", "rows": null})
+        );
+        assert_eq!(
+            serde_json::from_value::<Counts>(value).expect("round trip"),
+            large
+        );
+
+        let parsed: Counts =
+            serde_json::from_value(json!({"version": 3, "rows": "9007199254740993"}))
+                .expect("mixed");
+        assert_eq!(parsed.rows, Some(MAX_SAFE_INTEGER + 2));
+        assert!(serde_json::from_value::<Counts>(json!({"version": -1})).is_err());
+        assert!(serde_json::from_value::<Counts>(json!({"version": "v1"})).is_err());
+    }
+}
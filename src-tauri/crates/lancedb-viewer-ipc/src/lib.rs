@@ -0,0 +1,7 @@
+//! Request and response types of the LanceDB Viewer v1 IPC, shared by the Tauri
+//! backend, the `client` wrapper and (with the `ts` feature) the generated
+//! TypeScript bindings.
+
+pub mod client;
+pub mod json_u64;
+pub mod v1;
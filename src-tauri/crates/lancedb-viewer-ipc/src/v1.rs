@@ -0,0 +1,4941 @@
+use arrow_schema::{DataType, Field, IntervalUnit, Schema, TimeUnit, UnionMode};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum ApiVersion {
+    #[default]
+    V1,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    InvalidArgument,
+    NotFound,
+    Internal,
+    NotImplemented,
+    PermissionDenied,
+    /// The command was stopped by `cancel_job_v1` before it finished.
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum LocaleV1 {
+    #[default]
+    En,
+    ZhCn,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum MessageCodeV1 {
+    ConnectionNotFound,
+    TableNotFound,
+    StateLockFailed,
+    CompactSubmitted,
+    CompactSubmittedWithTarget,
+    VacuumSubmitted,
+    VacuumSubmittedOlderThan,
+    IndexesOptimized,
+    ConnectionReadOnly,
+    RoleForbidden,
+    FtsIndexMissing,
+    FtsColumnsNotIndexed,
+    VectorFilterHint,
+    DiskSpaceInsufficient,
+    EncryptedColumnLocked,
+    PathEmpty,
+    TableNameEmpty,
+    IndexNameEmpty,
+    JobNotFound,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct MessageV1 {
+    pub code: MessageCodeV1,
+    #[serde(default)]
+    pub params: HashMap<String, String>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorEnvelope {
+    pub code: ErrorCode,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_code: Option<MessageCodeV1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(type = "unknown", optional = nullable))]
+    pub details: Option<serde_json::Value>,
+}
+
+impl ErrorEnvelope {
+    pub fn from_message(code: ErrorCode, message: MessageV1) -> Self {
+        Self {
+            code,
+            message: message.text,
+            message_code: Some(message.code),
+            params: if message.params.is_empty() {
+                None
+            } else {
+                Some(message.params)
+            },
+            details: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ResultEnvelope<T> {
+    #[serde(default)]
+    pub api_version: ApiVersion,
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ErrorEnvelope>,
+}
+
+impl<T> ResultEnvelope<T> {
+    pub fn ok(data: T) -> Self {
+        Self {
+            api_version: ApiVersion::V1,
+            ok: true,
+            data: Some(data),
+            error: None,
+        }
+    }
+
+    pub fn err(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            api_version: ApiVersion::V1,
+            ok: false,
+            data: None,
+            error: Some(ErrorEnvelope {
+                code,
+                message: message.into(),
+                message_code: None,
+                params: None,
+                details: None,
+            }),
+        }
+    }
+
+    pub fn err_message(code: ErrorCode, message: MessageV1) -> Self {
+        Self::err_envelope(ErrorEnvelope::from_message(code, message))
+    }
+
+    /// Wraps an error returned by another command.
+    pub fn err_envelope(error: ErrorEnvelope) -> Self {
+        Self {
+            api_version: ApiVersion::V1,
+            ok: false,
+            data: None,
+            error: Some(error),
+        }
+    }
+
+    pub fn into_result(self) -> Result<T, ErrorEnvelope> {
+        match (self.data, self.error) {
+            (Some(data), _) => Ok(data),
+            (None, Some(error)) => Err(error),
+            (None, None) => Err(ErrorEnvelope {
+                code: ErrorCode::Internal,
+                message: "empty result envelope".to_string(),
+                message_code: None,
+                params: None,
+                details: None,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum DataFormat {
+    #[default]
+    Json,
+    Arrow,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum DataFileFormatV1 {
+    Csv,
+    Parquet,
+    Jsonl,
+    /// GeoJSON FeatureCollection built from a geometry column. Export only.
+    Geojson,
+}
+
+/// How NaN and infinite floats are rendered; JSON itself has no such numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum NonFiniteFloatFormatV1 {
+    #[default]
+    Null,
+    /// `"NaN"`, `"Infinity"` or `"-Infinity"`.
+    String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum BinaryFormatV1 {
+    #[default]
+    Hex,
+    Base64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum TimestampFormatV1 {
+    /// ISO 8601 text, with the offset when the column has a time zone.
+    #[default]
+    Iso,
+    /// Milliseconds since the Unix epoch as a number.
+    EpochMillis,
+}
+
+/// How Int64 and UInt64 values are rendered. JavaScript numbers lose precision
+/// above 2^53, so `string` keeps ids intact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum LargeIntegerFormatV1 {
+    #[default]
+    Number,
+    String,
+    /// Strings only for columns holding a value outside the JavaScript safe-integer
+    /// range, numbers otherwise.
+    Auto,
+}
+
+/// Rendering of values in `JsonChunk` rows. The defaults match the output of
+/// earlier versions.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct JsonOptionsV1 {
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub non_finite_floats: NonFiniteFloatFormatV1,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub binary: BinaryFormatV1,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub timestamps: TimestampFormatV1,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub large_integers: LargeIntegerFormatV1,
+    /// Writes null columns as `null` instead of leaving the key out of the row.
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub explicit_nulls: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum WriteDataMode {
+    #[default]
+    Append,
+    Overwrite,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum IndexTypeV1 {
+    Auto,
+    #[serde(rename = "btree", alias = "b_tree")]
+    BTree,
+    Bitmap,
+    LabelList,
+    Fts,
+    IvfFlat,
+    IvfSq,
+    IvfPq,
+    IvfRq,
+    IvfHnswPq,
+    IvfHnswSq,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum DistanceTypeV1 {
+    L2,
+    Cosine,
+    Dot,
+    Hamming,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AuthDescriptor {
+    #[default]
+    None,
+    Inline {
+        provider: String,
+        params: HashMap<String, String>,
+    },
+    SecretRef {
+        provider: String,
+        reference: String,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_consistency_interval_seconds: Option<u64>,
+    /// Rejects writes through this connection. Defaults to `readOnlyDefault` from the
+    /// app config.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_store: Option<ObjectStoreTuningV1>,
+}
+
+/// Object-store client settings for S3, GCS and Azure connections, translated into
+/// storage options when connecting. Local connections ignore them.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectStoreTuningV1 {
+    /// Time allowed to open a connection to the store.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connect_timeout_ms: Option<u64>,
+    /// Time allowed for a whole request, including reading the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_timeout_ms: Option<u64>,
+}
+
+/// Storage behind a connection URI, inferred from its scheme.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum BackendKind {
+    Local,
+    S3,
+    Gcs,
+    Azure,
+    Remote,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectProfile {
+    pub name: String,
+    pub uri: String,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub storage_options: HashMap<String, String>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub options: ConnectOptions,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub auth: AuthDescriptor,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectRequestV1 {
+    pub profile: ConnectProfile,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectResponseV1 {
+    pub connection_id: String,
+    pub backend_kind: BackendKind,
+    pub name: String,
+    pub uri: String,
+    #[serde(default)]
+    pub read_only: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DisconnectRequestV1 {
+    pub connection_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DisconnectResponseV1 {
+    pub connection_id: String,
+    pub released_tables: usize,
+}
+
+/// Reconnects the live connections to `profile.uri` with new credentials. The
+/// profile's `auth` must be `inline` and carry the rotated params.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct RotateSecretRequestV1 {
+    pub profile: ConnectProfile,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct RotateSecretResponseV1 {
+    /// Connections now using the new credentials; their ids are unchanged.
+    pub connection_ids: Vec<String>,
+    /// Table handles reopened through them, also under their existing ids.
+    pub reopened_tables: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListTablesRequestV1 {
+    pub connection_id: String,
+    /// Opens every table to read its row count, version and size. Slower on large
+    /// catalogs and remote stores.
+    #[serde(default)]
+    pub include_stats: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct TableInfo {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<TableDescriptionV1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "crate::json_u64::option")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string | null"))]
+    pub row_count: Option<u64>,
+    /// `row_count` is the last cached count rather than one read for this listing,
+    /// and may lag behind writes made outside the app.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub row_count_cached: bool,
+    /// Latest version of the table.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "crate::json_u64::option")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string | null"))]
+    pub version: Option<u64>,
+    /// Commit time of the latest version, in Unix milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified_ms: Option<u64>,
+    /// Bytes of data files referenced by the latest version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_bytes: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListTablesResponseV1 {
+    pub tables: Vec<TableInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct GetRowCountRequestV1 {
+    pub table_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct RowCountResponseV1 {
+    pub table_id: String,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub row_count: u64,
+    /// Table version the count belongs to.
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub version: u64,
+    /// Served from the row-count cache without counting.
+    pub cached: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DropTableRequestV1 {
+    pub connection_id: String,
+    pub table_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DropTableResponseV1 {
+    pub table_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct RenameTableRequestV1 {
+    pub connection_id: String,
+    pub table_name: String,
+    pub new_table_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_namespace: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct RenameTableResponseV1 {
+    pub table_name: String,
+    pub new_table_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListIndexesRequestV1 {
+    pub table_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct IndexDefinitionV1 {
+    pub name: String,
+    pub index_type: IndexTypeV1,
+    pub columns: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_indexed_rows: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_unindexed_rows: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance_type: Option<DistanceTypeV1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_indices: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loss: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListIndexesResponseV1 {
+    pub indexes: Vec<IndexDefinitionV1>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct IndexStatsRequestV1 {
+    pub table_id: String,
+    pub index_name: String,
+}
+
+/// How much of the table an index covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum IndexReadinessV1 {
+    /// Every row is indexed.
+    Ready,
+    /// Some rows are not indexed yet; queries scan them next to the index.
+    Partial,
+    /// At least 10% of the rows are not indexed; searches warn with `stale_index`.
+    Stale,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct IndexStatsResponseV1 {
+    pub table_id: String,
+    pub index_name: String,
+    pub index_type: IndexTypeV1,
+    pub columns: Vec<String>,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub num_indexed_rows: u64,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub num_unindexed_rows: u64,
+    /// Share of the rows the index covers, from 0 to 1; 1 for an empty table.
+    pub coverage: f64,
+    pub readiness: IndexReadinessV1,
+    /// Some rows are not indexed; `optimize_table_v1` adds them to the index.
+    pub needs_optimize: bool,
+    /// Segments the index is stored in, counting deltas added by optimizes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_indices: Option<u32>,
+    /// IVF indexes only. Estimated from the number of indexed rows; lance does not
+    /// report the partition count of an index.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_partitions: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance_type: Option<DistanceTypeV1>,
+    /// Training loss of the IVF k-means, when lance reports it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loss: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CreateIndexRequestV1 {
+    pub table_id: String,
+    pub columns: Vec<String>,
+    pub index_type: IndexTypeV1,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub replace: bool,
+    /// Also read from `metric`. The alias is accepted on input only; the bindings
+    /// and responses use `distanceType`.
+    #[serde(skip_serializing_if = "Option::is_none", alias = "metric")]
+    pub distance_type: Option<DistanceTypeV1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_partitions: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sample_rate: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_iterations: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_partition_size: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_sub_vectors: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_bits: Option<u32>,
+    /// HNSW graph degree, called `m` in the HNSW paper. Also read from `m` on input
+    /// only; the bindings use `numEdges`.
+    #[serde(skip_serializing_if = "Option::is_none", alias = "m")]
+    pub num_edges: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ef_construction: Option<u32>,
+    /// Hardware that trains vector indexes; ignored by scalar and FTS indexes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accelerator: Option<IndexAcceleratorV1>,
+    /// Fills the IVF parameters left unset from the table's row count and vector
+    /// dimension.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preset: Option<IndexPresetV1>,
+    /// Tokenizer settings of an FTS index; rejected for other index types.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fts_tokenizer: Option<FtsTokenizerOptionsV1>,
+    /// Job id for the build, so `cancel_job_v1` can stop it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+}
+
+/// How an FTS index splits and normalizes text. Unset fields keep LanceDB's
+/// defaults (English, stemming, stop words removed, lowercased, ASCII folded,
+/// tokens up to 40 bytes).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct FtsTokenizerOptionsV1 {
+    /// Language of the stemmer and stop-word list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<FtsLanguageV1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stem: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove_stop_words: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lower_case: Option<bool>,
+    /// Folds accented letters to ASCII, so `café` matches `cafe`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ascii_folding: Option<bool>,
+    /// Longer tokens are dropped from the index.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_token_length: Option<u32>,
+}
+
+/// Languages with a stemmer and stop-word list for FTS indexes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum FtsLanguageV1 {
+    Arabic,
+    Danish,
+    Dutch,
+    English,
+    Finnish,
+    French,
+    German,
+    Greek,
+    Hungarian,
+    Italian,
+    Norwegian,
+    Portuguese,
+    Romanian,
+    Russian,
+    Spanish,
+    Swedish,
+    Tamil,
+    Turkish,
+}
+
+/// Curated vector index tunings, trading build time for recall.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum IndexPresetV1 {
+    FastBuild,
+    Balanced,
+    MaxRecall,
+}
+
+/// Parameters a preset computed. Explicit request values override them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct IndexPresetParametersV1 {
+    pub preset: IndexPresetV1,
+    pub num_partitions: u32,
+    pub sample_rate: u32,
+    pub max_iterations: u32,
+    /// Set for PQ indexes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_sub_vectors: Option<u32>,
+    /// Set for HNSW indexes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_edges: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ef_construction: Option<u32>,
+}
+
+/// Hardware used to train vector indexes. `auto` picks the best one the build supports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum IndexAcceleratorV1 {
+    Auto,
+    Cpu,
+    Gpu,
+}
+
+/// What the running build can use to train vector indexes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct IndexCapabilitiesResponseV1 {
+    /// Accelerators `create_index_v1` accepts, `auto` included.
+    pub accelerators: Vec<IndexAcceleratorV1>,
+    /// Accelerator `auto` resolves to.
+    pub default_accelerator: IndexAcceleratorV1,
+    /// Threads Lance uses for CPU-bound index training, set by `LANCE_CPU_THREADS`.
+    pub cpu_threads: usize,
+    /// Why GPU training is unavailable, when it is.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gpu_unavailable_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CreateIndexResponseV1 {
+    pub table_id: String,
+    pub index_type: IndexTypeV1,
+    pub columns: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Accelerator that trained the index; absent for scalar and FTS indexes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accelerator: Option<IndexAcceleratorV1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preset_parameters: Option<IndexPresetParametersV1>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DropIndexRequestV1 {
+    pub table_id: String,
+    pub index_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DropIndexResponseV1 {
+    pub table_id: String,
+    pub index_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct OpenTableRequestV1 {
+    pub connection_id: String,
+    pub table_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct TableHandle {
+    pub table_id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct OpenTablesRequestV1 {
+    pub connection_id: String,
+    pub table_names: Vec<String>,
+}
+
+/// Outcome of one table of an `open_tables_v1` batch: a handle or the error the
+/// single-table `open_table_v1` would have returned.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct OpenTableResultV1 {
+    pub table_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handle: Option<TableHandle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ErrorEnvelope>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct OpenTablesResponseV1 {
+    /// One entry per requested table, in request order.
+    pub tables: Vec<OpenTableResultV1>,
+    pub opened: usize,
+    pub failed: usize,
+}
+
+/// Opens a bare `.lance` dataset directory, such as one written by lance-python,
+/// through a synthetic connection to its parent directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct OpenDatasetRequestV1 {
+    pub uri: String,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub storage_options: HashMap<String, String>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub options: ConnectOptions,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct OpenDatasetResponseV1 {
+    pub connection: ConnectResponseV1,
+    pub table: TableHandle,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct GetSchemaRequestV1 {
+    pub table_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum FieldDataType {
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    #[serde(rename = "uint8", alias = "u_int8")]
+    UInt8,
+    #[serde(rename = "uint16", alias = "u_int16")]
+    UInt16,
+    #[serde(rename = "uint32", alias = "u_int32")]
+    UInt32,
+    #[serde(rename = "uint64", alias = "u_int64")]
+    UInt64,
+    Float32,
+    Float64,
+    Boolean,
+    Utf8,
+    LargeUtf8,
+    Binary,
+    LargeBinary,
+    FixedSizeListFloat32,
+    Time32Second,
+    Time32Millisecond,
+    Time64Microsecond,
+    Time64Nanosecond,
+    DurationSecond,
+    DurationMillisecond,
+    DurationMicrosecond,
+    DurationNanosecond,
+    IntervalYearMonth,
+    IntervalDayTime,
+    IntervalMonthDayNano,
+    Null,
+    /// Int32-keyed dictionary of strings, for low-cardinality text columns.
+    DictionaryUtf8,
+    /// WKB-encoded binary tagged as `geoarrow.wkb`.
+    Geometry,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaFieldInput {
+    pub name: String,
+    pub data_type: FieldDataType,
+    pub nullable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vector_length: Option<i32>,
+    /// Model that produces the vectors of this column, stored in its metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embedding: Option<EmbeddingModelV1>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaDefinitionInput {
+    pub fields: Vec<SchemaFieldInput>,
+}
+
+const PARQUET_FIELD_ID_KEY: &str = "PARQUET:field_id";
+pub const EXTENSION_NAME_KEY: &str = "ARROW:extension:name";
+pub const GEOARROW_WKB_EXTENSION: &str = "geoarrow.wkb";
+pub const GEOARROW_WKT_EXTENSION: &str = "geoarrow.wkt";
+pub const EMBEDDING_MODEL_KEY: &str = "lancedb_viewer:embedding_model";
+pub const EMBEDDING_MODEL_VERSION_KEY: &str = "lancedb_viewer:embedding_model_version";
+pub const EMBEDDING_DIMENSION_KEY: &str = "lancedb_viewer:embedding_dimension";
+/// Field metadata marking a vector column whose vectors were written L2-normalized.
+pub const VECTOR_NORMALIZATION_KEY: &str = "lancedb_viewer:normalization";
+pub const L2_NORMALIZATION: &str = "l2";
+/// Column names treated as geometry when no GeoArrow extension is present.
+const GEOMETRY_COLUMN_NAMES: [&str; 7] =
+    ["geometry", "geom", "the_geom", "wkt", "wkb", "shape", "geo"];
+
+/// Embedding model behind a vector column, kept in the column metadata so searches
+/// can tell whether a query vector comes from the same model.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct EmbeddingModelV1 {
+    pub model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimension: Option<i32>,
+}
+
+impl EmbeddingModelV1 {
+    /// Reads the model from field metadata. The dimension falls back to the length of
+    /// a fixed-size list column.
+    pub fn from_field(field: &Field) -> Option<Self> {
+        let metadata = field.metadata();
+        let model = metadata.get(EMBEDDING_MODEL_KEY)?.clone();
+        let dimension = metadata
+            .get(EMBEDDING_DIMENSION_KEY)
+            .and_then(|value| value.parse().ok())
+            .or(match field.data_type() {
+                DataType::FixedSizeList(_, length) => Some(*length),
+                _ => None,
+            });
+        Some(Self {
+            model,
+            version: metadata.get(EMBEDDING_MODEL_VERSION_KEY).cloned(),
+            dimension,
+        })
+    }
+
+    pub fn to_metadata(&self) -> HashMap<String, String> {
+        let mut metadata = HashMap::from([(EMBEDDING_MODEL_KEY.to_string(), self.model.clone())]);
+        if let Some(version) = &self.version {
+            metadata.insert(EMBEDDING_MODEL_VERSION_KEY.to_string(), version.clone());
+        }
+        if let Some(dimension) = self.dimension {
+            metadata.insert(EMBEDDING_DIMENSION_KEY.to_string(), dimension.to_string());
+        }
+        metadata
+    }
+
+    /// Whether vectors from `other` can be compared with this column. Versions and
+    /// dimensions only count when both sides name one.
+    pub fn is_compatible(&self, other: &Self) -> bool {
+        let same_version = match (&self.version, &other.version) {
+            (Some(left), Some(right)) => left == right,
+            _ => true,
+        };
+        let same_dimension = match (self.dimension, other.dimension) {
+            (Some(left), Some(right)) => left == right,
+            _ => true,
+        };
+        self.model == other.model && same_version && same_dimension
+    }
+
+    /// `model@version`, or the bare model name.
+    pub fn label(&self) -> String {
+        match &self.version {
+            Some(version) => format!("{}@{version}", self.model),
+            None => self.model.clone(),
+        }
+    }
+}
+
+/// How a geometry column stores its values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum GeometryEncodingV1 {
+    Wkt,
+    Wkb,
+}
+
+impl GeometryEncodingV1 {
+    /// Recognizes geometry columns from a GeoArrow extension name, or from a
+    /// geometry-like column name on string and binary columns.
+    pub fn detect(field: &Field) -> Option<Self> {
+        let encoding = match field.data_type() {
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => Self::Wkt,
+            DataType::Binary | DataType::LargeBinary | DataType::BinaryView => Self::Wkb,
+            _ => return None,
+        };
+        match field.metadata().get(EXTENSION_NAME_KEY).map(String::as_str) {
+            Some(GEOARROW_WKB_EXTENSION) if encoding == Self::Wkb => return Some(encoding),
+            Some(GEOARROW_WKT_EXTENSION) if encoding == Self::Wkt => return Some(encoding),
+            _ => {}
+        }
+        let name = field.name().to_ascii_lowercase();
+        let geometry_like = GEOMETRY_COLUMN_NAMES.contains(&name.as_str())
+            || ["_geom", "_geometry", "_wkt", "_wkb"]
+                .iter()
+                .any(|suffix| name.ends_with(suffix));
+        geometry_like.then_some(encoding)
+    }
+}
+
+/// Structured Arrow data type. `kind` is the snake_case type name (`int32`,
+/// `fixed_size_list`, `timestamp`, `struct`, ...); `params` carries type parameters
+/// such as `listSize`, `unit`, `timezone`, `precision` or `scale`; `children` holds
+/// the child fields of nested types.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DataTypeDescriptorV1 {
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[cfg_attr(feature = "ts", ts(type = "Record<string, unknown>"))]
+    pub params: BTreeMap<String, serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub children: Vec<SchemaField>,
+}
+
+fn time_unit_name(unit: &TimeUnit) -> &'static str {
+    match unit {
+        TimeUnit::Second => "second",
+        TimeUnit::Millisecond => "millisecond",
+        TimeUnit::Microsecond => "microsecond",
+        TimeUnit::Nanosecond => "nanosecond",
+    }
+}
+
+impl DataTypeDescriptorV1 {
+    fn new(kind: &str) -> Self {
+        Self {
+            kind: kind.to_string(),
+            params: BTreeMap::new(),
+            children: Vec::new(),
+        }
+    }
+
+    fn param(mut self, name: &str, value: impl Into<serde_json::Value>) -> Self {
+        self.params.insert(name.to_string(), value.into());
+        self
+    }
+
+    fn child(mut self, field: &Field) -> Self {
+        self.children.push(SchemaField::from_arrow_field(field));
+        self
+    }
+
+    pub fn from_arrow_type(data_type: &DataType) -> Self {
+        match data_type {
+            DataType::Null => Self::new("null"),
+            DataType::Boolean => Self::new("boolean"),
+            DataType::Int8 => Self::new("int8"),
+            DataType::Int16 => Self::new("int16"),
+            DataType::Int32 => Self::new("int32"),
+            DataType::Int64 => Self::new("int64"),
+            DataType::UInt8 => Self::new("uint8"),
+            DataType::UInt16 => Self::new("uint16"),
+            DataType::UInt32 => Self::new("uint32"),
+            DataType::UInt64 => Self::new("uint64"),
+            DataType::Float16 => Self::new("float16"),
+            DataType::Float32 => Self::new("float32"),
+            DataType::Float64 => Self::new("float64"),
+            DataType::Timestamp(unit, timezone) => {
+                let descriptor = Self::new("timestamp").param("unit", time_unit_name(unit));
+                match timezone {
+                    Some(timezone) => descriptor.param("timezone", timezone.to_string()),
+                    None => descriptor,
+                }
+            }
+            DataType::Date32 => Self::new("date32"),
+            DataType::Date64 => Self::new("date64"),
+            DataType::Time32(unit) => Self::new("time32").param("unit", time_unit_name(unit)),
+            DataType::Time64(unit) => Self::new("time64").param("unit", time_unit_name(unit)),
+            DataType::Duration(unit) => Self::new("duration").param("unit", time_unit_name(unit)),
+            DataType::Interval(unit) => Self::new("interval").param(
+                "unit",
+                match unit {
+                    IntervalUnit::YearMonth => "year_month",
+                    IntervalUnit::DayTime => "day_time",
+                    IntervalUnit::MonthDayNano => "month_day_nano",
+                },
+            ),
+            DataType::Binary => Self::new("binary"),
+            DataType::LargeBinary => Self::new("large_binary"),
+            DataType::BinaryView => Self::new("binary_view"),
+            DataType::FixedSizeBinary(width) => {
+                Self::new("fixed_size_binary").param("byteWidth", *width)
+            }
+            DataType::Utf8 => Self::new("utf8"),
+            DataType::LargeUtf8 => Self::new("large_utf8"),
+            DataType::Utf8View => Self::new("utf8_view"),
+            DataType::List(item) => Self::new("list").child(item),
+            DataType::LargeList(item) => Self::new("large_list").child(item),
+            DataType::ListView(item) => Self::new("list_view").child(item),
+            DataType::LargeListView(item) => Self::new("large_list_view").child(item),
+            DataType::FixedSizeList(item, size) => Self::new("fixed_size_list")
+                .param("listSize", *size)
+                .child(item),
+            DataType::Struct(fields) => fields
+                .iter()
+                .fold(Self::new("struct"), |descriptor, field| {
+                    descriptor.child(field)
+                }),
+            DataType::Map(entries, keys_sorted) => Self::new("map")
+                .param("keysSorted", *keys_sorted)
+                .child(entries),
+            DataType::Union(fields, mode) => fields.iter().fold(
+                Self::new("union").param(
+                    "mode",
+                    match mode {
+                        UnionMode::Sparse => "sparse",
+                        UnionMode::Dense => "dense",
+                    },
+                ),
+                |descriptor, (_, field)| descriptor.child(field),
+            ),
+            DataType::Dictionary(key, value) => Self::new("dictionary")
+                .param("keyType", Self::from_arrow_type(key).kind)
+                .child(&Field::new("values", value.as_ref().clone(), true)),
+            DataType::Decimal128(precision, scale) => Self::new("decimal128")
+                .param("precision", *precision)
+                .param("scale", *scale),
+            DataType::Decimal256(precision, scale) => Self::new("decimal256")
+                .param("precision", *precision)
+                .param("scale", *scale),
+            DataType::RunEndEncoded(run_ends, values) => {
+                Self::new("run_end_encoded").child(run_ends).child(values)
+            }
+            other => Self::new(&format!("{other:?}").to_ascii_lowercase()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaField {
+    pub name: String,
+    /// Display string kept for compatibility; prefer `type_descriptor`.
+    pub data_type: String,
+    pub type_descriptor: DataTypeDescriptorV1,
+    pub nullable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+    /// From `PARQUET:field_id` metadata, when present.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field_id: Option<i32>,
+    /// From `ARROW:extension:name` metadata, when present.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extension_name: Option<String>,
+    /// Set when the column holds WKT or WKB geometries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geometry: Option<GeometryEncodingV1>,
+    /// Set when JSON rows carry this Int64/UInt64 column as decimal strings.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub string_encoded: bool,
+    /// Model that produced the vectors, when the column records one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embedding: Option<EmbeddingModelV1>,
+    /// Set when the column records that its vectors are L2-normalized.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub normalized: bool,
+}
+
+impl SchemaField {
+    pub fn from_arrow_field(field: &Field) -> Self {
+        let metadata = field.metadata();
+        Self {
+            name: field.name().to_string(),
+            data_type: format!("{:?}", field.data_type()),
+            type_descriptor: DataTypeDescriptorV1::from_arrow_type(field.data_type()),
+            nullable: field.is_nullable(),
+            metadata: if metadata.is_empty() {
+                None
+            } else {
+                Some(metadata.clone())
+            },
+            field_id: metadata
+                .get(PARQUET_FIELD_ID_KEY)
+                .and_then(|value| value.parse().ok()),
+            extension_name: metadata.get(EXTENSION_NAME_KEY).cloned(),
+            geometry: GeometryEncodingV1::detect(field),
+            string_encoded: false,
+            embedding: EmbeddingModelV1::from_field(field),
+            normalized: metadata
+                .get(VECTOR_NORMALIZATION_KEY)
+                .is_some_and(|value| value == L2_NORMALIZATION),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaDefinition {
+    pub fields: Vec<SchemaField>,
+}
+
+impl SchemaDefinition {
+    pub fn from_arrow_schema(schema: &Schema) -> Self {
+        let fields = schema
+            .fields()
+            .iter()
+            .map(|field| SchemaField::from_arrow_field(field.as_ref()))
+            .collect();
+
+        Self { fields }
+    }
+
+    /// Flags `columns` as rendered as strings in JSON rows.
+    pub fn mark_string_encoded(&mut self, columns: &[String]) {
+        for field in &mut self.fields {
+            if columns.contains(&field.name) {
+                field.string_encoded = true;
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ScanRequestV1 {
+    pub table_id: String,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub format: DataFormat,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection: Option<Vec<String>>,
+    /// Name of a saved projection preset of the table, used instead of `projection`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection_preset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<usize>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub apply_masking: bool,
+    /// Queries the next page in the background so the following request is served
+    /// from cache.
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub prefetch: bool,
+    /// Returns an execution report alongside the page.
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub include_stats: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub json_options: Option<JsonOptionsV1>,
+    /// Reads this table version instead of the latest one. Paging with the `version`
+    /// of the first page keeps later pages stable while the table is written to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "crate::json_u64::option")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string | null"))]
+    pub as_of_version: Option<u64>,
+    /// Arrow pages only: the most bytes of encoded IPC one response may carry. A page
+    /// over the budget is cut short (to at least one row) and continues at `nextOffset`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_chunk_bytes: Option<usize>,
+    /// Tracks the scan as a job that `cancel_job_v1` can stop. Scans without one are
+    /// not tracked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct WriteRowsRequestV1 {
+    pub table_id: String,
+    #[cfg_attr(feature = "ts", ts(type = "unknown[]"))]
+    pub rows: Vec<serde_json::Value>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub mode: WriteDataMode,
+    /// L2-normalizes every float vector column and records it in the column
+    /// metadata. Columns that already record it are normalized either way.
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub normalize_vectors: bool,
+    /// Appended to the automatic checkpoint tag of this change; only ASCII letters
+    /// and digits are kept.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checkpoint_note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct WriteRowsResponseV1 {
+    pub table_id: String,
+    pub rows: usize,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub version: u64,
+    /// Vector columns whose values were L2-normalized.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub normalized_columns: Vec<String>,
+}
+
+/// `details` of the `invalid_argument` error `write_rows_v1` returns when a row does
+/// not fit the table schema.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct RowValidationErrorV1 {
+    /// Index of the first offending row in the request.
+    pub row_index: u64,
+    /// Offending field; missing when the row itself is malformed, e.g. not an object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateColumnInputV1 {
+    pub column: String,
+    pub expr: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRowsRequestV1 {
+    pub table_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    pub updates: Vec<UpdateColumnInputV1>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub allow_full_table: bool,
+    /// Appended to the automatic checkpoint tag of this change; only ASCII letters
+    /// and digits are kept.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checkpoint_note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRowsResponseV1 {
+    pub table_id: String,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub rows_updated: u64,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub version: u64,
+}
+
+/// Evaluates `updates` against up to `limit` rows matching `filter` without
+/// writing anything.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewUpdateRequestV1 {
+    pub table_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    pub updates: Vec<UpdateColumnInputV1>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub allow_full_table: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+/// One sampled row before and after the update. Values are cast to the column type
+/// the same way `update_rows_v1` writes them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePreviewRowV1 {
+    #[cfg_attr(feature = "ts", ts(type = "Record<string, unknown>"))]
+    pub before: serde_json::Value,
+    #[cfg_attr(feature = "ts", ts(type = "Record<string, unknown>"))]
+    pub after: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewUpdateResponseV1 {
+    pub table_id: String,
+    /// Updated columns, in request order.
+    pub columns: Vec<String>,
+    pub rows: Vec<UpdatePreviewRowV1>,
+    /// Rows the update would change.
+    pub matched_rows: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteRowsRequestV1 {
+    pub table_id: String,
+    pub filter: String,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub allow_full_table: bool,
+    /// Deletes at most this many rows per commit and tracks the delete as a job
+    /// that can be cancelled between chunks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chunk_size: Option<usize>,
+    /// Job id for a chunked delete, so progress can be polled while it runs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+    /// Appended to the automatic checkpoint tag of this change; only ASCII letters
+    /// and digits are kept.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checkpoint_note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteRowsResponseV1 {
+    pub table_id: String,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub version: u64,
+    /// Set for chunked deletes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+    /// Rows removed by a chunked delete.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "crate::json_u64::option")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string | null"))]
+    pub rows_deleted: Option<u64>,
+    /// True when a chunked delete was cancelled; chunks committed before that
+    /// stay deleted.
+    #[serde(default)]
+    pub cancelled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ImportDataRequestV1 {
+    pub table_id: String,
+    pub path: String,
+    pub format: DataFileFormatV1,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub mode: WriteDataMode,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_header: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delimiter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_sha256: Option<String>,
+    /// Stamps every row with `_source_file`, `_import_job_id` and `_source_line`,
+    /// adding the columns to the table first when it lacks them.
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub lineage: bool,
+    /// As in `write_rows_v1`.
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub normalize_vectors: bool,
+    /// Job id for the import, so `cancel_job_v1` can stop it. A cancelled import
+    /// commits nothing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+    /// Appended to the automatic checkpoint tag of this change; only ASCII letters
+    /// and digits are kept.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checkpoint_note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ImportDataResponseV1 {
+    pub table_id: String,
+    pub rows: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+    /// Value of `_import_job_id` on the imported rows, set for lineage imports.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub import_job_id: Option<String>,
+    /// Lineage columns this import added to the table.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub added_columns: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub normalized_columns: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnTypeMismatchV1 {
+    pub column: String,
+    pub table_type: String,
+    pub file_type: String,
+    /// Arrow type to cast the file column to, when Arrow can cast between the two.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggested_cast: Option<String>,
+}
+
+/// `details` of the `invalid_argument` error `import_data_v1` returns when the file
+/// does not fit the table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaCompatibilityReportV1 {
+    /// Table columns the file does not have.
+    pub missing_columns: Vec<String>,
+    /// File columns the table does not have.
+    pub extra_columns: Vec<String>,
+    pub type_mismatches: Vec<ColumnTypeMismatchV1>,
+    /// CSV headers name the table columns in another order; CSV columns are read
+    /// by position.
+    #[serde(default)]
+    pub column_order_differs: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ExportDataRequestV1 {
+    pub table_id: String,
+    pub path: String,
+    pub format: DataFileFormatV1,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delimiter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub with_header: Option<bool>,
+    /// Geometry column for `geojson` exports; detected from the schema when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geometry_column: Option<String>,
+    /// Id to poll the export under; a new one is generated when omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ExportDataResponseV1 {
+    pub path: String,
+    pub rows: usize,
+    pub bytes: u64,
+    pub sha256: String,
+}
+
+/// Compares an existing Parquet or CSV file with the columns an export of the table
+/// would write, before appending to it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CheckExportTargetRequestV1 {
+    pub table_id: String,
+    pub path: String,
+    pub format: DataFileFormatV1,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delimiter: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CheckExportTargetResponseV1 {
+    pub path: String,
+    pub exists: bool,
+    /// True when the file is missing or has the columns the export writes.
+    pub compatible: bool,
+    /// Differences between the export (the "table" side) and the existing file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report: Option<SchemaCompatibilityReportV1>,
+}
+
+/// Exports the rows with the given `_rowid`s, e.g. the rows selected in the grid.
+/// Rows keep the table order; `rows` of the response counts those still present.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ExportRowsRequestV1 {
+    pub table_id: String,
+    pub row_ids: Vec<u64>,
+    pub format: DataFileFormatV1,
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delimiter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub with_header: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geometry_column: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+}
+
+/// A saved export configuration. `pathPattern` may contain `{table}`, `{date}`,
+/// `{time}` and `{timestamp}`, expanded in local time when the template runs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ExportTemplateV1 {
+    pub name: String,
+    pub format: DataFileFormatV1,
+    pub path_pattern: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delimiter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub with_header: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListExportTemplatesResponseV1 {
+    pub templates: Vec<ExportTemplateV1>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SaveExportTemplateRequestV1 {
+    pub template: ExportTemplateV1,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SaveExportTemplateResponseV1 {
+    pub template: ExportTemplateV1,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteExportTemplateRequestV1 {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteExportTemplateResponseV1 {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct RunExportTemplateRequestV1 {
+    pub table_id: String,
+    pub name: String,
+}
+
+/// A table served by a share. Masking rules of the table always apply.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ShareTableV1 {
+    pub table_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection: Option<Vec<String>>,
+    /// Only rows matching this filter are visible through the share.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct StartShareRequestV1 {
+    pub tables: Vec<ShareTableV1>,
+    /// Defaults to one hour; at most one day.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_in_seconds: Option<u64>,
+    /// A free port is picked when unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    /// Listen on the loopback interface only instead of the LAN.
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub local_only: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ShareSessionV1 {
+    pub share_id: String,
+    /// Browser URL including the token.
+    pub url: String,
+    pub token: String,
+    /// Names of the shared tables.
+    pub tables: Vec<String>,
+    pub started_at_ms: u64,
+    pub expires_at_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListSharesResponseV1 {
+    pub shares: Vec<ShareSessionV1>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct StopShareRequestV1 {
+    pub share_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct StopShareResponseV1 {
+    pub share_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct BundleShardV1 {
+    pub file: String,
+    pub rows: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct BundleIndexV1 {
+    pub name: String,
+    pub index_type: IndexTypeV1,
+    pub columns: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct BundleManifestV1 {
+    pub format_version: u32,
+    pub table_name: String,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub source_version: u64,
+    pub created_at_ms: u64,
+    pub rows: usize,
+    pub shards: Vec<BundleShardV1>,
+    #[serde(default)]
+    pub indexes: Vec<BundleIndexV1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection: Option<Vec<String>>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ExportBundleRequestV1 {
+    pub table_id: String,
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rows_per_shard: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub overwrite: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ExportBundleResponseV1 {
+    pub path: String,
+    pub manifest: BundleManifestV1,
+}
+
+/// Writes a zip holding `manifest.json`, `schema.json` and `rows` scrambled rows as
+/// `sample.arrows`, for attaching to bug reports.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ExportReproSampleRequestV1 {
+    pub table_id: String,
+    pub path: String,
+    pub rows: usize,
+    /// Narrows the sample to the rows that reproduce the problem.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// Scrambles the same way as an earlier export; random when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "crate::json_u64::option")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string | null"))]
+    pub seed: Option<u64>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub overwrite: bool,
+}
+
+/// Leaves out the table name and row values so the archive can be shared.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ReproSampleManifestV1 {
+    pub format_version: u32,
+    pub viewer_version: String,
+    pub created_at_ms: u64,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub source_version: u64,
+    pub source_rows: usize,
+    pub rows: usize,
+    /// Random seeds use all 64 bits, so this is a `json_u64` like the versions.
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub seed: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    #[serde(default)]
+    pub indexes: Vec<BundleIndexV1>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ExportReproSampleResponseV1 {
+    pub path: String,
+    pub bytes: u64,
+    pub sha256: String,
+    pub manifest: ReproSampleManifestV1,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ImportBundleRequestV1 {
+    pub connection_id: String,
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table_name: Option<String>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub skip_indexes: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ImportBundleResponseV1 {
+    pub table_id: String,
+    pub name: String,
+    pub rows: usize,
+    pub indexes_created: Vec<String>,
+    pub index_warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum OptimizeActionV1 {
+    Compact,
+    Vacuum,
+    /// Adds rows written since the last build to the existing indexes.
+    Index,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct OptimizeTableRequestV1 {
+    pub table_id: String,
+    pub action: OptimizeActionV1,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_rows_per_fragment: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub older_than_days: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete_unverified: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_if_tagged_old_versions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<LocaleV1>,
+    /// `index` only: how long to wait for every index to cover the table after the
+    /// merge, for backends that index in the background. Unset returns at once.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wait_timeout_ms: Option<u64>,
+    /// Tracks the optimize as a job, so its progress events carry this id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+}
+
+/// Rows one index covers after an `index` optimize.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct OptimizedIndexV1 {
+    pub name: String,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub num_indexed_rows: u64,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub num_unindexed_rows: u64,
+}
+
+/// What an optimize did, as reported by LanceDB. Each action fills its own fields.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct OptimizeStatsV1 {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fragments_removed: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fragments_added: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files_removed: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files_added: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bytes_removed: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_versions_removed: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub indexes: Vec<OptimizedIndexV1>,
+    /// Set when `waitTimeoutMs` ran out before every index covered the table. The
+    /// optimization itself has committed; `indexes` shows how far indexing got.
+    #[serde(default)]
+    pub index_pending: bool,
+    pub elapsed_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct OptimizeTableResponseV1 {
+    pub table_id: String,
+    pub action: OptimizeActionV1,
+    pub summary: String,
+    pub summary_message: MessageV1,
+    pub stats: OptimizeStatsV1,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTableRequestV1 {
+    pub connection_id: String,
+    pub table_name: String,
+    pub schema: SchemaDefinitionInput,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub options: CreateTableOptionsV1,
+}
+
+/// Dataset settings fixed at creation time.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTableOptionsV1 {
+    /// Keeps `_rowid` values stable across compaction and updates.
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub enable_stable_row_ids: bool,
+    /// Lance data storage version such as `2.1`; defaults to the library default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_storage_version: Option<String>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub enable_v2_manifest_paths: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTableResponseV1 {
+    pub table_id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct AddColumnsRequestV1 {
+    pub table_id: String,
+    pub columns: SchemaDefinitionInput,
+    /// Appended to the automatic checkpoint tag of this change; only ASCII letters
+    /// and digits are kept.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checkpoint_note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct AddColumnsResponseV1 {
+    pub table_id: String,
+    pub added: Vec<String>,
+    pub schema: SchemaDefinition,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnAlterationInput {
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rename: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nullable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_type: Option<FieldDataType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vector_length: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct AlterColumnsRequestV1 {
+    pub table_id: String,
+    pub columns: Vec<ColumnAlterationInput>,
+    /// Drops and recreates the indexes on renamed or retyped columns once the
+    /// alteration is committed. Otherwise they are only reported.
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub rebuild_indexes: bool,
+    /// Appended to the automatic checkpoint tag of this change; only ASCII letters
+    /// and digits are kept.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checkpoint_note: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum IndexRebuildReasonV1 {
+    Renamed,
+    /// Lance cannot keep an index over a column whose values were cast.
+    Retyped,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum IndexRebuildStateV1 {
+    /// Reported only; the request did not ask for a rebuild.
+    Planned,
+    Rebuilt,
+    Failed,
+}
+
+/// An index over a column that `alter_columns_v1` renamed or retyped.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct IndexRebuildV1 {
+    pub index_name: String,
+    pub index_type: IndexTypeV1,
+    /// Indexed columns under their names after the alteration.
+    pub columns: Vec<String>,
+    pub reason: IndexRebuildReasonV1,
+    /// Name of the recreated index: the default name for the renamed column when
+    /// the old one was the default, the old name otherwise.
+    pub new_index_name: String,
+    /// Metric of a vector index, kept by the rebuild.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance_type: Option<DistanceTypeV1>,
+    pub state: IndexRebuildStateV1,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Settings saved by the backend that can name a column.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum SavedArtifactKindV1 {
+    /// The display order stored in the table metadata.
+    ColumnOrder,
+    SearchDefaults,
+    RetentionPolicy,
+    MaskingRule,
+    EncryptedColumn,
+    RecentFilter,
+    ExportTemplate,
+    ProjectionPreset,
+}
+
+/// A saved setting `alter_columns_v1` rewrote to follow a renamed column.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatedArtifactV1 {
+    pub kind: SavedArtifactKindV1,
+    /// Template name, the filter as rewritten, or the column of a masking or
+    /// encryption rule. Per-table settings carry the table name.
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct AlterColumnsResponseV1 {
+    pub table_id: String,
+    pub updated: Vec<String>,
+    pub schema: SchemaDefinition,
+    /// Indexes affected by the alteration.
+    #[serde(default)]
+    pub index_rebuilds: Vec<IndexRebuildV1>,
+    /// The `index_build` job that recreated them, when `rebuildIndexes` was set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rebuild_job_id: Option<String>,
+    /// Saved settings rewritten to use the new names of renamed columns.
+    #[serde(default)]
+    pub updated_artifacts: Vec<UpdatedArtifactV1>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DropColumnsRequestV1 {
+    pub table_id: String,
+    pub columns: Vec<String>,
+    /// Appended to the automatic checkpoint tag of this change; only ASCII letters
+    /// and digits are kept.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checkpoint_note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DropColumnsResponseV1 {
+    pub table_id: String,
+    pub dropped: Vec<String>,
+    pub schema: SchemaDefinition,
+}
+
+/// Sets the display order of the columns. Columns `order` leaves out follow in
+/// schema order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ReorderColumnsRequestV1 {
+    pub table_id: String,
+    pub order: Vec<String>,
+    /// Appended to the automatic checkpoint tag of this change; only ASCII letters
+    /// and digits are kept.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checkpoint_note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ReorderColumnsResponseV1 {
+    pub table_id: String,
+    /// Every column, in the stored order.
+    pub order: Vec<String>,
+    pub schema: SchemaDefinition,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct JsonChunk {
+    #[cfg_attr(feature = "ts", ts(type = "unknown[]"))]
+    pub rows: Vec<serde_json::Value>,
+    pub schema: SchemaDefinition,
+    pub offset: usize,
+    pub limit: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ArrowChunk {
+    pub ipc_base64: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compression: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(tag = "format", rename_all = "snake_case")]
+pub enum DataChunk {
+    Json(JsonChunk),
+    Arrow(ArrowChunk),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ScanResponseV1 {
+    pub chunk: DataChunk,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<usize>,
+    /// Table version the page was read from.
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub version: u64,
+    /// Set when `maxChunkBytes` cut the page short.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split: Option<ChunkSplitV1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<QueryExecutionStatsV1>,
+}
+
+/// How a page was split to stay under `maxChunkBytes`. The rest of the page is read
+/// from `nextOffset` with `limit` set to `remainingRows`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkSplitV1 {
+    /// Rows in this chunk.
+    pub rows: usize,
+    /// Rows of the requested page left for the following chunks.
+    pub remaining_rows: usize,
+    /// Size of this chunk's IPC payload.
+    pub bytes: usize,
+}
+
+/// Streams a whole scan over a channel instead of returning one page. Chunks are
+/// complete Arrow IPC streams sent as raw bytes, numbered from 1 in the order they
+/// arrive. At most `window` chunks are sent ahead of the last one acknowledged
+/// with `ack_scan_stream_v1`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ScanStreamRequestV1 {
+    pub table_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection: Option<Vec<String>>,
+    /// Name of a saved projection preset of the table, used instead of `projection`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection_preset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// Stops after this many rows; the whole table is streamed by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<usize>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub apply_masking: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "crate::json_u64::option")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string | null"))]
+    pub as_of_version: Option<u64>,
+    /// Largest encoded chunk; defaults to 4 MiB. Batches over it are split by rows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_chunk_bytes: Option<usize>,
+    /// Unacknowledged chunks allowed in flight; defaults to 4, at most 64.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window: Option<u32>,
+}
+
+/// JSON messages of a scan stream. Chunks arrive between `started` and
+/// `completed` as raw bytes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ScanStreamEventV1 {
+    Started(ScanStreamStartedV1),
+    Completed(ScanStreamSummaryV1),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ScanStreamStartedV1 {
+    pub stream_id: String,
+    /// Table version the stream reads.
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub version: u64,
+    pub window: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ScanStreamSummaryV1 {
+    pub stream_id: String,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub version: u64,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub total_rows: u64,
+    pub chunks: u64,
+    pub total_bytes: u64,
+    /// Set when `cancel_scan_stream_v1` stopped the stream early.
+    pub cancelled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct AckScanStreamRequestV1 {
+    pub stream_id: String,
+    /// Every chunk up to and including this one has been handled.
+    pub sequence: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CancelScanStreamRequestV1 {
+    pub stream_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ScanStreamControlResponseV1 {
+    pub stream_id: String,
+    /// False once the stream has finished; late acknowledgements are ignored.
+    pub active: bool,
+}
+
+/// One node of an analyzed query plan. `depth` is the nesting level in the plan tree.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct PlanStageV1 {
+    pub name: String,
+    pub depth: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "crate::json_u64::option")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string | null"))]
+    pub output_rows: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elapsed_ms: Option<f64>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub metrics: BTreeMap<String, String>,
+}
+
+/// Execution report returned when a request sets `includeStats`. The query is run a
+/// second time under `analyze_plan`, so `elapsedMs` covers that run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct QueryExecutionStatsV1 {
+    pub elapsed_ms: u64,
+    pub index_used: bool,
+    pub flat_scan: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "crate::json_u64::option")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string | null"))]
+    pub rows_scanned: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bytes_read: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iops: Option<u64>,
+    pub stages: Vec<PlanStageV1>,
+    pub plan: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct VersionInfoV1 {
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub version: u64,
+    pub timestamp: String,
+    pub metadata: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListVersionsRequestV1 {
+    pub table_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListVersionsResponseV1 {
+    pub versions: Vec<VersionInfoV1>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct GetTableVersionRequestV1 {
+    pub table_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct GetTableVersionResponseV1 {
+    pub table_id: String,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub version: u64,
+}
+
+/// Checks out `version`, or the version `tag` points to. Exactly one is set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CheckoutTableVersionRequestV1 {
+    pub table_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "crate::json_u64::option")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string | null"))]
+    pub version: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CheckoutTableVersionResponseV1 {
+    pub table_id: String,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub version: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CheckoutTableLatestRequestV1 {
+    pub table_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CheckoutTableLatestResponseV1 {
+    pub table_id: String,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub version: u64,
+}
+
+/// Rolls the table back to `version` by committing that version's state as a
+/// new latest version; later versions stay in the history.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreTableVersionRequestV1 {
+    pub table_id: String,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub version: u64,
+    /// Appended to the automatic checkpoint tag of this change; only ASCII letters
+    /// and digits are kept.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checkpoint_note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreTableVersionResponseV1 {
+    pub table_id: String,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub restored_version: u64,
+    /// The new version holding the restored state.
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub version: u64,
+}
+
+/// A name pointing at a table version. Lance keeps tagged versions when old
+/// versions are cleaned up.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct VersionTagV1 {
+    pub name: String,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub version: u64,
+    /// Checkpoint note of an automatic tag, as written; the name only carries its
+    /// ASCII slug.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListTagsRequestV1 {
+    pub table_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListTagsResponseV1 {
+    pub table_id: String,
+    /// Newest version first.
+    pub tags: Vec<VersionTagV1>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTagRequestV1 {
+    pub table_id: String,
+    pub name: String,
+    /// Defaults to the checked-out version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "crate::json_u64::option")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string | null"))]
+    pub version: Option<u64>,
+}
+
+/// Points an existing tag at another version.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateTagRequestV1 {
+    pub table_id: String,
+    pub name: String,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub version: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct TagResponseV1 {
+    pub table_id: String,
+    pub tag: VersionTagV1,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteTagRequestV1 {
+    pub table_id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteTagResponseV1 {
+    pub table_id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CloneTableRequestV1 {
+    pub connection_id: String,
+    pub table_id: String,
+    pub target_table_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "crate::json_u64::option")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string | null"))]
+    pub source_version: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_shallow: Option<bool>,
+    /// Tracks the clone as a job, so its progress events carry this id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CloneTableResponseV1 {
+    pub table_id: String,
+    pub name: String,
+}
+
+/// The filter or search whose result `materialize_query_v1` saves. Paging fields are
+/// honoured; a filter without `limit` saves every matching row.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MaterializeSourceV1 {
+    Filter(QueryFilterRequestV1),
+    VectorSearch(VectorSearchRequestV1),
+    FtsSearch(FtsSearchRequestV1),
+    CombinedSearch(CombinedSearchRequestV1),
+}
+
+impl MaterializeSourceV1 {
+    pub fn table_id(&self) -> &str {
+        match self {
+            MaterializeSourceV1::Filter(request) => &request.table_id,
+            MaterializeSourceV1::VectorSearch(request) => &request.table_id,
+            MaterializeSourceV1::FtsSearch(request) => &request.table_id,
+            MaterializeSourceV1::CombinedSearch(request) => &request.table_id,
+        }
+    }
+
+    /// Table version a search source reads; filters always read the latest one.
+    pub fn as_of_version(&self) -> Option<u64> {
+        match self {
+            MaterializeSourceV1::Filter(_) => None,
+            MaterializeSourceV1::VectorSearch(request) => request.as_of_version,
+            MaterializeSourceV1::FtsSearch(request) => request.as_of_version,
+            MaterializeSourceV1::CombinedSearch(request) => request.as_of_version,
+        }
+    }
+
+    pub fn projection(&self) -> Option<&[String]> {
+        match self {
+            MaterializeSourceV1::Filter(request) => request.projection.as_deref(),
+            MaterializeSourceV1::VectorSearch(request) => request.projection.as_deref(),
+            MaterializeSourceV1::FtsSearch(request) => request.projection.as_deref(),
+            MaterializeSourceV1::CombinedSearch(request) => request.projection.as_deref(),
+        }
+    }
+
+    /// The projection and projection preset fields, so a preset can be resolved in
+    /// place.
+    pub fn projection_fields_mut(&mut self) -> (&mut Option<Vec<String>>, &mut Option<String>) {
+        match self {
+            MaterializeSourceV1::Filter(request) => {
+                (&mut request.projection, &mut request.projection_preset)
+            }
+            MaterializeSourceV1::VectorSearch(request) => {
+                (&mut request.projection, &mut request.projection_preset)
+            }
+            MaterializeSourceV1::FtsSearch(request) => {
+                (&mut request.projection, &mut request.projection_preset)
+            }
+            MaterializeSourceV1::CombinedSearch(request) => {
+                (&mut request.projection, &mut request.projection_preset)
+            }
+        }
+    }
+
+    pub fn json_options(&self) -> Option<&JsonOptionsV1> {
+        match self {
+            MaterializeSourceV1::Filter(request) => request.json_options.as_ref(),
+            MaterializeSourceV1::VectorSearch(request) => request.json_options.as_ref(),
+            MaterializeSourceV1::FtsSearch(request) => request.json_options.as_ref(),
+            MaterializeSourceV1::CombinedSearch(request) => request.json_options.as_ref(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct MaterializeQueryRequestV1 {
+    pub connection_id: String,
+    pub source: MaterializeSourceV1,
+    pub table_name: String,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub overwrite: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct MaterializeQueryResponseV1 {
+    pub table_id: String,
+    pub name: String,
+    pub rows: usize,
+}
+
+/// Runs `query` against two versions of a table and diffs the results. The table id
+/// and version pin inside `query` are ignored. Rows are matched by `keyColumns`;
+/// without keys, whole rows are compared and nothing is reported as changed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CompareQueryVersionsRequestV1 {
+    pub table_id: String,
+    pub query: MaterializeSourceV1,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub version_a: u64,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub version_b: u64,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub key_columns: Vec<String>,
+    /// Most rows listed per kind of difference; counts always cover every row.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_rows: Option<usize>,
+}
+
+/// A row whose key matched in both versions with different values.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ChangedRowV1 {
+    #[cfg_attr(feature = "ts", ts(type = "Record<string, unknown>"))]
+    pub key: serde_json::Value,
+    #[cfg_attr(feature = "ts", ts(type = "Record<string, unknown>"))]
+    pub before: serde_json::Value,
+    #[cfg_attr(feature = "ts", ts(type = "Record<string, unknown>"))]
+    pub after: serde_json::Value,
+    /// Columns whose values differ.
+    pub columns: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CompareQueryVersionsResponseV1 {
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub version_a: u64,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub version_b: u64,
+    pub rows_a: usize,
+    pub rows_b: usize,
+    /// Rows only `versionB` returns.
+    #[cfg_attr(feature = "ts", ts(type = "Record<string, unknown>[]"))]
+    pub added: Vec<serde_json::Value>,
+    /// Rows only `versionA` returns.
+    #[cfg_attr(feature = "ts", ts(type = "Record<string, unknown>[]"))]
+    pub removed: Vec<serde_json::Value>,
+    pub changed: Vec<ChangedRowV1>,
+    pub added_count: usize,
+    pub removed_count: usize,
+    pub changed_count: usize,
+    pub unchanged_count: usize,
+    /// Set when `maxRows` left rows out of a list.
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum JobKindV1 {
+    Backup,
+    Restore,
+    MigrateFormat,
+    Delete,
+    Export,
+    IndexBuild,
+    Scan,
+    Import,
+    Optimize,
+    Clone,
+}
+
+/// Step a long-running job is at, reported in `ProgressEventV1`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum ProgressStageV1 {
+    Starting,
+    Reading,
+    Writing,
+    Copying,
+    Indexing,
+    Compacting,
+    Pruning,
+    Verifying,
+    Finished,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum JobStateV1 {
+    Running,
+    Succeeded,
+    Failed,
+    /// Stopped by `cancel_job_v1` or by closing the app before it finished.
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct JobStatusV1 {
+    pub job_id: String,
+    pub kind: JobKindV1,
+    pub state: JobStateV1,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub processed_rows: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "crate::json_u64::option")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string | null"))]
+    pub total_rows: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    pub started_at_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finished_at_ms: Option<u64>,
+    /// Last stage the job reported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stage: Option<ProgressStageV1>,
+}
+
+/// Emitted as the `job-progress` event whenever a job starts, changes stage,
+/// processes rows or finishes, so one progress component can follow imports,
+/// exports, index builds, copies and compactions alike.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ProgressEventV1 {
+    pub job_id: String,
+    pub kind: JobKindV1,
+    pub state: JobStateV1,
+    pub stage: ProgressStageV1,
+    /// 0 to 100 when the job knows its total rows or has finished; unset while the
+    /// amount of work is unknown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percent: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub processed_rows: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "crate::json_u64::option")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string | null"))]
+    pub total_rows: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct GetJobStatusRequestV1 {
+    pub job_id: String,
+}
+
+/// Lists tracked jobs, newest first. Running jobs and the last 100 finished ones
+/// are kept.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListJobsRequestV1 {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<JobStateV1>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<JobKindV1>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListJobsResponseV1 {
+    pub jobs: Vec<JobStatusV1>,
+}
+
+/// Stops a running job at its next progress checkpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CancelJobRequestV1 {
+    pub job_id: String,
+}
+
+/// A scan, filter query or search that is still executing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveQueryV1 {
+    pub query_id: String,
+    /// Command running the query, e.g. `scan_v1`.
+    pub command: String,
+    pub table_id: String,
+    /// What the query runs, such as `filter where id > 3`, cut to 200 characters.
+    pub summary: String,
+    pub started_at_ms: u64,
+    pub elapsed_ms: u64,
+    /// Set once the query was killed; it leaves the list when its command returns.
+    #[serde(default)]
+    pub killed: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListActiveQueriesResponseV1 {
+    pub queries: Vec<ActiveQueryV1>,
+}
+
+/// Stops a running query; its command returns a `cancelled` error.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct KillQueryRequestV1 {
+    pub query_id: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum BackupModeV1 {
+    #[default]
+    Full,
+    Shallow,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct BackupTableRequestV1 {
+    pub table_id: String,
+    pub destination_uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backup_table_name: Option<String>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub mode: BackupModeV1,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_options: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct BackupTableResponseV1 {
+    pub job_id: String,
+    pub destination_uri: String,
+    pub backup_table_name: String,
+    pub mode: BackupModeV1,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub source_version: u64,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub rows: u64,
+    pub versions: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreBackupRequestV1 {
+    pub connection_id: String,
+    pub backup_uri: String,
+    pub backup_table_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_table_name: Option<String>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub overwrite: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_options: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreBackupResponseV1 {
+    pub job_id: String,
+    pub table_id: String,
+    pub name: String,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub rows: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<BackupModeV1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_table: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "crate::json_u64::option")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string | null"))]
+    pub source_version: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backed_up_at_ms: Option<u64>,
+    pub versions: Vec<VersionInfoV1>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct TableFormatInfoRequestV1 {
+    pub table_id: String,
+}
+
+/// Storage format recorded in the table's current manifest.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct TableFormatInfoV1 {
+    pub table_id: String,
+    /// Manifest (table) version the information was read from.
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub version: u64,
+    pub file_format: String,
+    /// Lance data storage version, such as `2.0`; `0.1` is the legacy format.
+    pub storage_version: String,
+    pub latest_storage_version: String,
+    pub upgrade_available: bool,
+    pub stable_row_ids: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub writer_library: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub writer_version: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct TableLocationRequestV1 {
+    pub table_id: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum DatasetDirectoryKindV1 {
+    Data,
+    Indices,
+    Versions,
+    Deletions,
+    Transactions,
+}
+
+/// A directory of the lance dataset layout.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DatasetDirectoryV1 {
+    pub kind: DatasetDirectoryKindV1,
+    pub uri: String,
+    /// Filesystem path on local backends.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub local_path: Option<String>,
+    /// Whether the directory exists; only checked on local backends.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exists: Option<bool>,
+}
+
+/// Where a table's dataset is stored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct TableLocationV1 {
+    pub table_id: String,
+    pub name: String,
+    pub dataset_uri: String,
+    pub backend_kind: BackendKind,
+    /// Absolute path of the dataset directory on local backends, for opening it in a
+    /// file manager.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub local_path: Option<String>,
+    pub directories: Vec<DatasetDirectoryV1>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct MigrateTableFormatRequestV1 {
+    pub table_id: String,
+    /// Defaults to the latest supported storage version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_version: Option<String>,
+    /// Defaults to `<table>_v<version>`, e.g. `items_v2_1`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_table_name: Option<String>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub overwrite: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct MigrateTableFormatResponseV1 {
+    pub job_id: String,
+    pub table_id: String,
+    pub name: String,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub rows: u64,
+    pub from_version: String,
+    pub to_version: String,
+    /// Indexes on the source table; they are not copied and must be rebuilt.
+    pub indexes_to_rebuild: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScheduleActionV1 {
+    #[serde(rename_all = "camelCase")]
+    Compact {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        target_rows_per_fragment: Option<u64>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Vacuum {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        older_than_days: Option<u64>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Backup {
+        destination_uri: String,
+        #[serde(default)]
+        #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+        mode: BackupModeV1,
+    },
+    #[serde(rename_all = "camelCase")]
+    Export {
+        path: String,
+        format: DataFileFormatV1,
+    },
+    /// Deletes expired rows according to the table's retention policy.
+    Retention,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduleRunStatusV1 {
+    Succeeded,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleV1 {
+    pub id: String,
+    pub connection_uri: String,
+    pub table_name: String,
+    pub cron: String,
+    pub action: ScheduleActionV1,
+    pub enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_run_at_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_status: Option<ScheduleRunStatusV1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_message: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListSchedulesRequestV1 {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListSchedulesResponseV1 {
+    pub schedules: Vec<ScheduleV1>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SetScheduleRequestV1 {
+    pub connection_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub table_name: String,
+    pub cron: String,
+    pub action: ScheduleActionV1,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SetScheduleResponseV1 {
+    pub schedule: ScheduleV1,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteScheduleRequestV1 {
+    pub id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteScheduleResponseV1 {
+    pub id: String,
+}
+
+/// A directory whose new files are appended to a table in the background. Files are
+/// imported once each, keyed by file name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct WatchFolderV1 {
+    pub id: String,
+    pub connection_uri: String,
+    pub table_name: String,
+    pub directory: String,
+    pub format: DataFileFormatV1,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_header: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delimiter: Option<String>,
+    /// As in `import_data_v1`.
+    #[serde(default)]
+    pub lineage: bool,
+    pub enabled: bool,
+    /// Names of the files already imported or skipped, including those present when
+    /// the folder was set up without `includeExisting`.
+    #[serde(default)]
+    pub seen_files: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_ingested_at_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListWatchFoldersRequestV1 {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListWatchFoldersResponseV1 {
+    pub watch_folders: Vec<WatchFolderV1>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SetWatchFolderRequestV1 {
+    pub connection_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub table_name: String,
+    pub directory: String,
+    pub format: DataFileFormatV1,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_header: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delimiter: Option<String>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub lineage: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Imports the files already in the directory too. Otherwise only files that
+    /// appear later are imported.
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub include_existing: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SetWatchFolderResponseV1 {
+    pub watch_folder: WatchFolderV1,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteWatchFolderRequestV1 {
+    pub id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteWatchFolderResponseV1 {
+    pub id: String,
+}
+
+/// Emitted as the `watch-folder-ingested` event for every file a watch folder
+/// imports or fails to import.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct WatchFolderEventV1 {
+    pub watch_folder_id: String,
+    pub table_name: String,
+    pub file_name: String,
+    pub rows: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub ingested_at_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MaskingStrategyV1 {
+    /// SHA-256 hex digest of the value, so masked columns can still be joined.
+    Hash,
+    Redact,
+    #[serde(rename_all = "camelCase")]
+    Truncate {
+        length: usize,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct MaskingRuleV1 {
+    /// Applies to every table of the connection when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table_name: Option<String>,
+    pub column: String,
+    pub strategy: MaskingStrategyV1,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListMaskingRulesRequestV1 {
+    pub connection_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListMaskingRulesResponseV1 {
+    pub rules: Vec<MaskingRuleV1>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SetMaskingRulesRequestV1 {
+    pub connection_id: String,
+    pub rules: Vec<MaskingRuleV1>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SetMaskingRulesResponseV1 {
+    pub rules: Vec<MaskingRuleV1>,
+}
+
+/// A string column encrypted client-side with AES-256-GCM under the key `key_id`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptedColumnV1 {
+    /// Applies to every table of the connection when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table_name: Option<String>,
+    pub column: String,
+    /// Reference of the key in the credential vault.
+    pub key_id: String,
+    /// Authenticated with every value in place of `column`, which it defaults to.
+    /// Renaming the column sets it to the old name, so existing ciphertext still
+    /// decrypts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListEncryptedColumnsRequestV1 {
+    pub connection_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListEncryptedColumnsResponseV1 {
+    pub columns: Vec<EncryptedColumnV1>,
+    /// Keys of `columns` not unlocked in this session.
+    pub locked_key_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SetEncryptedColumnsRequestV1 {
+    pub connection_id: String,
+    pub columns: Vec<EncryptedColumnV1>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SetEncryptedColumnsResponseV1 {
+    pub columns: Vec<EncryptedColumnV1>,
+}
+
+/// Unlocks a key for this session, or locks it again when `key` is omitted.
+#[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SetEncryptionKeyRequestV1 {
+    pub key_id: String,
+    /// Base64 encoded 256-bit key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+}
+
+impl std::fmt::Debug for SetEncryptionKeyRequestV1 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SetEncryptionKeyRequestV1")
+            .field("key_id", &self.key_id)
+            .field("key", &self.key.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SetEncryptionKeyResponseV1 {
+    pub key_id: String,
+    pub unlocked: bool,
+}
+
+/// Rows whose `timestamp_column` is older than `max_age_seconds` are expired.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionPolicyV1 {
+    pub timestamp_column: String,
+    pub max_age_seconds: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct GetRetentionPolicyRequestV1 {
+    pub table_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SetRetentionPolicyRequestV1 {
+    pub table_id: String,
+    /// Clears the policy when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<RetentionPolicyV1>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionPolicyResponseV1 {
+    pub table_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<RetentionPolicyV1>,
+}
+
+/// Free-text documentation attached to a table by the user.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct TableDescriptionV1 {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(default)]
+    pub tags: BTreeMap<String, String>,
+    /// Set by the backend when the description is saved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct GetTableDescriptionRequestV1 {
+    pub table_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SetTableDescriptionRequestV1 {
+    pub table_id: String,
+    /// Clears the description when omitted or empty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<TableDescriptionV1>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct TableDescriptionResponseV1 {
+    pub table_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<TableDescriptionV1>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum RecentFilterSortV1 {
+    Recent,
+    Frequent,
+}
+
+/// Filters previously used on a table, for autocomplete in the filter bar. Scans
+/// and filter queries record their filter when they fetch the first page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct RecentFiltersRequestV1 {
+    pub table_id: String,
+    /// Only filters starting with this text, ignoring case.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    /// Defaults to `recent`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<RecentFilterSortV1>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct RecentFilterV1 {
+    pub filter: String,
+    pub uses: u64,
+    pub last_used_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct RecentFiltersResponseV1 {
+    pub table_id: String,
+    pub filters: Vec<RecentFilterV1>,
+}
+
+/// Groups column types by the operators and functions that apply to them.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum ValueCategoryV1 {
+    Numeric,
+    String,
+    Boolean,
+    Temporal,
+    Binary,
+    List,
+    /// Fixed-size lists of floats. Filters reach them through the list functions,
+    /// e.g. `array_length(vector)` or `vector[1]`; similarity needs vector search.
+    Vector,
+    Struct,
+    Other,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionMetadataRequestV1 {
+    pub table_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionColumnV1 {
+    /// Column name, or a dotted path for struct fields.
+    pub name: String,
+    /// `name` quoted where a filter needs it, e.g. `` meta.`source file` ``.
+    pub insert_text: String,
+    pub data_type: String,
+    pub category: ValueCategoryV1,
+    pub nullable: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryOperatorsV1 {
+    pub category: ValueCategoryV1,
+    pub operators: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SqlFunctionV1 {
+    pub name: String,
+    pub signature: String,
+    pub description: String,
+    /// Categories of the first argument; empty when any column fits.
+    pub applies_to: Vec<ValueCategoryV1>,
+}
+
+/// What a filter or SQL editor needs to suggest completions for a table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionMetadataResponseV1 {
+    pub table_id: String,
+    pub columns: Vec<CompletionColumnV1>,
+    pub operators: Vec<CategoryOperatorsV1>,
+    pub functions: Vec<SqlFunctionV1>,
+    pub keywords: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ValidateFilterRequestV1 {
+    pub table_id: String,
+    pub filter: String,
+}
+
+/// Whether a filter plans against the table, checked without reading rows.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ValidateFilterResponseV1 {
+    pub table_id: String,
+    pub valid: bool,
+    /// The planner's error when the filter is invalid.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// How to filter on a vector column the invalid filter references.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<MessageV1>,
+}
+
+/// Search settings remembered for a table, used to pre-fill the search forms.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SearchDefaultsV1 {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vector_column: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance_type: Option<DistanceTypeV1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_k: Option<usize>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub fts_columns: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct GetSearchDefaultsRequestV1 {
+    pub table_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SetSearchDefaultsRequestV1 {
+    pub table_id: String,
+    /// Clears the defaults when omitted or empty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub defaults: Option<SearchDefaultsV1>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SearchDefaultsResponseV1 {
+    pub table_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub defaults: Option<SearchDefaultsV1>,
+}
+
+/// A named projection saved for one table. Scan and search requests pass its
+/// name as `projectionPreset` instead of the column list.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectionPresetV1 {
+    pub name: String,
+    /// Projection entries as `projection` takes them, columns or `expr AS alias`.
+    pub columns: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListProjectionPresetsRequestV1 {
+    pub table_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListProjectionPresetsResponseV1 {
+    pub table_id: String,
+    pub presets: Vec<ProjectionPresetV1>,
+}
+
+/// Saves `preset`, replacing the table's preset with the same name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SaveProjectionPresetRequestV1 {
+    pub table_id: String,
+    pub preset: ProjectionPresetV1,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SaveProjectionPresetResponseV1 {
+    pub table_id: String,
+    pub preset: ProjectionPresetV1,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteProjectionPresetRequestV1 {
+    pub table_id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteProjectionPresetResponseV1 {
+    pub table_id: String,
+    pub name: String,
+}
+
+/// Table statistics recorded at one point in time.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct TableStatsSnapshotV1 {
+    /// Unix milliseconds.
+    pub taken_at_ms: u64,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub row_count: u64,
+    pub size_bytes: u64,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub version: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct StatsHistoryRequestV1 {
+    pub table_id: String,
+    /// Only returns snapshots taken at or after this time, in Unix milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct StatsHistoryResponseV1 {
+    pub table_id: String,
+    /// Oldest first.
+    pub snapshots: Vec<TableStatsSnapshotV1>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct RunRetentionRequestV1 {
+    pub table_id: String,
+    /// Counts expired rows without deleting them.
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct RunRetentionResponseV1 {
+    pub table_id: String,
+    pub timestamp_column: String,
+    pub cutoff: String,
+    pub rows_expired: usize,
+    pub rows_deleted: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "crate::json_u64::option")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string | null"))]
+    pub version: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct OverviewRequestV1 {
+    /// Time budget of each connection; defaults to the query timeout, or 5 seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectionHealthV1 {
+    Ok,
+    /// Some tables could not be read; totals leave them out.
+    Degraded,
+    Timeout,
+    Error,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionOverviewV1 {
+    pub connection_id: String,
+    pub uri: String,
+    pub read_only: bool,
+    pub health: ConnectionHealthV1,
+    /// Table handles the app holds open on this connection.
+    pub open_tables: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "crate::json_u64::option")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string | null"))]
+    pub total_rows: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_size_bytes: Option<u64>,
+    /// Tables whose stats could not be read.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failed_tables: Vec<String>,
+    pub elapsed_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct OverviewResponseV1 {
+    /// Sorted by URI.
+    pub connections: Vec<ConnectionOverviewV1>,
+    pub elapsed_ms: u64,
+}
+
+/// Snapshot of the backend state for troubleshooting.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct HealthResponseV1 {
+    pub version: String,
+    pub uptime_ms: u64,
+    pub active_connections: usize,
+    pub open_tables: usize,
+    pub running_jobs: Vec<JobStatusV1>,
+    /// Running and recently finished jobs kept for status polling.
+    pub tracked_jobs: usize,
+    pub schedules: usize,
+    pub prefetched_pages: usize,
+    /// Local tables whose first page is kept warm for quick switching.
+    #[serde(default)]
+    pub warm_tables: usize,
+    /// Resident memory of the process; only reported on Linux.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resident_memory_bytes: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CommandMetricsV1 {
+    pub command: String,
+    pub calls: u64,
+    pub errors: u64,
+    pub total_ms: u64,
+    pub max_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error_code: Option<ErrorCode>,
+}
+
+/// An open connection with credentials left out: the URI loses its user info and
+/// query, and only the names of storage options are kept.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnosticsConnectionV1 {
+    pub connection_id: String,
+    pub uri: String,
+    pub read_only: bool,
+    pub open_tables: usize,
+    pub storage_option_keys: Vec<String>,
+}
+
+/// Everything `export_diagnostics_v1` writes, as one JSON document.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnosticsReportV1 {
+    pub created_at_ms: u64,
+    pub viewer_version: String,
+    pub tauri_version: String,
+    pub os: String,
+    pub arch: String,
+    pub uptime_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resident_memory_bytes: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_settings: Option<LogSettingsV1>,
+    pub commands: Vec<CommandMetricsV1>,
+    pub command_memory: Vec<CommandMemoryV1>,
+    pub connections: Vec<DiagnosticsConnectionV1>,
+    pub running_jobs: Vec<JobStatusV1>,
+    /// Last lines of the app log, oldest first.
+    pub logs: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ExportDiagnosticsRequestV1 {
+    pub path: String,
+    /// Log lines to include; 500 when omitted, at most 5,000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_lines: Option<usize>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub overwrite: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ExportDiagnosticsResponseV1 {
+    pub path: String,
+    pub bytes: u64,
+    pub log_lines: usize,
+    pub commands: usize,
+    pub connections: usize,
+}
+
+/// How a remote client reached the service layer. Share servers are the only
+/// remote front end so far.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum AccessTransportV1 {
+    Http,
+}
+
+/// One call made by a remote client. Calls from the app window are not logged.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct AccessLogEntryV1 {
+    pub at_ms: u64,
+    pub transport: AccessTransportV1,
+    /// Peer address or client name as the transport reports it.
+    pub client: String,
+    pub command: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table: Option<String>,
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<ErrorCode>,
+    pub elapsed_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct GetAccessLogRequestV1 {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transport: Option<AccessTransportV1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    /// Only entries at or after this time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct GetAccessLogResponseV1 {
+    /// Newest first.
+    pub entries: Vec<AccessLogEntryV1>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum LogLevelV1 {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+/// Runtime log configuration. `module_filter` is a comma-separated list of module
+/// path prefixes (for example `lancedb_viewer_lib::services,lance`); when set, `level`
+/// applies only to those modules.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct LogSettingsV1 {
+    pub level: LogLevelV1,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub module_filter: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SetLogLevelRequestV1 {
+    pub level: LogLevelV1,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub module_filter: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SetLogLevelResponseV1 {
+    pub settings: LogSettingsV1,
+}
+
+/// Tunables read from `~/.lancedb-viewer/config.toml`.
+/// Rows per page when a scan or filter request sets no limit.
+pub const DEFAULT_PAGE_SIZE: usize = 100;
+/// Pages kept by the scan prefetch cache.
+pub const DEFAULT_PREFETCH_CACHE_PAGES: usize = 32;
+/// Seconds a prefetched page stays valid.
+pub const DEFAULT_PREFETCH_TTL_SECONDS: u64 = 30;
+
+/// What the user of this workstation may change. Viewers only read; editors also
+/// write rows and table settings; admins may also drop, create and alter tables,
+/// build indexes and change app settings.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum RoleV1 {
+    #[default]
+    Admin,
+    Editor,
+    Viewer,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct AppConfigV1 {
+    /// Row limit used by scans and filter queries that omit `limit`.
+    pub default_page_size: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_timeout_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connect_timeout_ms: Option<u64>,
+    pub prefetch_cache_pages: usize,
+    pub prefetch_ttl_seconds: u64,
+    /// Opens connections read-only unless the profile says otherwise.
+    #[serde(default)]
+    pub read_only_default: bool,
+    /// Startup log level when `set_log_level_v1` has not saved one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_level: Option<LogLevelV1>,
+    /// Receives a JSON POST with the job id, status and output path whenever a
+    /// backup, restore, export, index build or other job finishes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_webhook_url: Option<String>,
+    #[serde(default)]
+    pub role: RoleV1,
+    /// Tags the version every mutation produces with its operation and time, so the
+    /// versions panel reads like a change history.
+    #[serde(default)]
+    pub auto_checkpoints: bool,
+    /// Language of the messages commands return. Every command also takes an
+    /// optional `locale` argument next to `request`, as does the request of
+    /// `optimize_table_v1`; both win over this one.
+    #[serde(default)]
+    pub locale: LocaleV1,
+}
+
+impl Default for AppConfigV1 {
+    fn default() -> Self {
+        Self {
+            default_page_size: DEFAULT_PAGE_SIZE,
+            query_timeout_ms: None,
+            connect_timeout_ms: None,
+            prefetch_cache_pages: DEFAULT_PREFETCH_CACHE_PAGES,
+            prefetch_ttl_seconds: DEFAULT_PREFETCH_TTL_SECONDS,
+            read_only_default: false,
+            log_level: None,
+            job_webhook_url: None,
+            role: RoleV1::Admin,
+            auto_checkpoints: false,
+            locale: LocaleV1::En,
+        }
+    }
+}
+
+impl AppConfigV1 {
+    pub fn query_timeout(&self) -> Option<Duration> {
+        self.query_timeout_ms.map(Duration::from_millis)
+    }
+
+    pub fn connect_timeout(&self) -> Option<Duration> {
+        self.connect_timeout_ms.map(Duration::from_millis)
+    }
+
+    pub fn prefetch_ttl(&self) -> Duration {
+        Duration::from_secs(self.prefetch_ttl_seconds)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigResponseV1 {
+    pub config: AppConfigV1,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SetConfigRequestV1 {
+    pub config: AppConfigV1,
+}
+
+/// Reciprocal rank fusion of the vector and full-text rankings.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct RrfRerankerV1 {
+    /// Rank constant; defaults to 60.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub k: Option<f32>,
+}
+
+/// A cross-encoder served over HTTP. The endpoint receives
+/// `{"query": ..., "documents": [...]}` and answers `{"scores": [...]}`, one score
+/// per document.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CrossEncoderRerankerV1 {
+    pub endpoint: String,
+    /// Text column sent as the documents.
+    pub column: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+}
+
+/// An arithmetic expression over numeric columns and the search scores
+/// (`_score`, `_distance`, `_relevance_score`), e.g. `_score * 0.8 + ln(1 + votes)`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ExpressionRerankerV1 {
+    pub expression: String,
+}
+
+/// Reorders the candidates of a full-text or hybrid search before they are paged.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RerankerV1 {
+    Rrf(RrfRerankerV1),
+    CrossEncoder(CrossEncoderRerankerV1),
+    Expression(ExpressionRerankerV1),
+}
+
+impl RerankerV1 {
+    /// Name reported in `_hybrid_source` and the logs.
+    pub fn name(&self) -> &'static str {
+        match self {
+            RerankerV1::Rrf(_) => "rrf",
+            RerankerV1::CrossEncoder(_) => "cross_encoder",
+            RerankerV1::Expression(_) => "expression",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CombinedSearchRequestV1 {
+    pub table_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vector: Option<Vec<f32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vector_column: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub columns: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection: Option<Vec<String>>,
+    /// Name of a saved projection preset of the table, used instead of `projection`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection_preset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nprobes: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refine_factor: Option<u32>,
+    /// Defaults to reciprocal rank fusion.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reranker: Option<RerankerV1>,
+    /// Searches this table version instead of the latest one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "crate::json_u64::option")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string | null"))]
+    pub as_of_version: Option<u64>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub include_stats: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub json_options: Option<JsonOptionsV1>,
+    /// Model that embedded the query vector; a mismatch with the column's model is
+    /// reported as a warning.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_embedding: Option<EmbeddingModelV1>,
+    /// L2-normalizes the query vector before searching. Without it, a query that is
+    /// not unit length against a normalized column is reported as a warning.
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub normalize_query: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct VectorSearchRequestV1 {
+    pub table_id: String,
+    pub vector: Vec<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_k: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection: Option<Vec<String>>,
+    /// Name of a saved projection preset of the table, used instead of `projection`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection_preset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nprobes: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refine_factor: Option<u32>,
+    /// Overrides the metric of the vector index, or L2 for a flat search.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance_type: Option<DistanceTypeV1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<usize>,
+    /// Searches this table version instead of the latest one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "crate::json_u64::option")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string | null"))]
+    pub as_of_version: Option<u64>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub include_stats: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub json_options: Option<JsonOptionsV1>,
+    /// Model that embedded the query vector; a mismatch with the column's model is
+    /// reported as a warning.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_embedding: Option<EmbeddingModelV1>,
+    /// L2-normalizes the query vector before searching. Without it, a query that is
+    /// not unit length against a normalized column is reported as a warning.
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub normalize_query: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct FtsSearchRequestV1 {
+    pub table_id: String,
+    pub query: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub columns: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection: Option<Vec<String>>,
+    /// Name of a saved projection preset of the table, used instead of `projection`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection_preset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// Reorders the matches by another score; results are ranked by BM25 otherwise.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reranker: Option<RerankerV1>,
+    /// Searches this table version instead of the latest one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "crate::json_u64::option")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string | null"))]
+    pub as_of_version: Option<u64>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub include_stats: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub json_options: Option<JsonOptionsV1>,
+    /// Returns `highlights` marking the query terms in the searched columns.
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub highlight: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct QueryFilterRequestV1 {
+    pub table_id: String,
+    pub filter: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection: Option<Vec<String>>,
+    /// Name of a saved projection preset of the table, used instead of `projection`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection_preset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<usize>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub include_stats: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub json_options: Option<JsonOptionsV1>,
+}
+
+/// A scan or filter query to estimate without running it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct EstimateQueryRequestV1 {
+    pub table_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<usize>,
+}
+
+/// Upper bounds for what a query would read, from the table statistics and the
+/// planned scan. Bytes are split across columns by their value width.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct EstimateQueryResponseV1 {
+    pub table_id: String,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub total_rows: u64,
+    pub total_fragments: u64,
+    pub total_bytes: u64,
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub estimated_rows_scanned: u64,
+    pub estimated_fragments: u64,
+    pub estimated_bytes_read: u64,
+    /// Columns the planned scan loads.
+    pub columns_read: Vec<String>,
+    pub index_used: bool,
+    /// Every row is read and no index narrows the scan.
+    pub full_scan: bool,
+    pub plan: String,
+}
+
+/// Most batch memory one command held at once. Only query paths that report their
+/// batches are counted, so this is a lower bound of what the process used.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CommandMemoryV1 {
+    pub command: String,
+    pub calls: u64,
+    pub last_peak_bytes: u64,
+    pub max_peak_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct MemoryStatsResponseV1 {
+    /// Largest peak first.
+    pub commands: Vec<CommandMemoryV1>,
+}
+
+/// A command module compiled in behind a Cargo feature. Its commands are invoked
+/// as `plugin:<name>|<command>`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ExtensionV1 {
+    pub name: String,
+    pub description: String,
+    pub commands: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ListExtensionsResponseV1 {
+    pub extensions: Vec<ExtensionV1>,
+}
+
+/// ANN parameters a vector or hybrid search ran with. When the request omitted
+/// `nprobes` or `refineFactor`, the values are derived from the vector index and the
+/// matching `*Defaulted` flag is set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct VectorSearchParamsV1 {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index_type: Option<IndexTypeV1>,
+    /// Estimated from the number of indexed rows; lance does not report the
+    /// partition count of an index.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_partitions: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nprobes: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refine_factor: Option<u32>,
+    #[serde(default)]
+    pub nprobes_defaulted: bool,
+    #[serde(default)]
+    pub refine_factor_defaulted: bool,
+}
+
+/// Why a search response carries an index warning.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum SearchWarningKindV1 {
+    /// No index covers the searched column, so every row was scanned.
+    MissingIndex,
+    /// The index leaves many rows unindexed; those are scanned on every search.
+    StaleIndex,
+    /// The query vector comes from another embedding model than the column.
+    EmbeddingModelMismatch,
+    /// The column holds L2-normalized vectors but the query vector is not unit
+    /// length, so L2 and dot distances do not rank by cosine similarity.
+    UnnormalizedQuery,
+}
+
+/// Kind of index a search relies on.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum SearchIndexKindV1 {
+    Vector,
+    Fts,
+}
+
+/// Index coverage or embedding model problem found while running a vector or
+/// full-text search.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SearchWarningV1 {
+    pub kind: SearchWarningKindV1,
+    pub index_kind: SearchIndexKindV1,
+    /// Searched column, when the request or the index names one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index_type: Option<IndexTypeV1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "crate::json_u64::option")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string | null"))]
+    pub indexed_rows: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "crate::json_u64::option")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string | null"))]
+    pub unindexed_rows: Option<u64>,
+    pub message: String,
+}
+
+/// Measures how many exact nearest neighbours the vector index of `column` finds.
+/// Vectors of sampled rows serve as queries; each is searched once without the
+/// index for the ground truth and once per `nprobes` setting.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct EvaluateRecallRequestV1 {
+    pub table_id: String,
+    pub column: String,
+    /// Number of rows, spread evenly over the table, used as queries; defaults to 20.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sample_queries: Option<usize>,
+    /// Neighbours compared per query; defaults to 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub k: Option<usize>,
+    /// Settings to compare. Defaults to powers of two up to the estimated partition
+    /// count.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nprobes: Option<Vec<usize>>,
+    /// Applied to every setting; defaults as in `vector_search_v1`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refine_factor: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct RecallAtNprobesV1 {
+    pub nprobes: usize,
+    /// Share of the exact top-k neighbours the index returned, from 0 to 1.
+    pub recall: f64,
+    pub mean_latency_ms: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct EvaluateRecallResponseV1 {
+    pub table_id: String,
+    pub column: String,
+    pub index_name: String,
+    pub index_type: IndexTypeV1,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance_type: Option<DistanceTypeV1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_partitions: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refine_factor: Option<u32>,
+    pub k: usize,
+    /// Queries evaluated; rows with a null vector are skipped.
+    pub sample_size: usize,
+    /// Mean latency of the exact searches that gave the ground truth.
+    pub flat_mean_latency_ms: f64,
+    pub results: Vec<RecallAtNprobesV1>,
+}
+
+/// Pricing and throughput of an embedding provider. The viewer ships no price
+/// list, so the caller supplies what its provider charges.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct EmbeddingProviderV1 {
+    pub name: String,
+    pub model: String,
+    /// Price of one million input tokens, in the provider's billing currency.
+    pub price_per_million_tokens: f64,
+    /// Rate limit used for the time estimate; without it no time is estimated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tokens_per_minute: Option<u64>,
+    /// Inputs longer than this are truncated by the provider and billed at the
+    /// limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens_per_input: Option<u64>,
+}
+
+/// Estimates what embedding every value of `text_column` would cost before a paid
+/// run is started. Tokens are approximated from the text, not counted by the
+/// provider's tokenizer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct EstimateEmbeddingJobRequestV1 {
+    pub table_id: String,
+    pub text_column: String,
+    pub provider: EmbeddingProviderV1,
+    /// Limits the estimate to the rows a filtered run would embed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct EstimateEmbeddingJobResponseV1 {
+    pub table_id: String,
+    pub text_column: String,
+    pub provider: String,
+    pub model: String,
+    /// Rows with text to embed.
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub row_count: u64,
+    /// Rows with a null or blank value, which are not sent to the provider.
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub skipped_rows: u64,
+    pub total_chars: u64,
+    pub estimated_tokens: u64,
+    /// Rows billed at `max_tokens_per_input` because their text is longer.
+    #[serde(with = "crate::json_u64")]
+    #[cfg_attr(feature = "ts", ts(type = "number | string"))]
+    pub truncated_rows: u64,
+    pub estimated_cost: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_seconds: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct QueryResponseV1 {
+    pub chunk: DataChunk,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_params: Option<VectorSearchParamsV1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<QueryExecutionStatsV1>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub warnings: Vec<SearchWarningV1>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub highlights: Vec<FtsHighlightV1>,
+}
+
+/// `details` of the error a full-text search returns when the table has no FTS
+/// index: the string columns `create_index_v1` could index.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct MissingFtsIndexV1 {
+    pub string_columns: Vec<String>,
+}
+
+/// `details` of the `invalid_argument` error a full-text search returns when some
+/// requested columns have no FTS index.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct FtsColumnsNotIndexedV1 {
+    /// Requested columns without an FTS index.
+    pub columns: Vec<String>,
+    pub indexed_columns: Vec<String>,
+}
+
+/// `details` of the `internal` error an import, export or compaction returns when
+/// the local volume it writes to is too full to finish.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct DiskSpaceShortageV1 {
+    /// The dataset directory or file being written.
+    pub path: String,
+    /// Estimated bytes the write needs, headroom included.
+    pub needed_bytes: u64,
+    pub available_bytes: u64,
+}
+
+/// Offsets in UTF-16 code units, as JavaScript indexes strings.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct TextRangeV1 {
+    pub start: u32,
+    pub end: u32,
+}
+
+/// Query terms found in one column of a full-text search hit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct FtsHighlightV1 {
+    /// Index of the row in the returned chunk.
+    pub row_index: usize,
+    pub column: String,
+    /// The value, or an excerpt around the first match for long values.
+    pub snippet: String,
+    /// Matched terms within `snippet`.
+    pub ranges: Vec<TextRangeV1>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "snake_case")]
+pub enum PivotAggregationV1 {
+    #[default]
+    Count,
+    Sum,
+    Avg,
+    Min,
+    Max,
+}
+
+/// Cross-tab of `value_column` by `row_keys` and the distinct values of `column_key`.
+/// `count` counts rows, or non-null values when `value_column` is set; the other
+/// aggregations need a numeric `value_column`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct PivotRequestV1 {
+    pub table_id: String,
+    pub row_keys: Vec<String>,
+    pub column_key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_column: Option<String>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(as = "Option<_>"))]
+    pub aggregation: PivotAggregationV1,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// Keeps the most frequent column values; defaults to 50 and is capped at 500.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_columns: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct PivotRowV1 {
+    /// Values of the row keys, in request order.
+    #[cfg_attr(feature = "ts", ts(type = "unknown[]"))]
+    pub keys: Vec<serde_json::Value>,
+    /// One cell per entry in `PivotResponseV1::columns`; `None` when no rows matched.
+    pub values: Vec<Option<f64>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS), ts(export, optional_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct PivotResponseV1 {
+    pub row_keys: Vec<String>,
+    pub column_key: String,
+    pub aggregation: PivotAggregationV1,
+    /// Column key values, most frequent first. Nulls are labelled `null`.
+    pub columns: Vec<String>,
+    pub rows: Vec<PivotRowV1>,
+    /// Distinct column values dropped by `max_columns`.
+    pub omitted_columns: usize,
+    pub scanned_rows: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn reads_the_old_spellings_of_renamed_variants() {
+        let index_type: IndexTypeV1 = serde_json::from_value(json!("b_tree")).expect("alias");
+        assert!(matches!(index_type, IndexTypeV1::BTree));
+        assert_eq!(
+            serde_json::to_value(&index_type).expect("serialize"),
+            json!("btree")
+        );
+
+        let data_type: FieldDataType = serde_json::from_value(json!("u_int32")).expect("alias");
+        assert!(matches!(data_type, FieldDataType::UInt32));
+        assert_eq!(
+            serde_json::to_value(&data_type).expect("serialize"),
+            json!("uint32")
+        );
+    }
+
+    #[test]
+    fn reads_index_parameter_aliases() {
+        let request: CreateIndexRequestV1 = serde_json::from_value(json!({
+            "tableId": "t1",
+            "columns": ["vector"],
+            "indexType": "ivf_hnsw_pq",
+            "metric": "cosine",
+            "m": 24,
+        }))
+        .expect("aliases");
+        assert!(matches!(
+            request.distance_type,
+            Some(DistanceTypeV1::Cosine)
+        ));
+        assert_eq!(request.num_edges, Some(24));
+
+        let value = serde_json::to_value(&request).expect("serialize");
+        assert_eq!(value["distanceType"], json!("cosine"));
+        assert_eq!(value["numEdges"], json!(24));
+        assert!(value.get("metric").is_none() && value.get("m").is_none());
+        let round_trip: CreateIndexRequestV1 = serde_json::from_value(value).expect("round trip");
+        assert_eq!(round_trip.num_edges, Some(24));
+    }
+
+    #[test]
+    fn keeps_full_range_repro_seeds_exact() {
+        let manifest = ReproSampleManifestV1 {
+            format_version: 1,
+            viewer_version: "0.0.2".to_string(),
+            created_at_ms: 0,
+            source_version: 3,
+            source_rows: 10,
+            rows: 5,
+            seed: u64::MAX,
+            filter: None,
+            indexes: Vec::new(),
+        };
+        let value = serde_json::to_value(&manifest).expect("serialize");
+        assert_eq!(value["seed"], json!("18446744073709551615"));
+
+        let request: ExportReproSampleRequestV1 = serde_json::from_value(json!({
+            "tableId": "t1",
+            "path": "sample.zip",
+            "rows": 5,
+            "seed": value["seed"],
+        }))
+        .expect("string seed");
+        assert_eq!(request.seed, Some(u64::MAX));
+    }
+}
@@ -1,20 +1,65 @@
+use tauri::ipc::{Channel, InvokeResponseBody};
+
 use crate::ipc::v1::{
-    AddColumnsRequestV1, AddColumnsResponseV1, AlterColumnsRequestV1, AlterColumnsResponseV1,
-    CheckoutTableLatestRequestV1, CheckoutTableLatestResponseV1, CheckoutTableVersionRequestV1,
-    CheckoutTableVersionResponseV1, CloneTableRequestV1, CloneTableResponseV1,
-    CombinedSearchRequestV1, ConnectRequestV1, ConnectResponseV1, CreateIndexRequestV1,
-    CreateIndexResponseV1, CreateTableRequestV1, CreateTableResponseV1, DeleteRowsRequestV1,
-    DeleteRowsResponseV1, DisconnectRequestV1, DisconnectResponseV1, DropColumnsRequestV1,
-    DropColumnsResponseV1, DropIndexRequestV1, DropIndexResponseV1, DropTableRequestV1,
-    DropTableResponseV1, ExportDataRequestV1, ExportDataResponseV1, FtsSearchRequestV1,
-    GetSchemaRequestV1, GetTableVersionRequestV1, GetTableVersionResponseV1, ImportDataRequestV1,
-    ImportDataResponseV1, ListIndexesRequestV1, ListIndexesResponseV1, ListTablesRequestV1,
-    ListTablesResponseV1, ListVersionsRequestV1, ListVersionsResponseV1, OpenTableRequestV1,
-    OptimizeTableRequestV1, OptimizeTableResponseV1, QueryFilterRequestV1, QueryResponseV1,
-    RenameTableRequestV1, RenameTableResponseV1, ResultEnvelope, ScanRequestV1, ScanResponseV1,
-    SchemaDefinition, TableHandle, UpdateRowsRequestV1, UpdateRowsResponseV1,
-    VectorSearchRequestV1, WriteRowsRequestV1, WriteRowsResponseV1,
+    AckScanStreamRequestV1, ActiveQueryV1, AddColumnsRequestV1, AddColumnsResponseV1,
+    AlterColumnsRequestV1, AlterColumnsResponseV1, BackupTableRequestV1, BackupTableResponseV1,
+    CancelJobRequestV1, CancelScanStreamRequestV1, CheckExportTargetRequestV1,
+    CheckExportTargetResponseV1, CheckoutTableLatestRequestV1, CheckoutTableLatestResponseV1,
+    CheckoutTableVersionRequestV1, CheckoutTableVersionResponseV1, CloneTableRequestV1,
+    CloneTableResponseV1, CombinedSearchRequestV1, CompareQueryVersionsRequestV1,
+    CompareQueryVersionsResponseV1, CompletionMetadataRequestV1, CompletionMetadataResponseV1,
+    ConfigResponseV1, ConnectRequestV1, ConnectResponseV1, CreateIndexRequestV1,
+    CreateIndexResponseV1, CreateTableRequestV1, CreateTableResponseV1, CreateTagRequestV1,
+    DeleteExportTemplateRequestV1, DeleteExportTemplateResponseV1, DeleteProjectionPresetRequestV1,
+    DeleteProjectionPresetResponseV1, DeleteRowsRequestV1, DeleteRowsResponseV1,
+    DeleteScheduleRequestV1, DeleteScheduleResponseV1, DeleteTagRequestV1, DeleteTagResponseV1,
+    DeleteWatchFolderRequestV1, DeleteWatchFolderResponseV1, DisconnectRequestV1,
+    DisconnectResponseV1, DropColumnsRequestV1, DropColumnsResponseV1, DropIndexRequestV1,
+    DropIndexResponseV1, DropTableRequestV1, DropTableResponseV1, EstimateEmbeddingJobRequestV1,
+    EstimateEmbeddingJobResponseV1, EstimateQueryRequestV1, EstimateQueryResponseV1,
+    EvaluateRecallRequestV1, EvaluateRecallResponseV1, ExportBundleRequestV1,
+    ExportBundleResponseV1, ExportDataRequestV1, ExportDataResponseV1, ExportDiagnosticsRequestV1,
+    ExportDiagnosticsResponseV1, ExportReproSampleRequestV1, ExportReproSampleResponseV1,
+    ExportRowsRequestV1, FtsSearchRequestV1, GetAccessLogRequestV1, GetAccessLogResponseV1,
+    GetJobStatusRequestV1, GetRetentionPolicyRequestV1, GetRowCountRequestV1, GetSchemaRequestV1,
+    GetSearchDefaultsRequestV1, GetTableDescriptionRequestV1, GetTableVersionRequestV1,
+    GetTableVersionResponseV1, HealthResponseV1, ImportBundleRequestV1, ImportBundleResponseV1,
+    ImportDataRequestV1, ImportDataResponseV1, IndexCapabilitiesResponseV1, IndexStatsRequestV1,
+    IndexStatsResponseV1, JobStatusV1, KillQueryRequestV1, ListActiveQueriesResponseV1,
+    ListEncryptedColumnsRequestV1, ListEncryptedColumnsResponseV1, ListExportTemplatesResponseV1,
+    ListExtensionsResponseV1, ListIndexesRequestV1, ListIndexesResponseV1, ListJobsRequestV1,
+    ListJobsResponseV1, ListMaskingRulesRequestV1, ListMaskingRulesResponseV1,
+    ListProjectionPresetsRequestV1, ListProjectionPresetsResponseV1, ListSchedulesRequestV1,
+    ListSchedulesResponseV1, ListSharesResponseV1, ListTablesRequestV1, ListTablesResponseV1,
+    ListTagsRequestV1, ListTagsResponseV1, ListVersionsRequestV1, ListVersionsResponseV1,
+    ListWatchFoldersRequestV1, ListWatchFoldersResponseV1, LocaleV1, MaterializeQueryRequestV1,
+    MaterializeQueryResponseV1, MemoryStatsResponseV1, MigrateTableFormatRequestV1,
+    MigrateTableFormatResponseV1, OpenDatasetRequestV1, OpenDatasetResponseV1, OpenTableRequestV1,
+    OpenTablesRequestV1, OpenTablesResponseV1, OptimizeTableRequestV1, OptimizeTableResponseV1,
+    OverviewRequestV1, OverviewResponseV1, PivotRequestV1, PivotResponseV1, PreviewUpdateRequestV1,
+    PreviewUpdateResponseV1, QueryFilterRequestV1, QueryResponseV1, RecentFiltersRequestV1,
+    RecentFiltersResponseV1, RenameTableRequestV1, RenameTableResponseV1, ReorderColumnsRequestV1,
+    ReorderColumnsResponseV1, RestoreBackupRequestV1, RestoreBackupResponseV1,
+    RestoreTableVersionRequestV1, RestoreTableVersionResponseV1, ResultEnvelope,
+    RetentionPolicyResponseV1, RotateSecretRequestV1, RotateSecretResponseV1, RowCountResponseV1,
+    RunExportTemplateRequestV1, RunRetentionRequestV1, RunRetentionResponseV1,
+    SaveExportTemplateRequestV1, SaveExportTemplateResponseV1, SaveProjectionPresetRequestV1,
+    SaveProjectionPresetResponseV1, ScanRequestV1, ScanResponseV1, ScanStreamControlResponseV1,
+    ScanStreamRequestV1, ScanStreamSummaryV1, SchemaDefinition, SearchDefaultsResponseV1,
+    SetConfigRequestV1, SetEncryptedColumnsRequestV1, SetEncryptedColumnsResponseV1,
+    SetEncryptionKeyRequestV1, SetEncryptionKeyResponseV1, SetLogLevelRequestV1,
+    SetLogLevelResponseV1, SetMaskingRulesRequestV1, SetMaskingRulesResponseV1,
+    SetRetentionPolicyRequestV1, SetScheduleRequestV1, SetScheduleResponseV1,
+    SetSearchDefaultsRequestV1, SetTableDescriptionRequestV1, SetWatchFolderRequestV1,
+    SetWatchFolderResponseV1, ShareSessionV1, StartShareRequestV1, StatsHistoryRequestV1,
+    StatsHistoryResponseV1, StopShareRequestV1, StopShareResponseV1, TableDescriptionResponseV1,
+    TableFormatInfoRequestV1, TableFormatInfoV1, TableHandle, TableLocationRequestV1,
+    TableLocationV1, TagResponseV1, UpdateRowsRequestV1, UpdateRowsResponseV1, UpdateTagRequestV1,
+    ValidateFilterRequestV1, ValidateFilterResponseV1, VectorSearchRequestV1, WriteRowsRequestV1,
+    WriteRowsResponseV1,
 };
+use crate::services::messages;
+use crate::services::scan_stream::ScanStreamMessage;
 use crate::services::v1 as services_v1;
 use crate::state::AppState;
 
@@ -22,238 +67,1248 @@ use crate::state::AppState;
 pub async fn connect_v1(
     state: tauri::State<'_, AppState>,
     request: ConnectRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<ConnectResponseV1>, String> {
-    Ok(services_v1::connect_v1(state.inner(), request).await)
+    Ok(messages::request_locale(locale, services_v1::connect_v1(state.inner(), request)).await)
 }
 
 #[tauri::command]
 pub async fn disconnect_v1(
     state: tauri::State<'_, AppState>,
     request: DisconnectRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<DisconnectResponseV1>, String> {
-    Ok(services_v1::disconnect_v1(state.inner(), request).await)
+    Ok(messages::request_locale(locale, services_v1::disconnect_v1(state.inner(), request)).await)
+}
+
+#[tauri::command]
+pub async fn rotate_secret_v1(
+    state: tauri::State<'_, AppState>,
+    request: RotateSecretRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<RotateSecretResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::rotate_secret_v1(state.inner(), request),
+    )
+    .await)
 }
 
 #[tauri::command]
 pub async fn list_tables_v1(
     state: tauri::State<'_, AppState>,
     request: ListTablesRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<ListTablesResponseV1>, String> {
-    Ok(services_v1::list_tables_v1(state.inner(), request).await)
+    Ok(messages::request_locale(locale, services_v1::list_tables_v1(state.inner(), request)).await)
 }
 
 #[tauri::command]
 pub async fn drop_table_v1(
     state: tauri::State<'_, AppState>,
     request: DropTableRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<DropTableResponseV1>, String> {
-    Ok(services_v1::drop_table_v1(state.inner(), request).await)
+    Ok(messages::request_locale(locale, services_v1::drop_table_v1(state.inner(), request)).await)
 }
 
 #[tauri::command]
 pub async fn rename_table_v1(
     state: tauri::State<'_, AppState>,
     request: RenameTableRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<RenameTableResponseV1>, String> {
-    Ok(services_v1::rename_table_v1(state.inner(), request).await)
+    Ok(
+        messages::request_locale(locale, services_v1::rename_table_v1(state.inner(), request))
+            .await,
+    )
 }
 
 #[tauri::command]
 pub async fn list_indexes_v1(
     state: tauri::State<'_, AppState>,
     request: ListIndexesRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<ListIndexesResponseV1>, String> {
-    Ok(services_v1::list_indexes_v1(state.inner(), request).await)
+    Ok(
+        messages::request_locale(locale, services_v1::list_indexes_v1(state.inner(), request))
+            .await,
+    )
+}
+
+#[tauri::command]
+pub async fn index_stats_v1(
+    state: tauri::State<'_, AppState>,
+    request: IndexStatsRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<IndexStatsResponseV1>, String> {
+    Ok(messages::request_locale(locale, services_v1::index_stats_v1(state.inner(), request)).await)
 }
 
 #[tauri::command]
 pub async fn create_index_v1(
     state: tauri::State<'_, AppState>,
     request: CreateIndexRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<CreateIndexResponseV1>, String> {
-    Ok(services_v1::create_index_v1(state.inner(), request).await)
+    Ok(
+        messages::request_locale(locale, services_v1::create_index_v1(state.inner(), request))
+            .await,
+    )
 }
 
 #[tauri::command]
 pub async fn drop_index_v1(
     state: tauri::State<'_, AppState>,
     request: DropIndexRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<DropIndexResponseV1>, String> {
-    Ok(services_v1::drop_index_v1(state.inner(), request).await)
+    Ok(messages::request_locale(locale, services_v1::drop_index_v1(state.inner(), request)).await)
 }
 
 #[tauri::command]
 pub async fn create_table_v1(
     state: tauri::State<'_, AppState>,
     request: CreateTableRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<CreateTableResponseV1>, String> {
-    Ok(services_v1::create_table_v1(state.inner(), request).await)
+    Ok(
+        messages::request_locale(locale, services_v1::create_table_v1(state.inner(), request))
+            .await,
+    )
 }
 
 #[tauri::command]
 pub async fn open_table_v1(
     state: tauri::State<'_, AppState>,
     request: OpenTableRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<TableHandle>, String> {
-    Ok(services_v1::open_table_v1(state.inner(), request).await)
+    Ok(messages::request_locale(locale, services_v1::open_table_v1(state.inner(), request)).await)
+}
+
+#[tauri::command]
+pub async fn open_tables_v1(
+    state: tauri::State<'_, AppState>,
+    request: OpenTablesRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<OpenTablesResponseV1>, String> {
+    Ok(messages::request_locale(locale, services_v1::open_tables_v1(state.inner(), request)).await)
 }
 
 #[tauri::command]
 pub async fn get_schema_v1(
     state: tauri::State<'_, AppState>,
     request: GetSchemaRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<SchemaDefinition>, String> {
-    Ok(services_v1::get_schema_v1(state.inner(), request).await)
+    Ok(messages::request_locale(locale, services_v1::get_schema_v1(state.inner(), request)).await)
 }
 
 #[tauri::command]
 pub async fn list_versions_v1(
     state: tauri::State<'_, AppState>,
     request: ListVersionsRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<ListVersionsResponseV1>, String> {
-    Ok(services_v1::list_versions_v1(state.inner(), request).await)
+    Ok(messages::request_locale(
+        locale,
+        services_v1::list_versions_v1(state.inner(), request),
+    )
+    .await)
 }
 
 #[tauri::command]
 pub async fn get_table_version_v1(
     state: tauri::State<'_, AppState>,
     request: GetTableVersionRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<GetTableVersionResponseV1>, String> {
-    Ok(services_v1::get_table_version_v1(state.inner(), request).await)
+    Ok(messages::request_locale(
+        locale,
+        services_v1::get_table_version_v1(state.inner(), request),
+    )
+    .await)
 }
 
 #[tauri::command]
 pub async fn checkout_table_version_v1(
     state: tauri::State<'_, AppState>,
     request: CheckoutTableVersionRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<CheckoutTableVersionResponseV1>, String> {
-    Ok(services_v1::checkout_table_version_v1(state.inner(), request).await)
+    Ok(messages::request_locale(
+        locale,
+        services_v1::checkout_table_version_v1(state.inner(), request),
+    )
+    .await)
 }
 
 #[tauri::command]
 pub async fn checkout_table_latest_v1(
     state: tauri::State<'_, AppState>,
     request: CheckoutTableLatestRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<CheckoutTableLatestResponseV1>, String> {
-    Ok(services_v1::checkout_table_latest_v1(state.inner(), request).await)
+    Ok(messages::request_locale(
+        locale,
+        services_v1::checkout_table_latest_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn restore_table_version_v1(
+    state: tauri::State<'_, AppState>,
+    request: RestoreTableVersionRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<RestoreTableVersionResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::restore_table_version_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn list_tags_v1(
+    state: tauri::State<'_, AppState>,
+    request: ListTagsRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ListTagsResponseV1>, String> {
+    Ok(messages::request_locale(locale, services_v1::list_tags_v1(state.inner(), request)).await)
+}
+
+#[tauri::command]
+pub async fn create_tag_v1(
+    state: tauri::State<'_, AppState>,
+    request: CreateTagRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<TagResponseV1>, String> {
+    Ok(messages::request_locale(locale, services_v1::create_tag_v1(state.inner(), request)).await)
+}
+
+#[tauri::command]
+pub async fn update_tag_v1(
+    state: tauri::State<'_, AppState>,
+    request: UpdateTagRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<TagResponseV1>, String> {
+    Ok(messages::request_locale(locale, services_v1::update_tag_v1(state.inner(), request)).await)
+}
+
+#[tauri::command]
+pub async fn delete_tag_v1(
+    state: tauri::State<'_, AppState>,
+    request: DeleteTagRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<DeleteTagResponseV1>, String> {
+    Ok(messages::request_locale(locale, services_v1::delete_tag_v1(state.inner(), request)).await)
 }
 
 #[tauri::command]
 pub async fn clone_table_v1(
     state: tauri::State<'_, AppState>,
     request: CloneTableRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<CloneTableResponseV1>, String> {
-    Ok(services_v1::clone_table_v1(state.inner(), request).await)
+    Ok(messages::request_locale(locale, services_v1::clone_table_v1(state.inner(), request)).await)
 }
 
 #[tauri::command]
 pub async fn add_columns_v1(
     state: tauri::State<'_, AppState>,
     request: AddColumnsRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<AddColumnsResponseV1>, String> {
-    Ok(services_v1::add_columns_v1(state.inner(), request).await)
+    Ok(messages::request_locale(locale, services_v1::add_columns_v1(state.inner(), request)).await)
 }
 
 #[tauri::command]
 pub async fn alter_columns_v1(
     state: tauri::State<'_, AppState>,
     request: AlterColumnsRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<AlterColumnsResponseV1>, String> {
-    Ok(services_v1::alter_columns_v1(state.inner(), request).await)
+    Ok(messages::request_locale(
+        locale,
+        services_v1::alter_columns_v1(state.inner(), request),
+    )
+    .await)
 }
 
 #[tauri::command]
 pub async fn drop_columns_v1(
     state: tauri::State<'_, AppState>,
     request: DropColumnsRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<DropColumnsResponseV1>, String> {
-    Ok(services_v1::drop_columns_v1(state.inner(), request).await)
+    Ok(
+        messages::request_locale(locale, services_v1::drop_columns_v1(state.inner(), request))
+            .await,
+    )
 }
 
 #[tauri::command]
 pub async fn write_rows_v1(
     state: tauri::State<'_, AppState>,
     request: WriteRowsRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<WriteRowsResponseV1>, String> {
-    Ok(services_v1::write_rows_v1(state.inner(), request).await)
+    Ok(messages::request_locale(locale, services_v1::write_rows_v1(state.inner(), request)).await)
 }
 
 #[tauri::command]
 pub async fn update_rows_v1(
     state: tauri::State<'_, AppState>,
     request: UpdateRowsRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<UpdateRowsResponseV1>, String> {
-    Ok(services_v1::update_rows_v1(state.inner(), request).await)
+    Ok(messages::request_locale(locale, services_v1::update_rows_v1(state.inner(), request)).await)
 }
 
 #[tauri::command]
 pub async fn delete_rows_v1(
     state: tauri::State<'_, AppState>,
     request: DeleteRowsRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<DeleteRowsResponseV1>, String> {
-    Ok(services_v1::delete_rows_v1(state.inner(), request).await)
+    Ok(messages::request_locale(locale, services_v1::delete_rows_v1(state.inner(), request)).await)
 }
 
 #[tauri::command]
 pub async fn import_data_v1(
     state: tauri::State<'_, AppState>,
     request: ImportDataRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<ImportDataResponseV1>, String> {
-    Ok(services_v1::import_data_v1(state.inner(), request).await)
+    Ok(messages::request_locale(locale, services_v1::import_data_v1(state.inner(), request)).await)
 }
 
 #[tauri::command]
 pub async fn export_data_v1(
     state: tauri::State<'_, AppState>,
     request: ExportDataRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<ExportDataResponseV1>, String> {
-    Ok(services_v1::export_data_v1(state.inner(), request).await)
+    Ok(messages::request_locale(locale, services_v1::export_data_v1(state.inner(), request)).await)
+}
+
+#[tauri::command]
+pub async fn check_export_target_v1(
+    state: tauri::State<'_, AppState>,
+    request: CheckExportTargetRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<CheckExportTargetResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::check_export_target_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn export_bundle_v1(
+    state: tauri::State<'_, AppState>,
+    request: ExportBundleRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ExportBundleResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::export_bundle_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn export_repro_sample_v1(
+    state: tauri::State<'_, AppState>,
+    request: ExportReproSampleRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ExportReproSampleResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::export_repro_sample_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn import_bundle_v1(
+    state: tauri::State<'_, AppState>,
+    request: ImportBundleRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ImportBundleResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::import_bundle_v1(state.inner(), request),
+    )
+    .await)
 }
 
 #[tauri::command]
 pub async fn optimize_table_v1(
     state: tauri::State<'_, AppState>,
     request: OptimizeTableRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<OptimizeTableResponseV1>, String> {
-    Ok(services_v1::optimize_table_v1(state.inner(), request).await)
+    Ok(messages::request_locale(
+        locale,
+        services_v1::optimize_table_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn backup_table_v1(
+    state: tauri::State<'_, AppState>,
+    request: BackupTableRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<BackupTableResponseV1>, String> {
+    Ok(
+        messages::request_locale(locale, services_v1::backup_table_v1(state.inner(), request))
+            .await,
+    )
+}
+
+#[tauri::command]
+pub async fn restore_backup_v1(
+    state: tauri::State<'_, AppState>,
+    request: RestoreBackupRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<RestoreBackupResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::restore_backup_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn get_job_status_v1(
+    state: tauri::State<'_, AppState>,
+    request: GetJobStatusRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<JobStatusV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::get_job_status_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn list_jobs_v1(
+    state: tauri::State<'_, AppState>,
+    request: ListJobsRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ListJobsResponseV1>, String> {
+    Ok(messages::request_locale(locale, services_v1::list_jobs_v1(state.inner(), request)).await)
+}
+
+#[tauri::command]
+pub async fn list_schedules_v1(
+    state: tauri::State<'_, AppState>,
+    request: ListSchedulesRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ListSchedulesResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::list_schedules_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn set_schedule_v1(
+    state: tauri::State<'_, AppState>,
+    request: SetScheduleRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<SetScheduleResponseV1>, String> {
+    Ok(
+        messages::request_locale(locale, services_v1::set_schedule_v1(state.inner(), request))
+            .await,
+    )
+}
+
+#[tauri::command]
+pub async fn delete_schedule_v1(
+    state: tauri::State<'_, AppState>,
+    request: DeleteScheduleRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<DeleteScheduleResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::delete_schedule_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn list_watch_folders_v1(
+    state: tauri::State<'_, AppState>,
+    request: ListWatchFoldersRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ListWatchFoldersResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::list_watch_folders_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn set_watch_folder_v1(
+    state: tauri::State<'_, AppState>,
+    request: SetWatchFolderRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<SetWatchFolderResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::set_watch_folder_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn delete_watch_folder_v1(
+    state: tauri::State<'_, AppState>,
+    request: DeleteWatchFolderRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<DeleteWatchFolderResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::delete_watch_folder_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn list_masking_rules_v1(
+    state: tauri::State<'_, AppState>,
+    request: ListMaskingRulesRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ListMaskingRulesResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::list_masking_rules_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn set_masking_rules_v1(
+    state: tauri::State<'_, AppState>,
+    request: SetMaskingRulesRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<SetMaskingRulesResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::set_masking_rules_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn list_encrypted_columns_v1(
+    state: tauri::State<'_, AppState>,
+    request: ListEncryptedColumnsRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ListEncryptedColumnsResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::list_encrypted_columns_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn set_encrypted_columns_v1(
+    state: tauri::State<'_, AppState>,
+    request: SetEncryptedColumnsRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<SetEncryptedColumnsResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::set_encrypted_columns_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn set_encryption_key_v1(
+    state: tauri::State<'_, AppState>,
+    request: SetEncryptionKeyRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<SetEncryptionKeyResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::set_encryption_key_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn get_retention_policy_v1(
+    state: tauri::State<'_, AppState>,
+    request: GetRetentionPolicyRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<RetentionPolicyResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::get_retention_policy_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn set_retention_policy_v1(
+    state: tauri::State<'_, AppState>,
+    request: SetRetentionPolicyRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<RetentionPolicyResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::set_retention_policy_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn run_retention_v1(
+    state: tauri::State<'_, AppState>,
+    request: RunRetentionRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<RunRetentionResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::run_retention_v1(state.inner(), request),
+    )
+    .await)
 }
 
 #[tauri::command]
 pub async fn scan_v1(
     state: tauri::State<'_, AppState>,
     request: ScanRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<ScanResponseV1>, String> {
-    Ok(services_v1::scan_v1(state.inner(), request).await)
+    Ok(messages::request_locale(locale, services_v1::scan_v1(state.inner(), request)).await)
+}
+
+/// Sends `ScanStreamEventV1` messages as JSON and Arrow IPC chunks as raw bytes, which
+/// arrive in the webview as `ArrayBuffer`s.
+#[tauri::command]
+pub async fn scan_stream_v1(
+    state: tauri::State<'_, AppState>,
+    request: ScanStreamRequestV1,
+    on_event: Channel<InvokeResponseBody>,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ScanStreamSummaryV1>, String> {
+    let send = |message: ScanStreamMessage| {
+        let body = match message {
+            ScanStreamMessage::Event(event) => InvokeResponseBody::Json(
+                serde_json::to_string(&event).map_err(|error| error.to_string())?,
+            ),
+            ScanStreamMessage::Chunk(bytes) => InvokeResponseBody::Raw(bytes),
+        };
+        on_event.send(body).map_err(|error| error.to_string())
+    };
+    Ok(messages::request_locale(
+        locale,
+        services_v1::scan_stream_v1(state.inner(), request, send),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn ack_scan_stream_v1(
+    state: tauri::State<'_, AppState>,
+    request: AckScanStreamRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ScanStreamControlResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::ack_scan_stream_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn cancel_scan_stream_v1(
+    state: tauri::State<'_, AppState>,
+    request: CancelScanStreamRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ScanStreamControlResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::cancel_scan_stream_v1(state.inner(), request),
+    )
+    .await)
 }
 
 #[tauri::command]
 pub async fn query_filter_v1(
     state: tauri::State<'_, AppState>,
     request: QueryFilterRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<QueryResponseV1>, String> {
-    Ok(services_v1::query_filter_v1(state.inner(), request).await)
+    Ok(
+        messages::request_locale(locale, services_v1::query_filter_v1(state.inner(), request))
+            .await,
+    )
 }
 
 #[tauri::command]
 pub async fn combined_search_v1(
     state: tauri::State<'_, AppState>,
     request: CombinedSearchRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<QueryResponseV1>, String> {
-    Ok(services_v1::combined_search_v1(state.inner(), request).await)
+    Ok(messages::request_locale(
+        locale,
+        services_v1::combined_search_v1(state.inner(), request),
+    )
+    .await)
 }
 
 #[tauri::command]
 pub async fn vector_search_v1(
     state: tauri::State<'_, AppState>,
     request: VectorSearchRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<QueryResponseV1>, String> {
-    Ok(services_v1::vector_search_v1(state.inner(), request).await)
+    Ok(messages::request_locale(
+        locale,
+        services_v1::vector_search_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn evaluate_recall_v1(
+    state: tauri::State<'_, AppState>,
+    request: EvaluateRecallRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<EvaluateRecallResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::evaluate_recall_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn estimate_embedding_job_v1(
+    state: tauri::State<'_, AppState>,
+    request: EstimateEmbeddingJobRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<EstimateEmbeddingJobResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::estimate_embedding_job_v1(state.inner(), request),
+    )
+    .await)
 }
 
 #[tauri::command]
 pub async fn fts_search_v1(
     state: tauri::State<'_, AppState>,
     request: FtsSearchRequestV1,
+    locale: Option<LocaleV1>,
 ) -> Result<ResultEnvelope<QueryResponseV1>, String> {
-    Ok(services_v1::fts_search_v1(state.inner(), request).await)
+    Ok(messages::request_locale(locale, services_v1::fts_search_v1(state.inner(), request)).await)
+}
+
+#[tauri::command]
+pub async fn set_log_level_v1(
+    state: tauri::State<'_, AppState>,
+    request: SetLogLevelRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<SetLogLevelResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::set_log_level_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn health_v1(
+    state: tauri::State<'_, AppState>,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<HealthResponseV1>, String> {
+    Ok(messages::request_locale(locale, services_v1::health_v1(state.inner())).await)
+}
+
+#[tauri::command]
+pub async fn export_diagnostics_v1(
+    state: tauri::State<'_, AppState>,
+    request: ExportDiagnosticsRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ExportDiagnosticsResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::export_diagnostics_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn get_access_log_v1(
+    state: tauri::State<'_, AppState>,
+    request: GetAccessLogRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<GetAccessLogResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::get_access_log_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn get_config_v1(
+    state: tauri::State<'_, AppState>,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ConfigResponseV1>, String> {
+    Ok(messages::request_locale(locale, services_v1::get_config_v1(state.inner())).await)
+}
+
+#[tauri::command]
+pub async fn set_config_v1(
+    state: tauri::State<'_, AppState>,
+    request: SetConfigRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ConfigResponseV1>, String> {
+    Ok(messages::request_locale(locale, services_v1::set_config_v1(state.inner(), request)).await)
+}
+
+#[tauri::command]
+pub async fn open_dataset_v1(
+    state: tauri::State<'_, AppState>,
+    request: OpenDatasetRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<OpenDatasetResponseV1>, String> {
+    Ok(
+        messages::request_locale(locale, services_v1::open_dataset_v1(state.inner(), request))
+            .await,
+    )
+}
+
+#[tauri::command]
+pub async fn table_format_info_v1(
+    state: tauri::State<'_, AppState>,
+    request: TableFormatInfoRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<TableFormatInfoV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::table_format_info_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn table_location_v1(
+    state: tauri::State<'_, AppState>,
+    request: TableLocationRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<TableLocationV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::table_location_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn migrate_table_format_v1(
+    state: tauri::State<'_, AppState>,
+    request: MigrateTableFormatRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<MigrateTableFormatResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::migrate_table_format_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn get_table_description_v1(
+    state: tauri::State<'_, AppState>,
+    request: GetTableDescriptionRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<TableDescriptionResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::get_table_description_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn set_table_description_v1(
+    state: tauri::State<'_, AppState>,
+    request: SetTableDescriptionRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<TableDescriptionResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::set_table_description_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn pivot_v1(
+    state: tauri::State<'_, AppState>,
+    request: PivotRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<PivotResponseV1>, String> {
+    Ok(messages::request_locale(locale, services_v1::pivot_v1(state.inner(), request)).await)
+}
+
+#[tauri::command]
+pub async fn preview_update_v1(
+    state: tauri::State<'_, AppState>,
+    request: PreviewUpdateRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<PreviewUpdateResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::preview_update_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn list_active_queries_v1(
+    state: tauri::State<'_, AppState>,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ListActiveQueriesResponseV1>, String> {
+    Ok(messages::request_locale(locale, services_v1::list_active_queries_v1(state.inner())).await)
+}
+
+#[tauri::command]
+pub async fn kill_query_v1(
+    state: tauri::State<'_, AppState>,
+    request: KillQueryRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ActiveQueryV1>, String> {
+    Ok(messages::request_locale(locale, services_v1::kill_query_v1(state.inner(), request)).await)
+}
+
+#[tauri::command]
+pub async fn cancel_job_v1(
+    state: tauri::State<'_, AppState>,
+    request: CancelJobRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<JobStatusV1>, String> {
+    Ok(messages::request_locale(locale, services_v1::cancel_job_v1(state.inner(), request)).await)
+}
+
+#[tauri::command]
+pub async fn materialize_query_v1(
+    state: tauri::State<'_, AppState>,
+    request: MaterializeQueryRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<MaterializeQueryResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::materialize_query_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn compare_query_versions_v1(
+    state: tauri::State<'_, AppState>,
+    request: CompareQueryVersionsRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<CompareQueryVersionsResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::compare_query_versions_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn get_row_count_v1(
+    state: tauri::State<'_, AppState>,
+    request: GetRowCountRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<RowCountResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::get_row_count_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn get_search_defaults_v1(
+    state: tauri::State<'_, AppState>,
+    request: GetSearchDefaultsRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<SearchDefaultsResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::get_search_defaults_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn set_search_defaults_v1(
+    state: tauri::State<'_, AppState>,
+    request: SetSearchDefaultsRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<SearchDefaultsResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::set_search_defaults_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn list_projection_presets_v1(
+    state: tauri::State<'_, AppState>,
+    request: ListProjectionPresetsRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ListProjectionPresetsResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::list_projection_presets_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn save_projection_preset_v1(
+    state: tauri::State<'_, AppState>,
+    request: SaveProjectionPresetRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<SaveProjectionPresetResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::save_projection_preset_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn delete_projection_preset_v1(
+    state: tauri::State<'_, AppState>,
+    request: DeleteProjectionPresetRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<DeleteProjectionPresetResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::delete_projection_preset_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn get_index_capabilities_v1(
+    state: tauri::State<'_, AppState>,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<IndexCapabilitiesResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::get_index_capabilities_v1(state.inner()),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn stats_history_v1(
+    state: tauri::State<'_, AppState>,
+    request: StatsHistoryRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<StatsHistoryResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::stats_history_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn overview_v1(
+    state: tauri::State<'_, AppState>,
+    request: OverviewRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<OverviewResponseV1>, String> {
+    Ok(messages::request_locale(locale, services_v1::overview_v1(state.inner(), request)).await)
+}
+
+#[tauri::command]
+pub async fn list_export_templates_v1(
+    state: tauri::State<'_, AppState>,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ListExportTemplatesResponseV1>, String> {
+    Ok(
+        messages::request_locale(locale, services_v1::list_export_templates_v1(state.inner()))
+            .await,
+    )
+}
+
+#[tauri::command]
+pub async fn save_export_template_v1(
+    state: tauri::State<'_, AppState>,
+    request: SaveExportTemplateRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<SaveExportTemplateResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::save_export_template_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn delete_export_template_v1(
+    state: tauri::State<'_, AppState>,
+    request: DeleteExportTemplateRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<DeleteExportTemplateResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::delete_export_template_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn run_export_template_v1(
+    state: tauri::State<'_, AppState>,
+    request: RunExportTemplateRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ExportDataResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::run_export_template_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn start_share_v1(
+    state: tauri::State<'_, AppState>,
+    request: StartShareRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ShareSessionV1>, String> {
+    Ok(messages::request_locale(locale, services_v1::start_share_v1(state.inner(), request)).await)
+}
+
+#[tauri::command]
+pub async fn list_shares_v1(
+    state: tauri::State<'_, AppState>,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ListSharesResponseV1>, String> {
+    Ok(messages::request_locale(locale, services_v1::list_shares_v1(state.inner())).await)
+}
+
+#[tauri::command]
+pub async fn stop_share_v1(
+    state: tauri::State<'_, AppState>,
+    request: StopShareRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<StopShareResponseV1>, String> {
+    Ok(messages::request_locale(locale, services_v1::stop_share_v1(state.inner(), request)).await)
+}
+
+#[tauri::command]
+pub async fn estimate_query_v1(
+    state: tauri::State<'_, AppState>,
+    request: EstimateQueryRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<EstimateQueryResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::estimate_query_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn memory_stats_v1(
+    state: tauri::State<'_, AppState>,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<MemoryStatsResponseV1>, String> {
+    Ok(messages::request_locale(locale, services_v1::memory_stats_v1(state.inner())).await)
+}
+
+#[tauri::command]
+pub async fn list_extensions_v1(
+    state: tauri::State<'_, AppState>,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ListExtensionsResponseV1>, String> {
+    Ok(messages::request_locale(locale, services_v1::list_extensions_v1(state.inner())).await)
+}
+
+#[tauri::command]
+pub async fn reorder_columns_v1(
+    state: tauri::State<'_, AppState>,
+    request: ReorderColumnsRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ReorderColumnsResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::reorder_columns_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn recent_filters_v1(
+    state: tauri::State<'_, AppState>,
+    request: RecentFiltersRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<RecentFiltersResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::recent_filters_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn completion_metadata_v1(
+    state: tauri::State<'_, AppState>,
+    request: CompletionMetadataRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<CompletionMetadataResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::completion_metadata_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn validate_filter_v1(
+    state: tauri::State<'_, AppState>,
+    request: ValidateFilterRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ValidateFilterResponseV1>, String> {
+    Ok(messages::request_locale(
+        locale,
+        services_v1::validate_filter_v1(state.inner(), request),
+    )
+    .await)
+}
+
+#[tauri::command]
+pub async fn export_rows_v1(
+    state: tauri::State<'_, AppState>,
+    request: ExportRowsRequestV1,
+    locale: Option<LocaleV1>,
+) -> Result<ResultEnvelope<ExportDataResponseV1>, String> {
+    Ok(messages::request_locale(locale, services_v1::export_rows_v1(state.inner(), request)).await)
 }
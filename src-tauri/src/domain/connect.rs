@@ -1,15 +1,6 @@
-use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum BackendKind {
-    Local,
-    S3,
-    Gcs,
-    Azure,
-    Remote,
-    Unknown,
-}
+pub use crate::ipc::v1::BackendKind;
 
 pub fn infer_backend_kind(uri: &str) -> BackendKind {
     let lower = uri.trim().to_lowercase();
@@ -27,3 +18,40 @@ pub fn infer_backend_kind(uri: &str) -> BackendKind {
         BackendKind::Local
     }
 }
+
+/// Absolute filesystem path of a local (`file://` or plain path) URI. Returns `None` for object stores and remote databases.
+pub fn local_path(uri: &str) -> Option<PathBuf> {
+    let trimmed = uri.trim();
+    let path = match trimmed.strip_prefix("file://") {
+        Some(path) => PathBuf::from(path),
+        None if matches!(infer_backend_kind(trimmed), BackendKind::Local) => PathBuf::from(trimmed),
+        None => return None,
+    };
+    Some(std::path::absolute(&path).unwrap_or(path))
+}
+
+/// Splits a `.lance` dataset URI into the directory LanceDB connects to and the table
+/// name that resolves back to `<name>.lance`. Returns `None` when the last path segment
+/// is not a `.lance` directory.
+pub fn split_dataset_uri(uri: &str) -> Option<(String, String)> {
+    let trimmed = uri.trim().trim_end_matches(['/', '\\']);
+    let (parent, file) = if trimmed.contains("://") {
+        let (parent, file) = trimmed.rsplit_once('/')?;
+        if parent.ends_with(':') || parent.ends_with(":/") {
+            return None;
+        }
+        (parent.to_string(), file)
+    } else {
+        let path = Path::new(trimmed);
+        let parent = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_string_lossy().to_string(),
+            _ => ".".to_string(),
+        };
+        (parent, path.file_name()?.to_str()?)
+    };
+    let name = file.strip_suffix(".lance")?;
+    if name.is_empty() {
+        return None;
+    }
+    Some((parent, name.to_string()))
+}
@@ -0,0 +1,150 @@
+use tauri::plugin::TauriPlugin;
+use tauri::{Builder, Wry};
+
+use crate::ipc::v1::ExtensionV1;
+
+// `registry::enabled` is only called by `build.rs`, which includes the same file.
+#[allow(dead_code)]
+mod registry;
+
+use registry::Registration;
+
+type PluginFn = fn() -> TauriPlugin<Wry>;
+
+/// An optional command module, e.g. embeddings, a Flight server or a DuckDB bridge.
+/// It is installed as a Tauri plugin named after its registration, so its commands
+/// are invoked as `plugin:<name>|<command>` and never touch the core
+/// `generate_handler!` list.
+pub struct Extension {
+    pub registration: &'static Registration,
+    pub plugin: PluginFn,
+}
+
+impl Extension {
+    pub fn describe(&self) -> ExtensionV1 {
+        ExtensionV1 {
+            name: self.registration.name.to_string(),
+            description: self.registration.description.to_string(),
+            commands: self
+                .registration
+                .commands
+                .iter()
+                .map(|command| command.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// Plugins of the extensions compiled into this build, by registration name. A
+/// module behind a Cargo feature lives in `src/extensions/<name>.rs`, is listed in
+/// `registry::REGISTRATIONS` and adds its `plugin` function here with a gated line:
+///
+/// ```ignore
+/// #[cfg(feature = "duckdb")]
+/// mod duckdb;
+///
+/// const PLUGINS: &[(&str, PluginFn)] = &[
+///     #[cfg(feature = "duckdb")]
+///     ("duckdb", duckdb::plugin),
+/// ];
+/// ```
+const PLUGINS: &[(&str, PluginFn)] = &[];
+
+/// Extensions compiled into this build.
+pub fn extensions() -> Vec<Extension> {
+    compiled(registry::REGISTRATIONS, PLUGINS)
+}
+
+/// Pairs each plugin with its registration. A plugin without one is skipped,
+/// since `build.rs` would not have granted its commands.
+fn compiled(
+    registrations: &'static [Registration],
+    plugins: &[(&str, PluginFn)],
+) -> Vec<Extension> {
+    plugins
+        .iter()
+        .filter_map(|&(name, plugin)| {
+            registrations
+                .iter()
+                .find(|registration| registration.name == name)
+                .map(|registration| Extension {
+                    registration,
+                    plugin,
+                })
+        })
+        .collect()
+}
+
+/// Installs every compiled-in extension as a plugin on `builder`.
+pub fn install(builder: Builder<Wry>) -> Builder<Wry> {
+    install_all(builder, &extensions())
+}
+
+fn install_all(mut builder: Builder<Wry>, extensions: &[Extension]) -> Builder<Wry> {
+    for extension in extensions {
+        builder = builder.plugin((extension.plugin)());
+    }
+    builder
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    /// Only registered here, to run the extension path end to end.
+    const ECHO: &[Registration] = &[Registration {
+        feature: "echo-extension",
+        name: "echo",
+        description: "Returns its input.",
+        commands: &["echo"],
+    }];
+
+    #[tauri::command]
+    fn echo(value: String) -> String {
+        value
+    }
+
+    fn echo_plugin() -> TauriPlugin<Wry> {
+        tauri::plugin::Builder::new("echo")
+            .invoke_handler(tauri::generate_handler![echo])
+            .build()
+    }
+
+    #[test]
+    fn extensions_have_unique_names_and_commands() {
+        let mut names = HashSet::new();
+        for registration in registry::REGISTRATIONS {
+            assert!(
+                names.insert(registration.name),
+                "duplicate {}",
+                registration.name
+            );
+            assert!(
+                !registration.commands.is_empty(),
+                "{} has no commands",
+                registration.name
+            );
+        }
+        assert_eq!(extensions().len(), PLUGINS.len(), "unregistered plugin");
+    }
+
+    #[test]
+    fn installs_describes_and_grants_registered_extensions() {
+        let extensions = compiled(ECHO, &[("echo", echo_plugin), ("other", echo_plugin)]);
+        assert_eq!(extensions.len(), 1);
+        let described = extensions[0].describe();
+        assert_eq!(described.name, "echo");
+        assert_eq!(described.description, "Returns its input.");
+        assert_eq!(described.commands, vec!["echo".to_string()]);
+        let _builder = install_all(Builder::default(), &extensions);
+
+        let granted =
+            registry::enabled(ECHO, |variable| variable == "CARGO_FEATURE_ECHO_EXTENSION")
+                .map(|registration| (registration.name, registration.commands))
+                .collect::<Vec<_>>();
+        assert_eq!(granted, vec![("echo", &["echo"][..])]);
+        assert_eq!(registry::enabled(ECHO, |_| false).count(), 0);
+    }
+}
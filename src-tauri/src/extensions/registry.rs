@@ -0,0 +1,31 @@
+//! The one list of optional command modules. `build.rs` includes this file with
+//! `#[path]`, so it only uses `std`.
+
+/// A command module compiled in behind a Cargo feature.
+pub struct Registration {
+    pub feature: &'static str,
+    /// Plugin name; the commands are invoked as `plugin:<name>|<command>`.
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Must match the handler the plugin registers.
+    pub commands: &'static [&'static str],
+}
+
+/// Every optional command module. `build.rs` gives the enabled ones a `default`
+/// permission allowing their commands, which a capability grants as
+/// `<name>:default`; `extensions::PLUGINS` installs them.
+pub const REGISTRATIONS: &[Registration] = &[];
+
+/// Registrations whose feature is enabled, checked through the `CARGO_FEATURE_*`
+/// variable Cargo sets for build scripts.
+pub fn enabled<'a>(
+    registrations: &'a [Registration],
+    is_set: impl Fn(&str) -> bool,
+) -> impl Iterator<Item = &'a Registration> {
+    registrations.iter().filter(move |registration| {
+        is_set(&format!(
+            "CARGO_FEATURE_{}",
+            registration.feature.to_uppercase().replace('-', "_")
+        ))
+    })
+}
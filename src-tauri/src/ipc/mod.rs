@@ -1 +1,3 @@
-pub mod v1;
+//! The v1 protocol types live in the `lancedb-viewer-ipc` crate so external tools
+//! can share them; they are re-exported here under their original path.
+pub use lancedb_viewer_ipc::v1;
@@ -1,24 +1,19 @@
 mod commands;
 mod domain;
+mod extensions;
 pub mod ipc;
 pub mod services;
 pub mod state;
 
 use log::LevelFilter;
-use sha2::{Digest, Sha256};
+use tauri::{Emitter, Manager};
 use tauri_plugin_log::{Target, TargetKind};
 
 use state::AppState;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    let log_level = if cfg!(debug_assertions) {
-        LevelFilter::Trace
-    } else {
-        LevelFilter::Info
-    };
-
-    tauri::Builder::default()
+    let builder = tauri::Builder::default()
         .plugin(
             tauri_plugin_log::Builder::new()
                 .targets([
@@ -27,38 +22,124 @@ pub fn run() {
                         file_name: Some("lancedb-viewer.log".to_string()),
                     }),
                 ])
-                .level(log_level)
+                // Everything reaches the runtime filter, which `set_log_level_v1`
+                // reconfigures; the effective default is applied in `setup`.
+                .level(LevelFilter::Trace)
+                .filter(services::log_control::allows)
                 .build(),
         )
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_store::Builder::new().build())
-        .plugin(
-            tauri_plugin_stronghold::Builder::new(|password| {
-                let mut hasher = Sha256::new();
-                hasher.update(password.as_bytes());
-                hasher.finalize().to_vec()
-            })
-            .build(),
-        )
-        .manage(AppState::new())
+        .plugin(tauri_plugin_stronghold::Builder::new(services::secrets::password_hash).build());
+    extensions::install(builder)
+        .setup(|app| {
+            let data_dir = app.path().app_data_dir()?;
+            if let Ok(working_dir) = std::env::current_dir() {
+                let legacy_vault = working_dir.join(services::secrets::LEGACY_VAULT_FILE);
+                match services::secrets::migrate_legacy_vault(&data_dir, &legacy_vault) {
+                    Ok(0) => {}
+                    Ok(copied) => log::info!("migrated {} credentials from the old vault", copied),
+                    Err(error) => log::warn!("credential vault migration failed error={}", error),
+                }
+            }
+            let config_path = app
+                .path()
+                .home_dir()?
+                .join(services::config::CONFIG_DIR)
+                .join(services::config::CONFIG_FILE);
+            let state = AppState::with_data_dir(data_dir)
+                .with_config(config_path)
+                .with_log_dir(app.path().app_log_dir()?);
+            let log_settings = state
+                .log_settings
+                .lock()
+                .ok()
+                .and_then(|store| store.get())
+                .or_else(|| {
+                    let config = state.config.lock().ok()?.get();
+                    services::log_control::config_settings(config.log_level)
+                });
+            services::log_control::restore(log_settings.as_ref());
+            let handle = app.handle().clone();
+            if let Ok(mut jobs) = state.jobs.lock() {
+                jobs.set_progress_sink(Box::new(move |event| {
+                    if let Err(error) = handle.emit(services::jobs::PROGRESS_EVENT, event) {
+                        log::warn!("failed to emit job progress event error={}", error);
+                    }
+                }));
+            }
+            app.manage(state);
+
+            let handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                let state = handle.state::<AppState>();
+                services::scheduler::run_loop(state.inner()).await;
+            });
+
+            let handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                let state = handle.state::<AppState>();
+                services::stats_history::run_loop(state.inner()).await;
+            });
+
+            let handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                let state = handle.state::<AppState>();
+                services::watch_folders::run_loop(state.inner(), |event| {
+                    if let Err(error) =
+                        handle.emit(services::watch_folders::WATCH_FOLDER_EVENT, event)
+                    {
+                        log::warn!("failed to emit watch folder event error={}", error);
+                    }
+                })
+                .await;
+            });
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             commands::v1::connect_v1,
             commands::v1::disconnect_v1,
+            commands::v1::rotate_secret_v1,
             commands::v1::list_tables_v1,
             commands::v1::drop_table_v1,
             commands::v1::rename_table_v1,
             commands::v1::list_indexes_v1,
+            commands::v1::index_stats_v1,
             commands::v1::create_index_v1,
             commands::v1::drop_index_v1,
             commands::v1::create_table_v1,
             commands::v1::open_table_v1,
+            commands::v1::open_tables_v1,
             commands::v1::get_schema_v1,
             commands::v1::list_versions_v1,
             commands::v1::get_table_version_v1,
             commands::v1::checkout_table_version_v1,
             commands::v1::checkout_table_latest_v1,
+            commands::v1::restore_table_version_v1,
+            commands::v1::list_tags_v1,
+            commands::v1::create_tag_v1,
+            commands::v1::update_tag_v1,
+            commands::v1::delete_tag_v1,
             commands::v1::clone_table_v1,
+            commands::v1::backup_table_v1,
+            commands::v1::restore_backup_v1,
+            commands::v1::get_job_status_v1,
+            commands::v1::list_jobs_v1,
+            commands::v1::list_schedules_v1,
+            commands::v1::set_schedule_v1,
+            commands::v1::delete_schedule_v1,
+            commands::v1::list_watch_folders_v1,
+            commands::v1::set_watch_folder_v1,
+            commands::v1::delete_watch_folder_v1,
+            commands::v1::list_masking_rules_v1,
+            commands::v1::set_masking_rules_v1,
+            commands::v1::list_encrypted_columns_v1,
+            commands::v1::set_encrypted_columns_v1,
+            commands::v1::set_encryption_key_v1,
+            commands::v1::get_retention_policy_v1,
+            commands::v1::set_retention_policy_v1,
+            commands::v1::run_retention_v1,
             commands::v1::add_columns_v1,
             commands::v1::alter_columns_v1,
             commands::v1::drop_columns_v1,
@@ -67,13 +148,85 @@ pub fn run() {
             commands::v1::delete_rows_v1,
             commands::v1::import_data_v1,
             commands::v1::export_data_v1,
+            commands::v1::check_export_target_v1,
+            commands::v1::export_bundle_v1,
+            commands::v1::export_repro_sample_v1,
+            commands::v1::import_bundle_v1,
             commands::v1::optimize_table_v1,
             commands::v1::scan_v1,
+            commands::v1::scan_stream_v1,
+            commands::v1::ack_scan_stream_v1,
+            commands::v1::cancel_scan_stream_v1,
             commands::v1::query_filter_v1,
             commands::v1::combined_search_v1,
             commands::v1::vector_search_v1,
+            commands::v1::evaluate_recall_v1,
+            commands::v1::estimate_embedding_job_v1,
             commands::v1::fts_search_v1,
+            commands::v1::set_log_level_v1,
+            commands::v1::health_v1,
+            commands::v1::export_diagnostics_v1,
+            commands::v1::get_access_log_v1,
+            commands::v1::get_config_v1,
+            commands::v1::set_config_v1,
+            commands::v1::open_dataset_v1,
+            commands::v1::table_format_info_v1,
+            commands::v1::table_location_v1,
+            commands::v1::migrate_table_format_v1,
+            commands::v1::get_table_description_v1,
+            commands::v1::set_table_description_v1,
+            commands::v1::pivot_v1,
+            commands::v1::preview_update_v1,
+            commands::v1::cancel_job_v1,
+            commands::v1::list_active_queries_v1,
+            commands::v1::kill_query_v1,
+            commands::v1::materialize_query_v1,
+            commands::v1::compare_query_versions_v1,
+            commands::v1::get_row_count_v1,
+            commands::v1::get_search_defaults_v1,
+            commands::v1::set_search_defaults_v1,
+            commands::v1::list_projection_presets_v1,
+            commands::v1::save_projection_preset_v1,
+            commands::v1::delete_projection_preset_v1,
+            commands::v1::get_index_capabilities_v1,
+            commands::v1::stats_history_v1,
+            commands::v1::overview_v1,
+            commands::v1::list_export_templates_v1,
+            commands::v1::save_export_template_v1,
+            commands::v1::delete_export_template_v1,
+            commands::v1::run_export_template_v1,
+            commands::v1::start_share_v1,
+            commands::v1::list_shares_v1,
+            commands::v1::stop_share_v1,
+            commands::v1::estimate_query_v1,
+            commands::v1::memory_stats_v1,
+            commands::v1::list_extensions_v1,
+            commands::v1::reorder_columns_v1,
+            commands::v1::recent_filters_v1,
+            commands::v1::completion_metadata_v1,
+            commands::v1::validate_filter_v1,
+            commands::v1::export_rows_v1,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app, event| {
+            // Hold the exit until running jobs stop at a chunk boundary, then let the
+            // `exit` call below through.
+            if let tauri::RunEvent::ExitRequested { api, .. } = event {
+                let state = app.state::<AppState>();
+                if services::shutdown::begin(state.inner()) {
+                    api.prevent_exit();
+                    let handle = app.clone();
+                    tauri::async_runtime::spawn(async move {
+                        let state = handle.state::<AppState>();
+                        services::shutdown::shutdown(
+                            state.inner(),
+                            services::shutdown::DRAIN_TIMEOUT,
+                        )
+                        .await;
+                        handle.exit(0);
+                    });
+                }
+            }
+        });
 }
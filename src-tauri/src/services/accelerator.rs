@@ -0,0 +1,102 @@
+use std::thread;
+
+use crate::ipc::v1::{IndexAcceleratorV1, IndexCapabilitiesResponseV1, IndexTypeV1};
+
+/// Lance sizes its pool for CPU-bound work (k-means, PQ training) from this variable
+/// when the pool is first used, so it cannot change per index build.
+const CPU_THREADS_ENV: &str = "LANCE_CPU_THREADS";
+
+const GPU_UNAVAILABLE: &str =
+    "this build trains indexes on the CPU only; GPU training needs a CUDA-enabled Lance build";
+
+/// GPU training is not compiled into this build; the probe and the checks below read
+/// this flag so enabling it later only touches one place.
+const GPU_SUPPORTED: bool = false;
+
+pub fn cpu_threads() -> usize {
+    std::env::var(CPU_THREADS_ENV)
+        .ok()
+        .and_then(|value| value.trim().parse::<usize>().ok())
+        .filter(|threads| *threads > 0)
+        .unwrap_or_else(|| {
+            thread::available_parallelism()
+                .map(|threads| threads.get())
+                .unwrap_or(1)
+        })
+}
+
+pub fn capabilities() -> IndexCapabilitiesResponseV1 {
+    let mut accelerators = vec![IndexAcceleratorV1::Auto, IndexAcceleratorV1::Cpu];
+    if GPU_SUPPORTED {
+        accelerators.push(IndexAcceleratorV1::Gpu);
+    }
+    IndexCapabilitiesResponseV1 {
+        accelerators,
+        default_accelerator: default_accelerator(),
+        cpu_threads: cpu_threads(),
+        gpu_unavailable_reason: (!GPU_SUPPORTED).then(|| GPU_UNAVAILABLE.to_string()),
+    }
+}
+
+fn default_accelerator() -> IndexAcceleratorV1 {
+    if GPU_SUPPORTED {
+        IndexAcceleratorV1::Gpu
+    } else {
+        IndexAcceleratorV1::Cpu
+    }
+}
+
+/// `auto` may build a vector index on a vector column, so it counts as one here.
+fn trains_vectors(index_type: &IndexTypeV1) -> bool {
+    !matches!(
+        index_type,
+        IndexTypeV1::BTree | IndexTypeV1::Bitmap | IndexTypeV1::LabelList | IndexTypeV1::Fts
+    )
+}
+
+/// Resolves the requested accelerator for an index build. Returns `None` for index
+/// types that do no vector training.
+pub fn resolve(
+    requested: Option<IndexAcceleratorV1>,
+    index_type: &IndexTypeV1,
+) -> Result<Option<IndexAcceleratorV1>, String> {
+    if !trains_vectors(index_type) {
+        return Ok(None);
+    }
+    match requested.unwrap_or(IndexAcceleratorV1::Auto) {
+        IndexAcceleratorV1::Auto => Ok(Some(default_accelerator())),
+        IndexAcceleratorV1::Gpu if !GPU_SUPPORTED => Err(GPU_UNAVAILABLE.to_string()),
+        accelerator => Ok(Some(accelerator)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_accelerators_for_vector_indexes() {
+        assert_eq!(
+            resolve(None, &IndexTypeV1::IvfPq),
+            Ok(Some(IndexAcceleratorV1::Cpu))
+        );
+        assert_eq!(
+            resolve(Some(IndexAcceleratorV1::Cpu), &IndexTypeV1::IvfHnswSq),
+            Ok(Some(IndexAcceleratorV1::Cpu))
+        );
+        assert!(resolve(Some(IndexAcceleratorV1::Gpu), &IndexTypeV1::IvfPq).is_err());
+        assert_eq!(
+            resolve(Some(IndexAcceleratorV1::Gpu), &IndexTypeV1::Fts),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn reports_cpu_only_capabilities() {
+        let capabilities = capabilities();
+        assert!(!capabilities.accelerators.contains(&IndexAcceleratorV1::Gpu));
+        assert_eq!(capabilities.default_accelerator, IndexAcceleratorV1::Cpu);
+        assert!(capabilities.cpu_threads > 0);
+        assert!(capabilities.gpu_unavailable_reason.is_some());
+    }
+}
@@ -0,0 +1,159 @@
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+
+use log::warn;
+
+use crate::ipc::v1::{AccessLogEntryV1, GetAccessLogRequestV1};
+
+/// Written next to the app log, one JSON entry per line.
+pub const ACCESS_LOG_FILE: &str = "access.jsonl";
+/// Entries kept in memory for `get_access_log_v1`.
+pub const MAX_ENTRIES: usize = 2_000;
+pub const DEFAULT_LIMIT: usize = 200;
+/// The file is rotated to `access.1.jsonl` once it grows past this.
+const MAX_FILE_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Recent remote calls, appended to `access.jsonl` in the log directory when one
+/// is configured. Shared with the threads of share servers.
+#[derive(Default)]
+pub struct AccessLog {
+    path: Option<PathBuf>,
+    entries: VecDeque<AccessLogEntryV1>,
+}
+
+impl AccessLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opens the log at `path`, reading back its most recent entries.
+    pub fn load(path: PathBuf) -> Self {
+        let mut entries = VecDeque::new();
+        if let Ok(file) = File::open(&path) {
+            for line in BufReader::new(file).lines().map_while(Result::ok) {
+                match serde_json::from_str::<AccessLogEntryV1>(&line) {
+                    Ok(entry) => {
+                        if entries.len() == MAX_ENTRIES {
+                            entries.pop_front();
+                        }
+                        entries.push_back(entry);
+                    }
+                    Err(error) => warn!("skipped invalid access log line error={}", error),
+                }
+            }
+        }
+        Self {
+            path: Some(path),
+            entries,
+        }
+    }
+
+    pub fn record(&mut self, entry: AccessLogEntryV1) {
+        if let Err(error) = self.append(&entry) {
+            warn!("failed to write access log error={}", error);
+        }
+        if self.entries.len() == MAX_ENTRIES {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+
+    fn append(&self, entry: &AccessLogEntryV1) -> Result<(), String> {
+        let Some(path) = self.path.as_ref() else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|error| error.to_string())?;
+        }
+        if fs::metadata(path).is_ok_and(|metadata| metadata.len() > MAX_FILE_BYTES) {
+            fs::rename(path, path.with_extension("1.jsonl")).map_err(|error| error.to_string())?;
+        }
+        let line = serde_json::to_string(entry).map_err(|error| error.to_string())?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|error| error.to_string())?;
+        writeln!(file, "{line}").map_err(|error| error.to_string())
+    }
+
+    /// Entries matching `request`, newest first.
+    pub fn list(&self, request: &GetAccessLogRequestV1) -> Vec<AccessLogEntryV1> {
+        self.entries
+            .iter()
+            .rev()
+            .filter(|entry| {
+                request
+                    .transport
+                    .is_none_or(|value| entry.transport == value)
+            })
+            .filter(|entry| {
+                request
+                    .client
+                    .as_deref()
+                    .is_none_or(|client| entry.client == client)
+            })
+            .filter(|entry| {
+                request
+                    .command
+                    .as_deref()
+                    .is_none_or(|command| entry.command == command)
+            })
+            .filter(|entry| request.since_ms.is_none_or(|since| entry.at_ms >= since))
+            .take(request.limit.unwrap_or(DEFAULT_LIMIT))
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ipc::v1::AccessTransportV1;
+
+    use super::*;
+
+    fn entry(at_ms: u64, command: &str) -> AccessLogEntryV1 {
+        AccessLogEntryV1 {
+            at_ms,
+            transport: AccessTransportV1::Http,
+            client: "10.0.0.2:5000".to_string(),
+            command: command.to_string(),
+            table: Some("items".to_string()),
+            ok: true,
+            error_code: None,
+            elapsed_ms: 3,
+        }
+    }
+
+    #[test]
+    fn persists_and_filters_entries() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join(ACCESS_LOG_FILE);
+        let mut log = AccessLog::load(path.clone());
+        log.record(entry(1, "scan_v1"));
+        log.record(entry(2, "share_api"));
+
+        let reloaded = AccessLog::load(path);
+        let all = GetAccessLogRequestV1 {
+            transport: None,
+            client: None,
+            command: None,
+            since_ms: None,
+            limit: None,
+        };
+        let listed = reloaded.list(&all);
+        assert_eq!(listed, vec![entry(2, "share_api"), entry(1, "scan_v1")]);
+        let scans = GetAccessLogRequestV1 {
+            command: Some("scan_v1".to_string()),
+            ..all.clone()
+        };
+        assert_eq!(reloaded.list(&scans), vec![entry(1, "scan_v1")]);
+        let other_client = GetAccessLogRequestV1 {
+            client: Some("10.0.0.3:5000".to_string()),
+            ..all
+        };
+        assert!(reloaded.list(&other_client).is_empty());
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use std::time::Instant;
+
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::ipc::v1::ActiveQueryV1;
+use crate::services::clock::now_unix_millis;
+
+/// Longest statement summary kept per query; longer ones end in an ellipsis.
+pub const MAX_SUMMARY_CHARS: usize = 200;
+
+struct ActiveQuery {
+    command: &'static str,
+    table_id: String,
+    summary: String,
+    started_at_ms: u64,
+    started_at: Instant,
+    token: CancellationToken,
+}
+
+/// Read queries that are still executing. Each one races its work against a token,
+/// so killing it drops the query future and returns `cancelled` at once.
+#[derive(Default)]
+pub struct ActiveQueryRegistry {
+    queries: HashMap<String, ActiveQuery>,
+}
+
+impl ActiveQueryRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn start(
+        &mut self,
+        command: &'static str,
+        table_id: &str,
+        summary: &str,
+    ) -> (String, CancellationToken) {
+        let query_id = Uuid::new_v4().to_string();
+        let token = CancellationToken::new();
+        self.queries.insert(
+            query_id.clone(),
+            ActiveQuery {
+                command,
+                table_id: table_id.to_string(),
+                summary: truncate_summary(summary),
+                started_at_ms: now_unix_millis(),
+                started_at: Instant::now(),
+                token: token.clone(),
+            },
+        );
+        (query_id, token)
+    }
+
+    pub fn finish(&mut self, query_id: &str) {
+        self.queries.remove(query_id);
+    }
+
+    /// Cancels a running query and returns it as it was when killed, or `None` when
+    /// it has already finished.
+    pub fn kill(&mut self, query_id: &str) -> Option<ActiveQueryV1> {
+        let query = self.queries.get(query_id)?;
+        query.token.cancel();
+        Some(to_active_query_v1(query_id, query))
+    }
+
+    /// Running queries, longest running first.
+    pub fn list(&self) -> Vec<ActiveQueryV1> {
+        let mut queries = self
+            .queries
+            .iter()
+            .map(|(query_id, query)| to_active_query_v1(query_id, query))
+            .collect::<Vec<_>>();
+        queries.sort_by(|left, right| {
+            right
+                .elapsed_ms
+                .cmp(&left.elapsed_ms)
+                .then_with(|| left.query_id.cmp(&right.query_id))
+        });
+        queries
+    }
+}
+
+fn to_active_query_v1(query_id: &str, query: &ActiveQuery) -> ActiveQueryV1 {
+    ActiveQueryV1 {
+        query_id: query_id.to_string(),
+        command: query.command.to_string(),
+        table_id: query.table_id.clone(),
+        summary: query.summary.clone(),
+        started_at_ms: query.started_at_ms,
+        elapsed_ms: query.started_at.elapsed().as_millis() as u64,
+        killed: query.token.is_cancelled(),
+    }
+}
+
+/// One line describing what a query runs, such as `scan where id > 3`.
+pub fn summary(operation: &str, detail: Option<&str>, filter: Option<&str>) -> String {
+    let mut summary = operation.to_string();
+    if let Some(detail) = detail.map(str::trim).filter(|detail| !detail.is_empty()) {
+        summary.push(' ');
+        summary.push_str(detail);
+    }
+    if let Some(filter) = filter.map(str::trim).filter(|filter| !filter.is_empty()) {
+        summary.push_str(" where ");
+        summary.push_str(filter);
+    }
+    summary
+}
+
+fn truncate_summary(summary: &str) -> String {
+    let summary = summary.split_whitespace().collect::<Vec<_>>().join(" ");
+    if summary.chars().count() <= MAX_SUMMARY_CHARS {
+        return summary;
+    }
+    let mut truncated = summary
+        .chars()
+        .take(MAX_SUMMARY_CHARS - 1)
+        .collect::<String>();
+    truncated.push('…');
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kills_and_lists_running_queries() {
+        let mut queries = ActiveQueryRegistry::new();
+        let (scan_id, scan_token) = queries.start("scan_v1", "t1", &summary("scan", None, None));
+        let (filter_id, _) = queries.start(
+            "query_filter_v1",
+            "t2",
+            &summary("filter", None, Some("id >\n 3")),
+        );
+        let listed = queries.list();
+        assert_eq!(listed.len(), 2);
+        assert!(listed.iter().any(|query| query.query_id == filter_id
+            && query.summary == "filter where id > 3"
+            && query.table_id == "t2"));
+
+        let killed = queries.kill(&scan_id).expect("running query");
+        assert!(killed.killed);
+        assert!(scan_token.is_cancelled());
+        queries.finish(&scan_id);
+        assert!(queries.kill(&scan_id).is_none());
+        assert_eq!(queries.list().len(), 1);
+    }
+
+    #[test]
+    fn truncates_long_summaries() {
+        let long = "x".repeat(MAX_SUMMARY_CHARS + 10);
+        let truncated = truncate_summary(&long);
+        assert_eq!(truncated.chars().count(), MAX_SUMMARY_CHARS);
+        assert!(truncated.ends_with('…'));
+    }
+}
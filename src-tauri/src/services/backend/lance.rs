@@ -0,0 +1,289 @@
+use std::sync::Arc;
+
+use arrow_array::{RecordBatch, RecordBatchIterator};
+use arrow_schema::SchemaRef;
+use futures_util::{FutureExt, TryStreamExt};
+use lancedb::index::IndexType;
+use lancedb::query::{ExecutableQuery, QueryBase};
+use lancedb::table::AddDataMode;
+use lancedb::{Connection, DistanceType, Table};
+use log::warn;
+
+use crate::ipc::v1::{
+    DistanceTypeV1, IndexDefinitionV1, IndexTypeV1, VersionInfoV1, WriteDataMode,
+};
+use crate::services::backend::{
+    BackendError, BackendFuture, DatastoreBackend, TableBackend, TableScan, TableStorageStats,
+};
+use crate::services::projection;
+
+pub fn to_version_info(version: lancedb::table::Version) -> VersionInfoV1 {
+    VersionInfoV1 {
+        version: version.version,
+        timestamp: version.timestamp.to_rfc3339(),
+        metadata: version.metadata.into_iter().collect(),
+    }
+}
+
+pub fn to_index_type_v1(index_type: &IndexType) -> IndexTypeV1 {
+    match index_type {
+        IndexType::BTree => IndexTypeV1::BTree,
+        IndexType::Bitmap => IndexTypeV1::Bitmap,
+        IndexType::LabelList => IndexTypeV1::LabelList,
+        IndexType::FTS => IndexTypeV1::Fts,
+        IndexType::IvfFlat => IndexTypeV1::IvfFlat,
+        IndexType::IvfSq => IndexTypeV1::IvfSq,
+        IndexType::IvfPq => IndexTypeV1::IvfPq,
+        IndexType::IvfRq => IndexTypeV1::IvfRq,
+        IndexType::IvfHnswPq => IndexTypeV1::IvfHnswPq,
+        IndexType::IvfHnswSq => IndexTypeV1::IvfHnswSq,
+    }
+}
+
+pub fn to_distance_type_v1(distance_type: &DistanceType) -> DistanceTypeV1 {
+    match distance_type {
+        DistanceType::L2 => DistanceTypeV1::L2,
+        DistanceType::Cosine => DistanceTypeV1::Cosine,
+        DistanceType::Dot => DistanceTypeV1::Dot,
+        DistanceType::Hamming => DistanceTypeV1::Hamming,
+        _ => DistanceTypeV1::L2,
+    }
+}
+
+impl From<lancedb::Error> for BackendError {
+    fn from(error: lancedb::Error) -> Self {
+        match error {
+            lancedb::Error::InvalidTableName { .. }
+            | lancedb::Error::InvalidInput { .. }
+            | lancedb::Error::Schema { .. }
+            | lancedb::Error::IndexNotFound { .. } => Self::InvalidInput(error.to_string()),
+            lancedb::Error::TableNotFound { name, .. } => Self::TableNotFound { name },
+            lancedb::Error::NotSupported { .. } => Self::NotSupported(error.to_string()),
+            error => Self::Engine(error.to_string()),
+        }
+    }
+}
+
+/// Like the `From` conversion, but reports a missing table under the name it was
+/// asked for rather than the one LanceDB resolved.
+fn table_error(name: &str, error: lancedb::Error) -> BackendError {
+    match error {
+        lancedb::Error::TableNotFound { .. } => BackendError::TableNotFound {
+            name: name.to_string(),
+        },
+        error => error.into(),
+    }
+}
+
+pub struct LanceBackend {
+    connection: Connection,
+}
+
+impl LanceBackend {
+    pub fn new(connection: Connection) -> Self {
+        Self { connection }
+    }
+}
+
+impl DatastoreBackend for LanceBackend {
+    fn uri(&self) -> &str {
+        self.connection.uri()
+    }
+
+    fn table_names(&self) -> BackendFuture<'_, Vec<String>> {
+        async move {
+            self.connection
+                .table_names()
+                .execute()
+                .await
+                .map_err(BackendError::from)
+        }
+        .boxed()
+    }
+
+    fn open_table<'a>(&'a self, name: &'a str) -> BackendFuture<'a, Arc<dyn TableBackend>> {
+        async move {
+            let table = self
+                .connection
+                .open_table(name)
+                .execute()
+                .await
+                .map_err(|error| table_error(name, error))?;
+            Ok(Arc::new(LanceTableBackend::new(table)) as Arc<dyn TableBackend>)
+        }
+        .boxed()
+    }
+
+    fn drop_table<'a>(&'a self, name: &'a str, namespace: &'a [String]) -> BackendFuture<'a, ()> {
+        async move {
+            self.connection
+                .drop_table(name, namespace)
+                .await
+                .map_err(|error| table_error(name, error))
+        }
+        .boxed()
+    }
+
+    fn rename_table<'a>(
+        &'a self,
+        name: &'a str,
+        new_name: &'a str,
+        namespace: &'a [String],
+        new_namespace: &'a [String],
+    ) -> BackendFuture<'a, ()> {
+        async move {
+            self.connection
+                .rename_table(name, new_name, namespace, new_namespace)
+                .await
+                .map_err(|error| table_error(name, error))
+        }
+        .boxed()
+    }
+}
+
+pub struct LanceTableBackend {
+    table: Table,
+}
+
+impl LanceTableBackend {
+    pub fn new(table: Table) -> Self {
+        Self { table }
+    }
+}
+
+impl TableBackend for LanceTableBackend {
+    fn name(&self) -> &str {
+        self.table.name()
+    }
+
+    fn schema(&self) -> BackendFuture<'_, SchemaRef> {
+        async move { Ok(self.table.schema().await?) }.boxed()
+    }
+
+    fn version(&self) -> BackendFuture<'_, u64> {
+        async move { Ok(self.table.version().await?) }.boxed()
+    }
+
+    fn list_versions(&self) -> BackendFuture<'_, Vec<VersionInfoV1>> {
+        async move {
+            let versions = self.table.list_versions().await?;
+            Ok(versions.into_iter().map(to_version_info).collect())
+        }
+        .boxed()
+    }
+
+    fn storage_stats(&self) -> BackendFuture<'_, TableStorageStats> {
+        async move {
+            let stats = self.table.stats().await?;
+            let version = self.table.version().await?;
+            // Remote tables expose no manifest, so they report no modification time.
+            let last_modified_ms = match self.table.as_native() {
+                Some(native) => native
+                    .manifest()
+                    .await
+                    .ok()
+                    .map(|manifest| (manifest.timestamp_nanos / 1_000_000) as u64),
+                None => None,
+            };
+            Ok(TableStorageStats {
+                row_count: stats.num_rows as u64,
+                version,
+                last_modified_ms,
+                size_bytes: stats.total_bytes as u64,
+            })
+        }
+        .boxed()
+    }
+
+    fn count_rows<'a>(&'a self, filter: Option<&'a str>) -> BackendFuture<'a, u64> {
+        async move {
+            let rows = self.table.count_rows(filter.map(str::to_string)).await?;
+            Ok(rows as u64)
+        }
+        .boxed()
+    }
+
+    fn scan<'a>(&'a self, scan: &'a TableScan) -> BackendFuture<'a, Vec<RecordBatch>> {
+        async move {
+            let mut query = self.table.query();
+            if let Some(filter) = scan.filter.as_deref() {
+                query = query.only_if(filter);
+            }
+            if let Some(limit) = scan.limit {
+                query = query.limit(limit);
+            }
+            if let Some(offset) = scan.offset {
+                query = query.offset(offset);
+            }
+            if let Some(columns) = scan
+                .projection
+                .as_deref()
+                .filter(|columns| !columns.is_empty())
+            {
+                query = query.select(projection::to_select(columns));
+            }
+            Ok(query.execute().await?.try_collect().await?)
+        }
+        .boxed()
+    }
+
+    fn add(&self, batches: Vec<RecordBatch>, mode: WriteDataMode) -> BackendFuture<'_, u64> {
+        async move {
+            let schema = match batches.first() {
+                Some(batch) => batch.schema(),
+                None => self.table.schema().await?,
+            };
+            let mut builder = self.table.add(RecordBatchIterator::new(
+                batches.into_iter().map(Ok),
+                schema,
+            ));
+            if matches!(mode, WriteDataMode::Overwrite) {
+                builder = builder.mode(AddDataMode::Overwrite);
+            }
+            Ok(builder.execute().await?.version)
+        }
+        .boxed()
+    }
+
+    fn delete<'a>(&'a self, filter: &'a str) -> BackendFuture<'a, u64> {
+        async move { Ok(self.table.delete(filter).await?.version) }.boxed()
+    }
+
+    fn list_indexes(&self) -> BackendFuture<'_, Vec<IndexDefinitionV1>> {
+        async move {
+            let mut indexes = Vec::new();
+            for config in self.table.list_indices().await? {
+                let stats = match self.table.index_stats(&config.name).await {
+                    Ok(stats) => stats,
+                    Err(error) => {
+                        warn!(
+                            "failed to read index stats table={} index={} error={}",
+                            self.table.name(),
+                            config.name,
+                            error
+                        );
+                        None
+                    }
+                };
+                indexes.push(IndexDefinitionV1 {
+                    name: config.name,
+                    index_type: to_index_type_v1(&config.index_type),
+                    columns: config.columns,
+                    num_indexed_rows: stats.as_ref().map(|stats| stats.num_indexed_rows),
+                    num_unindexed_rows: stats.as_ref().map(|stats| stats.num_unindexed_rows),
+                    distance_type: stats
+                        .as_ref()
+                        .and_then(|stats| stats.distance_type.as_ref().map(to_distance_type_v1)),
+                    num_indices: stats.as_ref().and_then(|stats| stats.num_indices),
+                    loss: stats.as_ref().and_then(|stats| stats.loss),
+                });
+            }
+            Ok(indexes)
+        }
+        .boxed()
+    }
+
+    fn drop_index<'a>(&'a self, name: &'a str) -> BackendFuture<'a, ()> {
+        async move { Ok(self.table.drop_index(name).await?) }.boxed()
+    }
+}
@@ -0,0 +1,633 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+
+use arrow_array::{Array, BooleanArray, Float64Array, RecordBatch, StringArray};
+use arrow_schema::{DataType, SchemaRef};
+use chrono::Utc;
+use futures_util::FutureExt;
+
+use crate::ipc::v1::{IndexDefinitionV1, VersionInfoV1, WriteDataMode};
+use crate::services::backend::{
+    BackendError, BackendFuture, DatastoreBackend, TableBackend, TableScan, TableStorageStats,
+};
+
+#[derive(Debug)]
+struct TableData {
+    schema: SchemaRef,
+    batches: Vec<RecordBatch>,
+    size_bytes: u64,
+    versions: Vec<VersionInfoV1>,
+    indexes: Vec<IndexDefinitionV1>,
+}
+
+impl TableData {
+    fn row_count(&self) -> u64 {
+        self.batches
+            .iter()
+            .map(|batch| batch.num_rows() as u64)
+            .sum()
+    }
+
+    fn commit(&mut self) -> u64 {
+        let version = self.versions.len() as u64 + 1;
+        self.versions.push(VersionInfoV1 {
+            version,
+            timestamp: Utc::now().to_rfc3339(),
+            metadata: HashMap::new(),
+        });
+        version
+    }
+}
+
+/// Table held entirely in memory. Clones share their rows, so a handle opened from a
+/// `MemoryBackend` sees the writes made through another.
+///
+/// Filters are limited to one `column op literal` comparison, with `=`, `!=`, `<`,
+/// `<=`, `>` or `>=` against a number or a single-quoted string; anything else is
+/// reported as not supported.
+#[derive(Debug, Clone)]
+pub struct MemoryTable {
+    name: String,
+    data: Arc<Mutex<TableData>>,
+}
+
+impl MemoryTable {
+    /// An empty table at version 1.
+    pub fn new(name: impl Into<String>, schema: SchemaRef) -> Self {
+        Self {
+            name: name.into(),
+            data: Arc::new(Mutex::new(TableData {
+                schema,
+                batches: Vec::new(),
+                size_bytes: 0,
+                versions: vec![VersionInfoV1 {
+                    version: 1,
+                    timestamp: "1970-01-01T00:00:00+00:00".to_string(),
+                    metadata: HashMap::new(),
+                }],
+                indexes: Vec::new(),
+            })),
+        }
+    }
+
+    /// Adds rows without committing a version, as if the table had been created with them.
+    pub fn with_batch(self, batch: RecordBatch) -> Self {
+        if let Ok(mut data) = self.data.lock() {
+            data.batches.push(batch);
+        }
+        self
+    }
+
+    pub fn with_size_bytes(self, size_bytes: u64) -> Self {
+        if let Ok(mut data) = self.data.lock() {
+            data.size_bytes = size_bytes;
+        }
+        self
+    }
+
+    /// Records a new version; versions are numbered consecutively from 1.
+    pub fn with_version(self, timestamp: impl Into<String>) -> Self {
+        if let Ok(mut data) = self.data.lock() {
+            let version = data.versions.len() as u64 + 1;
+            data.versions.push(VersionInfoV1 {
+                version,
+                timestamp: timestamp.into(),
+                metadata: HashMap::new(),
+            });
+        }
+        self
+    }
+
+    pub fn with_index(self, index: IndexDefinitionV1) -> Self {
+        if let Ok(mut data) = self.data.lock() {
+            data.indexes.push(index);
+        }
+        self
+    }
+
+    fn with_data<T>(
+        &self,
+        apply: impl FnOnce(&mut TableData) -> Result<T, BackendError>,
+    ) -> Result<T, BackendError> {
+        let mut data = self
+            .data
+            .lock()
+            .map_err(|_| BackendError::Engine("memory table lock poisoned".to_string()))?;
+        apply(&mut data)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Comparison {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+impl Comparison {
+    fn holds(self, ordering: std::cmp::Ordering) -> bool {
+        match self {
+            Self::Eq => ordering.is_eq(),
+            Self::NotEq => ordering.is_ne(),
+            Self::Lt => ordering.is_lt(),
+            Self::LtEq => ordering.is_le(),
+            Self::Gt => ordering.is_gt(),
+            Self::GtEq => ordering.is_ge(),
+        }
+    }
+}
+
+enum Literal {
+    Number(f64),
+    Text(String),
+}
+
+struct Predicate {
+    column: String,
+    comparison: Comparison,
+    literal: Literal,
+}
+
+fn parse_filter(filter: &str) -> Result<Predicate, BackendError> {
+    let unsupported = || BackendError::NotSupported(format!("unsupported filter: {filter}"));
+    let (column, comparison, literal) = [
+        ("!=", Comparison::NotEq),
+        ("<=", Comparison::LtEq),
+        (">=", Comparison::GtEq),
+        ("=", Comparison::Eq),
+        ("<", Comparison::Lt),
+        (">", Comparison::Gt),
+    ]
+    .into_iter()
+    .find_map(|(operator, comparison)| {
+        filter
+            .split_once(operator)
+            .map(|(column, literal)| (column.trim(), comparison, literal.trim()))
+    })
+    .ok_or_else(unsupported)?;
+    if column.is_empty() || !column.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return Err(unsupported());
+    }
+    let literal = match literal
+        .strip_prefix('\'')
+        .and_then(|literal| literal.strip_suffix('\''))
+    {
+        Some(text) => Literal::Text(text.to_string()),
+        None => Literal::Number(literal.parse().map_err(|_| unsupported())?),
+    };
+    Ok(Predicate {
+        column: column.to_string(),
+        comparison,
+        literal,
+    })
+}
+
+/// Rows of `batch` the predicate holds for; null values never match.
+fn matching_rows(batch: &RecordBatch, predicate: &Predicate) -> Result<BooleanArray, BackendError> {
+    let column = batch.column_by_name(&predicate.column).ok_or_else(|| {
+        BackendError::InvalidInput(format!("no such column: {}", predicate.column))
+    })?;
+    let cast_error =
+        |error: arrow_schema::ArrowError| BackendError::InvalidInput(error.to_string());
+    let mask = match &predicate.literal {
+        Literal::Number(literal) => {
+            let values = arrow_cast::cast(column, &DataType::Float64).map_err(cast_error)?;
+            let values = values
+                .as_any()
+                .downcast_ref::<Float64Array>()
+                .ok_or_else(|| BackendError::Engine("cast did not produce f64".to_string()))?;
+            values
+                .iter()
+                .map(|value| {
+                    Some(value.is_some_and(|value| {
+                        value
+                            .partial_cmp(literal)
+                            .is_some_and(|ordering| predicate.comparison.holds(ordering))
+                    }))
+                })
+                .collect()
+        }
+        Literal::Text(literal) => {
+            let values = arrow_cast::cast(column, &DataType::Utf8).map_err(cast_error)?;
+            let values = values
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .ok_or_else(|| BackendError::Engine("cast did not produce utf8".to_string()))?;
+            values
+                .iter()
+                .map(|value| {
+                    Some(value.is_some_and(|value| {
+                        predicate.comparison.holds(value.cmp(literal.as_str()))
+                    }))
+                })
+                .collect()
+        }
+    };
+    Ok(mask)
+}
+
+/// Keeps the rows of `batches` that match `filter`, or the ones that don't when
+/// `keep_matching` is false.
+fn filter_batches(
+    batches: &[RecordBatch],
+    filter: Option<&str>,
+    keep_matching: bool,
+) -> Result<Vec<RecordBatch>, BackendError> {
+    let Some(filter) = filter else {
+        return Ok(batches.to_vec());
+    };
+    let predicate = parse_filter(filter)?;
+    batches
+        .iter()
+        .map(|batch| {
+            let mask = matching_rows(batch, &predicate)?;
+            let mask = if keep_matching {
+                mask
+            } else {
+                mask.iter()
+                    .map(|matched| matched.map(|matched| !matched))
+                    .collect()
+            };
+            arrow_select::filter::filter_record_batch(batch, &mask)
+                .map_err(|error| BackendError::Engine(error.to_string()))
+        })
+        .collect()
+}
+
+fn scan_batches(data: &TableData, scan: &TableScan) -> Result<Vec<RecordBatch>, BackendError> {
+    let batches = filter_batches(&data.batches, scan.filter.as_deref(), true)?;
+    let mut skip = scan.offset.unwrap_or(0);
+    let mut remaining = scan.limit.unwrap_or(usize::MAX);
+    let mut rows = Vec::new();
+    for batch in batches {
+        if remaining == 0 {
+            break;
+        }
+        if skip >= batch.num_rows() {
+            skip -= batch.num_rows();
+            continue;
+        }
+        let length = (batch.num_rows() - skip).min(remaining);
+        rows.push(batch.slice(skip, length));
+        remaining -= length;
+        skip = 0;
+    }
+    let Some(columns) = scan
+        .projection
+        .as_deref()
+        .filter(|columns| !columns.is_empty())
+    else {
+        return Ok(rows);
+    };
+    let indices = columns
+        .iter()
+        .map(|column| {
+            data.schema
+                .index_of(column)
+                .map_err(|_| BackendError::InvalidInput(format!("no such column: {column}")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    rows.iter()
+        .map(|batch| {
+            batch
+                .project(&indices)
+                .map_err(|error| BackendError::Engine(error.to_string()))
+        })
+        .collect()
+}
+
+impl TableBackend for MemoryTable {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn schema(&self) -> BackendFuture<'_, SchemaRef> {
+        let schema = self.with_data(|data| Ok(data.schema.clone()));
+        async move { schema }.boxed()
+    }
+
+    fn version(&self) -> BackendFuture<'_, u64> {
+        let version = self.with_data(|data| Ok(data.versions.len() as u64));
+        async move { version }.boxed()
+    }
+
+    fn list_versions(&self) -> BackendFuture<'_, Vec<VersionInfoV1>> {
+        let versions = self.with_data(|data| Ok(data.versions.clone()));
+        async move { versions }.boxed()
+    }
+
+    fn storage_stats(&self) -> BackendFuture<'_, TableStorageStats> {
+        let stats = self.with_data(|data| {
+            Ok(TableStorageStats {
+                row_count: data.row_count(),
+                version: data.versions.len() as u64,
+                last_modified_ms: None,
+                size_bytes: data.size_bytes,
+            })
+        });
+        async move { stats }.boxed()
+    }
+
+    fn count_rows<'a>(&'a self, filter: Option<&'a str>) -> BackendFuture<'a, u64> {
+        let rows = self.with_data(|data| {
+            let batches = filter_batches(&data.batches, filter, true)?;
+            Ok(batches.iter().map(|batch| batch.num_rows() as u64).sum())
+        });
+        async move { rows }.boxed()
+    }
+
+    fn scan<'a>(&'a self, scan: &'a TableScan) -> BackendFuture<'a, Vec<RecordBatch>> {
+        let batches = self.with_data(|data| scan_batches(data, scan));
+        async move { batches }.boxed()
+    }
+
+    fn add(&self, batches: Vec<RecordBatch>, mode: WriteDataMode) -> BackendFuture<'_, u64> {
+        let version = self.with_data(|data| {
+            for batch in &batches {
+                let names = batch
+                    .schema()
+                    .fields()
+                    .iter()
+                    .map(|field| field.name().clone());
+                if !names.eq(data
+                    .schema
+                    .fields()
+                    .iter()
+                    .map(|field| field.name().clone()))
+                {
+                    return Err(BackendError::InvalidInput(
+                        "batch schema does not match the table schema".to_string(),
+                    ));
+                }
+            }
+            if matches!(mode, WriteDataMode::Overwrite) {
+                data.batches.clear();
+            }
+            data.batches.extend(batches);
+            Ok(data.commit())
+        });
+        async move { version }.boxed()
+    }
+
+    fn delete<'a>(&'a self, filter: &'a str) -> BackendFuture<'a, u64> {
+        let version = self.with_data(|data| {
+            data.batches = filter_batches(&data.batches, Some(filter), false)?;
+            Ok(data.commit())
+        });
+        async move { version }.boxed()
+    }
+
+    fn list_indexes(&self) -> BackendFuture<'_, Vec<IndexDefinitionV1>> {
+        let indexes = self.with_data(|data| Ok(data.indexes.clone()));
+        async move { indexes }.boxed()
+    }
+
+    fn drop_index<'a>(&'a self, name: &'a str) -> BackendFuture<'a, ()> {
+        let dropped = self.with_data(|data| {
+            let count = data.indexes.len();
+            data.indexes.retain(|index| index.name != name);
+            if data.indexes.len() == count {
+                return Err(BackendError::InvalidInput(format!(
+                    "Index '{name}' not found"
+                )));
+            }
+            Ok(())
+        });
+        async move { dropped }.boxed()
+    }
+}
+
+fn table_not_found(name: &str) -> BackendError {
+    BackendError::TableNotFound {
+        name: name.to_string(),
+    }
+}
+
+/// Catalog kept in a map. Namespaces are accepted but ignored.
+pub struct MemoryBackend {
+    uri: String,
+    tables: Mutex<BTreeMap<String, MemoryTable>>,
+}
+
+impl MemoryBackend {
+    pub fn new(uri: impl Into<String>) -> Self {
+        Self {
+            uri: uri.into(),
+            tables: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn with_table(self, table: MemoryTable) -> Self {
+        if let Ok(mut tables) = self.tables.lock() {
+            tables.insert(table.name.clone(), table);
+        }
+        self
+    }
+
+    fn with_tables<T>(
+        &self,
+        apply: impl FnOnce(&mut BTreeMap<String, MemoryTable>) -> Result<T, BackendError>,
+    ) -> Result<T, BackendError> {
+        let mut tables = self
+            .tables
+            .lock()
+            .map_err(|_| BackendError::Engine("memory backend lock poisoned".to_string()))?;
+        apply(&mut tables)
+    }
+}
+
+impl DatastoreBackend for MemoryBackend {
+    fn uri(&self) -> &str {
+        &self.uri
+    }
+
+    fn table_names(&self) -> BackendFuture<'_, Vec<String>> {
+        let names = self.with_tables(|tables| Ok(tables.keys().cloned().collect()));
+        async move { names }.boxed()
+    }
+
+    fn open_table<'a>(&'a self, name: &'a str) -> BackendFuture<'a, Arc<dyn TableBackend>> {
+        let table = self.with_tables(|tables| {
+            tables
+                .get(name)
+                .cloned()
+                .map(|table| Arc::new(table) as Arc<dyn TableBackend>)
+                .ok_or_else(|| table_not_found(name))
+        });
+        async move { table }.boxed()
+    }
+
+    fn drop_table<'a>(&'a self, name: &'a str, _namespace: &'a [String]) -> BackendFuture<'a, ()> {
+        let dropped = self.with_tables(|tables| {
+            tables
+                .remove(name)
+                .map(|_| ())
+                .ok_or_else(|| table_not_found(name))
+        });
+        async move { dropped }.boxed()
+    }
+
+    fn rename_table<'a>(
+        &'a self,
+        name: &'a str,
+        new_name: &'a str,
+        _namespace: &'a [String],
+        _new_namespace: &'a [String],
+    ) -> BackendFuture<'a, ()> {
+        let renamed = self.with_tables(|tables| {
+            if tables.contains_key(new_name) {
+                return Err(BackendError::InvalidInput(format!(
+                    "Table '{new_name}' already exists"
+                )));
+            }
+            let mut table = tables.remove(name).ok_or_else(|| table_not_found(name))?;
+            table.name = new_name.to_string();
+            tables.insert(new_name.to_string(), table);
+            Ok(())
+        });
+        async move { renamed }.boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::Int32Array;
+    use arrow_schema::{Field, Schema};
+
+    use super::*;
+    use crate::ipc::v1::{ErrorCode, IndexTypeV1};
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("name", DataType::Utf8, true),
+        ]))
+    }
+
+    fn batch(ids: &[i32]) -> RecordBatch {
+        let names = ids.iter().map(|id| format!("row-{id}")).collect::<Vec<_>>();
+        RecordBatch::try_new(
+            schema(),
+            vec![
+                Arc::new(Int32Array::from(ids.to_vec())),
+                Arc::new(StringArray::from(names)),
+            ],
+        )
+        .expect("batch")
+    }
+
+    fn ids(batches: &[RecordBatch]) -> Vec<i32> {
+        batches
+            .iter()
+            .flat_map(|batch| {
+                let column = batch.column(0).as_any().downcast_ref::<Int32Array>();
+                column.expect("ids").values().to_vec()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn renames_and_drops_tables() {
+        let backend = MemoryBackend::new("memory://test")
+            .with_table(MemoryTable::new("items", schema()).with_batch(batch(&[1, 2, 3])))
+            .with_table(MemoryTable::new("other", schema()));
+
+        assert!(backend
+            .rename_table("items", "other", &[], &[])
+            .await
+            .is_err_and(|error| error.code() == ErrorCode::InvalidArgument));
+        backend
+            .rename_table("items", "products", &[], &[])
+            .await
+            .expect("rename");
+        assert_eq!(
+            backend.table_names().await.expect("names"),
+            vec!["other", "products"]
+        );
+        let table = backend.open_table("products").await.expect("open");
+        assert_eq!(table.name(), "products");
+        assert_eq!(table.storage_stats().await.expect("stats").row_count, 3);
+
+        backend.drop_table("other", &[]).await.expect("drop");
+        assert!(backend
+            .open_table("other")
+            .await
+            .err()
+            .is_some_and(|error| matches!(error, BackendError::TableNotFound { .. })));
+        assert!(backend
+            .drop_table("other", &[])
+            .await
+            .is_err_and(|error| matches!(error, BackendError::TableNotFound { .. })));
+    }
+
+    #[tokio::test]
+    async fn writes_scans_and_deletes_rows() {
+        let backend = MemoryBackend::new("memory://test")
+            .with_table(MemoryTable::new("items", schema()).with_batch(batch(&[1, 2, 3])));
+        let table = backend.open_table("items").await.expect("open");
+
+        let version = table
+            .add(vec![batch(&[4, 5])], WriteDataMode::Append)
+            .await
+            .expect("append");
+        assert_eq!(version, 2);
+        // Handles opened earlier share the rows.
+        let reopened = backend.open_table("items").await.expect("reopen");
+        assert_eq!(reopened.count_rows(None).await.expect("count"), 5);
+        assert_eq!(
+            reopened.count_rows(Some("id >= 3")).await.expect("count"),
+            3
+        );
+
+        let scan = TableScan {
+            filter: Some("name != 'row-2'".to_string()),
+            projection: Some(vec!["id".to_string()]),
+            limit: Some(2),
+            offset: Some(1),
+        };
+        let batches = table.scan(&scan).await.expect("scan");
+        assert_eq!(ids(&batches), vec![3, 4]);
+        assert!(batches.iter().all(|batch| batch.num_columns() == 1));
+
+        assert_eq!(table.delete("id < 3").await.expect("delete"), 3);
+        assert_eq!(
+            ids(&table.scan(&TableScan::default()).await.expect("scan")),
+            vec![3, 4, 5]
+        );
+        assert!(matches!(
+            table.delete("id IN (1, 2)").await,
+            Err(BackendError::NotSupported(_))
+        ));
+
+        table
+            .add(vec![batch(&[9])], WriteDataMode::Overwrite)
+            .await
+            .expect("overwrite");
+        assert_eq!(table.count_rows(None).await.expect("count"), 1);
+        assert_eq!(table.list_versions().await.expect("versions").len(), 4);
+    }
+
+    #[tokio::test]
+    async fn lists_and_drops_indexes() {
+        let table = MemoryTable::new("items", schema()).with_index(IndexDefinitionV1 {
+            name: "id_idx".to_string(),
+            index_type: IndexTypeV1::BTree,
+            columns: vec!["id".to_string()],
+            num_indexed_rows: Some(3),
+            num_unindexed_rows: Some(0),
+            distance_type: None,
+            num_indices: None,
+            loss: None,
+        });
+        assert_eq!(table.list_indexes().await.expect("indexes").len(), 1);
+        table.drop_index("id_idx").await.expect("drop");
+        assert!(table.list_indexes().await.expect("indexes").is_empty());
+        assert!(matches!(
+            table.drop_index("id_idx").await,
+            Err(BackendError::InvalidInput(_))
+        ));
+    }
+}
@@ -0,0 +1,127 @@
+//! Engine-neutral view of the catalog, table and data operations used by
+//! `services::v1`. `lance` wraps a LanceDB connection; `memory` keeps everything in
+//! process so handlers can be exercised without creating datasets on disk.
+
+pub mod lance;
+pub mod memory;
+
+use std::fmt;
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_schema::SchemaRef;
+use futures_util::future::BoxFuture;
+
+use crate::ipc::v1::{ErrorCode, IndexDefinitionV1, VersionInfoV1, WriteDataMode};
+
+pub use lance::{LanceBackend, LanceTableBackend};
+pub use memory::{MemoryBackend, MemoryTable};
+
+pub type BackendFuture<'a, T> = BoxFuture<'a, Result<T, BackendError>>;
+
+/// Why a backend operation failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BackendError {
+    /// No table of this name exists.
+    TableNotFound { name: String },
+    /// The request itself is wrong, e.g. a bad filter or a batch of another schema.
+    InvalidInput(String),
+    /// The engine cannot do this for this table or connection.
+    NotSupported(String),
+    /// Anything else the engine reported.
+    Engine(String),
+}
+
+impl BackendError {
+    /// Envelope code a handler reports for this error.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::TableNotFound { .. } => ErrorCode::NotFound,
+            Self::InvalidInput(_) => ErrorCode::InvalidArgument,
+            Self::NotSupported(_) => ErrorCode::NotImplemented,
+            Self::Engine(_) => ErrorCode::Internal,
+        }
+    }
+}
+
+impl fmt::Display for BackendError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            // The wording LanceDB uses.
+            Self::TableNotFound { name } => write!(formatter, "Table '{name}' was not found"),
+            Self::InvalidInput(message) | Self::NotSupported(message) | Self::Engine(message) => {
+                formatter.write_str(message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BackendError {}
+
+/// Rows a scan reads. The filter is SQL as LanceDB accepts it; `projection` names
+/// the columns to return, all of them when unset or empty.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TableScan {
+    pub filter: Option<String>,
+    pub projection: Option<Vec<String>>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+}
+
+/// Size and freshness of a table, as listed by `list_tables_v1`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableStorageStats {
+    pub row_count: u64,
+    pub version: u64,
+    /// Commit time of the latest version, when the engine records one.
+    pub last_modified_ms: Option<u64>,
+    pub size_bytes: u64,
+}
+
+/// Catalog operations of one connection.
+pub trait DatastoreBackend: Send + Sync {
+    fn uri(&self) -> &str;
+
+    fn table_names(&self) -> BackendFuture<'_, Vec<String>>;
+
+    fn open_table<'a>(&'a self, name: &'a str) -> BackendFuture<'a, Arc<dyn TableBackend>>;
+
+    fn drop_table<'a>(&'a self, name: &'a str, namespace: &'a [String]) -> BackendFuture<'a, ()>;
+
+    fn rename_table<'a>(
+        &'a self,
+        name: &'a str,
+        new_name: &'a str,
+        namespace: &'a [String],
+        new_namespace: &'a [String],
+    ) -> BackendFuture<'a, ()>;
+}
+
+/// Operations on one open table.
+pub trait TableBackend: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn schema(&self) -> BackendFuture<'_, SchemaRef>;
+
+    fn version(&self) -> BackendFuture<'_, u64>;
+
+    fn list_versions(&self) -> BackendFuture<'_, Vec<VersionInfoV1>>;
+
+    fn storage_stats(&self) -> BackendFuture<'_, TableStorageStats>;
+
+    /// Rows matching `filter`, or every row when it is unset.
+    fn count_rows<'a>(&'a self, filter: Option<&'a str>) -> BackendFuture<'a, u64>;
+
+    fn scan<'a>(&'a self, scan: &'a TableScan) -> BackendFuture<'a, Vec<RecordBatch>>;
+
+    /// Appends `batches`, or replaces every row with them, and returns the version
+    /// committed.
+    fn add(&self, batches: Vec<RecordBatch>, mode: WriteDataMode) -> BackendFuture<'_, u64>;
+
+    /// Deletes the rows matching `filter` and returns the version committed.
+    fn delete<'a>(&'a self, filter: &'a str) -> BackendFuture<'a, u64>;
+
+    fn list_indexes(&self) -> BackendFuture<'_, Vec<IndexDefinitionV1>>;
+
+    fn drop_index<'a>(&'a self, name: &'a str) -> BackendFuture<'a, ()>;
+}
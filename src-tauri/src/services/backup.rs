@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::{RecordBatch, RecordBatchIterator};
+use arrow_schema::{Schema, SchemaRef};
+use futures_util::TryStreamExt;
+use lancedb::query::ExecutableQuery;
+use lancedb::Table;
+
+use crate::ipc::v1::{BackupModeV1, VersionInfoV1};
+use crate::services::memory;
+
+pub const BACKUP_METADATA_PREFIX: &str = "lancedb_viewer.backup.";
+const COPY_CHUNK_ROWS: usize = 50_000;
+
+/// Backup provenance stored in the backup table's schema metadata, so it travels
+/// with the data to local paths and object storage alike.
+#[derive(Debug, Clone, Default)]
+pub struct BackupMetadata {
+    pub source_table: Option<String>,
+    pub source_version: Option<u64>,
+    pub created_at_ms: Option<u64>,
+    pub mode: Option<BackupModeV1>,
+    pub versions: Vec<VersionInfoV1>,
+}
+
+fn metadata_key(name: &str) -> String {
+    format!("{BACKUP_METADATA_PREFIX}{name}")
+}
+
+pub fn schema_with_backup_metadata(
+    schema: &Schema,
+    metadata: &BackupMetadata,
+) -> Result<SchemaRef, String> {
+    let mut entries = schema.metadata().clone();
+    if let Some(source_table) = &metadata.source_table {
+        entries.insert(metadata_key("source_table"), source_table.clone());
+    }
+    if let Some(source_version) = metadata.source_version {
+        entries.insert(metadata_key("source_version"), source_version.to_string());
+    }
+    if let Some(created_at_ms) = metadata.created_at_ms {
+        entries.insert(metadata_key("created_at_ms"), created_at_ms.to_string());
+    }
+    if let Some(mode) = metadata.mode {
+        let mode = match mode {
+            BackupModeV1::Full => "full",
+            BackupModeV1::Shallow => "shallow",
+        };
+        entries.insert(metadata_key("mode"), mode.to_string());
+    }
+    let versions = serde_json::to_string(&metadata.versions).map_err(|error| error.to_string())?;
+    entries.insert(metadata_key("versions"), versions);
+    Ok(Arc::new(schema.clone().with_metadata(entries)))
+}
+
+/// Splits backup provenance out of a schema, returning the schema without those keys.
+pub fn split_backup_metadata(schema: &Schema) -> (SchemaRef, BackupMetadata) {
+    let mut retained = HashMap::new();
+    let mut metadata = BackupMetadata::default();
+    for (key, value) in schema.metadata() {
+        let Some(name) = key.strip_prefix(BACKUP_METADATA_PREFIX) else {
+            retained.insert(key.clone(), value.clone());
+            continue;
+        };
+        match name {
+            "source_table" => metadata.source_table = Some(value.clone()),
+            "source_version" => metadata.source_version = value.parse().ok(),
+            "created_at_ms" => metadata.created_at_ms = value.parse().ok(),
+            "mode" => {
+                metadata.mode = match value.as_str() {
+                    "full" => Some(BackupModeV1::Full),
+                    "shallow" => Some(BackupModeV1::Shallow),
+                    _ => None,
+                }
+            }
+            "versions" => metadata.versions = serde_json::from_str(value).unwrap_or_default(),
+            _ => {}
+        }
+    }
+    (Arc::new(schema.clone().with_metadata(retained)), metadata)
+}
+
+async fn flush_chunk(
+    target: &Table,
+    schema: &SchemaRef,
+    chunk: &mut Vec<RecordBatch>,
+) -> Result<(), String> {
+    if chunk.is_empty() {
+        return Ok(());
+    }
+    let batches = std::mem::take(chunk);
+    let bytes = batches.iter().map(memory::batch_bytes).sum::<u64>();
+    let batch_iter = RecordBatchIterator::new(batches.into_iter().map(Ok), schema.clone());
+    let added = target.add(batch_iter).execute().await;
+    memory::release(bytes);
+    added.map(|_| ()).map_err(|error| error.to_string())
+}
+
+/// Streams every row of `source` into `target` in chunks, reporting the running row
+/// count after each chunk is committed. An error from `on_progress` stops the copy
+/// between chunks, so a cancelled job never leaves a half-written append behind.
+pub async fn copy_table_rows(
+    source: &Table,
+    target: &Table,
+    schema: SchemaRef,
+    mut on_progress: impl FnMut(u64) -> Result<(), String>,
+) -> Result<u64, String> {
+    let mut stream = source
+        .query()
+        .execute()
+        .await
+        .map_err(|error| error.to_string())?;
+
+    let mut copied = 0u64;
+    let mut chunk = Vec::new();
+    let mut chunk_rows = 0usize;
+    while let Some(batch) = stream.try_next().await.map_err(|error| error.to_string())? {
+        let batch = RecordBatch::try_new(schema.clone(), batch.columns().to_vec())
+            .map_err(|error| error.to_string())?;
+        chunk_rows += batch.num_rows();
+        memory::retain(memory::batch_bytes(&batch));
+        chunk.push(batch);
+        if chunk_rows >= COPY_CHUNK_ROWS {
+            flush_chunk(target, &schema, &mut chunk).await?;
+            copied += chunk_rows as u64;
+            chunk_rows = 0;
+            on_progress(copied)?;
+        }
+    }
+    flush_chunk(target, &schema, &mut chunk).await?;
+    copied += chunk_rows as u64;
+    on_progress(copied)?;
+
+    Ok(copied)
+}
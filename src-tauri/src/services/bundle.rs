@@ -0,0 +1,155 @@
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+use arrow_array::RecordBatch;
+use arrow_schema::SchemaRef;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ArrowWriter;
+
+use crate::ipc::v1::{BundleManifestV1, BundleShardV1};
+use crate::services::checksum;
+
+pub const BUNDLE_MANIFEST_FILE: &str = "manifest.json";
+pub const BUNDLE_SCHEMA_FILE: &str = "schema.json";
+pub const BUNDLE_FORMAT_VERSION: u32 = 1;
+pub const DEFAULT_ROWS_PER_SHARD: usize = 100_000;
+
+fn shard_file_name(index: usize) -> String {
+    format!("shard-{index:05}.parquet")
+}
+
+fn close_shard(
+    dir: &Path,
+    writer: ArrowWriter<BufWriter<File>>,
+    file: String,
+    rows: usize,
+    shards: &mut Vec<BundleShardV1>,
+) -> Result<(), String> {
+    writer.close().map_err(|error| error.to_string())?;
+    let (sha256, _) = checksum::sha256_file(&dir.join(&file))?;
+    shards.push(BundleShardV1 {
+        file,
+        rows,
+        sha256: Some(sha256),
+    });
+    Ok(())
+}
+
+/// Writes batches into numbered Parquet shards of at most `rows_per_shard` rows.
+/// An empty result still produces one shard so the bundle keeps its schema.
+pub fn write_parquet_shards(
+    dir: &Path,
+    schema: SchemaRef,
+    batches: &[RecordBatch],
+    rows_per_shard: usize,
+) -> Result<Vec<BundleShardV1>, String> {
+    let rows_per_shard = rows_per_shard.max(1);
+    let mut shards = Vec::new();
+    let mut current: Option<(ArrowWriter<BufWriter<File>>, String, usize)> = None;
+
+    for batch in batches {
+        let mut offset = 0;
+        while offset < batch.num_rows() {
+            let (mut writer, file, rows) = match current.take() {
+                Some(open) => open,
+                None => {
+                    let file = shard_file_name(shards.len());
+                    let handle =
+                        File::create(dir.join(&file)).map_err(|error| error.to_string())?;
+                    let writer = ArrowWriter::try_new(BufWriter::new(handle), schema.clone(), None)
+                        .map_err(|error| error.to_string())?;
+                    (writer, file, 0)
+                }
+            };
+
+            let take = (rows_per_shard - rows).min(batch.num_rows() - offset);
+            writer
+                .write(&batch.slice(offset, take))
+                .map_err(|error| error.to_string())?;
+            offset += take;
+
+            let rows = rows + take;
+            if rows >= rows_per_shard {
+                close_shard(dir, writer, file, rows, &mut shards)?;
+            } else {
+                current = Some((writer, file, rows));
+            }
+        }
+    }
+
+    if let Some((writer, file, rows)) = current {
+        close_shard(dir, writer, file, rows, &mut shards)?;
+    }
+
+    if shards.is_empty() {
+        let file = shard_file_name(0);
+        let handle = File::create(dir.join(&file)).map_err(|error| error.to_string())?;
+        let writer = ArrowWriter::try_new(BufWriter::new(handle), schema, None)
+            .map_err(|error| error.to_string())?;
+        close_shard(dir, writer, file, 0, &mut shards)?;
+    }
+
+    Ok(shards)
+}
+
+/// Verifies shard checksums, then reads every shard listed in the manifest, returning
+/// the batches and the shard schema.
+pub fn read_parquet_shards(
+    dir: &Path,
+    manifest: &BundleManifestV1,
+) -> Result<(Vec<RecordBatch>, SchemaRef), String> {
+    let mut batches = Vec::new();
+    let mut schema: Option<SchemaRef> = None;
+
+    for shard in &manifest.shards {
+        if let Some(expected) = shard.sha256.as_deref() {
+            checksum::verify_sha256(&dir.join(&shard.file), expected)?;
+        }
+    }
+
+    for shard in &manifest.shards {
+        let file = File::open(dir.join(&shard.file))
+            .map_err(|error| format!("failed to open shard '{}': {error}", shard.file))?;
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new(file).map_err(|error| error.to_string())?;
+        if schema.is_none() {
+            schema = Some(builder.schema().clone());
+        }
+        let reader = builder.build().map_err(|error| error.to_string())?;
+        for batch in reader {
+            batches.push(batch.map_err(|error| error.to_string())?);
+        }
+    }
+
+    let schema = schema.ok_or_else(|| "bundle does not contain any shards".to_string())?;
+    Ok((batches, schema))
+}
+
+pub fn write_json_file<T: serde::Serialize>(path: &Path, value: &T) -> Result<(), String> {
+    let file = File::create(path).map_err(|error| error.to_string())?;
+    serde_json::to_writer_pretty(BufWriter::new(file), value).map_err(|error| error.to_string())
+}
+
+pub fn read_manifest(dir: &Path) -> Result<BundleManifestV1, String> {
+    let path = dir.join(BUNDLE_MANIFEST_FILE);
+    let file = File::open(&path).map_err(|_| "bundle manifest.json not found".to_string())?;
+    let manifest: BundleManifestV1 = serde_json::from_reader(BufReader::new(file))
+        .map_err(|error| format!("invalid bundle manifest: {error}"))?;
+    if manifest.format_version > BUNDLE_FORMAT_VERSION {
+        return Err(format!(
+            "bundle format version {} is newer than supported version {}",
+            manifest.format_version, BUNDLE_FORMAT_VERSION
+        ));
+    }
+    Ok(manifest)
+}
+
+pub fn prepare_bundle_dir(dir: &Path, overwrite: bool) -> Result<(), String> {
+    if dir.join(BUNDLE_MANIFEST_FILE).exists() && !overwrite {
+        return Err(
+            "bundle already exists at path; set overwrite to true to replace it".to_string(),
+        );
+    }
+    fs::create_dir_all(dir).map_err(|error| error.to_string())
+}
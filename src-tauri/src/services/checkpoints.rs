@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::services::store;
+
+pub const CHECKPOINT_NOTES_FILE: &str = "checkpoint_notes.json";
+
+/// Every automatic checkpoint tag starts with this, so the versions panel can tell
+/// them from tags a user created.
+pub const AUTO_TAG_PREFIX: &str = "auto-";
+/// Longest note kept in a tag name; tag names end up in file names.
+const MAX_NOTE_CHARS: usize = 40;
+
+/// The note as it appears in a tag name: lowercase ASCII letters and digits, with
+/// every other run of characters turned into one `-`.
+pub fn note_slug(note: &str) -> String {
+    let mut slug = String::new();
+    for character in note.chars() {
+        if character.is_ascii_alphanumeric() {
+            slug.push(character.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.chars().take(MAX_NOTE_CHARS).collect::<String>();
+    slug.trim_end_matches('-').to_string()
+}
+
+/// Tag for the version a mutation produced, e.g.
+/// `auto-v12-update-20261016T153000Z-price-fix`. The version keeps names unique
+/// and the name stays valid for `version_tags::validate_tag_name`. A note without
+/// ASCII letters or digits is left out of the name; the full note is kept in
+/// [`CheckpointNoteStore`].
+pub fn tag_name(version: u64, operation: &str, at: DateTime<Utc>, note: Option<&str>) -> String {
+    let mut name = format!(
+        "{AUTO_TAG_PREFIX}v{version}-{operation}-{}",
+        at.format("%Y%m%dT%H%M%SZ")
+    );
+    if let Some(slug) = note.map(note_slug).filter(|slug| !slug.is_empty()) {
+        name.push('-');
+        name.push_str(&slug);
+    }
+    name
+}
+
+pub fn is_auto_tag(name: &str) -> bool {
+    name.starts_with(AUTO_TAG_PREFIX)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CheckpointNote {
+    connection_uri: String,
+    table_name: String,
+    tag: String,
+    note: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CheckpointNoteFile {
+    #[serde(default)]
+    notes: Vec<CheckpointNote>,
+}
+
+/// Notes of automatic checkpoints as written, keyed by connection URI, table name
+/// and tag, persisted to `checkpoint_notes.json` in the app data directory when one
+/// is configured. Tag names only carry an ASCII slug of the note.
+#[derive(Default)]
+pub struct CheckpointNoteStore {
+    path: Option<PathBuf>,
+    notes: Vec<CheckpointNote>,
+}
+
+impl CheckpointNoteStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load(path: PathBuf) -> Self {
+        let notes = match store::load_json::<CheckpointNoteFile>(&path) {
+            Ok(file) => file.notes,
+            Err(error) => {
+                warn!(
+                    "failed to load checkpoint notes path={:?} error={}",
+                    path, error
+                );
+                Vec::new()
+            }
+        };
+        Self {
+            path: Some(path),
+            notes,
+        }
+    }
+
+    /// Notes of the tags of a table, keyed by tag name.
+    pub fn for_table(&self, connection_uri: &str, table_name: &str) -> HashMap<String, String> {
+        self.notes
+            .iter()
+            .filter(|note| note.connection_uri == connection_uri && note.table_name == table_name)
+            .map(|note| (note.tag.clone(), note.note.clone()))
+            .collect()
+    }
+
+    /// Replaces the note of a tag; a blank or missing note removes it.
+    pub fn set(
+        &mut self,
+        connection_uri: &str,
+        table_name: &str,
+        tag: &str,
+        note: Option<&str>,
+    ) -> Result<(), String> {
+        self.notes.retain(|entry| {
+            entry.connection_uri != connection_uri
+                || entry.table_name != table_name
+                || entry.tag != tag
+        });
+        if let Some(note) = note.map(str::trim).filter(|note| !note.is_empty()) {
+            self.notes.push(CheckpointNote {
+                connection_uri: connection_uri.to_string(),
+                table_name: table_name.to_string(),
+                tag: tag.to_string(),
+                note: note.to_string(),
+            });
+        }
+        self.persist()
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        let Some(path) = self.path.as_ref() else {
+            return Ok(());
+        };
+        store::save_json(
+            path,
+            &CheckpointNoteFile {
+                notes: self.notes.clone(),
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use crate::services::version_tags::validate_tag_name;
+
+    use super::*;
+
+    #[test]
+    fn names_checkpoints_by_version_operation_and_note() {
+        let at = Utc.with_ymd_and_hms(2026, 10, 16, 15, 30, 0).unwrap();
+        let name = tag_name(12, "update", at, Some("  Price fix: EU / 2026 "));
+        assert_eq!(name, "auto-v12-update-20261016T153000Z-price-fix-eu-2026");
+        assert!(validate_tag_name(&name).is_ok());
+        assert!(is_auto_tag(&name));
+        assert!(!is_auto_tag("release-1"));
+    }
+
+    #[test]
+    fn keeps_notes_the_tag_name_cannot_carry() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join(CHECKPOINT_NOTES_FILE);
+        let at = Utc.with_ymd_and_hms(2026, 10, 16, 15, 30, 0).unwrap();
+        let name = tag_name(3, "add-columns", at, Some("价格"));
+        assert_eq!(name, "auto-v3-add-columns-20261016T153000Z");
+
+        let mut notes = CheckpointNoteStore::load(path.clone());
+        notes
+            .set("memory://db", "items", &name, Some(" 价格 "))
+            .expect("set note");
+        notes
+            .set("memory://db", "items", "auto-v4-update", Some("  "))
+            .expect("blank note");
+
+        let reloaded = CheckpointNoteStore::load(path);
+        assert_eq!(
+            reloaded.for_table("memory://db", "items"),
+            HashMap::from([(name, "价格".to_string())])
+        );
+        assert!(reloaded.for_table("memory://db", "other").is_empty());
+    }
+
+    #[test]
+    fn slugs_notes_to_ascii_words() {
+        assert_eq!(note_slug("nightly load"), "nightly-load");
+        assert!(note_slug("价格 — ").is_empty());
+        assert_eq!(note_slug(&"a".repeat(60)).len(), MAX_NOTE_CHARS);
+    }
+}
@@ -0,0 +1,56 @@
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+
+use sha2::{Digest, Sha256};
+
+pub fn to_hex(digest: &[u8]) -> String {
+    digest.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Hashes a file in fixed-size chunks, returning the lowercase hex digest and the
+/// number of bytes read.
+pub fn sha256_file(path: &Path) -> Result<(String, u64), String> {
+    let file = File::open(path).map_err(|error| error.to_string())?;
+    let mut reader = BufReader::new(file);
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; 64 * 1024];
+    let mut total = 0u64;
+    loop {
+        let read = reader
+            .read(&mut buffer)
+            .map_err(|error| error.to_string())?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+        total += read as u64;
+    }
+    Ok((to_hex(&hasher.finalize()), total))
+}
+
+/// Accepts `abc…`, `ABC…` or `sha256:abc…` and returns the normalized lowercase digest.
+pub fn normalize_sha256(value: &str) -> Result<String, String> {
+    let trimmed = value.trim();
+    let digest = trimmed
+        .strip_prefix("sha256:")
+        .unwrap_or(trimmed)
+        .to_ascii_lowercase();
+    if digest.len() != 64 || !digest.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        return Err("expected checksum must be a 64-character hex SHA-256 digest".to_string());
+    }
+    Ok(digest)
+}
+
+/// Verifies a file against an expected digest, returning the actual digest on success.
+pub fn verify_sha256(path: &Path, expected: &str) -> Result<String, String> {
+    let expected = normalize_sha256(expected)?;
+    let (actual, _) = sha256_file(path)?;
+    if actual != expected {
+        return Err(format!(
+            "checksum mismatch for {}: expected {expected}, got {actual}",
+            path.display()
+        ));
+    }
+    Ok(actual)
+}
@@ -0,0 +1,196 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_schema::{Schema, SchemaRef};
+
+use crate::ipc::v1::SchemaDefinition;
+
+/// Schema metadata key holding the display order of the columns as a JSON array.
+/// Lance cannot reorder columns without rewriting the data, so the order lives in
+/// the table metadata where every client opening the table can read it.
+pub const COLUMN_ORDER_KEY: &str = "lancedb_viewer:column_order";
+
+/// Appends the columns `listed` leaves out in schema order and skips names the
+/// schema no longer has, e.g. after a column was dropped or renamed.
+fn complete(schema: &Schema, listed: &[String]) -> Vec<String> {
+    let names = schema
+        .fields()
+        .iter()
+        .map(|field| field.name().as_str())
+        .collect::<HashSet<_>>();
+    let mut order = listed
+        .iter()
+        .filter(|name| names.contains(name.as_str()))
+        .cloned()
+        .collect::<Vec<_>>();
+    let seen = order.iter().cloned().collect::<HashSet<_>>();
+    order.extend(
+        schema
+            .fields()
+            .iter()
+            .filter(|field| !seen.contains(field.name()))
+            .map(|field| field.name().clone()),
+    );
+    order
+}
+
+/// The display order as it was stored, possibly naming columns the table no
+/// longer has.
+pub fn listed(schema: &Schema) -> Option<Vec<String>> {
+    let raw = schema.metadata().get(COLUMN_ORDER_KEY)?;
+    serde_json::from_str::<Vec<String>>(raw).ok()
+}
+
+/// The display order stored on the table, adjusted to its current columns.
+pub fn stored(schema: &Schema) -> Option<Vec<String>> {
+    Some(complete(schema, &listed(schema)?))
+}
+
+/// Checks a requested order and completes it. Columns it leaves out keep their
+/// schema order after the listed ones.
+pub fn resolve(schema: &Schema, requested: &[String]) -> Result<Vec<String>, String> {
+    let mut seen = HashSet::new();
+    for name in requested {
+        if schema.field_with_name(name).is_err() {
+            return Err(format!("column '{name}' does not exist"));
+        }
+        if !seen.insert(name.as_str()) {
+            return Err(format!("column '{name}' is listed more than once"));
+        }
+    }
+    Ok(complete(schema, requested))
+}
+
+/// Schema metadata with `order` stored. The rest of the metadata is kept, since
+/// lance replaces the whole map.
+pub fn metadata(schema: &Schema, order: &[String]) -> Result<HashMap<String, String>, String> {
+    let mut metadata = schema.metadata().clone();
+    let value = serde_json::to_string(order).map_err(|error| error.to_string())?;
+    metadata.insert(COLUMN_ORDER_KEY.to_string(), value);
+    Ok(metadata)
+}
+
+/// Positions of the columns of `schema` in display order. Names missing from
+/// `schema`, e.g. outside a projection, are skipped.
+fn indices(schema: &Schema, order: &[String]) -> Vec<usize> {
+    complete(schema, order)
+        .iter()
+        .filter_map(|name| schema.index_of(name).ok())
+        .collect()
+}
+
+pub fn definition(schema: &Schema) -> SchemaDefinition {
+    let mut definition = SchemaDefinition::from_arrow_schema(schema);
+    if let Some(order) = stored(schema) {
+        definition.fields.sort_by_key(|field| {
+            order
+                .iter()
+                .position(|name| *name == field.name)
+                .unwrap_or(usize::MAX)
+        });
+    }
+    definition
+}
+
+pub fn reorder_schema(schema: &SchemaRef, order: &[String]) -> Result<SchemaRef, String> {
+    schema
+        .project(&indices(schema, order))
+        .map(Arc::new)
+        .map_err(|error| error.to_string())
+}
+
+pub fn reorder_batches(
+    batches: Vec<RecordBatch>,
+    order: &[String],
+) -> Result<Vec<RecordBatch>, String> {
+    batches
+        .into_iter()
+        .map(|batch| {
+            batch
+                .project(&indices(batch.schema().as_ref(), order))
+                .map_err(|error| error.to_string())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::Int32Array;
+    use arrow_schema::{DataType, Field};
+
+    use super::*;
+
+    fn schema() -> Schema {
+        Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("text", DataType::Utf8, true),
+            Field::new("score", DataType::Float32, true),
+        ])
+    }
+
+    #[test]
+    fn resolves_partial_orders() {
+        let schema = schema();
+        let order = resolve(&schema, &["score".to_string()]).expect("order");
+        assert_eq!(order, vec!["score", "id", "text"]);
+        assert!(resolve(&schema, &["missing".to_string()]).is_err());
+        assert!(resolve(&schema, &["id".to_string(), "id".to_string()]).is_err());
+
+        let metadata = metadata(&schema, &order).expect("metadata");
+        let stored_schema = Schema::new_with_metadata(schema.fields().clone(), metadata);
+        assert_eq!(stored(&stored_schema), Some(order));
+        assert_eq!(
+            definition(&stored_schema)
+                .fields
+                .iter()
+                .map(|field| field.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["score", "id", "text"]
+        );
+    }
+
+    #[test]
+    fn adjusts_stored_orders_to_schema_changes() {
+        let mut metadata = HashMap::new();
+        metadata.insert(
+            COLUMN_ORDER_KEY.to_string(),
+            r#"["dropped", "score", "id"]"#.to_string(),
+        );
+        let schema = Schema::new_with_metadata(schema().fields().clone(), metadata);
+        assert_eq!(
+            stored(&schema),
+            Some(vec![
+                "score".to_string(),
+                "id".to_string(),
+                "text".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn reorders_projected_batches() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("score", DataType::Int32, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![1])),
+                Arc::new(Int32Array::from(vec![2])),
+            ],
+        )
+        .expect("batch");
+        let order = vec!["score".to_string(), "text".to_string(), "id".to_string()];
+        let reordered = reorder_batches(vec![batch], &order).expect("reorder");
+        assert_eq!(reordered[0].schema().field(0).name(), "score");
+        assert_eq!(
+            reorder_schema(&schema, &order)
+                .expect("schema")
+                .field(1)
+                .name(),
+            "id"
+        );
+    }
+}
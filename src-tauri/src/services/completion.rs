@@ -0,0 +1,444 @@
+use arrow_schema::{DataType, Field, Schema};
+
+use crate::ipc::v1::{CategoryOperatorsV1, CompletionColumnV1, SqlFunctionV1, ValueCategoryV1};
+use crate::services::{column_order, projection};
+
+/// Keywords the filter parser accepts besides operators and functions.
+pub const KEYWORDS: [&str; 12] = [
+    "AND",
+    "OR",
+    "NOT",
+    "NULL",
+    "TRUE",
+    "FALSE",
+    "CAST",
+    "AS",
+    "DATE",
+    "TIMESTAMP",
+    "INTERVAL",
+    "ESCAPE",
+];
+
+const NULL_CHECKS: [&str; 2] = ["IS NULL", "IS NOT NULL"];
+const ORDERING: [&str; 6] = ["=", "!=", "<", "<=", ">", ">="];
+
+pub fn category(data_type: &DataType) -> ValueCategoryV1 {
+    match data_type {
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Float16
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::Decimal128(_, _)
+        | DataType::Decimal256(_, _) => ValueCategoryV1::Numeric,
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => ValueCategoryV1::String,
+        DataType::Boolean => ValueCategoryV1::Boolean,
+        DataType::Date32
+        | DataType::Date64
+        | DataType::Timestamp(_, _)
+        | DataType::Time32(_)
+        | DataType::Time64(_)
+        | DataType::Duration(_)
+        | DataType::Interval(_) => ValueCategoryV1::Temporal,
+        DataType::Binary
+        | DataType::LargeBinary
+        | DataType::BinaryView
+        | DataType::FixedSizeBinary(_) => ValueCategoryV1::Binary,
+        DataType::FixedSizeList(item, _) if item.data_type().is_floating() => {
+            ValueCategoryV1::Vector
+        }
+        DataType::List(_) | DataType::LargeList(_) | DataType::FixedSizeList(_, _) => {
+            ValueCategoryV1::List
+        }
+        DataType::Struct(_) => ValueCategoryV1::Struct,
+        DataType::Dictionary(_, value) => category(value),
+        _ => ValueCategoryV1::Other,
+    }
+}
+
+pub fn operators(category: ValueCategoryV1) -> Vec<&'static str> {
+    let mut operators = Vec::new();
+    match category {
+        ValueCategoryV1::Numeric => {
+            operators.extend(ORDERING);
+            operators.extend(["BETWEEN", "NOT BETWEEN", "IN", "NOT IN"]);
+        }
+        ValueCategoryV1::String => {
+            operators.extend(ORDERING);
+            operators.extend(["LIKE", "NOT LIKE", "IN", "NOT IN"]);
+        }
+        ValueCategoryV1::Temporal => {
+            operators.extend(ORDERING);
+            operators.extend(["BETWEEN", "NOT BETWEEN"]);
+        }
+        ValueCategoryV1::Boolean => operators.extend(["=", "!=", "IS TRUE", "IS FALSE"]),
+        ValueCategoryV1::Binary => operators.extend(["=", "!="]),
+        ValueCategoryV1::List
+        | ValueCategoryV1::Vector
+        | ValueCategoryV1::Struct
+        | ValueCategoryV1::Other => {}
+    }
+    operators.extend(NULL_CHECKS);
+    operators
+}
+
+pub fn operator_table() -> Vec<CategoryOperatorsV1> {
+    [
+        ValueCategoryV1::Numeric,
+        ValueCategoryV1::String,
+        ValueCategoryV1::Boolean,
+        ValueCategoryV1::Temporal,
+        ValueCategoryV1::Binary,
+        ValueCategoryV1::List,
+        ValueCategoryV1::Vector,
+        ValueCategoryV1::Struct,
+        ValueCategoryV1::Other,
+    ]
+    .into_iter()
+    .map(|category| CategoryOperatorsV1 {
+        category,
+        operators: operators(category)
+            .into_iter()
+            .map(str::to_string)
+            .collect(),
+    })
+    .collect()
+}
+
+fn push_column(columns: &mut Vec<CompletionColumnV1>, field: &Field, parent: Option<(&str, &str)>) {
+    let quoted = projection::quote_identifier(field.name());
+    let (name, insert_text) = match parent {
+        Some((name, insert_text)) => (
+            format!("{name}.{}", field.name()),
+            format!("{insert_text}.{quoted}"),
+        ),
+        None => (field.name().clone(), quoted),
+    };
+    let category = category(field.data_type());
+    columns.push(CompletionColumnV1 {
+        name: name.clone(),
+        insert_text: insert_text.clone(),
+        data_type: field.data_type().to_string(),
+        category,
+        nullable: field.is_nullable(),
+    });
+    if let DataType::Struct(children) = field.data_type() {
+        for child in children {
+            push_column(columns, child, Some((&name, &insert_text)));
+        }
+    }
+}
+
+/// Columns a filter can reference, in display order. Struct fields are followed by
+/// their nested paths, e.g. `meta.source`.
+pub fn columns(schema: &Schema) -> Vec<CompletionColumnV1> {
+    let order = column_order::stored(schema).unwrap_or_else(|| {
+        schema
+            .fields()
+            .iter()
+            .map(|field| field.name().clone())
+            .collect()
+    });
+    let mut columns = Vec::new();
+    for name in order {
+        if let Ok(field) = schema.field_with_name(&name) {
+            push_column(&mut columns, field, None);
+        }
+    }
+    columns
+}
+
+fn function(
+    name: &str,
+    signature: &str,
+    description: &str,
+    applies_to: &[ValueCategoryV1],
+) -> SqlFunctionV1 {
+    SqlFunctionV1 {
+        name: name.to_string(),
+        signature: signature.to_string(),
+        description: description.to_string(),
+        applies_to: applies_to.to_vec(),
+    }
+}
+
+/// Scalar functions commonly used in lance filters. The filter parser is DataFusion,
+/// which accepts more; these are the ones worth suggesting. An empty `applies_to`
+/// means any column.
+pub fn functions() -> Vec<SqlFunctionV1> {
+    use ValueCategoryV1::{List, Numeric, Temporal, Vector};
+    let text = ValueCategoryV1::String;
+    vec![
+        function("lower", "lower(text)", "Lowercases a string.", &[text]),
+        function("upper", "upper(text)", "Uppercases a string.", &[text]),
+        function(
+            "character_length",
+            "character_length(text)",
+            "Number of characters in a string.",
+            &[text],
+        ),
+        function("trim", "trim(text)", "Strips surrounding spaces.", &[text]),
+        function(
+            "starts_with",
+            "starts_with(text, prefix)",
+            "Whether a string starts with a prefix.",
+            &[text],
+        ),
+        function(
+            "ends_with",
+            "ends_with(text, suffix)",
+            "Whether a string ends with a suffix.",
+            &[text],
+        ),
+        function(
+            "substr",
+            "substr(text, start, length)",
+            "Part of a string; `start` is 1-based.",
+            &[text],
+        ),
+        function(
+            "regexp_like",
+            "regexp_like(text, pattern)",
+            "Whether a string matches a regular expression.",
+            &[text],
+        ),
+        function("abs", "abs(number)", "Absolute value.", &[Numeric]),
+        function(
+            "round",
+            "round(number, digits)",
+            "Rounds to the given number of decimals.",
+            &[Numeric],
+        ),
+        function("floor", "floor(number)", "Rounds down.", &[Numeric]),
+        function("ceil", "ceil(number)", "Rounds up.", &[Numeric]),
+        function(
+            "date_trunc",
+            "date_trunc('day', timestamp)",
+            "Truncates a timestamp to a unit such as 'hour', 'day' or 'month'.",
+            &[Temporal],
+        ),
+        function(
+            "date_part",
+            "date_part('year', timestamp)",
+            "Extracts a field such as 'year', 'month' or 'dow'.",
+            &[Temporal],
+        ),
+        function("now", "now()", "Current timestamp.", &[Temporal]),
+        function(
+            "last_7_days",
+            "last_7_days(timestamp)",
+            "Rows from the last 7 days; also `last_<n>_minutes`, `_hours` and `_weeks`.",
+            &[Temporal],
+        ),
+        function(
+            "today",
+            "today(timestamp)",
+            "Rows from the current UTC day.",
+            &[Temporal],
+        ),
+        function(
+            "yesterday",
+            "yesterday(timestamp)",
+            "Rows from the previous UTC day.",
+            &[Temporal],
+        ),
+        function(
+            "array_has",
+            "array_has(list, value)",
+            "Whether a list contains a value.",
+            &[List, Vector],
+        ),
+        function(
+            "array_has_any",
+            "array_has_any(list, [values])",
+            "Whether a list contains any of the values.",
+            &[List, Vector],
+        ),
+        function(
+            "array_has_all",
+            "array_has_all(list, [values])",
+            "Whether a list contains all of the values.",
+            &[List, Vector],
+        ),
+        function(
+            "array_length",
+            "array_length(list)",
+            "Number of items in a list.",
+            &[List, Vector],
+        ),
+        function(
+            "array_element",
+            "array_element(list, 1)",
+            "Item at a 1-based position; `list[1]` is the same.",
+            &[List, Vector],
+        ),
+        function(
+            "make_array",
+            "make_array(value, ...)",
+            "Builds a list to compare with; the literal `[0.5, 1.0]` is the same.",
+            &[],
+        ),
+        function(
+            "coalesce",
+            "coalesce(value, fallback, ...)",
+            "First argument that is not null.",
+            &[],
+        ),
+    ]
+}
+
+/// Identifiers in a filter outside string literals. Backtick-quoted names keep
+/// their case; bare names are matched case-insensitively by the caller.
+fn identifiers(filter: &str) -> Vec<(String, bool)> {
+    let mut identifiers = Vec::new();
+    let mut chars = filter.chars().peekable();
+    while let Some(character) = chars.next() {
+        match character {
+            '\'' => {
+                for next in chars.by_ref() {
+                    if next == '\'' {
+                        break;
+                    }
+                }
+            }
+            '`' => {
+                let name = chars.by_ref().take_while(|next| *next != '`').collect();
+                identifiers.push((name, true));
+            }
+            character if character.is_alphanumeric() || character == '_' => {
+                let mut name = character.to_string();
+                while let Some(next) = chars.next_if(|next| next.is_alphanumeric() || *next == '_')
+                {
+                    name.push(next);
+                }
+                // Words starting with a digit are numbers such as `2e5`.
+                if !character.is_ascii_digit() {
+                    identifiers.push((name, false));
+                }
+            }
+            _ => {}
+        }
+    }
+    identifiers
+}
+
+/// Top-level vector columns a filter references, in schema order.
+pub fn vector_columns(schema: &Schema, filter: &str) -> Vec<String> {
+    let identifiers = identifiers(filter);
+    schema
+        .fields()
+        .iter()
+        .filter(|field| category(field.data_type()) == ValueCategoryV1::Vector)
+        .map(|field| field.name())
+        .filter(|name| {
+            identifiers.iter().any(|(identifier, quoted)| {
+                if *quoted {
+                    identifier == *name
+                } else {
+                    identifier.eq_ignore_ascii_case(name)
+                }
+            })
+        })
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_schema::Fields;
+
+    use super::*;
+
+    #[test]
+    fn lists_nested_columns_with_quoted_paths() {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new(
+                "vector",
+                DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Float32, true)), 3),
+                true,
+            ),
+            Field::new(
+                "meta",
+                DataType::Struct(Fields::from(vec![Field::new(
+                    "source file",
+                    DataType::Utf8,
+                    true,
+                )])),
+                true,
+            ),
+        ]);
+        let columns = columns(&schema);
+        let names = columns
+            .iter()
+            .map(|column| (column.name.as_str(), column.insert_text.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                ("id", "id"),
+                ("vector", "vector"),
+                ("meta", "meta"),
+                ("meta.source file", "meta.`source file`"),
+            ]
+        );
+        assert_eq!(columns[0].category, ValueCategoryV1::Numeric);
+        assert_eq!(columns[1].category, ValueCategoryV1::Vector);
+        assert_eq!(columns[3].category, ValueCategoryV1::String);
+    }
+
+    #[test]
+    fn offers_operators_by_category() {
+        assert!(operators(ValueCategoryV1::String).contains(&"LIKE"));
+        assert!(!operators(ValueCategoryV1::Numeric).contains(&"LIKE"));
+        assert_eq!(
+            operators(ValueCategoryV1::Vector),
+            vec!["IS NULL", "IS NOT NULL"]
+        );
+        assert_eq!(
+            category(&DataType::Dictionary(
+                Box::new(DataType::Int32),
+                Box::new(DataType::Utf8)
+            )),
+            ValueCategoryV1::String
+        );
+        assert_eq!(operator_table().len(), 9);
+    }
+
+    #[test]
+    fn finds_vector_columns_outside_string_literals() {
+        let vector = |name: &str| {
+            Field::new(
+                name,
+                DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Float32, true)), 3),
+                true,
+            )
+        };
+        let schema = Schema::new(vec![
+            Field::new("text", DataType::Utf8, true),
+            vector("vector"),
+            vector("Embedding 2"),
+        ]);
+        assert_eq!(
+            vector_columns(&schema, "array_length(VECTOR) = 3 AND text = 'Embedding 2'"),
+            vec!["vector"]
+        );
+        assert_eq!(
+            vector_columns(&schema, "`Embedding 2`[1] > 0.5 AND text = 'vector'"),
+            vec!["Embedding 2"]
+        );
+        assert!(vector_columns(&schema, "text LIKE '%vector%' AND 2e5 > 1").is_empty());
+        assert!(functions()
+            .iter()
+            .any(|function| function.name == "array_length"
+                && function.applies_to.contains(&ValueCategoryV1::Vector)));
+    }
+}
@@ -0,0 +1,241 @@
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::ipc::v1::{AppConfigV1, LocaleV1, LogLevelV1, RoleV1};
+
+/// Directory under the user's home that holds `config.toml`.
+pub const CONFIG_DIR: &str = ".lancedb-viewer";
+pub const CONFIG_FILE: &str = "config.toml";
+
+pub use crate::ipc::v1::DEFAULT_PAGE_SIZE;
+const MAX_PAGE_SIZE: usize = 10_000;
+const MAX_PREFETCH_CACHE_PAGES: usize = 1_024;
+
+/// On-disk layout of `config.toml`. Keys are snake_case so the file reads like a
+/// regular TOML config; missing keys fall back to the defaults.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+struct ConfigFile {
+    default_page_size: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_timeout_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    connect_timeout_ms: Option<u64>,
+    prefetch_cache_pages: usize,
+    prefetch_ttl_seconds: u64,
+    read_only_default: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    log_level: Option<LogLevelV1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    job_webhook_url: Option<String>,
+    role: RoleV1,
+    auto_checkpoints: bool,
+    locale: LocaleV1,
+}
+
+impl Default for ConfigFile {
+    fn default() -> Self {
+        AppConfigV1::default().into()
+    }
+}
+
+impl From<AppConfigV1> for ConfigFile {
+    fn from(config: AppConfigV1) -> Self {
+        Self {
+            default_page_size: config.default_page_size,
+            query_timeout_ms: config.query_timeout_ms,
+            connect_timeout_ms: config.connect_timeout_ms,
+            prefetch_cache_pages: config.prefetch_cache_pages,
+            prefetch_ttl_seconds: config.prefetch_ttl_seconds,
+            read_only_default: config.read_only_default,
+            log_level: config.log_level,
+            job_webhook_url: config.job_webhook_url,
+            role: config.role,
+            auto_checkpoints: config.auto_checkpoints,
+            locale: config.locale,
+        }
+    }
+}
+
+impl From<ConfigFile> for AppConfigV1 {
+    fn from(file: ConfigFile) -> Self {
+        Self {
+            default_page_size: file.default_page_size,
+            query_timeout_ms: file.query_timeout_ms,
+            connect_timeout_ms: file.connect_timeout_ms,
+            prefetch_cache_pages: file.prefetch_cache_pages,
+            prefetch_ttl_seconds: file.prefetch_ttl_seconds,
+            read_only_default: file.read_only_default,
+            log_level: file.log_level,
+            job_webhook_url: file.job_webhook_url,
+            role: file.role,
+            auto_checkpoints: file.auto_checkpoints,
+            locale: file.locale,
+        }
+    }
+}
+
+pub fn validate_config(config: &AppConfigV1) -> Result<(), String> {
+    if config.default_page_size == 0 || config.default_page_size > MAX_PAGE_SIZE {
+        return Err(format!(
+            "defaultPageSize must be between 1 and {MAX_PAGE_SIZE}"
+        ));
+    }
+    if config.prefetch_cache_pages == 0 || config.prefetch_cache_pages > MAX_PREFETCH_CACHE_PAGES {
+        return Err(format!(
+            "prefetchCachePages must be between 1 and {MAX_PREFETCH_CACHE_PAGES}"
+        ));
+    }
+    if config.query_timeout_ms == Some(0) {
+        return Err("queryTimeoutMs must be greater than 0".to_string());
+    }
+    if config.connect_timeout_ms == Some(0) {
+        return Err("connectTimeoutMs must be greater than 0".to_string());
+    }
+    if let Some(webhook) = &config.job_webhook_url {
+        let url = url::Url::parse(webhook.trim())
+            .map_err(|error| format!("invalid jobWebhookUrl: {error}"))?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err("jobWebhookUrl must be an http or https URL".to_string());
+        }
+    }
+    Ok(())
+}
+
+pub fn parse_config(text: &str) -> Result<AppConfigV1, String> {
+    let config: AppConfigV1 = toml::from_str::<ConfigFile>(text)
+        .map_err(|error| error.to_string())?
+        .into();
+    validate_config(&config)?;
+    Ok(config)
+}
+
+fn load_config(path: &Path) -> Result<AppConfigV1, String> {
+    if !path.exists() {
+        return Ok(AppConfigV1::default());
+    }
+    let text = fs::read_to_string(path).map_err(|error| error.to_string())?;
+    parse_config(&text).map_err(|error| format!("invalid config in {}: {error}", path.display()))
+}
+
+/// Writes through a temporary file, like `store::save_json`.
+fn save_config(path: &Path, config: &AppConfigV1) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|error| error.to_string())?;
+    }
+    let text = toml::to_string_pretty(&ConfigFile::from(config.clone()))
+        .map_err(|error| error.to_string())?;
+    let temp_path = path.with_extension("toml.tmp");
+    {
+        let mut file = File::create(&temp_path).map_err(|error| error.to_string())?;
+        file.write_all(text.as_bytes())
+            .map_err(|error| error.to_string())?;
+        file.flush().map_err(|error| error.to_string())?;
+    }
+    fs::rename(&temp_path, path).map_err(|error| error.to_string())
+}
+
+/// Settings loaded from `~/.lancedb-viewer/config.toml`. The file is optional; an
+/// invalid one is reported and ignored so the app still starts with defaults.
+#[derive(Default)]
+pub struct ConfigStore {
+    path: Option<PathBuf>,
+    config: AppConfigV1,
+}
+
+impl ConfigStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load(path: PathBuf) -> Self {
+        let config = match load_config(&path) {
+            Ok(config) => config,
+            Err(error) => {
+                warn!("failed to load config path={:?} error={}", path, error);
+                AppConfigV1::default()
+            }
+        };
+        Self {
+            path: Some(path),
+            config,
+        }
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn get(&self) -> AppConfigV1 {
+        self.config.clone()
+    }
+
+    pub fn role(&self) -> RoleV1 {
+        self.config.role
+    }
+
+    pub fn locale(&self) -> LocaleV1 {
+        self.config.locale
+    }
+
+    pub fn set(&mut self, config: AppConfigV1) -> Result<(), String> {
+        validate_config(&config)?;
+        if let Some(path) = self.path.as_ref() {
+            save_config(path, &config)?;
+        }
+        self.config = config;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::prefetch::DEFAULT_MAX_PREFETCHED_PAGES;
+
+    #[test]
+    fn parses_partial_config_with_defaults() {
+        let config = parse_config(
+            "default_page_size = 250\nquery_timeout_ms = 5000\nread_only_default = true\nlog_level = \"debug\"\n",
+        )
+        .expect("parse config");
+        assert_eq!(config.default_page_size, 250);
+        assert_eq!(config.query_timeout_ms, Some(5000));
+        assert!(config.read_only_default);
+        assert_eq!(config.log_level, Some(LogLevelV1::Debug));
+        assert_eq!(config.prefetch_cache_pages, DEFAULT_MAX_PREFETCHED_PAGES);
+        assert_eq!(config.connect_timeout_ms, None);
+        assert_eq!(config.role, RoleV1::Admin);
+        assert_eq!(config.locale, LocaleV1::En);
+
+        let text = toml::to_string_pretty(&ConfigFile::from(config.clone())).expect("serialize");
+        assert!(text.contains("default_page_size = 250"));
+        assert_eq!(
+            parse_config(&text).expect("round trip").default_page_size,
+            250
+        );
+
+        assert_eq!(
+            parse_config("locale = \"zh_cn\"").expect("locale").locale,
+            LocaleV1::ZhCn
+        );
+        assert!(parse_config("default_page_size = 0").is_err());
+        assert!(parse_config("default_page_size = \"ten\"").is_err());
+        assert!(parse_config("job_webhook_url = \"https://hooks.example.com/jobs\"").is_ok());
+        assert!(parse_config("job_webhook_url = \"ftp://hooks.example.com\"").is_err());
+        assert_eq!(
+            parse_config("role = \"viewer\"").expect("role").role,
+            RoleV1::Viewer
+        );
+        assert!(parse_config("role = \"owner\"").is_err());
+        assert!(
+            parse_config("auto_checkpoints = true")
+                .expect("checkpoints")
+                .auto_checkpoints
+        );
+    }
+}
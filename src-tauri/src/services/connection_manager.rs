@@ -5,15 +5,32 @@ use uuid::Uuid;
 
 #[derive(Default)]
 pub struct ConnectionManager {
-    connections: HashMap<String, Connection>,
+    connections: HashMap<String, StoredConnection>,
     tables: HashMap<String, StoredTable>,
 }
 
+#[derive(Clone)]
+struct StoredConnection {
+    connection: Connection,
+    storage_options: HashMap<String, String>,
+    read_only: bool,
+}
+
 #[derive(Clone)]
 struct StoredTable {
     name: String,
     table: Table,
     connection_id: String,
+    /// Version the handle was checked out at, or `None` when it follows the latest.
+    checked_out_version: Option<u64>,
+}
+
+/// A table handle opened from a connection, as `connection_tables` lists it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectionTable {
+    pub table_id: String,
+    pub name: String,
+    pub checked_out_version: Option<u64>,
 }
 
 impl ConnectionManager {
@@ -21,14 +38,127 @@ impl ConnectionManager {
         Self::default()
     }
 
-    pub fn insert_connection(&mut self, connection: Connection) -> String {
+    pub fn insert_connection(
+        &mut self,
+        connection: Connection,
+        storage_options: HashMap<String, String>,
+        read_only: bool,
+    ) -> String {
         let id = Uuid::new_v4().to_string();
-        self.connections.insert(id.clone(), connection);
+        self.connections.insert(
+            id.clone(),
+            StoredConnection {
+                connection,
+                storage_options,
+                read_only,
+            },
+        );
         id
     }
 
+    pub fn connection_count(&self) -> usize {
+        self.connections.len()
+    }
+
+    pub fn table_count(&self) -> usize {
+        self.tables.len()
+    }
+
+    pub fn connection_ids(&self) -> Vec<String> {
+        self.connections.keys().cloned().collect()
+    }
+
+    /// Number of table handles opened from a connection.
+    pub fn connection_table_count(&self, connection_id: &str) -> usize {
+        self.tables
+            .values()
+            .filter(|entry| entry.connection_id == connection_id)
+            .count()
+    }
+
     pub fn get_connection(&self, connection_id: &str) -> Option<Connection> {
-        self.connections.get(connection_id).cloned()
+        self.connections
+            .get(connection_id)
+            .map(|entry| entry.connection.clone())
+    }
+
+    /// Storage options (including inline auth params) the connection was opened with.
+    pub fn get_storage_options(&self, connection_id: &str) -> Option<HashMap<String, String>> {
+        self.connections
+            .get(connection_id)
+            .map(|entry| entry.storage_options.clone())
+    }
+
+    pub fn is_read_only(&self, connection_id: &str) -> bool {
+        self.connections
+            .get(connection_id)
+            .is_some_and(|entry| entry.read_only)
+    }
+
+    /// Whether the connection that opened `table_id` rejects writes.
+    pub fn is_table_read_only(&self, table_id: &str) -> bool {
+        self.tables
+            .get(table_id)
+            .is_some_and(|entry| self.is_read_only(&entry.connection_id))
+    }
+
+    /// Every live connection opened to `uri`.
+    pub fn connection_ids_for_uri(&self, uri: &str) -> Vec<String> {
+        self.connections
+            .iter()
+            .filter(|(_, entry)| entry.connection.uri() == uri)
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+
+    /// The table handles opened from a connection.
+    pub fn connection_tables(&self, connection_id: &str) -> Vec<ConnectionTable> {
+        self.tables
+            .iter()
+            .filter(|(_, entry)| entry.connection_id == connection_id)
+            .map(|(id, entry)| ConnectionTable {
+                table_id: id.clone(),
+                name: entry.name.clone(),
+                checked_out_version: entry.checked_out_version,
+            })
+            .collect()
+    }
+
+    /// Records the version a table handle was checked out at, so handles reopened
+    /// for the same table stay on it. `None` means the handle follows the latest.
+    pub fn set_checked_out_version(&mut self, table_id: &str, version: Option<u64>) {
+        if let Some(entry) = self.tables.get_mut(table_id) {
+            entry.checked_out_version = version;
+        }
+    }
+
+    /// Swaps the connection and the given table handles under their existing ids,
+    /// e.g. after its credentials changed. Returns false if the connection is gone.
+    pub fn replace_connection(
+        &mut self,
+        connection_id: &str,
+        connection: Connection,
+        storage_options: HashMap<String, String>,
+        tables: Vec<(String, Table)>,
+    ) -> bool {
+        let Some(entry) = self.connections.get_mut(connection_id) else {
+            return false;
+        };
+        entry.connection = connection;
+        entry.storage_options = storage_options;
+        for (table_id, table) in tables {
+            if let Some(entry) = self.tables.get_mut(&table_id) {
+                entry.table = table;
+            }
+        }
+        true
+    }
+
+    pub fn find_connection_by_uri(&self, uri: &str) -> Option<(String, Connection)> {
+        self.connections
+            .iter()
+            .find(|(_, entry)| entry.connection.uri() == uri)
+            .map(|(id, entry)| (id.clone(), entry.connection.clone()))
     }
 
     pub fn insert_table(&mut self, name: String, table: Table, connection_id: String) -> String {
@@ -39,11 +169,16 @@ impl ConnectionManager {
                 name,
                 table,
                 connection_id,
+                checked_out_version: None,
             },
         );
         id
     }
 
+    pub fn table_ids(&self) -> Vec<String> {
+        self.tables.keys().cloned().collect()
+    }
+
     pub fn get_table(&self, table_id: &str) -> Option<Table> {
         self.tables.get(table_id).map(|entry| entry.table.clone())
     }
@@ -52,6 +187,31 @@ impl ConnectionManager {
         self.tables.get(table_id).map(|entry| entry.name.clone())
     }
 
+    pub fn get_table_connection_id(&self, table_id: &str) -> Option<String> {
+        self.tables
+            .get(table_id)
+            .map(|entry| entry.connection_id.clone())
+    }
+
+    /// URI of the connection a table was opened from.
+    pub fn get_table_connection_uri(&self, table_id: &str) -> Option<String> {
+        self.tables.get(table_id).and_then(|entry| {
+            self.connections
+                .get(&entry.connection_id)
+                .map(|connection| connection.connection.uri().to_string())
+        })
+    }
+
+    pub fn get_table_storage_options(&self, table_id: &str) -> Option<HashMap<String, String>> {
+        self.tables
+            .get(table_id)
+            .and_then(|entry| self.get_storage_options(&entry.connection_id))
+    }
+
+    pub fn remove_table(&mut self, table_id: &str) -> bool {
+        self.tables.remove(table_id).is_some()
+    }
+
     pub fn remove_connection(&mut self, connection_id: &str) -> Option<usize> {
         if self.connections.remove(connection_id).is_none() {
             return None;
@@ -61,4 +221,13 @@ impl ConnectionManager {
             .retain(|_, entry| entry.connection_id != connection_id);
         Some(before.saturating_sub(self.tables.len()))
     }
+
+    /// Drops every cached connection and table handle, returning how many of each
+    /// were open.
+    pub fn close_all(&mut self) -> (usize, usize) {
+        let counts = (self.connections.len(), self.tables.len());
+        self.tables.clear();
+        self.connections.clear();
+        counts
+    }
 }
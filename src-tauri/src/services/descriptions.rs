@@ -0,0 +1,188 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::ipc::v1::TableDescriptionV1;
+use crate::services::store;
+
+pub const DESCRIPTIONS_FILE: &str = "table_descriptions.json";
+
+const MAX_DESCRIPTION_CHARS: usize = 4_000;
+const MAX_TAGS: usize = 64;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DescriptionEntry {
+    connection_uri: String,
+    table_name: String,
+    #[serde(flatten)]
+    metadata: TableDescriptionV1,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DescriptionFile {
+    #[serde(default)]
+    tables: Vec<DescriptionEntry>,
+}
+
+/// Table descriptions, owners and tags keyed by connection URI and table name,
+/// persisted to `table_descriptions.json` in the app data directory when one is
+/// configured.
+#[derive(Default)]
+pub struct DescriptionStore {
+    path: Option<PathBuf>,
+    entries: Vec<DescriptionEntry>,
+}
+
+impl DescriptionStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load(path: PathBuf) -> Self {
+        let entries = match store::load_json::<DescriptionFile>(&path) {
+            Ok(file) => file.tables,
+            Err(error) => {
+                warn!(
+                    "failed to load table descriptions path={:?} error={}",
+                    path, error
+                );
+                Vec::new()
+            }
+        };
+        Self {
+            path: Some(path),
+            entries,
+        }
+    }
+
+    pub fn get(&self, connection_uri: &str, table_name: &str) -> Option<TableDescriptionV1> {
+        self.entries
+            .iter()
+            .find(|entry| entry.connection_uri == connection_uri && entry.table_name == table_name)
+            .map(|entry| entry.metadata.clone())
+    }
+
+    /// Descriptions of every table under `connection_uri`, keyed by table name.
+    pub fn for_connection(&self, connection_uri: &str) -> HashMap<String, TableDescriptionV1> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.connection_uri == connection_uri)
+            .map(|entry| (entry.table_name.clone(), entry.metadata.clone()))
+            .collect()
+    }
+
+    /// Replaces the description of a table; `None` removes it.
+    pub fn set(
+        &mut self,
+        connection_uri: &str,
+        table_name: &str,
+        metadata: Option<TableDescriptionV1>,
+    ) -> Result<(), String> {
+        self.entries.retain(|entry| {
+            entry.connection_uri != connection_uri || entry.table_name != table_name
+        });
+        if let Some(metadata) = metadata {
+            self.entries.push(DescriptionEntry {
+                connection_uri: connection_uri.to_string(),
+                table_name: table_name.to_string(),
+                metadata,
+            });
+        }
+        self.persist()
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        let Some(path) = self.path.as_ref() else {
+            return Ok(());
+        };
+        store::save_json(
+            path,
+            &DescriptionFile {
+                tables: self.entries.clone(),
+            },
+        )
+    }
+}
+
+fn trimmed(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// Trims text fields and tag keys, dropping blanks. Returns `None` when nothing is left
+/// so an empty description clears the entry.
+pub fn normalize(metadata: TableDescriptionV1) -> Result<Option<TableDescriptionV1>, String> {
+    let description = trimmed(metadata.description);
+    if description
+        .as_ref()
+        .is_some_and(|value| value.chars().count() > MAX_DESCRIPTION_CHARS)
+    {
+        return Err(format!(
+            "description cannot exceed {MAX_DESCRIPTION_CHARS} characters"
+        ));
+    }
+    let tags = metadata
+        .tags
+        .into_iter()
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .filter(|(key, _)| !key.is_empty())
+        .collect::<BTreeMap<_, _>>();
+    if tags.len() > MAX_TAGS {
+        return Err(format!("a table cannot have more than {MAX_TAGS} tags"));
+    }
+
+    let normalized = TableDescriptionV1 {
+        description,
+        owner: trimmed(metadata.owner),
+        tags,
+        updated_at_ms: metadata.updated_at_ms,
+    };
+    if normalized.description.is_none() && normalized.owner.is_none() && normalized.tags.is_empty()
+    {
+        return Ok(None);
+    }
+    Ok(Some(normalized))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_and_clears_descriptions() {
+        let normalized = normalize(TableDescriptionV1 {
+            description: Some("  Product embeddings ".to_string()),
+            owner: Some("   ".to_string()),
+            tags: BTreeMap::from([
+                (" team ".to_string(), " search ".to_string()),
+                (" ".to_string(), "dropped".to_string()),
+            ]),
+            updated_at_ms: None,
+        })
+        .expect("normalize")
+        .expect("description");
+        assert_eq!(
+            normalized.description.as_deref(),
+            Some("Product embeddings")
+        );
+        assert_eq!(normalized.owner, None);
+        assert_eq!(
+            normalized.tags,
+            BTreeMap::from([("team".to_string(), "search".to_string())])
+        );
+
+        let empty = normalize(TableDescriptionV1 {
+            description: Some(" ".to_string()),
+            owner: None,
+            tags: BTreeMap::new(),
+            updated_at_ms: None,
+        })
+        .expect("normalize");
+        assert!(empty.is_none());
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use url::Url;
+
+use crate::ipc::v1::{CommandMetricsV1, ErrorEnvelope};
+
+/// Start of the log file names in the app log directory. The log plugin appends its
+/// own extension and rotation suffixes to the name set in `lib.rs`.
+pub const LOG_FILE_PREFIX: &str = "lancedb-viewer";
+pub const DEFAULT_LOG_LINES: usize = 500;
+pub const MAX_LOG_LINES: usize = 5_000;
+/// How much of the end of the log file is read for the tail.
+const LOG_TAIL_BYTES: u64 = 4 * 1024 * 1024;
+
+/// Calls, failures and latency per command name since the app started.
+#[derive(Default)]
+pub struct CommandMetrics {
+    commands: HashMap<&'static str, CommandMetricsV1>,
+}
+
+impl CommandMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(
+        &mut self,
+        command: &'static str,
+        elapsed: Duration,
+        error: Option<&ErrorEnvelope>,
+    ) {
+        let entry = self
+            .commands
+            .entry(command)
+            .or_insert_with(|| CommandMetricsV1 {
+                command: command.to_string(),
+                calls: 0,
+                errors: 0,
+                total_ms: 0,
+                max_ms: 0,
+                last_error_code: None,
+            });
+        let elapsed_ms = elapsed.as_millis() as u64;
+        entry.calls += 1;
+        entry.total_ms += elapsed_ms;
+        entry.max_ms = entry.max_ms.max(elapsed_ms);
+        if let Some(error) = error {
+            entry.errors += 1;
+            entry.last_error_code = Some(error.code.clone());
+        }
+    }
+
+    /// Commands with the most time spent first.
+    pub fn list(&self) -> Vec<CommandMetricsV1> {
+        let mut commands = self.commands.values().cloned().collect::<Vec<_>>();
+        commands.sort_by(|left, right| {
+            right
+                .total_ms
+                .cmp(&left.total_ms)
+                .then_with(|| left.command.cmp(&right.command))
+        });
+        commands
+    }
+}
+
+/// `uri` without user info, query or fragment, where signed URLs and inline
+/// credentials would sit. Local paths are returned unchanged.
+pub fn redact_uri(uri: &str) -> String {
+    match Url::parse(uri) {
+        Ok(mut url) if url.has_host() => {
+            let _ = url.set_username("");
+            let _ = url.set_password(None);
+            url.set_query(None);
+            url.set_fragment(None);
+            url.to_string()
+        }
+        _ => uri.to_string(),
+    }
+}
+
+/// The most recently written log file in `log_dir`.
+fn latest_log_file(log_dir: &Path) -> Option<PathBuf> {
+    fs::read_dir(log_dir)
+        .ok()?
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            name.starts_with(LOG_FILE_PREFIX) && name.ends_with(".log")
+        })
+        .filter_map(|entry| {
+            let modified = entry.metadata().ok()?.modified().ok()?;
+            Some((modified, entry.path()))
+        })
+        .max()
+        .map(|(_, path)| path)
+}
+
+/// The last `lines` lines of the app log. Empty when nothing was logged yet.
+pub fn tail_log(log_dir: &Path, lines: usize) -> Result<Vec<String>, String> {
+    let Some(path) = latest_log_file(log_dir) else {
+        return Ok(Vec::new());
+    };
+    let mut file = File::open(&path).map_err(|error| error.to_string())?;
+    let len = file.metadata().map_err(|error| error.to_string())?.len();
+    let start = len.saturating_sub(LOG_TAIL_BYTES);
+    file.seek(SeekFrom::Start(start))
+        .map_err(|error| error.to_string())?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)
+        .map_err(|error| error.to_string())?;
+    let text = String::from_utf8_lossy(&bytes);
+    let mut tail = text.lines().collect::<Vec<_>>();
+    // Reading from the middle of the file starts inside a line.
+    if start > 0 && !tail.is_empty() {
+        tail.remove(0);
+    }
+    let skip = tail.len().saturating_sub(lines);
+    Ok(tail[skip..].iter().map(|line| line.to_string()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ipc::v1::{ErrorCode, ResultEnvelope};
+
+    use super::*;
+
+    #[test]
+    fn records_metrics_redacts_uris_and_tails_logs() {
+        let mut metrics = CommandMetrics::new();
+        metrics.record("scan_v1", Duration::from_millis(5), None);
+        let error = ResultEnvelope::<()>::err(ErrorCode::NotFound, "missing")
+            .error
+            .expect("error");
+        metrics.record("scan_v1", Duration::from_millis(20), Some(&error));
+        metrics.record("health_v1", Duration::from_millis(1), None);
+        let listed = metrics.list();
+        assert_eq!(listed[0].command, "scan_v1");
+        assert_eq!((listed[0].calls, listed[0].errors), (2, 1));
+        assert_eq!((listed[0].total_ms, listed[0].max_ms), (25, 20));
+        assert_eq!(listed[0].last_error_code, Some(ErrorCode::NotFound));
+
+        assert_eq!(
+            redact_uri("s3://key:secret@bucket/path?X-Amz-Signature=abc"),
+            "s3://bucket/path"
+        );
+        assert_eq!(redact_uri("/data/lance"), "/data/lance");
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        assert!(tail_log(dir.path(), 2).expect("empty").is_empty());
+        fs::write(dir.path().join("lancedb-viewer.log"), "one\ntwo\nthree\n").expect("log");
+        assert_eq!(tail_log(dir.path(), 2).expect("tail"), vec!["two", "three"]);
+    }
+}
@@ -0,0 +1,63 @@
+use std::io;
+use std::path::Path;
+
+use crate::ipc::v1::DiskSpaceShortageV1;
+
+/// Space kept free on top of every estimate, for manifests, index files and the
+/// file system's own bookkeeping.
+const HEADROOM_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Free bytes on the volume holding `path`. The path itself may not exist yet, as
+/// with an export target, so the nearest existing directory above it is measured.
+pub fn available(path: &Path) -> io::Result<u64> {
+    let path = std::path::absolute(path)?;
+    let directory = path
+        .ancestors()
+        .find(|ancestor| ancestor.is_dir())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no existing parent directory"))?;
+    fs4::available_space(directory)
+}
+
+/// Bytes a write of about `estimate` bytes needs free: the estimate plus a tenth for
+/// encoding overhead, plus the fixed headroom.
+pub fn required(estimate: u64) -> u64 {
+    estimate
+        .saturating_add(estimate / 10)
+        .saturating_add(HEADROOM_BYTES)
+}
+
+/// Compares the free space at `path` with what writing `estimate` bytes needs.
+/// When the free space cannot be read the check passes; the write itself still
+/// fails on a full disk.
+pub fn check(path: &Path, estimate: u64) -> Result<(), DiskSpaceShortageV1> {
+    let Ok(available) = available(path) else {
+        return Ok(());
+    };
+    let needed = required(estimate);
+    if available >= needed {
+        return Ok(());
+    }
+    Err(DiskSpaceShortageV1 {
+        path: path.display().to_string(),
+        needed_bytes: needed,
+        available_bytes: available,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_shortages_with_needed_and_free_bytes() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let target = dir.path().join("missing").join("export.csv");
+        assert!(available(&target).expect("free space") > 0);
+        assert!(check(&target, 0).is_ok());
+
+        let shortage = check(&target, u64::MAX / 2).expect_err("shortage");
+        assert_eq!(shortage.needed_bytes, required(u64::MAX / 2));
+        assert!(shortage.available_bytes < shortage.needed_bytes);
+        assert!(shortage.path.ends_with("export.csv"));
+    }
+}
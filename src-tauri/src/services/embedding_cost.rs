@@ -0,0 +1,136 @@
+use arrow_array::{Array, ArrayRef, StringArray};
+use arrow_schema::DataType;
+
+use crate::ipc::v1::EmbeddingProviderV1;
+
+/// ASCII characters per token of BPE tokenizers on English text.
+const ASCII_CHARS_PER_TOKEN: u64 = 4;
+
+/// Approximate token count of `text`: one token per four ASCII characters and one
+/// per other character, since CJK text tokenizes close to a token per character.
+pub fn approx_tokens(text: &str) -> u64 {
+    let (ascii, other) = text
+        .chars()
+        .fold((0u64, 0u64), |(ascii, other), character| {
+            if character.is_ascii() {
+                (ascii + 1, other)
+            } else {
+                (ascii, other + 1)
+            }
+        });
+    ascii.div_ceil(ASCII_CHARS_PER_TOKEN) + other
+}
+
+/// Checks the caller's pricing before the table is scanned.
+pub fn validate(provider: &EmbeddingProviderV1) -> Result<(), String> {
+    if provider.model.trim().is_empty() {
+        return Err("embedding model cannot be empty".to_string());
+    }
+    if !(provider.price_per_million_tokens.is_finite() && provider.price_per_million_tokens >= 0.0)
+    {
+        return Err("price per million tokens must be zero or a positive number".to_string());
+    }
+    if provider.tokens_per_minute == Some(0) {
+        return Err("tokens per minute must be positive".to_string());
+    }
+    if provider.max_tokens_per_input == Some(0) {
+        return Err("max tokens per input must be positive".to_string());
+    }
+    Ok(())
+}
+
+/// Running totals over the text values an embedding run would send.
+#[derive(Debug, Default)]
+pub struct TokenTally {
+    pub rows: u64,
+    pub skipped_rows: u64,
+    pub chars: u64,
+    pub tokens: u64,
+    pub truncated_rows: u64,
+}
+
+impl TokenTally {
+    pub fn add(&mut self, text: Option<&str>, max_tokens: Option<u64>) {
+        let Some(text) = text.filter(|text| !text.trim().is_empty()) else {
+            self.skipped_rows += 1;
+            return;
+        };
+        let mut tokens = approx_tokens(text);
+        if let Some(max_tokens) = max_tokens.filter(|max_tokens| tokens > *max_tokens) {
+            tokens = max_tokens;
+            self.truncated_rows += 1;
+        }
+        self.rows += 1;
+        self.chars += text.chars().count() as u64;
+        self.tokens += tokens;
+    }
+
+    /// Adds every value of a string column.
+    pub fn add_array(&mut self, array: &ArrayRef, max_tokens: Option<u64>) -> Result<(), String> {
+        let values = arrow_cast::cast(array, &DataType::Utf8).map_err(|error| error.to_string())?;
+        let values = values
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .ok_or_else(|| "text column does not hold strings".to_string())?;
+        for index in 0..values.len() {
+            let text = (!values.is_null(index)).then(|| values.value(index));
+            self.add(text, max_tokens);
+        }
+        Ok(())
+    }
+
+    pub fn cost(&self, provider: &EmbeddingProviderV1) -> f64 {
+        self.tokens as f64 / 1_000_000.0 * provider.price_per_million_tokens
+    }
+
+    pub fn seconds(&self, provider: &EmbeddingProviderV1) -> Option<f64> {
+        provider
+            .tokens_per_minute
+            .map(|per_minute| self.tokens as f64 / per_minute as f64 * 60.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[test]
+    fn tallies_tokens_cost_and_time() {
+        assert_eq!(approx_tokens("lance"), 2);
+        assert_eq!(approx_tokens("向量数据"), 4);
+        assert_eq!(approx_tokens(""), 0);
+
+        let provider = EmbeddingProviderV1 {
+            name: "openai".to_string(),
+            model: "text-embedding-3-small".to_string(),
+            price_per_million_tokens: 0.02,
+            tokens_per_minute: Some(1_000),
+            max_tokens_per_input: Some(4),
+        };
+        assert!(validate(&provider).is_ok());
+        let array: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("item 1"),
+            None,
+            Some("  "),
+            Some("a much longer value"),
+        ]));
+        let mut tally = TokenTally::default();
+        tally
+            .add_array(&array, provider.max_tokens_per_input)
+            .expect("tally");
+        assert_eq!(tally.rows, 2);
+        assert_eq!(tally.skipped_rows, 2);
+        assert_eq!(tally.truncated_rows, 1);
+        assert_eq!(tally.tokens, 6);
+        assert!((tally.seconds(&provider).expect("seconds") - 0.36).abs() < 1e-9);
+        assert!((tally.cost(&provider) - 0.000_000_12).abs() < 1e-12);
+
+        let negative = EmbeddingProviderV1 {
+            price_per_million_tokens: -1.0,
+            ..provider
+        };
+        assert!(validate(&negative).is_err());
+    }
+}
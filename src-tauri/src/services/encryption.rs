@@ -0,0 +1,625 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use arrow_array::cast::AsArray;
+use arrow_array::{ArrayRef, GenericStringArray, OffsetSizeTrait, RecordBatch};
+use arrow_schema::DataType;
+use base64::{engine::general_purpose, Engine as _};
+use log::warn;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::ipc::v1::EncryptedColumnV1;
+use crate::services::{checksum, store};
+
+pub const ENCRYPTED_COLUMNS_FILE: &str = "encrypted_columns.json";
+/// Marks encrypted values, followed by base64 of the nonce and the ciphertext.
+const CIPHERTEXT_PREFIX: &str = "lvenc:v1:";
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EncryptedColumnsFile {
+    #[serde(default)]
+    connections: HashMap<String, Vec<EncryptedColumnV1>>,
+}
+
+/// Encrypted columns keyed by connection URI, persisted to `encrypted_columns.json`
+/// in the app data directory when one is configured. Keys are never persisted: the
+/// frontend reads them from the stronghold vault and unlocks them for the session.
+#[derive(Default)]
+pub struct EncryptionStore {
+    path: Option<PathBuf>,
+    columns: HashMap<String, Vec<EncryptedColumnV1>>,
+    keys: HashMap<String, Aes256Gcm>,
+}
+
+impl EncryptionStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load(path: PathBuf) -> Self {
+        let columns = match store::load_json::<EncryptedColumnsFile>(&path) {
+            Ok(file) => file.connections,
+            Err(error) => {
+                warn!(
+                    "failed to load encrypted columns path={:?} error={}",
+                    path, error
+                );
+                HashMap::new()
+            }
+        };
+        Self {
+            path: Some(path),
+            columns,
+            keys: HashMap::new(),
+        }
+    }
+
+    pub fn list(&self, connection_uri: &str) -> Vec<EncryptedColumnV1> {
+        self.columns
+            .get(connection_uri)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Replaces every encrypted column of a connection. An empty list clears them.
+    /// A column sent without a column id keeps the one saved for it, so a client
+    /// that drops the field doesn't lose the ciphertext of a renamed column.
+    pub fn set(
+        &mut self,
+        connection_uri: &str,
+        mut columns: Vec<EncryptedColumnV1>,
+    ) -> Result<(), String> {
+        let saved = self.list(connection_uri);
+        for column in columns
+            .iter_mut()
+            .filter(|column| column.column_id.is_none())
+        {
+            column.column_id = saved
+                .iter()
+                .find(|entry| {
+                    entry.table_name == column.table_name && entry.column == column.column
+                })
+                .and_then(|entry| entry.column_id.clone());
+        }
+        if columns.is_empty() {
+            self.columns.remove(connection_uri);
+        } else {
+            self.columns.insert(connection_uri.to_string(), columns);
+        }
+        self.persist()
+    }
+
+    /// Unlocks `key_id` with a base64 encoded 256-bit key, or locks it again.
+    pub fn set_key(&mut self, key_id: &str, key: Option<&str>) -> Result<(), String> {
+        let Some(key) = key else {
+            self.keys.remove(key_id);
+            return Ok(());
+        };
+        let bytes = general_purpose::STANDARD
+            .decode(key.trim())
+            .map_err(|_| "encryption key must be base64".to_string())?;
+        let cipher = Aes256Gcm::new_from_slice(&bytes)
+            .map_err(|_| "encryption key must be 32 bytes".to_string())?;
+        self.keys.insert(key_id.to_string(), cipher);
+        Ok(())
+    }
+
+    pub fn is_unlocked(&self, key_id: &str) -> bool {
+        self.keys.contains_key(key_id)
+    }
+
+    /// Names of the encrypted columns of a table, whether or not their key is unlocked.
+    pub fn table_columns(&self, connection_uri: &str, table_name: &str) -> Vec<String> {
+        self.columns
+            .get(connection_uri)
+            .into_iter()
+            .flatten()
+            .filter(|column| {
+                column
+                    .table_name
+                    .as_deref()
+                    .is_none_or(|name| name == table_name)
+            })
+            .map(|column| column.column.clone())
+            .collect()
+    }
+
+    /// Ciphers for the encrypted columns of a table whose key is unlocked. With
+    /// `require_all`, a locked key is an error, since writes would store plaintext.
+    pub fn column_keys(
+        &self,
+        connection_uri: &str,
+        table_name: &str,
+        require_all: bool,
+    ) -> Result<ColumnKeys, String> {
+        let mut columns = Vec::new();
+        let mut locked = Vec::new();
+        for column in self.columns.get(connection_uri).into_iter().flatten() {
+            if column
+                .table_name
+                .as_deref()
+                .is_some_and(|name| name != table_name)
+            {
+                continue;
+            }
+            match self.keys.get(&column.key_id) {
+                Some(cipher) => columns.push(UnlockedColumn {
+                    column: column.column.clone(),
+                    column_id: binding(column).to_string(),
+                    key_id: column.key_id.clone(),
+                    cipher: cipher.clone(),
+                }),
+                None if require_all => {
+                    return Err(format!(
+                        "column '{}' is encrypted with key '{}', which is locked",
+                        column.column, column.key_id
+                    ))
+                }
+                None => locked.push((column.column.clone(), column.key_id.clone())),
+            }
+        }
+        Ok(ColumnKeys { columns, locked })
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        let Some(path) = self.path.as_ref() else {
+            return Ok(());
+        };
+        store::save_json(
+            path,
+            &EncryptedColumnsFile {
+                connections: self.columns.clone(),
+            },
+        )
+    }
+}
+
+pub fn validate_column(column: &EncryptedColumnV1) -> Result<(), String> {
+    if column.column.trim().is_empty() {
+        return Err("encrypted column name cannot be empty".to_string());
+    }
+    if column.key_id.trim().is_empty() {
+        return Err("encrypted column key id cannot be empty".to_string());
+    }
+    if column
+        .column_id
+        .as_deref()
+        .is_some_and(|id| id.trim().is_empty())
+    {
+        return Err("encrypted column id cannot be empty".to_string());
+    }
+    if let Some(table_name) = column.table_name.as_deref() {
+        if table_name.trim().is_empty() {
+            return Err("encrypted column table name cannot be empty".to_string());
+        }
+    }
+    Ok(())
+}
+
+/// What the values of `column` are authenticated with: its `column_id`, or the
+/// column name when it was never renamed.
+pub fn binding(column: &EncryptedColumnV1) -> &str {
+    column.column_id.as_deref().unwrap_or(&column.column)
+}
+
+/// Unlocked ciphers by column name for one table, plus the encrypted columns whose
+/// key is still locked.
+#[derive(Clone, Default)]
+pub struct ColumnKeys {
+    columns: Vec<UnlockedColumn>,
+    locked: Vec<(String, String)>,
+}
+
+#[derive(Clone)]
+struct UnlockedColumn {
+    column: String,
+    column_id: String,
+    key_id: String,
+    cipher: Aes256Gcm,
+}
+
+impl ColumnKeys {
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Identifies which columns are decrypted and with which key ids, so cached
+    /// pages read with other keys are never reused. Key material stays out of it.
+    pub fn fingerprint(&self) -> String {
+        let mut entries = self
+            .columns
+            .iter()
+            .map(|column| {
+                (
+                    column.column.as_str(),
+                    column.key_id.as_str(),
+                    column.column_id.as_str(),
+                )
+            })
+            .collect::<Vec<_>>();
+        entries.sort_unstable();
+        let mut hasher = Sha256::new();
+        for (_, key_id, column_id) in &entries {
+            hasher.update(key_id.as_bytes());
+            hasher.update([0]);
+            hasher.update(column_id.as_bytes());
+            hasher.update([0]);
+        }
+        let names = entries
+            .iter()
+            .map(|(column, _, _)| *column)
+            .collect::<Vec<_>>()
+            .join(",");
+        let digest = checksum::to_hex(&hasher.finalize());
+        format!("[{names}]#{}", &digest[..16])
+    }
+
+    /// Key id of `column` when it is encrypted with a key that is not unlocked.
+    pub fn locked_key(&self, column: &str) -> Option<&str> {
+        self.locked
+            .iter()
+            .find(|(name, _)| name == column)
+            .map(|(_, key_id)| key_id.as_str())
+    }
+
+    /// Rewrites the `SET` expression of an encrypted column. A string literal is
+    /// encrypted and `NULL` kept; any other expression would be evaluated against
+    /// ciphertext, so it is refused. `None` when the column has no unlocked key.
+    pub fn encrypt_update_expr(&self, column: &str, expr: &str) -> Option<Result<String, String>> {
+        let unlocked = self.get(column)?;
+        let expr = expr.trim();
+        if expr.eq_ignore_ascii_case("null") {
+            return Some(Ok(expr.to_string()));
+        }
+        let Some(plaintext) = string_literal(expr) else {
+            return Some(Err(format!(
+                "column '{column}' is encrypted; it can only be set to a string literal or NULL"
+            )));
+        };
+        // The ciphertext is base64 after an ASCII prefix, so it needs no escaping.
+        Some(encrypt_value(unlocked, &plaintext).map(|value| format!("'{value}'")))
+    }
+
+    fn get(&self, column: &str) -> Option<&UnlockedColumn> {
+        self.columns
+            .iter()
+            .find(|unlocked| unlocked.column == column)
+    }
+}
+
+impl fmt::Debug for ColumnKeys {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ColumnKeys")
+            .field(
+                "columns",
+                &self
+                    .columns
+                    .iter()
+                    .map(|unlocked| &unlocked.column)
+                    .collect::<Vec<_>>(),
+            )
+            .field("locked", &self.locked)
+            .finish()
+    }
+}
+
+/// The value of a single-quoted SQL string literal, with `''` unescaped.
+fn string_literal(expr: &str) -> Option<String> {
+    let inner = expr.strip_prefix('\'')?.strip_suffix('\'')?;
+    let mut value = String::with_capacity(inner.len());
+    let mut characters = inner.chars();
+    while let Some(character) = characters.next() {
+        if character == '\'' {
+            // A lone quote would end the literal early.
+            if characters.next() != Some('\'') {
+                return None;
+            }
+        }
+        value.push(character);
+    }
+    Some(value)
+}
+
+/// Encrypts with a fresh nonce. The column id is authenticated with the value, so
+/// a value copied into another column fails to decrypt.
+fn encrypt_value(unlocked: &UnlockedColumn, plaintext: &str) -> Result<String, String> {
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = unlocked
+        .cipher
+        .encrypt(
+            &nonce,
+            Payload {
+                msg: plaintext.as_bytes(),
+                aad: unlocked.column_id.as_bytes(),
+            },
+        )
+        .map_err(|_| format!("failed to encrypt column '{}'", unlocked.column))?;
+    let mut payload = nonce.to_vec();
+    payload.extend(ciphertext);
+    Ok(format!(
+        "{CIPHERTEXT_PREFIX}{}",
+        general_purpose::STANDARD.encode(payload)
+    ))
+}
+
+fn decrypt_value(unlocked: &UnlockedColumn, value: &str) -> Result<String, String> {
+    let invalid = || {
+        format!(
+            "failed to decrypt column '{}': wrong key or corrupt value",
+            unlocked.column
+        )
+    };
+    let encoded = value.strip_prefix(CIPHERTEXT_PREFIX).ok_or_else(invalid)?;
+    let payload = general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(|_| invalid())?;
+    if payload.len() < NONCE_LEN {
+        return Err(invalid());
+    }
+    let (nonce, ciphertext) = payload.split_at(NONCE_LEN);
+    let plaintext = unlocked
+        .cipher
+        .decrypt(
+            Nonce::from_slice(nonce),
+            Payload {
+                msg: ciphertext,
+                aad: unlocked.column_id.as_bytes(),
+            },
+        )
+        .map_err(|_| invalid())?;
+    String::from_utf8(plaintext).map_err(|_| invalid())
+}
+
+fn map_strings<O: OffsetSizeTrait>(
+    array: &GenericStringArray<O>,
+    apply: impl Fn(&str) -> Result<String, String>,
+) -> Result<ArrayRef, String> {
+    let values = array
+        .iter()
+        .map(|value| value.map(&apply).transpose())
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Arc::new(GenericStringArray::<O>::from(values)))
+}
+
+fn map_batch(
+    batch: &RecordBatch,
+    keys: &ColumnKeys,
+    apply: impl Fn(&UnlockedColumn, &str) -> Result<String, String>,
+) -> Result<RecordBatch, String> {
+    let schema = batch.schema();
+    let mut columns = Vec::with_capacity(batch.num_columns());
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        let Some(unlocked) = keys.get(field.name()) else {
+            columns.push(column.clone());
+            continue;
+        };
+        let convert = |value: &str| apply(unlocked, value);
+        columns.push(match field.data_type() {
+            DataType::Utf8 => map_strings(column.as_string::<i32>(), convert)?,
+            DataType::LargeUtf8 => map_strings(column.as_string::<i64>(), convert)?,
+            other => {
+                return Err(format!(
+                    "column '{}' is encrypted but has type {other}; only string columns can be encrypted",
+                    field.name()
+                ))
+            }
+        });
+    }
+    RecordBatch::try_new(schema, columns).map_err(|error| error.to_string())
+}
+
+/// Encrypts the configured columns before a write. Values that are already
+/// encrypted, e.g. copied from another table, are kept as they are.
+pub fn encrypt_batches(
+    batches: Vec<RecordBatch>,
+    keys: &ColumnKeys,
+) -> Result<Vec<RecordBatch>, String> {
+    if keys.is_empty() {
+        return Ok(batches);
+    }
+    batches
+        .iter()
+        .map(|batch| {
+            map_batch(batch, keys, |unlocked, value| {
+                if value.starts_with(CIPHERTEXT_PREFIX) {
+                    Ok(value.to_string())
+                } else {
+                    encrypt_value(unlocked, value)
+                }
+            })
+        })
+        .collect()
+}
+
+/// Decrypts the configured columns present in the batches. Plaintext values, e.g.
+/// written before the column was encrypted, pass through.
+pub fn decrypt_batches(
+    batches: Vec<RecordBatch>,
+    keys: &ColumnKeys,
+) -> Result<Vec<RecordBatch>, String> {
+    if keys.is_empty() {
+        return Ok(batches);
+    }
+    batches
+        .iter()
+        .map(|batch| {
+            map_batch(batch, keys, |unlocked, value| {
+                if value.starts_with(CIPHERTEXT_PREFIX) {
+                    decrypt_value(unlocked, value)
+                } else {
+                    Ok(value.to_string())
+                }
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::{Int32Array, StringArray};
+    use arrow_schema::{Field, Schema};
+
+    use super::*;
+
+    const KEY: &str = "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=";
+
+    fn store() -> EncryptionStore {
+        let mut store = EncryptionStore::new();
+        store
+            .set(
+                "memory://",
+                vec![EncryptedColumnV1 {
+                    table_name: Some("items".to_string()),
+                    column: "secret".to_string(),
+                    key_id: "vault-key".to_string(),
+                    column_id: None,
+                }],
+            )
+            .expect("set columns");
+        store
+    }
+
+    fn texts(batch: &RecordBatch, column: usize) -> Vec<Option<String>> {
+        batch
+            .column(column)
+            .as_string::<i32>()
+            .iter()
+            .map(|value| value.map(str::to_string))
+            .collect()
+    }
+
+    #[test]
+    fn round_trips_configured_columns() {
+        let mut store = store();
+        assert!(store.column_keys("memory://", "items", true).is_err());
+        assert!(store
+            .column_keys("memory://", "items", false)
+            .expect("keys")
+            .is_empty());
+        assert!(store.set_key("vault-key", Some("c2hvcnQ=")).is_err());
+        store.set_key("vault-key", Some(KEY)).expect("unlock");
+        let keys = store.column_keys("memory://", "items", true).expect("keys");
+        assert_eq!(keys.len(), 1);
+        assert_eq!(store.table_columns("memory://", "items"), vec!["secret"]);
+        assert!(store.table_columns("memory://", "other").is_empty());
+        assert!(store
+            .column_keys("memory://", "other", true)
+            .expect("keys")
+            .is_empty());
+
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("id", DataType::Int32, false),
+                Field::new("secret", DataType::Utf8, true),
+            ])),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2])),
+                Arc::new(StringArray::from(vec![Some("card 4242"), None])),
+            ],
+        )
+        .expect("batch");
+        let encrypted = encrypt_batches(vec![batch.clone()], &keys).expect("encrypt");
+        let stored = texts(&encrypted[0], 1);
+        assert!(stored[0]
+            .as_deref()
+            .is_some_and(|value| value.starts_with(CIPHERTEXT_PREFIX)));
+        assert_eq!(stored[1], None);
+        let twice = encrypt_batches(encrypted.clone(), &keys).expect("encrypt again");
+        assert_eq!(texts(&twice[0], 1), stored);
+
+        let decrypted = decrypt_batches(encrypted.clone(), &keys).expect("decrypt");
+        assert_eq!(texts(&decrypted[0], 1), texts(&batch, 1));
+
+        store
+            .set_key(
+                "vault-key",
+                Some("AQECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8="),
+            )
+            .expect("other key");
+        let wrong = store.column_keys("memory://", "items", true).expect("keys");
+        assert!(decrypt_batches(encrypted, &wrong).is_err());
+    }
+
+    #[test]
+    fn encrypts_update_literals_only() {
+        let mut store = store();
+        let locked = store
+            .column_keys("memory://", "items", false)
+            .expect("keys");
+        assert_eq!(locked.locked_key("secret"), Some("vault-key"));
+        assert!(locked.encrypt_update_expr("secret", "'x'").is_none());
+
+        store.set_key("vault-key", Some(KEY)).expect("unlock");
+        let keys = store.column_keys("memory://", "items", true).expect("keys");
+        assert_eq!(keys.locked_key("secret"), None);
+        assert!(keys.encrypt_update_expr("id", "1").is_none());
+        assert_eq!(
+            keys.encrypt_update_expr("secret", "NULL")
+                .expect("encrypted")
+                .as_deref(),
+            Ok("NULL")
+        );
+        assert!(keys
+            .encrypt_update_expr("secret", "upper(secret)")
+            .expect("encrypted")
+            .is_err());
+        assert!(keys
+            .encrypt_update_expr("secret", "'a' || 'b'")
+            .expect("encrypted")
+            .is_err());
+
+        let expr = keys
+            .encrypt_update_expr("secret", "'it''s'")
+            .expect("encrypted")
+            .expect("literal");
+        let stored = string_literal(&expr).expect("quoted ciphertext");
+        let unlocked = keys.get("secret").expect("cipher");
+        assert_eq!(decrypt_value(unlocked, &stored).as_deref(), Ok("it's"));
+    }
+
+    #[test]
+    fn fingerprints_change_with_key_ids() {
+        let mut store = store();
+        let locked = store
+            .column_keys("memory://", "items", false)
+            .expect("keys")
+            .fingerprint();
+        store.set_key("vault-key", Some(KEY)).expect("unlock");
+        let unlocked = store
+            .column_keys("memory://", "items", true)
+            .expect("keys")
+            .fingerprint();
+        assert_ne!(locked, unlocked);
+        assert!(unlocked.starts_with("[secret]#"));
+        assert!(!unlocked.contains("vault-key"));
+
+        store
+            .set(
+                "memory://",
+                vec![EncryptedColumnV1 {
+                    table_name: Some("items".to_string()),
+                    column: "secret".to_string(),
+                    key_id: "other-key".to_string(),
+                    column_id: None,
+                }],
+            )
+            .expect("set columns");
+        store.set_key("other-key", Some(KEY)).expect("unlock");
+        let rekeyed = store
+            .column_keys("memory://", "items", true)
+            .expect("keys")
+            .fingerprint();
+        assert_ne!(unlocked, rekeyed);
+    }
+}
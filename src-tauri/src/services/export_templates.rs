@@ -0,0 +1,268 @@
+use std::fmt::Display;
+use std::path::PathBuf;
+
+use chrono::{DateTime, TimeZone};
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::ipc::v1::ExportTemplateV1;
+use crate::services::store;
+
+pub const EXPORT_TEMPLATES_FILE: &str = "export_templates.json";
+
+/// Placeholders accepted in export paths, expanded by [`expand_path`].
+const PLACEHOLDERS: [&str; 4] = ["table", "date", "time", "timestamp"];
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExportTemplatesFile {
+    #[serde(default)]
+    templates: Vec<ExportTemplateV1>,
+}
+
+/// Named export configurations, persisted to `export_templates.json` in the app data
+/// directory when one is configured.
+#[derive(Default)]
+pub struct ExportTemplateStore {
+    path: Option<PathBuf>,
+    templates: Vec<ExportTemplateV1>,
+}
+
+impl ExportTemplateStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load(path: PathBuf) -> Self {
+        let templates = match store::load_json::<ExportTemplatesFile>(&path) {
+            Ok(file) => file.templates,
+            Err(error) => {
+                warn!(
+                    "failed to load export templates path={:?} error={}",
+                    path, error
+                );
+                Vec::new()
+            }
+        };
+        Self {
+            path: Some(path),
+            templates,
+        }
+    }
+
+    /// Templates sorted by name.
+    pub fn list(&self) -> Vec<ExportTemplateV1> {
+        let mut templates = self.templates.clone();
+        templates.sort_by(|left, right| left.name.cmp(&right.name));
+        templates
+    }
+
+    pub fn get(&self, name: &str) -> Option<ExportTemplateV1> {
+        self.templates
+            .iter()
+            .find(|template| template.name == name)
+            .cloned()
+    }
+
+    /// Adds a template or replaces the one with the same name.
+    pub fn save(&mut self, template: ExportTemplateV1) -> Result<(), String> {
+        self.templates
+            .retain(|existing| existing.name != template.name);
+        self.templates.push(template);
+        self.persist()
+    }
+
+    /// Returns false when no template has this name.
+    pub fn delete(&mut self, name: &str) -> Result<bool, String> {
+        let before = self.templates.len();
+        self.templates.retain(|template| template.name != name);
+        if self.templates.len() == before {
+            return Ok(false);
+        }
+        self.persist()?;
+        Ok(true)
+    }
+
+    /// Rewrites every template with `rewrite`, which returns `None` for those it leaves
+    /// alone. Returns the names of the rewritten templates.
+    pub fn rewrite(
+        &mut self,
+        rewrite: impl Fn(&ExportTemplateV1) -> Option<ExportTemplateV1>,
+    ) -> Result<Vec<String>, String> {
+        let mut rewritten = Vec::new();
+        for template in &mut self.templates {
+            if let Some(updated) = rewrite(template) {
+                rewritten.push(updated.name.clone());
+                *template = updated;
+            }
+        }
+        if rewritten.is_empty() {
+            return Ok(rewritten);
+        }
+        rewritten.sort();
+        self.persist().map(|_| rewritten)
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        let Some(path) = self.path.as_ref() else {
+            return Ok(());
+        };
+        store::save_json(
+            path,
+            &ExportTemplatesFile {
+                templates: self.templates.clone(),
+            },
+        )
+    }
+}
+
+/// Rejects `{...}` names other than the supported placeholders, so a typo does not
+/// end up as a literal part of every exported file name.
+fn check_placeholders(pattern: &str) -> Result<(), String> {
+    let mut rest = pattern;
+    while let Some(start) = rest.find('{') {
+        let after = &rest[start + 1..];
+        let Some(end) = after.find('}') else {
+            return Err("unclosed '{' in path pattern".to_string());
+        };
+        let name = &after[..end];
+        if !PLACEHOLDERS.contains(&name) {
+            return Err(format!(
+                "unknown placeholder '{{{name}}}'; supported: {}",
+                PLACEHOLDERS
+                    .iter()
+                    .map(|placeholder| format!("{{{placeholder}}}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        rest = &after[end + 1..];
+    }
+    Ok(())
+}
+
+/// Trims the template and checks its name and path pattern. Empty projections and
+/// filters are dropped.
+pub fn normalize(template: ExportTemplateV1) -> Result<ExportTemplateV1, String> {
+    let name = template.name.trim().to_string();
+    if name.is_empty() {
+        return Err("template name cannot be empty".to_string());
+    }
+    let path_pattern = template.path_pattern.trim().to_string();
+    if path_pattern.is_empty() {
+        return Err("path pattern cannot be empty".to_string());
+    }
+    check_placeholders(&path_pattern)?;
+
+    let projection = template
+        .projection
+        .map(|columns| {
+            columns
+                .iter()
+                .map(|column| column.trim().to_string())
+                .filter(|column| !column.is_empty())
+                .collect::<Vec<_>>()
+        })
+        .filter(|columns| !columns.is_empty());
+    let filter = template
+        .filter
+        .map(|filter| filter.trim().to_string())
+        .filter(|filter| !filter.is_empty());
+
+    Ok(ExportTemplateV1 {
+        name,
+        path_pattern,
+        projection,
+        filter,
+        ..template
+    })
+}
+
+/// Expands `{table}`, `{date}` (`2024-05-01`), `{time}` (`1430`) and `{timestamp}`
+/// (`20240501-1430`) in an export path, using the time zone of `now`.
+pub fn expand_path<Tz: TimeZone>(pattern: &str, table_name: &str, now: &DateTime<Tz>) -> String
+where
+    Tz::Offset: Display,
+{
+    pattern
+        .replace("{table}", table_name)
+        .replace("{date}", &now.format("%Y-%m-%d").to_string())
+        .replace("{time}", &now.format("%H%M").to_string())
+        .replace("{timestamp}", &now.format("%Y%m%d-%H%M").to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::FixedOffset;
+
+    use crate::ipc::v1::DataFileFormatV1;
+
+    use super::*;
+
+    fn template(name: &str, path_pattern: &str) -> ExportTemplateV1 {
+        ExportTemplateV1 {
+            name: name.to_string(),
+            format: DataFileFormatV1::Csv,
+            path_pattern: path_pattern.to_string(),
+            projection: None,
+            filter: None,
+            limit: None,
+            delimiter: None,
+            with_header: None,
+        }
+    }
+
+    #[test]
+    fn expands_date_placeholders() {
+        let now = FixedOffset::east_opt(0)
+            .unwrap()
+            .with_ymd_and_hms(2024, 5, 1, 14, 30, 0)
+            .unwrap();
+        assert_eq!(
+            expand_path("/exports/{table}/{date}_{time}.csv", "items", &now),
+            "/exports/items/2024-05-01_1430.csv"
+        );
+        assert_eq!(
+            expand_path("s3://bucket/{table}-{timestamp}.parquet", "items", &now),
+            "s3://bucket/items-20240501-1430.parquet"
+        );
+    }
+
+    #[test]
+    fn normalizes_templates() {
+        let mut daily = template(" daily ", " /exports/{table}-{date}.csv ");
+        daily.projection = Some(vec![" id ".to_string(), " ".to_string()]);
+        daily.filter = Some("  ".to_string());
+        let daily = normalize(daily).expect("normalize");
+        assert_eq!(daily.name, "daily");
+        assert_eq!(daily.path_pattern, "/exports/{table}-{date}.csv");
+        assert_eq!(daily.projection, Some(vec!["id".to_string()]));
+        assert_eq!(daily.filter, None);
+
+        assert!(normalize(template(" ", "/exports/a.csv")).is_err());
+        assert!(normalize(template("daily", "/exports/{day}.csv")).is_err());
+        assert!(normalize(template("daily", "/exports/{date.csv")).is_err());
+    }
+
+    #[test]
+    fn replaces_templates_by_name() {
+        let mut store = ExportTemplateStore::new();
+        store.save(template("weekly", "/a.csv")).unwrap();
+        store.save(template("daily", "/b.csv")).unwrap();
+        store.save(template("weekly", "/c.csv")).unwrap();
+        let names = store
+            .list()
+            .into_iter()
+            .map(|template| (template.name, template.path_pattern))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                ("daily".to_string(), "/b.csv".to_string()),
+                ("weekly".to_string(), "/c.csv".to_string()),
+            ]
+        );
+        assert!(store.delete("daily").unwrap());
+        assert!(!store.delete("daily").unwrap());
+    }
+}
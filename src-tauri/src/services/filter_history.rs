@@ -0,0 +1,283 @@
+use std::path::PathBuf;
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::ipc::v1::{RecentFilterSortV1, RecentFilterV1};
+use crate::services::store;
+
+pub const FILTER_HISTORY_FILE: &str = "filter_history.json";
+
+/// Older filters of a table are dropped past this many.
+const MAX_FILTERS_PER_TABLE: usize = 100;
+/// Longer filters are generated rather than typed and are not worth suggesting.
+const MAX_FILTER_CHARS: usize = 2_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FilterEntry {
+    connection_uri: String,
+    table_name: String,
+    filter: String,
+    uses: u64,
+    last_used_ms: u64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FilterHistoryFile {
+    #[serde(default)]
+    filters: Vec<FilterEntry>,
+}
+
+/// Filters used per table, keyed by connection URI and table name, persisted to
+/// `filter_history.json` in the app data directory when one is configured.
+#[derive(Default)]
+pub struct FilterHistoryStore {
+    path: Option<PathBuf>,
+    entries: Vec<FilterEntry>,
+}
+
+impl FilterHistoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load(path: PathBuf) -> Self {
+        let entries = match store::load_json::<FilterHistoryFile>(&path) {
+            Ok(file) => file.filters,
+            Err(error) => {
+                warn!(
+                    "failed to load filter history path={:?} error={}",
+                    path, error
+                );
+                Vec::new()
+            }
+        };
+        Self {
+            path: Some(path),
+            entries,
+        }
+    }
+
+    /// Counts a use of `filter`. Whitespace is collapsed so the same predicate typed
+    /// with other spacing is one entry. Blank and very long filters are ignored.
+    pub fn record(
+        &mut self,
+        connection_uri: &str,
+        table_name: &str,
+        filter: &str,
+        now_ms: u64,
+    ) -> Result<(), String> {
+        let filter = filter.split_whitespace().collect::<Vec<_>>().join(" ");
+        if filter.is_empty() || filter.chars().count() > MAX_FILTER_CHARS {
+            return Ok(());
+        }
+        match self.entries.iter_mut().find(|entry| {
+            entry.connection_uri == connection_uri
+                && entry.table_name == table_name
+                && entry.filter == filter
+        }) {
+            Some(entry) => {
+                entry.uses += 1;
+                entry.last_used_ms = now_ms;
+            }
+            None => self.entries.push(FilterEntry {
+                connection_uri: connection_uri.to_string(),
+                table_name: table_name.to_string(),
+                filter,
+                uses: 1,
+                last_used_ms: now_ms,
+            }),
+        }
+        self.prune(connection_uri, table_name);
+        self.persist()
+    }
+
+    fn prune(&mut self, connection_uri: &str, table_name: &str) {
+        let mut used_at = self
+            .entries
+            .iter()
+            .filter(|entry| {
+                entry.connection_uri == connection_uri && entry.table_name == table_name
+            })
+            .map(|entry| entry.last_used_ms)
+            .collect::<Vec<_>>();
+        if used_at.len() <= MAX_FILTERS_PER_TABLE {
+            return;
+        }
+        used_at.sort_unstable_by(|left, right| right.cmp(left));
+        let cutoff = used_at[MAX_FILTERS_PER_TABLE - 1];
+        self.entries.retain(|entry| {
+            entry.connection_uri != connection_uri
+                || entry.table_name != table_name
+                || entry.last_used_ms >= cutoff
+        });
+    }
+
+    /// Filters of a table starting with `prefix` (ignoring case), most recent or most
+    /// used first.
+    pub fn recent(
+        &self,
+        connection_uri: &str,
+        table_name: &str,
+        prefix: &str,
+        sort: RecentFilterSortV1,
+        limit: usize,
+    ) -> Vec<RecentFilterV1> {
+        let prefix = prefix.trim().to_lowercase();
+        let mut filters = self
+            .entries
+            .iter()
+            .filter(|entry| {
+                entry.connection_uri == connection_uri && entry.table_name == table_name
+            })
+            .filter(|entry| entry.filter.to_lowercase().starts_with(&prefix))
+            .map(|entry| RecentFilterV1 {
+                filter: entry.filter.clone(),
+                uses: entry.uses,
+                last_used_ms: entry.last_used_ms,
+            })
+            .collect::<Vec<_>>();
+        match sort {
+            RecentFilterSortV1::Recent => filters.sort_by(|left, right| {
+                right
+                    .last_used_ms
+                    .cmp(&left.last_used_ms)
+                    .then_with(|| right.uses.cmp(&left.uses))
+            }),
+            RecentFilterSortV1::Frequent => filters.sort_by(|left, right| {
+                right
+                    .uses
+                    .cmp(&left.uses)
+                    .then_with(|| right.last_used_ms.cmp(&left.last_used_ms))
+            }),
+        }
+        filters.truncate(limit);
+        filters
+    }
+
+    /// Rewrites the filters of a table with `rewrite`, which returns `None` for those
+    /// it leaves alone. Filters that end up equal are merged. Returns the rewritten
+    /// filters.
+    pub fn rewrite(
+        &mut self,
+        connection_uri: &str,
+        table_name: &str,
+        rewrite: impl Fn(&str) -> Option<String>,
+    ) -> Result<Vec<String>, String> {
+        let mut rewritten = Vec::new();
+        for entry in self.entries.iter_mut().filter(|entry| {
+            entry.connection_uri == connection_uri && entry.table_name == table_name
+        }) {
+            if let Some(filter) = rewrite(&entry.filter) {
+                entry.filter = filter.clone();
+                rewritten.push(filter);
+            }
+        }
+        if rewritten.is_empty() {
+            return Ok(rewritten);
+        }
+        let mut merged: Vec<FilterEntry> = Vec::with_capacity(self.entries.len());
+        for entry in self.entries.drain(..) {
+            match merged.iter_mut().find(|existing| {
+                existing.connection_uri == entry.connection_uri
+                    && existing.table_name == entry.table_name
+                    && existing.filter == entry.filter
+            }) {
+                Some(existing) => {
+                    existing.uses += entry.uses;
+                    existing.last_used_ms = existing.last_used_ms.max(entry.last_used_ms);
+                }
+                None => merged.push(entry),
+            }
+        }
+        self.entries = merged;
+        rewritten.sort();
+        rewritten.dedup();
+        self.persist().map(|_| rewritten)
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        let Some(path) = self.path.as_ref() else {
+            return Ok(());
+        };
+        store::save_json(
+            path,
+            &FilterHistoryFile {
+                filters: self.entries.clone(),
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filters(history: &FilterHistoryStore, sort: RecentFilterSortV1) -> Vec<String> {
+        history
+            .recent("memory://", "items", "", sort, 10)
+            .into_iter()
+            .map(|filter| filter.filter)
+            .collect()
+    }
+
+    #[test]
+    fn ranks_filters_by_recency_or_use() {
+        let mut history = FilterHistoryStore::new();
+        history.record("memory://", "items", "id > 1", 1).unwrap();
+        history.record("memory://", "items", "id  >  1", 2).unwrap();
+        history
+            .record("memory://", "items", "text = 'a'", 3)
+            .unwrap();
+        history.record("memory://", "other", "id < 0", 4).unwrap();
+        history.record("memory://", "items", "   ", 5).unwrap();
+
+        assert_eq!(
+            filters(&history, RecentFilterSortV1::Recent),
+            vec!["text = 'a'", "id > 1"]
+        );
+        assert_eq!(
+            filters(&history, RecentFilterSortV1::Frequent),
+            vec!["id > 1", "text = 'a'"]
+        );
+        let matching = history.recent("memory://", "items", "TEXT", RecentFilterSortV1::Recent, 10);
+        assert_eq!(matching.len(), 1);
+        assert_eq!(matching[0].uses, 1);
+    }
+
+    #[test]
+    fn keeps_the_most_recent_filters_per_table() {
+        let mut history = FilterHistoryStore::new();
+        for index in 0..=MAX_FILTERS_PER_TABLE as u64 {
+            history
+                .record("memory://", "items", &format!("id = {index}"), index)
+                .unwrap();
+        }
+        let kept = filters(&history, RecentFilterSortV1::Recent);
+        assert_eq!(
+            history
+                .recent(
+                    "memory://",
+                    "items",
+                    "",
+                    RecentFilterSortV1::Recent,
+                    usize::MAX
+                )
+                .len(),
+            MAX_FILTERS_PER_TABLE
+        );
+        assert_eq!(kept[0], format!("id = {MAX_FILTERS_PER_TABLE}"));
+        assert!(history
+            .recent(
+                "memory://",
+                "items",
+                "id = 0",
+                RecentFilterSortV1::Recent,
+                10
+            )
+            .iter()
+            .all(|filter| filter.filter != "id = 0"));
+    }
+}
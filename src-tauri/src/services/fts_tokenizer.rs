@@ -0,0 +1,124 @@
+use lancedb::index::scalar::FtsIndexBuilder;
+
+use crate::ipc::v1::{FtsLanguageV1, FtsTokenizerOptionsV1, IndexTypeV1};
+
+/// Lance reads the language by its tantivy name.
+fn language_name(language: FtsLanguageV1) -> &'static str {
+    match language {
+        FtsLanguageV1::Arabic => "Arabic",
+        FtsLanguageV1::Danish => "Danish",
+        FtsLanguageV1::Dutch => "Dutch",
+        FtsLanguageV1::English => "English",
+        FtsLanguageV1::Finnish => "Finnish",
+        FtsLanguageV1::French => "French",
+        FtsLanguageV1::German => "German",
+        FtsLanguageV1::Greek => "Greek",
+        FtsLanguageV1::Hungarian => "Hungarian",
+        FtsLanguageV1::Italian => "Italian",
+        FtsLanguageV1::Norwegian => "Norwegian",
+        FtsLanguageV1::Portuguese => "Portuguese",
+        FtsLanguageV1::Romanian => "Romanian",
+        FtsLanguageV1::Russian => "Russian",
+        FtsLanguageV1::Spanish => "Spanish",
+        FtsLanguageV1::Swedish => "Swedish",
+        FtsLanguageV1::Tamil => "Tamil",
+        FtsLanguageV1::Turkish => "Turkish",
+    }
+}
+
+pub fn validate(
+    options: Option<&FtsTokenizerOptionsV1>,
+    index_type: &IndexTypeV1,
+) -> Result<(), String> {
+    let Some(options) = options else {
+        return Ok(());
+    };
+    if !matches!(index_type, IndexTypeV1::Fts) {
+        return Err("ftsTokenizer only applies to fts indexes".to_string());
+    }
+    if options.max_token_length == Some(0) {
+        return Err("maxTokenLength must be greater than 0".to_string());
+    }
+    Ok(())
+}
+
+/// FTS index builder with the requested tokenizer settings over LanceDB's defaults.
+pub fn builder(options: Option<&FtsTokenizerOptionsV1>) -> Result<FtsIndexBuilder, String> {
+    let mut builder = FtsIndexBuilder::default();
+    let Some(options) = options else {
+        return Ok(builder);
+    };
+    if let Some(language) = options.language {
+        builder = builder
+            .language(language_name(language))
+            .map_err(|error| error.to_string())?;
+    }
+    if let Some(stem) = options.stem {
+        builder = builder.stem(stem);
+    }
+    if let Some(remove_stop_words) = options.remove_stop_words {
+        builder = builder.remove_stop_words(remove_stop_words);
+    }
+    if let Some(lower_case) = options.lower_case {
+        builder = builder.lower_case(lower_case);
+    }
+    if let Some(ascii_folding) = options.ascii_folding {
+        builder = builder.ascii_folding(ascii_folding);
+    }
+    if let Some(max_token_length) = options.max_token_length {
+        builder = builder.max_token_length(Some(max_token_length as usize));
+    }
+    Ok(builder)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_tokenizer_options() {
+        let options = FtsTokenizerOptionsV1 {
+            language: Some(FtsLanguageV1::German),
+            stem: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(validate(None, &IndexTypeV1::BTree), Ok(()));
+        assert_eq!(validate(Some(&options), &IndexTypeV1::Fts), Ok(()));
+        assert!(validate(Some(&options), &IndexTypeV1::IvfPq).is_err());
+        let zero_length = FtsTokenizerOptionsV1 {
+            max_token_length: Some(0),
+            ..Default::default()
+        };
+        assert!(validate(Some(&zero_length), &IndexTypeV1::Fts).is_err());
+    }
+
+    #[test]
+    fn builds_every_language() {
+        for language in [
+            FtsLanguageV1::Arabic,
+            FtsLanguageV1::Danish,
+            FtsLanguageV1::Dutch,
+            FtsLanguageV1::English,
+            FtsLanguageV1::Finnish,
+            FtsLanguageV1::French,
+            FtsLanguageV1::German,
+            FtsLanguageV1::Greek,
+            FtsLanguageV1::Hungarian,
+            FtsLanguageV1::Italian,
+            FtsLanguageV1::Norwegian,
+            FtsLanguageV1::Portuguese,
+            FtsLanguageV1::Romanian,
+            FtsLanguageV1::Russian,
+            FtsLanguageV1::Spanish,
+            FtsLanguageV1::Swedish,
+            FtsLanguageV1::Tamil,
+            FtsLanguageV1::Turkish,
+        ] {
+            let options = FtsTokenizerOptionsV1 {
+                language: Some(language),
+                ..Default::default()
+            };
+            assert!(builder(Some(&options)).is_ok(), "{language:?}");
+        }
+    }
+}
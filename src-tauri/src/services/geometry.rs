@@ -0,0 +1,596 @@
+use arrow_array::cast::AsArray;
+use arrow_array::Array;
+use arrow_schema::{DataType, Schema};
+use serde_json::{json, Value};
+
+use crate::ipc::v1::GeometryEncodingV1;
+
+const WKB_POINT: u32 = 1;
+const WKB_LINE_STRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTI_POINT: u32 = 4;
+const WKB_MULTI_LINE_STRING: u32 = 5;
+const WKB_MULTI_POLYGON: u32 = 6;
+const WKB_GEOMETRY_COLLECTION: u32 = 7;
+/// EWKB flag bits carried in the high bits of the geometry type.
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+/// Picks the geometry column for a GeoJSON export: the requested column when given,
+/// otherwise the first column recognized as geometry.
+pub fn resolve_column(
+    schema: &Schema,
+    requested: Option<&str>,
+) -> Result<(String, GeometryEncodingV1), String> {
+    let requested = requested.map(str::trim).filter(|name| !name.is_empty());
+    let Some(name) = requested else {
+        return schema
+            .fields()
+            .iter()
+            .find_map(|field| {
+                GeometryEncodingV1::detect(field).map(|encoding| (field.name().clone(), encoding))
+            })
+            .ok_or_else(|| "no geometry column found; set geometry_column".to_string());
+    };
+
+    let field = schema
+        .field_with_name(name)
+        .map_err(|_| format!("geometry column not found: {name}"))?;
+    let encoding = match field.data_type() {
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => GeometryEncodingV1::Wkt,
+        DataType::Binary | DataType::LargeBinary | DataType::BinaryView => GeometryEncodingV1::Wkb,
+        other => {
+            return Err(format!(
+                "geometry column {name} must hold WKT strings or WKB binary, found {other:?}"
+            ))
+        }
+    };
+    Ok((name.to_string(), encoding))
+}
+
+/// Decodes every value of a geometry column into a GeoJSON geometry. Nulls and blank
+/// WKT strings become `null` geometries.
+pub fn column_geometries(
+    array: &dyn Array,
+    encoding: GeometryEncodingV1,
+) -> Result<Vec<Value>, String> {
+    let mut geometries = Vec::with_capacity(array.len());
+    for index in 0..array.len() {
+        if array.is_null(index) {
+            geometries.push(Value::Null);
+            continue;
+        }
+        let geometry = match encoding {
+            GeometryEncodingV1::Wkt => {
+                let text = string_value(array, index)?;
+                if text.trim().is_empty() {
+                    Ok(Value::Null)
+                } else {
+                    parse_wkt(text)
+                }
+            }
+            GeometryEncodingV1::Wkb => parse_wkb(binary_value(array, index)?),
+        };
+        geometries.push(geometry.map_err(|error| format!("invalid geometry: {error}"))?);
+    }
+    Ok(geometries)
+}
+
+/// Pairs geometries with their row properties as a GeoJSON FeatureCollection.
+pub fn feature_collection(geometries: Vec<Value>, properties: Vec<Value>) -> Value {
+    let features = geometries
+        .into_iter()
+        .zip(properties)
+        .map(|(geometry, properties)| {
+            json!({
+                "type": "Feature",
+                "geometry": geometry,
+                "properties": properties,
+            })
+        })
+        .collect::<Vec<_>>();
+    json!({
+        "type": "FeatureCollection",
+        "features": features,
+    })
+}
+
+fn string_value(array: &dyn Array, index: usize) -> Result<&str, String> {
+    match array.data_type() {
+        DataType::Utf8 => Ok(array.as_string::<i32>().value(index)),
+        DataType::LargeUtf8 => Ok(array.as_string::<i64>().value(index)),
+        DataType::Utf8View => Ok(array.as_string_view().value(index)),
+        other => Err(format!("expected WKT strings, found {other:?}")),
+    }
+}
+
+fn binary_value(array: &dyn Array, index: usize) -> Result<&[u8], String> {
+    match array.data_type() {
+        DataType::Binary => Ok(array.as_binary::<i32>().value(index)),
+        DataType::LargeBinary => Ok(array.as_binary::<i64>().value(index)),
+        DataType::BinaryView => Ok(array.as_binary_view().value(index)),
+        other => Err(format!("expected WKB binary, found {other:?}")),
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Dimensions {
+    has_z: bool,
+    has_m: bool,
+}
+
+impl Dimensions {
+    /// GeoJSON positions carry x, y and an optional z; measures are dropped.
+    fn position(self, values: &[f64]) -> Value {
+        let keep = if self.has_m && !self.has_z { 2 } else { 3 };
+        json!(values.iter().take(keep).copied().collect::<Vec<_>>())
+    }
+}
+
+fn geometry(kind: &str, coordinates: Value) -> Value {
+    json!({ "type": kind, "coordinates": coordinates })
+}
+
+/// Parses WKT, including the EWKT `SRID=...;` prefix and `Z`, `M` or `ZM` tags.
+pub fn parse_wkt(text: &str) -> Result<Value, String> {
+    let mut body = text.trim();
+    if body
+        .get(..5)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("SRID="))
+    {
+        let (_, rest) = body
+            .split_once(';')
+            .ok_or_else(|| "SRID prefix must end with ';'".to_string())?;
+        body = rest;
+    }
+    let mut parser = WktParser {
+        input: body.as_bytes(),
+        pos: 0,
+    };
+    let value = parser.geometry()?;
+    parser.skip_whitespace();
+    if parser.pos < parser.input.len() {
+        return Err(format!("unexpected trailing WKT at offset {}", parser.pos));
+    }
+    Ok(value)
+}
+
+struct WktParser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl WktParser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self
+            .input
+            .get(self.pos)
+            .is_some_and(|byte| byte.is_ascii_whitespace())
+        {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.input.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<(), String> {
+        match self.peek() {
+            Some(byte) if byte == expected => {
+                self.pos += 1;
+                Ok(())
+            }
+            _ => Err(format!(
+                "expected '{}' at offset {}",
+                expected as char, self.pos
+            )),
+        }
+    }
+
+    fn word(&mut self) -> String {
+        self.skip_whitespace();
+        let start = self.pos;
+        while self
+            .input
+            .get(self.pos)
+            .is_some_and(|byte| byte.is_ascii_alphabetic())
+        {
+            self.pos += 1;
+        }
+        String::from_utf8_lossy(&self.input[start..self.pos]).to_ascii_uppercase()
+    }
+
+    /// Consumes `keyword` when it is the next word.
+    fn keyword(&mut self, keyword: &str) -> bool {
+        let start = self.pos;
+        if self.word() == keyword {
+            true
+        } else {
+            self.pos = start;
+            false
+        }
+    }
+
+    fn number(&mut self) -> Result<f64, String> {
+        self.skip_whitespace();
+        let start = self.pos;
+        while self
+            .input
+            .get(self.pos)
+            .is_some_and(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'.' | b'-' | b'+'))
+        {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.input[start..self.pos])
+            .ok()
+            .and_then(|value| value.parse::<f64>().ok())
+            .ok_or_else(|| format!("expected a number at offset {start}"))
+    }
+
+    fn list<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> Result<T, String>,
+    ) -> Result<Vec<T>, String> {
+        if self.keyword("EMPTY") {
+            return Ok(Vec::new());
+        }
+        self.expect(b'(')?;
+        let mut items = vec![item(self)?];
+        while self.peek() == Some(b',') {
+            self.pos += 1;
+            items.push(item(self)?);
+        }
+        self.expect(b')')?;
+        Ok(items)
+    }
+
+    fn position(&mut self, dims: Dimensions) -> Result<Value, String> {
+        let mut values = Vec::with_capacity(4);
+        while !matches!(self.peek(), Some(b',' | b')') | None) {
+            values.push(self.number()?);
+        }
+        if values.len() < 2 {
+            return Err(format!(
+                "position needs at least x and y at offset {}",
+                self.pos
+            ));
+        }
+        Ok(dims.position(&values))
+    }
+
+    fn positions(&mut self, dims: Dimensions) -> Result<Value, String> {
+        self.list(|parser| parser.position(dims)).map(Value::from)
+    }
+
+    /// MULTIPOINT accepts both `(1 2, 3 4)` and `((1 2), (3 4))`.
+    fn multi_point_position(&mut self, dims: Dimensions) -> Result<Value, String> {
+        if self.peek() == Some(b'(') {
+            self.pos += 1;
+            let position = self.position(dims)?;
+            self.expect(b')')?;
+            Ok(position)
+        } else {
+            self.position(dims)
+        }
+    }
+
+    fn rings(&mut self, dims: Dimensions) -> Result<Value, String> {
+        self.list(|parser| parser.positions(dims)).map(Value::from)
+    }
+
+    fn geometry(&mut self) -> Result<Value, String> {
+        let kind = self.word();
+        let mut dims = Dimensions::default();
+        if self.keyword("ZM") {
+            dims = Dimensions {
+                has_z: true,
+                has_m: true,
+            };
+        } else if self.keyword("Z") {
+            dims.has_z = true;
+        } else if self.keyword("M") {
+            dims.has_m = true;
+        }
+
+        match kind.as_str() {
+            "POINT" => {
+                let positions = self.list(|parser| parser.position(dims))?;
+                let coordinates = positions.into_iter().next().unwrap_or_else(|| json!([]));
+                Ok(geometry("Point", coordinates))
+            }
+            "LINESTRING" => Ok(geometry("LineString", self.positions(dims)?)),
+            "POLYGON" => Ok(geometry("Polygon", self.rings(dims)?)),
+            "MULTIPOINT" => {
+                let positions = self.list(|parser| parser.multi_point_position(dims))?;
+                Ok(geometry("MultiPoint", Value::from(positions)))
+            }
+            "MULTILINESTRING" => {
+                let lines = self.list(|parser| parser.positions(dims))?;
+                Ok(geometry("MultiLineString", Value::from(lines)))
+            }
+            "MULTIPOLYGON" => {
+                let polygons = self.list(|parser| parser.rings(dims))?;
+                Ok(geometry("MultiPolygon", Value::from(polygons)))
+            }
+            "GEOMETRYCOLLECTION" => {
+                let geometries = self.list(Self::geometry)?;
+                Ok(json!({ "type": "GeometryCollection", "geometries": geometries }))
+            }
+            "" => Err(format!("expected a geometry type at offset {}", self.pos)),
+            other => Err(format!("unsupported WKT geometry type: {other}")),
+        }
+    }
+}
+
+/// Parses ISO WKB and PostGIS EWKB in either byte order.
+pub fn parse_wkb(bytes: &[u8]) -> Result<Value, String> {
+    let mut reader = WkbReader {
+        bytes,
+        pos: 0,
+        little_endian: true,
+    };
+    let value = reader.geometry()?;
+    if reader.pos != bytes.len() {
+        return Err(format!(
+            "unexpected {} trailing WKB bytes",
+            bytes.len() - reader.pos
+        ));
+    }
+    Ok(value)
+}
+
+struct WkbReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    little_endian: bool,
+}
+
+impl WkbReader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        let end = self.pos + N;
+        let slice = self
+            .bytes
+            .get(self.pos..end)
+            .ok_or_else(|| "WKB ended unexpectedly".to_string())?;
+        self.pos = end;
+        let mut buffer = [0u8; N];
+        buffer.copy_from_slice(slice);
+        Ok(buffer)
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        let bytes = self.take::<4>()?;
+        Ok(if self.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn f64(&mut self) -> Result<f64, String> {
+        let bytes = self.take::<8>()?;
+        Ok(if self.little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    fn values(&mut self, dims: Dimensions) -> Result<Vec<f64>, String> {
+        let count = 2 + usize::from(dims.has_z) + usize::from(dims.has_m);
+        (0..count).map(|_| self.f64()).collect()
+    }
+
+    fn positions(&mut self, dims: Dimensions) -> Result<Value, String> {
+        let count = self.u32()?;
+        let positions = (0..count)
+            .map(|_| self.values(dims).map(|values| dims.position(&values)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Value::from(positions))
+    }
+
+    fn rings(&mut self, dims: Dimensions) -> Result<Value, String> {
+        let count = self.u32()?;
+        let rings = (0..count)
+            .map(|_| self.positions(dims))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Value::from(rings))
+    }
+
+    /// Reads `count` nested geometries of type `expected` and collects their coordinates.
+    fn parts(&mut self, expected: &str) -> Result<Value, String> {
+        let count = self.u32()?;
+        let mut parts = Vec::new();
+        for _ in 0..count {
+            let mut part = self.geometry()?;
+            if part["type"] != expected {
+                return Err(format!("expected {expected} parts, found {}", part["type"]));
+            }
+            parts.push(part["coordinates"].take());
+        }
+        Ok(Value::from(parts))
+    }
+
+    fn geometry(&mut self) -> Result<Value, String> {
+        let outer_endianness = self.little_endian;
+        self.little_endian = match self.take::<1>()?[0] {
+            0 => false,
+            1 => true,
+            other => return Err(format!("invalid WKB byte order: {other}")),
+        };
+
+        let raw = self.u32()?;
+        let mut dims = Dimensions {
+            has_z: raw & EWKB_Z != 0,
+            has_m: raw & EWKB_M != 0,
+        };
+        let iso_type = raw & !(EWKB_Z | EWKB_M | EWKB_SRID);
+        match iso_type / 1000 {
+            1 => dims.has_z = true,
+            2 => dims.has_m = true,
+            3 => {
+                dims.has_z = true;
+                dims.has_m = true;
+            }
+            _ => {}
+        }
+        if raw & EWKB_SRID != 0 {
+            self.u32()?;
+        }
+
+        let value = match iso_type % 1000 {
+            WKB_POINT => {
+                let values = self.values(dims)?;
+                // Empty points are encoded with NaN coordinates.
+                if values.iter().all(|value| value.is_nan()) {
+                    geometry("Point", json!([]))
+                } else {
+                    geometry("Point", dims.position(&values))
+                }
+            }
+            WKB_LINE_STRING => geometry("LineString", self.positions(dims)?),
+            WKB_POLYGON => geometry("Polygon", self.rings(dims)?),
+            WKB_MULTI_POINT => geometry("MultiPoint", self.parts("Point")?),
+            WKB_MULTI_LINE_STRING => geometry("MultiLineString", self.parts("LineString")?),
+            WKB_MULTI_POLYGON => geometry("MultiPolygon", self.parts("Polygon")?),
+            WKB_GEOMETRY_COLLECTION => {
+                let count = self.u32()?;
+                let geometries = (0..count)
+                    .map(|_| self.geometry())
+                    .collect::<Result<Vec<_>, _>>()?;
+                json!({ "type": "GeometryCollection", "geometries": geometries })
+            }
+            other => return Err(format!("unsupported WKB geometry type: {other}")),
+        };
+        self.little_endian = outer_endianness;
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::{BinaryArray, StringArray};
+    use arrow_schema::Field;
+
+    use super::*;
+
+    fn wkb_point_le(x: f64, y: f64) -> Vec<u8> {
+        let mut bytes = vec![1];
+        bytes.extend(WKB_POINT.to_le_bytes());
+        bytes.extend(x.to_le_bytes());
+        bytes.extend(y.to_le_bytes());
+        bytes
+    }
+
+    #[test]
+    fn parses_wkt_geometries() {
+        assert_eq!(
+            parse_wkt("POINT (30 10)").expect("point"),
+            json!({ "type": "Point", "coordinates": [30.0, 10.0] })
+        );
+        assert_eq!(
+            parse_wkt("SRID=4326;point z(1 2 3)").expect("ewkt point"),
+            json!({ "type": "Point", "coordinates": [1.0, 2.0, 3.0] })
+        );
+        assert_eq!(
+            parse_wkt("LINESTRING M (0 0 5, 1 1 6)").expect("measured line"),
+            json!({ "type": "LineString", "coordinates": [[0.0, 0.0], [1.0, 1.0]] })
+        );
+        assert_eq!(
+            parse_wkt("POLYGON ((0 0, 4 0, 4 4, 0 0), (1 1, 2 1, 1 2, 1 1))").expect("polygon")
+                ["coordinates"][1][2],
+            json!([1.0, 2.0])
+        );
+        assert_eq!(
+            parse_wkt("MULTIPOINT ((1 2), (3 4))").expect("multipoint"),
+            parse_wkt("MULTIPOINT (1 2, 3 4)").expect("bare multipoint")
+        );
+        assert_eq!(
+            parse_wkt("GEOMETRYCOLLECTION (POINT (1 2), LINESTRING EMPTY)").expect("collection")
+                ["geometries"][1],
+            json!({ "type": "LineString", "coordinates": [] })
+        );
+        assert!(parse_wkt("POINT (1)").is_err());
+        assert!(parse_wkt("CIRCLE (1 2)").is_err());
+        assert!(parse_wkt("POINT (1 2) extra").is_err());
+    }
+
+    #[test]
+    fn parses_wkb_in_both_byte_orders() {
+        assert_eq!(
+            parse_wkb(&wkb_point_le(1.5, -2.0)).expect("point"),
+            json!({ "type": "Point", "coordinates": [1.5, -2.0] })
+        );
+
+        // Big-endian ISO LineString Z with two positions.
+        let mut line = vec![0];
+        line.extend((1000 + WKB_LINE_STRING).to_be_bytes());
+        line.extend(2u32.to_be_bytes());
+        for value in [0.0f64, 1.0, 2.0, 3.0, 4.0, 5.0] {
+            line.extend(value.to_be_bytes());
+        }
+        assert_eq!(
+            parse_wkb(&line).expect("line"),
+            json!({ "type": "LineString", "coordinates": [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]] })
+        );
+
+        // Little-endian EWKB MultiPoint with an SRID and big-endian parts.
+        let mut multi = vec![1];
+        multi.extend((WKB_MULTI_POINT | EWKB_SRID).to_le_bytes());
+        multi.extend(4326u32.to_le_bytes());
+        multi.extend(2u32.to_le_bytes());
+        for (x, y) in [(1.0f64, 2.0f64), (3.0, 4.0)] {
+            multi.push(0);
+            multi.extend(WKB_POINT.to_be_bytes());
+            multi.extend(x.to_be_bytes());
+            multi.extend(y.to_be_bytes());
+        }
+        assert_eq!(
+            parse_wkb(&multi).expect("multipoint"),
+            json!({ "type": "MultiPoint", "coordinates": [[1.0, 2.0], [3.0, 4.0]] })
+        );
+
+        assert!(parse_wkb(&wkb_point_le(1.0, 2.0)[..12]).is_err());
+    }
+
+    #[test]
+    fn resolves_geometry_columns_and_builds_features() {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("footprint_wkb", DataType::Binary, true),
+            Field::new("label", DataType::Utf8, true),
+        ]);
+        assert_eq!(
+            resolve_column(&schema, None).expect("detected"),
+            ("footprint_wkb".to_string(), GeometryEncodingV1::Wkb)
+        );
+        assert_eq!(
+            resolve_column(&schema, Some("label")).expect("requested"),
+            ("label".to_string(), GeometryEncodingV1::Wkt)
+        );
+        assert!(resolve_column(&schema, Some("id")).is_err());
+        assert!(resolve_column(&schema, Some("missing")).is_err());
+
+        let point = wkb_point_le(1.0, 2.0);
+        let wkb: Arc<dyn Array> = Arc::new(BinaryArray::from(vec![Some(point.as_slice()), None]));
+        let geometries = column_geometries(wkb.as_ref(), GeometryEncodingV1::Wkb).expect("wkb");
+        assert_eq!(geometries[1], Value::Null);
+
+        let wkt = StringArray::from(vec!["POINT (1 2)", " "]);
+        assert_eq!(
+            column_geometries(&wkt, GeometryEncodingV1::Wkt).expect("wkt"),
+            geometries
+        );
+
+        let collection =
+            feature_collection(geometries, vec![json!({ "id": 1 }), json!({ "id": 2 })]);
+        assert_eq!(collection["type"], "FeatureCollection");
+        assert_eq!(collection["features"][0]["geometry"]["type"], "Point");
+        assert_eq!(collection["features"][1]["properties"]["id"], 2);
+    }
+}
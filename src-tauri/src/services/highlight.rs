@@ -0,0 +1,145 @@
+use crate::ipc::v1::{FtsHighlightV1, TextRangeV1};
+
+/// Characters of context kept on each side of the first match in a snippet.
+const SNIPPET_CONTEXT_CHARS: usize = 60;
+/// Longer values are cut to a snippet around the first match.
+const MAX_SNIPPET_CHARS: usize = 200;
+
+/// Lowercased words of a full-text query, split like lance's simple tokenizer.
+pub fn query_terms(query: &str) -> Vec<String> {
+    let mut terms = Vec::new();
+    for word in words(query) {
+        let term = query[word.0..word.1].to_lowercase();
+        if !terms.contains(&term) {
+            terms.push(term);
+        }
+    }
+    terms
+}
+
+/// Byte ranges of the alphanumeric words in `text`.
+fn words(text: &str) -> Vec<(usize, usize)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (index, character) in text.char_indices() {
+        match (character.is_alphanumeric(), start) {
+            (true, None) => start = Some(index),
+            (false, Some(begin)) => {
+                words.push((begin, index));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(begin) = start {
+        words.push((begin, text.len()));
+    }
+    words
+}
+
+/// Offset of byte `index` in UTF-16 code units, the way JavaScript indexes strings.
+fn utf16_offset(text: &str, index: usize) -> u32 {
+    text[..index].encode_utf16().count() as u32
+}
+
+/// The words of `text` that equal a query term, as a snippet with the matches
+/// marked. Stemmed or fuzzy matches of the index are not highlighted.
+pub fn highlight(
+    row_index: usize,
+    column: &str,
+    text: &str,
+    terms: &[String],
+) -> Option<FtsHighlightV1> {
+    let matches = words(text)
+        .into_iter()
+        .filter(|(start, end)| terms.contains(&text[*start..*end].to_lowercase()))
+        .collect::<Vec<_>>();
+    let first = matches.first()?;
+
+    let (mut snippet_start, mut snippet_end) = (0, text.len());
+    if text.chars().count() > MAX_SNIPPET_CHARS {
+        snippet_start = text[..first.0]
+            .char_indices()
+            .rev()
+            .nth(SNIPPET_CONTEXT_CHARS.saturating_sub(1))
+            .map_or(0, |(index, _)| index);
+        snippet_end = text[snippet_start..]
+            .char_indices()
+            .nth(MAX_SNIPPET_CHARS)
+            .map_or(text.len(), |(index, _)| snippet_start + index);
+    }
+    let prefix = if snippet_start > 0 { "…" } else { "" };
+    let suffix = if snippet_end < text.len() { "…" } else { "" };
+    let snippet = format!("{prefix}{}{suffix}", &text[snippet_start..snippet_end]);
+    let shift = prefix.len();
+    let ranges = matches
+        .into_iter()
+        .filter(|(start, end)| *start >= snippet_start && *end <= snippet_end)
+        .map(|(start, end)| TextRangeV1 {
+            start: utf16_offset(&snippet, start - snippet_start + shift),
+            end: utf16_offset(&snippet, end - snippet_start + shift),
+        })
+        .collect();
+    Some(FtsHighlightV1 {
+        row_index,
+        column: column.to_string(),
+        snippet,
+        ranges,
+    })
+}
+
+/// Highlights of the string values of `columns` in search result rows.
+pub fn highlight_rows(
+    rows: &[serde_json::Value],
+    columns: &[String],
+    terms: &[String],
+) -> Vec<FtsHighlightV1> {
+    if terms.is_empty() {
+        return Vec::new();
+    }
+    let mut highlights = Vec::new();
+    for (row_index, row) in rows.iter().enumerate() {
+        for column in columns {
+            if let Some(text) = row.get(column).and_then(serde_json::Value::as_str) {
+                highlights.extend(highlight(row_index, column, text, terms));
+            }
+        }
+    }
+    highlights
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(highlight: &FtsHighlightV1) -> Vec<String> {
+        let units = highlight.snippet.encode_utf16().collect::<Vec<_>>();
+        highlight
+            .ranges
+            .iter()
+            .map(|range| {
+                String::from_utf16(&units[range.start as usize..range.end as usize]).expect("utf16")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn marks_whole_words_matching_the_query() {
+        let terms = query_terms("Lance, vector lance");
+        assert_eq!(terms, vec!["lance", "vector"]);
+        let found =
+            highlight(2, "text", "Lance stores vectors; lance is fast", &terms).expect("match");
+        assert_eq!(found.row_index, 2);
+        assert_eq!(ranges(&found), vec!["Lance", "lance"]);
+        assert!(highlight(0, "text", "nothing here", &terms).is_none());
+    }
+
+    #[test]
+    fn cuts_long_values_around_the_first_match() {
+        let text = format!("{} 数据 lance {}", "a".repeat(300), "b".repeat(300));
+        let found = highlight(0, "text", &text, &["lance".to_string()]).expect("match");
+        assert!(found.snippet.starts_with('…') && found.snippet.ends_with('…'));
+        assert!(found.snippet.chars().count() <= MAX_SNIPPET_CHARS + 2);
+        assert_eq!(ranges(&found), vec!["lance"]);
+    }
+}
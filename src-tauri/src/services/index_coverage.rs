@@ -0,0 +1,140 @@
+use crate::ipc::v1::{
+    IndexReadinessV1, IndexTypeV1, SearchIndexKindV1, SearchWarningKindV1, SearchWarningV1,
+};
+
+/// Share of unindexed rows from which an index is reported as stale.
+pub const STALE_UNINDEXED_RATIO: f64 = 0.1;
+
+/// An index on a searched column and how many rows it covers.
+#[derive(Debug, Clone)]
+pub struct IndexCoverage {
+    pub name: String,
+    pub index_type: IndexTypeV1,
+    pub column: Option<String>,
+    pub indexed_rows: u64,
+    pub unindexed_rows: u64,
+}
+
+pub fn is_stale(indexed_rows: u64, unindexed_rows: u64) -> bool {
+    let total = indexed_rows + unindexed_rows;
+    total > 0 && unindexed_rows as f64 / total as f64 >= STALE_UNINDEXED_RATIO
+}
+
+/// Share of the rows an index covers; an empty table counts as fully covered.
+pub fn coverage_ratio(indexed_rows: u64, unindexed_rows: u64) -> f64 {
+    let total = indexed_rows + unindexed_rows;
+    if total == 0 {
+        1.0
+    } else {
+        indexed_rows as f64 / total as f64
+    }
+}
+
+pub fn readiness(indexed_rows: u64, unindexed_rows: u64) -> IndexReadinessV1 {
+    if unindexed_rows == 0 {
+        IndexReadinessV1::Ready
+    } else if is_stale(indexed_rows, unindexed_rows) {
+        IndexReadinessV1::Stale
+    } else {
+        IndexReadinessV1::Partial
+    }
+}
+
+fn index_label(kind: SearchIndexKindV1) -> &'static str {
+    match kind {
+        SearchIndexKindV1::Vector => "vector",
+        SearchIndexKindV1::Fts => "full-text",
+    }
+}
+
+pub fn missing_index(kind: SearchIndexKindV1, column: Option<&str>) -> SearchWarningV1 {
+    let label = index_label(kind);
+    let message = match column {
+        Some(column) => {
+            format!("column {column} has no {label} index; the search scanned every row")
+        }
+        None => format!("the table has no {label} index; the search scanned every row"),
+    };
+    SearchWarningV1 {
+        kind: SearchWarningKindV1::MissingIndex,
+        index_kind: kind,
+        column: column.map(str::to_string),
+        index_name: None,
+        index_type: None,
+        indexed_rows: None,
+        unindexed_rows: None,
+        message,
+    }
+}
+
+/// Warns when `coverage` leaves at least `STALE_UNINDEXED_RATIO` of the rows unindexed.
+pub fn stale_index(kind: SearchIndexKindV1, coverage: IndexCoverage) -> Option<SearchWarningV1> {
+    if !is_stale(coverage.indexed_rows, coverage.unindexed_rows) {
+        return None;
+    }
+    let total = coverage.indexed_rows + coverage.unindexed_rows;
+    Some(SearchWarningV1 {
+        kind: SearchWarningKindV1::StaleIndex,
+        index_kind: kind,
+        message: format!(
+            "index {} does not cover {} of {total} rows; optimize the table to index them",
+            coverage.name, coverage.unindexed_rows
+        ),
+        column: coverage.column,
+        index_name: Some(coverage.name),
+        index_type: Some(coverage.index_type),
+        indexed_rows: Some(coverage.indexed_rows),
+        unindexed_rows: Some(coverage.unindexed_rows),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coverage(indexed_rows: u64, unindexed_rows: u64) -> IndexCoverage {
+        IndexCoverage {
+            name: "vector_idx".to_string(),
+            index_type: IndexTypeV1::IvfPq,
+            column: Some("vector".to_string()),
+            indexed_rows,
+            unindexed_rows,
+        }
+    }
+
+    #[test]
+    fn reports_indexes_with_many_unindexed_rows() {
+        assert!(!is_stale(0, 0));
+        assert!(!is_stale(950, 50));
+        assert!(is_stale(900, 100));
+        assert!(is_stale(0, 10));
+
+        assert!(stale_index(SearchIndexKindV1::Vector, coverage(990, 10)).is_none());
+        let warning = stale_index(SearchIndexKindV1::Vector, coverage(600, 400)).expect("warning");
+        assert_eq!(warning.kind, SearchWarningKindV1::StaleIndex);
+        assert_eq!(warning.index_name.as_deref(), Some("vector_idx"));
+        assert_eq!(warning.unindexed_rows, Some(400));
+        assert!(warning.message.contains("400 of 1000 rows"));
+    }
+
+    #[test]
+    fn grades_index_readiness() {
+        assert_eq!(readiness(0, 0), IndexReadinessV1::Ready);
+        assert_eq!(readiness(1000, 0), IndexReadinessV1::Ready);
+        assert_eq!(readiness(950, 50), IndexReadinessV1::Partial);
+        assert_eq!(readiness(900, 100), IndexReadinessV1::Stale);
+        assert_eq!(coverage_ratio(0, 0), 1.0);
+        assert_eq!(coverage_ratio(750, 250), 0.75);
+    }
+
+    #[test]
+    fn describes_missing_indexes() {
+        let warning = missing_index(SearchIndexKindV1::Fts, Some("text"));
+        assert_eq!(warning.kind, SearchWarningKindV1::MissingIndex);
+        assert_eq!(warning.column.as_deref(), Some("text"));
+        assert!(warning.message.contains("no full-text index"));
+        assert!(missing_index(SearchIndexKindV1::Vector, None)
+            .column
+            .is_none());
+    }
+}
@@ -0,0 +1,169 @@
+use arrow_schema::{DataType, Schema};
+
+use crate::ipc::v1::{CreateIndexRequestV1, IndexPresetParametersV1, IndexPresetV1, IndexTypeV1};
+
+/// Largest partition count a preset picks; beyond it k-means training dominates
+/// the build.
+const MAX_PARTITIONS: u32 = 16_384;
+
+struct Tuning {
+    /// Rows per IVF partition.
+    partition_rows: usize,
+    /// Vector dimensions per PQ sub-vector.
+    sub_vector_dims: usize,
+    sample_rate: u32,
+    max_iterations: u32,
+    num_edges: u32,
+    ef_construction: u32,
+}
+
+fn tuning(preset: IndexPresetV1) -> Tuning {
+    match preset {
+        IndexPresetV1::FastBuild => Tuning {
+            partition_rows: 8_192,
+            sub_vector_dims: 16,
+            sample_rate: 64,
+            max_iterations: 20,
+            num_edges: 12,
+            ef_construction: 100,
+        },
+        IndexPresetV1::Balanced => Tuning {
+            partition_rows: 4_096,
+            sub_vector_dims: 8,
+            sample_rate: 256,
+            max_iterations: 50,
+            num_edges: 20,
+            ef_construction: 300,
+        },
+        IndexPresetV1::MaxRecall => Tuning {
+            partition_rows: 4_096,
+            sub_vector_dims: 4,
+            sample_rate: 512,
+            max_iterations: 100,
+            num_edges: 32,
+            ef_construction: 500,
+        },
+    }
+}
+
+fn uses_pq(index_type: &IndexTypeV1) -> bool {
+    matches!(index_type, IndexTypeV1::IvfPq | IndexTypeV1::IvfHnswPq)
+}
+
+fn uses_hnsw(index_type: &IndexTypeV1) -> bool {
+    matches!(index_type, IndexTypeV1::IvfHnswPq | IndexTypeV1::IvfHnswSq)
+}
+
+/// Rejects presets on index types they have no parameters for.
+pub fn validate(index_type: &IndexTypeV1) -> Result<(), String> {
+    match index_type {
+        IndexTypeV1::IvfFlat
+        | IndexTypeV1::IvfSq
+        | IndexTypeV1::IvfPq
+        | IndexTypeV1::IvfRq
+        | IndexTypeV1::IvfHnswPq
+        | IndexTypeV1::IvfHnswSq => Ok(()),
+        _ => Err(format!(
+            "index presets apply to IVF vector indexes, not {index_type:?}"
+        )),
+    }
+}
+
+/// Dimension of the fixed size list vector `column`.
+pub fn vector_dimension(schema: &Schema, column: &str) -> Result<usize, String> {
+    let field = schema
+        .field_with_name(column)
+        .map_err(|_| format!("column '{column}' does not exist"))?;
+    match field.data_type() {
+        DataType::FixedSizeList(_, size) if *size > 0 => Ok(*size as usize),
+        _ => Err(format!("column '{column}' is not a vector column")),
+    }
+}
+
+/// Largest divisor of `dimension` that is at most `limit`, so sub-vectors split
+/// the vector evenly.
+fn sub_vector_count(dimension: usize, limit: usize) -> u32 {
+    let limit = limit.clamp(1, dimension.max(1));
+    (1..=limit)
+        .rev()
+        .find(|count| dimension % count == 0)
+        .unwrap_or(1) as u32
+}
+
+/// Parameters `preset` picks for `index_type` on a table of `rows` vectors of
+/// `dimension` values.
+pub fn parameters(
+    preset: IndexPresetV1,
+    index_type: &IndexTypeV1,
+    rows: usize,
+    dimension: usize,
+) -> IndexPresetParametersV1 {
+    let tuning = tuning(preset);
+    let partitions = (rows / tuning.partition_rows).clamp(1, MAX_PARTITIONS as usize) as u32;
+    IndexPresetParametersV1 {
+        preset,
+        num_partitions: partitions,
+        sample_rate: tuning.sample_rate,
+        max_iterations: tuning.max_iterations,
+        num_sub_vectors: uses_pq(index_type)
+            .then(|| sub_vector_count(dimension, dimension / tuning.sub_vector_dims)),
+        num_edges: uses_hnsw(index_type).then_some(tuning.num_edges),
+        ef_construction: uses_hnsw(index_type).then_some(tuning.ef_construction),
+    }
+}
+
+/// Fills the parameters `request` leaves unset from `parameters`; explicit values
+/// win over the preset.
+pub fn apply(request: &mut CreateIndexRequestV1, parameters: &IndexPresetParametersV1) {
+    request
+        .num_partitions
+        .get_or_insert(parameters.num_partitions);
+    request.sample_rate.get_or_insert(parameters.sample_rate);
+    request
+        .max_iterations
+        .get_or_insert(parameters.max_iterations);
+    if request.num_sub_vectors.is_none() {
+        request.num_sub_vectors = parameters.num_sub_vectors;
+    }
+    if request.num_edges.is_none() {
+        request.num_edges = parameters.num_edges;
+    }
+    if request.ef_construction.is_none() {
+        request.ef_construction = parameters.ef_construction;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scales_presets_with_rows_and_dimension() {
+        let fast = parameters(
+            IndexPresetV1::FastBuild,
+            &IndexTypeV1::IvfPq,
+            1_000_000,
+            768,
+        );
+        assert_eq!(fast.num_partitions, 122);
+        assert_eq!(fast.num_sub_vectors, Some(48));
+        assert_eq!(fast.num_edges, None);
+
+        let recall = parameters(
+            IndexPresetV1::MaxRecall,
+            &IndexTypeV1::IvfHnswPq,
+            1_000_000,
+            768,
+        );
+        assert_eq!(recall.num_partitions, 244);
+        assert_eq!(recall.num_sub_vectors, Some(192));
+        assert_eq!(recall.ef_construction, Some(500));
+
+        let small = parameters(IndexPresetV1::Balanced, &IndexTypeV1::IvfPq, 50, 3);
+        assert_eq!((small.num_partitions, small.num_sub_vectors), (1, Some(1)));
+        assert_eq!(sub_vector_count(100, 12), 10);
+
+        assert!(validate(&IndexTypeV1::BTree).is_err());
+        assert!(validate(&IndexTypeV1::IvfRq).is_ok());
+    }
+}
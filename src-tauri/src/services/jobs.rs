@@ -0,0 +1,387 @@
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::ipc::v1::{JobKindV1, JobStateV1, JobStatusV1, ProgressEventV1, ProgressStageV1};
+use crate::services::clock::now_unix_millis;
+
+const MAX_FINISHED_JOBS: usize = 100;
+/// Tauri event carrying a `ProgressEventV1`.
+pub const PROGRESS_EVENT: &str = "job-progress";
+
+/// Receives every progress event; the app forwards them to the frontend.
+pub type ProgressSink = Box<dyn Fn(&ProgressEventV1) + Send + Sync>;
+
+tokio::task_local! {
+    static CURRENT_JOB: String;
+}
+
+/// Runs `future` as the job `job_id`, so the command inside can report stages
+/// through `current` without threading the id down.
+pub async fn scope<F: Future>(job_id: String, future: F) -> F::Output {
+    CURRENT_JOB.scope(job_id, future).await
+}
+
+/// Job the calling command runs as, if it was started through `scope`.
+pub fn current() -> Option<String> {
+    CURRENT_JOB.try_with(|job_id| job_id.clone()).ok()
+}
+
+#[derive(Default)]
+pub struct JobRegistry {
+    jobs: HashMap<String, JobStatusV1>,
+    /// Jobs whose owning command has not called `finish` yet. A cancelled job stays
+    /// here until its task reaches the next progress checkpoint and returns.
+    active: HashSet<String>,
+    /// Cancelled when the job is, so commands that race their work against it stop
+    /// at once instead of at the next checkpoint.
+    tokens: HashMap<String, CancellationToken>,
+    sink: Option<ProgressSink>,
+}
+
+impl JobRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_progress_sink(&mut self, sink: ProgressSink) {
+        self.sink = Some(sink);
+    }
+
+    /// Registers a running job. Callers may pass their own id so the frontend can poll
+    /// `get_job_status_v1` while the command that owns the job is still in flight.
+    /// Fails when a job with that id is still executing, cancelled or not, so one id
+    /// never stands for two tasks.
+    pub fn start(&mut self, job_id: Option<String>, kind: JobKindV1) -> Result<String, String> {
+        let job_id = job_id
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .unwrap_or_else(|| Uuid::new_v4().to_string());
+        if self.active.contains(&job_id) {
+            return Err(format!("job '{job_id}' is already running"));
+        }
+        self.prune_finished();
+        self.active.insert(job_id.clone());
+        self.tokens.insert(job_id.clone(), CancellationToken::new());
+        self.jobs.insert(
+            job_id.clone(),
+            JobStatusV1 {
+                job_id: job_id.clone(),
+                kind,
+                state: JobStateV1::Running,
+                processed_rows: 0,
+                total_rows: None,
+                message: None,
+                started_at_ms: now_unix_millis(),
+                finished_at_ms: None,
+                stage: Some(ProgressStageV1::Starting),
+            },
+        );
+        self.emit(&job_id);
+        Ok(job_id)
+    }
+
+    pub fn set_total(&mut self, job_id: &str, total_rows: Option<u64>) {
+        if let Some(job) = self.jobs.get_mut(job_id) {
+            job.total_rows = total_rows;
+        }
+    }
+
+    pub fn set_progress(&mut self, job_id: &str, processed_rows: u64) {
+        if let Some(job) = self.jobs.get_mut(job_id) {
+            job.processed_rows = processed_rows;
+        }
+        self.emit(job_id);
+    }
+
+    /// Moves a running job to `stage`; finished jobs keep their last stage.
+    pub fn set_stage(&mut self, job_id: &str, stage: ProgressStageV1) {
+        match self.jobs.get_mut(job_id) {
+            Some(job) if job.state == JobStateV1::Running => job.stage = Some(stage),
+            _ => return,
+        }
+        self.emit(job_id);
+    }
+
+    /// Records the outcome of a job. A job that was already cancelled keeps its
+    /// cancellation message.
+    pub fn finish(&mut self, job_id: &str, result: Result<(), String>) {
+        self.active.remove(job_id);
+        self.tokens.remove(job_id);
+        if let Some(job) = self.jobs.get_mut(job_id) {
+            if job.state != JobStateV1::Running {
+                return;
+            }
+            match result {
+                Ok(()) => job.state = JobStateV1::Succeeded,
+                Err(error) => {
+                    job.state = JobStateV1::Failed;
+                    job.message = Some(error);
+                }
+            }
+            job.finished_at_ms = Some(now_unix_millis());
+            job.stage = Some(ProgressStageV1::Finished);
+        }
+        self.emit(job_id);
+    }
+
+    /// Marks one running job cancelled with `message`. Returns false when the job is
+    /// unknown or has already finished.
+    pub fn cancel(&mut self, job_id: &str, message: &str) -> bool {
+        let Some(job) = self.jobs.get_mut(job_id) else {
+            return false;
+        };
+        if job.state != JobStateV1::Running {
+            return false;
+        }
+        job.state = JobStateV1::Cancelled;
+        job.message = Some(message.to_string());
+        job.finished_at_ms = Some(now_unix_millis());
+        job.stage = Some(ProgressStageV1::Finished);
+        if let Some(token) = self.tokens.get(job_id) {
+            token.cancel();
+        }
+        self.emit(job_id);
+        true
+    }
+
+    /// Marks every running job cancelled with `message` and returns how many were
+    /// cancelled. Owning tasks notice at their next progress update.
+    pub fn cancel_running(&mut self, message: &str) -> usize {
+        let finished_at_ms = now_unix_millis();
+        let mut cancelled = Vec::new();
+        for job in self.jobs.values_mut() {
+            if job.state == JobStateV1::Running {
+                job.state = JobStateV1::Cancelled;
+                job.message = Some(message.to_string());
+                job.finished_at_ms = Some(finished_at_ms);
+                job.stage = Some(ProgressStageV1::Finished);
+                cancelled.push(job.job_id.clone());
+            }
+        }
+        for token in self.tokens.values() {
+            token.cancel();
+        }
+        for job_id in &cancelled {
+            self.emit(job_id);
+        }
+        cancelled.len()
+    }
+
+    pub fn is_running(&self, job_id: &str) -> bool {
+        self.jobs
+            .get(job_id)
+            .is_some_and(|job| job.state == JobStateV1::Running)
+    }
+
+    /// Token of a job whose owning command has not finished yet.
+    pub fn token(&self, job_id: &str) -> Option<CancellationToken> {
+        self.tokens.get(job_id).cloned()
+    }
+
+    pub fn get(&self, job_id: &str) -> Option<JobStatusV1> {
+        self.jobs.get(job_id).cloned()
+    }
+
+    /// Jobs that have not finished yet, oldest first.
+    pub fn running(&self) -> Vec<JobStatusV1> {
+        let mut running = self
+            .jobs
+            .values()
+            .filter(|job| job.state == JobStateV1::Running)
+            .cloned()
+            .collect::<Vec<_>>();
+        running.sort_by_key(|job| job.started_at_ms);
+        running
+    }
+
+    /// Tracked jobs matching the filters, most recently started first.
+    pub fn list(
+        &self,
+        state: Option<JobStateV1>,
+        kind: Option<JobKindV1>,
+        limit: Option<usize>,
+    ) -> Vec<JobStatusV1> {
+        let mut jobs = self
+            .jobs
+            .values()
+            .filter(|job| state.is_none_or(|state| job.state == state))
+            .filter(|job| kind.is_none_or(|kind| job.kind == kind))
+            .cloned()
+            .collect::<Vec<_>>();
+        jobs.sort_by(|left, right| {
+            right
+                .started_at_ms
+                .cmp(&left.started_at_ms)
+                .then_with(|| left.job_id.cmp(&right.job_id))
+        });
+        if let Some(limit) = limit {
+            jobs.truncate(limit);
+        }
+        jobs
+    }
+
+    pub fn job_count(&self) -> usize {
+        self.jobs.len()
+    }
+
+    /// Number of jobs whose owning task is still executing, including cancelled ones.
+    pub fn active_count(&self) -> usize {
+        self.active.len()
+    }
+
+    fn emit(&self, job_id: &str) {
+        let (Some(sink), Some(job)) = (self.sink.as_ref(), self.jobs.get(job_id)) else {
+            return;
+        };
+        sink(&progress_event(job));
+    }
+
+    fn prune_finished(&mut self) {
+        let mut finished: Vec<(String, u64)> = self
+            .jobs
+            .values()
+            .filter_map(|job| job.finished_at_ms.map(|at| (job.job_id.clone(), at)))
+            .collect();
+        if finished.len() < MAX_FINISHED_JOBS {
+            return;
+        }
+        finished.sort_by_key(|(_, at)| *at);
+        let excess = finished.len() + 1 - MAX_FINISHED_JOBS;
+        for (job_id, _) in finished.into_iter().take(excess) {
+            self.jobs.remove(&job_id);
+        }
+    }
+}
+
+/// Progress of a job as sent to the frontend. Jobs that have not reported a total
+/// have no percentage until they finish.
+pub fn progress_event(job: &JobStatusV1) -> ProgressEventV1 {
+    let percent = match (job.state, job.total_rows) {
+        (JobStateV1::Succeeded, _) => Some(100.0),
+        (_, Some(0)) => None,
+        (_, Some(total)) => Some((job.processed_rows as f64 / total as f64 * 100.0).min(100.0)),
+        (_, None) => None,
+    };
+    ProgressEventV1 {
+        job_id: job.job_id.clone(),
+        kind: job.kind,
+        state: job.state,
+        stage: job.stage.unwrap_or(ProgressStageV1::Starting),
+        percent,
+        message: job.message.clone(),
+        processed_rows: job.processed_rows,
+        total_rows: job.total_rows,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    #[test]
+    fn cancelling_a_job_cancels_its_token() {
+        let mut jobs = JobRegistry::new();
+        let job_id = jobs
+            .start(Some("scan-1".to_string()), JobKindV1::Scan)
+            .expect("start job");
+        let token = jobs.token(&job_id).expect("token");
+        assert!(!token.is_cancelled());
+        assert!(jobs.cancel(&job_id, "job cancelled"));
+        assert!(token.is_cancelled());
+        jobs.finish(&job_id, Err("cancelled".to_string()));
+        assert!(jobs.token(&job_id).is_none());
+        let job = jobs.get(&job_id).expect("job");
+        assert_eq!(job.state, JobStateV1::Cancelled);
+        assert_eq!(job.message.as_deref(), Some("job cancelled"));
+
+        let other = jobs.start(None, JobKindV1::Import).expect("start job");
+        let token = jobs.token(&other).expect("token");
+        assert_eq!(jobs.cancel_running("shutting down"), 1);
+        assert!(token.is_cancelled());
+        assert_eq!(
+            jobs.get(&other).map(|job| job.state),
+            Some(JobStateV1::Cancelled)
+        );
+    }
+
+    #[test]
+    fn rejects_ids_of_jobs_that_are_still_running() {
+        let mut jobs = JobRegistry::new();
+        jobs.start(Some("backup-1".to_string()), JobKindV1::Backup)
+            .expect("start job");
+        assert!(jobs
+            .start(Some(" backup-1 ".to_string()), JobKindV1::Backup)
+            .is_err());
+        assert!(jobs.cancel("backup-1", "job cancelled"));
+        assert!(jobs
+            .start(Some("backup-1".to_string()), JobKindV1::Backup)
+            .is_err());
+        jobs.finish("backup-1", Err("cancelled".to_string()));
+        jobs.start(Some("backup-1".to_string()), JobKindV1::Backup)
+            .expect("reuse a finished id");
+        assert!(jobs.is_running("backup-1"));
+    }
+
+    #[test]
+    fn lists_jobs_by_state_and_kind() {
+        let mut jobs = JobRegistry::new();
+        let import = jobs
+            .start(Some("import-1".to_string()), JobKindV1::Import)
+            .expect("start job");
+        let export = jobs
+            .start(Some("export-1".to_string()), JobKindV1::Export)
+            .expect("start job");
+        jobs.finish(&import, Ok(()));
+
+        let all = jobs.list(None, None, None);
+        assert_eq!(all.len(), 2);
+        let running = jobs.list(Some(JobStateV1::Running), None, None);
+        assert_eq!(running.len(), 1);
+        assert_eq!(running[0].job_id, export);
+        let imports = jobs.list(None, Some(JobKindV1::Import), None);
+        assert_eq!(imports.len(), 1);
+        assert_eq!(imports[0].state, JobStateV1::Succeeded);
+        assert_eq!(jobs.list(None, None, Some(1)).len(), 1);
+    }
+
+    #[test]
+    fn reports_stages_and_percent_to_the_progress_sink() {
+        let events = Arc::new(Mutex::new(Vec::<ProgressEventV1>::new()));
+        let mut jobs = JobRegistry::new();
+        let sink = Arc::clone(&events);
+        jobs.set_progress_sink(Box::new(move |event| {
+            sink.lock().expect("events").push(event.clone());
+        }));
+
+        let job_id = jobs
+            .start(Some("copy-1".to_string()), JobKindV1::Backup)
+            .expect("start job");
+        jobs.set_stage(&job_id, ProgressStageV1::Copying);
+        jobs.set_total(&job_id, Some(200));
+        jobs.set_progress(&job_id, 50);
+        jobs.finish(&job_id, Ok(()));
+        jobs.set_stage(&job_id, ProgressStageV1::Writing);
+
+        let events = events.lock().expect("events");
+        let stages = events.iter().map(|event| event.stage).collect::<Vec<_>>();
+        assert_eq!(
+            stages,
+            vec![
+                ProgressStageV1::Starting,
+                ProgressStageV1::Copying,
+                ProgressStageV1::Copying,
+                ProgressStageV1::Finished,
+            ]
+        );
+        assert!(events.iter().all(|event| event.job_id == "copy-1"));
+        assert_eq!(events[1].percent, None);
+        assert_eq!(events[2].percent, Some(25.0));
+        assert_eq!(events[3].state, JobStateV1::Succeeded);
+        assert_eq!(events[3].percent, Some(100.0));
+    }
+}
@@ -0,0 +1,328 @@
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float64Type, Int64Type, UInt64Type};
+use arrow_array::{Array, ArrayRef, RecordBatch, StringArray};
+use arrow_json::writer::{JsonArray, WriterBuilder};
+use arrow_schema::{DataType, Field, Schema, TimeUnit};
+use base64::{engine::general_purpose, Engine as _};
+use serde_json::Value;
+
+use crate::ipc::v1::{
+    BinaryFormatV1, JsonOptionsV1, LargeIntegerFormatV1, NonFiniteFloatFormatV1, TimestampFormatV1,
+};
+
+/// Largest integer a JavaScript number represents exactly, 2^53 - 1.
+pub const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+/// A NaN or infinite float written back into a row after encoding, since
+/// ArrayWriter always renders them as null.
+struct NonFiniteValue {
+    row: usize,
+    column: String,
+    label: &'static str,
+}
+
+fn cast(column: &ArrayRef, data_type: &DataType) -> Result<ArrayRef, String> {
+    arrow_cast::cast(column, data_type).map_err(|error| error.to_string())
+}
+
+fn base64_strings(column: &ArrayRef) -> Result<ArrayRef, String> {
+    let binary = cast(column, &DataType::LargeBinary)?;
+    let strings = binary
+        .as_binary::<i64>()
+        .iter()
+        .map(|value| value.map(|bytes| general_purpose::STANDARD.encode(bytes)))
+        .collect::<StringArray>();
+    Ok(Arc::new(strings))
+}
+
+fn is_large_integer(data_type: &DataType) -> bool {
+    matches!(data_type, DataType::Int64 | DataType::UInt64)
+}
+
+fn exceeds_safe_range(column: &ArrayRef) -> bool {
+    match column.data_type() {
+        DataType::Int64 => column
+            .as_primitive::<Int64Type>()
+            .iter()
+            .flatten()
+            .any(|value| value.unsigned_abs() > MAX_SAFE_INTEGER),
+        DataType::UInt64 => column
+            .as_primitive::<UInt64Type>()
+            .iter()
+            .flatten()
+            .any(|value| value > MAX_SAFE_INTEGER),
+        _ => false,
+    }
+}
+
+/// Int64/UInt64 columns rendered as strings. In `auto` mode a column qualifies when
+/// any batch holds an unsafe value, so every row of the column uses the same type.
+fn string_encoded_columns(batches: &[RecordBatch], format: LargeIntegerFormatV1) -> Vec<String> {
+    let Some(first) = batches.first() else {
+        return Vec::new();
+    };
+    first
+        .schema()
+        .fields()
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| is_large_integer(field.data_type()))
+        .filter(|(index, _)| match format {
+            LargeIntegerFormatV1::Number => false,
+            LargeIntegerFormatV1::String => true,
+            LargeIntegerFormatV1::Auto => batches
+                .iter()
+                .any(|batch| exceeds_safe_range(batch.column(*index))),
+        })
+        .map(|(_, field)| field.name().clone())
+        .collect()
+}
+
+/// Converts a column whose default rendering the options override, or returns `None`
+/// to keep it as is.
+fn convert_column(
+    field: &Field,
+    column: &ArrayRef,
+    options: &JsonOptionsV1,
+    string_columns: &[String],
+) -> Result<Option<ArrayRef>, String> {
+    let converted = match column.data_type() {
+        DataType::Int64 | DataType::UInt64 if string_columns.contains(field.name()) => {
+            cast(column, &DataType::Utf8)?
+        }
+        DataType::Timestamp(_, time_zone)
+            if options.timestamps == TimestampFormatV1::EpochMillis =>
+        {
+            let millis = cast(
+                column,
+                &DataType::Timestamp(TimeUnit::Millisecond, time_zone.clone()),
+            )?;
+            cast(&millis, &DataType::Int64)?
+        }
+        DataType::Binary
+        | DataType::LargeBinary
+        | DataType::BinaryView
+        | DataType::FixedSizeBinary(_)
+            if options.binary == BinaryFormatV1::Base64 =>
+        {
+            base64_strings(column)?
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(converted))
+}
+
+/// Rewrites the top-level columns the options render differently from ArrayWriter.
+fn prepare_batch(
+    batch: &RecordBatch,
+    options: &JsonOptionsV1,
+    string_columns: &[String],
+) -> Result<RecordBatch, String> {
+    let schema = batch.schema();
+    let mut fields = Vec::with_capacity(schema.fields().len());
+    let mut columns = Vec::with_capacity(batch.num_columns());
+    let mut changed = false;
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        match convert_column(field, column, options, string_columns)? {
+            Some(converted) => {
+                changed = true;
+                fields.push(Field::new(
+                    field.name(),
+                    converted.data_type().clone(),
+                    field.is_nullable(),
+                ));
+                columns.push(converted);
+            }
+            None => {
+                fields.push(field.as_ref().clone());
+                columns.push(column.clone());
+            }
+        }
+    }
+    if !changed {
+        return Ok(batch.clone());
+    }
+    RecordBatch::try_new(Arc::new(Schema::new(fields)), columns).map_err(|error| error.to_string())
+}
+
+fn non_finite_values(
+    batch: &RecordBatch,
+    row_offset: usize,
+    values: &mut Vec<NonFiniteValue>,
+) -> Result<(), String> {
+    let schema = batch.schema();
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        if !matches!(
+            field.data_type(),
+            DataType::Float16 | DataType::Float32 | DataType::Float64
+        ) {
+            continue;
+        }
+        let floats = cast(column, &DataType::Float64)?;
+        for (row, value) in floats.as_primitive::<Float64Type>().iter().enumerate() {
+            let label = match value {
+                Some(value) if value.is_nan() => "NaN",
+                Some(value) if value == f64::INFINITY => "Infinity",
+                Some(value) if value == f64::NEG_INFINITY => "-Infinity",
+                _ => continue,
+            };
+            values.push(NonFiniteValue {
+                row: row_offset + row,
+                column: field.name().clone(),
+                label,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Encodes dictionary-free `batches` as JSON objects, one per row, and returns the
+/// names of the integer columns rendered as strings.
+pub fn encode_rows(
+    batches: &[RecordBatch],
+    options: &JsonOptionsV1,
+) -> Result<(Vec<Value>, Vec<String>), String> {
+    let string_columns = string_encoded_columns(batches, options.large_integers);
+    let mut non_finite = Vec::new();
+    let mut prepared = Vec::with_capacity(batches.len());
+    let mut row_offset = 0;
+    for batch in batches {
+        if options.non_finite_floats == NonFiniteFloatFormatV1::String {
+            non_finite_values(batch, row_offset, &mut non_finite)?;
+        }
+        row_offset += batch.num_rows();
+        prepared.push(prepare_batch(batch, options, &string_columns)?);
+    }
+
+    let mut writer = WriterBuilder::new()
+        .with_explicit_nulls(options.explicit_nulls)
+        .build::<_, JsonArray>(Vec::new());
+    let batch_refs: Vec<&RecordBatch> = prepared.iter().collect();
+    writer
+        .write_batches(&batch_refs)
+        .map_err(|error| error.to_string())?;
+    writer.finish().map_err(|error| error.to_string())?;
+
+    let mut rows: Vec<Value> =
+        serde_json::from_slice(&writer.into_inner()).map_err(|error| error.to_string())?;
+    for value in non_finite {
+        if let Some(Value::Object(row)) = rows.get_mut(value.row) {
+            row.insert(value.column, Value::String(value.label.to_string()));
+        }
+    }
+    Ok((rows, string_columns))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::{
+        BinaryArray, Float64Array, Int64Array, TimestampMicrosecondArray, UInt64Array,
+    };
+    use serde_json::json;
+
+    use super::*;
+
+    fn batch() -> RecordBatch {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("score", DataType::Float64, true),
+            Field::new("payload", DataType::Binary, true),
+            Field::new(
+                "created_at",
+                DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+                true,
+            ),
+        ]);
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(Int64Array::from(vec![9_007_199_254_740_993, 2])),
+                Arc::new(Float64Array::from(vec![Some(f64::NAN), None])),
+                Arc::new(BinaryArray::from(vec![Some(&b"hi"[..]), None])),
+                Arc::new(
+                    TimestampMicrosecondArray::from(vec![Some(1_700_000_000_123_456), None])
+                        .with_timezone("UTC"),
+                ),
+            ],
+        )
+        .expect("batch")
+    }
+
+    #[test]
+    fn default_options_keep_array_writer_output() {
+        let (rows, string_columns) =
+            encode_rows(&[batch()], &JsonOptionsV1::default()).expect("rows");
+        assert!(string_columns.is_empty());
+        assert_eq!(rows[0]["id"], json!(9_007_199_254_740_993_i64));
+        assert_eq!(rows[0]["score"], Value::Null);
+        assert_eq!(rows[0]["payload"], json!("6869"));
+        assert!(rows[0]["created_at"]
+            .as_str()
+            .is_some_and(|text| text.starts_with("2023-11-14T22:13:20.123456")));
+        assert_eq!(rows[1], json!({"id": 2}));
+    }
+
+    #[test]
+    fn options_override_rendering() {
+        let options = JsonOptionsV1 {
+            non_finite_floats: NonFiniteFloatFormatV1::String,
+            binary: BinaryFormatV1::Base64,
+            timestamps: TimestampFormatV1::EpochMillis,
+            large_integers: LargeIntegerFormatV1::String,
+            explicit_nulls: true,
+        };
+        let (rows, string_columns) = encode_rows(&[batch(), batch()], &options).expect("rows");
+        assert_eq!(string_columns, vec!["id"]);
+        assert_eq!(
+            rows[0],
+            json!({
+                "id": "9007199254740993",
+                "score": "NaN",
+                "payload": "aGk=",
+                "created_at": 1_700_000_000_123_i64,
+            })
+        );
+        assert_eq!(
+            rows[1],
+            json!({"id": "2", "score": null, "payload": null, "created_at": null})
+        );
+        assert_eq!(rows[2]["score"], json!("NaN"));
+    }
+
+    #[test]
+    fn auto_mode_stringifies_only_columns_with_unsafe_values() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("key", DataType::UInt64, true),
+        ]));
+        let batch = |ids: Vec<i64>, keys: Vec<Option<u64>>| {
+            RecordBatch::try_new(
+                schema.clone(),
+                vec![
+                    Arc::new(Int64Array::from(ids)),
+                    Arc::new(UInt64Array::from(keys)),
+                ],
+            )
+            .expect("batch")
+        };
+        let batches = [
+            batch(vec![-(MAX_SAFE_INTEGER as i64), 1], vec![Some(7), None]),
+            batch(vec![2], vec![Some(MAX_SAFE_INTEGER + 2)]),
+        ];
+        let options = JsonOptionsV1 {
+            large_integers: LargeIntegerFormatV1::Auto,
+            ..Default::default()
+        };
+
+        let (rows, string_columns) = encode_rows(&batches, &options).expect("rows");
+        assert_eq!(string_columns, vec!["key"]);
+        assert_eq!(
+            rows[0],
+            json!({"id": -9_007_199_254_740_991_i64, "key": "7"})
+        );
+        assert_eq!(rows[1], json!({"id": 1}));
+        assert_eq!(rows[2], json!({"id": 2, "key": "9007199254740993"}));
+    }
+}
@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, RecordBatch, StringArray, UInt64Array};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+
+/// Path of the file a row was imported from.
+pub const SOURCE_FILE_COLUMN: &str = "_source_file";
+/// Id of the `import_data_v1` call that wrote the row.
+pub const IMPORT_JOB_COLUMN: &str = "_import_job_id";
+/// Line of the row in a CSV or JSONL file, or its 1-based position in a Parquet file.
+pub const SOURCE_LINE_COLUMN: &str = "_source_line";
+
+fn lineage_fields() -> [Field; 3] {
+    [
+        Field::new(SOURCE_FILE_COLUMN, DataType::Utf8, true),
+        Field::new(IMPORT_JOB_COLUMN, DataType::Utf8, true),
+        Field::new(SOURCE_LINE_COLUMN, DataType::UInt64, true),
+    ]
+}
+
+pub fn is_lineage_column(name: &str) -> bool {
+    matches!(
+        name,
+        SOURCE_FILE_COLUMN | IMPORT_JOB_COLUMN | SOURCE_LINE_COLUMN
+    )
+}
+
+/// Lineage columns the table does not have yet. Fails when a column already uses one
+/// of the names with another type.
+pub fn missing_fields(schema: &Schema) -> Result<Vec<Field>, String> {
+    let mut missing = Vec::new();
+    for field in lineage_fields() {
+        match schema.field_with_name(field.name()) {
+            Ok(existing) if existing.data_type() == field.data_type() => {}
+            Ok(existing) => {
+                return Err(format!(
+                    "column '{}' exists with type {} but lineage needs {}",
+                    field.name(),
+                    existing.data_type(),
+                    field.data_type()
+                ))
+            }
+            Err(_) => missing.push(field),
+        }
+    }
+    Ok(missing)
+}
+
+/// The table schema without lineage columns, which is what the source file holds.
+pub fn source_schema(schema: &Schema) -> SchemaRef {
+    Arc::new(Schema::new_with_metadata(
+        schema
+            .fields()
+            .iter()
+            .filter(|field| !is_lineage_column(field.name()))
+            .cloned()
+            .collect::<Vec<_>>(),
+        schema.metadata().clone(),
+    ))
+}
+
+/// Builds a batch in the column order of `target`, taking data columns from `batch`
+/// by name and filling the lineage columns. `lines` holds one entry per row.
+pub fn stamp(
+    batch: &RecordBatch,
+    target: &SchemaRef,
+    source_file: &str,
+    import_job_id: &str,
+    lines: &[u64],
+) -> Result<RecordBatch, String> {
+    let rows = batch.num_rows();
+    if lines.len() != rows {
+        return Err(format!(
+            "expected {rows} line numbers for the batch, got {}",
+            lines.len()
+        ));
+    }
+    let columns = target
+        .fields()
+        .iter()
+        .map(|field| -> Result<ArrayRef, String> {
+            Ok(match field.name().as_str() {
+                SOURCE_FILE_COLUMN => Arc::new(StringArray::from(vec![source_file; rows])),
+                IMPORT_JOB_COLUMN => Arc::new(StringArray::from(vec![import_job_id; rows])),
+                SOURCE_LINE_COLUMN => Arc::new(UInt64Array::from(lines.to_vec())),
+                name => batch
+                    .column_by_name(name)
+                    .cloned()
+                    .ok_or_else(|| format!("column '{name}' is missing from the source file"))?,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    RecordBatch::try_new(target.clone(), columns).map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::{Array, Int32Array};
+
+    use super::*;
+
+    #[test]
+    fn stamps_batches_in_table_order() {
+        let table_schema = Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new(SOURCE_LINE_COLUMN, DataType::UInt64, true),
+        ]);
+        let missing = missing_fields(&table_schema).expect("missing");
+        assert_eq!(
+            missing.iter().map(|field| field.name()).collect::<Vec<_>>(),
+            vec![SOURCE_FILE_COLUMN, IMPORT_JOB_COLUMN]
+        );
+        let source = source_schema(&table_schema);
+        assert_eq!(source.fields().len(), 1);
+
+        let mut fields = table_schema.fields().iter().cloned().collect::<Vec<_>>();
+        fields.extend(missing.into_iter().map(Arc::new));
+        let target: SchemaRef = Arc::new(Schema::new(fields));
+        let batch = RecordBatch::try_new(source, vec![Arc::new(Int32Array::from(vec![7, 8]))])
+            .expect("batch");
+        let stamped = stamp(&batch, &target, "rows.csv", "job-1", &[2, 3]).expect("stamp");
+        assert_eq!(stamped.schema(), target);
+        let lines = stamped
+            .column(1)
+            .as_any()
+            .downcast_ref::<UInt64Array>()
+            .expect("lines");
+        assert_eq!(lines.values(), &[2, 3]);
+        let files = stamped
+            .column(2)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .expect("files");
+        assert_eq!(files.value(1), "rows.csv");
+        assert_eq!(stamped.column(3).null_count(), 0);
+
+        assert!(stamp(&batch, &target, "rows.csv", "job-1", &[2]).is_err());
+    }
+
+    #[test]
+    fn rejects_lineage_columns_with_other_types() {
+        let schema = Schema::new(vec![Field::new(SOURCE_FILE_COLUMN, DataType::Int64, true)]);
+        assert!(missing_fields(&schema).is_err());
+    }
+}
@@ -0,0 +1,210 @@
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+use log::{warn, LevelFilter, Metadata};
+
+use crate::ipc::v1::{LogLevelV1, LogSettingsV1};
+use crate::services::store;
+
+pub const LOG_SETTINGS_FILE: &str = "log_settings.json";
+
+struct RuntimeFilter {
+    level: LevelFilter,
+    modules: Vec<String>,
+    /// Level for targets outside `modules`.
+    fallback: LevelFilter,
+}
+
+/// Filter consulted by the log plugin for every record. `None` means the build
+/// default applies.
+static RUNTIME_FILTER: RwLock<Option<RuntimeFilter>> = RwLock::new(None);
+
+/// Trace in debug builds, info in release builds.
+pub fn default_level() -> LevelFilter {
+    if cfg!(debug_assertions) {
+        LevelFilter::Trace
+    } else {
+        LevelFilter::Info
+    }
+}
+
+pub fn to_level_filter(level: LogLevelV1) -> LevelFilter {
+    match level {
+        LogLevelV1::Off => LevelFilter::Off,
+        LogLevelV1::Error => LevelFilter::Error,
+        LogLevelV1::Warn => LevelFilter::Warn,
+        LogLevelV1::Info => LevelFilter::Info,
+        LogLevelV1::Debug => LevelFilter::Debug,
+        LogLevelV1::Trace => LevelFilter::Trace,
+    }
+}
+
+/// Splits a comma-separated list of module path prefixes, dropping blanks.
+pub fn parse_module_filter(module_filter: Option<&str>) -> Vec<String> {
+    module_filter
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|module| !module.is_empty())
+        .map(ToOwned::to_owned)
+        .collect()
+}
+
+fn matches_module(target: &str, module: &str) -> bool {
+    target == module
+        || target
+            .strip_prefix(module)
+            .is_some_and(|rest| rest.starts_with("::"))
+}
+
+impl RuntimeFilter {
+    fn allows(&self, metadata: &Metadata) -> bool {
+        if self.modules.is_empty()
+            || self
+                .modules
+                .iter()
+                .any(|module| matches_module(metadata.target(), module))
+        {
+            metadata.level() <= self.level
+        } else {
+            metadata.level() <= self.fallback
+        }
+    }
+}
+
+/// Log plugin filter. Without a module filter the configured level applies to every
+/// target; with one it applies to the listed modules and the build default to the rest.
+pub fn allows(metadata: &Metadata) -> bool {
+    match RUNTIME_FILTER.read() {
+        Ok(filter) => match filter.as_ref() {
+            Some(filter) => filter.allows(metadata),
+            None => metadata.level() <= default_level(),
+        },
+        Err(_) => metadata.level() <= default_level(),
+    }
+}
+
+/// Installs `settings` as the active filter and raises the global max level so the
+/// `log` macros do not discard records before the filter sees them.
+pub fn apply(settings: &LogSettingsV1) {
+    let level = to_level_filter(settings.level);
+    let modules = parse_module_filter(settings.module_filter.as_deref());
+    let fallback = default_level();
+    let max_level = if modules.is_empty() {
+        level
+    } else {
+        level.max(fallback)
+    };
+    if let Ok(mut filter) = RUNTIME_FILTER.write() {
+        *filter = Some(RuntimeFilter {
+            level,
+            modules,
+            fallback,
+        });
+    }
+    log::set_max_level(max_level);
+}
+
+/// Settings for the `log_level` default in `config.toml`, which covers every module.
+pub fn config_settings(level: Option<LogLevelV1>) -> Option<LogSettingsV1> {
+    level.map(|level| LogSettingsV1 {
+        level,
+        module_filter: None,
+    })
+}
+
+/// Applies persisted settings at startup, or the build default when none were saved.
+pub fn restore(settings: Option<&LogSettingsV1>) {
+    match settings {
+        Some(settings) => apply(settings),
+        None => {
+            if let Ok(mut filter) = RUNTIME_FILTER.write() {
+                *filter = None;
+            }
+            log::set_max_level(default_level());
+        }
+    }
+}
+
+/// Log settings persisted to `log_settings.json` so the chosen level survives restarts.
+#[derive(Default)]
+pub struct LogSettingsStore {
+    path: Option<PathBuf>,
+    settings: Option<LogSettingsV1>,
+}
+
+impl LogSettingsStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load(path: PathBuf) -> Self {
+        let settings = match store::load_json::<Option<LogSettingsV1>>(&path) {
+            Ok(settings) => settings,
+            Err(error) => {
+                warn!(
+                    "failed to load log settings path={:?} error={}",
+                    path, error
+                );
+                None
+            }
+        };
+        Self {
+            path: Some(path),
+            settings,
+        }
+    }
+
+    pub fn get(&self) -> Option<LogSettingsV1> {
+        self.settings.clone()
+    }
+
+    pub fn set(&mut self, settings: LogSettingsV1) -> Result<(), String> {
+        self.settings = Some(settings);
+        self.persist()
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        let Some(path) = self.path.as_ref() else {
+            return Ok(());
+        };
+        store::save_json(path, &self.settings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use log::{Level, MetadataBuilder};
+
+    fn metadata(level: Level, target: &str) -> Metadata<'_> {
+        MetadataBuilder::new().level(level).target(target).build()
+    }
+
+    #[test]
+    fn module_filter_raises_level_for_matching_targets_only() {
+        let filter = RuntimeFilter {
+            level: LevelFilter::Trace,
+            modules: parse_module_filter(Some(" lancedb_viewer_lib::services , ,lance")),
+            fallback: LevelFilter::Info,
+        };
+        assert_eq!(
+            filter.modules,
+            vec!["lancedb_viewer_lib::services", "lance"]
+        );
+
+        assert!(filter.allows(&metadata(Level::Trace, "lancedb_viewer_lib::services::v1")));
+        assert!(filter.allows(&metadata(Level::Trace, "lance")));
+        assert!(!filter.allows(&metadata(Level::Trace, "lancedb::table")));
+        assert!(!filter.allows(&metadata(Level::Debug, "lancedb_viewer_lib::servicesx")));
+        assert!(filter.allows(&metadata(Level::Info, "tao")));
+
+        let global = RuntimeFilter {
+            level: LevelFilter::Warn,
+            modules: Vec::new(),
+            fallback: LevelFilter::Trace,
+        };
+        assert!(!global.allows(&metadata(Level::Info, "lance")));
+        assert!(global.allows(&metadata(Level::Error, "lance")));
+    }
+}
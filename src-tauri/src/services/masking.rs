@@ -0,0 +1,255 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use arrow_array::{Array, ArrayRef, RecordBatch, StringArray};
+use arrow_cast::display::{ArrayFormatter, FormatOptions};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use log::warn;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::ipc::v1::{MaskingRuleV1, MaskingStrategyV1};
+use crate::services::{checksum, store};
+
+pub const MASKING_RULES_FILE: &str = "masking_rules.json";
+const REDACTED_VALUE: &str = "****";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MaskingRulesFile {
+    #[serde(default)]
+    connections: HashMap<String, Vec<MaskingRuleV1>>,
+}
+
+/// Masking rules keyed by connection URI, persisted to `masking_rules.json` in the app
+/// data directory when one is configured.
+#[derive(Default)]
+pub struct MaskingStore {
+    path: Option<PathBuf>,
+    rules: HashMap<String, Vec<MaskingRuleV1>>,
+}
+
+impl MaskingStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load(path: PathBuf) -> Self {
+        let rules = match store::load_json::<MaskingRulesFile>(&path) {
+            Ok(file) => file.connections,
+            Err(error) => {
+                warn!(
+                    "failed to load masking rules path={:?} error={}",
+                    path, error
+                );
+                HashMap::new()
+            }
+        };
+        Self {
+            path: Some(path),
+            rules,
+        }
+    }
+
+    pub fn list(&self, connection_uri: &str) -> Vec<MaskingRuleV1> {
+        self.rules.get(connection_uri).cloned().unwrap_or_default()
+    }
+
+    /// Rules of a connection that apply to `table_name`; rules without a table name
+    /// apply to every table.
+    pub fn rules_for_table(&self, connection_uri: &str, table_name: &str) -> Vec<MaskingRuleV1> {
+        self.rules
+            .get(connection_uri)
+            .map(|rules| {
+                rules
+                    .iter()
+                    .filter(|rule| match rule.table_name.as_deref() {
+                        Some(name) => name == table_name,
+                        None => true,
+                    })
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Replaces every rule of a connection. An empty list clears them.
+    pub fn set(&mut self, connection_uri: &str, rules: Vec<MaskingRuleV1>) -> Result<(), String> {
+        if rules.is_empty() {
+            self.rules.remove(connection_uri);
+        } else {
+            self.rules.insert(connection_uri.to_string(), rules);
+        }
+        self.persist()
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        let Some(path) = self.path.as_ref() else {
+            return Ok(());
+        };
+        store::save_json(
+            path,
+            &MaskingRulesFile {
+                connections: self.rules.clone(),
+            },
+        )
+    }
+}
+
+pub fn validate_rule(rule: &MaskingRuleV1) -> Result<(), String> {
+    if rule.column.trim().is_empty() {
+        return Err("masking rule column cannot be empty".to_string());
+    }
+    if let Some(table_name) = rule.table_name.as_deref() {
+        if table_name.trim().is_empty() {
+            return Err("masking rule table name cannot be empty".to_string());
+        }
+    }
+    Ok(())
+}
+
+fn find_rule<'a>(rules: &'a [MaskingRuleV1], column: &str) -> Option<&'a MaskingRuleV1> {
+    rules.iter().find(|rule| rule.column == column)
+}
+
+fn mask_value(value: &str, strategy: &MaskingStrategyV1) -> String {
+    match strategy {
+        MaskingStrategyV1::Hash => checksum::to_hex(&Sha256::digest(value.as_bytes())),
+        MaskingStrategyV1::Redact => REDACTED_VALUE.to_string(),
+        MaskingStrategyV1::Truncate { length } => value.chars().take(*length).collect(),
+    }
+}
+
+/// Masked columns are always emitted as nullable strings, whatever their source type.
+fn masked_field(field: &Field) -> Field {
+    Field::new(field.name(), DataType::Utf8, true).with_metadata(field.metadata().clone())
+}
+
+fn mask_column(array: &ArrayRef, strategy: &MaskingStrategyV1) -> Result<ArrayRef, String> {
+    let formatter = ArrayFormatter::try_new(array.as_ref(), &FormatOptions::default())
+        .map_err(|error| error.to_string())?;
+    let values = (0..array.len())
+        .map(|index| {
+            if array.is_null(index) {
+                None
+            } else {
+                Some(mask_value(&formatter.value(index).to_string(), strategy))
+            }
+        })
+        .collect::<StringArray>();
+    Ok(Arc::new(values))
+}
+
+/// Returns the schema produced by [`mask_batch`] for the same rules.
+pub fn mask_schema(schema: &Schema, rules: &[MaskingRuleV1]) -> SchemaRef {
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|field| match find_rule(rules, field.name()) {
+            Some(_) => masked_field(field),
+            None => field.as_ref().clone(),
+        })
+        .collect::<Vec<_>>();
+    Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone()))
+}
+
+/// Applies the matching rules to a batch. Rules naming columns that are not part of
+/// the batch (for example because of a projection) are ignored.
+pub fn mask_batch(batch: &RecordBatch, rules: &[MaskingRuleV1]) -> Result<RecordBatch, String> {
+    let schema = batch.schema();
+    if !schema
+        .fields()
+        .iter()
+        .any(|field| find_rule(rules, field.name()).is_some())
+    {
+        return Ok(batch.clone());
+    }
+    let mut columns = Vec::with_capacity(batch.num_columns());
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        match find_rule(rules, field.name()) {
+            Some(rule) => columns.push(mask_column(column, &rule.strategy)?),
+            None => columns.push(column.clone()),
+        }
+    }
+    RecordBatch::try_new(mask_schema(schema.as_ref(), rules), columns)
+        .map_err(|error| error.to_string())
+}
+
+pub fn mask_batches(
+    batches: Vec<RecordBatch>,
+    rules: &[MaskingRuleV1],
+) -> Result<Vec<RecordBatch>, String> {
+    if rules.is_empty() {
+        return Ok(batches);
+    }
+    batches
+        .iter()
+        .map(|batch| mask_batch(batch, rules))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::Int32Array;
+
+    fn rule(column: &str, strategy: MaskingStrategyV1) -> MaskingRuleV1 {
+        MaskingRuleV1 {
+            table_name: None,
+            column: column.to_string(),
+            strategy,
+        }
+    }
+
+    #[test]
+    fn masks_configured_columns_only() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("email", DataType::Utf8, true),
+            Field::new("name", DataType::Utf8, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2])),
+                Arc::new(StringArray::from(vec![Some("a@example.com"), None])),
+                Arc::new(StringArray::from(vec![Some("Alice"), Some("Bob")])),
+            ],
+        )
+        .expect("batch");
+
+        let masked = mask_batch(
+            &batch,
+            &[
+                rule("id", MaskingStrategyV1::Hash),
+                rule("email", MaskingStrategyV1::Redact),
+                rule("name", MaskingStrategyV1::Truncate { length: 2 }),
+                rule("missing", MaskingStrategyV1::Redact),
+            ],
+        )
+        .expect("mask");
+
+        let ids = masked
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .expect("ids");
+        assert_eq!(ids.value(0), checksum::to_hex(&Sha256::digest(b"1")));
+        let emails = masked
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .expect("emails");
+        assert_eq!(emails.value(0), REDACTED_VALUE);
+        assert!(emails.is_null(1));
+        let names = masked
+            .column(2)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .expect("names");
+        assert_eq!(names.value(0), "Al");
+        assert_eq!(names.value(1), "Bo");
+        assert_eq!(masked.schema().field(0).data_type(), &DataType::Utf8);
+    }
+}
@@ -0,0 +1,202 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use arrow_array::RecordBatch;
+
+use crate::ipc::v1::CommandMemoryV1;
+
+/// Peaks above this are logged as warnings so OOM-prone commands stand out.
+pub const LARGE_PEAK_BYTES: u64 = 256 * 1024 * 1024;
+
+thread_local! {
+    static CURRENT: RefCell<Option<Arc<MemoryTracker>>> = const { RefCell::new(None) };
+}
+
+/// Batch memory a command holds at once. Query paths report batches as they
+/// receive and drop them; the tracker keeps the running total and its peak. A
+/// nested command also counts towards the command that started it.
+#[derive(Debug, Default)]
+pub struct MemoryTracker {
+    parent: Option<Arc<MemoryTracker>>,
+    current: AtomicU64,
+    peak: AtomicU64,
+}
+
+impl MemoryTracker {
+    /// A tracker for a new command, nested under the command being polled on this
+    /// thread, if any.
+    pub fn for_command() -> Arc<Self> {
+        Arc::new(Self {
+            parent: CURRENT.with(|current| current.borrow().clone()),
+            ..Self::default()
+        })
+    }
+
+    pub fn retain(&self, bytes: u64) {
+        let now = self.current.fetch_add(bytes, Ordering::SeqCst) + bytes;
+        self.peak.fetch_max(now, Ordering::SeqCst);
+        if let Some(parent) = &self.parent {
+            parent.retain(bytes);
+        }
+    }
+
+    pub fn release(&self, bytes: u64) {
+        let _ = self
+            .current
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |current| {
+                Some(current.saturating_sub(bytes))
+            });
+        if let Some(parent) = &self.parent {
+            parent.release(bytes);
+        }
+    }
+
+    pub fn peak(&self) -> u64 {
+        self.peak.load(Ordering::SeqCst)
+    }
+}
+
+/// Makes `tracker` the current tracker while `future` is polled. Tokio here has no
+/// task-local storage, so the tracker is installed around every poll instead.
+pub fn track<F: Future>(tracker: Arc<MemoryTracker>, future: F) -> Tracked<F> {
+    Tracked {
+        future: Box::pin(future),
+        tracker,
+    }
+}
+
+pub struct Tracked<F> {
+    future: Pin<Box<F>>,
+    tracker: Arc<MemoryTracker>,
+}
+
+/// Restores the previous tracker, also when the polled future panics.
+struct Restore(Option<Arc<MemoryTracker>>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+impl<F: Future> Future for Tracked<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+        let this = self.get_mut();
+        let _restore =
+            Restore(CURRENT.with(|current| current.borrow_mut().replace(this.tracker.clone())));
+        this.future.as_mut().poll(cx)
+    }
+}
+
+fn with_current(apply: impl FnOnce(&MemoryTracker)) {
+    CURRENT.with(|current| {
+        if let Some(tracker) = current.borrow().as_ref() {
+            apply(tracker);
+        }
+    });
+}
+
+/// Counts `bytes` against the running command. Does nothing outside a command,
+/// e.g. in background jobs.
+pub fn retain(bytes: u64) {
+    with_current(|tracker| tracker.retain(bytes));
+}
+
+pub fn release(bytes: u64) {
+    with_current(|tracker| tracker.release(bytes));
+}
+
+pub fn batch_bytes(batch: &RecordBatch) -> u64 {
+    batch.get_array_memory_size() as u64
+}
+
+/// Peak batch memory per command name since the app started.
+#[derive(Default)]
+pub struct MemoryStats {
+    commands: HashMap<&'static str, CommandMemoryV1>,
+}
+
+impl MemoryStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, command: &'static str, peak_bytes: u64) {
+        let entry = self
+            .commands
+            .entry(command)
+            .or_insert_with(|| CommandMemoryV1 {
+                command: command.to_string(),
+                calls: 0,
+                last_peak_bytes: 0,
+                max_peak_bytes: 0,
+            });
+        entry.calls += 1;
+        entry.last_peak_bytes = peak_bytes;
+        entry.max_peak_bytes = entry.max_peak_bytes.max(peak_bytes);
+    }
+
+    /// Commands with the largest peak first.
+    pub fn list(&self) -> Vec<CommandMemoryV1> {
+        let mut commands = self.commands.values().cloned().collect::<Vec<_>>();
+        commands.sort_by(|left, right| {
+            right
+                .max_peak_bytes
+                .cmp(&left.max_peak_bytes)
+                .then_with(|| left.command.cmp(&right.command))
+        });
+        commands
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn tracks_peaks_of_nested_commands() {
+        let outer = MemoryTracker::for_command();
+        let inner_peak = track(outer.clone(), async {
+            retain(100);
+            let inner = MemoryTracker::for_command();
+            track(inner.clone(), async {
+                retain(50);
+                release(50);
+                retain(20);
+            })
+            .await;
+            release(100);
+            inner.peak()
+        })
+        .await;
+
+        assert_eq!(inner_peak, 50);
+        assert_eq!(outer.peak(), 150);
+        // Outside a command nothing is tracked.
+        retain(1_000);
+        assert_eq!(outer.peak(), 150);
+    }
+
+    #[test]
+    fn records_the_largest_peak_per_command() {
+        let mut stats = MemoryStats::new();
+        stats.record("query_filter_v1", 300);
+        stats.record("scan_v1", 100);
+        stats.record("query_filter_v1", 200);
+
+        let commands = stats.list();
+        assert_eq!(commands[0].command, "query_filter_v1");
+        assert_eq!(commands[0].calls, 2);
+        assert_eq!(commands[0].last_peak_bytes, 200);
+        assert_eq!(commands[0].max_peak_bytes, 300);
+        assert_eq!(commands[1].command, "scan_v1");
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+use std::future::Future;
+
+use crate::ipc::v1::{LocaleV1, MessageCodeV1, MessageV1};
+
+tokio::task_local! {
+    static CURRENT_LOCALE: LocaleV1;
+    static REQUESTED_LOCALE: LocaleV1;
+}
+
+/// Runs `future` with `locale` as the language of the messages it builds.
+/// `middleware::run` scopes every command to the requested or configured locale.
+pub async fn scope<F: Future>(locale: LocaleV1, future: F) -> F::Output {
+    CURRENT_LOCALE.scope(locale, future).await
+}
+
+/// Runs the commands `future` makes with the `locale` their caller asked for, which
+/// wins over the configured one. `None` leaves the configured locale in place.
+pub async fn request_locale<F: Future>(locale: Option<LocaleV1>, future: F) -> F::Output {
+    match locale {
+        Some(locale) => REQUESTED_LOCALE.scope(locale, future).await,
+        None => future.await,
+    }
+}
+
+/// Locale the caller of the running command asked for, if any.
+pub fn requested_locale() -> Option<LocaleV1> {
+    REQUESTED_LOCALE.try_with(|locale| *locale).ok()
+}
+
+/// Locale of the running command, or the default outside one.
+pub fn current_locale() -> LocaleV1 {
+    CURRENT_LOCALE
+        .try_with(|locale| *locale)
+        .unwrap_or_default()
+}
+
+fn template(code: MessageCodeV1, locale: LocaleV1) -> &'static str {
+    match (code, locale) {
+        (MessageCodeV1::ConnectionNotFound, LocaleV1::En) => "connection not found",
+        (MessageCodeV1::ConnectionNotFound, LocaleV1::ZhCn) => "连接不存在",
+        (MessageCodeV1::TableNotFound, LocaleV1::En) => "table not found",
+        (MessageCodeV1::TableNotFound, LocaleV1::ZhCn) => "数据表不存在",
+        (MessageCodeV1::StateLockFailed, LocaleV1::En) => "failed to lock connection manager",
+        (MessageCodeV1::StateLockFailed, LocaleV1::ZhCn) => "连接管理器加锁失败",
+        (MessageCodeV1::CompactSubmitted, LocaleV1::En) => "Compact submitted",
+        (MessageCodeV1::CompactSubmitted, LocaleV1::ZhCn) => "Compact 已提交",
+        (MessageCodeV1::CompactSubmittedWithTarget, LocaleV1::En) => {
+            "Compact submitted, target rows per fragment={target_rows}"
+        }
+        (MessageCodeV1::CompactSubmittedWithTarget, LocaleV1::ZhCn) => {
+            "Compact 已提交，目标片段行数={target_rows}"
+        }
+        (MessageCodeV1::VacuumSubmitted, LocaleV1::En) => "Vacuum submitted",
+        (MessageCodeV1::VacuumSubmitted, LocaleV1::ZhCn) => "Vacuum 已提交",
+        (MessageCodeV1::VacuumSubmittedOlderThan, LocaleV1::En) => {
+            "Vacuum submitted, removing versions older than {days} days"
+        }
+        (MessageCodeV1::VacuumSubmittedOlderThan, LocaleV1::ZhCn) => {
+            "Vacuum 已提交，清理超过 {days} 天的历史版本"
+        }
+        (MessageCodeV1::IndexesOptimized, LocaleV1::En) => {
+            "Optimized {indexes} indexes, {unindexed_rows} rows still unindexed"
+        }
+        (MessageCodeV1::IndexesOptimized, LocaleV1::ZhCn) => {
+            "已优化 {indexes} 个索引，仍有 {unindexed_rows} 行未建索引"
+        }
+        (MessageCodeV1::ConnectionReadOnly, LocaleV1::En) => "connection is read-only",
+        (MessageCodeV1::ConnectionReadOnly, LocaleV1::ZhCn) => "连接为只读模式",
+        (MessageCodeV1::RoleForbidden, LocaleV1::En) => "role {role} may not run {command}",
+        (MessageCodeV1::RoleForbidden, LocaleV1::ZhCn) => "当前角色 {role} 无权执行 {command}",
+        (MessageCodeV1::FtsIndexMissing, LocaleV1::En) => {
+            "table has no full-text index; create one on a string column to search it"
+        }
+        (MessageCodeV1::FtsIndexMissing, LocaleV1::ZhCn) => {
+            "数据表没有全文索引，请先在字符串列上创建全文索引"
+        }
+        (MessageCodeV1::FtsColumnsNotIndexed, LocaleV1::En) => {
+            "no full-text index on {columns}; indexed columns: {indexed}"
+        }
+        (MessageCodeV1::FtsColumnsNotIndexed, LocaleV1::ZhCn) => {
+            "列 {columns} 没有全文索引，已建立全文索引的列：{indexed}"
+        }
+        (MessageCodeV1::VectorFilterHint, LocaleV1::En) => {
+            "{column} is a vector column; filter on array_length({column}), an element such as {column}[1], or array_has_any({column}, [0.5, 1.0])"
+        }
+        (MessageCodeV1::VectorFilterHint, LocaleV1::ZhCn) => {
+            "{column} 是向量列，请使用 array_length({column})、{column}[1] 这样的元素访问或 array_has_any({column}, [0.5, 1.0]) 进行过滤"
+        }
+        (MessageCodeV1::DiskSpaceInsufficient, LocaleV1::En) => {
+            "not enough disk space at {path}: {needed} bytes needed, {available} bytes free"
+        }
+        (MessageCodeV1::DiskSpaceInsufficient, LocaleV1::ZhCn) => {
+            "{path} 所在磁盘空间不足：需要 {needed} 字节，可用 {available} 字节"
+        }
+        (MessageCodeV1::EncryptedColumnLocked, LocaleV1::En) => {
+            "column {column} is encrypted with key {key_id}, which is locked"
+        }
+        (MessageCodeV1::EncryptedColumnLocked, LocaleV1::ZhCn) => {
+            "列 {column} 使用密钥 {key_id} 加密，该密钥尚未解锁"
+        }
+        (MessageCodeV1::PathEmpty, LocaleV1::En) => "path cannot be empty",
+        (MessageCodeV1::PathEmpty, LocaleV1::ZhCn) => "路径不能为空",
+        (MessageCodeV1::TableNameEmpty, LocaleV1::En) => "table name cannot be empty",
+        (MessageCodeV1::TableNameEmpty, LocaleV1::ZhCn) => "表名不能为空",
+        (MessageCodeV1::IndexNameEmpty, LocaleV1::En) => "index name cannot be empty",
+        (MessageCodeV1::IndexNameEmpty, LocaleV1::ZhCn) => "索引名不能为空",
+        (MessageCodeV1::JobNotFound, LocaleV1::En) => "job not found",
+        (MessageCodeV1::JobNotFound, LocaleV1::ZhCn) => "任务不存在",
+    }
+}
+
+pub fn render(code: MessageCodeV1, locale: LocaleV1, params: &HashMap<String, String>) -> String {
+    let mut text = template(code, locale).to_string();
+    for (key, value) in params {
+        text = text.replace(&format!("{{{key}}}"), value);
+    }
+    text
+}
+
+pub fn message_with(
+    code: MessageCodeV1,
+    locale: LocaleV1,
+    params: HashMap<String, String>,
+) -> MessageV1 {
+    MessageV1 {
+        code,
+        text: render(code, locale, &params),
+        params,
+    }
+}
+
+/// `code` without parameters, in the locale of the running command.
+pub fn message(code: MessageCodeV1) -> MessageV1 {
+    message_with(code, current_locale(), HashMap::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{message, message_with, scope};
+    use crate::ipc::v1::{LocaleV1, MessageCodeV1};
+
+    #[test]
+    fn renders_params_for_each_locale() {
+        let params = HashMap::from([("days".to_string(), "7".to_string())]);
+
+        let en = message_with(
+            MessageCodeV1::VacuumSubmittedOlderThan,
+            LocaleV1::En,
+            params.clone(),
+        );
+        let zh = message_with(
+            MessageCodeV1::VacuumSubmittedOlderThan,
+            LocaleV1::ZhCn,
+            params,
+        );
+
+        assert_eq!(
+            en.text,
+            "Vacuum submitted, removing versions older than 7 days"
+        );
+        assert_eq!(zh.text, "Vacuum 已提交，清理超过 7 天的历史版本");
+        assert_eq!(
+            message(MessageCodeV1::TableNotFound).text,
+            "table not found"
+        );
+    }
+
+    #[tokio::test]
+    async fn uses_the_locale_of_the_running_command() {
+        let zh = scope(LocaleV1::ZhCn, async {
+            message(MessageCodeV1::TableNameEmpty)
+        })
+        .await;
+        assert_eq!(zh.text, "表名不能为空");
+        assert_eq!(
+            message(MessageCodeV1::TableNameEmpty).text,
+            "table name cannot be empty"
+        );
+    }
+}
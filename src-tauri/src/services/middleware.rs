@@ -0,0 +1,568 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use futures_util::FutureExt;
+use log::{debug, error, info, warn};
+
+use crate::ipc::v1::{ErrorCode, ErrorEnvelope, MessageCodeV1, ResultEnvelope, RoleV1};
+use crate::services::memory::{self, MemoryTracker};
+use crate::services::messages;
+use crate::state::AppState;
+
+/// Log target for the audit trail of write commands.
+pub const AUDIT_TARGET: &str = "lancedb_viewer::audit";
+
+/// What a command touches, used by hooks that guard or record writes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Access {
+    Read,
+    /// Writes to the table behind a table handle.
+    WriteTable(String),
+    /// Creates, drops or renames tables through a connection.
+    WriteConnection(String),
+}
+
+impl Access {
+    pub fn is_write(&self) -> bool {
+        !matches!(self, Access::Read)
+    }
+
+    fn target(&self) -> Option<&str> {
+        match self {
+            Access::Read => None,
+            Access::WriteTable(id) | Access::WriteConnection(id) => Some(id),
+        }
+    }
+}
+
+/// Commands grouped by what a role may run. Every context states its group, so a
+/// new command never falls into a wider group by omission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandGroup {
+    Read,
+    /// Row edits and table settings.
+    Data,
+    /// Drops, creates, renames, schema changes, indexes and maintenance.
+    Schema,
+    /// App-wide settings, including the role itself.
+    Settings,
+    /// State that is not a table or a setting: secrets, shares, backups, and jobs
+    /// or queries other commands started.
+    Admin,
+}
+
+impl CommandGroup {
+    pub fn allowed_for(self, role: RoleV1) -> bool {
+        match role {
+            RoleV1::Admin => true,
+            RoleV1::Editor => matches!(self, CommandGroup::Read | CommandGroup::Data),
+            RoleV1::Viewer => self == CommandGroup::Read,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CommandContext {
+    pub command: &'static str,
+    pub access: Access,
+    pub group: CommandGroup,
+    pub started_at: Instant,
+}
+
+impl CommandContext {
+    pub fn read(command: &'static str) -> Self {
+        Self::new(command, Access::Read, CommandGroup::Read)
+    }
+
+    /// Row edits and table settings an editor may run.
+    pub fn edit_table(command: &'static str, table_id: &str) -> Self {
+        Self::new(
+            command,
+            Access::WriteTable(table_id.to_string()),
+            CommandGroup::Data,
+        )
+    }
+
+    pub fn write_table(command: &'static str, table_id: &str) -> Self {
+        Self::new(
+            command,
+            Access::WriteTable(table_id.to_string()),
+            CommandGroup::Schema,
+        )
+    }
+
+    pub fn write_connection(command: &'static str, connection_id: &str) -> Self {
+        Self::new(
+            command,
+            Access::WriteConnection(connection_id.to_string()),
+            CommandGroup::Schema,
+        )
+    }
+
+    pub fn settings(command: &'static str) -> Self {
+        Self::new(command, Access::Read, CommandGroup::Settings)
+    }
+
+    pub fn admin(command: &'static str) -> Self {
+        Self::new(command, Access::Read, CommandGroup::Admin)
+    }
+
+    fn new(command: &'static str, access: Access, group: CommandGroup) -> Self {
+        Self {
+            command,
+            access,
+            group,
+            started_at: Instant::now(),
+        }
+    }
+
+    pub fn group(&self) -> CommandGroup {
+        self.group
+    }
+
+    /// Whether the command changes anything, so it is audited.
+    pub fn is_mutation(&self) -> bool {
+        self.access.is_write() || self.group != CommandGroup::Read
+    }
+}
+
+/// Result of a command as seen by `CommandHook::after`.
+pub struct CommandOutcome<'a> {
+    pub elapsed: Duration,
+    pub error: Option<&'a ErrorEnvelope>,
+    /// Most batch memory the command held at once, in bytes.
+    pub peak_memory_bytes: u64,
+}
+
+/// Cross-cutting behavior applied to every command. `before` runs in registration
+/// order and can reject the command; `after` runs for every command that was not
+/// rejected by an earlier hook.
+pub trait CommandHook: Send + Sync {
+    fn before(&self, _state: &AppState, _context: &CommandContext) -> Result<(), ErrorEnvelope> {
+        Ok(())
+    }
+
+    fn after(&self, _state: &AppState, _context: &CommandContext, _outcome: &CommandOutcome) {}
+}
+
+/// Rejects writes through connections opened read-only.
+pub struct ReadOnlyGuard;
+
+impl CommandHook for ReadOnlyGuard {
+    fn before(&self, state: &AppState, context: &CommandContext) -> Result<(), ErrorEnvelope> {
+        let read_only = match &context.access {
+            Access::Read => false,
+            Access::WriteTable(table_id) => state
+                .connections
+                .lock()
+                .is_ok_and(|manager| manager.is_table_read_only(table_id)),
+            Access::WriteConnection(connection_id) => state
+                .connections
+                .lock()
+                .is_ok_and(|manager| manager.is_read_only(connection_id)),
+        };
+        if !read_only {
+            return Ok(());
+        }
+        warn!(
+            "{} rejected on read-only connection id={}",
+            context.command,
+            context.access.target().unwrap_or_default()
+        );
+        Err(ErrorEnvelope::from_message(
+            ErrorCode::PermissionDenied,
+            messages::message(MessageCodeV1::ConnectionReadOnly),
+        ))
+    }
+}
+
+/// Rejects commands outside the groups the configured role may run. This guards
+/// shared workstations against accidents; anyone who can edit `config.toml` can
+/// change the role.
+pub struct RoleGuard;
+
+impl CommandHook for RoleGuard {
+    fn before(&self, state: &AppState, context: &CommandContext) -> Result<(), ErrorEnvelope> {
+        let role = state
+            .config
+            .lock()
+            .map(|config| config.role())
+            .unwrap_or_default();
+        if context.group().allowed_for(role) {
+            return Ok(());
+        }
+        let role = match role {
+            RoleV1::Admin => "admin",
+            RoleV1::Editor => "editor",
+            RoleV1::Viewer => "viewer",
+        };
+        warn!("{} rejected for role={}", context.command, role);
+        Err(ErrorEnvelope::from_message(
+            ErrorCode::PermissionDenied,
+            messages::message_with(
+                MessageCodeV1::RoleForbidden,
+                messages::current_locale(),
+                HashMap::from([
+                    ("role".to_string(), role.to_string()),
+                    ("command".to_string(), context.command.to_string()),
+                ]),
+            ),
+        ))
+    }
+}
+
+/// Records every command that changes a table, a setting or app state, and its
+/// result, under `AUDIT_TARGET`.
+pub struct AuditLog;
+
+impl CommandHook for AuditLog {
+    fn after(&self, _state: &AppState, context: &CommandContext, outcome: &CommandOutcome) {
+        if !context.is_mutation() {
+            return;
+        }
+        info!(
+            target: AUDIT_TARGET,
+            "command={} group={:?} target={} ok={} error_code={:?} elapsed_ms={}",
+            context.command,
+            context.group,
+            context.access.target().unwrap_or_default(),
+            outcome.error.is_none(),
+            outcome.error.map(|error| &error.code),
+            outcome.elapsed.as_millis()
+        );
+    }
+}
+
+/// Keeps per-command peak batch memory and warns about commands that held a lot
+/// of it at once.
+pub struct MemoryAccounting;
+
+impl CommandHook for MemoryAccounting {
+    fn after(&self, state: &AppState, context: &CommandContext, outcome: &CommandOutcome) {
+        if let Ok(mut stats) = state.memory_stats.lock() {
+            stats.record(context.command, outcome.peak_memory_bytes);
+        }
+        if outcome.peak_memory_bytes > memory::LARGE_PEAK_BYTES {
+            warn!(
+                "{} held large batches peak_memory_bytes={} elapsed_ms={}",
+                context.command,
+                outcome.peak_memory_bytes,
+                outcome.elapsed.as_millis()
+            );
+        }
+    }
+}
+
+/// Counts calls, failures and elapsed time per command for diagnostics.
+pub struct CommandTimings;
+
+impl CommandHook for CommandTimings {
+    fn after(&self, state: &AppState, context: &CommandContext, outcome: &CommandOutcome) {
+        if let Ok(mut metrics) = state.command_metrics.lock() {
+            metrics.record(context.command, outcome.elapsed, outcome.error);
+        }
+    }
+}
+
+/// Ordered hooks every command runs through.
+#[derive(Clone)]
+pub struct Pipeline {
+    hooks: Vec<Arc<dyn CommandHook>>,
+}
+
+impl Default for Pipeline {
+    fn default() -> Self {
+        Self {
+            hooks: vec![
+                Arc::new(ReadOnlyGuard),
+                Arc::new(RoleGuard),
+                Arc::new(AuditLog),
+                Arc::new(MemoryAccounting),
+                Arc::new(CommandTimings),
+            ],
+        }
+    }
+}
+
+impl Pipeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a hook that runs after the built-in ones.
+    pub fn register(&mut self, hook: Arc<dyn CommandHook>) {
+        self.hooks.push(hook);
+    }
+}
+
+fn panic_message(panic: &(dyn Any + Send)) -> &str {
+    panic
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic")
+}
+
+/// Runs `command` through the registered hooks. A panic inside the command is
+/// reported as an internal error instead of tearing down the IPC call. Batch
+/// memory the command reports is tracked for the duration of the call, and the
+/// messages it builds use the locale the call asked for through
+/// `messages::request_locale`, or else the configured `locale`.
+pub async fn run<T>(
+    state: &AppState,
+    context: CommandContext,
+    command: impl Future<Output = ResultEnvelope<T>>,
+) -> ResultEnvelope<T> {
+    let locale = messages::requested_locale().unwrap_or_else(|| {
+        state
+            .config
+            .lock()
+            .map(|config| config.locale())
+            .unwrap_or_default()
+    });
+    messages::scope(locale, dispatch(state, context, command)).await
+}
+
+async fn dispatch<T>(
+    state: &AppState,
+    context: CommandContext,
+    command: impl Future<Output = ResultEnvelope<T>>,
+) -> ResultEnvelope<T> {
+    let hooks = match state.pipeline.read() {
+        Ok(pipeline) => pipeline.hooks.clone(),
+        Err(_) => {
+            error!("{} failed to lock command pipeline", context.command);
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+    debug!("{} dispatch access={:?}", context.command, context.access);
+
+    for hook in &hooks {
+        if let Err(error) = hook.before(state, &context) {
+            return ResultEnvelope::err_envelope(error);
+        }
+    }
+
+    let tracker = MemoryTracker::for_command();
+    let tracked = memory::track(tracker.clone(), command);
+    let envelope = match AssertUnwindSafe(tracked).catch_unwind().await {
+        Ok(envelope) => envelope,
+        Err(panic) => {
+            let message = panic_message(panic.as_ref());
+            error!("{} panicked error={}", context.command, message);
+            ResultEnvelope::err(
+                ErrorCode::Internal,
+                format!("{} failed unexpectedly: {}", context.command, message),
+            )
+        }
+    };
+
+    let outcome = CommandOutcome {
+        elapsed: context.started_at.elapsed(),
+        error: envelope.error.as_ref(),
+        peak_memory_bytes: tracker.peak(),
+    };
+    for hook in &hooks {
+        hook.after(state, &context, &outcome);
+    }
+    debug!(
+        "{} finished ok={} elapsed_ms={} peak_memory_bytes={}",
+        context.command,
+        envelope.ok,
+        outcome.elapsed.as_millis(),
+        outcome.peak_memory_bytes
+    );
+
+    envelope
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::ipc::v1::LocaleV1;
+
+    struct Counter {
+        after: AtomicUsize,
+    }
+
+    impl CommandHook for Counter {
+        fn after(&self, _state: &AppState, _context: &CommandContext, _outcome: &CommandOutcome) {
+            self.after.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn explode() -> ResultEnvelope<i32> {
+        panic!("boom")
+    }
+
+    struct Deny;
+
+    impl CommandHook for Deny {
+        fn before(
+            &self,
+            _state: &AppState,
+            _context: &CommandContext,
+        ) -> Result<(), ErrorEnvelope> {
+            Err(ErrorEnvelope {
+                code: ErrorCode::PermissionDenied,
+                message: "denied".to_string(),
+                message_code: None,
+                params: None,
+                details: None,
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn runs_registered_hooks_and_maps_panics() {
+        let state = AppState::new();
+        let counter = Arc::new(Counter {
+            after: AtomicUsize::new(0),
+        });
+        state
+            .pipeline
+            .write()
+            .expect("pipeline")
+            .register(counter.clone());
+
+        let ok = run(&state, CommandContext::read("probe"), async {
+            memory::retain(64);
+            ResultEnvelope::ok(1)
+        })
+        .await;
+        assert_eq!(ok.data, Some(1));
+        let recorded = state.memory_stats.lock().expect("memory stats").list();
+        assert_eq!(recorded[0].max_peak_bytes, 64);
+
+        let panicked = run(&state, CommandContext::read("probe"), async { explode() }).await;
+        let error = panicked.error.expect("error");
+        assert_eq!(error.code, ErrorCode::Internal);
+        assert!(error.message.contains("boom"));
+        assert_eq!(counter.after.load(Ordering::SeqCst), 2);
+
+        state
+            .pipeline
+            .write()
+            .expect("pipeline")
+            .register(Arc::new(Deny));
+        let denied = run(&state, CommandContext::read("probe"), async {
+            ResultEnvelope::ok(1)
+        })
+        .await;
+        assert_eq!(
+            denied.error.expect("error").code,
+            ErrorCode::PermissionDenied
+        );
+        assert_eq!(counter.after.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn groups_commands_for_roles() {
+        let drop = CommandContext::write_connection("drop_table_v1", "conn");
+        let write = CommandContext::edit_table("write_rows_v1", "table");
+        let config = CommandContext::settings("set_config_v1");
+        let share = CommandContext::admin("start_share_v1");
+        let scan = CommandContext::read("scan_v1");
+        assert_eq!(drop.group(), CommandGroup::Schema);
+        assert_eq!(write.group(), CommandGroup::Data);
+        assert_eq!(config.group(), CommandGroup::Settings);
+        assert_eq!(share.group(), CommandGroup::Admin);
+        assert_eq!(scan.group(), CommandGroup::Read);
+        assert!(share.is_mutation());
+        assert!(!scan.is_mutation());
+
+        assert!(drop.group().allowed_for(RoleV1::Admin));
+        assert!(!drop.group().allowed_for(RoleV1::Editor));
+        assert!(write.group().allowed_for(RoleV1::Editor));
+        assert!(!write.group().allowed_for(RoleV1::Viewer));
+        assert!(!config.group().allowed_for(RoleV1::Editor));
+        assert!(!share.group().allowed_for(RoleV1::Editor));
+        assert!(scan.group().allowed_for(RoleV1::Viewer));
+    }
+
+    #[test]
+    fn role_guard_rejects_state_changes_for_viewers() {
+        let state = AppState::new();
+        let mut config = state.config.lock().expect("config").get();
+        config.role = RoleV1::Viewer;
+        state
+            .config
+            .lock()
+            .expect("config")
+            .set(config)
+            .expect("set role");
+
+        let contexts = [
+            CommandContext::admin("rotate_secret_v1"),
+            CommandContext::settings("save_export_template_v1"),
+            CommandContext::admin("start_share_v1"),
+            CommandContext::admin("cancel_job_v1"),
+            CommandContext::admin("kill_query_v1"),
+            CommandContext::admin("backup_table_v1"),
+            CommandContext::settings("set_encryption_key_v1"),
+        ];
+        for context in &contexts {
+            let error = RoleGuard
+                .before(&state, context)
+                .expect_err(context.command);
+            assert_eq!(error.code, ErrorCode::PermissionDenied);
+            assert_eq!(error.message_code, Some(MessageCodeV1::RoleForbidden));
+        }
+        assert!(RoleGuard
+            .before(&state, &CommandContext::read("scan_v1"))
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn renders_messages_in_the_configured_locale() {
+        let state = AppState::new();
+        let mut config = state.config.lock().expect("config").get();
+        config.locale = LocaleV1::ZhCn;
+        config.role = RoleV1::Viewer;
+        state
+            .config
+            .lock()
+            .expect("config")
+            .set(config)
+            .expect("set locale");
+
+        let missing = run(&state, CommandContext::read("get_job_v1"), async {
+            ResultEnvelope::<i32>::err_message(
+                ErrorCode::NotFound,
+                messages::message(MessageCodeV1::JobNotFound),
+            )
+        })
+        .await;
+        assert_eq!(missing.error.expect("error").message, "任务不存在");
+
+        let rejected = run(&state, CommandContext::admin("cancel_job_v1"), async {
+            ResultEnvelope::ok(1)
+        })
+        .await;
+        assert_eq!(
+            rejected.error.expect("error").message,
+            "当前角色 viewer 无权执行 cancel_job_v1"
+        );
+
+        let requested = messages::request_locale(
+            Some(LocaleV1::En),
+            run(&state, CommandContext::admin("cancel_job_v1"), async {
+                ResultEnvelope::ok(1)
+            }),
+        )
+        .await;
+        assert_eq!(
+            requested.error.expect("error").message,
+            "role viewer may not run cancel_job_v1"
+        );
+    }
+}
@@ -1,2 +1,64 @@
+pub mod accelerator;
+pub mod access_log;
+pub mod active_queries;
+pub mod backend;
+pub mod backup;
+pub mod bundle;
+pub mod checkpoints;
+pub mod checksum;
+pub mod clock;
+pub mod column_order;
+pub mod completion;
+pub mod config;
 pub mod connection_manager;
+pub mod descriptions;
+pub mod diagnostics;
+pub mod disk_space;
+pub mod embedding_cost;
+pub mod encryption;
+pub mod export_templates;
+pub mod filter_history;
+pub mod fts_tokenizer;
+pub mod geometry;
+pub mod health;
+pub mod highlight;
+pub mod index_coverage;
+pub mod index_presets;
+pub mod jobs;
+pub mod json_format;
+pub mod lineage;
+pub mod log_control;
+pub mod masking;
+pub mod memory;
+pub mod messages;
+pub mod middleware;
+pub mod normalization;
+pub mod notifications;
+pub mod object_storage;
+pub mod pivot;
+pub mod prefetch;
+pub mod projection;
+pub mod projection_presets;
+pub mod query_stats;
+pub mod recall;
+pub mod rename_cascade;
+pub mod repro_sample;
+pub mod reranking;
+pub mod retention;
+pub mod row_counts;
+pub mod scan_stream;
+pub mod scheduler;
+pub mod schema_compat;
+pub mod search_defaults;
+pub mod secrets;
+pub mod share;
+pub mod shutdown;
+pub mod stats_history;
+pub mod store;
+pub mod time_filters;
+pub mod update_preview;
 pub mod v1;
+pub mod version_diff;
+pub mod version_tags;
+pub mod warm_scans;
+pub mod watch_folders;
@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::{Array, ArrayRef, FixedSizeListArray, Float64Array, RecordBatch};
+use arrow_schema::{DataType, Field, FieldRef, Schema};
+
+use crate::ipc::v1::{L2_NORMALIZATION, VECTOR_NORMALIZATION_KEY};
+
+/// Distance from 1 within which a vector counts as unit length.
+const UNIT_TOLERANCE: f64 = 1e-3;
+
+pub fn is_float_vector(field: &Field) -> bool {
+    matches!(field.data_type(), DataType::FixedSizeList(item, _) if item.data_type().is_floating())
+}
+
+pub fn is_normalized(field: &Field) -> bool {
+    field
+        .metadata()
+        .get(VECTOR_NORMALIZATION_KEY)
+        .is_some_and(|value| value == L2_NORMALIZATION)
+}
+
+/// Metadata of `field` with L2 normalization recorded. Lance replaces the whole map,
+/// so the rest is kept.
+pub fn normalized_metadata(field: &Field) -> HashMap<String, String> {
+    let mut metadata = field.metadata().clone();
+    metadata.insert(
+        VECTOR_NORMALIZATION_KEY.to_string(),
+        L2_NORMALIZATION.to_string(),
+    );
+    metadata
+}
+
+/// Float vector columns of `schema` a write normalizes: all of them when requested,
+/// otherwise those that already record normalization.
+pub fn columns_to_normalize(schema: &Schema, requested: bool) -> Vec<String> {
+    schema
+        .fields()
+        .iter()
+        .filter(|field| is_float_vector(field) && (requested || is_normalized(field)))
+        .map(|field| field.name().clone())
+        .collect()
+}
+
+fn norm(values: impl Iterator<Item = f64>) -> f64 {
+    values.map(|value| value * value).sum::<f64>().sqrt()
+}
+
+/// Scales `vector` to unit L2 length. Zero vectors have no direction and are kept.
+pub fn l2_normalize(vector: &mut [f32]) {
+    let norm = norm(vector.iter().map(|value| f64::from(*value)));
+    if norm > 0.0 {
+        for value in vector {
+            *value = (f64::from(*value) / norm) as f32;
+        }
+    }
+}
+
+pub fn is_unit(vector: &[f32]) -> bool {
+    (norm(vector.iter().map(|value| f64::from(*value))) - 1.0).abs() <= UNIT_TOLERANCE
+}
+
+/// L2-normalizes every vector of `columns` in `batch`. Null vectors stay null and
+/// null elements count as zero.
+pub fn normalize_batch(batch: &RecordBatch, columns: &[String]) -> Result<RecordBatch, String> {
+    if columns.is_empty() {
+        return Ok(batch.clone());
+    }
+    let schema = batch.schema();
+    let arrays = schema
+        .fields()
+        .iter()
+        .zip(batch.columns())
+        .map(|(field, array)| match field.data_type() {
+            DataType::FixedSizeList(item, size) if columns.contains(field.name()) => {
+                normalize_list(array, item, *size)
+            }
+            _ => Ok(array.clone()),
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    RecordBatch::try_new(schema, arrays).map_err(|error| error.to_string())
+}
+
+fn normalize_list(array: &ArrayRef, item: &FieldRef, size: i32) -> Result<ArrayRef, String> {
+    let list = array
+        .as_any()
+        .downcast_ref::<FixedSizeListArray>()
+        .ok_or_else(|| "vector column is not a fixed-size list".to_string())?;
+    let values =
+        arrow_cast::cast(list.values(), &DataType::Float64).map_err(|error| error.to_string())?;
+    let values = values
+        .as_any()
+        .downcast_ref::<Float64Array>()
+        .ok_or_else(|| "vector column does not hold numbers".to_string())?;
+    let size = size as usize;
+    let mut scaled = Vec::with_capacity(list.len() * size);
+    for index in 0..list.len() {
+        let range = list.value_offset(index) as usize..list.value_offset(index) as usize + size;
+        let vector = range
+            .map(|position| (!values.is_null(position)).then(|| values.value(position)))
+            .collect::<Vec<_>>();
+        let norm = norm(vector.iter().map(|value| value.unwrap_or(0.0)));
+        if list.is_null(index) || norm == 0.0 {
+            scaled.extend(vector);
+        } else {
+            scaled.extend(
+                vector
+                    .into_iter()
+                    .map(|value| value.map(|value| value / norm)),
+            );
+        }
+    }
+    let scaled = arrow_cast::cast(&Float64Array::from(scaled), item.data_type())
+        .map_err(|error| error.to_string())?;
+    let list =
+        FixedSizeListArray::try_new(item.clone(), size as i32, scaled, list.nulls().cloned())
+            .map_err(|error| error.to_string())?;
+    Ok(Arc::new(list))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::Float32Array;
+
+    use super::*;
+
+    #[test]
+    fn normalizes_vector_columns_and_keeps_nulls() {
+        let item = Arc::new(Field::new("item", DataType::Float32, true));
+        let values = Float32Array::from(vec![3.0, 4.0, 0.0, 0.0, 1.0, 1.0]);
+        let list = FixedSizeListArray::try_new(
+            item.clone(),
+            2,
+            Arc::new(values),
+            Some(vec![true, true, false].into()),
+        )
+        .expect("list");
+        let field = Field::new("vector", DataType::FixedSizeList(item, 2), true);
+        let schema = Arc::new(Schema::new(vec![field.clone()]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(list)]).expect("batch");
+
+        assert_eq!(columns_to_normalize(&schema, false), Vec::<String>::new());
+        let columns = columns_to_normalize(&schema, true);
+        assert_eq!(columns, vec!["vector"]);
+        let normalized = normalize_batch(&batch, &columns).expect("normalized");
+        let list = normalized
+            .column(0)
+            .as_any()
+            .downcast_ref::<FixedSizeListArray>()
+            .expect("list");
+        let values = list
+            .values()
+            .as_any()
+            .downcast_ref::<Float32Array>()
+            .expect("f32");
+        assert_eq!(values.values().to_vec(), vec![0.6, 0.8, 0.0, 0.0, 1.0, 1.0]);
+        assert!(list.is_null(2));
+
+        let recorded = field.with_metadata(normalized_metadata(&field));
+        assert!(is_normalized(&recorded));
+        let mut query = vec![3.0, 4.0];
+        assert!(!is_unit(&query));
+        l2_normalize(&mut query);
+        assert!(is_unit(&query));
+    }
+}
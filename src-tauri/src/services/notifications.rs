@@ -0,0 +1,122 @@
+use std::time::Duration;
+
+use log::{debug, warn};
+use serde::Serialize;
+
+use crate::ipc::v1::{JobKindV1, JobStateV1, JobStatusV1};
+use crate::state::AppState;
+
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Body POSTed to `jobWebhookUrl` when a job finishes.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobNotification {
+    pub job_id: String,
+    pub kind: JobKindV1,
+    pub status: JobStateV1,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    pub processed_rows: u64,
+    pub started_at_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finished_at_ms: Option<u64>,
+}
+
+impl JobNotification {
+    pub fn new(job: JobStatusV1, output_path: Option<String>) -> Self {
+        Self {
+            job_id: job.job_id,
+            kind: job.kind,
+            status: job.state,
+            output_path,
+            message: job.message,
+            processed_rows: job.processed_rows,
+            started_at_ms: job.started_at_ms,
+            finished_at_ms: job.finished_at_ms,
+        }
+    }
+}
+
+async fn post(url: &str, notification: &JobNotification) -> Result<(), String> {
+    let client = reqwest::Client::builder()
+        .timeout(WEBHOOK_TIMEOUT)
+        .build()
+        .map_err(|error| error.to_string())?;
+    client
+        .post(url)
+        .json(notification)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map(|_| ())
+        .map_err(|error| format!("job webhook request failed: {error}"))
+}
+
+/// Sends the finished job to the configured webhook in the background. Does nothing
+/// without a webhook or when the job is unknown; delivery failures are only logged so
+/// they never change the outcome of the job.
+pub fn job_finished(state: &AppState, job_id: &str, output_path: Option<String>) {
+    let url = state
+        .config
+        .lock()
+        .ok()
+        .and_then(|store| store.get().job_webhook_url);
+    let Some(url) = url else {
+        return;
+    };
+    let Some(job) = state.jobs.lock().ok().and_then(|jobs| jobs.get(job_id)) else {
+        return;
+    };
+    let notification = JobNotification::new(job, output_path);
+    tauri::async_runtime::spawn(async move {
+        match post(&url, &notification).await {
+            Ok(()) => debug!(
+                "job webhook delivered job_id={} status={:?}",
+                notification.job_id, notification.status
+            ),
+            Err(error) => warn!(
+                "job webhook failed job_id={} error={}",
+                notification.job_id, error
+            ),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_finished_jobs() {
+        let notification = JobNotification::new(
+            JobStatusV1 {
+                job_id: "job-1".to_string(),
+                kind: JobKindV1::Export,
+                state: JobStateV1::Failed,
+                processed_rows: 3,
+                total_rows: Some(10),
+                message: Some("disk full".to_string()),
+                started_at_ms: 1_000,
+                finished_at_ms: Some(2_000),
+                stage: None,
+            },
+            Some("/tmp/items.csv".to_string()),
+        );
+        assert_eq!(
+            serde_json::to_value(&notification).expect("serialize"),
+            serde_json::json!({
+                "jobId": "job-1",
+                "kind": "export",
+                "status": "failed",
+                "outputPath": "/tmp/items.csv",
+                "message": "disk full",
+                "processedRows": 3,
+                "startedAtMs": 1_000,
+                "finishedAtMs": 2_000,
+            })
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+
+use arrow_array::RecordBatch;
+use arrow_csv::WriterBuilder as CsvWriterBuilder;
+use arrow_json::LineDelimitedWriter;
+use arrow_schema::SchemaRef;
+use object_store::{ObjectStore, WriteMultipart};
+use parquet::arrow::ArrowWriter;
+use sha2::{Digest, Sha256};
+use url::Url;
+
+use crate::ipc::v1::{DataFileFormatV1, ObjectStoreTuningV1};
+use crate::services::checksum;
+
+const OBJECT_STORE_SCHEMES: [&str; 3] = ["s3://", "gs://", "az://"];
+const MAX_CONCURRENT_PARTS: usize = 4;
+
+/// Storage option keys the object_store client, and so lance, reads as timeouts.
+const CONNECT_TIMEOUT_KEY: &str = "connect_timeout";
+const REQUEST_TIMEOUT_KEY: &str = "timeout";
+
+/// Storage options for a connection's object-store tuning. Timeouts are written in
+/// milliseconds, which object_store parses as durations such as `1500ms`.
+pub fn tuning_options(tuning: &ObjectStoreTuningV1) -> Result<Vec<(String, String)>, String> {
+    let mut options = Vec::new();
+    for (key, name, value) in [
+        (
+            CONNECT_TIMEOUT_KEY,
+            "connectTimeoutMs",
+            tuning.connect_timeout_ms,
+        ),
+        (
+            REQUEST_TIMEOUT_KEY,
+            "requestTimeoutMs",
+            tuning.request_timeout_ms,
+        ),
+    ] {
+        match value {
+            Some(0) => return Err(format!("{name} must be greater than 0")),
+            Some(millis) => options.push((key.to_string(), format!("{millis}ms"))),
+            None => {}
+        }
+    }
+    Ok(options)
+}
+
+pub fn is_object_store_uri(path: &str) -> bool {
+    let lower = path.trim().to_ascii_lowercase();
+    OBJECT_STORE_SCHEMES
+        .iter()
+        .any(|scheme| lower.starts_with(scheme))
+}
+
+/// Options for encoding delimited exports.
+#[derive(Debug, Clone, Copy)]
+pub struct CsvOptions {
+    pub delimiter: u8,
+    pub with_header: bool,
+}
+
+/// Streams encoded export bytes into a multipart upload, so exports to buckets never
+/// touch the local disk.
+pub struct ObjectUpload {
+    upload: WriteMultipart,
+    hasher: Sha256,
+    bytes_written: u64,
+}
+
+/// Size and SHA-256 digest of a completed upload.
+#[derive(Debug, Clone)]
+pub struct UploadSummary {
+    pub bytes: u64,
+    pub sha256: String,
+}
+
+impl ObjectUpload {
+    pub async fn open(
+        uri: &str,
+        storage_options: &HashMap<String, String>,
+    ) -> Result<Self, String> {
+        let url = Url::parse(uri.trim()).map_err(|error| format!("invalid uri: {error}"))?;
+        let (store, path) = object_store::parse_url_opts(&url, storage_options.iter())
+            .map_err(|error| error.to_string())?;
+        let upload = store
+            .put_multipart(&path)
+            .await
+            .map_err(|error| error.to_string())?;
+        Ok(Self {
+            upload: WriteMultipart::new(upload),
+            hasher: Sha256::new(),
+            bytes_written: 0,
+        })
+    }
+
+    pub async fn write(&mut self, bytes: &[u8]) -> Result<(), String> {
+        if bytes.is_empty() {
+            return Ok(());
+        }
+        self.upload
+            .wait_for_capacity(MAX_CONCURRENT_PARTS)
+            .await
+            .map_err(|error| error.to_string())?;
+        self.upload.write(bytes);
+        self.hasher.update(bytes);
+        self.bytes_written += bytes.len() as u64;
+        Ok(())
+    }
+
+    pub async fn finish(self) -> Result<UploadSummary, String> {
+        self.upload
+            .finish()
+            .await
+            .map_err(|error| error.to_string())?;
+        Ok(UploadSummary {
+            bytes: self.bytes_written,
+            sha256: checksum::to_hex(&self.hasher.finalize()),
+        })
+    }
+
+    pub async fn abort(self) {
+        let _ = self.upload.abort().await;
+    }
+}
+
+async fn write_encoded(
+    upload: &mut ObjectUpload,
+    schema: SchemaRef,
+    batches: &[RecordBatch],
+    format: &DataFileFormatV1,
+    csv: CsvOptions,
+) -> Result<(), String> {
+    match format {
+        DataFileFormatV1::Csv => {
+            if batches.is_empty() {
+                let mut writer = CsvWriterBuilder::new()
+                    .with_header(csv.with_header)
+                    .with_delimiter(csv.delimiter)
+                    .build(Vec::new());
+                writer
+                    .write(&RecordBatch::new_empty(schema))
+                    .map_err(|error| error.to_string())?;
+                upload.write(&writer.into_inner()).await?;
+                return Ok(());
+            }
+            for (index, batch) in batches.iter().enumerate() {
+                let mut writer = CsvWriterBuilder::new()
+                    .with_header(csv.with_header && index == 0)
+                    .with_delimiter(csv.delimiter)
+                    .build(Vec::new());
+                writer.write(batch).map_err(|error| error.to_string())?;
+                upload.write(&writer.into_inner()).await?;
+            }
+        }
+        DataFileFormatV1::Parquet => {
+            let mut writer = ArrowWriter::try_new(Vec::new(), schema, None)
+                .map_err(|error| error.to_string())?;
+            for batch in batches {
+                writer.write(batch).map_err(|error| error.to_string())?;
+                writer.flush().map_err(|error| error.to_string())?;
+                let bytes = std::mem::take(writer.inner_mut());
+                upload.write(&bytes).await?;
+            }
+            let bytes = writer.into_inner().map_err(|error| error.to_string())?;
+            upload.write(&bytes).await?;
+        }
+        DataFileFormatV1::Jsonl => {
+            for batch in batches {
+                let mut writer = LineDelimitedWriter::new(Vec::new());
+                writer.write(batch).map_err(|error| error.to_string())?;
+                writer.finish().map_err(|error| error.to_string())?;
+                upload.write(&writer.into_inner()).await?;
+            }
+        }
+        DataFileFormatV1::Geojson => {
+            return Err("geojson exports are encoded before upload".to_string());
+        }
+    }
+    Ok(())
+}
+
+/// Encodes `batches` in the requested format and uploads them to `uri`, returning the
+/// size and checksum of the uploaded object. The upload is aborted if encoding or any
+/// part fails.
+pub async fn export_batches(
+    uri: &str,
+    storage_options: &HashMap<String, String>,
+    schema: SchemaRef,
+    batches: &[RecordBatch],
+    format: &DataFileFormatV1,
+    csv: CsvOptions,
+) -> Result<UploadSummary, String> {
+    let mut upload = ObjectUpload::open(uri, storage_options).await?;
+    if let Err(error) = write_encoded(&mut upload, schema, batches, format, csv).await {
+        upload.abort().await;
+        return Err(error);
+    }
+    upload.finish().await
+}
+
+/// Uploads already-encoded export bytes to `uri`.
+pub async fn upload_bytes(
+    uri: &str,
+    storage_options: &HashMap<String, String>,
+    bytes: &[u8],
+) -> Result<UploadSummary, String> {
+    let mut upload = ObjectUpload::open(uri, storage_options).await?;
+    if let Err(error) = upload.write(bytes).await {
+        upload.abort().await;
+        return Err(error);
+    }
+    upload.finish().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn translates_tuning_into_storage_options() {
+        let tuning = ObjectStoreTuningV1 {
+            connect_timeout_ms: Some(1500),
+            request_timeout_ms: None,
+        };
+        let options = tuning_options(&tuning)
+            .expect("options")
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+        assert_eq!(options.len(), 1);
+        assert_eq!(options["connect_timeout"], "1500ms");
+
+        let no_timeout = ObjectStoreTuningV1 {
+            request_timeout_ms: Some(0),
+            ..Default::default()
+        };
+        assert!(tuning_options(&no_timeout).is_err());
+    }
+}
@@ -0,0 +1,403 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use arrow_schema::Schema;
+use serde_json::Value;
+
+use crate::ipc::v1::{PivotAggregationV1, PivotResponseV1, PivotRowV1};
+
+pub const DEFAULT_MAX_COLUMNS: usize = 50;
+pub const MAX_COLUMNS_LIMIT: usize = 500;
+/// Distinct row groups a pivot may produce before it is rejected.
+pub const MAX_ROW_GROUPS: usize = 10_000;
+
+/// Validated columns of a pivot request.
+#[derive(Debug, Clone)]
+pub struct PivotSpec {
+    pub row_keys: Vec<String>,
+    pub column_key: String,
+    pub value_column: Option<String>,
+    pub aggregation: PivotAggregationV1,
+}
+
+impl PivotSpec {
+    pub fn new(
+        row_keys: Vec<String>,
+        column_key: String,
+        value_column: Option<String>,
+        aggregation: PivotAggregationV1,
+    ) -> Result<Self, String> {
+        let row_keys = row_keys
+            .into_iter()
+            .map(|key| key.trim().to_string())
+            .collect::<Vec<_>>();
+        if row_keys.is_empty() || row_keys.iter().any(String::is_empty) {
+            return Err("row_keys must name at least one column".to_string());
+        }
+        let column_key = column_key.trim().to_string();
+        if column_key.is_empty() {
+            return Err("column_key cannot be empty".to_string());
+        }
+        if row_keys.contains(&column_key) {
+            return Err(format!("column_key {column_key} cannot also be a row key"));
+        }
+        let value_column = value_column
+            .map(|column| column.trim().to_string())
+            .filter(|column| !column.is_empty());
+        if value_column.is_none() && aggregation != PivotAggregationV1::Count {
+            return Err(format!("value_column is required for {aggregation:?}"));
+        }
+        Ok(Self {
+            row_keys,
+            column_key,
+            value_column,
+            aggregation,
+        })
+    }
+
+    /// Columns the scan has to read.
+    pub fn projection(&self) -> Vec<String> {
+        let mut columns = self.row_keys.clone();
+        columns.push(self.column_key.clone());
+        if let Some(value) = &self.value_column {
+            if !columns.contains(value) {
+                columns.push(value.clone());
+            }
+        }
+        columns
+    }
+
+    /// Checks that every column exists and that aggregated values are numeric.
+    pub fn validate(&self, schema: &Schema) -> Result<(), String> {
+        for column in self.projection() {
+            schema
+                .field_with_name(&column)
+                .map_err(|_| format!("column not found: {column}"))?;
+        }
+        if let Some(value) = &self.value_column {
+            let data_type = schema
+                .field_with_name(value)
+                .map_err(|_| format!("column not found: {value}"))?
+                .data_type();
+            if self.aggregation != PivotAggregationV1::Count && !data_type.is_numeric() {
+                return Err(format!(
+                    "value_column {value} must be numeric for {:?}, found {data_type:?}",
+                    self.aggregation
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct Cell {
+    count: u64,
+    sum: f64,
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+impl Cell {
+    fn add(&mut self, value: Option<f64>) {
+        self.count += 1;
+        if let Some(value) = value {
+            self.sum += value;
+            self.min = Some(self.min.map_or(value, |min| min.min(value)));
+            self.max = Some(self.max.map_or(value, |max| max.max(value)));
+        }
+    }
+
+    fn finish(&self, aggregation: PivotAggregationV1) -> Option<f64> {
+        if self.count == 0 {
+            return None;
+        }
+        match aggregation {
+            PivotAggregationV1::Count => Some(self.count as f64),
+            PivotAggregationV1::Sum => Some(self.sum),
+            PivotAggregationV1::Avg => Some(self.sum / self.count as f64),
+            PivotAggregationV1::Min => self.min,
+            PivotAggregationV1::Max => self.max,
+        }
+    }
+}
+
+struct RowGroup {
+    keys: Vec<Value>,
+    cells: HashMap<String, Cell>,
+}
+
+/// Accumulates JSON rows into pivot cells batch by batch.
+pub struct PivotBuilder {
+    spec: PivotSpec,
+    groups: Vec<RowGroup>,
+    group_index: HashMap<String, usize>,
+    column_counts: HashMap<String, u64>,
+    scanned_rows: usize,
+    overflowed: bool,
+}
+
+fn column_label(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => "null".to_string(),
+        Some(Value::String(text)) => text.clone(),
+        Some(other) => other.to_string(),
+    }
+}
+
+/// Reads an aggregated value. Decimals may arrive as JSON strings.
+fn numeric_value(value: Option<&Value>) -> Option<f64> {
+    match value? {
+        Value::Number(number) => number.as_f64(),
+        Value::String(text) => text.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Orders nulls first, then booleans, numbers and strings by value.
+fn compare_values(left: &Value, right: &Value) -> Ordering {
+    fn rank(value: &Value) -> u8 {
+        match value {
+            Value::Null => 0,
+            Value::Bool(_) => 1,
+            Value::Number(_) => 2,
+            Value::String(_) => 3,
+            _ => 4,
+        }
+    }
+    match (left, right) {
+        (Value::Bool(left), Value::Bool(right)) => left.cmp(right),
+        (Value::Number(left), Value::Number(right)) => left
+            .as_f64()
+            .partial_cmp(&right.as_f64())
+            .unwrap_or(Ordering::Equal),
+        (Value::String(left), Value::String(right)) => left.cmp(right),
+        _ => rank(left)
+            .cmp(&rank(right))
+            .then_with(|| left.to_string().cmp(&right.to_string())),
+    }
+}
+
+impl PivotBuilder {
+    pub fn new(spec: PivotSpec) -> Self {
+        Self {
+            spec,
+            groups: Vec::new(),
+            group_index: HashMap::new(),
+            column_counts: HashMap::new(),
+            scanned_rows: 0,
+            overflowed: false,
+        }
+    }
+
+    pub fn add_rows(&mut self, rows: &[Value]) {
+        for row in rows {
+            self.scanned_rows += 1;
+            let keys = self
+                .spec
+                .row_keys
+                .iter()
+                .map(|key| row.get(key).cloned().unwrap_or(Value::Null))
+                .collect::<Vec<_>>();
+            let group_key = Value::from(keys.clone()).to_string();
+            let index = match self.group_index.get(&group_key) {
+                Some(index) => *index,
+                None => {
+                    if self.groups.len() >= MAX_ROW_GROUPS {
+                        self.overflowed = true;
+                        continue;
+                    }
+                    self.groups.push(RowGroup {
+                        keys,
+                        cells: HashMap::new(),
+                    });
+                    self.group_index.insert(group_key, self.groups.len() - 1);
+                    self.groups.len() - 1
+                }
+            };
+
+            let column = column_label(row.get(&self.spec.column_key));
+            let value = match &self.spec.value_column {
+                Some(value_column) => {
+                    let value = row.get(value_column).filter(|value| !value.is_null());
+                    if value.is_none() {
+                        continue;
+                    }
+                    numeric_value(value)
+                }
+                None => None,
+            };
+            *self.column_counts.entry(column.clone()).or_default() += 1;
+            self.groups[index]
+                .cells
+                .entry(column)
+                .or_default()
+                .add(value);
+        }
+    }
+
+    /// Keeps the `max_columns` most frequent column values and sorts rows by key.
+    pub fn finish(self, max_columns: usize) -> Result<PivotResponseV1, String> {
+        if self.overflowed {
+            return Err(format!(
+                "pivot produces more than {MAX_ROW_GROUPS} rows; narrow the filter or row keys"
+            ));
+        }
+        let mut columns = self.column_counts.into_iter().collect::<Vec<_>>();
+        columns.sort_by(|(left, left_count), (right, right_count)| {
+            right_count.cmp(left_count).then_with(|| left.cmp(right))
+        });
+        let omitted_columns = columns.len().saturating_sub(max_columns);
+        let columns = columns
+            .into_iter()
+            .take(max_columns)
+            .map(|(column, _)| column)
+            .collect::<Vec<_>>();
+
+        let aggregation = self.spec.aggregation;
+        let mut groups = self.groups;
+        groups.sort_by(|left, right| {
+            left.keys
+                .iter()
+                .zip(&right.keys)
+                .map(|(left, right)| compare_values(left, right))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(Ordering::Equal)
+        });
+        let rows = groups
+            .into_iter()
+            .map(|group| PivotRowV1 {
+                values: columns
+                    .iter()
+                    .map(|column| {
+                        group
+                            .cells
+                            .get(column)
+                            .and_then(|cell| cell.finish(aggregation))
+                    })
+                    .collect(),
+                keys: group.keys,
+            })
+            .collect();
+
+        Ok(PivotResponseV1 {
+            row_keys: self.spec.row_keys,
+            column_key: self.spec.column_key,
+            aggregation,
+            columns,
+            rows,
+            omitted_columns,
+            scanned_rows: self.scanned_rows,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_schema::{DataType, Field};
+    use serde_json::json;
+
+    use super::*;
+
+    fn spec(value_column: Option<&str>, aggregation: PivotAggregationV1) -> PivotSpec {
+        PivotSpec::new(
+            vec!["region".to_string()],
+            "product".to_string(),
+            value_column.map(str::to_string),
+            aggregation,
+        )
+        .expect("spec")
+    }
+
+    fn sales() -> Vec<Value> {
+        vec![
+            json!({"region": "west", "product": "tea", "amount": 3}),
+            json!({"region": "east", "product": "tea", "amount": 5}),
+            json!({"region": "west", "product": "tea", "amount": 7}),
+            json!({"region": "west", "product": "coffee", "amount": 1}),
+            json!({"region": "east", "product": "cocoa"}),
+            json!({"product": "tea", "amount": 2}),
+        ]
+    }
+
+    #[test]
+    fn builds_cross_tab_with_capped_columns() {
+        let mut builder = PivotBuilder::new(spec(Some("amount"), PivotAggregationV1::Sum));
+        builder.add_rows(&sales());
+        let pivot = builder.finish(2).expect("pivot");
+
+        assert_eq!(pivot.columns, vec!["tea", "coffee"]);
+        assert_eq!(pivot.omitted_columns, 0);
+        assert_eq!(pivot.scanned_rows, 6);
+        assert_eq!(
+            pivot.rows,
+            vec![
+                PivotRowV1 {
+                    keys: vec![Value::Null],
+                    values: vec![Some(2.0), None],
+                },
+                PivotRowV1 {
+                    keys: vec![json!("east")],
+                    values: vec![Some(5.0), None],
+                },
+                PivotRowV1 {
+                    keys: vec![json!("west")],
+                    values: vec![Some(10.0), Some(1.0)],
+                },
+            ]
+        );
+
+        let mut counts = PivotBuilder::new(spec(None, PivotAggregationV1::Count));
+        counts.add_rows(&sales());
+        let counts = counts.finish(1).expect("pivot");
+        assert_eq!(counts.columns, vec!["tea"]);
+        assert_eq!(counts.omitted_columns, 2);
+        assert_eq!(counts.rows[2].values, vec![Some(2.0)]);
+
+        let mut averages = PivotBuilder::new(spec(Some("amount"), PivotAggregationV1::Avg));
+        averages.add_rows(&sales());
+        assert_eq!(
+            averages.finish(10).expect("pivot").rows[2].values,
+            vec![Some(5.0), Some(1.0)]
+        );
+    }
+
+    #[test]
+    fn validates_pivot_columns() {
+        assert!(
+            PivotSpec::new(Vec::new(), "product".to_string(), None, Default::default()).is_err()
+        );
+        assert!(PivotSpec::new(
+            vec!["product".to_string()],
+            "product".to_string(),
+            None,
+            Default::default()
+        )
+        .is_err());
+        assert!(PivotSpec::new(
+            vec!["region".to_string()],
+            "product".to_string(),
+            None,
+            PivotAggregationV1::Max
+        )
+        .is_err());
+
+        let schema = Schema::new(vec![
+            Field::new("region", DataType::Utf8, true),
+            Field::new("product", DataType::Utf8, true),
+            Field::new("amount", DataType::Int64, true),
+        ]);
+        assert!(spec(Some("amount"), PivotAggregationV1::Sum)
+            .validate(&schema)
+            .is_ok());
+        assert!(spec(Some("product"), PivotAggregationV1::Sum)
+            .validate(&schema)
+            .is_err());
+        assert!(spec(Some("product"), PivotAggregationV1::Count)
+            .validate(&schema)
+            .is_ok());
+        assert!(spec(Some("missing"), PivotAggregationV1::Count)
+            .validate(&schema)
+            .is_err());
+    }
+}
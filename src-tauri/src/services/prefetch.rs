@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use futures_util::future::{BoxFuture, FutureExt, Shared};
+
+use crate::ipc::v1::{ScanResponseV1, DEFAULT_PREFETCH_CACHE_PAGES, DEFAULT_PREFETCH_TTL_SECONDS};
+
+pub const DEFAULT_PREFETCH_TTL: Duration = Duration::from_secs(DEFAULT_PREFETCH_TTL_SECONDS);
+pub const DEFAULT_MAX_PREFETCHED_PAGES: usize = DEFAULT_PREFETCH_CACHE_PAGES;
+
+/// A page query running (or finished) in the background. Cloning shares the result.
+pub type PrefetchedPage = Shared<BoxFuture<'static, Result<ScanResponseV1, String>>>;
+
+struct PrefetchEntry {
+    table_id: String,
+    created_at: Instant,
+    page: PrefetchedPage,
+}
+
+/// Next-page scan results keyed by cursor. Entries are consumed on first use, expire
+/// after a short TTL, and are dropped when their table is modified.
+pub struct PrefetchCache {
+    entries: HashMap<String, PrefetchEntry>,
+    max_pages: usize,
+    ttl: Duration,
+}
+
+impl Default for PrefetchCache {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+            max_pages: DEFAULT_MAX_PREFETCHED_PAGES,
+            ttl: DEFAULT_PREFETCH_TTL,
+        }
+    }
+}
+
+impl PrefetchCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies configured limits; entries beyond the new size are evicted on the next
+    /// insert.
+    pub fn set_limits(&mut self, max_pages: usize, ttl: Duration) {
+        self.max_pages = max_pages.max(1);
+        self.ttl = ttl;
+    }
+
+    /// Registers a page future and returns a handle that drives it to completion.
+    pub fn insert(
+        &mut self,
+        key: String,
+        table_id: &str,
+        page: BoxFuture<'static, Result<ScanResponseV1, String>>,
+    ) -> PrefetchedPage {
+        self.prune();
+        let page = page.shared();
+        self.entries.insert(
+            key,
+            PrefetchEntry {
+                table_id: table_id.to_string(),
+                created_at: Instant::now(),
+                page: page.clone(),
+            },
+        );
+        page
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    pub fn take(&mut self, key: &str) -> Option<PrefetchedPage> {
+        let entry = self.entries.remove(key)?;
+        if entry.created_at.elapsed() > self.ttl {
+            return None;
+        }
+        Some(entry.page)
+    }
+
+    pub fn invalidate_table(&mut self, table_id: &str) {
+        self.entries.retain(|_, entry| entry.table_id != table_id);
+    }
+
+    fn prune(&mut self) {
+        let ttl = self.ttl;
+        self.entries
+            .retain(|_, entry| entry.created_at.elapsed() <= ttl);
+        while self.entries.len() >= self.max_pages {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.created_at)
+                .map(|(key, _)| key.clone());
+            match oldest {
+                Some(key) => self.entries.remove(&key),
+                None => break,
+            };
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use lancedb::query::Select;
+
+use crate::ipc::v1::MaskingRuleV1;
+
+/// Characters that mark a projection entry as a SQL expression rather than a column.
+const EXPRESSION_MARKERS: [&str; 7] = ["(", "+", "*", "/", "%", "||", " - "];
+
+/// One projection entry: a column name (or nested path), or a SQL expression
+/// returned under `alias`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProjectionEntry {
+    Column(String),
+    Expression { alias: String, expression: String },
+}
+
+impl ProjectionEntry {
+    /// Name of the output column.
+    pub fn output_name(&self) -> &str {
+        match self {
+            ProjectionEntry::Column(name) => name,
+            ProjectionEntry::Expression { alias, .. } => alias,
+        }
+    }
+}
+
+/// Finds the byte offset of the last ` AS ` outside quotes, backticks and parentheses.
+fn alias_separator(entry: &str) -> Option<usize> {
+    let bytes = entry.as_bytes();
+    let mut depth = 0usize;
+    let mut quote: Option<u8> = None;
+    let mut found = None;
+    for (index, &byte) in bytes.iter().enumerate() {
+        match quote {
+            Some(open) if byte == open => quote = None,
+            Some(_) => {}
+            None => match byte {
+                b'\'' | b'"' | b'`' => quote = Some(byte),
+                b'(' => depth += 1,
+                b')' => depth = depth.saturating_sub(1),
+                _ if depth == 0
+                    && byte.is_ascii_whitespace()
+                    && entry
+                        .get(index + 1..index + 3)
+                        .is_some_and(|word| word.eq_ignore_ascii_case("as"))
+                    && bytes
+                        .get(index + 3)
+                        .is_some_and(|next| next.is_ascii_whitespace()) =>
+                {
+                    found = Some(index);
+                }
+                _ => {}
+            },
+        }
+    }
+    found
+}
+
+fn unquote_identifier(value: &str) -> String {
+    let value = value.trim();
+    value
+        .strip_prefix('`')
+        .and_then(|rest| rest.strip_suffix('`'))
+        .or_else(|| {
+            value
+                .strip_prefix('"')
+                .and_then(|rest| rest.strip_suffix('"'))
+        })
+        .unwrap_or(value)
+        .to_string()
+}
+
+/// Parses `price * qty AS total`, `lower(text)` or a plain column name. Expressions
+/// without an alias are returned under their own text.
+pub fn parse_entry(entry: &str) -> ProjectionEntry {
+    let entry = entry.trim();
+    if let Some(separator) = alias_separator(entry) {
+        let expression = entry[..separator].trim();
+        let alias = unquote_identifier(&entry[separator + 3..]);
+        if !expression.is_empty() && !alias.is_empty() {
+            return ProjectionEntry::Expression {
+                alias,
+                expression: expression.to_string(),
+            };
+        }
+    }
+    if EXPRESSION_MARKERS
+        .iter()
+        .any(|marker| entry.contains(marker))
+    {
+        return ProjectionEntry::Expression {
+            alias: entry.to_string(),
+            expression: entry.to_string(),
+        };
+    }
+    ProjectionEntry::Column(entry.to_string())
+}
+
+fn is_plain_path(name: &str) -> bool {
+    name.split('.').all(|part| {
+        part.chars()
+            .next()
+            .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+            && part
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+    })
+}
+
+/// Quotes a column name for SQL unless it is a plain identifier or nested path.
+pub fn quote_identifier(name: &str) -> String {
+    if is_plain_path(name) {
+        name.to_string()
+    } else {
+        format!("`{}`", name.replace('`', "``"))
+    }
+}
+
+/// Builds the LanceDB selection. Plain column lists keep using `Select::columns`;
+/// any expression switches the whole projection to `Select::dynamic`.
+pub fn to_select(projection: &[String]) -> Select {
+    let entries = projection
+        .iter()
+        .map(|entry| parse_entry(entry))
+        .collect::<Vec<_>>();
+    if entries
+        .iter()
+        .all(|entry| matches!(entry, ProjectionEntry::Column(_)))
+    {
+        return Select::columns(projection);
+    }
+    let columns = entries
+        .into_iter()
+        .map(|entry| match entry {
+            ProjectionEntry::Column(name) => {
+                let quoted = quote_identifier(&name);
+                (name, quoted)
+            }
+            ProjectionEntry::Expression { alias, expression } => (alias, expression),
+        })
+        .collect::<Vec<_>>();
+    Select::dynamic(&columns)
+}
+
+/// Identifiers an expression mentions, ignoring string literals.
+fn referenced_identifiers(expression: &str) -> Vec<String> {
+    let mut identifiers = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    for ch in expression.chars() {
+        match quote {
+            Some('`') if ch == '`' => {
+                identifiers.push(std::mem::take(&mut current));
+                quote = None;
+            }
+            Some('`') => current.push(ch),
+            Some(open) if ch == open => quote = None,
+            Some(_) => {}
+            None if ch == '\'' || ch == '`' => {
+                if !current.is_empty() {
+                    identifiers.push(std::mem::take(&mut current));
+                }
+                quote = Some(ch);
+            }
+            None if ch.is_alphanumeric() || ch == '_' => current.push(ch),
+            None => {
+                if !current.is_empty() {
+                    identifiers.push(std::mem::take(&mut current));
+                }
+            }
+        }
+    }
+    if !current.is_empty() {
+        identifiers.push(current);
+    }
+    identifiers
+}
+
+/// Masking works on output columns, so an expression over a masked column would
+/// return raw values. Such projections are rejected.
+pub fn check_masked_columns(projection: &[String], rules: &[MaskingRuleV1]) -> Result<(), String> {
+    if rules.is_empty() {
+        return Ok(());
+    }
+    for entry in projection.iter().map(|entry| parse_entry(entry)) {
+        let ProjectionEntry::Expression { expression, .. } = entry else {
+            continue;
+        };
+        let identifiers = referenced_identifiers(&expression);
+        if let Some(rule) = rules.iter().find(|rule| {
+            identifiers
+                .iter()
+                .any(|identifier| identifier.eq_ignore_ascii_case(&rule.column))
+        }) {
+            return Err(format!(
+                "projection expression `{expression}` references masked column {}",
+                rule.column
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ipc::v1::MaskingStrategyV1;
+
+    use super::*;
+
+    fn expression(alias: &str, expression: &str) -> ProjectionEntry {
+        ProjectionEntry::Expression {
+            alias: alias.to_string(),
+            expression: expression.to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_columns_and_aliased_expressions() {
+        assert_eq!(
+            parse_entry(" id "),
+            ProjectionEntry::Column("id".to_string())
+        );
+        assert_eq!(
+            parse_entry("meta.source"),
+            ProjectionEntry::Column("meta.source".to_string())
+        );
+        assert_eq!(
+            parse_entry("price * qty AS total"),
+            expression("total", "price * qty")
+        );
+        assert_eq!(
+            parse_entry("regexp_extract(text, '(\\w+) as (\\w+)', 1) as `first word`"),
+            expression("first word", "regexp_extract(text, '(\\w+) as (\\w+)', 1)")
+        );
+        assert_eq!(
+            parse_entry("lower(text)"),
+            expression("lower(text)", "lower(text)")
+        );
+        assert_eq!(parse_entry("total AS").output_name(), "total AS");
+        assert!(is_plain_path("meta.source_1"));
+        assert!(!is_plain_path("unit price"));
+    }
+
+    #[test]
+    fn rejects_expressions_over_masked_columns() {
+        let rules = vec![MaskingRuleV1 {
+            table_name: None,
+            column: "email".to_string(),
+            strategy: MaskingStrategyV1::Redact,
+        }];
+        let projection = |entries: &[&str]| {
+            entries
+                .iter()
+                .map(|entry| entry.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert!(check_masked_columns(&projection(&["email", "id + 1 AS next"]), &rules).is_ok());
+        assert!(check_masked_columns(&projection(&["'email' || name AS label"]), &rules).is_ok());
+        assert!(check_masked_columns(&projection(&["upper(email) AS shout"]), &rules).is_err());
+        assert!(check_masked_columns(&projection(&["upper(`EMAIL`) AS shout"]), &rules).is_err());
+        assert!(check_masked_columns(&projection(&["upper(email) AS shout"]), &[]).is_ok());
+    }
+}
@@ -0,0 +1,254 @@
+use std::path::PathBuf;
+
+use arrow_schema::Schema;
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::ipc::v1::ProjectionPresetV1;
+use crate::services::projection::{self, ProjectionEntry};
+use crate::services::store;
+
+pub const PROJECTION_PRESETS_FILE: &str = "projection_presets.json";
+
+const MAX_NAME_CHARS: usize = 64;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProjectionPresetEntry {
+    connection_uri: String,
+    table_name: String,
+    #[serde(flatten)]
+    preset: ProjectionPresetV1,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProjectionPresetsFile {
+    #[serde(default)]
+    presets: Vec<ProjectionPresetEntry>,
+}
+
+/// Named column lists keyed by connection URI and table name, persisted to
+/// `projection_presets.json` in the app data directory when one is configured.
+#[derive(Default)]
+pub struct ProjectionPresetStore {
+    path: Option<PathBuf>,
+    entries: Vec<ProjectionPresetEntry>,
+}
+
+impl ProjectionPresetStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load(path: PathBuf) -> Self {
+        let entries = match store::load_json::<ProjectionPresetsFile>(&path) {
+            Ok(file) => file.presets,
+            Err(error) => {
+                warn!(
+                    "failed to load projection presets path={:?} error={}",
+                    path, error
+                );
+                Vec::new()
+            }
+        };
+        Self {
+            path: Some(path),
+            entries,
+        }
+    }
+
+    fn is_table(entry: &ProjectionPresetEntry, connection_uri: &str, table_name: &str) -> bool {
+        entry.connection_uri == connection_uri && entry.table_name == table_name
+    }
+
+    /// Presets of a table sorted by name.
+    pub fn list(&self, connection_uri: &str, table_name: &str) -> Vec<ProjectionPresetV1> {
+        let mut presets = self
+            .entries
+            .iter()
+            .filter(|entry| Self::is_table(entry, connection_uri, table_name))
+            .map(|entry| entry.preset.clone())
+            .collect::<Vec<_>>();
+        presets.sort_by(|left, right| left.name.cmp(&right.name));
+        presets
+    }
+
+    pub fn get(
+        &self,
+        connection_uri: &str,
+        table_name: &str,
+        name: &str,
+    ) -> Option<ProjectionPresetV1> {
+        self.entries
+            .iter()
+            .find(|entry| {
+                Self::is_table(entry, connection_uri, table_name) && entry.preset.name == name
+            })
+            .map(|entry| entry.preset.clone())
+    }
+
+    /// Adds a preset or replaces the table's preset with the same name.
+    pub fn save(
+        &mut self,
+        connection_uri: &str,
+        table_name: &str,
+        preset: ProjectionPresetV1,
+    ) -> Result<(), String> {
+        self.entries.retain(|entry| {
+            !Self::is_table(entry, connection_uri, table_name) || entry.preset.name != preset.name
+        });
+        self.entries.push(ProjectionPresetEntry {
+            connection_uri: connection_uri.to_string(),
+            table_name: table_name.to_string(),
+            preset,
+        });
+        self.persist()
+    }
+
+    /// Returns false when the table has no preset with this name.
+    pub fn delete(
+        &mut self,
+        connection_uri: &str,
+        table_name: &str,
+        name: &str,
+    ) -> Result<bool, String> {
+        let before = self.entries.len();
+        self.entries.retain(|entry| {
+            !Self::is_table(entry, connection_uri, table_name) || entry.preset.name != name
+        });
+        if self.entries.len() == before {
+            return Ok(false);
+        }
+        self.persist()?;
+        Ok(true)
+    }
+
+    /// Rewrites the table's presets with `rewrite`, which returns `None` for those it
+    /// leaves alone. Returns the names of the rewritten presets.
+    pub fn rewrite(
+        &mut self,
+        connection_uri: &str,
+        table_name: &str,
+        rewrite: impl Fn(&ProjectionPresetV1) -> Option<ProjectionPresetV1>,
+    ) -> Result<Vec<String>, String> {
+        let mut rewritten = Vec::new();
+        for entry in &mut self.entries {
+            if !Self::is_table(entry, connection_uri, table_name) {
+                continue;
+            }
+            if let Some(updated) = rewrite(&entry.preset) {
+                rewritten.push(updated.name.clone());
+                entry.preset = updated;
+            }
+        }
+        if rewritten.is_empty() {
+            return Ok(rewritten);
+        }
+        rewritten.sort();
+        self.persist().map(|_| rewritten)
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        let Some(path) = self.path.as_ref() else {
+            return Ok(());
+        };
+        store::save_json(
+            path,
+            &ProjectionPresetsFile {
+                presets: self.entries.clone(),
+            },
+        )
+    }
+}
+
+/// Trims the name and entries and checks the plain columns against the table
+/// schema. Expressions are kept as written and checked when a query runs them.
+pub fn normalize(
+    preset: ProjectionPresetV1,
+    schema: &Schema,
+) -> Result<ProjectionPresetV1, String> {
+    let name = preset.name.trim().to_string();
+    if name.is_empty() {
+        return Err("projection preset name cannot be empty".to_string());
+    }
+    if name.chars().count() > MAX_NAME_CHARS {
+        return Err(format!(
+            "projection preset name is longer than {MAX_NAME_CHARS} characters"
+        ));
+    }
+
+    let mut columns = Vec::new();
+    for entry in preset.columns {
+        let entry = entry.trim();
+        if entry.is_empty() || columns.iter().any(|column| column == entry) {
+            continue;
+        }
+        if let ProjectionEntry::Column(column) = projection::parse_entry(entry) {
+            let top_level = column.split('.').next().unwrap_or(&column);
+            if schema.field_with_name(top_level).is_err() {
+                return Err(format!("column not found: {column}"));
+            }
+        }
+        columns.push(entry.to_string());
+    }
+    if columns.is_empty() {
+        return Err("projection preset needs at least one column".to_string());
+    }
+    Ok(ProjectionPresetV1 { name, columns })
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_schema::{DataType, Field};
+
+    use super::*;
+
+    fn preset(name: &str, columns: &[&str]) -> ProjectionPresetV1 {
+        ProjectionPresetV1 {
+            name: name.to_string(),
+            columns: columns.iter().map(|column| column.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn normalizes_and_stores_presets_per_table() {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("text", DataType::Utf8, true),
+        ]);
+        let normalized = normalize(
+            preset(" minimal ", &["id", " text ", "id", "", "id * 2 AS double"]),
+            &schema,
+        )
+        .expect("normalize");
+        assert_eq!(
+            normalized,
+            preset("minimal", &["id", "text", "id * 2 AS double"])
+        );
+        assert!(normalize(preset("x", &["missing"]), &schema).is_err());
+        assert!(normalize(preset(" ", &["id"]), &schema).is_err());
+        assert!(normalize(preset("x", &[" "]), &schema).is_err());
+
+        let mut store = ProjectionPresetStore::new();
+        store
+            .save("/data", "items", preset("b", &["id"]))
+            .expect("save");
+        store
+            .save("/data", "items", preset("a", &["text"]))
+            .expect("save");
+        store
+            .save("/data", "other", preset("a", &["id"]))
+            .expect("save");
+        store
+            .save("/data", "items", preset("a", &["id", "text"]))
+            .expect("replace");
+        assert_eq!(
+            store.list("/data", "items"),
+            vec![preset("a", &["id", "text"]), preset("b", &["id"])]
+        );
+        assert!(store.delete("/data", "items", "a").expect("delete"));
+        assert!(!store.delete("/data", "items", "a").expect("delete again"));
+        assert_eq!(store.get("/data", "other", "a"), Some(preset("a", &["id"])));
+    }
+}
@@ -0,0 +1,424 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use arrow_schema::{DataType, Schema};
+
+use crate::ipc::v1::{PlanStageV1, QueryExecutionStatsV1};
+
+/// Plan nodes that read through a vector, scalar or full-text index.
+const INDEX_STAGES: [&str; 8] = [
+    "ANNSubIndex",
+    "ANNIvfPartition",
+    "ScalarIndexQuery",
+    "MaterializeIndex",
+    "MatchQuery",
+    "PhraseQuery",
+    "BoostQuery",
+    "BooleanQuery",
+];
+/// Plan nodes that compare every candidate row instead of using an index.
+const FLAT_STAGES: [&str; 2] = ["KNNVectorDistance", "FlatMatchQuery"];
+/// Plan nodes that read rows from the dataset files.
+const READ_STAGES: [&str; 3] = ["LanceScan", "LanceRead", "LanceTake"];
+
+fn stage_name(line: &str) -> &str {
+    let end = line
+        .find(|ch: char| ch == ':' || ch == ',' || ch.is_whitespace())
+        .unwrap_or(line.len());
+    &line[..end]
+}
+
+/// Extracts `key=value` pairs from the trailing `metrics=[...]` of a plan line.
+/// Values may contain commas inside brackets, so the split tracks nesting.
+fn stage_metrics(line: &str) -> BTreeMap<String, String> {
+    let mut metrics = BTreeMap::new();
+    let Some(start) = line.rfind("metrics=[") else {
+        return metrics;
+    };
+    let body = &line[start + "metrics=[".len()..];
+    let body = body.strip_suffix(']').unwrap_or(body);
+
+    let mut depth = 0usize;
+    let mut current = String::new();
+    let mut entries = Vec::new();
+    for ch in body.chars() {
+        match ch {
+            '[' | '(' | '{' => depth += 1,
+            ']' | ')' | '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                entries.push(std::mem::take(&mut current));
+                continue;
+            }
+            _ => {}
+        }
+        current.push(ch);
+    }
+    entries.push(current);
+
+    for entry in entries {
+        if let Some((key, value)) = entry.split_once('=') {
+            metrics.insert(key.trim().to_string(), value.trim().to_string());
+        }
+    }
+    metrics
+}
+
+fn parse_count(value: &str) -> Option<u64> {
+    value.trim().replace('_', "").parse().ok()
+}
+
+/// Converts DataFusion duration strings such as `12.5ms`, `340µs` or `2s` to
+/// milliseconds.
+fn parse_duration_ms(value: &str) -> Option<f64> {
+    let value = value.trim();
+    let split = value
+        .find(|ch: char| !(ch.is_ascii_digit() || ch == '.'))
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number = number.parse::<f64>().ok()?;
+    let factor = match unit.trim() {
+        "ns" => 1e-6,
+        "µs" | "us" => 1e-3,
+        "ms" => 1.0,
+        "s" => 1e3,
+        _ => return None,
+    };
+    Some(number * factor)
+}
+
+/// Parses the indented text produced by `analyze_plan` into one stage per plan node.
+pub fn parse_analyzed_plan(plan: &str) -> Vec<PlanStageV1> {
+    plan.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let trimmed = line.trim_start();
+            let depth = (line.len() - trimmed.len()) / 2;
+            let metrics = stage_metrics(trimmed);
+            PlanStageV1 {
+                name: stage_name(trimmed).to_string(),
+                depth,
+                output_rows: metrics
+                    .get("output_rows")
+                    .and_then(|value| parse_count(value)),
+                elapsed_ms: metrics
+                    .get("elapsed_compute")
+                    .and_then(|value| parse_duration_ms(value)),
+                metrics,
+            }
+        })
+        .collect()
+}
+
+fn sum_metric(stages: &[PlanStageV1], key: &str) -> Option<u64> {
+    stages
+        .iter()
+        .filter_map(|stage| stage.metrics.get(key).and_then(|value| parse_count(value)))
+        .reduce(|total, value| total.saturating_add(value))
+}
+
+/// Summarizes an analyzed plan. `elapsed` is the wall time of the analyze run.
+pub fn summarize_plan(plan: String, elapsed: Duration) -> QueryExecutionStatsV1 {
+    let stages = parse_analyzed_plan(&plan);
+    let has_stage = |names: &[&str]| {
+        stages
+            .iter()
+            .any(|stage| names.contains(&stage.name.as_str()))
+    };
+    let index_used = has_stage(&INDEX_STAGES);
+    let flat_scan = has_stage(&FLAT_STAGES) || !index_used;
+    let rows_scanned = stages
+        .iter()
+        .filter(|stage| READ_STAGES.contains(&stage.name.as_str()))
+        .filter_map(|stage| stage.output_rows)
+        .reduce(|total, value| total.saturating_add(value));
+
+    QueryExecutionStatsV1 {
+        elapsed_ms: elapsed.as_millis() as u64,
+        index_used,
+        flat_scan,
+        rows_scanned,
+        bytes_read: sum_metric(&stages, "bytes_read"),
+        iops: sum_metric(&stages, "iops"),
+        stages,
+        plan,
+    }
+}
+
+/// Assumed size of one string or binary value when weighing columns.
+const VARIABLE_WIDTH_BYTES: u64 = 32;
+
+/// What an unexecuted plan reads, taken from the scan stages of `explain_plan`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlannedRead {
+    /// Columns loaded by the scan stages.
+    pub columns: Vec<String>,
+    /// Fragments the scan stages visit, when the plan reports them.
+    pub fragments: Option<u64>,
+    pub index_used: bool,
+}
+
+/// Size of a table before any query runs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TableFootprint {
+    pub rows: u64,
+    pub fragments: u64,
+    pub bytes: u64,
+}
+
+/// Upper bounds for what a query reads.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScanEstimate {
+    pub rows: u64,
+    pub fragments: u64,
+    pub bytes: u64,
+    /// Every row of the table is read and no index narrows the scan.
+    pub full_scan: bool,
+}
+
+/// Returns the comma-separated entries of `key=[...]` in a plan line.
+fn bracketed_list(line: &str, key: &str) -> Option<Vec<String>> {
+    let start = line.find(&format!("{key}=["))? + key.len() + 2;
+    let mut depth = 0usize;
+    let mut current = String::new();
+    let mut entries = Vec::new();
+    for ch in line[start..].chars() {
+        match ch {
+            '[' | '(' | '{' => depth += 1,
+            ']' if depth == 0 => break,
+            ']' | ')' | '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                entries.push(std::mem::take(&mut current));
+                continue;
+            }
+            _ => {}
+        }
+        current.push(ch);
+    }
+    entries.push(current);
+    Some(
+        entries
+            .into_iter()
+            .map(|entry| entry.trim().to_string())
+            .filter(|entry| !entry.is_empty())
+            .collect(),
+    )
+}
+
+fn line_value<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    let start = line.find(&format!("{key}="))? + key.len() + 1;
+    let rest = &line[start..];
+    let end = rest
+        .find(|ch: char| ch == ',' || ch.is_whitespace())
+        .unwrap_or(rest.len());
+    Some(&rest[..end])
+}
+
+/// Reads the columns and fragments of the scan stages in an `explain_plan` output.
+pub fn planned_read(plan: &str) -> PlannedRead {
+    let stages = parse_analyzed_plan(plan);
+    let mut read = PlannedRead {
+        index_used: stages
+            .iter()
+            .any(|stage| INDEX_STAGES.contains(&stage.name.as_str())),
+        ..PlannedRead::default()
+    };
+    for line in plan.lines().map(str::trim_start) {
+        if !READ_STAGES.contains(&stage_name(line)) {
+            continue;
+        }
+        for column in bracketed_list(line, "projection").unwrap_or_default() {
+            if !read.columns.contains(&column) {
+                read.columns.push(column);
+            }
+        }
+        if let Some(fragments) = line_value(line, "num_fragments").and_then(parse_count) {
+            read.fragments = Some(read.fragments.unwrap_or(0).saturating_add(fragments));
+        }
+    }
+    read
+}
+
+/// Bytes one value of `data_type` takes on disk, roughly.
+fn column_width(data_type: &DataType) -> u64 {
+    match data_type {
+        DataType::Boolean => 1,
+        DataType::FixedSizeBinary(size) => (*size).max(0) as u64,
+        DataType::FixedSizeList(item, size) => {
+            column_width(item.data_type()).saturating_mul((*size).max(0) as u64)
+        }
+        DataType::Struct(fields) => fields
+            .iter()
+            .map(|field| column_width(field.data_type()))
+            .sum(),
+        other => other
+            .primitive_width()
+            .map(|width| width as u64)
+            .unwrap_or(VARIABLE_WIDTH_BYTES),
+    }
+}
+
+/// Estimates what a query reads from the table size and its plan. Bytes are split
+/// across columns by their value width, so wide vector columns weigh more than ids.
+/// `row_cap` is the offset plus limit of an unfiltered scan, which stops early.
+pub fn estimate_scan(
+    schema: &Schema,
+    footprint: TableFootprint,
+    read: &PlannedRead,
+    row_cap: Option<u64>,
+) -> ScanEstimate {
+    let widths = schema
+        .fields()
+        .iter()
+        .map(|field| (field.name().as_str(), column_width(field.data_type())))
+        .collect::<Vec<_>>();
+    let total_width = widths.iter().map(|(_, width)| width).sum::<u64>().max(1);
+    let read_width = if read.columns.is_empty() {
+        total_width
+    } else {
+        widths
+            .iter()
+            .filter(|(name, _)| {
+                read.columns
+                    .iter()
+                    .any(|column| column.split('.').next() == Some(*name))
+            })
+            .map(|(_, width)| width)
+            .sum()
+    };
+
+    let fragments = read.fragments.map_or(footprint.fragments, |fragments| {
+        fragments.min(footprint.fragments)
+    });
+    let mut rows = if footprint.fragments == 0 {
+        footprint.rows
+    } else {
+        (footprint.rows as u128 * fragments as u128 / footprint.fragments as u128) as u64
+    };
+    let mut fragments = fragments;
+    if let Some(cap) = row_cap.filter(|cap| *cap < rows) {
+        let rows_per_fragment = footprint.rows.div_ceil(footprint.fragments.max(1)).max(1);
+        rows = cap;
+        fragments = fragments.min(cap.div_ceil(rows_per_fragment));
+    }
+    let bytes = if footprint.rows == 0 {
+        0
+    } else {
+        (footprint.bytes as u128 * read_width as u128 * rows as u128
+            / (total_width as u128 * footprint.rows as u128)) as u64
+    };
+
+    ScanEstimate {
+        rows,
+        fragments,
+        bytes,
+        full_scan: !read.index_used && rows == footprint.rows && footprint.rows > 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_schema::Field;
+
+    use super::*;
+
+    const PLAN: &str = "AnalyzeExec verbose=true, metrics=[]
+  ProjectionExec: expr=[id@0 as id], metrics=[output_rows=10, elapsed_compute=12.5µs]
+    Take: columns=\"id, _distance\", metrics=[output_rows=10, elapsed_compute=1.2ms, bytes_read=4096, iops=2]
+      SortExec: TopK(fetch=10), expr=[_distance@1 ASC], metrics=[output_rows=10, elapsed_compute=30µs]
+        ANNSubIndex: name=vector_idx, k=10, deltas=1, metrics=[output_rows=40, elapsed_compute=2ms, index_comparisons=120]
+          ANNIvfPartition: uuid=abc, minimum_nprobes=4, metrics=[output_rows=1, elapsed_compute=100µs]
+";
+
+    #[test]
+    fn parses_analyzed_plan_stages_and_summary() {
+        let stats = summarize_plan(PLAN.to_string(), Duration::from_millis(7));
+
+        assert_eq!(stats.stages.len(), 6);
+        assert_eq!(stats.stages[0].name, "AnalyzeExec");
+        assert_eq!(stats.stages[4].name, "ANNSubIndex");
+        assert_eq!(stats.stages[4].depth, 4);
+        assert_eq!(stats.stages[4].output_rows, Some(40));
+        assert_eq!(stats.stages[2].elapsed_ms, Some(1.2));
+        assert_eq!(
+            stats.stages[3]
+                .metrics
+                .get("elapsed_compute")
+                .map(String::as_str),
+            Some("30µs")
+        );
+        assert!(stats.index_used);
+        assert!(!stats.flat_scan);
+        assert_eq!(stats.bytes_read, Some(4096));
+        assert_eq!(stats.iops, Some(2));
+        assert_eq!(stats.elapsed_ms, 7);
+    }
+
+    #[test]
+    fn reports_flat_scans_without_index_stages() {
+        let plan = "ProjectionExec: expr=[id@0 as id], metrics=[output_rows=5]
+  LanceRead: uri=items.lance, metrics=[output_rows=50, bytes_read=1024, iops=1]
+";
+        let stats = summarize_plan(plan.to_string(), Duration::ZERO);
+
+        assert!(!stats.index_used);
+        assert!(stats.flat_scan);
+        assert_eq!(stats.rows_scanned, Some(50));
+    }
+
+    #[test]
+    fn reads_scan_columns_from_explained_plans() {
+        let plan = "ProjectionExec: expr=[id@0 as id]
+  LanceRead: uri=items.lance, projection=[id, vector], num_fragments=3, range_before=None, full_filter=id < Int32(5), refine_filter=--
+";
+        let read = planned_read(plan);
+        assert_eq!(read.columns, vec!["id", "vector"]);
+        assert_eq!(read.fragments, Some(3));
+        assert!(!read.index_used);
+    }
+
+    #[test]
+    fn estimates_bytes_by_column_width() {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new(
+                "vector",
+                DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Float32, true)), 3),
+                true,
+            ),
+        ]);
+        let footprint = TableFootprint {
+            rows: 100,
+            fragments: 4,
+            bytes: 1_600,
+        };
+        let ids = PlannedRead {
+            columns: vec!["id".to_string()],
+            fragments: None,
+            index_used: false,
+        };
+
+        let full = estimate_scan(&schema, footprint, &ids, None);
+        assert_eq!(full.rows, 100);
+        assert_eq!(full.fragments, 4);
+        assert_eq!(full.bytes, 400);
+        assert!(full.full_scan);
+
+        let limited = estimate_scan(&schema, footprint, &ids, Some(10));
+        assert_eq!(limited.rows, 10);
+        assert_eq!(limited.fragments, 1);
+        assert_eq!(limited.bytes, 40);
+        assert!(!limited.full_scan);
+
+        let indexed = PlannedRead {
+            columns: Vec::new(),
+            fragments: Some(1),
+            index_used: true,
+        };
+        let indexed = estimate_scan(&schema, footprint, &indexed, None);
+        assert_eq!(indexed.rows, 25);
+        assert_eq!(indexed.bytes, 400);
+        assert!(!indexed.full_scan);
+    }
+}
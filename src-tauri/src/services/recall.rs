@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+
+use arrow_array::{Array, FixedSizeListArray, Float32Array, RecordBatch};
+use arrow_schema::DataType;
+
+/// `nprobes` settings compared when a request lists none: powers of two below the
+/// partition count, then the partition count itself, which probes every partition.
+pub fn nprobes_ladder(num_partitions: usize) -> Vec<usize> {
+    let num_partitions = num_partitions.max(1);
+    let mut ladder = Vec::new();
+    let mut nprobes = 1;
+    while nprobes < num_partitions {
+        ladder.push(nprobes);
+        nprobes *= 2;
+    }
+    ladder.push(num_partitions);
+    ladder
+}
+
+/// Offsets of `samples` rows spread evenly over `row_count` rows.
+pub fn sample_offsets(row_count: usize, samples: usize) -> Vec<usize> {
+    let samples = samples.min(row_count);
+    (0..samples)
+        .map(|index| index * row_count / samples)
+        .collect()
+}
+
+/// Query vectors read from `column`, cast to f32. Null vectors are skipped.
+pub fn vectors(batches: &[RecordBatch], column: &str) -> Result<Vec<Vec<f32>>, String> {
+    let mut vectors = Vec::new();
+    for batch in batches {
+        let array = batch
+            .column_by_name(column)
+            .ok_or_else(|| format!("column {column} is missing from the sample"))?;
+        let list = array
+            .as_any()
+            .downcast_ref::<FixedSizeListArray>()
+            .ok_or_else(|| format!("column {column} is not a fixed-size vector column"))?;
+        for index in 0..list.len() {
+            if list.is_null(index) {
+                continue;
+            }
+            let values = arrow_cast::cast(&list.value(index), &DataType::Float32)
+                .map_err(|error| error.to_string())?;
+            let values = values
+                .as_any()
+                .downcast_ref::<Float32Array>()
+                .ok_or_else(|| format!("column {column} does not hold numbers"))?;
+            vectors.push(values.iter().map(|value| value.unwrap_or(0.0)).collect());
+        }
+    }
+    Ok(vectors)
+}
+
+/// How many of the exact neighbours `found` contains.
+pub fn hits(truth: &[u64], found: &[u64]) -> usize {
+    let found = found.iter().collect::<HashSet<_>>();
+    truth.iter().filter(|id| found.contains(id)).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_schema::{Field, Schema};
+
+    use super::*;
+
+    #[test]
+    fn plans_samples_and_probes() {
+        assert_eq!(nprobes_ladder(1), vec![1]);
+        assert_eq!(nprobes_ladder(7), vec![1, 2, 4, 7]);
+        assert_eq!(nprobes_ladder(8), vec![1, 2, 4, 8]);
+        assert_eq!(sample_offsets(10, 4), vec![0, 2, 5, 7]);
+        assert_eq!(sample_offsets(2, 5), vec![0, 1]);
+        assert_eq!(hits(&[1, 2, 3, 4], &[4, 9, 2]), 2);
+    }
+
+    #[test]
+    fn reads_vectors_and_skips_nulls() {
+        let item = Arc::new(Field::new("item", DataType::Float64, true));
+        let values = arrow_array::Float64Array::from(vec![1.0, 2.0, 0.0, 0.0, 3.0, 4.0]);
+        let list = FixedSizeListArray::try_new(
+            item.clone(),
+            2,
+            Arc::new(values),
+            Some(vec![true, false, true].into()),
+        )
+        .expect("list");
+        let schema = Schema::new(vec![Field::new(
+            "vector",
+            DataType::FixedSizeList(item, 2),
+            true,
+        )]);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(list)]).expect("batch");
+        assert_eq!(
+            vectors(&[batch], "vector").expect("vectors"),
+            vec![vec![1.0, 2.0], vec![3.0, 4.0]]
+        );
+    }
+}
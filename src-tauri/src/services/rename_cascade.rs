@@ -0,0 +1,476 @@
+use log::warn;
+
+use crate::ipc::v1::{
+    ColumnAlterationInput, EncryptedColumnV1, ExportTemplateV1, MaskingRuleV1, ProjectionPresetV1,
+    SavedArtifactKindV1, UpdatedArtifactV1,
+};
+use crate::services::encryption;
+use crate::state::AppState;
+
+/// A top-level column renamed by `alter_columns_v1`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnRename {
+    pub from: String,
+    pub to: String,
+}
+
+/// Renames of top-level columns in `inputs`. Saved settings only name top-level
+/// columns, so renamed struct fields are left out.
+pub fn renames(inputs: &[ColumnAlterationInput]) -> Vec<ColumnRename> {
+    inputs
+        .iter()
+        .filter_map(|input| {
+            let from = input.path.trim();
+            let to = input
+                .rename
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())?;
+            (!from.contains('.') && from != to).then(|| ColumnRename {
+                from: from.to_string(),
+                to: to.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn renamed<'a>(column: &str, renames: &'a [ColumnRename]) -> Option<&'a str> {
+    renames
+        .iter()
+        .find(|rename| rename.from == column)
+        .map(|rename| rename.to.as_str())
+}
+
+/// `columns` with the renamed ones replaced, or `None` when none was renamed.
+pub fn rename_columns(columns: &[String], renames: &[ColumnRename]) -> Option<Vec<String>> {
+    if !columns
+        .iter()
+        .any(|column| renamed(column, renames).is_some())
+    {
+        return None;
+    }
+    Some(
+        columns
+            .iter()
+            .map(|column| renamed(column, renames).unwrap_or(column).to_string())
+            .collect(),
+    )
+}
+
+fn is_bare_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_alphabetic() || first == '_')
+        && chars.all(|next| next.is_alphanumeric() || next == '_')
+}
+
+/// `filter` with references to renamed columns replaced, or `None` when it has
+/// none. Tokenizes like `completion`: string literals are skipped, backtick-quoted
+/// names match exactly and bare names ignoring case. Names after a `.` are struct
+/// fields and names before a `(` are functions, so neither is replaced.
+pub fn rename_in_filter(filter: &str, renames: &[ColumnRename]) -> Option<String> {
+    let mut output = String::with_capacity(filter.len());
+    let mut changed = false;
+    let mut previous = None;
+    let mut chars = filter.chars().peekable();
+    while let Some(character) = chars.next() {
+        match character {
+            '\'' => {
+                output.push(character);
+                for next in chars.by_ref() {
+                    output.push(next);
+                    if next == '\'' {
+                        break;
+                    }
+                }
+            }
+            '`' => {
+                let mut name = String::new();
+                let mut closed = false;
+                for next in chars.by_ref() {
+                    if next == '`' {
+                        closed = true;
+                        break;
+                    }
+                    name.push(next);
+                }
+                let rename = if closed && previous != Some('.') {
+                    renamed(&name, renames)
+                } else {
+                    None
+                };
+                output.push('`');
+                match rename {
+                    Some(to) => {
+                        changed = true;
+                        output.push_str(to);
+                    }
+                    None => output.push_str(&name),
+                }
+                if closed {
+                    output.push('`');
+                }
+            }
+            character if character.is_alphanumeric() || character == '_' => {
+                let mut name = character.to_string();
+                while let Some(next) = chars.next_if(|next| next.is_alphanumeric() || *next == '_')
+                {
+                    name.push(next);
+                }
+                let is_call = chars.clone().find(|next| !next.is_whitespace()) == Some('(');
+                // Words starting with a digit are numbers such as `2e5`.
+                let rename = if character.is_ascii_digit() || previous == Some('.') || is_call {
+                    None
+                } else {
+                    renames
+                        .iter()
+                        .find(|rename| rename.from.eq_ignore_ascii_case(&name))
+                };
+                match rename {
+                    Some(rename) if is_bare_identifier(&rename.to) => {
+                        changed = true;
+                        output.push_str(&rename.to);
+                    }
+                    Some(rename) => {
+                        changed = true;
+                        output.push('`');
+                        output.push_str(&rename.to);
+                        output.push('`');
+                    }
+                    None => output.push_str(&name),
+                }
+            }
+            character => output.push(character),
+        }
+        if !character.is_whitespace() {
+            previous = Some(character);
+        }
+    }
+    changed.then_some(output)
+}
+
+fn artifact(kind: SavedArtifactKindV1, name: &str) -> UpdatedArtifactV1 {
+    UpdatedArtifactV1 {
+        kind,
+        name: name.to_string(),
+    }
+}
+
+fn search_defaults(
+    state: &AppState,
+    connection_uri: &str,
+    table_name: &str,
+    renames: &[ColumnRename],
+) -> Result<Vec<UpdatedArtifactV1>, String> {
+    let mut store = state
+        .search_defaults
+        .lock()
+        .map_err(|_| "failed to lock search defaults".to_string())?;
+    let Some(mut defaults) = store.get(connection_uri, table_name) else {
+        return Ok(Vec::new());
+    };
+    let vector_column = defaults
+        .vector_column
+        .as_deref()
+        .and_then(|column| renamed(column, renames));
+    let fts_columns = rename_columns(&defaults.fts_columns, renames);
+    if vector_column.is_none() && fts_columns.is_none() {
+        return Ok(Vec::new());
+    }
+    if let Some(column) = vector_column {
+        defaults.vector_column = Some(column.to_string());
+    }
+    if let Some(columns) = fts_columns {
+        defaults.fts_columns = columns;
+    }
+    store.set(connection_uri, table_name, Some(defaults))?;
+    Ok(vec![artifact(
+        SavedArtifactKindV1::SearchDefaults,
+        table_name,
+    )])
+}
+
+fn retention_policy(
+    state: &AppState,
+    connection_uri: &str,
+    table_name: &str,
+    renames: &[ColumnRename],
+) -> Result<Vec<UpdatedArtifactV1>, String> {
+    let mut store = state
+        .retention
+        .lock()
+        .map_err(|_| "failed to lock retention policies".to_string())?;
+    let Some(mut policy) = store.get(connection_uri, table_name) else {
+        return Ok(Vec::new());
+    };
+    let Some(column) = renamed(&policy.timestamp_column, renames) else {
+        return Ok(Vec::new());
+    };
+    policy.timestamp_column = column.to_string();
+    store.set(connection_uri, table_name, Some(policy))?;
+    Ok(vec![artifact(
+        SavedArtifactKindV1::RetentionPolicy,
+        table_name,
+    )])
+}
+
+/// Rules of the table follow the column. A connection-wide rule keeps covering the
+/// other tables, and the renamed column gets a rule of its own so it stays masked.
+fn masking_rules(
+    state: &AppState,
+    connection_uri: &str,
+    table_name: &str,
+    renames: &[ColumnRename],
+) -> Result<Vec<UpdatedArtifactV1>, String> {
+    let mut store = state
+        .masking
+        .lock()
+        .map_err(|_| "failed to lock masking rules".to_string())?;
+    let mut updated = Vec::new();
+    let mut rules = Vec::new();
+    for rule in store.list(connection_uri) {
+        let Some(column) = renamed(&rule.column, renames) else {
+            rules.push(rule);
+            continue;
+        };
+        match rule.table_name.as_deref() {
+            Some(name) if name != table_name => {
+                rules.push(rule);
+                continue;
+            }
+            Some(_) => rules.push(MaskingRuleV1 {
+                column: column.to_string(),
+                ..rule
+            }),
+            None => {
+                rules.push(rule.clone());
+                rules.push(MaskingRuleV1 {
+                    table_name: Some(table_name.to_string()),
+                    column: column.to_string(),
+                    ..rule
+                });
+            }
+        }
+        updated.push(artifact(SavedArtifactKindV1::MaskingRule, column));
+    }
+    if !updated.is_empty() {
+        store.set(connection_uri, rules)?;
+    }
+    Ok(updated)
+}
+
+/// Handled like masking rules, so the renamed column is still decrypted on read
+/// and encrypted on write. The entry keeps the column id its values were encrypted
+/// under.
+fn encrypted_columns(
+    state: &AppState,
+    connection_uri: &str,
+    table_name: &str,
+    renames: &[ColumnRename],
+) -> Result<Vec<UpdatedArtifactV1>, String> {
+    let mut store = state
+        .encryption
+        .lock()
+        .map_err(|_| "failed to lock encrypted columns".to_string())?;
+    let mut updated = Vec::new();
+    let mut columns = Vec::new();
+    for encrypted in store.list(connection_uri) {
+        let Some(column) = renamed(&encrypted.column, renames) else {
+            columns.push(encrypted);
+            continue;
+        };
+        match encrypted.table_name.as_deref() {
+            Some(name) if name != table_name => {
+                columns.push(encrypted);
+                continue;
+            }
+            Some(_) => columns.push(EncryptedColumnV1 {
+                column: column.to_string(),
+                column_id: Some(encryption::binding(&encrypted).to_string()),
+                ..encrypted
+            }),
+            None => {
+                columns.push(encrypted.clone());
+                columns.push(EncryptedColumnV1 {
+                    table_name: Some(table_name.to_string()),
+                    column: column.to_string(),
+                    column_id: Some(encryption::binding(&encrypted).to_string()),
+                    ..encrypted
+                });
+            }
+        }
+        updated.push(artifact(SavedArtifactKindV1::EncryptedColumn, column));
+    }
+    if !updated.is_empty() {
+        store.set(connection_uri, columns)?;
+    }
+    Ok(updated)
+}
+
+fn recent_filters(
+    state: &AppState,
+    connection_uri: &str,
+    table_name: &str,
+    renames: &[ColumnRename],
+) -> Result<Vec<UpdatedArtifactV1>, String> {
+    let mut store = state
+        .filter_history
+        .lock()
+        .map_err(|_| "failed to lock filter history".to_string())?;
+    let filters = store.rewrite(connection_uri, table_name, |filter| {
+        rename_in_filter(filter, renames)
+    })?;
+    Ok(filters
+        .iter()
+        .map(|filter| artifact(SavedArtifactKindV1::RecentFilter, filter))
+        .collect())
+}
+
+/// Templates are not tied to a table, so every template naming a renamed column is
+/// taken to be one for this table.
+fn export_templates(
+    state: &AppState,
+    renames: &[ColumnRename],
+) -> Result<Vec<UpdatedArtifactV1>, String> {
+    let mut store = state
+        .export_templates
+        .lock()
+        .map_err(|_| "failed to lock export templates".to_string())?;
+    let names = store.rewrite(|template| {
+        let projection = template
+            .projection
+            .as_deref()
+            .and_then(|columns| rename_columns(columns, renames));
+        let filter = template
+            .filter
+            .as_deref()
+            .and_then(|filter| rename_in_filter(filter, renames));
+        if projection.is_none() && filter.is_none() {
+            return None;
+        }
+        Some(ExportTemplateV1 {
+            projection: projection.or_else(|| template.projection.clone()),
+            filter: filter.or_else(|| template.filter.clone()),
+            ..template.clone()
+        })
+    })?;
+    Ok(names
+        .iter()
+        .map(|name| artifact(SavedArtifactKindV1::ExportTemplate, name))
+        .collect())
+}
+
+fn projection_presets(
+    state: &AppState,
+    connection_uri: &str,
+    table_name: &str,
+    renames: &[ColumnRename],
+) -> Result<Vec<UpdatedArtifactV1>, String> {
+    let mut store = state
+        .projection_presets
+        .lock()
+        .map_err(|_| "failed to lock projection presets".to_string())?;
+    let names = store.rewrite(connection_uri, table_name, |preset| {
+        rename_columns(&preset.columns, renames).map(|columns| ProjectionPresetV1 {
+            columns,
+            ..preset.clone()
+        })
+    })?;
+    Ok(names
+        .iter()
+        .map(|name| artifact(SavedArtifactKindV1::ProjectionPreset, name))
+        .collect())
+}
+
+/// Rewrites the settings saved for a table that name its renamed columns and
+/// returns what changed. The rename is already committed when this runs, so a
+/// setting that fails to update is logged and the rest still are.
+pub fn cascade(
+    state: &AppState,
+    connection_uri: &str,
+    table_name: &str,
+    renames: &[ColumnRename],
+) -> Vec<UpdatedArtifactV1> {
+    if renames.is_empty() {
+        return Vec::new();
+    }
+    let results = [
+        (
+            "search defaults",
+            search_defaults(state, connection_uri, table_name, renames),
+        ),
+        (
+            "retention policy",
+            retention_policy(state, connection_uri, table_name, renames),
+        ),
+        (
+            "masking rules",
+            masking_rules(state, connection_uri, table_name, renames),
+        ),
+        (
+            "encrypted columns",
+            encrypted_columns(state, connection_uri, table_name, renames),
+        ),
+        (
+            "recent filters",
+            recent_filters(state, connection_uri, table_name, renames),
+        ),
+        ("export templates", export_templates(state, renames)),
+        (
+            "projection presets",
+            projection_presets(state, connection_uri, table_name, renames),
+        ),
+    ];
+    let mut updated = Vec::new();
+    for (artifact, result) in results {
+        match result {
+            Ok(artifacts) => updated.extend(artifacts),
+            Err(error) => warn!(
+                "column rename not applied to {} table=\"{}\" error={}",
+                artifact, table_name, error
+            ),
+        }
+    }
+    updated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rename(from: &str, to: &str) -> Vec<ColumnRename> {
+        vec![ColumnRename {
+            from: from.to_string(),
+            to: to.to_string(),
+        }]
+    }
+
+    #[test]
+    fn renames_identifiers_outside_literals() {
+        let renames = rename("text", "label");
+        assert_eq!(
+            rename_in_filter("TEXT = 'text' AND `text` LIKE 'a%'", &renames).as_deref(),
+            Some("label = 'text' AND `label` LIKE 'a%'")
+        );
+        assert_eq!(
+            rename_in_filter("meta.text = 1 OR text(id) > 2", &renames),
+            None
+        );
+        assert_eq!(
+            rename_in_filter("text IS NULL", &rename("text", "my label")).as_deref(),
+            Some("`my label` IS NULL")
+        );
+        assert_eq!(rename_in_filter("texts = 1", &renames), None);
+
+        let columns = vec!["id".to_string(), "text".to_string()];
+        assert_eq!(
+            rename_columns(&columns, &renames),
+            Some(vec!["id".to_string(), "label".to_string()])
+        );
+        assert_eq!(
+            rename_columns(&columns, &rename("vector", "embedding")),
+            None
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use arrow_array::{Array, ArrayRef, BinaryArray, RecordBatch, StringArray, UInt32Array};
+use arrow_schema::DataType;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+pub const SAMPLE_FORMAT_VERSION: u32 = 1;
+pub const MAX_SAMPLE_ROWS: usize = 10_000;
+pub const MANIFEST_FILE: &str = "manifest.json";
+pub const SCHEMA_FILE: &str = "schema.json";
+/// Sampled rows as an Arrow IPC stream.
+pub const ROWS_FILE: &str = "sample.arrows";
+
+const CJK_START: u32 = 0x4E00;
+const CJK_END: u32 = 0x9FFF;
+
+/// SplitMix64 generator. Samples are scrambled from a seed recorded in the
+/// manifest, so an export can be repeated exactly.
+pub struct Scrambler {
+    state: u64,
+}
+
+impl Scrambler {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        value ^ (value >> 31)
+    }
+
+    fn below(&mut self, bound: u32) -> u32 {
+        (self.next() % u64::from(bound)) as u32
+    }
+
+    fn pick(&mut self, first: char, count: u32) -> char {
+        char::from_u32(first as u32 + self.below(count)).unwrap_or(first)
+    }
+
+    /// Replaces letters and digits with random ones of the same kind. Whitespace and
+    /// punctuation are kept, so lengths and separators survive.
+    fn scramble_text(&mut self, text: &str) -> String {
+        text.chars()
+            .map(|character| match character {
+                'a'..='z' => self.pick('a', 26),
+                'A'..='Z' => self.pick('A', 26),
+                '0'..='9' => self.pick('0', 10),
+                character if (CJK_START..=CJK_END).contains(&(character as u32)) => {
+                    char::from_u32(CJK_START + self.below(CJK_END - CJK_START + 1))
+                        .unwrap_or(character)
+                }
+                character if character.is_alphanumeric() => self.pick('a', 26),
+                character => character,
+            })
+            .collect()
+    }
+
+    fn permutation(&mut self, len: usize) -> UInt32Array {
+        let mut indices = (0..len as u32).collect::<Vec<_>>();
+        for index in (1..len).rev() {
+            let other = self.below(index as u32 + 1) as usize;
+            indices.swap(index, other);
+        }
+        UInt32Array::from(indices)
+    }
+}
+
+fn is_text(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => true,
+        DataType::Dictionary(_, value) => is_text(value),
+        _ => false,
+    }
+}
+
+fn is_binary(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Binary | DataType::LargeBinary | DataType::BinaryView
+    )
+}
+
+fn scramble_strings(array: &ArrayRef, scrambler: &mut Scrambler) -> Result<ArrayRef, String> {
+    let values = arrow_cast::cast(array, &DataType::Utf8).map_err(|error| error.to_string())?;
+    let values = values
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .ok_or_else(|| "text column does not hold strings".to_string())?;
+    let scrambled = values
+        .iter()
+        .map(|value| value.map(|text| scrambler.scramble_text(text)))
+        .collect::<StringArray>();
+    arrow_cast::cast(&scrambled, array.data_type()).map_err(|error| error.to_string())
+}
+
+fn scramble_bytes(array: &ArrayRef, scrambler: &mut Scrambler) -> Result<ArrayRef, String> {
+    let values = arrow_cast::cast(array, &DataType::Binary).map_err(|error| error.to_string())?;
+    let values = values
+        .as_any()
+        .downcast_ref::<BinaryArray>()
+        .ok_or_else(|| "binary column does not hold bytes".to_string())?;
+    let scrambled = values
+        .iter()
+        .map(|value| {
+            value.map(|bytes| {
+                bytes
+                    .iter()
+                    .map(|_| scrambler.below(256) as u8)
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect::<BinaryArray>();
+    arrow_cast::cast(&scrambled, array.data_type()).map_err(|error| error.to_string())
+}
+
+/// Anonymizes `batch` while keeping its schema and value shapes. Text keeps its
+/// length with letters and digits replaced, binary values become random bytes of
+/// the same length, and every other column is shuffled on its own, so values no
+/// longer line up into real rows. Strings nested in lists or structs are only
+/// shuffled.
+pub fn scramble_batch(
+    batch: &RecordBatch,
+    scrambler: &mut Scrambler,
+) -> Result<RecordBatch, String> {
+    let columns = batch
+        .columns()
+        .iter()
+        .map(|array| {
+            if is_text(array.data_type()) {
+                scramble_strings(array, scrambler)
+            } else if is_binary(array.data_type()) {
+                scramble_bytes(array, scrambler)
+            } else {
+                let indices = scrambler.permutation(array.len());
+                arrow_select::take::take(array.as_ref(), &indices, None)
+                    .map_err(|error| error.to_string())
+            }
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    RecordBatch::try_new(batch.schema(), columns).map_err(|error| error.to_string())
+}
+
+/// Writes `entries` as deflated files of a new zip archive at `path`.
+pub fn write_zip(path: &Path, entries: &[(&str, &[u8])]) -> Result<(), String> {
+    let file = File::create(path).map_err(|error| error.to_string())?;
+    let mut zip = ZipWriter::new(BufWriter::new(file));
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    for (name, bytes) in entries {
+        zip.start_file(*name, options)
+            .map_err(|error| error.to_string())?;
+        zip.write_all(bytes).map_err(|error| error.to_string())?;
+    }
+    zip.finish()
+        .map_err(|error| error.to_string())?
+        .flush()
+        .map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::Int32Array;
+    use arrow_schema::{Field, Schema};
+
+    use super::*;
+
+    fn sample_batch() -> RecordBatch {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("text", DataType::Utf8, true),
+        ]));
+        let ids: ArrayRef = Arc::new(Int32Array::from((0..20).collect::<Vec<_>>()));
+        let texts: ArrayRef = Arc::new(StringArray::from(
+            (0..20)
+                .map(|index| (index % 5 != 0).then(|| format!("user-{index} 向量")))
+                .collect::<Vec<_>>(),
+        ));
+        RecordBatch::try_new(schema, vec![ids, texts]).expect("batch")
+    }
+
+    #[test]
+    fn scrambles_text_and_shuffles_values_repeatably() {
+        let batch = sample_batch();
+        let scrambled = scramble_batch(&batch, &mut Scrambler::new(7)).expect("scrambled");
+        assert_eq!(scrambled.schema(), batch.schema());
+        assert_eq!(scrambled.num_rows(), 20);
+
+        let ids = scrambled
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .expect("ids");
+        let mut sorted = ids.values().to_vec();
+        assert_ne!(sorted, (0..20).collect::<Vec<_>>());
+        sorted.sort();
+        assert_eq!(sorted, (0..20).collect::<Vec<_>>());
+
+        let before = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .expect("text");
+        let after = scrambled
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .expect("text");
+        for (before, after) in before.iter().zip(after.iter()) {
+            match (before, after) {
+                (Some(before), Some(after)) => {
+                    assert_ne!(before, after);
+                    assert_eq!(before.chars().count(), after.chars().count());
+                    assert_eq!(after.chars().nth(4), Some('-'));
+                }
+                (before, after) => assert_eq!(before, after),
+            }
+        }
+
+        let again = scramble_batch(&batch, &mut Scrambler::new(7)).expect("scrambled");
+        assert_eq!(again, scrambled);
+    }
+}
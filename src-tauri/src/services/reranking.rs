@@ -0,0 +1,470 @@
+use std::cmp::Ordering;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::ipc::v1::{CrossEncoderRerankerV1, RerankerV1};
+
+/// Column holding the score a reranker gave each row.
+pub const RERANK_SCORE_COLUMN: &str = "_rerank_score";
+
+/// Fewest candidates fetched for reranking, so the first pages have enough to reorder.
+pub const MIN_CANDIDATES: usize = 100;
+/// Most candidates fetched for reranking; pages beyond them are not reachable.
+pub const MAX_CANDIDATES: usize = 1_000;
+
+const DEFAULT_CROSS_ENCODER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Number of candidates to rerank for the page at `offset`, plus one row to tell
+/// whether another page follows.
+pub fn candidate_limit(offset: usize, limit: usize) -> Result<usize, String> {
+    let needed = offset.saturating_add(limit).saturating_add(1);
+    if needed > MAX_CANDIDATES + 1 {
+        return Err(format!(
+            "reranked searches page through the first {MAX_CANDIDATES} candidates only"
+        ));
+    }
+    Ok(needed.max(MIN_CANDIDATES).min(MAX_CANDIDATES))
+}
+
+/// Checks a reranker before any search runs. RRF fuses two rankings, so only hybrid
+/// searches accept it.
+pub fn validate(reranker: &RerankerV1, hybrid: bool) -> Result<(), String> {
+    match reranker {
+        RerankerV1::Rrf(config) => {
+            if !hybrid {
+                return Err(
+                    "rrf reranking needs both vector and full-text results; use combined_search_v1"
+                        .to_string(),
+                );
+            }
+            if config.k.is_some_and(|k| !(k.is_finite() && k > 0.0)) {
+                return Err("rrf k must be a positive number".to_string());
+            }
+        }
+        RerankerV1::CrossEncoder(config) => {
+            let endpoint = url::Url::parse(config.endpoint.trim())
+                .map_err(|error| format!("invalid cross-encoder endpoint: {error}"))?;
+            if !matches!(endpoint.scheme(), "http" | "https") {
+                return Err("cross-encoder endpoint must be an http or https URL".to_string());
+            }
+            if config.column.trim().is_empty() {
+                return Err("cross-encoder column cannot be empty".to_string());
+            }
+        }
+        RerankerV1::Expression(config) => {
+            ScoreExpression::parse(&config.expression)?;
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Function {
+    Abs,
+    Exp,
+    Ln,
+    Log10,
+    Sqrt,
+}
+
+impl Function {
+    fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "abs" => Some(Function::Abs),
+            "exp" => Some(Function::Exp),
+            "ln" => Some(Function::Ln),
+            "log10" => Some(Function::Log10),
+            "sqrt" => Some(Function::Sqrt),
+            _ => None,
+        }
+    }
+
+    fn apply(self, value: f64) -> f64 {
+        match self {
+            Function::Abs => value.abs(),
+            Function::Exp => value.exp(),
+            Function::Ln => value.ln(),
+            Function::Log10 => value.log10(),
+            Function::Sqrt => value.sqrt(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Number(f64),
+    Column(String),
+    Negate(Box<Node>),
+    Call(Function, Box<Node>),
+    Binary(char, Box<Node>, Box<Node>),
+}
+
+/// A parsed rerank expression: numbers, columns (bare or in backticks), `+ - * /`,
+/// parentheses and `abs`, `exp`, `ln`, `log10`, `sqrt`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreExpression {
+    root: Node,
+}
+
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+    source: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|(_, ch)| ch.is_whitespace()).is_some() {}
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.chars.peek().map(|(_, ch)| *ch)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        match self.peek() {
+            Some(ch) if ch == expected => {
+                self.chars.next();
+                Ok(())
+            }
+            Some(ch) => Err(format!("expected `{expected}` but found `{ch}`")),
+            None => Err(format!(
+                "expected `{expected}` at the end of the expression"
+            )),
+        }
+    }
+
+    fn sum(&mut self) -> Result<Node, String> {
+        let mut node = self.product()?;
+        while let Some(op @ ('+' | '-')) = self.peek() {
+            self.chars.next();
+            node = Node::Binary(op, Box::new(node), Box::new(self.product()?));
+        }
+        Ok(node)
+    }
+
+    fn product(&mut self) -> Result<Node, String> {
+        let mut node = self.unary()?;
+        while let Some(op @ ('*' | '/')) = self.peek() {
+            self.chars.next();
+            node = Node::Binary(op, Box::new(node), Box::new(self.unary()?));
+        }
+        Ok(node)
+    }
+
+    fn unary(&mut self) -> Result<Node, String> {
+        if self.peek() == Some('-') {
+            self.chars.next();
+            return Ok(Node::Negate(Box::new(self.unary()?)));
+        }
+        self.primary()
+    }
+
+    fn take_while(&mut self, start: usize, accept: impl Fn(char) -> bool) -> &'a str {
+        let mut end = start;
+        while let Some((index, ch)) = self.chars.next_if(|(_, ch)| accept(*ch)) {
+            end = index + ch.len_utf8();
+        }
+        &self.source[start..end]
+    }
+
+    fn primary(&mut self) -> Result<Node, String> {
+        self.skip_whitespace();
+        let Some(&(start, next)) = self.chars.peek() else {
+            return Err("expression ended unexpectedly".to_string());
+        };
+        match next {
+            '(' => {
+                self.chars.next();
+                let node = self.sum()?;
+                self.expect(')')?;
+                Ok(node)
+            }
+            '`' => {
+                self.chars.next();
+                let name = self.take_while(start + 1, |ch| ch != '`').to_string();
+                self.expect('`')?;
+                Ok(Node::Column(name))
+            }
+            ch if ch.is_ascii_digit() || ch == '.' => {
+                let text = self.take_while(start, |ch| ch.is_ascii_digit() || ch == '.');
+                text.parse::<f64>()
+                    .map(Node::Number)
+                    .map_err(|_| format!("invalid number `{text}`"))
+            }
+            ch if ch.is_alphabetic() || ch == '_' => {
+                let name =
+                    self.take_while(start, |ch| ch.is_alphanumeric() || ch == '_' || ch == '.');
+                if self.peek() != Some('(') {
+                    return Ok(Node::Column(name.to_string()));
+                }
+                let function =
+                    Function::parse(name).ok_or_else(|| format!("unknown function `{name}`"))?;
+                self.chars.next();
+                let argument = self.sum()?;
+                self.expect(')')?;
+                Ok(Node::Call(function, Box::new(argument)))
+            }
+            ch => Err(format!("unexpected `{ch}` in expression")),
+        }
+    }
+}
+
+fn numeric_value(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(number) => number.as_f64(),
+        Value::Bool(flag) => Some(if *flag { 1.0 } else { 0.0 }),
+        // Large integers may be rendered as strings to keep them exact.
+        Value::String(text) => text.parse().ok(),
+        _ => None,
+    }
+}
+
+fn evaluate(node: &Node, row: &Map<String, Value>) -> Option<f64> {
+    match node {
+        Node::Number(value) => Some(*value),
+        Node::Column(name) => row.get(name).and_then(numeric_value),
+        Node::Negate(inner) => evaluate(inner, row).map(|value| -value),
+        Node::Call(function, argument) => {
+            evaluate(argument, row).map(|value| function.apply(value))
+        }
+        Node::Binary(op, left, right) => {
+            let (left, right) = (evaluate(left, row)?, evaluate(right, row)?);
+            Some(match op {
+                '+' => left + right,
+                '-' => left - right,
+                '*' => left * right,
+                _ => left / right,
+            })
+        }
+    }
+}
+
+fn collect_columns<'a>(node: &'a Node, columns: &mut Vec<&'a str>) {
+    match node {
+        Node::Number(_) => {}
+        Node::Column(name) => columns.push(name),
+        Node::Negate(inner) | Node::Call(_, inner) => collect_columns(inner, columns),
+        Node::Binary(_, left, right) => {
+            collect_columns(left, columns);
+            collect_columns(right, columns);
+        }
+    }
+}
+
+impl ScoreExpression {
+    pub fn parse(source: &str) -> Result<Self, String> {
+        if source.trim().is_empty() {
+            return Err("rerank expression cannot be empty".to_string());
+        }
+        let mut parser = Parser {
+            chars: source.char_indices().peekable(),
+            source,
+        };
+        let root = parser.sum()?;
+        if let Some(ch) = parser.peek() {
+            return Err(format!("unexpected `{ch}` in expression"));
+        }
+        Ok(Self { root })
+    }
+
+    /// Columns the expression reads.
+    pub fn columns(&self) -> Vec<&str> {
+        let mut columns = Vec::new();
+        collect_columns(&self.root, &mut columns);
+        columns
+    }
+
+    /// Scores a row; missing, null or non-numeric inputs and non-finite results give `None`.
+    pub fn score(&self, row: &Value) -> Option<f64> {
+        let object = row.as_object()?;
+        evaluate(&self.root, object).filter(|value| value.is_finite())
+    }
+}
+
+/// Stores each score in `_rerank_score` and orders the rows by it, highest first.
+/// Rows without a score keep their relative order after the scored ones.
+pub fn rank_rows(rows: Vec<Value>, scores: Vec<Option<f64>>) -> Vec<Value> {
+    let mut scored = rows.into_iter().zip(scores).collect::<Vec<_>>();
+    scored.sort_by(|(_, left), (_, right)| match (left, right) {
+        (Some(left), Some(right)) => right.partial_cmp(left).unwrap_or(Ordering::Equal),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    });
+    scored
+        .into_iter()
+        .map(|(mut row, score)| {
+            if let Some(object) = row.as_object_mut() {
+                let value = score
+                    .and_then(serde_json::Number::from_f64)
+                    .map(Value::Number)
+                    .unwrap_or(Value::Null);
+                object.insert(RERANK_SCORE_COLUMN.to_string(), value);
+            }
+            row
+        })
+        .collect()
+}
+
+/// The page of reranked rows at `offset`, and whether more rows follow it.
+pub fn page(rows: Vec<Value>, offset: usize, limit: usize) -> (Vec<Value>, bool) {
+    let has_more = rows.len() > offset.saturating_add(limit);
+    let rows = rows.into_iter().skip(offset).take(limit).collect();
+    (rows, has_more)
+}
+
+#[derive(Serialize)]
+struct CrossEncoderRequest<'a> {
+    query: &'a str,
+    documents: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct CrossEncoderResponse {
+    scores: Vec<f64>,
+}
+
+/// Scores the `column` text of every row against `query` with the cross-encoder
+/// endpoint. Rows without text are sent as empty documents.
+pub async fn cross_encoder_scores(
+    reranker: &CrossEncoderRerankerV1,
+    query: &str,
+    rows: &[Value],
+) -> Result<Vec<Option<f64>>, String> {
+    let documents = rows
+        .iter()
+        .map(|row| match row.get(reranker.column.trim()) {
+            Some(Value::String(text)) => text.clone(),
+            Some(Value::Null) | None => String::new(),
+            Some(other) => other.to_string(),
+        })
+        .collect::<Vec<_>>();
+    let timeout = reranker
+        .timeout_ms
+        .map(Duration::from_millis)
+        .unwrap_or(DEFAULT_CROSS_ENCODER_TIMEOUT);
+    let client = reqwest::Client::builder()
+        .timeout(timeout)
+        .build()
+        .map_err(|error| error.to_string())?;
+    let response = client
+        .post(reranker.endpoint.trim())
+        .json(&CrossEncoderRequest { query, documents })
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|error| format!("cross-encoder request failed: {error}"))?;
+    let body = response
+        .json::<CrossEncoderResponse>()
+        .await
+        .map_err(|error| format!("invalid cross-encoder response: {error}"))?;
+    if body.scores.len() != rows.len() {
+        return Err(format!(
+            "cross-encoder returned {} scores for {} documents",
+            body.scores.len(),
+            rows.len()
+        ));
+    }
+    Ok(body
+        .scores
+        .into_iter()
+        .map(|score| Some(score).filter(|score| score.is_finite()))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::ipc::v1::{ExpressionRerankerV1, RrfRerankerV1};
+
+    use super::*;
+
+    #[test]
+    fn evaluates_score_expressions() {
+        let expression =
+            ScoreExpression::parse("_score * 0.5 + ln(1 + `up votes`) - -2").expect("parse");
+        assert_eq!(expression.columns(), vec!["_score", "up votes"]);
+        let score = expression
+            .score(&json!({"_score": 4.0, "up votes": "0"}))
+            .expect("score");
+        assert!((score - 4.0).abs() < f64::EPSILON);
+
+        assert_eq!(
+            expression.score(&json!({"_score": null, "up votes": 1})),
+            None
+        );
+        assert_eq!(
+            ScoreExpression::parse("1 / rating")
+                .expect("parse")
+                .score(&json!({"rating": 0})),
+            None
+        );
+        assert!(ScoreExpression::parse("").is_err());
+        assert!(ScoreExpression::parse("(_score").is_err());
+        assert!(ScoreExpression::parse("pow(_score)").is_err());
+        assert!(ScoreExpression::parse("_score _distance").is_err());
+    }
+
+    #[test]
+    fn ranks_and_pages_rows() {
+        let rows = vec![json!({"id": 1}), json!({"id": 2}), json!({"id": 3})];
+        let ranked = rank_rows(rows, vec![Some(0.2), None, Some(0.9)]);
+        let ids = ranked
+            .iter()
+            .map(|row| row["id"].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec![json!(3), json!(1), json!(2)]);
+        assert_eq!(ranked[2][RERANK_SCORE_COLUMN], Value::Null);
+
+        let (first, has_more) = page(ranked.clone(), 0, 2);
+        assert_eq!(first.len(), 2);
+        assert!(has_more);
+        let (last, has_more) = page(ranked, 2, 2);
+        assert_eq!(last.len(), 1);
+        assert!(!has_more);
+    }
+
+    #[test]
+    fn validates_rerankers() {
+        let rrf = RerankerV1::Rrf(RrfRerankerV1 { k: Some(30.0) });
+        assert!(validate(&rrf, true).is_ok());
+        assert!(validate(&rrf, false).is_err());
+        assert!(validate(&RerankerV1::Rrf(RrfRerankerV1 { k: Some(0.0) }), true).is_err());
+
+        let cross_encoder = |endpoint: &str, column: &str| {
+            RerankerV1::CrossEncoder(CrossEncoderRerankerV1 {
+                endpoint: endpoint.to_string(),
+                column: column.to_string(),
+                timeout_ms: None,
+            })
+        };
+        assert!(validate(
+            &cross_encoder("http://localhost:8080/rerank", "text"),
+            false
+        )
+        .is_ok());
+        assert!(validate(&cross_encoder("file:///tmp/model", "text"), false).is_err());
+        assert!(validate(&cross_encoder("not a url", "text"), false).is_err());
+        assert!(validate(&cross_encoder("https://example.com", " "), false).is_err());
+
+        let expression = |expression: &str| {
+            RerankerV1::Expression(ExpressionRerankerV1 {
+                expression: expression.to_string(),
+            })
+        };
+        assert!(validate(&expression("_score + 1"), false).is_ok());
+        assert!(validate(&expression("_score +"), false).is_err());
+    }
+
+    #[test]
+    fn limits_candidates() {
+        assert_eq!(candidate_limit(0, 10), Ok(MIN_CANDIDATES));
+        assert_eq!(candidate_limit(400, 100), Ok(501));
+        assert_eq!(candidate_limit(900, 100), Ok(MAX_CANDIDATES));
+        assert!(candidate_limit(950, 100).is_err());
+    }
+}
@@ -0,0 +1,189 @@
+use std::path::PathBuf;
+
+use arrow_schema::{DataType, Schema};
+use chrono::{DateTime, Duration, Utc};
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::ipc::v1::RetentionPolicyV1;
+use crate::services::store;
+
+pub const RETENTION_FILE: &str = "retention.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RetentionEntry {
+    connection_uri: String,
+    table_name: String,
+    #[serde(flatten)]
+    policy: RetentionPolicyV1,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RetentionFile {
+    #[serde(default)]
+    policies: Vec<RetentionEntry>,
+}
+
+/// Retention policies keyed by connection URI and table name, persisted to
+/// `retention.json` in the app data directory when one is configured.
+#[derive(Default)]
+pub struct RetentionStore {
+    path: Option<PathBuf>,
+    entries: Vec<RetentionEntry>,
+}
+
+impl RetentionStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load(path: PathBuf) -> Self {
+        let entries = match store::load_json::<RetentionFile>(&path) {
+            Ok(file) => file.policies,
+            Err(error) => {
+                warn!(
+                    "failed to load retention policies path={:?} error={}",
+                    path, error
+                );
+                Vec::new()
+            }
+        };
+        Self {
+            path: Some(path),
+            entries,
+        }
+    }
+
+    pub fn get(&self, connection_uri: &str, table_name: &str) -> Option<RetentionPolicyV1> {
+        self.entries
+            .iter()
+            .find(|entry| entry.connection_uri == connection_uri && entry.table_name == table_name)
+            .map(|entry| entry.policy.clone())
+    }
+
+    /// Replaces the policy of a table; `None` removes it.
+    pub fn set(
+        &mut self,
+        connection_uri: &str,
+        table_name: &str,
+        policy: Option<RetentionPolicyV1>,
+    ) -> Result<(), String> {
+        self.entries.retain(|entry| {
+            entry.connection_uri != connection_uri || entry.table_name != table_name
+        });
+        if let Some(policy) = policy {
+            self.entries.push(RetentionEntry {
+                connection_uri: connection_uri.to_string(),
+                table_name: table_name.to_string(),
+                policy,
+            });
+        }
+        self.persist()
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        let Some(path) = self.path.as_ref() else {
+            return Ok(());
+        };
+        store::save_json(
+            path,
+            &RetentionFile {
+                policies: self.entries.clone(),
+            },
+        )
+    }
+}
+
+/// Checks that the policy targets an existing timestamp or date column.
+pub fn validate_policy(schema: &Schema, policy: &RetentionPolicyV1) -> Result<(), String> {
+    if policy.max_age_seconds == 0 {
+        return Err("retention maxAgeSeconds must be greater than 0".to_string());
+    }
+    if policy.timestamp_column.contains('`') {
+        return Err("retention column name cannot contain backticks".to_string());
+    }
+    let field = schema
+        .field_with_name(&policy.timestamp_column)
+        .map_err(|_| format!("column not found: {}", policy.timestamp_column))?;
+    match field.data_type() {
+        DataType::Timestamp(_, _) | DataType::Date32 | DataType::Date64 => Ok(()),
+        other => Err(format!(
+            "retention column {} must be a timestamp or date column, got {other}",
+            policy.timestamp_column
+        )),
+    }
+}
+
+/// Rows older than the returned instant are expired.
+pub fn cutoff(now: DateTime<Utc>, policy: &RetentionPolicyV1) -> DateTime<Utc> {
+    i64::try_from(policy.max_age_seconds)
+        .ok()
+        .and_then(Duration::try_seconds)
+        .and_then(|max_age| now.checked_sub_signed(max_age))
+        .unwrap_or(DateTime::<Utc>::MIN_UTC)
+}
+
+/// Builds the filter matching expired rows. Timestamps without a time zone are
+/// compared as UTC.
+pub fn expired_filter(
+    schema: &Schema,
+    policy: &RetentionPolicyV1,
+    cutoff: DateTime<Utc>,
+) -> Result<String, String> {
+    validate_policy(schema, policy)?;
+    let field = schema
+        .field_with_name(&policy.timestamp_column)
+        .map_err(|error| error.to_string())?;
+    let literal = match field.data_type() {
+        DataType::Date32 | DataType::Date64 => format!("DATE '{}'", cutoff.format("%Y-%m-%d")),
+        _ => format!("TIMESTAMP '{}'", cutoff.format("%Y-%m-%d %H:%M:%S%.6f")),
+    };
+    Ok(format!("`{}` < {literal}", policy.timestamp_column))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_schema::{Field, TimeUnit};
+    use chrono::TimeZone;
+
+    #[test]
+    fn builds_expired_filter_for_timestamp_and_date_columns() {
+        let schema = Schema::new(vec![
+            Field::new(
+                "created_at",
+                DataType::Timestamp(TimeUnit::Microsecond, None),
+                true,
+            ),
+            Field::new("day", DataType::Date32, true),
+            Field::new("id", DataType::Int32, false),
+        ]);
+        let now = Utc.with_ymd_and_hms(2024, 3, 10, 12, 0, 0).unwrap();
+        let policy = RetentionPolicyV1 {
+            timestamp_column: "created_at".to_string(),
+            max_age_seconds: 86_400,
+        };
+        let cutoff = cutoff(now, &policy);
+        assert_eq!(
+            expired_filter(&schema, &policy, cutoff).unwrap(),
+            "`created_at` < TIMESTAMP '2024-03-09 12:00:00.000000'"
+        );
+
+        let by_day = RetentionPolicyV1 {
+            timestamp_column: "day".to_string(),
+            max_age_seconds: 86_400,
+        };
+        assert_eq!(
+            expired_filter(&schema, &by_day, cutoff).unwrap(),
+            "`day` < DATE '2024-03-09'"
+        );
+
+        let wrong_type = RetentionPolicyV1 {
+            timestamp_column: "id".to_string(),
+            max_age_seconds: 86_400,
+        };
+        assert!(validate_policy(&schema, &wrong_type).is_err());
+    }
+}
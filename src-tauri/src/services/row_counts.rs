@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+/// Row count of a table and the version it was counted at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CachedRowCount {
+    pub row_count: u64,
+    pub version: u64,
+}
+
+/// Last known row counts keyed by connection URI and table name, so every handle on a
+/// table shares them and they survive reopening it. Counts are refreshed after writes
+/// and whenever a reader sees a newer table version.
+#[derive(Debug, Default)]
+pub struct RowCountCache {
+    entries: HashMap<(String, String), CachedRowCount>,
+}
+
+fn key(uri: &str, table_name: &str) -> (String, String) {
+    (uri.to_string(), table_name.to_string())
+}
+
+impl RowCountCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The last known count, whatever version it was taken at.
+    pub fn get(&self, uri: &str, table_name: &str) -> Option<CachedRowCount> {
+        self.entries.get(&key(uri, table_name)).copied()
+    }
+
+    /// The count if it was taken at `version`.
+    pub fn get_at_version(&self, uri: &str, table_name: &str, version: u64) -> Option<u64> {
+        self.get(uri, table_name)
+            .filter(|cached| cached.version == version)
+            .map(|cached| cached.row_count)
+    }
+
+    /// Stores a count unless a newer version has already been recorded, since
+    /// background refreshes may finish out of order.
+    pub fn record(&mut self, uri: &str, table_name: &str, version: u64, row_count: u64) {
+        let entry = self
+            .entries
+            .entry(key(uri, table_name))
+            .or_insert(CachedRowCount { row_count, version });
+        if version >= entry.version {
+            *entry = CachedRowCount { row_count, version };
+        }
+    }
+
+    pub fn remove(&mut self, uri: &str, table_name: &str) {
+        self.entries.remove(&key(uri, table_name));
+    }
+
+    pub fn rename(&mut self, uri: &str, table_name: &str, new_table_name: &str) {
+        if let Some(cached) = self.entries.remove(&key(uri, table_name)) {
+            self.entries.insert(key(uri, new_table_name), cached);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_the_newest_count_per_table() {
+        let mut cache = RowCountCache::new();
+        cache.record("memory://a", "items", 2, 50);
+        cache.record("memory://a", "items", 1, 40);
+        cache.record("memory://b", "items", 1, 7);
+
+        assert_eq!(
+            cache.get("memory://a", "items"),
+            Some(CachedRowCount {
+                row_count: 50,
+                version: 2
+            })
+        );
+        assert_eq!(cache.get_at_version("memory://a", "items", 2), Some(50));
+        assert_eq!(cache.get_at_version("memory://a", "items", 3), None);
+        assert_eq!(cache.get_at_version("memory://b", "items", 1), Some(7));
+
+        cache.rename("memory://a", "items", "products");
+        assert_eq!(cache.get("memory://a", "items"), None);
+        assert_eq!(cache.get_at_version("memory://a", "products", 2), Some(50));
+
+        cache.remove("memory://a", "products");
+        assert_eq!(cache.get("memory://a", "products"), None);
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use crate::ipc::v1::ScanStreamEventV1;
+
+/// Chunks a stream may have in flight when the request sets no window.
+pub const DEFAULT_WINDOW: u32 = 4;
+pub const MAX_WINDOW: u32 = 64;
+/// Encoded bytes per chunk when the request sets no `maxChunkBytes`.
+pub const DEFAULT_CHUNK_BYTES: usize = 4 * 1024 * 1024;
+/// How often a stream that is out of credit checks for acknowledgements.
+pub const ACK_POLL_INTERVAL: Duration = Duration::from_millis(10);
+/// How long a stream waits for an acknowledgement when no query timeout is set.
+pub const ACK_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// What `scan_stream_v1` sends over its channel: JSON events, and Arrow IPC chunks
+/// as raw bytes so they are not base64-encoded on the way.
+#[derive(Debug)]
+pub enum ScanStreamMessage {
+    Event(ScanStreamEventV1),
+    Chunk(Vec<u8>),
+}
+
+#[derive(Debug, Default)]
+struct StreamCursor {
+    /// Highest chunk sequence the frontend has acknowledged, starting at 1.
+    acked: u64,
+    cancelled: bool,
+}
+
+/// Acknowledgement state of the streams that are still sending.
+#[derive(Default)]
+pub struct ScanStreamRegistry {
+    streams: HashMap<String, StreamCursor>,
+}
+
+impl ScanStreamRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn open(&mut self) -> String {
+        let stream_id = Uuid::new_v4().to_string();
+        self.streams
+            .insert(stream_id.clone(), StreamCursor::default());
+        stream_id
+    }
+
+    /// Records that every chunk up to `sequence` was handled. Returns false when the
+    /// stream has already finished.
+    pub fn ack(&mut self, stream_id: &str, sequence: u64) -> bool {
+        match self.streams.get_mut(stream_id) {
+            Some(cursor) => {
+                cursor.acked = cursor.acked.max(sequence);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Stops the stream before its next chunk. Returns false when it has already
+    /// finished.
+    pub fn cancel(&mut self, stream_id: &str) -> bool {
+        match self.streams.get_mut(stream_id) {
+            Some(cursor) => {
+                cursor.cancelled = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The acknowledged sequence and whether the stream was cancelled.
+    pub fn cursor(&self, stream_id: &str) -> Option<(u64, bool)> {
+        self.streams
+            .get(stream_id)
+            .map(|cursor| (cursor.acked, cursor.cancelled))
+    }
+
+    pub fn close(&mut self, stream_id: &str) {
+        self.streams.remove(stream_id);
+    }
+}
+
+/// Whether another chunk may be sent with `sent` chunks out and `acked` handled.
+pub fn has_credit(sent: u64, acked: u64, window: u32) -> bool {
+    sent.saturating_sub(acked) < u64::from(window)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracks_acknowledgements_and_cancellation() {
+        let mut registry = ScanStreamRegistry::new();
+        let stream_id = registry.open();
+        assert_eq!(registry.cursor(&stream_id), Some((0, false)));
+        assert!(registry.ack(&stream_id, 3));
+        assert!(registry.ack(&stream_id, 2));
+        assert_eq!(registry.cursor(&stream_id), Some((3, false)));
+        assert!(registry.cancel(&stream_id));
+        assert_eq!(registry.cursor(&stream_id), Some((3, true)));
+        registry.close(&stream_id);
+        assert!(!registry.ack(&stream_id, 4));
+        assert!(!registry.cancel(&stream_id));
+
+        assert!(has_credit(3, 0, 4));
+        assert!(!has_credit(4, 0, 4));
+        assert!(has_credit(5, 2, 4));
+    }
+}
@@ -0,0 +1,480 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use chrono::{DateTime, Datelike, Local, Timelike};
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+
+use crate::ipc::v1::{
+    BackupTableRequestV1, ExportDataRequestV1, OptimizeActionV1, OptimizeTableRequestV1,
+    ResultEnvelope, RunRetentionRequestV1, ScheduleActionV1, ScheduleRunStatusV1, ScheduleV1,
+};
+use crate::services::{export_templates, shutdown, store, v1};
+use crate::state::AppState;
+
+pub const SCHEDULES_FILE: &str = "schedules.json";
+const SCHEDULER_TICK: Duration = Duration::from_secs(30);
+
+/// A parsed five-field cron expression (`minute hour day-of-month month day-of-week`).
+/// Fields accept `*`, numbers, lists (`1,15`), ranges (`1-5`) and steps (`*/10`, `0-30/5`).
+/// `@hourly`, `@daily` and `@weekly` are accepted as shorthands. Times are local.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronExpr {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    day_of_month_any: bool,
+    day_of_week_any: bool,
+}
+
+fn parse_cron_field(field: &str, min: u32, max: u32) -> Result<(u64, bool), String> {
+    let mut mask = 0u64;
+    let mut any = false;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step
+                    .parse()
+                    .map_err(|_| format!("invalid cron step '{step}'"))?;
+                if step == 0 {
+                    return Err("cron step must be greater than 0".to_string());
+                }
+                (range, step)
+            }
+            None => (part, 1),
+        };
+        let (start, end) = if range == "*" {
+            any = any || step == 1;
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            let start = start
+                .parse()
+                .map_err(|_| format!("invalid cron value '{start}'"))?;
+            let end = end
+                .parse()
+                .map_err(|_| format!("invalid cron value '{end}'"))?;
+            (start, end)
+        } else {
+            let value = range
+                .parse()
+                .map_err(|_| format!("invalid cron value '{range}'"))?;
+            (value, value)
+        };
+        if start < min || end > max || start > end {
+            return Err(format!(
+                "cron value '{part}' is outside the range {min}-{max}"
+            ));
+        }
+        // A step past `u32::MAX` ends the range instead of wrapping around.
+        let mut value = Some(start);
+        while let Some(current) = value.filter(|value| *value <= end) {
+            mask |= 1 << current;
+            value = current.checked_add(step);
+        }
+    }
+    Ok((mask, any))
+}
+
+impl CronExpr {
+    pub fn parse(expression: &str) -> Result<Self, String> {
+        let expression = match expression.trim() {
+            "@hourly" => "0 * * * *",
+            "@daily" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            other => other,
+        };
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err("cron expression must have 5 fields".to_string());
+        }
+        let (minutes, _) = parse_cron_field(fields[0], 0, 59)?;
+        let (hours, _) = parse_cron_field(fields[1], 0, 23)?;
+        let (days_of_month, day_of_month_any) = parse_cron_field(fields[2], 1, 31)?;
+        let (months, _) = parse_cron_field(fields[3], 1, 12)?;
+        // Both 0 and 7 mean Sunday.
+        let (mut days_of_week, day_of_week_any) = parse_cron_field(fields[4], 0, 7)?;
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week | 1) & !(1 << 7);
+        }
+        Ok(Self {
+            minutes,
+            hours,
+            days_of_month,
+            months,
+            days_of_week,
+            day_of_month_any,
+            day_of_week_any,
+        })
+    }
+
+    pub fn matches(&self, time: &DateTime<Local>) -> bool {
+        let bit = |mask: u64, value: u32| mask & (1 << value) != 0;
+        if !bit(self.minutes, time.minute())
+            || !bit(self.hours, time.hour())
+            || !bit(self.months, time.month())
+        {
+            return false;
+        }
+        let day_of_month = bit(self.days_of_month, time.day());
+        let day_of_week = bit(self.days_of_week, time.weekday().num_days_from_sunday());
+        // Standard cron semantics: when both day fields are restricted, either may match.
+        match (self.day_of_month_any, self.day_of_week_any) {
+            (true, true) => true,
+            (true, false) => day_of_week,
+            (false, true) => day_of_month,
+            (false, false) => day_of_month || day_of_week,
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SchedulesFile {
+    #[serde(default)]
+    schedules: Vec<ScheduleV1>,
+}
+
+/// Schedules keyed by connection URI, persisted to `schedules.json` in the app data
+/// directory when one is configured.
+#[derive(Default)]
+pub struct ScheduleStore {
+    path: Option<PathBuf>,
+    schedules: Vec<ScheduleV1>,
+}
+
+impl ScheduleStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load(path: PathBuf) -> Self {
+        let schedules = match store::load_json::<SchedulesFile>(&path) {
+            Ok(file) => file.schedules,
+            Err(error) => {
+                warn!("failed to load schedules path={:?} error={}", path, error);
+                Vec::new()
+            }
+        };
+        Self {
+            path: Some(path),
+            schedules,
+        }
+    }
+
+    pub fn list(&self, connection_uri: Option<&str>) -> Vec<ScheduleV1> {
+        self.schedules
+            .iter()
+            .filter(|schedule| match connection_uri {
+                Some(uri) => schedule.connection_uri == uri,
+                None => true,
+            })
+            .cloned()
+            .collect()
+    }
+
+    pub fn get(&self, id: &str) -> Option<ScheduleV1> {
+        self.schedules
+            .iter()
+            .find(|schedule| schedule.id == id)
+            .cloned()
+    }
+
+    pub fn upsert(&mut self, schedule: ScheduleV1) -> Result<(), String> {
+        match self
+            .schedules
+            .iter_mut()
+            .find(|item| item.id == schedule.id)
+        {
+            Some(existing) => *existing = schedule,
+            None => self.schedules.push(schedule),
+        }
+        self.persist()
+    }
+
+    pub fn remove(&mut self, id: &str) -> Result<bool, String> {
+        let before = self.schedules.len();
+        self.schedules.retain(|schedule| schedule.id != id);
+        if self.schedules.len() == before {
+            return Ok(false);
+        }
+        self.persist().map(|_| true)
+    }
+
+    pub fn record_run(&mut self, id: &str, run_at_ms: u64, result: Result<String, String>) {
+        let Some(schedule) = self.schedules.iter_mut().find(|item| item.id == id) else {
+            return;
+        };
+        schedule.last_run_at_ms = Some(run_at_ms);
+        match result {
+            Ok(message) => {
+                schedule.last_status = Some(ScheduleRunStatusV1::Succeeded);
+                schedule.last_message = Some(message);
+            }
+            Err(message) => {
+                schedule.last_status = Some(ScheduleRunStatusV1::Failed);
+                schedule.last_message = Some(message);
+            }
+        }
+        if let Err(error) = self.persist() {
+            warn!("failed to persist schedule run id={} error={}", id, error);
+        }
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        let Some(path) = self.path.as_ref() else {
+            return Ok(());
+        };
+        store::save_json(
+            path,
+            &SchedulesFile {
+                schedules: self.schedules.clone(),
+            },
+        )
+    }
+}
+
+fn envelope_result<T>(
+    envelope: ResultEnvelope<T>,
+    summary: impl FnOnce(&T) -> String,
+) -> Result<String, String> {
+    match (envelope.ok, envelope.data, envelope.error) {
+        (true, Some(data), _) => Ok(summary(&data)),
+        (_, _, Some(error)) => Err(error.message),
+        _ => Err("scheduled action returned no result".to_string()),
+    }
+}
+
+async fn run_action(
+    state: &AppState,
+    table_id: &str,
+    schedule: &ScheduleV1,
+    now: &DateTime<Local>,
+) -> Result<String, String> {
+    let stamp = now.format("%Y%m%d-%H%M").to_string();
+    match &schedule.action {
+        ScheduleActionV1::Compact {
+            target_rows_per_fragment,
+        } => {
+            let envelope = v1::optimize_table_v1(
+                state,
+                OptimizeTableRequestV1 {
+                    table_id: table_id.to_string(),
+                    action: OptimizeActionV1::Compact,
+                    target_rows_per_fragment: *target_rows_per_fragment,
+                    older_than_days: None,
+                    delete_unverified: None,
+                    error_if_tagged_old_versions: None,
+                    locale: None,
+                    wait_timeout_ms: None,
+                    job_id: None,
+                },
+            )
+            .await;
+            envelope_result(envelope, |data| data.summary.clone())
+        }
+        ScheduleActionV1::Vacuum { older_than_days } => {
+            let envelope = v1::optimize_table_v1(
+                state,
+                OptimizeTableRequestV1 {
+                    table_id: table_id.to_string(),
+                    action: OptimizeActionV1::Vacuum,
+                    target_rows_per_fragment: None,
+                    older_than_days: *older_than_days,
+                    delete_unverified: None,
+                    error_if_tagged_old_versions: None,
+                    locale: None,
+                    wait_timeout_ms: None,
+                    job_id: None,
+                },
+            )
+            .await;
+            envelope_result(envelope, |data| data.summary.clone())
+        }
+        ScheduleActionV1::Backup {
+            destination_uri,
+            mode,
+        } => {
+            let envelope = v1::backup_table_v1(
+                state,
+                BackupTableRequestV1 {
+                    table_id: table_id.to_string(),
+                    destination_uri: destination_uri.clone(),
+                    backup_table_name: Some(format!(
+                        "{}_{}",
+                        schedule.table_name,
+                        stamp.replace('-', "_")
+                    )),
+                    mode: *mode,
+                    storage_options: None,
+                    job_id: None,
+                },
+            )
+            .await;
+            envelope_result(envelope, |data| {
+                format!("backed up {} rows to {}", data.rows, data.backup_table_name)
+            })
+        }
+        ScheduleActionV1::Export { path, format } => {
+            let path = export_templates::expand_path(path, &schedule.table_name, now);
+            let envelope = v1::export_data_v1(
+                state,
+                ExportDataRequestV1 {
+                    table_id: table_id.to_string(),
+                    path,
+                    format: format.clone(),
+                    projection: None,
+                    filter: None,
+                    limit: None,
+                    offset: None,
+                    delimiter: None,
+                    with_header: None,
+                    geometry_column: None,
+                    job_id: None,
+                },
+            )
+            .await;
+            envelope_result(envelope, |data| {
+                format!("exported {} rows to {}", data.rows, data.path)
+            })
+        }
+        ScheduleActionV1::Retention => {
+            let envelope = v1::run_retention_v1(
+                state,
+                RunRetentionRequestV1 {
+                    table_id: table_id.to_string(),
+                    dry_run: false,
+                },
+            )
+            .await;
+            envelope_result(envelope, |data| {
+                format!(
+                    "deleted {} rows older than {}",
+                    data.rows_deleted, data.cutoff
+                )
+            })
+        }
+    }
+}
+
+async fn run_schedule(
+    state: &AppState,
+    schedule: &ScheduleV1,
+    now: &DateTime<Local>,
+) -> Result<String, String> {
+    let connection = match state.connections.lock() {
+        Ok(manager) => manager.find_connection_by_uri(&schedule.connection_uri),
+        Err(_) => return Err("failed to lock connection manager".to_string()),
+    };
+    let Some((connection_id, connection)) = connection else {
+        return Err("connection is not open".to_string());
+    };
+
+    let table = connection
+        .open_table(&schedule.table_name)
+        .execute()
+        .await
+        .map_err(|error| error.to_string())?;
+    let table_id = match state.connections.lock() {
+        Ok(mut manager) => manager.insert_table(schedule.table_name.clone(), table, connection_id),
+        Err(_) => return Err("failed to lock connection manager".to_string()),
+    };
+
+    let result = run_action(state, &table_id, schedule, now).await;
+
+    if let Ok(mut manager) = state.connections.lock() {
+        manager.remove_table(&table_id);
+    }
+    result
+}
+
+/// Runs every enabled schedule whose cron expression matches `now` and that has not
+/// already run during the current minute.
+pub async fn run_due_schedules(state: &AppState, now: DateTime<Local>) -> usize {
+    let minute_start_ms = (now.timestamp_millis() / 60_000 * 60_000) as u64;
+    let due: Vec<ScheduleV1> = match state.schedules.lock() {
+        Ok(schedules) => schedules
+            .list(None)
+            .into_iter()
+            .filter(|schedule| schedule.enabled)
+            .filter(|schedule| {
+                !matches!(schedule.last_run_at_ms, Some(last_run) if last_run >= minute_start_ms)
+            })
+            .filter(|schedule| {
+                CronExpr::parse(&schedule.cron)
+                    .map(|cron| cron.matches(&now))
+                    .unwrap_or(false)
+            })
+            .collect(),
+        Err(_) => {
+            error!("scheduler failed to lock schedule store");
+            return 0;
+        }
+    };
+
+    for schedule in &due {
+        info!(
+            "scheduler run start id={} table=\"{}\" action={:?}",
+            schedule.id, schedule.table_name, schedule.action
+        );
+        let result = run_schedule(state, schedule, &now).await;
+        match &result {
+            Ok(message) => info!("scheduler run ok id={} {}", schedule.id, message),
+            Err(error) => warn!("scheduler run failed id={} error={}", schedule.id, error),
+        }
+        if let Ok(mut schedules) = state.schedules.lock() {
+            schedules.record_run(&schedule.id, minute_start_ms, result);
+        }
+    }
+
+    due.len()
+}
+
+/// Ticks the scheduler for as long as the app is running.
+pub async fn run_loop(state: &AppState) {
+    let mut interval = tokio::time::interval(SCHEDULER_TICK);
+    loop {
+        interval.tick().await;
+        if shutdown::is_shutting_down(state) {
+            break;
+        }
+        run_due_schedules(state, Local::now()).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Local, TimeZone};
+
+    use super::CronExpr;
+
+    #[test]
+    fn parses_and_matches_cron_fields() {
+        let monday_0230 = Local.with_ymd_and_hms(2024, 1, 1, 2, 30, 0).unwrap();
+
+        assert!(CronExpr::parse("* * * * *").unwrap().matches(&monday_0230));
+        assert!(CronExpr::parse("30 2 * * 1").unwrap().matches(&monday_0230));
+        assert!(CronExpr::parse("*/15 0-3 * * *")
+            .unwrap()
+            .matches(&monday_0230));
+        assert!(!CronExpr::parse("@daily").unwrap().matches(&monday_0230));
+        assert!(!CronExpr::parse("30 2 * * 0,7")
+            .unwrap()
+            .matches(&monday_0230));
+        assert!(CronExpr::parse("30 2 15 * 1")
+            .unwrap()
+            .matches(&monday_0230));
+        assert!(CronExpr::parse("61 * * * *").is_err());
+        assert!(CronExpr::parse("* * *").is_err());
+    }
+
+    #[test]
+    fn huge_steps_stop_at_the_first_value() {
+        let first = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let second = Local.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
+        let expr = CronExpr::parse("0 0 1-31/4294967295 * *").expect("huge step");
+        assert!(expr.matches(&first));
+        assert!(!expr.matches(&second));
+    }
+}
@@ -0,0 +1,178 @@
+use std::collections::HashSet;
+
+use arrow_cast::can_cast_types;
+use arrow_schema::Schema;
+
+use crate::ipc::v1::{ColumnTypeMismatchV1, SchemaCompatibilityReportV1};
+
+/// Compares the schema of an import file with the table it is written to. Columns are
+/// matched by name; types are only compared when `compare_types` is set, since CSV
+/// types are inferred loosely and parsed into the table types anyway. `positional`
+/// also flags a different column order, which matters for readers that map columns
+/// by position. Returns `None` when the file fits the table.
+pub fn report(
+    table: &Schema,
+    file: &Schema,
+    compare_types: bool,
+    positional: bool,
+) -> Option<SchemaCompatibilityReportV1> {
+    let table_names = table
+        .fields()
+        .iter()
+        .map(|field| field.name().as_str())
+        .collect::<HashSet<_>>();
+    let file_names = file
+        .fields()
+        .iter()
+        .map(|field| field.name().as_str())
+        .collect::<HashSet<_>>();
+
+    let missing_columns = table
+        .fields()
+        .iter()
+        .filter(|field| !file_names.contains(field.name().as_str()))
+        .map(|field| field.name().clone())
+        .collect::<Vec<_>>();
+    let extra_columns = file
+        .fields()
+        .iter()
+        .filter(|field| !table_names.contains(field.name().as_str()))
+        .map(|field| field.name().clone())
+        .collect::<Vec<_>>();
+
+    let type_mismatches = if compare_types {
+        table
+            .fields()
+            .iter()
+            .filter_map(|expected| {
+                let (_, actual) = file.column_with_name(expected.name())?;
+                if actual.data_type().equals_datatype(expected.data_type()) {
+                    return None;
+                }
+                Some(ColumnTypeMismatchV1 {
+                    column: expected.name().clone(),
+                    table_type: expected.data_type().to_string(),
+                    file_type: actual.data_type().to_string(),
+                    suggested_cast: can_cast_types(actual.data_type(), expected.data_type())
+                        .then(|| expected.data_type().to_string()),
+                })
+            })
+            .collect::<Vec<_>>()
+    } else {
+        Vec::new()
+    };
+
+    let column_order_differs = positional
+        && missing_columns.is_empty()
+        && extra_columns.is_empty()
+        && table
+            .fields()
+            .iter()
+            .zip(file.fields().iter())
+            .any(|(expected, actual)| expected.name() != actual.name());
+
+    if missing_columns.is_empty()
+        && extra_columns.is_empty()
+        && type_mismatches.is_empty()
+        && !column_order_differs
+    {
+        return None;
+    }
+    Some(SchemaCompatibilityReportV1 {
+        missing_columns,
+        extra_columns,
+        type_mismatches,
+        column_order_differs,
+    })
+}
+
+/// One-line summary of a report, used as the error message.
+pub fn summary(report: &SchemaCompatibilityReportV1) -> String {
+    let mut parts = Vec::new();
+    if !report.missing_columns.is_empty() {
+        parts.push(format!(
+            "missing columns: {}",
+            report.missing_columns.join(", ")
+        ));
+    }
+    if !report.extra_columns.is_empty() {
+        parts.push(format!(
+            "extra columns: {}",
+            report.extra_columns.join(", ")
+        ));
+    }
+    if !report.type_mismatches.is_empty() {
+        parts.push(format!(
+            "type mismatches: {}",
+            report
+                .type_mismatches
+                .iter()
+                .map(|mismatch| format!(
+                    "{} ({} in file, {} in table)",
+                    mismatch.column, mismatch.file_type, mismatch.table_type
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    if report.column_order_differs {
+        parts.push("columns are in a different order than the table".to_string());
+    }
+    format!("file schema does not match the table: {}", parts.join("; "))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_schema::{DataType, Field};
+
+    use super::*;
+
+    fn schema(fields: &[(&str, DataType)]) -> Schema {
+        Schema::new(
+            fields
+                .iter()
+                .map(|(name, data_type)| Field::new(*name, data_type.clone(), true))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn reports_missing_extra_and_mismatched_columns() {
+        let table = schema(&[
+            ("id", DataType::Int32),
+            ("text", DataType::Utf8),
+            ("score", DataType::Float32),
+        ]);
+        let file = schema(&[
+            ("id", DataType::Int64),
+            ("score", DataType::Binary),
+            ("note", DataType::Utf8),
+        ]);
+
+        let report = report(&table, &file, true, false).expect("report");
+        assert_eq!(report.missing_columns, vec!["text"]);
+        assert_eq!(report.extra_columns, vec!["note"]);
+        assert_eq!(report.type_mismatches.len(), 2);
+        assert_eq!(report.type_mismatches[0].column, "id");
+        assert_eq!(
+            report.type_mismatches[0].suggested_cast.as_deref(),
+            Some("Int32")
+        );
+        assert_eq!(report.type_mismatches[1].suggested_cast, None);
+        assert!(summary(&report).contains("missing columns: text"));
+    }
+
+    #[test]
+    fn accepts_matching_schemas_and_flags_reordered_columns() {
+        let table = schema(&[("id", DataType::Int32), ("text", DataType::Utf8)]);
+        assert!(report(&table, &table, true, true).is_none());
+
+        let reordered = schema(&[("text", DataType::Utf8), ("id", DataType::Int32)]);
+        assert!(report(&table, &reordered, true, false).is_none());
+        assert!(
+            report(&table, &reordered, false, true)
+                .expect("report")
+                .column_order_differs
+        );
+    }
+}
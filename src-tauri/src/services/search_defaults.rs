@@ -0,0 +1,232 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use arrow_schema::{DataType, Schema};
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::ipc::v1::SearchDefaultsV1;
+use crate::services::store;
+
+pub const SEARCH_DEFAULTS_FILE: &str = "search_defaults.json";
+
+const MAX_TOP_K: usize = 10_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SearchDefaultsEntry {
+    connection_uri: String,
+    table_name: String,
+    #[serde(flatten)]
+    defaults: SearchDefaultsV1,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SearchDefaultsFile {
+    #[serde(default)]
+    tables: Vec<SearchDefaultsEntry>,
+}
+
+/// Per-table search defaults keyed by connection URI and table name, persisted to
+/// `search_defaults.json` in the app data directory when one is configured.
+#[derive(Default)]
+pub struct SearchDefaultsStore {
+    path: Option<PathBuf>,
+    entries: Vec<SearchDefaultsEntry>,
+}
+
+impl SearchDefaultsStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load(path: PathBuf) -> Self {
+        let entries = match store::load_json::<SearchDefaultsFile>(&path) {
+            Ok(file) => file.tables,
+            Err(error) => {
+                warn!(
+                    "failed to load search defaults path={:?} error={}",
+                    path, error
+                );
+                Vec::new()
+            }
+        };
+        Self {
+            path: Some(path),
+            entries,
+        }
+    }
+
+    pub fn get(&self, connection_uri: &str, table_name: &str) -> Option<SearchDefaultsV1> {
+        self.entries
+            .iter()
+            .find(|entry| entry.connection_uri == connection_uri && entry.table_name == table_name)
+            .map(|entry| entry.defaults.clone())
+    }
+
+    /// Replaces the defaults of a table; `None` removes them.
+    pub fn set(
+        &mut self,
+        connection_uri: &str,
+        table_name: &str,
+        defaults: Option<SearchDefaultsV1>,
+    ) -> Result<(), String> {
+        self.entries.retain(|entry| {
+            entry.connection_uri != connection_uri || entry.table_name != table_name
+        });
+        if let Some(defaults) = defaults {
+            self.entries.push(SearchDefaultsEntry {
+                connection_uri: connection_uri.to_string(),
+                table_name: table_name.to_string(),
+                defaults,
+            });
+        }
+        self.persist()
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        let Some(path) = self.path.as_ref() else {
+            return Ok(());
+        };
+        store::save_json(
+            path,
+            &SearchDefaultsFile {
+                tables: self.entries.clone(),
+            },
+        )
+    }
+}
+
+fn is_vector_type(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::FixedSizeList(item, _) if item.data_type().is_floating()
+    )
+}
+
+fn is_text_type(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View
+    )
+}
+
+/// Trims the column names and checks them against the table schema. Returns `None`
+/// when nothing is set so empty defaults clear the entry.
+pub fn normalize(
+    defaults: SearchDefaultsV1,
+    schema: &Schema,
+) -> Result<Option<SearchDefaultsV1>, String> {
+    let vector_column = defaults
+        .vector_column
+        .map(|column| column.trim().to_string())
+        .filter(|column| !column.is_empty());
+    if let Some(column) = vector_column.as_deref() {
+        let field = schema
+            .field_with_name(column)
+            .map_err(|_| format!("column not found: {column}"))?;
+        if !is_vector_type(field.data_type()) {
+            return Err(format!("column {column} is not a vector column"));
+        }
+    }
+
+    if defaults
+        .top_k
+        .is_some_and(|top_k| top_k == 0 || top_k > MAX_TOP_K)
+    {
+        return Err(format!("top_k must be between 1 and {MAX_TOP_K}"));
+    }
+
+    let mut seen = HashSet::new();
+    let mut fts_columns = Vec::new();
+    for column in defaults.fts_columns {
+        let column = column.trim();
+        if column.is_empty() || !seen.insert(column.to_string()) {
+            continue;
+        }
+        let field = schema
+            .field_with_name(column)
+            .map_err(|_| format!("column not found: {column}"))?;
+        if !is_text_type(field.data_type()) {
+            return Err(format!("column {column} is not a text column"));
+        }
+        fts_columns.push(column.to_string());
+    }
+
+    let normalized = SearchDefaultsV1 {
+        vector_column,
+        distance_type: defaults.distance_type,
+        top_k: defaults.top_k,
+        fts_columns,
+    };
+    if normalized == SearchDefaultsV1::default() {
+        return Ok(None);
+    }
+    Ok(Some(normalized))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_schema::Field;
+
+    use crate::ipc::v1::DistanceTypeV1;
+
+    use super::*;
+
+    fn schema() -> Schema {
+        Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("text", DataType::Utf8, true),
+            Field::new(
+                "vector",
+                DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Float32, true)), 3),
+                true,
+            ),
+        ])
+    }
+
+    #[test]
+    fn normalizes_defaults_against_the_schema() {
+        let normalized = normalize(
+            SearchDefaultsV1 {
+                vector_column: Some(" vector ".to_string()),
+                distance_type: Some(DistanceTypeV1::Cosine),
+                top_k: Some(20),
+                fts_columns: vec![" text ".to_string(), "text".to_string(), " ".to_string()],
+            },
+            &schema(),
+        )
+        .expect("normalize")
+        .expect("defaults");
+        assert_eq!(normalized.vector_column.as_deref(), Some("vector"));
+        assert_eq!(normalized.fts_columns, vec!["text"]);
+
+        let cleared = normalize(
+            SearchDefaultsV1 {
+                vector_column: Some(" ".to_string()),
+                ..Default::default()
+            },
+            &schema(),
+        )
+        .expect("normalize");
+        assert!(cleared.is_none());
+    }
+
+    #[test]
+    fn rejects_columns_of_the_wrong_type() {
+        let defaults = |vector_column: &str, fts_column: &str, top_k: usize| SearchDefaultsV1 {
+            vector_column: Some(vector_column.to_string()),
+            distance_type: None,
+            top_k: Some(top_k),
+            fts_columns: vec![fts_column.to_string()],
+        };
+        assert!(normalize(defaults("vector", "text", 10), &schema()).is_ok());
+        assert!(normalize(defaults("text", "text", 10), &schema()).is_err());
+        assert!(normalize(defaults("missing", "text", 10), &schema()).is_err());
+        assert!(normalize(defaults("vector", "id", 10), &schema()).is_err());
+        assert!(normalize(defaults("vector", "text", 0), &schema()).is_err());
+    }
+}
@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use tauri_plugin_stronghold::stronghold::Stronghold;
+
+use crate::services::store;
+
+/// Stronghold snapshot the credentials view saves to, in the app data directory.
+pub const VAULT_FILE: &str = "credentials.stronghold";
+/// plugin-store file in the app data directory holding the vault passphrase.
+pub const VAULT_CONFIG_FILE: &str = "credentials-config.json";
+/// Stronghold client whose store holds one record per credential reference.
+pub const VAULT_CLIENT: &str = "lancedb-viewer";
+const PASSPHRASE_KEY: &str = "strongholdPassphrase";
+/// Snapshot earlier versions opened as `Stronghold.load("lancedb-viewer", ..)`,
+/// which the plugin resolves against the working directory.
+pub const LEGACY_VAULT_FILE: &str = "lancedb-viewer";
+/// plugin-store file in the app data directory listing the saved credentials.
+const VAULT_INDEX_FILE: &str = "credentials-meta.json";
+const INDEX_KEY: &str = "credentials";
+
+#[derive(Debug, Deserialize)]
+struct IndexedCredential {
+    reference: String,
+}
+
+/// A credential record as the frontend writes it: `{ "params": { ... } }`.
+#[derive(Debug, Default, Deserialize)]
+struct SecretRecord {
+    #[serde(default)]
+    params: HashMap<String, serde_json::Value>,
+}
+
+/// Key the stronghold plugin derives from a vault password. The plugin builder in
+/// `lib.rs` uses it too, so snapshots open the same way on both sides.
+pub fn password_hash(password: &str) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(password.as_bytes());
+    hasher.finalize().to_vec()
+}
+
+/// The params of a stored credential. Non-string values are kept as their JSON
+/// text, the way the frontend stringifies them.
+pub fn parse_record(bytes: &[u8]) -> Result<HashMap<String, String>, String> {
+    let record = serde_json::from_slice::<SecretRecord>(bytes)
+        .map_err(|error| format!("invalid credential record: {error}"))?;
+    Ok(record
+        .params
+        .into_iter()
+        .map(|(key, value)| match value {
+            serde_json::Value::String(value) => (key, value),
+            value => (key, value.to_string()),
+        })
+        .collect())
+}
+
+/// The vault passphrase the frontend generated, if it has created the vault.
+pub fn read_passphrase(data_dir: &Path) -> Result<Option<String>, String> {
+    let config =
+        store::load_json::<HashMap<String, serde_json::Value>>(&data_dir.join(VAULT_CONFIG_FILE))?;
+    Ok(config
+        .get(PASSPHRASE_KEY)
+        .and_then(serde_json::Value::as_str)
+        .map(str::trim)
+        .filter(|passphrase| !passphrase.is_empty())
+        .map(str::to_string))
+}
+
+/// Reads the params stored under `reference`. Returns `None` when the vault has not
+/// been created yet or holds no such reference.
+pub fn resolve(
+    data_dir: &Path,
+    reference: &str,
+) -> Result<Option<HashMap<String, String>>, String> {
+    let vault_path = data_dir.join(VAULT_FILE);
+    if !vault_path.exists() {
+        return Ok(None);
+    }
+    let Some(passphrase) = read_passphrase(data_dir)? else {
+        return Ok(None);
+    };
+    let stronghold = Stronghold::new(&vault_path, password_hash(&passphrase))
+        .map_err(|error| format!("failed to open the credential vault: {error}"))?;
+    // A vault without the client has no credentials saved yet.
+    let Ok(client) = stronghold.load_client(VAULT_CLIENT) else {
+        return Ok(None);
+    };
+    let record = client
+        .store()
+        .get(reference.as_bytes())
+        .map_err(|error| format!("failed to read the credential vault: {error}"))?;
+    record.map(|bytes| parse_record(&bytes)).transpose()
+}
+
+/// Copies the credentials of a vault saved by an earlier version into the vault in
+/// the app data directory, then removes the old snapshot so this runs once.
+/// Records already in the new vault win. Returns how many records were copied.
+pub fn migrate_legacy_vault(data_dir: &Path, legacy_path: &Path) -> Result<usize, String> {
+    if !legacy_path.is_file() {
+        return Ok(0);
+    }
+    let Some(passphrase) = read_passphrase(data_dir)? else {
+        return Ok(0);
+    };
+    let index =
+        store::load_json::<HashMap<String, serde_json::Value>>(&data_dir.join(VAULT_INDEX_FILE))?;
+    let references = index
+        .get(INDEX_KEY)
+        .cloned()
+        .map(serde_json::from_value::<Vec<IndexedCredential>>)
+        .transpose()
+        .map_err(|error| format!("invalid credential index: {error}"))?
+        .unwrap_or_default();
+
+    let legacy = Stronghold::new(legacy_path, password_hash(&passphrase))
+        .map_err(|error| format!("failed to open the old credential vault: {error}"))?;
+    let mut copied = 0;
+    if let Ok(legacy_client) = legacy.load_client(VAULT_CLIENT) {
+        let vault = Stronghold::new(data_dir.join(VAULT_FILE), password_hash(&passphrase))
+            .map_err(|error| format!("failed to open the credential vault: {error}"))?;
+        let client = match vault.load_client(VAULT_CLIENT) {
+            Ok(client) => client,
+            Err(_) => vault
+                .create_client(VAULT_CLIENT)
+                .map_err(|error| format!("failed to create the credential vault: {error}"))?,
+        };
+        for credential in &references {
+            let key = credential.reference.as_bytes();
+            let Some(record) = legacy_client
+                .store()
+                .get(key)
+                .map_err(|error| format!("failed to read the old credential vault: {error}"))?
+            else {
+                continue;
+            };
+            let exists = client
+                .store()
+                .contains_key(key)
+                .map_err(|error| format!("failed to read the credential vault: {error}"))?;
+            if exists {
+                continue;
+            }
+            client
+                .store()
+                .insert(key.to_vec(), record, None)
+                .map_err(|error| format!("failed to write the credential vault: {error}"))?;
+            copied += 1;
+        }
+        vault
+            .write_client(VAULT_CLIENT)
+            .map_err(|error| format!("failed to write the credential vault: {error}"))?;
+        vault
+            .save()
+            .map_err(|error| format!("failed to save the credential vault: {error}"))?;
+    }
+    fs::remove_file(legacy_path).map_err(|error| {
+        format!(
+            "failed to remove the old credential vault {}: {error}",
+            legacy_path.display()
+        )
+    })?;
+    Ok(copied)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_records_and_passphrases() {
+        let params = parse_record(br#"{"params":{"aws_access_key_id":"AKIA","port":9000}}"#)
+            .expect("record");
+        assert_eq!(params["aws_access_key_id"], "AKIA");
+        assert_eq!(params["port"], "9000");
+        assert!(parse_record(b"not json").is_err());
+        assert_eq!(password_hash("secret").len(), 32);
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        assert_eq!(read_passphrase(dir.path()).expect("missing config"), None);
+        assert_eq!(resolve(dir.path(), "cred_1").expect("missing vault"), None);
+        std::fs::write(
+            dir.path().join(VAULT_CONFIG_FILE),
+            r#"{"strongholdPassphrase":"pass-1"}"#,
+        )
+        .expect("write config");
+        assert_eq!(
+            read_passphrase(dir.path()).expect("config").as_deref(),
+            Some("pass-1")
+        );
+    }
+
+    #[test]
+    fn migrates_the_legacy_vault_once() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let legacy_path = dir.path().join(LEGACY_VAULT_FILE);
+        assert_eq!(
+            migrate_legacy_vault(dir.path(), &legacy_path).expect("no legacy vault"),
+            0
+        );
+        std::fs::write(
+            dir.path().join(VAULT_CONFIG_FILE),
+            r#"{"strongholdPassphrase":"pass-1"}"#,
+        )
+        .expect("write config");
+        std::fs::write(
+            dir.path().join(VAULT_INDEX_FILE),
+            r#"{"credentials":[{"reference":"cred_1","provider":"s3","updatedAt":"2026-10-16T00:00:00Z"}]}"#,
+        )
+        .expect("write index");
+
+        let legacy = Stronghold::new(&legacy_path, password_hash("pass-1")).expect("legacy");
+        let client = legacy.create_client(VAULT_CLIENT).expect("client");
+        client
+            .store()
+            .insert(
+                b"cred_1".to_vec(),
+                br#"{"params":{"aws_access_key_id":"AKIA"}}"#.to_vec(),
+                None,
+            )
+            .expect("insert");
+        legacy.write_client(VAULT_CLIENT).expect("write client");
+        legacy.save().expect("save legacy");
+        drop(legacy);
+
+        assert_eq!(
+            migrate_legacy_vault(dir.path(), &legacy_path).expect("migrate"),
+            1
+        );
+        assert!(!legacy_path.exists());
+        let params = resolve(dir.path(), "cred_1")
+            .expect("resolve")
+            .expect("migrated record");
+        assert_eq!(params["aws_access_key_id"], "AKIA");
+        assert_eq!(
+            migrate_legacy_vault(dir.path(), &legacy_path).expect("second run"),
+            0
+        );
+    }
+}
@@ -0,0 +1,726 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::io::{self, BufRead, BufReader, ErrorKind, Read, Write};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, TcpListener, TcpStream, UdpSocket};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use log::{debug, info, warn};
+use serde::Serialize;
+use subtle::ConstantTimeEq;
+use url::Url;
+
+use crate::ipc::v1::{AccessLogEntryV1, AccessTransportV1, ErrorCode, ShareSessionV1};
+use crate::services::access_log::AccessLog;
+use crate::services::clock;
+
+pub const DEFAULT_SHARE_TTL: Duration = Duration::from_secs(60 * 60);
+pub const MAX_SHARE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+const DEFAULT_PAGE_SIZE: usize = 100;
+const MAX_PAGE_SIZE: usize = 1_000;
+const ACCEPT_POLL: Duration = Duration::from_millis(100);
+/// Time a client has to send its whole request, however slowly it trickles in.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(30);
+/// Connections a share serves at once; more are turned away with a 503.
+const MAX_CONNECTIONS: usize = 8;
+/// Longest request line plus headers read from one connection.
+const MAX_REQUEST_BYTES: u64 = 16 * 1024;
+
+/// Rows of one shared page, already filtered, projected and masked.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SharePage {
+    pub columns: Vec<String>,
+    pub rows: Vec<serde_json::Value>,
+    pub offset: usize,
+    pub limit: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<usize>,
+}
+
+pub type PageFuture = Pin<Box<dyn Future<Output = Result<SharePage, String>> + Send>>;
+
+/// Loads the page at `(offset, limit)` of a shared table. The loader carries the
+/// table handle and the share's filter, so the server never sees anything else.
+pub type PageLoader = Arc<dyn Fn(usize, usize) -> PageFuture + Send + Sync>;
+
+pub struct SharedTable {
+    pub name: String,
+    pub filter: Option<String>,
+    pub load: PageLoader,
+}
+
+struct ShareServer {
+    token: String,
+    expires_at: Instant,
+    stop: Arc<AtomicBool>,
+    tables: Vec<SharedTable>,
+    access_log: Arc<Mutex<AccessLog>>,
+    connections: Arc<AtomicUsize>,
+}
+
+struct ShareHandle {
+    session: ShareSessionV1,
+    stop: Arc<AtomicBool>,
+}
+
+/// Running share servers. A server stops on its own once it expires; stopped ones
+/// are dropped the next time the registry is read.
+#[derive(Default)]
+pub struct ShareRegistry {
+    shares: HashMap<String, ShareHandle>,
+}
+
+impl ShareRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn prune(&mut self) {
+        let now_ms = clock::now_unix_millis();
+        self.shares.retain(|_, handle| {
+            !handle.stop.load(Ordering::SeqCst) && handle.session.expires_at_ms > now_ms
+        });
+    }
+
+    /// Active shares, oldest first.
+    pub fn list(&mut self) -> Vec<ShareSessionV1> {
+        self.prune();
+        let mut sessions = self
+            .shares
+            .values()
+            .map(|handle| handle.session.clone())
+            .collect::<Vec<_>>();
+        sessions.sort_by_key(|session| session.started_at_ms);
+        sessions
+    }
+
+    /// Returns false when no active share has this id.
+    pub fn stop(&mut self, share_id: &str) -> bool {
+        self.prune();
+        match self.shares.remove(share_id) {
+            Some(handle) => {
+                handle.stop.store(true, Ordering::SeqCst);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn stop_all(&mut self) -> usize {
+        let count = self.shares.len();
+        for (_, handle) in self.shares.drain() {
+            handle.stop.store(true, Ordering::SeqCst);
+        }
+        count
+    }
+
+    fn insert(&mut self, session: ShareSessionV1, stop: Arc<AtomicBool>) {
+        self.prune();
+        self.shares
+            .insert(session.share_id.clone(), ShareHandle { session, stop });
+    }
+}
+
+/// Address other machines on the LAN reach this one at. Connecting a UDP socket
+/// only picks the outgoing interface; nothing is sent.
+fn lan_address() -> IpAddr {
+    UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))
+        .and_then(|socket| {
+            socket.connect((Ipv4Addr::new(192, 0, 2, 1), 80))?;
+            socket.local_addr()
+        })
+        .map(|address| address.ip())
+        .ok()
+        .filter(|address| !address.is_unspecified())
+        .unwrap_or(IpAddr::V4(Ipv4Addr::LOCALHOST))
+}
+
+/// Binds a listener and serves `tables` until the share expires or is stopped.
+/// `local_only` binds to the loopback interface instead of every interface. Every
+/// request is recorded in `access_log`.
+pub fn start(
+    registry: &mut ShareRegistry,
+    tables: Vec<SharedTable>,
+    ttl: Duration,
+    port: u16,
+    local_only: bool,
+    access_log: Arc<Mutex<AccessLog>>,
+) -> Result<ShareSessionV1, String> {
+    let bind_address = if local_only {
+        Ipv4Addr::LOCALHOST
+    } else {
+        Ipv4Addr::UNSPECIFIED
+    };
+    let listener = TcpListener::bind((bind_address, port))
+        .map_err(|error| format!("failed to bind share server: {error}"))?;
+    listener
+        .set_nonblocking(true)
+        .map_err(|error| error.to_string())?;
+    let port = listener
+        .local_addr()
+        .map_err(|error| error.to_string())?
+        .port();
+    let host = if local_only {
+        IpAddr::V4(Ipv4Addr::LOCALHOST)
+    } else {
+        lan_address()
+    };
+
+    let share_id = uuid::Uuid::new_v4().to_string();
+    let token = uuid::Uuid::new_v4().simple().to_string();
+    let started_at_ms = clock::now_unix_millis();
+    let session = ShareSessionV1 {
+        share_id: share_id.clone(),
+        url: format!("http://{}/?token={token}", SocketAddr::new(host, port)),
+        token: token.clone(),
+        tables: tables.iter().map(|table| table.name.clone()).collect(),
+        started_at_ms,
+        expires_at_ms: started_at_ms.saturating_add(ttl.as_millis() as u64),
+    };
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let server = Arc::new(ShareServer {
+        token,
+        expires_at: Instant::now() + ttl,
+        stop: stop.clone(),
+        tables,
+        access_log,
+        connections: Arc::new(AtomicUsize::new(0)),
+    });
+    thread::Builder::new()
+        .name(format!("share-{share_id}"))
+        .spawn(move || serve(listener, server, share_id))
+        .map_err(|error| format!("failed to start share server: {error}"))?;
+
+    registry.insert(session.clone(), stop);
+    Ok(session)
+}
+
+fn serve(listener: TcpListener, server: Arc<ShareServer>, share_id: String) {
+    info!("share server start share_id={}", share_id);
+    while !server.stop.load(Ordering::SeqCst) && Instant::now() < server.expires_at {
+        match listener.accept() {
+            Ok((stream, peer)) => {
+                debug!("share request share_id={} peer={}", share_id, peer);
+                let Some(slot) = ConnectionSlot::acquire(&server.connections) else {
+                    warn!("share busy share_id={} peer={}", share_id, peer);
+                    reject_busy(stream);
+                    continue;
+                };
+                let server = server.clone();
+                let spawned = thread::Builder::new()
+                    .name(format!("share-{share_id}-conn"))
+                    .spawn(move || {
+                        let _slot = slot;
+                        handle_connection(stream, peer, &server);
+                    });
+                if let Err(error) = spawned {
+                    warn!(
+                        "share connection thread failed share_id={} error={}",
+                        share_id, error
+                    );
+                }
+            }
+            Err(error) if error.kind() == ErrorKind::WouldBlock => thread::sleep(ACCEPT_POLL),
+            Err(error) => {
+                warn!("share accept failed share_id={} error={}", share_id, error);
+                thread::sleep(ACCEPT_POLL);
+            }
+        }
+    }
+    server.stop.store(true, Ordering::SeqCst);
+    info!("share server stop share_id={}", share_id);
+}
+
+/// One of the `MAX_CONNECTIONS` connections a share serves at once, released when
+/// the connection's thread drops it.
+struct ConnectionSlot(Arc<AtomicUsize>);
+
+impl ConnectionSlot {
+    fn acquire(connections: &Arc<AtomicUsize>) -> Option<Self> {
+        connections
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |count| {
+                (count < MAX_CONNECTIONS).then_some(count + 1)
+            })
+            .ok()?;
+        Some(Self(connections.clone()))
+    }
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Answers a connection over the limit from the accept loop, without reading it.
+fn reject_busy(mut stream: TcpStream) {
+    let _ = stream.set_nonblocking(false);
+    let _ = stream.set_write_timeout(Some(ACCEPT_POLL));
+    let body = "too many open connections to this share";
+    let _ = write!(
+        stream,
+        "HTTP/1.1 503 Service Unavailable\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nRetry-After: 1\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    );
+}
+
+/// Reads a connection until `deadline`, so a client sending one byte at a time
+/// cannot hold its thread for longer than `REQUEST_TIMEOUT`.
+struct DeadlineReader {
+    stream: TcpStream,
+    deadline: Instant,
+}
+
+impl Read for DeadlineReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let remaining = self.deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(io::Error::new(ErrorKind::TimedOut, "request timed out"));
+        }
+        self.stream.set_read_timeout(Some(remaining))?;
+        self.stream.read(buf)
+    }
+}
+
+struct Response {
+    status: &'static str,
+    content_type: &'static str,
+    body: String,
+}
+
+impl Response {
+    fn text(status: &'static str, body: &str) -> Self {
+        Self {
+            status,
+            content_type: "text/plain; charset=utf-8",
+            body: body.to_string(),
+        }
+    }
+
+    fn html(body: String) -> Self {
+        Self {
+            status: "200 OK",
+            content_type: "text/html; charset=utf-8",
+            body,
+        }
+    }
+
+    fn json(body: String) -> Self {
+        Self {
+            status: "200 OK",
+            content_type: "application/json",
+            body,
+        }
+    }
+}
+
+fn handle_connection(stream: TcpStream, peer: SocketAddr, server: &ShareServer) {
+    let started_at = Instant::now();
+    let _ = stream.set_nonblocking(false);
+    let _ = stream.set_write_timeout(Some(RESPONSE_TIMEOUT));
+    let mut reader = BufReader::new(
+        DeadlineReader {
+            stream,
+            deadline: started_at + REQUEST_TIMEOUT,
+        }
+        .take(MAX_REQUEST_BYTES),
+    );
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).is_err() {
+        return;
+    }
+    // Only the request line matters; the headers are read so the client sees a
+    // complete exchange.
+    let mut header = String::new();
+    while reader
+        .read_line(&mut header)
+        .map(|read| read > 2)
+        .unwrap_or(false)
+    {
+        header.clear();
+    }
+
+    let response = respond(server, &request_line);
+    let mut stream = reader.into_inner().into_inner().stream;
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.content_type,
+        response.body.len(),
+        response.body
+    );
+    let _ = stream.flush();
+    record_access(
+        server,
+        peer,
+        &request_line,
+        response.status,
+        started_at.elapsed(),
+    );
+}
+
+/// Command name and table of a share request, for the access log.
+fn request_summary(request_line: &str) -> (&'static str, Option<String>) {
+    let target = request_line.split_whitespace().nth(1).unwrap_or_default();
+    let Ok(url) = Url::parse(&format!("http://share{target}")) else {
+        return ("share_request", None);
+    };
+    let segments = url
+        .path_segments()
+        .map(|segments| {
+            segments
+                .filter(|segment| !segment.is_empty())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    match segments.as_slice() {
+        [] => ("share_index", None),
+        ["tables", name] => ("share_table", Some(percent_decode(name))),
+        ["api", "tables", name] => ("share_api", Some(percent_decode(name))),
+        _ => ("share_request", None),
+    }
+}
+
+/// Appends a served request to the access log. The share token is not recorded.
+fn record_access(
+    server: &ShareServer,
+    peer: SocketAddr,
+    request_line: &str,
+    status: &str,
+    elapsed: Duration,
+) {
+    let (command, table) = request_summary(request_line);
+    let error_code = match status.split_whitespace().next() {
+        Some("200") => None,
+        Some("403") => Some(ErrorCode::PermissionDenied),
+        Some("404") => Some(ErrorCode::NotFound),
+        Some("500") => Some(ErrorCode::Internal),
+        _ => Some(ErrorCode::InvalidArgument),
+    };
+    let entry = AccessLogEntryV1 {
+        at_ms: clock::now_unix_millis(),
+        transport: AccessTransportV1::Http,
+        client: peer.to_string(),
+        command: command.to_string(),
+        table,
+        ok: error_code.is_none(),
+        error_code,
+        elapsed_ms: elapsed.as_millis() as u64,
+    };
+    if let Ok(mut log) = server.access_log.lock() {
+        log.record(entry);
+    }
+}
+
+fn respond(server: &ShareServer, request_line: &str) -> Response {
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Response::text("400 Bad Request", "bad request");
+    };
+    if method != "GET" {
+        return Response::text("405 Method Not Allowed", "shares are read-only");
+    }
+    let Ok(url) = Url::parse(&format!("http://share{target}")) else {
+        return Response::text("400 Bad Request", "bad request");
+    };
+    let query = url.query_pairs().into_owned().collect::<HashMap<_, _>>();
+    if !token_matches(query.get("token"), &server.token) {
+        return Response::text("403 Forbidden", "invalid or missing share token");
+    }
+    if server.stop.load(Ordering::SeqCst) || Instant::now() >= server.expires_at {
+        return Response::text("410 Gone", "this share has expired");
+    }
+
+    let segments = url
+        .path_segments()
+        .map(|segments| {
+            segments
+                .filter(|segment| !segment.is_empty())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let (api, name) = match segments.as_slice() {
+        [] => return Response::html(index_page(server)),
+        ["tables", name] => (false, *name),
+        ["api", "tables", name] => (true, *name),
+        _ => return Response::text("404 Not Found", "not found"),
+    };
+    let name = percent_decode(name);
+    let Some(table) = server.tables.iter().find(|table| table.name == name) else {
+        return Response::text("404 Not Found", "table is not shared");
+    };
+
+    let offset = query
+        .get("offset")
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(0);
+    let limit = query
+        .get("limit")
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    let page = match tauri::async_runtime::block_on((table.load)(offset, limit)) {
+        Ok(page) => page,
+        Err(error) => {
+            warn!("share page failed table={} error={}", table.name, error);
+            return Response::text("500 Internal Server Error", &error);
+        }
+    };
+    if api {
+        match serde_json::to_string(&page) {
+            Ok(body) => Response::json(body),
+            Err(error) => Response::text("500 Internal Server Error", &error.to_string()),
+        }
+    } else {
+        Response::html(table_page(server, table, &page))
+    }
+}
+
+/// Compares the request's token in constant time, so response timing does not
+/// reveal how much of a guess was right.
+fn token_matches(given: Option<&String>, token: &str) -> bool {
+    given.is_some_and(|given| bool::from(given.as_bytes().ct_eq(token.as_bytes())))
+}
+
+fn percent_decode(segment: &str) -> String {
+    url::form_urlencoded::parse(format!("v={}", segment.replace('+', "%2B")).as_bytes())
+        .next()
+        .map(|(_, value)| value.into_owned())
+        .unwrap_or_else(|| segment.to_string())
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn table_link(server: &ShareServer, name: &str, offset: usize, limit: usize) -> String {
+    // `byte_serialize` escapes a literal `+` as `%2B`, so every `+` left is a space.
+    let name = url::form_urlencoded::byte_serialize(name.as_bytes())
+        .collect::<String>()
+        .replace('+', "%20");
+    let query = url::form_urlencoded::Serializer::new(String::new())
+        .append_pair("token", &server.token)
+        .append_pair("offset", &offset.to_string())
+        .append_pair("limit", &limit.to_string())
+        .finish();
+    escape_html(&format!("/tables/{name}?{query}"))
+}
+
+fn layout(title: &str, body: &str) -> String {
+    format!(
+        "<!doctype html><html><head><meta charset=\"utf-8\"><title>{title}</title>\
+         <style>body{{font-family:sans-serif;margin:24px}}table{{border-collapse:collapse}}\
+         td,th{{border:1px solid #ddd;padding:4px 8px;text-align:left;vertical-align:top}}\
+         th{{background:#f5f5f5}}</style></head><body>{body}</body></html>",
+        title = escape_html(title)
+    )
+}
+
+fn index_page(server: &ShareServer) -> String {
+    let items = server
+        .tables
+        .iter()
+        .map(|table| {
+            let filter = table
+                .filter
+                .as_deref()
+                .map(|filter| format!(" <small>({})</small>", escape_html(filter)))
+                .unwrap_or_default();
+            format!(
+                "<li><a href=\"{}\">{}</a>{}</li>",
+                table_link(server, &table.name, 0, DEFAULT_PAGE_SIZE),
+                escape_html(&table.name),
+                filter
+            )
+        })
+        .collect::<String>();
+    layout(
+        "LanceDB share",
+        &format!("<h1>Shared tables</h1><ul>{items}</ul>"),
+    )
+}
+
+fn cell(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(text) => escape_html(text),
+        other => escape_html(&other.to_string()),
+    }
+}
+
+fn table_page(server: &ShareServer, table: &SharedTable, page: &SharePage) -> String {
+    let header = page
+        .columns
+        .iter()
+        .map(|column| format!("<th>{}</th>", escape_html(column)))
+        .collect::<String>();
+    let rows = page
+        .rows
+        .iter()
+        .map(|row| {
+            let cells = page
+                .columns
+                .iter()
+                .map(|column| format!("<td>{}</td>", cell(&row[column])))
+                .collect::<String>();
+            format!("<tr>{cells}</tr>")
+        })
+        .collect::<String>();
+    let mut links = vec![format!(
+        "<a href=\"{}\">All tables</a>",
+        escape_html(&format!("/?token={}", server.token))
+    )];
+    if page.offset > 0 {
+        links.push(format!(
+            "<a href=\"{}\">Previous</a>",
+            table_link(
+                server,
+                &table.name,
+                page.offset.saturating_sub(page.limit),
+                page.limit
+            )
+        ));
+    }
+    if let Some(next_offset) = page.next_offset {
+        links.push(format!(
+            "<a href=\"{}\">Next</a>",
+            table_link(server, &table.name, next_offset, page.limit)
+        ));
+    }
+    layout(
+        &table.name,
+        &format!(
+            "<h1>{}</h1><p>Rows {}-{}</p><p>{}</p><table><tr>{header}</tr>{rows}</table>",
+            escape_html(&table.name),
+            page.offset + 1,
+            page.offset + page.rows.len(),
+            links.join(" | ")
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server() -> ShareServer {
+        let load: PageLoader = Arc::new(|offset, limit| {
+            Box::pin(async move {
+                Ok(SharePage {
+                    columns: vec!["id".to_string()],
+                    rows: vec![serde_json::json!({ "id": offset })],
+                    offset,
+                    limit,
+                    next_offset: None,
+                })
+            })
+        });
+        ShareServer {
+            token: "secret".to_string(),
+            expires_at: Instant::now() + Duration::from_secs(60),
+            stop: Arc::new(AtomicBool::new(false)),
+            tables: vec![SharedTable {
+                name: "my items".to_string(),
+                filter: None,
+                load,
+            }],
+            access_log: Arc::new(Mutex::new(AccessLog::new())),
+            connections: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    #[test]
+    fn requires_the_share_token() {
+        let server = server();
+        assert_eq!(respond(&server, "GET / HTTP/1.1").status, "403 Forbidden");
+        assert_eq!(
+            respond(&server, "GET /?token=wrong HTTP/1.1").status,
+            "403 Forbidden"
+        );
+        assert_eq!(
+            respond(&server, "POST /?token=secret HTTP/1.1").status,
+            "405 Method Not Allowed"
+        );
+        let index = respond(&server, "GET /?token=secret HTTP/1.1");
+        assert_eq!(index.status, "200 OK");
+        assert!(index
+            .body
+            .contains("/tables/my%20items?token=secret&amp;offset=0&amp;limit=100"));
+        assert_eq!(
+            request_summary("GET /api/tables/my%20items?token=secret HTTP/1.1"),
+            ("share_api", Some("my items".to_string()))
+        );
+    }
+
+    #[test]
+    fn links_tables_with_reserved_characters() {
+        let server = server();
+        for name in ["a+b c", "50%/x?y#z", "价格"] {
+            let link = table_link(&server, name, 0, 10).replace("&amp;", "&");
+            assert!(link.starts_with("/tables/"), "{link}");
+            assert_eq!(
+                request_summary(&format!("GET {link} HTTP/1.1")),
+                ("share_table", Some(name.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn serves_only_shared_tables() {
+        let server = server();
+        let page = respond(
+            &server,
+            "GET /api/tables/my%20items?token=secret&offset=5 HTTP/1.1",
+        );
+        assert_eq!(page.status, "200 OK");
+        let page: serde_json::Value = serde_json::from_str(&page.body).expect("json");
+        assert_eq!(page["rows"][0]["id"], 5);
+        assert_eq!(page["limit"], DEFAULT_PAGE_SIZE);
+        assert_eq!(
+            respond(&server, "GET /tables/other?token=secret HTTP/1.1").status,
+            "404 Not Found"
+        );
+
+        server.stop.store(true, Ordering::SeqCst);
+        assert_eq!(
+            respond(&server, "GET /?token=secret HTTP/1.1").status,
+            "410 Gone"
+        );
+    }
+
+    #[test]
+    fn limits_concurrent_connections() {
+        let connections = Arc::new(AtomicUsize::new(0));
+        let slots = (0..MAX_CONNECTIONS)
+            .map(|_| ConnectionSlot::acquire(&connections).expect("free slot"))
+            .collect::<Vec<_>>();
+        assert!(ConnectionSlot::acquire(&connections).is_none());
+        drop(slots);
+        assert_eq!(connections.load(Ordering::SeqCst), 0);
+        assert!(ConnectionSlot::acquire(&connections).is_some());
+        assert!(!token_matches(Some(&"secre".to_string()), "secret"));
+        assert!(!token_matches(None, "secret"));
+    }
+
+    #[test]
+    fn escapes_cell_values() {
+        assert_eq!(
+            cell(&serde_json::json!("<b>&</b>")),
+            "&lt;b&gt;&amp;&lt;/b&gt;"
+        );
+        assert_eq!(cell(&serde_json::Value::Null), "");
+        assert_eq!(cell(&serde_json::json!([1, 2])), "[1,2]");
+    }
+}
@@ -0,0 +1,104 @@
+use std::sync::atomic::Ordering;
+use std::time::{Duration, Instant};
+
+use log::{info, warn};
+
+use crate::state::AppState;
+
+/// How long the exit hook waits for cancelled jobs to stop before exiting anyway.
+pub const DRAIN_TIMEOUT: Duration = Duration::from_secs(10);
+const DRAIN_POLL: Duration = Duration::from_millis(50);
+
+pub const SHUTDOWN_MESSAGE: &str = "cancelled: application is shutting down";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ShutdownReport {
+    pub cancelled_jobs: usize,
+    /// Jobs still executing when the drain timeout elapsed.
+    pub abandoned_jobs: usize,
+    pub closed_connections: usize,
+    pub closed_tables: usize,
+}
+
+/// Flags the state as shutting down. Returns false when shutdown already began, so the
+/// exit hook runs once even though `exit` raises another exit request.
+pub fn begin(state: &AppState) -> bool {
+    !state.shutting_down.swap(true, Ordering::SeqCst)
+}
+
+pub fn is_shutting_down(state: &AppState) -> bool {
+    state.shutting_down.load(Ordering::SeqCst)
+}
+
+fn active_jobs(state: &AppState) -> usize {
+    state
+        .jobs
+        .lock()
+        .map(|jobs| jobs.active_count())
+        .unwrap_or(0)
+}
+
+/// Cancels running jobs, waits up to `drain_timeout` for their tasks to stop at the
+/// next chunk boundary, then drops cached pages and every open connection.
+///
+/// Schedules, masking rules, retention policies and log settings are written through
+/// on every change, so there is nothing buffered left to flush here.
+pub async fn shutdown(state: &AppState, drain_timeout: Duration) -> ShutdownReport {
+    let started_at = Instant::now();
+    begin(state);
+    info!("shutdown start");
+
+    let cancelled_jobs = match state.jobs.lock() {
+        Ok(mut jobs) => jobs.cancel_running(SHUTDOWN_MESSAGE),
+        Err(_) => {
+            warn!("shutdown failed to lock job registry");
+            0
+        }
+    };
+
+    let deadline = started_at + drain_timeout;
+    let mut abandoned_jobs = active_jobs(state);
+    while abandoned_jobs > 0 && Instant::now() < deadline {
+        tokio::time::sleep(DRAIN_POLL).await;
+        abandoned_jobs = active_jobs(state);
+    }
+    if abandoned_jobs > 0 {
+        warn!(
+            "shutdown drain timed out abandoned_jobs={} timeout_ms={}",
+            abandoned_jobs,
+            drain_timeout.as_millis()
+        );
+    }
+
+    if let Ok(mut prefetch) = state.prefetch.lock() {
+        prefetch.clear();
+    }
+    if let Ok(mut warm_scans) = state.warm_scans.lock() {
+        warm_scans.clear();
+    }
+    if let Ok(mut shares) = state.shares.lock() {
+        shares.stop_all();
+    }
+    let (closed_connections, closed_tables) = match state.connections.lock() {
+        Ok(mut connections) => connections.close_all(),
+        Err(_) => {
+            warn!("shutdown failed to lock connection manager");
+            (0, 0)
+        }
+    };
+
+    info!(
+        "shutdown ok cancelled_jobs={} abandoned_jobs={} connections={} tables={} elapsed_ms={}",
+        cancelled_jobs,
+        abandoned_jobs,
+        closed_connections,
+        closed_tables,
+        started_at.elapsed().as_millis()
+    );
+    ShutdownReport {
+        cancelled_jobs,
+        abandoned_jobs,
+        closed_connections,
+        closed_tables,
+    }
+}
@@ -0,0 +1,282 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use lancedb::Table;
+use log::{debug, error, warn};
+use serde::{Deserialize, Serialize};
+
+use crate::ipc::v1::TableStatsSnapshotV1;
+use crate::services::backend::{LanceTableBackend, TableBackend};
+use crate::services::{clock, shutdown, store};
+use crate::state::AppState;
+
+pub const STATS_HISTORY_FILE: &str = "stats_history.json";
+
+/// Minimum time between two snapshots of the same table.
+pub const SNAPSHOT_INTERVAL_MS: u64 = 60 * 60 * 1000;
+/// Oldest snapshots are dropped past this, about three months of hourly points.
+const MAX_SNAPSHOTS_PER_TABLE: usize = 2_160;
+const SNAPSHOT_TICK: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StatsHistoryEntry {
+    connection_uri: String,
+    table_name: String,
+    snapshots: Vec<TableStatsSnapshotV1>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StatsHistoryFile {
+    #[serde(default)]
+    tables: Vec<StatsHistoryEntry>,
+}
+
+/// Row count, size and version snapshots keyed by connection URI and table name,
+/// persisted to `stats_history.json` in the app data directory when one is configured.
+#[derive(Default)]
+pub struct StatsHistoryStore {
+    path: Option<PathBuf>,
+    entries: Vec<StatsHistoryEntry>,
+}
+
+impl StatsHistoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load(path: PathBuf) -> Self {
+        let entries = match store::load_json::<StatsHistoryFile>(&path) {
+            Ok(file) => file.tables,
+            Err(error) => {
+                warn!(
+                    "failed to load stats history path={:?} error={}",
+                    path, error
+                );
+                Vec::new()
+            }
+        };
+        Self {
+            path: Some(path),
+            entries,
+        }
+    }
+
+    fn entry(&self, connection_uri: &str, table_name: &str) -> Option<&StatsHistoryEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.connection_uri == connection_uri && entry.table_name == table_name)
+    }
+
+    /// Snapshots of a table taken at or after `since_ms`, oldest first.
+    pub fn history(
+        &self,
+        connection_uri: &str,
+        table_name: &str,
+        since_ms: Option<u64>,
+    ) -> Vec<TableStatsSnapshotV1> {
+        let since_ms = since_ms.unwrap_or(0);
+        self.entry(connection_uri, table_name)
+            .map(|entry| {
+                entry
+                    .snapshots
+                    .iter()
+                    .filter(|snapshot| snapshot.taken_at_ms >= since_ms)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Whether a table has no snapshot within [`SNAPSHOT_INTERVAL_MS`] of `now_ms`.
+    pub fn is_due(&self, connection_uri: &str, table_name: &str, now_ms: u64) -> bool {
+        self.entry(connection_uri, table_name)
+            .and_then(|entry| entry.snapshots.last())
+            .is_none_or(|last| now_ms.saturating_sub(last.taken_at_ms) >= SNAPSHOT_INTERVAL_MS)
+    }
+
+    /// Appends a snapshot unless one was taken within the interval. Returns whether
+    /// it was recorded.
+    pub fn record(
+        &mut self,
+        connection_uri: &str,
+        table_name: &str,
+        snapshot: TableStatsSnapshotV1,
+    ) -> Result<bool, String> {
+        if !self.is_due(connection_uri, table_name, snapshot.taken_at_ms) {
+            return Ok(false);
+        }
+        let index = match self.entries.iter().position(|entry| {
+            entry.connection_uri == connection_uri && entry.table_name == table_name
+        }) {
+            Some(index) => index,
+            None => {
+                self.entries.push(StatsHistoryEntry {
+                    connection_uri: connection_uri.to_string(),
+                    table_name: table_name.to_string(),
+                    snapshots: Vec::new(),
+                });
+                self.entries.len() - 1
+            }
+        };
+        let snapshots = &mut self.entries[index].snapshots;
+        snapshots.push(snapshot);
+        if snapshots.len() > MAX_SNAPSHOTS_PER_TABLE {
+            let excess = snapshots.len() - MAX_SNAPSHOTS_PER_TABLE;
+            snapshots.drain(..excess);
+        }
+        self.persist()?;
+        Ok(true)
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        let Some(path) = self.path.as_ref() else {
+            return Ok(());
+        };
+        store::save_json(
+            path,
+            &StatsHistoryFile {
+                tables: self.entries.clone(),
+            },
+        )
+    }
+}
+
+/// Reads the current stats of `table` and records them when a snapshot is due. The
+/// row count also refreshes the row count cache.
+pub async fn snapshot_if_due(
+    state: &AppState,
+    connection_uri: &str,
+    table: Table,
+    now_ms: u64,
+) -> Result<bool, String> {
+    let table_name = table.name().to_string();
+    let due = state
+        .stats_history
+        .lock()
+        .map_err(|_| "failed to lock stats history".to_string())?
+        .is_due(connection_uri, &table_name, now_ms);
+    if !due {
+        return Ok(false);
+    }
+
+    let stats = LanceTableBackend::new(table).storage_stats().await?;
+    if let Ok(mut cache) = state.row_counts.lock() {
+        cache.record(connection_uri, &table_name, stats.version, stats.row_count);
+    }
+    state
+        .stats_history
+        .lock()
+        .map_err(|_| "failed to lock stats history".to_string())?
+        .record(
+            connection_uri,
+            &table_name,
+            TableStatsSnapshotV1 {
+                taken_at_ms: now_ms,
+                row_count: stats.row_count,
+                size_bytes: stats.size_bytes,
+                version: stats.version,
+            },
+        )
+}
+
+/// Snapshots every open table that is due. Tables opened through several handles
+/// are recorded once.
+pub async fn snapshot_open_tables(state: &AppState, now_ms: u64) -> usize {
+    let tables: Vec<(String, Table)> = match state.connections.lock() {
+        Ok(manager) => manager
+            .table_ids()
+            .into_iter()
+            .filter_map(|table_id| {
+                Some((
+                    manager.get_table_connection_uri(&table_id)?,
+                    manager.get_table(&table_id)?,
+                ))
+            })
+            .collect(),
+        Err(_) => {
+            error!("stats history failed to lock connection manager");
+            return 0;
+        }
+    };
+
+    let mut recorded = 0;
+    for (connection_uri, table) in tables {
+        let table_name = table.name().to_string();
+        match snapshot_if_due(state, &connection_uri, table, now_ms).await {
+            Ok(true) => recorded += 1,
+            Ok(false) => {}
+            Err(error) => warn!(
+                "stats history snapshot failed uri={} table={} error={}",
+                connection_uri, table_name, error
+            ),
+        }
+    }
+    if recorded > 0 {
+        debug!("stats history recorded snapshots={}", recorded);
+    }
+    recorded
+}
+
+/// Snapshots open tables for as long as the app is running.
+pub async fn run_loop(state: &AppState) {
+    let mut interval = tokio::time::interval(SNAPSHOT_TICK);
+    loop {
+        interval.tick().await;
+        if shutdown::is_shutting_down(state) {
+            break;
+        }
+        snapshot_open_tables(state, clock::now_unix_millis()).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(taken_at_ms: u64, row_count: u64) -> TableStatsSnapshotV1 {
+        TableStatsSnapshotV1 {
+            taken_at_ms,
+            row_count,
+            size_bytes: row_count * 100,
+            version: row_count,
+        }
+    }
+
+    #[test]
+    fn records_one_snapshot_per_interval() {
+        let mut store = StatsHistoryStore::new();
+        assert!(store.record("mem://", "items", snapshot(0, 1)).unwrap());
+        assert!(!store.record("mem://", "items", snapshot(1_000, 2)).unwrap());
+        assert!(store
+            .record("mem://", "items", snapshot(SNAPSHOT_INTERVAL_MS, 3))
+            .unwrap());
+        assert!(store.record("mem://", "other", snapshot(1_000, 4)).unwrap());
+
+        let history = store.history("mem://", "items", None);
+        assert_eq!(
+            history.iter().map(|s| s.row_count).collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+        assert_eq!(store.history("mem://", "items", Some(1)).len(), 1);
+        assert!(store.history("mem://", "missing", None).is_empty());
+    }
+
+    #[test]
+    fn drops_the_oldest_snapshots_past_the_limit() {
+        let mut store = StatsHistoryStore::new();
+        for index in 0..MAX_SNAPSHOTS_PER_TABLE as u64 + 5 {
+            store
+                .record(
+                    "mem://",
+                    "items",
+                    snapshot(index * SNAPSHOT_INTERVAL_MS, index),
+                )
+                .unwrap();
+        }
+        let history = store.history("mem://", "items", None);
+        assert_eq!(history.len(), MAX_SNAPSHOTS_PER_TABLE);
+        assert_eq!(history[0].row_count, 5);
+    }
+}
@@ -0,0 +1,33 @@
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Loads a JSON document from the app data directory, falling back to the default
+/// value when the file does not exist yet.
+pub fn load_json<T: DeserializeOwned + Default>(path: &Path) -> Result<T, String> {
+    if !path.exists() {
+        return Ok(T::default());
+    }
+    let file = File::open(path).map_err(|error| error.to_string())?;
+    serde_json::from_reader(BufReader::new(file))
+        .map_err(|error| format!("invalid json in {}: {error}", path.display()))
+}
+
+/// Writes a JSON document through a temporary file so a crash never leaves a
+/// half-written file behind.
+pub fn save_json<T: Serialize>(path: &Path, value: &T) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|error| error.to_string())?;
+    }
+    let temp_path = path.with_extension("json.tmp");
+    {
+        let file = File::create(&temp_path).map_err(|error| error.to_string())?;
+        let mut writer = BufWriter::new(file);
+        serde_json::to_writer_pretty(&mut writer, value).map_err(|error| error.to_string())?;
+        writer.flush().map_err(|error| error.to_string())?;
+    }
+    fs::rename(&temp_path, path).map_err(|error| error.to_string())
+}
@@ -0,0 +1,168 @@
+use chrono::{DateTime, Duration, Utc};
+
+fn unit(name: &str) -> Option<Duration> {
+    match name {
+        "minute" | "minutes" => Some(Duration::minutes(1)),
+        "hour" | "hours" => Some(Duration::hours(1)),
+        "day" | "days" => Some(Duration::days(1)),
+        "week" | "weeks" => Some(Duration::weeks(1)),
+        _ => None,
+    }
+}
+
+/// The range a helper stands for and whether its end is inclusive, or `None` when
+/// the name is not a helper. Days are UTC days; `last_<n>_<unit>` ends at `now`.
+fn range(name: &str, now: DateTime<Utc>) -> Option<(DateTime<Utc>, DateTime<Utc>, bool)> {
+    let name = name.to_ascii_lowercase();
+    let midnight = now.date_naive().and_hms_opt(0, 0, 0)?.and_utc();
+    match name.as_str() {
+        "today" => return Some((midnight, midnight + Duration::days(1), false)),
+        "yesterday" => return Some((midnight - Duration::days(1), midnight, false)),
+        _ => {}
+    }
+    let rest = name.strip_prefix("last_")?;
+    let (count, unit_name) = rest.split_once('_')?;
+    let count = count.parse::<i32>().ok().filter(|count| *count > 0)?;
+    let span = unit(unit_name)?.checked_mul(count)?;
+    Some((now.checked_sub_signed(span)?, now, true))
+}
+
+fn timestamp_literal(value: DateTime<Utc>) -> String {
+    format!("TIMESTAMP '{}'", value.format("%Y-%m-%dT%H:%M:%S%.6fZ"))
+}
+
+fn is_identifier_char(character: char) -> bool {
+    character.is_alphanumeric() || character == '_'
+}
+
+/// Reads `( column )` after a helper name, where the column is a dotted path or a
+/// backtick-quoted name. Returns the column as written and the bytes consumed.
+fn call_argument(rest: &str) -> Option<(&str, usize)> {
+    let open = rest.len() - rest.trim_start().len();
+    let after_open = rest[open..].strip_prefix('(')?;
+    let start = rest.len() - after_open.trim_start().len();
+    let argument = &rest[start..];
+    let length = if let Some(quoted) = argument.strip_prefix('`') {
+        quoted.find('`')? + 2
+    } else {
+        argument
+            .find(|character: char| !is_identifier_char(character) && character != '.')
+            .unwrap_or(argument.len())
+    };
+    if length == 0 {
+        return None;
+    }
+    let column = &rest[start..start + length];
+    let after_column = &rest[start + length..];
+    let close = after_column.len() - after_column.trim_start().len();
+    after_column[close..].strip_prefix(')')?;
+    Some((column, start + length + close + 1))
+}
+
+/// Replaces relative time helpers such as `last_7_days(created_at)`, `today(col)`
+/// and `yesterday(col)` outside string literals and quoted names with timestamp
+/// comparisons against `now`. Anything else is left as written, so saved filters
+/// keep the helper and get fresh bounds each time they run.
+pub fn expand_at(filter: &str, now: DateTime<Utc>) -> String {
+    let mut expanded = String::with_capacity(filter.len());
+    let mut index = 0;
+    while let Some(character) = filter[index..].chars().next() {
+        let rest = &filter[index..];
+        if character == '\'' || character == '`' {
+            let end = rest[1..]
+                .find(character)
+                .map(|end| end + 2)
+                .unwrap_or(rest.len());
+            expanded.push_str(&rest[..end]);
+            index += end;
+            continue;
+        }
+        if !is_identifier_char(character) {
+            expanded.push(character);
+            index += character.len_utf8();
+            continue;
+        }
+        let word_length = rest
+            .find(|character: char| !is_identifier_char(character))
+            .unwrap_or(rest.len());
+        let word = &rest[..word_length];
+        match range(word, now).zip(call_argument(&rest[word_length..])) {
+            Some(((start, end, inclusive), (column, consumed))) => {
+                expanded.push_str(&format!(
+                    "({column} >= {} AND {column} {} {})",
+                    timestamp_literal(start),
+                    if inclusive { "<=" } else { "<" },
+                    timestamp_literal(end)
+                ));
+                index += word_length + consumed;
+            }
+            None => {
+                expanded.push_str(word);
+                index += word_length;
+            }
+        }
+    }
+    expanded
+}
+
+pub fn expand(filter: &str) -> String {
+    expand_at(filter, Utc::now())
+}
+
+/// Whether `filter` calls a time helper, so its result changes with the clock and
+/// must not be served from a cache keyed by the filter text.
+pub fn uses_helpers(filter: &str) -> bool {
+    expand(filter) != filter
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn expands_helpers_to_timestamp_ranges() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 16, 12, 30, 0).unwrap();
+        assert_eq!(
+            expand_at("id > 3 AND last_7_days(created_at)", now),
+            "id > 3 AND (created_at >= TIMESTAMP '2026-10-09T12:30:00.000000Z' \
+             AND created_at <= TIMESTAMP '2026-10-16T12:30:00.000000Z')"
+        );
+        assert_eq!(
+            expand_at("Yesterday( `Created At` )", now),
+            "(`Created At` >= TIMESTAMP '2026-10-15T00:00:00.000000Z' \
+             AND `Created At` < TIMESTAMP '2026-10-16T00:00:00.000000Z')"
+        );
+        assert_eq!(
+            expand_at("today(meta.seen)", now),
+            "(meta.seen >= TIMESTAMP '2026-10-16T00:00:00.000000Z' \
+             AND meta.seen < TIMESTAMP '2026-10-17T00:00:00.000000Z')"
+        );
+        assert_eq!(
+            expand_at("last_2_hours(ts)", now),
+            "(ts >= TIMESTAMP '2026-10-16T10:30:00.000000Z' \
+             AND ts <= TIMESTAMP '2026-10-16T12:30:00.000000Z')"
+        );
+        assert!(uses_helpers("id > 3 AND today(created_at)"));
+        assert!(!uses_helpers(
+            "name = 'today(created_at)' AND today_count > 1"
+        ));
+    }
+
+    #[test]
+    fn leaves_literals_and_other_calls_alone() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 16, 12, 30, 0).unwrap();
+        for filter in [
+            "text = 'today(created_at)'",
+            "`today(x)` = 1",
+            "last_0_days(created_at)",
+            "last_7_years(created_at)",
+            "my_today(created_at)",
+            "today()",
+            "lower(text) = 'é'",
+        ] {
+            assert_eq!(expand_at(filter, now), filter);
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use std::collections::HashSet;
+
+use arrow_array::RecordBatch;
+use arrow_cast::CastOptions;
+use arrow_schema::Schema;
+
+use crate::ipc::v1::UpdateColumnInputV1;
+
+pub const DEFAULT_SAMPLE_ROWS: usize = 10;
+pub const MAX_SAMPLE_ROWS: usize = 100;
+
+/// An update expression and the output column its values are read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreviewUpdate {
+    pub column: String,
+    pub expr: String,
+    pub alias: String,
+}
+
+/// Trims the updates and checks that each names an existing column once.
+pub fn validate_updates(
+    updates: &[UpdateColumnInputV1],
+    schema: &Schema,
+) -> Result<Vec<PreviewUpdate>, String> {
+    if updates.is_empty() {
+        return Err("no updates specified".to_string());
+    }
+    let mut seen = HashSet::new();
+    updates
+        .iter()
+        .enumerate()
+        .map(|(index, update)| {
+            let column = update.column.trim();
+            let expr = update.expr.trim();
+            if column.is_empty() || expr.is_empty() {
+                return Err("update column and expression cannot be empty".to_string());
+            }
+            schema
+                .field_with_name(column)
+                .map_err(|_| format!("column not found: {column}"))?;
+            if !seen.insert(column) {
+                return Err(format!("column {column} is updated more than once"));
+            }
+            Ok(PreviewUpdate {
+                column: column.to_string(),
+                expr: expr.to_string(),
+                alias: format!("__preview_update_{index}"),
+            })
+        })
+        .collect()
+}
+
+/// Reads every table column, followed by one aliased expression per update.
+pub fn projection(schema: &Schema, updates: &[PreviewUpdate]) -> Vec<String> {
+    schema
+        .fields()
+        .iter()
+        .map(|field| field.name().clone())
+        .chain(
+            updates
+                .iter()
+                .map(|update| format!("({}) AS {}", update.expr, update.alias)),
+        )
+        .collect()
+}
+
+/// Splits a batch read with `projection` into the rows before and after the update,
+/// casting each expression result to the type of the column it replaces. Values
+/// that do not fit the column are reported instead of turning into nulls.
+pub fn split_batch(
+    batch: &RecordBatch,
+    schema: &Schema,
+    updates: &[PreviewUpdate],
+) -> Result<(RecordBatch, RecordBatch), String> {
+    let indices = (0..schema.fields().len()).collect::<Vec<_>>();
+    let before = batch.project(&indices).map_err(|error| error.to_string())?;
+
+    let options = CastOptions {
+        safe: false,
+        ..Default::default()
+    };
+    let mut columns = before.columns().to_vec();
+    for update in updates {
+        let index = before
+            .schema()
+            .index_of(&update.column)
+            .map_err(|_| format!("column not found: {}", update.column))?;
+        let value = batch
+            .column_by_name(&update.alias)
+            .ok_or_else(|| format!("update expression missing from result: {}", update.expr))?;
+        let target = before.schema().field(index).data_type().clone();
+        columns[index] =
+            arrow_cast::cast_with_options(value, &target, &options).map_err(|error| {
+                format!(
+                    "cannot write `{}` into column {} of type {target:?}: {error}",
+                    update.expr, update.column
+                )
+            })?;
+    }
+    let after = RecordBatch::try_new(before.schema(), columns)
+        .map_err(|error| format!("update produces invalid values: {error}"))?;
+    Ok((before, after))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::{Array, Float64Array, Int32Array, StringArray};
+    use arrow_schema::{DataType, Field};
+
+    use super::*;
+
+    fn update(column: &str, expr: &str) -> UpdateColumnInputV1 {
+        UpdateColumnInputV1 {
+            column: column.to_string(),
+            expr: expr.to_string(),
+        }
+    }
+
+    fn table_schema() -> Schema {
+        Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("price", DataType::Int32, true),
+        ])
+    }
+
+    #[test]
+    fn validates_and_projects_updates() {
+        let schema = table_schema();
+        let updates =
+            validate_updates(&[update(" price ", " price * 1.1 ")], &schema).expect("updates");
+        assert_eq!(
+            projection(&schema, &updates),
+            vec!["id", "price", "(price * 1.1) AS __preview_update_0"]
+        );
+
+        assert!(validate_updates(&[], &schema).is_err());
+        assert!(validate_updates(&[update("missing", "1")], &schema).is_err());
+        assert!(validate_updates(&[update("price", " ")], &schema).is_err());
+        assert!(validate_updates(&[update("price", "1"), update("price", "2")], &schema).is_err());
+    }
+
+    #[test]
+    fn splits_rows_and_casts_to_column_type() {
+        let schema = table_schema();
+        let updates =
+            validate_updates(&[update("price", "price * 1.1")], &schema).expect("updates");
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("id", DataType::Int32, false),
+                Field::new("price", DataType::Int32, true),
+                Field::new("__preview_update_0", DataType::Float64, true),
+            ])),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2])),
+                Arc::new(Int32Array::from(vec![Some(10), None])),
+                Arc::new(Float64Array::from(vec![Some(11.0), None])),
+            ],
+        )
+        .expect("batch");
+
+        let (before, after) = split_batch(&batch, &schema, &updates).expect("split");
+        assert_eq!(before.num_columns(), 2);
+        assert_eq!(after.schema(), before.schema());
+        let prices = after
+            .column(1)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .expect("prices");
+        assert_eq!(prices.value(0), 11);
+        assert!(prices.is_null(1));
+
+        let text = validate_updates(&[update("price", "'x'")], &schema).expect("updates");
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("id", DataType::Int32, false),
+                Field::new("price", DataType::Int32, true),
+                Field::new("__preview_update_0", DataType::Utf8, true),
+            ])),
+            vec![
+                Arc::new(Int32Array::from(vec![1])),
+                Arc::new(Int32Array::from(vec![Some(10)])),
+                Arc::new(StringArray::from(vec![Some("x")])),
+            ],
+        )
+        .expect("batch");
+        assert!(split_batch(&batch, &schema, &text).is_err());
+    }
+}
@@ -1,6 +1,8 @@
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufRead, BufReader, BufWriter, Cursor, Write};
-use std::sync::Arc;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
 use arrow_array::{
@@ -8,110 +10,329 @@ use arrow_array::{
     Int16Array, Int32Array, Int64Array, Int8Array, LargeStringArray, RecordBatch,
     RecordBatchIterator, StringArray, UInt16Array, UInt32Array, UInt64Array, UInt8Array,
 };
+use arrow_csv::reader::Format as CsvFormat;
 use arrow_csv::{ReaderBuilder as CsvReaderBuilder, WriterBuilder as CsvWriterBuilder};
 use arrow_ipc::writer::StreamWriter;
-use arrow_json::{ArrayWriter, ReaderBuilder};
-use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use arrow_json::ReaderBuilder;
+use arrow_schema::{DataType, Field, IntervalUnit, Schema, SchemaRef, TimeUnit};
 use base64::{engine::general_purpose, Engine as _};
-use futures_util::TryStreamExt;
+use futures_util::{FutureExt, StreamExt, TryStreamExt};
 use lancedb::index::scalar::{
-    BTreeIndexBuilder, BitmapIndexBuilder, FtsIndexBuilder, FullTextSearchQuery,
-    LabelListIndexBuilder,
+    BTreeIndexBuilder, BitmapIndexBuilder, FullTextSearchQuery, LabelListIndexBuilder,
 };
 use lancedb::index::vector::{
     IvfFlatIndexBuilder, IvfHnswPqIndexBuilder, IvfHnswSqIndexBuilder, IvfPqIndexBuilder,
     IvfRqIndexBuilder, IvfSqIndexBuilder,
 };
 use lancedb::index::{Index, IndexType};
-use lancedb::query::{ExecutableQuery, QueryBase, Select};
+use lancedb::query::{ExecutableQuery, QueryBase, Select, VectorQuery};
 use lancedb::rerankers::rrf::RRFReranker;
 use lancedb::rerankers::NormalizeMethod;
 use lancedb::table::{
     AddDataMode, ColumnAlteration, CompactionOptions, Duration as LanceDuration,
-    NewColumnTransform, OptimizeAction,
+    NewColumnTransform, OptimizeAction, OptimizeOptions,
 };
 use lancedb::DistanceType;
 use lancedb::Table;
 use log::{debug, error, info, trace, warn};
 use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
 use parquet::arrow::ArrowWriter;
+use uuid::Uuid;
 
-use crate::domain::connect::infer_backend_kind;
+use crate::domain::connect::{infer_backend_kind, local_path, split_dataset_uri, BackendKind};
+use crate::extensions;
 use crate::ipc::v1::{
-    AddColumnsRequestV1, AddColumnsResponseV1, AlterColumnsRequestV1, AlterColumnsResponseV1,
-    ArrowChunk, AuthDescriptor, CheckoutTableLatestRequestV1, CheckoutTableLatestResponseV1,
-    CheckoutTableVersionRequestV1, CheckoutTableVersionResponseV1, CloneTableRequestV1,
-    CloneTableResponseV1, ColumnAlterationInput, CombinedSearchRequestV1, ConnectRequestV1,
-    ConnectResponseV1, CreateIndexRequestV1, CreateIndexResponseV1, CreateTableRequestV1,
-    CreateTableResponseV1, DataChunk, DataFileFormatV1, DataFormat, DeleteRowsRequestV1,
-    DeleteRowsResponseV1, DisconnectRequestV1, DisconnectResponseV1, DistanceTypeV1,
-    DropColumnsRequestV1, DropColumnsResponseV1, DropIndexRequestV1, DropIndexResponseV1,
-    DropTableRequestV1, DropTableResponseV1, ErrorCode, ExportDataRequestV1, ExportDataResponseV1,
-    FieldDataType, FtsSearchRequestV1, GetSchemaRequestV1, GetTableVersionRequestV1,
-    GetTableVersionResponseV1, ImportDataRequestV1, ImportDataResponseV1, IndexDefinitionV1,
-    IndexTypeV1, JsonChunk, ListIndexesRequestV1, ListIndexesResponseV1, ListTablesRequestV1,
-    ListTablesResponseV1, ListVersionsRequestV1, ListVersionsResponseV1, OpenTableRequestV1,
-    OptimizeActionV1, OptimizeTableRequestV1, OptimizeTableResponseV1, QueryFilterRequestV1,
-    QueryResponseV1, RenameTableRequestV1, RenameTableResponseV1, ResultEnvelope, ScanRequestV1,
-    ScanResponseV1, SchemaDefinition, SchemaDefinitionInput, SchemaField, SchemaFieldInput,
-    TableHandle, TableInfo, UpdateRowsRequestV1, UpdateRowsResponseV1, VectorSearchRequestV1,
-    VersionInfoV1, WriteDataMode, WriteRowsRequestV1, WriteRowsResponseV1,
+    AckScanStreamRequestV1, ActiveQueryV1, AddColumnsRequestV1, AddColumnsResponseV1,
+    AlterColumnsRequestV1, AlterColumnsResponseV1, AppConfigV1, ArrowChunk, AuthDescriptor,
+    BackupModeV1, BackupTableRequestV1, BackupTableResponseV1, BundleIndexV1, BundleManifestV1,
+    CancelJobRequestV1, CancelScanStreamRequestV1, CheckExportTargetRequestV1,
+    CheckExportTargetResponseV1, CheckoutTableLatestRequestV1, CheckoutTableLatestResponseV1,
+    CheckoutTableVersionRequestV1, CheckoutTableVersionResponseV1, ChunkSplitV1,
+    CloneTableRequestV1, CloneTableResponseV1, ColumnAlterationInput, CombinedSearchRequestV1,
+    CompareQueryVersionsRequestV1, CompareQueryVersionsResponseV1, CompletionMetadataRequestV1,
+    CompletionMetadataResponseV1, ConfigResponseV1, ConnectProfile, ConnectRequestV1,
+    ConnectResponseV1, ConnectionHealthV1, ConnectionOverviewV1, CreateIndexRequestV1,
+    CreateIndexResponseV1, CreateTableOptionsV1, CreateTableRequestV1, CreateTableResponseV1,
+    CreateTagRequestV1, DataChunk, DataFileFormatV1, DataFormat, DatasetDirectoryKindV1,
+    DatasetDirectoryV1, DeleteExportTemplateRequestV1, DeleteExportTemplateResponseV1,
+    DeleteProjectionPresetRequestV1, DeleteProjectionPresetResponseV1, DeleteRowsRequestV1,
+    DeleteRowsResponseV1, DeleteScheduleRequestV1, DeleteScheduleResponseV1, DeleteTagRequestV1,
+    DeleteTagResponseV1, DeleteWatchFolderRequestV1, DeleteWatchFolderResponseV1,
+    DiagnosticsConnectionV1, DiagnosticsReportV1, DisconnectRequestV1, DisconnectResponseV1,
+    DistanceTypeV1, DropColumnsRequestV1, DropColumnsResponseV1, DropIndexRequestV1,
+    DropIndexResponseV1, DropTableRequestV1, DropTableResponseV1, EmbeddingModelV1,
+    EncryptedColumnV1, ErrorCode, EstimateEmbeddingJobRequestV1, EstimateEmbeddingJobResponseV1,
+    EstimateQueryRequestV1, EstimateQueryResponseV1, EvaluateRecallRequestV1,
+    EvaluateRecallResponseV1, ExportBundleRequestV1, ExportBundleResponseV1, ExportDataRequestV1,
+    ExportDataResponseV1, ExportDiagnosticsRequestV1, ExportDiagnosticsResponseV1,
+    ExportReproSampleRequestV1, ExportReproSampleResponseV1, ExportRowsRequestV1, FieldDataType,
+    FtsColumnsNotIndexedV1, FtsSearchRequestV1, GeometryEncodingV1, GetAccessLogRequestV1,
+    GetAccessLogResponseV1, GetJobStatusRequestV1, GetRetentionPolicyRequestV1,
+    GetRowCountRequestV1, GetSchemaRequestV1, GetSearchDefaultsRequestV1,
+    GetTableDescriptionRequestV1, GetTableVersionRequestV1, GetTableVersionResponseV1,
+    HealthResponseV1, ImportBundleRequestV1, ImportBundleResponseV1, ImportDataRequestV1,
+    ImportDataResponseV1, IndexCapabilitiesResponseV1, IndexDefinitionV1, IndexRebuildReasonV1,
+    IndexRebuildStateV1, IndexRebuildV1, IndexStatsRequestV1, IndexStatsResponseV1, IndexTypeV1,
+    JobKindV1, JobStatusV1, JsonChunk, JsonOptionsV1, KillQueryRequestV1,
+    ListActiveQueriesResponseV1, ListEncryptedColumnsRequestV1, ListEncryptedColumnsResponseV1,
+    ListExportTemplatesResponseV1, ListExtensionsResponseV1, ListIndexesRequestV1,
+    ListIndexesResponseV1, ListJobsRequestV1, ListJobsResponseV1, ListMaskingRulesRequestV1,
+    ListMaskingRulesResponseV1, ListProjectionPresetsRequestV1, ListProjectionPresetsResponseV1,
+    ListSchedulesRequestV1, ListSchedulesResponseV1, ListSharesResponseV1, ListTablesRequestV1,
+    ListTablesResponseV1, ListTagsRequestV1, ListTagsResponseV1, ListVersionsRequestV1,
+    ListVersionsResponseV1, ListWatchFoldersRequestV1, ListWatchFoldersResponseV1, LogSettingsV1,
+    MaskingRuleV1, MaskingStrategyV1, MaterializeQueryRequestV1, MaterializeQueryResponseV1,
+    MaterializeSourceV1, MemoryStatsResponseV1, MessageCodeV1, MessageV1,
+    MigrateTableFormatRequestV1, MigrateTableFormatResponseV1, MissingFtsIndexV1,
+    OpenTableRequestV1, OpenTableResultV1, OpenTablesRequestV1, OpenTablesResponseV1,
+    OptimizeActionV1, OptimizeStatsV1, OptimizeTableRequestV1, OptimizeTableResponseV1,
+    OptimizedIndexV1, OverviewRequestV1, OverviewResponseV1, PivotRequestV1, PivotResponseV1,
+    PreviewUpdateRequestV1, PreviewUpdateResponseV1, ProgressStageV1, QueryExecutionStatsV1,
+    QueryFilterRequestV1, QueryResponseV1, RecallAtNprobesV1, RecentFilterSortV1,
+    RecentFiltersRequestV1, RecentFiltersResponseV1, RenameTableRequestV1, RenameTableResponseV1,
+    ReorderColumnsRequestV1, ReorderColumnsResponseV1, ReproSampleManifestV1, RerankerV1,
+    RestoreBackupRequestV1, RestoreBackupResponseV1, RestoreTableVersionRequestV1,
+    RestoreTableVersionResponseV1, ResultEnvelope, RetentionPolicyResponseV1, RetentionPolicyV1,
+    RotateSecretRequestV1, RotateSecretResponseV1, RowCountResponseV1, RowValidationErrorV1,
+    RunExportTemplateRequestV1, RunRetentionRequestV1, RunRetentionResponseV1,
+    SaveExportTemplateRequestV1, SaveExportTemplateResponseV1, SaveProjectionPresetRequestV1,
+    SaveProjectionPresetResponseV1, SavedArtifactKindV1, ScanRequestV1, ScanResponseV1,
+    ScanStreamControlResponseV1, ScanStreamEventV1, ScanStreamRequestV1, ScanStreamStartedV1,
+    ScanStreamSummaryV1, ScheduleActionV1, ScheduleV1, SchemaCompatibilityReportV1,
+    SchemaDefinition, SchemaDefinitionInput, SchemaField, SchemaFieldInput,
+    SearchDefaultsResponseV1, SearchIndexKindV1, SearchWarningKindV1, SearchWarningV1,
+    SetConfigRequestV1, SetEncryptedColumnsRequestV1, SetEncryptedColumnsResponseV1,
+    SetEncryptionKeyRequestV1, SetEncryptionKeyResponseV1, SetLogLevelRequestV1,
+    SetLogLevelResponseV1, SetMaskingRulesRequestV1, SetMaskingRulesResponseV1,
+    SetRetentionPolicyRequestV1, SetScheduleRequestV1, SetScheduleResponseV1,
+    SetSearchDefaultsRequestV1, SetTableDescriptionRequestV1, SetWatchFolderRequestV1,
+    SetWatchFolderResponseV1, ShareSessionV1, StartShareRequestV1, StatsHistoryRequestV1,
+    StatsHistoryResponseV1, StopShareRequestV1, StopShareResponseV1, TableDescriptionResponseV1,
+    TableDescriptionV1, TableFormatInfoRequestV1, TableFormatInfoV1, TableHandle, TableInfo,
+    TableLocationRequestV1, TableLocationV1, TagResponseV1, UpdatePreviewRowV1,
+    UpdateRowsRequestV1, UpdateRowsResponseV1, UpdateTagRequestV1, UpdatedArtifactV1,
+    ValidateFilterRequestV1, ValidateFilterResponseV1, VectorSearchParamsV1, VectorSearchRequestV1,
+    VersionInfoV1, VersionTagV1, WatchFolderV1, WriteDataMode, WriteRowsRequestV1,
+    WriteRowsResponseV1, EXTENSION_NAME_KEY, GEOARROW_WKB_EXTENSION,
+};
+use crate::services::backend::lance::{to_distance_type_v1, to_index_type_v1, to_version_info};
+use crate::services::backend::{
+    BackendError, DatastoreBackend, LanceBackend, LanceTableBackend, TableBackend, TableScan,
+    TableStorageStats,
+};
+use crate::services::connection_manager::ConnectionManager;
+use crate::services::middleware::CommandContext;
+use crate::services::row_counts::RowCountCache;
+use crate::services::scan_stream::ScanStreamMessage;
+use crate::services::scheduler::CronExpr;
+use crate::services::{
+    accelerator, access_log, active_queries, backup, bundle, checkpoints, checksum, clock,
+    column_order, completion, config, descriptions, diagnostics, disk_space, embedding_cost,
+    encryption, export_templates, fts_tokenizer, geometry, health, highlight, index_coverage,
+    index_presets, jobs, json_format, lineage, log_control, masking, memory, messages, middleware,
+    normalization, notifications, object_storage, pivot, projection, projection_presets,
+    query_stats, recall, rename_cascade, repro_sample, reranking, retention, scan_stream,
+    schema_compat, search_defaults, secrets, share, stats_history, time_filters, update_preview,
+    version_diff, version_tags, warm_scans, watch_folders,
 };
 use crate::state::AppState;
 
-fn batches_to_json_rows(batches: &[RecordBatch]) -> Result<Vec<serde_json::Value>, String> {
-    if batches.is_empty() {
-        return Ok(Vec::new());
+fn is_dictionary_field(field: &Field) -> bool {
+    matches!(field.data_type(), DataType::Dictionary(_, _))
+}
+
+fn decoded_field(field: &Field) -> Field {
+    match field.data_type() {
+        DataType::Dictionary(_, value_type) => {
+            field.clone().with_data_type(value_type.as_ref().clone())
+        }
+        _ => field.clone(),
     }
+}
 
-    let mut writer = ArrayWriter::new(Vec::new());
-    let batch_refs: Vec<&RecordBatch> = batches.iter().collect();
+/// Replaces top-level dictionary columns with their value type, so serialized pages
+/// carry plain values regardless of how lance stored the column.
+fn decode_dictionary_schema(schema: &Schema) -> Schema {
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|field| decoded_field(field))
+        .collect::<Vec<_>>();
+    Schema::new_with_metadata(fields, schema.metadata().clone())
+}
 
-    writer
-        .write_batches(&batch_refs)
-        .map_err(|error| error.to_string())?;
-    writer.finish().map_err(|error| error.to_string())?;
+fn decode_dictionaries(batch: &RecordBatch) -> Result<RecordBatch, String> {
+    let schema = batch.schema();
+    if !schema
+        .fields()
+        .iter()
+        .any(|field| is_dictionary_field(field))
+    {
+        return Ok(batch.clone());
+    }
+    let columns = schema
+        .fields()
+        .iter()
+        .zip(batch.columns())
+        .map(|(field, column)| match field.data_type() {
+            DataType::Dictionary(_, value_type) => {
+                arrow_cast::cast(column, value_type).map_err(|error| error.to_string())
+            }
+            _ => Ok(column.clone()),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    RecordBatch::try_new(Arc::new(decode_dictionary_schema(schema.as_ref())), columns)
+        .map_err(|error| error.to_string())
+}
+
+fn batches_to_json_rows(batches: &[RecordBatch]) -> Result<Vec<serde_json::Value>, String> {
+    batches_to_json_rows_with(batches, &JsonOptionsV1::default()).map(|(rows, _)| rows)
+}
+
+/// Also returns the integer columns rendered as strings.
+fn batches_to_json_rows_with(
+    batches: &[RecordBatch],
+    options: &JsonOptionsV1,
+) -> Result<(Vec<serde_json::Value>, Vec<String>), String> {
+    if batches.is_empty() {
+        return Ok((Vec::new(), Vec::new()));
+    }
 
-    let json = writer.into_inner();
-    let rows: Vec<serde_json::Value> =
-        serde_json::from_slice(&json).map_err(|error| error.to_string())?;
+    let batches = batches
+        .iter()
+        .map(decode_dictionaries)
+        .collect::<Result<Vec<_>, _>>()?;
+    json_format::encode_rows(&batches, options)
+}
 
-    Ok(rows)
+/// Encodes `batches` as a GeoJSON FeatureCollection, with every column other than
+/// the geometry column as feature properties.
+fn batches_to_geojson(
+    batches: &[RecordBatch],
+    column: &str,
+    encoding: GeometryEncodingV1,
+) -> Result<Vec<u8>, String> {
+    let mut geometries = Vec::new();
+    let mut properties = Vec::new();
+    for batch in batches {
+        let index = batch
+            .schema()
+            .index_of(column)
+            .map_err(|_| format!("geometry column not found: {column}"))?;
+        geometries.extend(geometry::column_geometries(
+            batch.column(index).as_ref(),
+            encoding,
+        )?);
+        let others = (0..batch.num_columns())
+            .filter(|other| *other != index)
+            .collect::<Vec<_>>();
+        if others.is_empty() {
+            properties.extend((0..batch.num_rows()).map(|_| serde_json::json!({})));
+        } else {
+            let projected = batch.project(&others).map_err(|error| error.to_string())?;
+            properties.extend(batches_to_json_rows(&[projected])?);
+        }
+    }
+    serde_json::to_vec(&geometry::feature_collection(geometries, properties))
+        .map_err(|error| error.to_string())
 }
 
 fn batches_to_arrow_ipc_base64(batches: &[RecordBatch], schema: &Schema) -> Result<String, String> {
+    Ok(general_purpose::STANDARD.encode(batches_to_arrow_ipc(batches, schema)?))
+}
+
+fn batches_to_arrow_ipc(batches: &[RecordBatch], schema: &Schema) -> Result<Vec<u8>, String> {
+    let schema = decode_dictionary_schema(schema);
     let mut buffer = Vec::new();
     let mut writer =
-        StreamWriter::try_new(&mut buffer, schema).map_err(|error| error.to_string())?;
+        StreamWriter::try_new(&mut buffer, &schema).map_err(|error| error.to_string())?;
 
     for batch in batches {
-        writer.write(batch).map_err(|error| error.to_string())?;
+        let batch = decode_dictionaries(batch)?;
+        writer.write(&batch).map_err(|error| error.to_string())?;
     }
 
     writer.finish().map_err(|error| error.to_string())?;
-    Ok(general_purpose::STANDARD.encode(buffer))
+    Ok(buffer)
+}
+
+/// Encodes `batch` as Arrow IPC chunks of at most `max_bytes`, halving it by rows
+/// until every part fits or holds a single row. Returns each chunk with its rows.
+fn arrow_ipc_chunks(
+    batch: &RecordBatch,
+    max_bytes: usize,
+) -> Result<Vec<(Vec<u8>, usize)>, String> {
+    let rows = batch.num_rows();
+    let encoded = batches_to_arrow_ipc(std::slice::from_ref(batch), batch.schema().as_ref())?;
+    if encoded.len() <= max_bytes || rows <= 1 {
+        return Ok(vec![(encoded, rows)]);
+    }
+    let half = rows / 2;
+    let mut chunks = arrow_ipc_chunks(&batch.slice(0, half), max_bytes)?;
+    chunks.extend(arrow_ipc_chunks(
+        &batch.slice(half, rows - half),
+        max_bytes,
+    )?);
+    Ok(chunks)
+}
+
+/// Encodes as many leading rows of `batches` as fit in `max_bytes` of base64 IPC, but
+/// at least one so paging always moves on. Returns the payload and its row count.
+fn batches_to_arrow_ipc_within(
+    batches: &[RecordBatch],
+    schema: &Schema,
+    max_bytes: usize,
+) -> Result<(String, usize), String> {
+    let total_rows = batches.iter().map(|batch| batch.num_rows()).sum::<usize>();
+    let encoded = batches_to_arrow_ipc_base64(batches, schema)?;
+    if encoded.len() <= max_bytes || total_rows <= 1 {
+        return Ok((encoded, total_rows));
+    }
+    let mut fits = 1;
+    let mut fits_encoded = batches_to_arrow_ipc_base64(&truncate_batches(batches, 1), schema)?;
+    if fits_encoded.len() > max_bytes {
+        return Ok((fits_encoded, 1));
+    }
+    // The payload grows with the row count, so bisect for the largest count that fits.
+    let mut too_many = total_rows;
+    while too_many - fits > 1 {
+        let middle = fits + (too_many - fits) / 2;
+        let candidate = batches_to_arrow_ipc_base64(&truncate_batches(batches, middle), schema)?;
+        if candidate.len() <= max_bytes {
+            fits = middle;
+            fits_encoded = candidate;
+        } else {
+            too_many = middle;
+        }
+    }
+    Ok((fits_encoded, fits))
 }
 
-fn ensure_schema_field(schema: &mut SchemaDefinition, name: &str, data_type: &str, nullable: bool) {
+fn ensure_schema_field(
+    schema: &mut SchemaDefinition,
+    name: &str,
+    data_type: DataType,
+    nullable: bool,
+) {
     if schema.fields.iter().any(|field| field.name == name) {
         return;
     }
 
-    schema.fields.push(SchemaField {
-        name: name.to_string(),
-        data_type: data_type.to_string(),
-        nullable,
-        metadata: None,
-    });
+    schema
+        .fields
+        .push(SchemaField::from_arrow_field(&Field::new(
+            name, data_type, nullable,
+        )));
 }
 
 fn annotate_hybrid_rows(
     rows: &mut [serde_json::Value],
     schema: &mut SchemaDefinition,
     offset: usize,
+    source: &str,
 ) {
-    ensure_schema_field(schema, "_hybrid_rank", "UInt64", false);
-    ensure_schema_field(schema, "_hybrid_source", "Utf8", false);
+    ensure_schema_field(schema, "_hybrid_rank", DataType::UInt64, false);
+    ensure_schema_field(schema, "_hybrid_source", DataType::Utf8, false);
 
     for (index, row) in rows.iter_mut().enumerate() {
         let Some(object) = row.as_object_mut() else {
@@ -125,11 +346,58 @@ fn annotate_hybrid_rows(
         );
         object.insert(
             "_hybrid_source".to_string(),
-            serde_json::Value::String("rrf".to_string()),
+            serde_json::Value::String(source.to_string()),
         );
     }
 }
 
+/// Scores search candidates with `reranker` and returns the page at `offset`, and
+/// whether more rows follow it.
+async fn rerank_rows(
+    reranker: &RerankerV1,
+    query_text: &str,
+    rows: Vec<serde_json::Value>,
+    schema: &mut SchemaDefinition,
+    offset: usize,
+    limit: usize,
+) -> Result<(Vec<serde_json::Value>, bool), String> {
+    let scores = match reranker {
+        RerankerV1::Rrf(_) => {
+            return Err("rrf reranking is applied by the hybrid query itself".to_string())
+        }
+        RerankerV1::CrossEncoder(config) => {
+            reranking::cross_encoder_scores(config, query_text, &rows).await?
+        }
+        RerankerV1::Expression(config) => {
+            let expression = reranking::ScoreExpression::parse(&config.expression)?;
+            rows.iter().map(|row| expression.score(row)).collect()
+        }
+    };
+    ensure_schema_field(
+        schema,
+        reranking::RERANK_SCORE_COLUMN,
+        DataType::Float64,
+        true,
+    );
+    Ok(reranking::page(
+        reranking::rank_rows(rows, scores),
+        offset,
+        limit,
+    ))
+}
+
+/// The text column a cross-encoder reads, when the search results do not have it.
+fn missing_rerank_column<'a>(
+    reranker: &'a RerankerV1,
+    schema: &SchemaDefinition,
+) -> Option<&'a str> {
+    let RerankerV1::CrossEncoder(config) = reranker else {
+        return None;
+    };
+    let column = config.column.trim();
+    (!schema.fields.iter().any(|field| field.name == column)).then_some(column)
+}
+
 fn truncate_batches(batches: &[RecordBatch], limit: usize) -> Vec<RecordBatch> {
     if limit == 0 {
         return Vec::new();
@@ -165,7 +433,7 @@ struct QueryOptions {
 
 fn apply_query_options<Q: QueryBase>(mut query: Q, options: &QueryOptions) -> Q {
     if let Some(filter) = options.filter.as_deref() {
-        query = query.only_if(filter);
+        query = query.only_if(time_filters::expand(filter));
     }
 
     if let Some(limit) = options.limit {
@@ -178,7 +446,7 @@ fn apply_query_options<Q: QueryBase>(mut query: Q, options: &QueryOptions) -> Q
 
     if let Some(projection) = &options.projection {
         if !projection.is_empty() {
-            query = query.select(Select::columns(projection));
+            query = query.select(projection::to_select(projection));
         }
     }
 
@@ -188,17 +456,57 @@ fn apply_query_options<Q: QueryBase>(mut query: Q, options: &QueryOptions) -> Q
 async fn execute_query_json(
     query: impl ExecutableQuery,
     fallback_schema: SchemaDefinition,
+    json_options: &JsonOptionsV1,
+) -> Result<(Vec<serde_json::Value>, SchemaDefinition), String> {
+    execute_masked_query_json(
+        query,
+        fallback_schema,
+        &encryption::ColumnKeys::default(),
+        &[],
+        json_options,
+    )
+    .await
+}
+
+/// Runs `query` under `analyze_plan` and summarizes the plan. Failures are logged and
+/// reported as missing stats so the query itself still succeeds.
+async fn collect_query_stats(
+    query: &impl ExecutableQuery,
+    operation: &str,
+    table_id: &str,
+) -> Option<QueryExecutionStatsV1> {
+    let started_at = Instant::now();
+    match query.analyze_plan().await {
+        Ok(plan) => Some(query_stats::summarize_plan(plan, started_at.elapsed())),
+        Err(error) => {
+            warn!(
+                "{} failed to analyze plan table_id={} error={}",
+                operation, table_id, error
+            );
+            None
+        }
+    }
+}
+
+async fn execute_masked_query_json(
+    query: impl ExecutableQuery,
+    fallback_schema: SchemaDefinition,
+    column_keys: &encryption::ColumnKeys,
+    masking_rules: &[MaskingRuleV1],
+    json_options: &JsonOptionsV1,
 ) -> Result<(Vec<serde_json::Value>, SchemaDefinition), String> {
-    let batches = execute_query_batches(query).await?;
+    let batches = encryption::decrypt_batches(execute_query_batches(query).await?, column_keys)?;
+    let batches = masking::mask_batches(batches, masking_rules)?;
     let batch_count = batches.len();
 
-    let schema = if let Some(first) = batches.first() {
+    let mut schema = if let Some(first) = batches.first() {
         SchemaDefinition::from_arrow_schema(first.schema().as_ref())
     } else {
         fallback_schema
     };
 
-    let rows = batches_to_json_rows(&batches)?;
+    let (rows, string_columns) = batches_to_json_rows_with(&batches, json_options)?;
+    schema.mark_string_encoded(&string_columns);
     trace!(
         "execute_query_json completed batches={} rows={}",
         batch_count,
@@ -207,12 +515,16 @@ async fn execute_query_json(
     Ok((rows, schema))
 }
 
+/// Collects every batch of `query`. The batches stay alive until the command
+/// finishes with them, so all of them count towards its peak memory.
 async fn execute_query_batches(query: impl ExecutableQuery) -> Result<Vec<RecordBatch>, String> {
-    let stream = query.execute().await.map_err(|error| error.to_string())?;
-    stream
-        .try_collect::<Vec<_>>()
-        .await
-        .map_err(|error| error.to_string())
+    let mut stream = query.execute().await.map_err(|error| error.to_string())?;
+    let mut batches = Vec::new();
+    while let Some(batch) = stream.try_next().await.map_err(|error| error.to_string())? {
+        memory::retain(memory::batch_bytes(&batch));
+        batches.push(batch);
+    }
+    Ok(batches)
 }
 
 fn json_rows_to_batches(
@@ -248,12 +560,60 @@ fn json_rows_to_batches(
     Ok(batches)
 }
 
+/// Finds the first row that fails to convert on its own and the field that fails in
+/// it. Arrow reports decode errors per batch without the row, so this reruns the
+/// conversion one row at a time after the whole batch failed.
+fn locate_invalid_row(
+    schema: &SchemaRef,
+    rows: &[serde_json::Value],
+) -> Option<RowValidationErrorV1> {
+    rows.iter().enumerate().find_map(|(row_index, row)| {
+        let row_error = json_rows_to_batches(schema.clone(), std::slice::from_ref(row)).err()?;
+        let field_error = row.is_object().then(|| {
+            schema.fields().iter().find_map(|field| {
+                let single = Arc::new(Schema::new(vec![field.clone()]));
+                json_rows_to_batches(single, std::slice::from_ref(row))
+                    .err()
+                    .map(|error| (field.name().clone(), error))
+            })
+        });
+        let (field, message) = match field_error.flatten() {
+            Some((field, error)) => (Some(field), error),
+            None => (None, row_error),
+        };
+        Some(RowValidationErrorV1 {
+            row_index: row_index as u64,
+            field,
+            // The row was converted alone, so the message calls it row 0.
+            message: message.replace("row 0", &format!("row {row_index}")),
+        })
+    })
+}
+
+/// InvalidArgument error for rows that do not fit the table. `details` names the
+/// first offending row and field so the editor can point at them.
+fn invalid_rows_error<T>(
+    error: String,
+    invalid_row: Option<RowValidationErrorV1>,
+) -> ResultEnvelope<T> {
+    let Some(invalid_row) = invalid_row else {
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+    };
+    ResultEnvelope::err_envelope(ErrorEnvelope {
+        code: ErrorCode::InvalidArgument,
+        message: invalid_row.message.clone(),
+        message_code: None,
+        params: None,
+        details: serde_json::to_value(&invalid_row).ok(),
+    })
+}
+
 fn schema_needs_manual_json_conversion(schema: &Schema) -> bool {
     schema.fields().iter().any(|field| {
         matches!(
             field.data_type(),
             DataType::FixedSizeList(item_field, _) if item_field.data_type() == &DataType::Float32
-        )
+        ) || is_dictionary_field(field)
     })
 }
 
@@ -603,6 +963,15 @@ fn json_values_to_array(field: &Field, rows: &[serde_json::Value]) -> Result<Arr
                 *length,
             )))
         }
+        DataType::Dictionary(_, _) => {
+            let values = json_values_to_array(&decoded_field(field), rows)?;
+            arrow_cast::cast(&values, field.data_type()).map_err(|error| {
+                format!(
+                    "field '{}' cannot be dictionary-encoded: {error}",
+                    field.name()
+                )
+            })
+        }
         data_type => Err(format!(
             "JSON row writes do not support Arrow data type {data_type:?} for field '{}'",
             field.name()
@@ -646,7 +1015,7 @@ fn validate_mutation_filter(
         ));
     }
 
-    Ok(Some(cleaned))
+    Ok(Some(time_filters::expand(&cleaned)))
 }
 
 fn parse_delimiter(delimiter: Option<String>, fallback: u8) -> Result<u8, String> {
@@ -666,11 +1035,11 @@ fn parse_delimiter(delimiter: Option<String>, fallback: u8) -> Result<u8, String
 
 fn sanitize_filter(filter: Option<String>) -> Option<String> {
     filter.and_then(|value| {
-        let trimmed = value.trim().to_string();
+        let trimmed = value.trim();
         if trimmed.is_empty() {
             None
         } else {
-            Some(trimmed)
+            Some(time_filters::expand(trimmed))
         }
     })
 }
@@ -719,14 +1088,68 @@ fn to_arrow_data_type(
             let item_field = Arc::new(Field::new("item", DataType::Float32, true));
             Ok(DataType::FixedSizeList(item_field, length))
         }
+        FieldDataType::Time32Second => Ok(DataType::Time32(TimeUnit::Second)),
+        FieldDataType::Time32Millisecond => Ok(DataType::Time32(TimeUnit::Millisecond)),
+        FieldDataType::Time64Microsecond => Ok(DataType::Time64(TimeUnit::Microsecond)),
+        FieldDataType::Time64Nanosecond => Ok(DataType::Time64(TimeUnit::Nanosecond)),
+        FieldDataType::DurationSecond => Ok(DataType::Duration(TimeUnit::Second)),
+        FieldDataType::DurationMillisecond => Ok(DataType::Duration(TimeUnit::Millisecond)),
+        FieldDataType::DurationMicrosecond => Ok(DataType::Duration(TimeUnit::Microsecond)),
+        FieldDataType::DurationNanosecond => Ok(DataType::Duration(TimeUnit::Nanosecond)),
+        FieldDataType::IntervalYearMonth => Ok(DataType::Interval(IntervalUnit::YearMonth)),
+        FieldDataType::IntervalDayTime => Ok(DataType::Interval(IntervalUnit::DayTime)),
+        FieldDataType::IntervalMonthDayNano => Ok(DataType::Interval(IntervalUnit::MonthDayNano)),
+        FieldDataType::Null => Ok(DataType::Null),
+        FieldDataType::DictionaryUtf8 => Ok(DataType::Dictionary(
+            Box::new(DataType::Int32),
+            Box::new(DataType::Utf8),
+        )),
+        FieldDataType::Geometry => Ok(DataType::Binary),
     }
 }
 
 fn to_arrow_field(input: &SchemaFieldInput) -> Result<Field, String> {
     let data_type = to_arrow_data_type(&input.data_type, input.vector_length)?;
+    let mut metadata = input.metadata.clone().unwrap_or_default();
+    if let Some(embedding) = &input.embedding {
+        let DataType::FixedSizeList(_, length) = data_type else {
+            return Err(format!(
+                "embedding model can only be set on vector column '{}'",
+                input.name
+            ));
+        };
+        if embedding.model.trim().is_empty() {
+            return Err(format!(
+                "embedding model of column '{}' cannot be empty",
+                input.name
+            ));
+        }
+        if embedding
+            .dimension
+            .is_some_and(|dimension| dimension != length)
+        {
+            return Err(format!(
+                "embedding dimension of column '{}' does not match its vector length {length}",
+                input.name
+            ));
+        }
+        metadata.extend(
+            EmbeddingModelV1 {
+                model: embedding.model.trim().to_string(),
+                version: embedding.version.clone(),
+                dimension: Some(length),
+            }
+            .to_metadata(),
+        );
+    }
     let mut field = Field::new(&input.name, data_type, input.nullable);
-    if let Some(metadata) = &input.metadata {
-        field = field.with_metadata(metadata.clone());
+    if matches!(input.data_type, FieldDataType::Geometry) {
+        metadata
+            .entry(EXTENSION_NAME_KEY.to_string())
+            .or_insert_with(|| GEOARROW_WKB_EXTENSION.to_string());
+    }
+    if !metadata.is_empty() {
+        field = field.with_metadata(metadata);
     }
     Ok(field)
 }
@@ -766,29 +1189,425 @@ fn sanitize_index_columns(columns: &[String]) -> Result<Vec<String>, String> {
     Ok(cleaned)
 }
 
-fn to_index_type_v1(index_type: &IndexType) -> IndexTypeV1 {
+/// Share of IVF partitions probed when a search omits `nprobes`.
+const DEFAULT_NPROBES_RATIO: f64 = 0.08;
+
+fn is_vector_index_type(index_type: &IndexType) -> bool {
+    matches!(
+        index_type,
+        IndexType::IvfFlat
+            | IndexType::IvfSq
+            | IndexType::IvfPq
+            | IndexType::IvfRq
+            | IndexType::IvfHnswPq
+            | IndexType::IvfHnswSq
+    )
+}
+
+/// Lance picks roughly `sqrt(rows)` IVF partitions when none are requested.
+fn estimated_num_partitions(num_indexed_rows: usize) -> usize {
+    ((num_indexed_rows as f64).sqrt() as usize).max(1)
+}
+
+fn default_nprobes(num_partitions: usize) -> usize {
+    let num_partitions = num_partitions.max(1);
+    ((num_partitions as f64 * DEFAULT_NPROBES_RATIO).ceil() as usize).clamp(1, num_partitions)
+}
+
+/// Quantized indexes lose precision, so their candidates are re-ranked on the raw
+/// vectors by default.
+fn default_refine_factor(index_type: &IndexType) -> Option<u32> {
     match index_type {
-        IndexType::BTree => IndexTypeV1::BTree,
-        IndexType::Bitmap => IndexTypeV1::Bitmap,
-        IndexType::LabelList => IndexTypeV1::LabelList,
-        IndexType::FTS => IndexTypeV1::Fts,
-        IndexType::IvfFlat => IndexTypeV1::IvfFlat,
-        IndexType::IvfSq => IndexTypeV1::IvfSq,
-        IndexType::IvfPq => IndexTypeV1::IvfPq,
-        IndexType::IvfRq => IndexTypeV1::IvfRq,
-        IndexType::IvfHnswPq => IndexTypeV1::IvfHnswPq,
-        IndexType::IvfHnswSq => IndexTypeV1::IvfHnswSq,
+        IndexType::IvfPq | IndexType::IvfRq | IndexType::IvfHnswPq => Some(5),
+        IndexType::IvfSq | IndexType::IvfHnswSq => Some(2),
+        _ => None,
     }
 }
 
-fn to_distance_type_v1(distance_type: &DistanceType) -> DistanceTypeV1 {
-    match distance_type {
-        DistanceType::L2 => DistanceTypeV1::L2,
-        DistanceType::Cosine => DistanceTypeV1::Cosine,
-        DistanceType::Dot => DistanceTypeV1::Dot,
-        DistanceType::Hamming => DistanceTypeV1::Hamming,
-        _ => DistanceTypeV1::L2,
+/// Resolves the ANN parameters for a search on `column` (or the only vector index
+/// when no column is given), filling in omitted values from the index statistics.
+async fn resolve_vector_search_params(
+    table: &Table,
+    column: Option<&str>,
+    nprobes: Option<usize>,
+    refine_factor: Option<u32>,
+) -> VectorSearchParamsV1 {
+    let mut params = VectorSearchParamsV1 {
+        index_name: None,
+        index_type: None,
+        estimated_partitions: None,
+        nprobes,
+        refine_factor,
+        nprobes_defaulted: false,
+        refine_factor_defaulted: false,
+    };
+    if nprobes.is_some() && refine_factor.is_some() {
+        return params;
+    }
+
+    let configs = match table.list_indices().await {
+        Ok(configs) => configs,
+        Err(error) => {
+            warn!(
+                "failed to list indexes for search defaults table={} error={}",
+                table.name(),
+                error
+            );
+            return params;
+        }
+    };
+    let mut candidates = configs
+        .into_iter()
+        .filter(|config| is_vector_index_type(&config.index_type))
+        .filter(|config| match column {
+            Some(column) => config.columns.len() == 1 && config.columns[0] == column,
+            None => true,
+        })
+        .collect::<Vec<_>>();
+    if candidates.len() != 1 {
+        return params;
+    }
+    let config = candidates.remove(0);
+
+    params.index_type = Some(to_index_type_v1(&config.index_type));
+    if refine_factor.is_none() {
+        params.refine_factor = default_refine_factor(&config.index_type);
+        params.refine_factor_defaulted = params.refine_factor.is_some();
+    }
+    if nprobes.is_none() {
+        match table.index_stats(&config.name).await {
+            Ok(Some(stats)) => {
+                let partitions = estimated_num_partitions(stats.num_indexed_rows);
+                params.estimated_partitions = Some(partitions);
+                params.nprobes = Some(default_nprobes(partitions));
+                params.nprobes_defaulted = true;
+            }
+            Ok(None) => {}
+            Err(error) => warn!(
+                "failed to read index stats for search defaults table={} index={} error={}",
+                table.name(),
+                config.name,
+                error
+            ),
+        }
+    }
+    params.index_name = Some(config.name);
+    params
+}
+
+/// Columns covered by a full-text index, which a search without `columns` matches.
+/// `None` when the indexes cannot be listed; the search then leaves the check to lance.
+async fn fts_indexed_columns(table: &Table) -> Option<Vec<String>> {
+    match table.list_indices().await {
+        Ok(configs) => Some(
+            configs
+                .into_iter()
+                .filter(|config| matches!(config.index_type, IndexType::FTS))
+                .flat_map(|config| config.columns)
+                .collect(),
+        ),
+        Err(error) => {
+            warn!(
+                "failed to list fts indexes table={} error={}",
+                table.name(),
+                error
+            );
+            None
+        }
+    }
+}
+
+/// Checks a full-text search against the FTS-indexed columns before it runs, so a
+/// missing index is reported by name instead of through lance's error text.
+fn check_fts_columns<T>(
+    schema: &Schema,
+    indexed: &[String],
+    columns: &[String],
+) -> Result<(), ResultEnvelope<T>> {
+    if indexed.is_empty() {
+        return Err(missing_fts_index_error(schema));
+    }
+    let unindexed = columns
+        .iter()
+        .filter(|column| !indexed.contains(column))
+        .cloned()
+        .collect::<Vec<_>>();
+    if unindexed.is_empty() {
+        return Ok(());
+    }
+    let details = FtsColumnsNotIndexedV1 {
+        columns: unindexed,
+        indexed_columns: indexed.to_vec(),
+    };
+    let mut envelope = ErrorEnvelope::from_message(
+        ErrorCode::InvalidArgument,
+        messages::message_with(
+            MessageCodeV1::FtsColumnsNotIndexed,
+            messages::current_locale(),
+            HashMap::from([
+                ("columns".to_string(), details.columns.join(", ")),
+                ("indexed".to_string(), details.indexed_columns.join(", ")),
+            ]),
+        ),
+    );
+    envelope.details = serde_json::to_value(&details).ok();
+    Err(ResultEnvelope::err_envelope(envelope))
+}
+
+/// Fails before a local write of about `estimate` bytes that would not fit on the
+/// volume holding `path`, rather than leaving a half-written dataset or file.
+fn check_disk_space<T>(path: &Path, estimate: u64, command: &str) -> Result<(), ResultEnvelope<T>> {
+    let Err(shortage) = disk_space::check(path, estimate) else {
+        return Ok(());
+    };
+    warn!(
+        "{} rejected for disk space path=\"{}\" needed={} available={}",
+        command, shortage.path, shortage.needed_bytes, shortage.available_bytes
+    );
+    let mut envelope = ErrorEnvelope::from_message(
+        ErrorCode::Internal,
+        messages::message_with(
+            MessageCodeV1::DiskSpaceInsufficient,
+            messages::current_locale(),
+            HashMap::from([
+                ("path".to_string(), shortage.path.clone()),
+                ("needed".to_string(), shortage.needed_bytes.to_string()),
+                (
+                    "available".to_string(),
+                    shortage.available_bytes.to_string(),
+                ),
+            ]),
+        ),
+    );
+    envelope.details = serde_json::to_value(&shortage).ok();
+    Err(ResultEnvelope::err_envelope(envelope))
+}
+
+/// Error for a full-text search on a table without an FTS index. The details list
+/// the string columns the client can offer to index.
+fn missing_fts_index_error<T>(schema: &Schema) -> ResultEnvelope<T> {
+    let details = MissingFtsIndexV1 {
+        string_columns: schema
+            .fields()
+            .iter()
+            .filter(|field| matches!(field.data_type(), DataType::Utf8 | DataType::LargeUtf8))
+            .map(|field| field.name().clone())
+            .collect(),
+    };
+    let mut envelope = ErrorEnvelope::from_message(
+        ErrorCode::NotFound,
+        messages::message(MessageCodeV1::FtsIndexMissing),
+    );
+    envelope.details = serde_json::to_value(&details).ok();
+    ResultEnvelope::err_envelope(envelope)
+}
+
+/// Warnings for searched columns without an index of `kind`, or whose index leaves
+/// many rows to a flat scan. With no `columns`, lancedb picks the column itself, so
+/// any index of that kind counts.
+async fn index_coverage_warnings(
+    table: &Table,
+    kind: SearchIndexKindV1,
+    columns: &[String],
+) -> Vec<SearchWarningV1> {
+    let configs = match table.list_indices().await {
+        Ok(configs) => configs,
+        Err(error) => {
+            warn!(
+                "failed to list indexes for coverage warnings table={} error={}",
+                table.name(),
+                error
+            );
+            return Vec::new();
+        }
+    };
+    let indexes = configs
+        .into_iter()
+        .filter(|config| match kind {
+            SearchIndexKindV1::Vector => is_vector_index_type(&config.index_type),
+            SearchIndexKindV1::Fts => matches!(config.index_type, IndexType::FTS),
+        })
+        .collect::<Vec<_>>();
+
+    let mut warnings = Vec::new();
+    let mut covering = Vec::new();
+    if columns.is_empty() {
+        if indexes.is_empty() {
+            warnings.push(index_coverage::missing_index(kind, None));
+        }
+        covering.extend(indexes.iter());
+    } else {
+        for column in columns {
+            let matching = indexes
+                .iter()
+                .filter(|config| config.columns.iter().any(|name| name == column))
+                .collect::<Vec<_>>();
+            if matching.is_empty() {
+                warnings.push(index_coverage::missing_index(kind, Some(column.as_str())));
+            }
+            covering.extend(matching);
+        }
+    }
+
+    for config in covering {
+        match table.index_stats(&config.name).await {
+            Ok(Some(stats)) => {
+                let coverage = index_coverage::IndexCoverage {
+                    name: config.name.clone(),
+                    index_type: to_index_type_v1(&config.index_type),
+                    column: config.columns.first().cloned(),
+                    indexed_rows: stats.num_indexed_rows as u64,
+                    unindexed_rows: stats.num_unindexed_rows as u64,
+                };
+                warnings.extend(index_coverage::stale_index(kind, coverage));
+            }
+            Ok(None) => {}
+            Err(error) => warn!(
+                "failed to read index stats for coverage warnings table={} index={} error={}",
+                table.name(),
+                config.name,
+                error
+            ),
+        }
+    }
+    warnings
+}
+
+/// Warns when the query vector was embedded by another model than the one recorded on
+/// the searched column. Without a column, lancedb searches the only vector column, so
+/// the only column with a recorded model is checked.
+fn embedding_model_warning(
+    schema: &SchemaDefinition,
+    column: Option<&str>,
+    query: Option<&EmbeddingModelV1>,
+) -> Option<SearchWarningV1> {
+    let query = query?;
+    let (column, stored) = match column {
+        Some(column) => {
+            let field = schema.fields.iter().find(|field| field.name == column)?;
+            (field.name.clone(), field.embedding.clone()?)
+        }
+        None => {
+            let mut recorded = schema.fields.iter().filter_map(|field| {
+                field
+                    .embedding
+                    .clone()
+                    .map(|embedding| (field.name.clone(), embedding))
+            });
+            let only = recorded.next()?;
+            if recorded.next().is_some() {
+                return None;
+            }
+            only
+        }
+    };
+    if stored.is_compatible(query) {
+        return None;
+    }
+    Some(SearchWarningV1 {
+        kind: SearchWarningKindV1::EmbeddingModelMismatch,
+        index_kind: SearchIndexKindV1::Vector,
+        message: format!(
+            "column {column} holds embeddings from {} but the query vector comes from {}; distances are not meaningful",
+            stored.label(),
+            query.label()
+        ),
+        column: Some(column),
+        index_name: None,
+        index_type: None,
+        indexed_rows: None,
+        unindexed_rows: None,
+    })
+}
+
+/// Warns when the searched column records L2-normalized vectors but the query vector
+/// is not unit length. Cosine distance normalizes both sides itself. Without a
+/// column, the only column that records normalization is checked.
+fn normalization_warning(
+    schema: &SchemaDefinition,
+    column: Option<&str>,
+    vector: &[f32],
+    distance_type: Option<&DistanceTypeV1>,
+) -> Option<SearchWarningV1> {
+    if matches!(distance_type, Some(DistanceTypeV1::Cosine)) || normalization::is_unit(vector) {
+        return None;
+    }
+    let column = match column {
+        Some(column) => schema
+            .fields
+            .iter()
+            .find(|field| field.name == column && field.normalized)?
+            .name
+            .clone(),
+        None => {
+            let mut normalized = schema.fields.iter().filter(|field| field.normalized);
+            let only = normalized.next()?;
+            if normalized.next().is_some() {
+                return None;
+            }
+            only.name.clone()
+        }
+    };
+    Some(SearchWarningV1 {
+        kind: SearchWarningKindV1::UnnormalizedQuery,
+        index_kind: SearchIndexKindV1::Vector,
+        message: format!(
+            "column {column} holds L2-normalized vectors but the query vector is not unit length; set normalizeQuery or search with cosine distance"
+        ),
+        column: Some(column),
+        index_name: None,
+        index_type: None,
+        indexed_rows: None,
+        unindexed_rows: None,
+    })
+}
+
+fn normalize_batches(
+    batches: Vec<RecordBatch>,
+    columns: &[String],
+) -> Result<Vec<RecordBatch>, String> {
+    if columns.is_empty() {
+        return Ok(batches);
+    }
+    batches
+        .iter()
+        .map(|batch| normalization::normalize_batch(batch, columns))
+        .collect()
+}
+
+/// Records L2 normalization in the field metadata of `columns` that lack it. Lance
+/// keeps field metadata in the manifest, which only local tables let us replace.
+async fn record_normalization(table: &Table, columns: &[String]) -> Result<(), String> {
+    if columns.is_empty() {
+        return Ok(());
     }
+    let native = table
+        .as_native()
+        .ok_or_else(|| "normalization can only be recorded on local tables".to_string())?;
+    let schema = table.schema().await.map_err(|error| error.to_string())?;
+    let manifest = native.manifest().await.map_err(|error| error.to_string())?;
+    let mut updates = Vec::new();
+    for column in columns {
+        let field = schema
+            .field_with_name(column)
+            .map_err(|error| error.to_string())?;
+        if normalization::is_normalized(field) {
+            continue;
+        }
+        let field_id = manifest
+            .schema
+            .field(column)
+            .map(|field| field.id)
+            .ok_or_else(|| format!("column {column} is missing from the manifest"))?;
+        updates.push((field_id as u32, normalization::normalized_metadata(field)));
+    }
+    if updates.is_empty() {
+        return Ok(());
+    }
+    native
+        .replace_field_metadata(updates)
+        .await
+        .map_err(|error| error.to_string())
 }
 
 fn to_lancedb_distance_type(distance_type: &DistanceTypeV1) -> DistanceType {
@@ -959,13 +1778,13 @@ fn apply_ivf_hnsw_sq_params(
     builder
 }
 
-fn to_lancedb_index(request: &CreateIndexRequestV1) -> Index {
-    match request.index_type {
+fn to_lancedb_index(request: &CreateIndexRequestV1) -> Result<Index, String> {
+    Ok(match request.index_type {
         IndexTypeV1::Auto => Index::Auto,
         IndexTypeV1::BTree => Index::BTree(BTreeIndexBuilder::default()),
         IndexTypeV1::Bitmap => Index::Bitmap(BitmapIndexBuilder::default()),
         IndexTypeV1::LabelList => Index::LabelList(LabelListIndexBuilder::default()),
-        IndexTypeV1::Fts => Index::FTS(FtsIndexBuilder::default()),
+        IndexTypeV1::Fts => Index::FTS(fts_tokenizer::builder(request.fts_tokenizer.as_ref())?),
         IndexTypeV1::IvfFlat => Index::IvfFlat(apply_ivf_flat_params(
             IvfFlatIndexBuilder::default(),
             request,
@@ -987,41 +1806,152 @@ fn to_lancedb_index(request: &CreateIndexRequestV1) -> Index {
             IvfHnswSqIndexBuilder::default(),
             request,
         )),
-    }
+    })
 }
 
 async fn read_table_schema(table: &Table) -> Result<SchemaDefinition, String> {
     let schema = table.schema().await.map_err(|error| error.to_string())?;
-    Ok(SchemaDefinition::from_arrow_schema(schema.as_ref()))
+    Ok(column_order::definition(schema.as_ref()))
 }
 
-fn to_version_info(version: lancedb::table::Version) -> VersionInfoV1 {
-    VersionInfoV1 {
-        version: version.version,
-        timestamp: version.timestamp.to_rfc3339(),
-        metadata: version.metadata.into_iter().collect(),
-    }
+/// Looks up an open table, or the error envelope `command` returns when the
+/// connection manager is poisoned or the handle is unknown.
+fn resolve_table<T>(
+    state: &AppState,
+    table_id: &str,
+    command: &str,
+) -> Result<Table, ResultEnvelope<T>> {
+    let table = match state.connections.lock() {
+        Ok(manager) => manager.get_table(table_id),
+        Err(_) => {
+            error!("{} failed to lock connection manager", command);
+            return Err(ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            ));
+        }
+    };
+    table.ok_or_else(|| {
+        warn!("{} table not found table_id={}", command, table_id);
+        ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::TableNotFound),
+        )
+    })
 }
 
-pub async fn connect_v1(
-    state: &AppState,
-    request: ConnectRequestV1,
-) -> ResultEnvelope<ConnectResponseV1> {
-    let started_at = Instant::now();
-    let profile = request.profile;
-    let backend_kind = infer_backend_kind(&profile.uri);
-    let mut storage_options = profile.storage_options.clone();
+/// Opens a separate handle on `table_name` checked out at `version`. Checking out
+/// the shared handle would move every other command to that version too.
+async fn open_table_version(
+    connection: &lancedb::Connection,
+    table_name: &str,
+    version: u64,
+) -> Result<Table, String> {
+    let table = connection
+        .open_table(table_name)
+        .execute()
+        .await
+        .map_err(|error| error.to_string())?;
+    table
+        .checkout(version)
+        .await
+        .map_err(|error| format!("cannot read version {version}: {error}"))?;
+    Ok(table)
+}
 
-    info!(
-        "connect_v1 start name=\"{}\" uri=\"{}\" backend={:?}",
-        profile.name, profile.uri, backend_kind
-    );
-    match &profile.auth {
-        AuthDescriptor::None => {}
-        AuthDescriptor::Inline { provider, params } => {
-            if !params.is_empty() {
-                let keys: Vec<String> = params.keys().cloned().collect();
-                trace!(
+/// Like [`resolve_table`], but reads `as_of_version` when a request pins one.
+async fn resolve_table_version<T>(
+    state: &AppState,
+    table_id: &str,
+    as_of_version: Option<u64>,
+    command: &str,
+) -> Result<Table, ResultEnvelope<T>> {
+    let table = resolve_table(state, table_id, command)?;
+    let Some(version) = as_of_version else {
+        return Ok(table);
+    };
+    let connection = match state.connections.lock() {
+        Ok(manager) => manager
+            .get_table_connection_id(table_id)
+            .and_then(|connection_id| manager.get_connection(&connection_id)),
+        Err(_) => {
+            error!("{} failed to lock connection manager", command);
+            return Err(ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            ));
+        }
+    };
+    let Some(connection) = connection else {
+        warn!("{} connection not found table_id={}", command, table_id);
+        return Err(ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::ConnectionNotFound),
+        ));
+    };
+    open_table_version(&connection, table.name(), version)
+        .await
+        .map_err(|error| {
+            warn!(
+                "{} failed to open version table_id={} version={} error={}",
+                command, table_id, version, error
+            );
+            ResultEnvelope::err(ErrorCode::InvalidArgument, error)
+        })
+}
+
+/// Runs `future` with the configured timeout, if any.
+async fn with_timeout<T>(
+    timeout: Option<Duration>,
+    future: impl std::future::Future<Output = Result<T, String>>,
+) -> Result<T, String> {
+    match timeout {
+        Some(timeout) => tokio::time::timeout(timeout, future)
+            .await
+            .map_err(|_| format!("timed out after {} ms", timeout.as_millis()))?,
+        None => future.await,
+    }
+}
+
+fn app_config(state: &AppState) -> AppConfigV1 {
+    state
+        .config
+        .lock()
+        .map(|config| config.get())
+        .unwrap_or_default()
+}
+
+pub async fn connect_v1(
+    state: &AppState,
+    request: ConnectRequestV1,
+) -> ResultEnvelope<ConnectResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("connect_v1"),
+        connect(state, request),
+    )
+    .await
+}
+
+async fn connect(state: &AppState, request: ConnectRequestV1) -> ResultEnvelope<ConnectResponseV1> {
+    let started_at = Instant::now();
+    let profile = request.profile;
+    let backend_kind = infer_backend_kind(&profile.uri);
+
+    info!(
+        "connect_v1 start name=\"{}\" uri=\"{}\" backend={:?}",
+        profile.name, profile.uri, backend_kind
+    );
+    let mut storage_options = match tuned_storage_options(&profile) {
+        Ok(storage_options) => storage_options,
+        Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
+    };
+    match &profile.auth {
+        AuthDescriptor::None => {}
+        AuthDescriptor::Inline { provider, params } => {
+            if !params.is_empty() {
+                let keys: Vec<String> = params.keys().cloned().collect();
+                trace!(
                     "connect_v1 auth_provider=\"{}\" auth_keys={:?}",
                     provider,
                     keys
@@ -1035,14 +1965,18 @@ pub async fn connect_v1(
             provider,
             reference,
         } => {
-            warn!(
-                "connect_v1 secret_ref not supported provider=\"{}\" reference=\"{}\"",
-                provider, reference
-            );
-            return ResultEnvelope::err(
-                ErrorCode::NotImplemented,
-                "secret_ref auth is not supported; resolve it before connecting",
+            let params = match resolve_secret(state, reference) {
+                Ok(params) => params,
+                Err(envelope) => return envelope,
+            };
+            let keys: Vec<&String> = params.keys().collect();
+            trace!(
+                "connect_v1 auth_provider=\"{}\" secret_ref=\"{}\" auth_keys={:?}",
+                provider,
+                reference,
+                keys
             );
+            storage_options.extend(params);
         }
     }
 
@@ -1054,41 +1988,39 @@ pub async fn connect_v1(
         debug!("connect_v1 read_consistency_interval_seconds={}", interval);
     }
 
-    let mut builder = lancedb::connect(&profile.uri);
-    if !storage_options.is_empty() {
-        builder = builder.storage_options(
-            storage_options
-                .iter()
-                .map(|(key, value)| (key.clone(), value.clone())),
-        );
-    }
-    if let Some(interval) = profile.options.read_consistency_interval_seconds {
-        builder = builder.read_consistency_interval(Duration::from_secs(interval));
-    }
-
-    let connection = match builder.execute().await {
+    let config = app_config(state);
+    let read_only = profile
+        .options
+        .read_only
+        .unwrap_or(config.read_only_default);
+    let connect = open_connection(state, &profile, &storage_options);
+    let connection = match with_timeout(config.connect_timeout(), connect).await {
         Ok(connection) => connection,
         Err(error) => {
             error!(
                 "connect_v1 failed to connect uri=\"{}\" error={}",
                 profile.uri, error
             );
-            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+            return ResultEnvelope::err(ErrorCode::Internal, error);
         }
     };
 
     let connection_id = match state.connections.lock() {
-        Ok(mut manager) => manager.insert_connection(connection),
+        Ok(mut manager) => manager.insert_connection(connection, storage_options, read_only),
         Err(_) => {
             error!("connect_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
         }
     };
 
     info!(
-        "connect_v1 ok id={} backend={:?} elapsed_ms={}",
+        "connect_v1 ok id={} backend={:?} read_only={} elapsed_ms={}",
         connection_id,
         backend_kind,
+        read_only,
         started_at.elapsed().as_millis()
     );
 
@@ -1097,12 +2029,240 @@ pub async fn connect_v1(
         backend_kind,
         name: profile.name,
         uri: profile.uri,
+        read_only,
+    })
+}
+
+/// Reads the params of a `secret_ref` credential from the stronghold vault, so they
+/// never cross IPC.
+fn resolve_secret<T>(
+    state: &AppState,
+    reference: &str,
+) -> Result<HashMap<String, String>, ResultEnvelope<T>> {
+    let resolved = match state.data_dir.as_deref() {
+        Some(data_dir) => secrets::resolve(data_dir, reference.trim()),
+        None => Ok(None),
+    };
+    match resolved {
+        Ok(Some(params)) => Ok(params),
+        Ok(None) => {
+            warn!(
+                "connect_v1 secret_ref not found reference=\"{}\"",
+                reference
+            );
+            Err(ResultEnvelope::err(
+                ErrorCode::NotFound,
+                format!("credential {reference} is not in the vault"),
+            ))
+        }
+        Err(error) => {
+            error!(
+                "connect_v1 failed to resolve secret_ref reference=\"{}\" error={}",
+                reference, error
+            );
+            Err(ResultEnvelope::err(ErrorCode::Internal, error))
+        }
+    }
+}
+
+/// The profile's storage options with its object-store tuning applied on top.
+fn tuned_storage_options(profile: &ConnectProfile) -> Result<HashMap<String, String>, String> {
+    let mut storage_options = profile.storage_options.clone();
+    if let Some(tuning) = &profile.options.object_store {
+        storage_options.extend(object_storage::tuning_options(tuning)?);
+    }
+    Ok(storage_options)
+}
+
+/// Local connections share `AppState::local_session`. Lance has no memory-mapped
+/// reader; local files are read through the OS page cache, so what stays warm
+/// between connections is the metadata and index caches of the session.
+async fn open_connection(
+    state: &AppState,
+    profile: &ConnectProfile,
+    storage_options: &HashMap<String, String>,
+) -> Result<lancedb::Connection, String> {
+    let mut builder = lancedb::connect(&profile.uri);
+    if local_path(&profile.uri).is_some() {
+        builder = builder.session(state.local_session.clone());
+    }
+    if !storage_options.is_empty() {
+        builder = builder.storage_options(
+            storage_options
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone())),
+        );
+    }
+    if let Some(interval) = profile.options.read_consistency_interval_seconds {
+        builder = builder.read_consistency_interval(Duration::from_secs(interval));
+    }
+    builder.execute().await.map_err(|error| error.to_string())
+}
+
+pub async fn rotate_secret_v1(
+    state: &AppState,
+    request: RotateSecretRequestV1,
+) -> ResultEnvelope<RotateSecretResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::admin("rotate_secret_v1"),
+        rotate_secret(state, request),
+    )
+    .await
+}
+
+/// Reconnects every live connection to the profile's URI with the credentials saved
+/// in the vault under the profile's `secret_ref`, and reopens its tables at the
+/// versions they were checked out at. Nothing is swapped in until every connection
+/// reconnected, so a failure leaves all of them on their old credentials.
+async fn rotate_secret(
+    state: &AppState,
+    request: RotateSecretRequestV1,
+) -> ResultEnvelope<RotateSecretResponseV1> {
+    let started_at = Instant::now();
+    let profile = request.profile;
+    info!(
+        "rotate_secret_v1 start name=\"{}\" uri=\"{}\"",
+        profile.name, profile.uri
+    );
+    let AuthDescriptor::SecretRef {
+        provider,
+        reference,
+    } = &profile.auth
+    else {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            "rotate_secret_v1 reads the new credentials from the vault; save them and pass a secret_ref",
+        );
+    };
+    let params = match resolve_secret(state, reference) {
+        Ok(params) => params,
+        Err(envelope) => return envelope,
+    };
+    let keys: Vec<&String> = params.keys().collect();
+    trace!(
+        "rotate_secret_v1 auth_provider=\"{}\" secret_ref=\"{}\" auth_keys={:?}",
+        provider,
+        reference,
+        keys
+    );
+    let mut storage_options = match tuned_storage_options(&profile) {
+        Ok(storage_options) => storage_options,
+        Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
+    };
+    storage_options.extend(params);
+
+    let connections = match state.connections.lock() {
+        Ok(manager) => manager
+            .connection_ids_for_uri(&profile.uri)
+            .into_iter()
+            .map(|id| {
+                let tables = manager.connection_tables(&id);
+                (id, tables)
+            })
+            .collect::<Vec<_>>(),
+        Err(_) => {
+            error!("rotate_secret_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    let timeout = app_config(state).connect_timeout();
+    let mut reconnected = Vec::with_capacity(connections.len());
+    for (connection_id, tables) in connections {
+        let reconnect = async {
+            let connection = open_connection(state, &profile, &storage_options).await?;
+            let mut reopened = Vec::with_capacity(tables.len());
+            for table in tables {
+                let name = &table.name;
+                let handle = connection
+                    .open_table(name)
+                    .execute()
+                    .await
+                    .map_err(|error| format!("failed to reopen table {name}: {error}"))?;
+                if let Some(version) = table.checked_out_version {
+                    handle.checkout(version).await.map_err(|error| {
+                        format!("failed to check out version {version} of table {name}: {error}")
+                    })?;
+                }
+                reopened.push((table.table_id, handle));
+            }
+            Ok::<_, String>((connection, reopened))
+        };
+        match with_timeout(timeout, reconnect).await {
+            Ok((connection, reopened)) => reconnected.push((connection_id, connection, reopened)),
+            Err(error) => {
+                error!(
+                    "rotate_secret_v1 failed to reconnect connection_id={} error={}; no connection was changed",
+                    connection_id, error
+                );
+                return ResultEnvelope::err(
+                    ErrorCode::Internal,
+                    format!("failed to reconnect connection {connection_id}: {error}"),
+                );
+            }
+        }
+    }
+
+    let mut connection_ids = Vec::new();
+    let mut reopened_tables = 0;
+    match state.connections.lock() {
+        Ok(mut manager) => {
+            for (connection_id, connection, reopened) in reconnected {
+                let count = reopened.len();
+                // A connection closed meanwhile is skipped rather than reported.
+                if manager.replace_connection(
+                    &connection_id,
+                    connection,
+                    storage_options.clone(),
+                    reopened,
+                ) {
+                    connection_ids.push(connection_id);
+                    reopened_tables += count;
+                }
+            }
+        }
+        Err(_) => {
+            error!("rotate_secret_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    }
+
+    info!(
+        "rotate_secret_v1 ok uri=\"{}\" connections={} tables={} elapsed_ms={}",
+        profile.uri,
+        connection_ids.len(),
+        reopened_tables,
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(RotateSecretResponseV1 {
+        connection_ids,
+        reopened_tables,
     })
 }
 
 pub async fn disconnect_v1(
     state: &AppState,
     request: DisconnectRequestV1,
+) -> ResultEnvelope<DisconnectResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("disconnect_v1"),
+        disconnect(state, request),
+    )
+    .await
+}
+
+async fn disconnect(
+    state: &AppState,
+    request: DisconnectRequestV1,
 ) -> ResultEnvelope<DisconnectResponseV1> {
     let started_at = Instant::now();
     info!(
@@ -1118,12 +2278,18 @@ pub async fn disconnect_v1(
                     "disconnect_v1 connection not found connection_id={}",
                     request.connection_id
                 );
-                return ResultEnvelope::err(ErrorCode::NotFound, "connection not found");
+                return ResultEnvelope::err_message(
+                    ErrorCode::NotFound,
+                    messages::message(MessageCodeV1::ConnectionNotFound),
+                );
             }
         },
         Err(_) => {
             error!("disconnect_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
         }
     };
 
@@ -1140,9 +2306,95 @@ pub async fn disconnect_v1(
     })
 }
 
+/// Tables whose stats are read at once when `list_tables_v1` sets `include_stats`.
+const LIST_TABLES_STATS_CONCURRENCY: usize = 8;
+
+async fn read_table_storage_stats(
+    backend: &dyn DatastoreBackend,
+    name: &str,
+) -> Result<TableStorageStats, BackendError> {
+    backend.open_table(name).await?.storage_stats().await
+}
+
+/// Lists the tables of `backend` with their saved descriptions and, when
+/// `include_stats` is set, their storage stats. Without stats, row counts come from
+/// `row_counts` where a table has been counted before.
+async fn list_table_infos(
+    backend: &dyn DatastoreBackend,
+    mut described: HashMap<String, TableDescriptionV1>,
+    include_stats: bool,
+    row_counts: &Mutex<RowCountCache>,
+) -> Result<Vec<TableInfo>, BackendError> {
+    let names = backend.table_names().await?;
+    let mut tables: Vec<TableInfo> = names
+        .into_iter()
+        .map(|name| TableInfo {
+            description: described.remove(&name),
+            name,
+            row_count: None,
+            row_count_cached: false,
+            version: None,
+            last_modified_ms: None,
+            size_bytes: None,
+        })
+        .collect();
+    if !include_stats {
+        if let Ok(cache) = row_counts.lock() {
+            for table in &mut tables {
+                if let Some(cached) = cache.get(backend.uri(), &table.name) {
+                    table.row_count = Some(cached.row_count);
+                    table.row_count_cached = true;
+                }
+            }
+        }
+        return Ok(tables);
+    }
+
+    let stats: Vec<_> = futures_util::stream::iter(
+        tables
+            .iter()
+            .map(|table| read_table_storage_stats(backend, &table.name)),
+    )
+    .buffered(LIST_TABLES_STATS_CONCURRENCY)
+    .collect()
+    .await;
+    for (table, stats) in tables.iter_mut().zip(stats) {
+        match stats {
+            Ok(stats) => {
+                if let Ok(mut cache) = row_counts.lock() {
+                    cache.record(backend.uri(), &table.name, stats.version, stats.row_count);
+                }
+                table.row_count = Some(stats.row_count);
+                table.version = Some(stats.version);
+                table.last_modified_ms = stats.last_modified_ms;
+                table.size_bytes = Some(stats.size_bytes);
+            }
+            Err(error) => warn!(
+                "list_tables_v1 failed to read stats uri={} table={} error={}",
+                backend.uri(),
+                table.name,
+                error
+            ),
+        }
+    }
+    Ok(tables)
+}
+
 pub async fn list_tables_v1(
     state: &AppState,
     request: ListTablesRequestV1,
+) -> ResultEnvelope<ListTablesResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("list_tables_v1"),
+        list_tables(state, request),
+    )
+    .await
+}
+
+async fn list_tables(
+    state: &AppState,
+    request: ListTablesRequestV1,
 ) -> ResultEnvelope<ListTablesResponseV1> {
     let started_at = Instant::now();
     info!(
@@ -1153,7 +2405,10 @@ pub async fn list_tables_v1(
         Ok(manager) => manager.get_connection(&request.connection_id),
         Err(_) => {
             error!("list_tables_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
         }
     };
 
@@ -1162,35 +2417,82 @@ pub async fn list_tables_v1(
             "list_tables_v1 connection not found connection_id={}",
             request.connection_id
         );
-        return ResultEnvelope::err(ErrorCode::NotFound, "connection not found");
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::ConnectionNotFound),
+        );
     };
 
-    let names: Vec<String> = match connection.table_names().execute().await {
-        Ok(names) => names,
+    let described = match state.descriptions.lock() {
+        Ok(store) => store.for_connection(connection.uri()),
+        Err(_) => {
+            error!("list_tables_v1 failed to lock description store");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+    let backend = LanceBackend::new(connection);
+    let tables = match list_table_infos(
+        &backend,
+        described,
+        request.include_stats,
+        &state.row_counts,
+    )
+    .await
+    {
+        Ok(tables) => tables,
         Err(error) => {
             error!(
                 "list_tables_v1 failed connection_id={} error={} ",
                 request.connection_id, error
             );
-            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+            return ResultEnvelope::err(error.code(), error.to_string());
         }
     };
 
-    let tables: Vec<TableInfo> = names.into_iter().map(|name| TableInfo { name }).collect();
-
     info!(
-        "list_tables_v1 ok connection_id={} tables={} elapsed_ms={}",
+        "list_tables_v1 ok connection_id={} tables={} include_stats={} elapsed_ms={}",
         request.connection_id,
         tables.len(),
+        request.include_stats,
         started_at.elapsed().as_millis()
     );
 
     ResultEnvelope::ok(ListTablesResponseV1 { tables })
 }
 
+/// NotFound error for a missing table. `details` lists the tables that do exist so a
+/// misspelt name is easy to spot.
+async fn table_not_found_error(backend: &dyn DatastoreBackend, table_name: &str) -> ErrorEnvelope {
+    let available_tables = backend.table_names().await.unwrap_or_default();
+    let mut error = ErrorEnvelope::from_message(
+        ErrorCode::NotFound,
+        messages::message(MessageCodeV1::TableNotFound),
+    );
+    error.details = Some(serde_json::json!({
+        "tableName": table_name,
+        "availableTables": available_tables,
+    }));
+    error
+}
+
 pub async fn drop_table_v1(
     state: &AppState,
     request: DropTableRequestV1,
+) -> ResultEnvelope<DropTableResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::write_connection("drop_table_v1", &request.connection_id),
+        drop_table(state, request),
+    )
+    .await
+}
+
+async fn drop_table(
+    state: &AppState,
+    request: DropTableRequestV1,
 ) -> ResultEnvelope<DropTableResponseV1> {
     let started_at = Instant::now();
     info!(
@@ -1202,7 +2504,10 @@ pub async fn drop_table_v1(
         Ok(manager) => manager.get_connection(&request.connection_id),
         Err(_) => {
             error!("drop_table_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
         }
     };
 
@@ -1211,16 +2516,29 @@ pub async fn drop_table_v1(
             "drop_table_v1 connection not found connection_id={}",
             request.connection_id
         );
-        return ResultEnvelope::err(ErrorCode::NotFound, "connection not found");
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::ConnectionNotFound),
+        );
     };
 
     let namespace = request.namespace.unwrap_or_default();
-    if let Err(error) = connection.drop_table(&request.table_name, &namespace).await {
+    let backend = LanceBackend::new(connection);
+    if let Err(error) = backend.drop_table(&request.table_name, &namespace).await {
         error!(
             "drop_table_v1 failed connection_id={} table=\"{}\" error={}",
             request.connection_id, request.table_name, error
         );
-        return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        if matches!(error, BackendError::TableNotFound { .. }) {
+            return ResultEnvelope::err_envelope(
+                table_not_found_error(&backend, &request.table_name).await,
+            );
+        }
+        return ResultEnvelope::err(error.code(), error.to_string());
+    }
+
+    if let Ok(mut cache) = state.row_counts.lock() {
+        cache.remove(backend.uri(), &request.table_name);
     }
 
     info!(
@@ -1238,6 +2556,18 @@ pub async fn drop_table_v1(
 pub async fn rename_table_v1(
     state: &AppState,
     request: RenameTableRequestV1,
+) -> ResultEnvelope<RenameTableResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::write_connection("rename_table_v1", &request.connection_id),
+        rename_table(state, request),
+    )
+    .await
+}
+
+async fn rename_table(
+    state: &AppState,
+    request: RenameTableRequestV1,
 ) -> ResultEnvelope<RenameTableResponseV1> {
     let started_at = Instant::now();
     info!(
@@ -1247,7 +2577,10 @@ pub async fn rename_table_v1(
 
     let table_name = request.table_name.trim();
     if table_name.is_empty() {
-        return ResultEnvelope::err(ErrorCode::InvalidArgument, "table name cannot be empty");
+        return ResultEnvelope::err_message(
+            ErrorCode::InvalidArgument,
+            messages::message(MessageCodeV1::TableNameEmpty),
+        );
     }
 
     let new_table_name = request.new_table_name.trim();
@@ -1266,7 +2599,10 @@ pub async fn rename_table_v1(
         Ok(manager) => manager.get_connection(&request.connection_id),
         Err(_) => {
             error!("rename_table_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
         }
     };
 
@@ -1275,28 +2611,32 @@ pub async fn rename_table_v1(
             "rename_table_v1 connection not found connection_id={}",
             request.connection_id
         );
-        return ResultEnvelope::err(ErrorCode::NotFound, "connection not found");
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::ConnectionNotFound),
+        );
     };
 
     let namespace = request.namespace.unwrap_or_default();
     let new_namespace = request.new_namespace.unwrap_or_default();
 
-    if let Err(error) = connection
+    let backend = LanceBackend::new(connection);
+    if let Err(error) = backend
         .rename_table(table_name, new_table_name, &namespace, &new_namespace)
         .await
     {
-        let message = error.to_string();
-        let lower = message.to_lowercase();
-        let code = if lower.contains("not supported") {
-            ErrorCode::NotImplemented
-        } else {
-            ErrorCode::Internal
-        };
         error!(
             "rename_table_v1 failed connection_id={} table=\"{}\" error={}",
-            request.connection_id, table_name, message
+            request.connection_id, table_name, error
         );
-        return ResultEnvelope::err(code, message);
+        if matches!(error, BackendError::TableNotFound { .. }) {
+            return ResultEnvelope::err_envelope(table_not_found_error(&backend, table_name).await);
+        }
+        return ResultEnvelope::err(error.code(), error.to_string());
+    }
+
+    if let Ok(mut cache) = state.row_counts.lock() {
+        cache.rename(backend.uri(), table_name, new_table_name);
     }
 
     info!(
@@ -1316,63 +2656,38 @@ pub async fn rename_table_v1(
 pub async fn list_indexes_v1(
     state: &AppState,
     request: ListIndexesRequestV1,
+) -> ResultEnvelope<ListIndexesResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("list_indexes_v1"),
+        list_indexes(state, request),
+    )
+    .await
+}
+
+async fn list_indexes(
+    state: &AppState,
+    request: ListIndexesRequestV1,
 ) -> ResultEnvelope<ListIndexesResponseV1> {
     let started_at = Instant::now();
     info!("list_indexes_v1 start table_id={}", request.table_id);
 
-    let table = match state.connections.lock() {
-        Ok(manager) => manager.get_table(&request.table_id),
-        Err(_) => {
-            error!("list_indexes_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
-        }
-    };
-
-    let Some(table) = table else {
-        warn!(
-            "list_indexes_v1 table not found table_id={}",
-            request.table_id
-        );
-        return ResultEnvelope::err(ErrorCode::NotFound, "table not found");
+    let table = match resolve_table(state, &request.table_id, "list_indexes_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
     };
 
-    let index_configs = match table.list_indices().await {
-        Ok(configs) => configs,
+    let indexes = match LanceTableBackend::new(table).list_indexes().await {
+        Ok(indexes) => indexes,
         Err(error) => {
             error!(
                 "list_indexes_v1 failed table_id={} error={}",
                 request.table_id, error
             );
-            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+            return ResultEnvelope::err(error.code(), error.to_string());
         }
     };
 
-    let mut indexes = Vec::new();
-    for config in index_configs {
-        let stats = match table.index_stats(&config.name).await {
-            Ok(stats) => stats,
-            Err(error) => {
-                warn!(
-                    "list_indexes_v1 failed to read index stats table_id={} index={} error={}",
-                    request.table_id, config.name, error
-                );
-                None
-            }
-        };
-        indexes.push(IndexDefinitionV1 {
-            name: config.name,
-            index_type: to_index_type_v1(&config.index_type),
-            columns: config.columns,
-            num_indexed_rows: stats.as_ref().map(|stats| stats.num_indexed_rows),
-            num_unindexed_rows: stats.as_ref().map(|stats| stats.num_unindexed_rows),
-            distance_type: stats
-                .as_ref()
-                .and_then(|stats| stats.distance_type.as_ref().map(to_distance_type_v1)),
-            num_indices: stats.as_ref().and_then(|stats| stats.num_indices),
-            loss: stats.as_ref().and_then(|stats| stats.loss),
-        });
-    }
-
     info!(
         "list_indexes_v1 ok table_id={} indexes={} elapsed_ms={}",
         request.table_id,
@@ -1383,23 +2698,132 @@ pub async fn list_indexes_v1(
     ResultEnvelope::ok(ListIndexesResponseV1 { indexes })
 }
 
-pub async fn create_index_v1(
+pub async fn index_stats_v1(
     state: &AppState,
-    request: CreateIndexRequestV1,
-) -> ResultEnvelope<CreateIndexResponseV1> {
-    let started_at = Instant::now();
+    request: IndexStatsRequestV1,
+) -> ResultEnvelope<IndexStatsResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("index_stats_v1"),
+        index_stats(state, request),
+    )
+    .await
+}
+
+/// Coverage of one index, so the UI can tell whether it needs an optimize.
+async fn index_stats(
+    state: &AppState,
+    request: IndexStatsRequestV1,
+) -> ResultEnvelope<IndexStatsResponseV1> {
     info!(
-        "create_index_v1 start table_id={} columns={} index_type={:?}",
-        request.table_id,
-        request.columns.len(),
-        request.index_type
+        "index_stats_v1 start table_id={} index_name=\"{}\"",
+        request.table_id, request.index_name
     );
 
-    let columns = match sanitize_index_columns(&request.columns) {
-        Ok(columns) => columns,
-        Err(error) => {
-            warn!("create_index_v1 invalid columns error={}", error);
-            return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+    let table = match resolve_table(state, &request.table_id, "index_stats_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+
+    let read = async {
+        let configs = table
+            .list_indices()
+            .await
+            .map_err(|error| error.to_string())?;
+        let Some(config) = configs
+            .into_iter()
+            .find(|config| config.name == request.index_name)
+        else {
+            return Ok(None);
+        };
+        let stats = table
+            .index_stats(&config.name)
+            .await
+            .map_err(|error| error.to_string())?;
+        Ok::<_, String>(stats.map(|stats| (config, stats)))
+    };
+    let (config, stats) = match read.await {
+        Ok(Some(found)) => found,
+        Ok(None) => {
+            warn!(
+                "index_stats_v1 index not found table_id={} index_name=\"{}\"",
+                request.table_id, request.index_name
+            );
+            return ResultEnvelope::err(
+                ErrorCode::NotFound,
+                format!("index not found: {}", request.index_name),
+            );
+        }
+        Err(error) => {
+            error!(
+                "index_stats_v1 failed table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    };
+
+    let indexed_rows = stats.num_indexed_rows as u64;
+    let unindexed_rows = stats.num_unindexed_rows as u64;
+    let readiness = index_coverage::readiness(indexed_rows, unindexed_rows);
+    debug!(
+        "index_stats_v1 ok table_id={} index_name=\"{}\" readiness={:?}",
+        request.table_id, config.name, readiness
+    );
+
+    ResultEnvelope::ok(IndexStatsResponseV1 {
+        table_id: request.table_id,
+        index_name: config.name,
+        index_type: to_index_type_v1(&config.index_type),
+        estimated_partitions: is_vector_index_type(&config.index_type)
+            .then(|| estimated_num_partitions(stats.num_indexed_rows)),
+        columns: config.columns,
+        num_indexed_rows: indexed_rows,
+        num_unindexed_rows: unindexed_rows,
+        coverage: index_coverage::coverage_ratio(indexed_rows, unindexed_rows),
+        readiness,
+        needs_optimize: unindexed_rows > 0,
+        num_indices: stats.num_indices,
+        distance_type: stats.distance_type.as_ref().map(to_distance_type_v1),
+        loss: stats.loss,
+    })
+}
+
+pub async fn create_index_v1(
+    state: &AppState,
+    request: CreateIndexRequestV1,
+) -> ResultEnvelope<CreateIndexResponseV1> {
+    run_as_job(
+        state,
+        request.job_id.clone(),
+        JobKindV1::IndexBuild,
+        request.name.clone(),
+        middleware::run(
+            state,
+            CommandContext::write_table("create_index_v1", &request.table_id),
+            create_index(state, request),
+        ),
+    )
+    .await
+}
+
+async fn create_index(
+    state: &AppState,
+    mut request: CreateIndexRequestV1,
+) -> ResultEnvelope<CreateIndexResponseV1> {
+    let started_at = Instant::now();
+    info!(
+        "create_index_v1 start table_id={} columns={} index_type={:?}",
+        request.table_id,
+        request.columns.len(),
+        request.index_type
+    );
+
+    let columns = match sanitize_index_columns(&request.columns) {
+        Ok(columns) => columns,
+        Err(error) => {
+            warn!("create_index_v1 invalid columns error={}", error);
+            return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
         }
     };
 
@@ -1409,32 +2833,89 @@ pub async fn create_index_v1(
         .map(|value| value.trim())
         .filter(|value| !value.is_empty());
     if request.name.is_some() && name.is_none() {
-        return ResultEnvelope::err(ErrorCode::InvalidArgument, "index name cannot be empty");
+        return ResultEnvelope::err_message(
+            ErrorCode::InvalidArgument,
+            messages::message(MessageCodeV1::IndexNameEmpty),
+        );
     }
     let resolved_name = name.map(str::to_string);
 
-    let table = match state.connections.lock() {
-        Ok(manager) => manager.get_table(&request.table_id),
-        Err(_) => {
-            error!("create_index_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
+    let accelerator = match accelerator::resolve(request.accelerator, &request.index_type) {
+        Ok(accelerator) => accelerator,
+        Err(error) => {
+            warn!("create_index_v1 unsupported accelerator error={}", error);
+            return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
         }
     };
 
-    let Some(table) = table else {
-        warn!(
-            "create_index_v1 table not found table_id={}",
-            request.table_id
-        );
-        return ResultEnvelope::err(ErrorCode::NotFound, "table not found");
+    if let Err(error) = fts_tokenizer::validate(request.fts_tokenizer.as_ref(), &request.index_type)
+    {
+        warn!("create_index_v1 invalid tokenizer options error={}", error);
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+    }
+
+    if let Some(preset) = request.preset {
+        if let Err(error) = index_presets::validate(&request.index_type) {
+            return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+        }
+        if columns.len() != 1 {
+            return ResultEnvelope::err(
+                ErrorCode::InvalidArgument,
+                "index presets need exactly one vector column",
+            );
+        }
+        debug!("create_index_v1 preset={:?}", preset);
+    }
+
+    let table = match resolve_table(state, &request.table_id, "create_index_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+
+    let preset_parameters = match request.preset {
+        Some(preset) => {
+            report_stage(state, ProgressStageV1::Reading);
+            let sized = async {
+                let schema = table.schema().await.map_err(|error| error.to_string())?;
+                let dimension = index_presets::vector_dimension(&schema, &columns[0])?;
+                let rows = table
+                    .count_rows(None)
+                    .await
+                    .map_err(|error| error.to_string())?;
+                Ok::<_, String>(index_presets::parameters(
+                    preset,
+                    &request.index_type,
+                    rows,
+                    dimension,
+                ))
+            };
+            match sized.await {
+                Ok(parameters) => {
+                    index_presets::apply(&mut request, &parameters);
+                    Some(parameters)
+                }
+                Err(error) => {
+                    warn!("create_index_v1 preset failed error={}", error);
+                    return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+                }
+            }
+        }
+        None => None,
     };
 
-    let index = to_lancedb_index(&request);
+    let index = match to_lancedb_index(&request) {
+        Ok(index) => index,
+        Err(error) => {
+            warn!("create_index_v1 invalid index options error={}", error);
+            return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+        }
+    };
     let mut builder = table.create_index(&columns, index).replace(request.replace);
     if let Some(name) = resolved_name.as_ref() {
         builder = builder.name(name.clone());
     }
 
+    report_stage(state, ProgressStageV1::Indexing);
     if let Err(error) = builder.execute().await {
         error!(
             "create_index_v1 failed table_id={} error={}",
@@ -1444,8 +2925,9 @@ pub async fn create_index_v1(
     }
 
     info!(
-        "create_index_v1 ok table_id={} elapsed_ms={}",
+        "create_index_v1 ok table_id={} accelerator={:?} elapsed_ms={}",
         request.table_id,
+        accelerator,
         started_at.elapsed().as_millis()
     );
 
@@ -1454,12 +2936,46 @@ pub async fn create_index_v1(
         index_type: request.index_type,
         columns,
         name: resolved_name,
+        accelerator,
+        preset_parameters,
     })
 }
 
+pub async fn get_index_capabilities_v1(
+    state: &AppState,
+) -> ResultEnvelope<IndexCapabilitiesResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("get_index_capabilities_v1"),
+        get_index_capabilities(),
+    )
+    .await
+}
+
+async fn get_index_capabilities() -> ResultEnvelope<IndexCapabilitiesResponseV1> {
+    let capabilities = accelerator::capabilities();
+    debug!(
+        "get_index_capabilities_v1 accelerators={:?} cpu_threads={}",
+        capabilities.accelerators, capabilities.cpu_threads
+    );
+    ResultEnvelope::ok(capabilities)
+}
+
 pub async fn drop_index_v1(
     state: &AppState,
     request: DropIndexRequestV1,
+) -> ResultEnvelope<DropIndexResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::write_table("drop_index_v1", &request.table_id),
+        drop_index(state, request),
+    )
+    .await
+}
+
+async fn drop_index(
+    state: &AppState,
+    request: DropIndexRequestV1,
 ) -> ResultEnvelope<DropIndexResponseV1> {
     let started_at = Instant::now();
     info!(
@@ -1469,31 +2985,23 @@ pub async fn drop_index_v1(
 
     let index_name = request.index_name.trim();
     if index_name.is_empty() {
-        return ResultEnvelope::err(ErrorCode::InvalidArgument, "index name cannot be empty");
+        return ResultEnvelope::err_message(
+            ErrorCode::InvalidArgument,
+            messages::message(MessageCodeV1::IndexNameEmpty),
+        );
     }
 
-    let table = match state.connections.lock() {
-        Ok(manager) => manager.get_table(&request.table_id),
-        Err(_) => {
-            error!("drop_index_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
-        }
-    };
-
-    let Some(table) = table else {
-        warn!(
-            "drop_index_v1 table not found table_id={}",
-            request.table_id
-        );
-        return ResultEnvelope::err(ErrorCode::NotFound, "table not found");
+    let table = match resolve_table(state, &request.table_id, "drop_index_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
     };
 
-    if let Err(error) = table.drop_index(index_name).await {
+    if let Err(error) = LanceTableBackend::new(table).drop_index(index_name).await {
         error!(
             "drop_index_v1 failed table_id={} error={}",
             request.table_id, error
         );
-        return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        return ResultEnvelope::err(error.code(), error.to_string());
     }
 
     info!(
@@ -1511,6 +3019,18 @@ pub async fn drop_index_v1(
 pub async fn create_table_v1(
     state: &AppState,
     request: CreateTableRequestV1,
+) -> ResultEnvelope<CreateTableResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::write_connection("create_table_v1", &request.connection_id),
+        create_table(state, request),
+    )
+    .await
+}
+
+async fn create_table(
+    state: &AppState,
+    request: CreateTableRequestV1,
 ) -> ResultEnvelope<CreateTableResponseV1> {
     let started_at = Instant::now();
     info!(
@@ -1519,14 +3039,33 @@ pub async fn create_table_v1(
     );
 
     if request.table_name.trim().is_empty() {
-        return ResultEnvelope::err(ErrorCode::InvalidArgument, "table name cannot be empty");
+        return ResultEnvelope::err_message(
+            ErrorCode::InvalidArgument,
+            messages::message(MessageCodeV1::TableNameEmpty),
+        );
     }
 
-    let connection = match state.connections.lock() {
-        Ok(manager) => manager.get_connection(&request.connection_id),
+    let creation_options = match table_creation_options(&request.options) {
+        Ok(options) => options,
+        Err(error) => {
+            warn!("create_table_v1 invalid options error={}", error);
+            return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+        }
+    };
+
+    let (connection, storage_options) = match state.connections.lock() {
+        Ok(manager) => (
+            manager.get_connection(&request.connection_id),
+            manager
+                .get_storage_options(&request.connection_id)
+                .unwrap_or_default(),
+        ),
         Err(_) => {
             error!("create_table_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
         }
     };
 
@@ -1535,7 +3074,10 @@ pub async fn create_table_v1(
             "create_table_v1 connection not found connection_id={}",
             request.connection_id
         );
-        return ResultEnvelope::err(ErrorCode::NotFound, "connection not found");
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::ConnectionNotFound),
+        );
     };
 
     let schema = match to_arrow_schema(&request.schema) {
@@ -1546,6 +3088,27 @@ pub async fn create_table_v1(
         }
     };
 
+    // Creation options are connection-level settings in LanceDB, so tables that need
+    // them are created through a short-lived connection to the same location.
+    let connection = if creation_options.is_empty() {
+        connection
+    } else {
+        debug!("create_table_v1 options={:?}", creation_options);
+        let mut options = storage_options;
+        options.extend(creation_options);
+        match connect_uri(connection.uri(), Some(&options)).await {
+            Ok(connection) => connection,
+            Err(error) => {
+                error!(
+                    "create_table_v1 failed to connect uri=\"{}\" error={}",
+                    connection.uri(),
+                    error
+                );
+                return ResultEnvelope::err(ErrorCode::Internal, error);
+            }
+        }
+    };
+
     let table = match connection
         .create_empty_table(&request.table_name, schema)
         .execute()
@@ -1569,7 +3132,10 @@ pub async fn create_table_v1(
         ),
         Err(_) => {
             error!("create_table_v1 failed to lock table manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock table manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
         }
     };
 
@@ -1590,24 +3156,25 @@ pub async fn create_table_v1(
 pub async fn add_columns_v1(
     state: &AppState,
     request: AddColumnsRequestV1,
+) -> ResultEnvelope<AddColumnsResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::write_table("add_columns_v1", &request.table_id),
+        add_columns(state, request),
+    )
+    .await
+}
+
+async fn add_columns(
+    state: &AppState,
+    request: AddColumnsRequestV1,
 ) -> ResultEnvelope<AddColumnsResponseV1> {
     let started_at = Instant::now();
     info!("add_columns_v1 start table_id={}", request.table_id);
 
-    let table = match state.connections.lock() {
-        Ok(manager) => manager.get_table(&request.table_id),
-        Err(_) => {
-            error!("add_columns_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
-        }
-    };
-
-    let Some(table) = table else {
-        warn!(
-            "add_columns_v1 table not found table_id={}",
-            request.table_id
-        );
-        return ResultEnvelope::err(ErrorCode::NotFound, "table not found");
+    let table = match resolve_table(state, &request.table_id, "add_columns_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
     };
 
     let schema = match to_arrow_schema(&request.columns) {
@@ -1619,13 +3186,16 @@ pub async fn add_columns_v1(
     };
 
     let transforms = NewColumnTransform::AllNulls(schema);
-    if let Err(error) = table.add_columns(transforms, None).await {
-        error!(
-            "add_columns_v1 failed table_id={} error={}",
-            request.table_id, error
-        );
-        return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
-    }
+    let added_version = match table.add_columns(transforms, None).await {
+        Ok(result) => result.version,
+        Err(error) => {
+            error!(
+                "add_columns_v1 failed table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    };
 
     let updated_schema = match read_table_schema(&table).await {
         Ok(schema) => schema,
@@ -1645,6 +3215,16 @@ pub async fn add_columns_v1(
         .map(|field| field.name.clone())
         .collect::<Vec<_>>();
 
+    after_table_write(state, &request.table_id);
+    auto_checkpoint(
+        state,
+        &request.table_id,
+        "add-columns",
+        Some(added_version),
+        request.checkpoint_note.as_deref(),
+    )
+    .await;
+
     info!(
         "add_columns_v1 ok table_id={} added={} elapsed_ms={}",
         request.table_id,
@@ -1695,24 +3275,25 @@ fn build_column_alteration(input: &ColumnAlterationInput) -> Result<ColumnAltera
 pub async fn alter_columns_v1(
     state: &AppState,
     request: AlterColumnsRequestV1,
+) -> ResultEnvelope<AlterColumnsResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::write_table("alter_columns_v1", &request.table_id),
+        alter_columns(state, request),
+    )
+    .await
+}
+
+async fn alter_columns(
+    state: &AppState,
+    request: AlterColumnsRequestV1,
 ) -> ResultEnvelope<AlterColumnsResponseV1> {
     let started_at = Instant::now();
     info!("alter_columns_v1 start table_id={}", request.table_id);
 
-    let table = match state.connections.lock() {
-        Ok(manager) => manager.get_table(&request.table_id),
-        Err(_) => {
-            error!("alter_columns_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
-        }
-    };
-
-    let Some(table) = table else {
-        warn!(
-            "alter_columns_v1 table not found table_id={}",
-            request.table_id
-        );
-        return ResultEnvelope::err(ErrorCode::NotFound, "table not found");
+    let table = match resolve_table(state, &request.table_id, "alter_columns_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
     };
 
     if request.columns.is_empty() {
@@ -1737,12 +3318,59 @@ pub async fn alter_columns_v1(
         }
     };
 
-    if let Err(error) = table.alter_columns(&alterations).await {
-        error!(
-            "alter_columns_v1 failed table_id={} error={}",
-            request.table_id, error
-        );
-        return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+    // Read before altering: a cast can take the index on the old column with it.
+    let mut index_rebuilds = match plan_index_rebuilds(&table, &request.columns).await {
+        Ok(plan) => plan,
+        Err(error) => {
+            warn!(
+                "alter_columns_v1 failed to list indexes table_id={} error={}",
+                request.table_id, error
+            );
+            Vec::new()
+        }
+    };
+
+    let altered_version = match table.alter_columns(&alterations).await {
+        Ok(result) => result.version,
+        Err(error) => {
+            error!(
+                "alter_columns_v1 failed table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    };
+
+    let rebuild_job_id = if request.rebuild_indexes && !index_rebuilds.is_empty() {
+        rebuild_indexes(state, &request.table_id, &table, &mut index_rebuilds).await
+    } else {
+        None
+    };
+
+    let renames = rename_cascade::renames(&request.columns);
+    let mut updated_artifacts = Vec::new();
+    if !renames.is_empty() {
+        match rename_column_order(&table, &renames).await {
+            Ok(true) => updated_artifacts.push(UpdatedArtifactV1 {
+                kind: SavedArtifactKindV1::ColumnOrder,
+                name: table.name().to_string(),
+            }),
+            Ok(false) => {}
+            Err(error) => warn!(
+                "alter_columns_v1 failed to update column order table_id={} error={}",
+                request.table_id, error
+            ),
+        }
+        match table_settings_key(state, &request.table_id) {
+            Ok(Some((connection_uri, table_name))) => updated_artifacts.extend(
+                rename_cascade::cascade(state, &connection_uri, &table_name, &renames),
+            ),
+            Ok(None) => {}
+            Err(_) => warn!(
+                "alter_columns_v1 failed to look up saved settings table_id={}",
+                request.table_id
+            ),
+        }
     }
 
     let updated_schema = match read_table_schema(&table).await {
@@ -1756,10 +3384,22 @@ pub async fn alter_columns_v1(
         }
     };
 
+    after_table_write(state, &request.table_id);
+    auto_checkpoint(
+        state,
+        &request.table_id,
+        "alter-columns",
+        Some(altered_version),
+        request.checkpoint_note.as_deref(),
+    )
+    .await;
+
     info!(
-        "alter_columns_v1 ok table_id={} updated={} elapsed_ms={}",
+        "alter_columns_v1 ok table_id={} updated={} affected_indexes={} updated_artifacts={} elapsed_ms={}",
         request.table_id,
         updated_paths.len(),
+        index_rebuilds.len(),
+        updated_artifacts.len(),
         started_at.elapsed().as_millis()
     );
 
@@ -1767,12 +3407,181 @@ pub async fn alter_columns_v1(
         table_id: request.table_id,
         updated: updated_paths,
         schema: updated_schema,
+        index_rebuilds,
+        rebuild_job_id,
+        updated_artifacts,
     })
 }
 
+/// Points the display order stored on a local table at the new names of renamed
+/// columns, which it would otherwise drop. Returns whether the order changed.
+async fn rename_column_order(
+    table: &Table,
+    renames: &[rename_cascade::ColumnRename],
+) -> Result<bool, String> {
+    let Some(native) = table.as_native() else {
+        return Ok(false);
+    };
+    let schema = table.schema().await.map_err(|error| error.to_string())?;
+    let Some(order) = column_order::listed(schema.as_ref())
+        .and_then(|order| rename_cascade::rename_columns(&order, renames))
+    else {
+        return Ok(false);
+    };
+    let metadata = column_order::metadata(schema.as_ref(), &order)?;
+    native
+        .replace_schema_metadata(metadata)
+        .await
+        .map_err(|error| error.to_string())?;
+    Ok(true)
+}
+
+/// Indexes over the columns `inputs` rename or retype, described as they will be
+/// after the alteration.
+async fn plan_index_rebuilds(
+    table: &Table,
+    inputs: &[ColumnAlterationInput],
+) -> Result<Vec<IndexRebuildV1>, String> {
+    let configs = table
+        .list_indices()
+        .await
+        .map_err(|error| error.to_string())?;
+    let mut plan = Vec::new();
+    for config in configs {
+        let mut reason = None;
+        let mut columns = config.columns.clone();
+        for column in &mut columns {
+            let Some(input) = inputs
+                .iter()
+                .find(|input| input.path.trim() == column.as_str())
+            else {
+                continue;
+            };
+            if input.data_type.is_some() {
+                reason = Some(IndexRebuildReasonV1::Retyped);
+            }
+            if let Some(rename) = input
+                .rename
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+            {
+                *column = rename.to_string();
+                reason.get_or_insert(IndexRebuildReasonV1::Renamed);
+            }
+        }
+        let Some(reason) = reason else {
+            continue;
+        };
+        // Lance names an index `<column>_idx` unless it is given a name.
+        let new_index_name = match (config.columns.as_slice(), columns.as_slice()) {
+            ([old], [new]) if config.name == format!("{old}_idx") => format!("{new}_idx"),
+            _ => config.name.clone(),
+        };
+        let distance_type = match table.index_stats(&config.name).await {
+            Ok(stats) => stats
+                .and_then(|stats| stats.distance_type)
+                .as_ref()
+                .map(to_distance_type_v1),
+            Err(_) => None,
+        };
+        plan.push(IndexRebuildV1 {
+            index_name: config.name,
+            index_type: to_index_type_v1(&config.index_type),
+            columns,
+            reason,
+            new_index_name,
+            distance_type,
+            state: IndexRebuildStateV1::Planned,
+            error: None,
+        });
+    }
+    Ok(plan)
+}
+
+/// Drops and recreates the planned indexes with default parameters, tracked as an
+/// `index_build` job. Dropping may fail when lance already dropped the index along
+/// with a cast; the rebuild goes ahead either way.
+async fn rebuild_indexes(
+    state: &AppState,
+    table_id: &str,
+    table: &Table,
+    plan: &mut [IndexRebuildV1],
+) -> Option<String> {
+    let job_id = state
+        .jobs
+        .lock()
+        .ok()
+        .and_then(|mut jobs| jobs.start(None, JobKindV1::IndexBuild).ok());
+    if let Some(job_id) = job_id.as_deref() {
+        set_job_stage(state, job_id, ProgressStageV1::Indexing);
+    }
+    let mut failures = Vec::new();
+    for rebuild in plan.iter_mut() {
+        if let Err(error) = table.drop_index(&rebuild.index_name).await {
+            debug!(
+                "alter_columns_v1 index already gone table_id={} index={} error={}",
+                table_id, rebuild.index_name, error
+            );
+        }
+        let created = create_index(
+            state,
+            CreateIndexRequestV1 {
+                table_id: table_id.to_string(),
+                columns: rebuild.columns.clone(),
+                index_type: rebuild.index_type.clone(),
+                name: Some(rebuild.new_index_name.clone()),
+                replace: true,
+                distance_type: rebuild.distance_type,
+                num_partitions: None,
+                sample_rate: None,
+                max_iterations: None,
+                target_partition_size: None,
+                num_sub_vectors: None,
+                num_bits: None,
+                num_edges: None,
+                ef_construction: None,
+                accelerator: None,
+                fts_tokenizer: None,
+                job_id: None,
+                preset: None,
+            },
+        )
+        .await;
+        match created.error {
+            None => rebuild.state = IndexRebuildStateV1::Rebuilt,
+            Some(error) => {
+                failures.push(format!("{}: {}", rebuild.index_name, error.message));
+                rebuild.state = IndexRebuildStateV1::Failed;
+                rebuild.error = Some(error.message);
+            }
+        }
+    }
+    let job_id = job_id?;
+    let result = if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures.join("; "))
+    };
+    finish_job(state, &job_id, None, result);
+    Some(job_id)
+}
+
 pub async fn drop_columns_v1(
     state: &AppState,
     request: DropColumnsRequestV1,
+) -> ResultEnvelope<DropColumnsResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::write_table("drop_columns_v1", &request.table_id),
+        drop_columns(state, request),
+    )
+    .await
+}
+
+async fn drop_columns(
+    state: &AppState,
+    request: DropColumnsRequestV1,
 ) -> ResultEnvelope<DropColumnsResponseV1> {
     let started_at = Instant::now();
     info!("drop_columns_v1 start table_id={}", request.table_id);
@@ -1781,20 +3590,9 @@ pub async fn drop_columns_v1(
         return ResultEnvelope::err(ErrorCode::InvalidArgument, "no columns specified");
     }
 
-    let table = match state.connections.lock() {
-        Ok(manager) => manager.get_table(&request.table_id),
-        Err(_) => {
-            error!("drop_columns_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
-        }
-    };
-
-    let Some(table) = table else {
-        warn!(
-            "drop_columns_v1 table not found table_id={}",
-            request.table_id
-        );
-        return ResultEnvelope::err(ErrorCode::NotFound, "table not found");
+    let table = match resolve_table(state, &request.table_id, "drop_columns_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
     };
 
     let column_refs = request
@@ -1802,13 +3600,16 @@ pub async fn drop_columns_v1(
         .iter()
         .map(String::as_str)
         .collect::<Vec<_>>();
-    if let Err(error) = table.drop_columns(&column_refs).await {
-        error!(
-            "drop_columns_v1 failed table_id={} error={}",
-            request.table_id, error
-        );
-        return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
-    }
+    let dropped_version = match table.drop_columns(&column_refs).await {
+        Ok(result) => result.version,
+        Err(error) => {
+            error!(
+                "drop_columns_v1 failed table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    };
 
     let updated_schema = match read_table_schema(&table).await {
         Ok(schema) => schema,
@@ -1821,6 +3622,16 @@ pub async fn drop_columns_v1(
         }
     };
 
+    after_table_write(state, &request.table_id);
+    auto_checkpoint(
+        state,
+        &request.table_id,
+        "drop-columns",
+        Some(dropped_version),
+        request.checkpoint_note.as_deref(),
+    )
+    .await;
+
     info!(
         "drop_columns_v1 ok table_id={} dropped={} elapsed_ms={}",
         request.table_id,
@@ -1835,9 +3646,111 @@ pub async fn drop_columns_v1(
     })
 }
 
+pub async fn reorder_columns_v1(
+    state: &AppState,
+    request: ReorderColumnsRequestV1,
+) -> ResultEnvelope<ReorderColumnsResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::edit_table("reorder_columns_v1", &request.table_id),
+        reorder_columns(state, request),
+    )
+    .await
+}
+
+async fn reorder_columns(
+    state: &AppState,
+    request: ReorderColumnsRequestV1,
+) -> ResultEnvelope<ReorderColumnsResponseV1> {
+    info!(
+        "reorder_columns_v1 start table_id={} columns={}",
+        request.table_id,
+        request.order.len()
+    );
+    let table = match resolve_table(state, &request.table_id, "reorder_columns_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+    // Lance has no in-place column reorder, so the order is kept in the schema
+    // metadata, which only local tables let us write.
+    let Some(native) = table.as_native() else {
+        return ResultEnvelope::err(
+            ErrorCode::NotImplemented,
+            "column order can only be stored on local tables",
+        );
+    };
+    let schema = match table.schema().await {
+        Ok(schema) => schema,
+        Err(error) => {
+            error!(
+                "reorder_columns_v1 failed to read schema table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    };
+    let order = match column_order::resolve(schema.as_ref(), &request.order) {
+        Ok(order) => order,
+        Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
+    };
+    let metadata = match column_order::metadata(schema.as_ref(), &order) {
+        Ok(metadata) => metadata,
+        Err(error) => return ResultEnvelope::err(ErrorCode::Internal, error),
+    };
+    if let Err(error) = native.replace_schema_metadata(metadata).await {
+        error!(
+            "reorder_columns_v1 failed table_id={} error={}",
+            request.table_id, error
+        );
+        return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+    }
+
+    let updated_schema = match read_table_schema(&table).await {
+        Ok(schema) => schema,
+        Err(error) => {
+            error!(
+                "reorder_columns_v1 schema reload failed table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    };
+    after_table_write(state, &request.table_id);
+    // Replacing schema metadata does not report the version it committed.
+    auto_checkpoint(
+        state,
+        &request.table_id,
+        "reorder-columns",
+        None,
+        request.checkpoint_note.as_deref(),
+    )
+    .await;
+    info!(
+        "reorder_columns_v1 ok table_id={} order={:?}",
+        request.table_id, order
+    );
+    ResultEnvelope::ok(ReorderColumnsResponseV1 {
+        table_id: request.table_id,
+        order,
+        schema: updated_schema,
+    })
+}
+
 pub async fn write_rows_v1(
     state: &AppState,
     request: WriteRowsRequestV1,
+) -> ResultEnvelope<WriteRowsResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::edit_table("write_rows_v1", &request.table_id),
+        write_rows(state, request),
+    )
+    .await
+}
+
+async fn write_rows(
+    state: &AppState,
+    request: WriteRowsRequestV1,
 ) -> ResultEnvelope<WriteRowsResponseV1> {
     let started_at = Instant::now();
     info!(
@@ -1847,20 +3760,9 @@ pub async fn write_rows_v1(
         request.mode
     );
 
-    let table = match state.connections.lock() {
-        Ok(manager) => manager.get_table(&request.table_id),
-        Err(_) => {
-            error!("write_rows_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
-        }
-    };
-
-    let Some(table) = table else {
-        warn!(
-            "write_rows_v1 table not found table_id={}",
-            request.table_id
-        );
-        return ResultEnvelope::err(ErrorCode::NotFound, "table not found");
+    let table = match resolve_table(state, &request.table_id, "write_rows_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
     };
 
     let schema = match table.schema().await {
@@ -1877,49 +3779,100 @@ pub async fn write_rows_v1(
     let batches = match json_rows_to_batches(schema.clone(), &request.rows) {
         Ok(batches) => batches,
         Err(error) => {
+            let invalid_row = locate_invalid_row(&schema, &request.rows);
             warn!(
-                "write_rows_v1 invalid rows table_id={} error={}",
-                request.table_id, error
+                "write_rows_v1 invalid rows table_id={} row={:?} error={}",
+                request.table_id,
+                invalid_row
+                    .as_ref()
+                    .map(|invalid_row| invalid_row.row_index),
+                error
             );
-            return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+            return invalid_rows_error(error, invalid_row);
         }
     };
+    let normalized_columns =
+        normalization::columns_to_normalize(schema.as_ref(), request.normalize_vectors);
+    let batches = match normalize_batches(batches, &normalized_columns) {
+        Ok(batches) => batches,
+        Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
+    };
+    let column_keys = match table_column_keys(state, &request.table_id, true) {
+        Ok(keys) => keys,
+        Err(error) => {
+            warn!(
+                "write_rows_v1 cannot encrypt rows table_id={} error={}",
+                request.table_id, error.message
+            );
+            return ResultEnvelope::err_envelope(error);
+        }
+    };
+    let batches = match encryption::encrypt_batches(batches, &column_keys) {
+        Ok(batches) => batches,
+        Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
+    };
 
-    let batch_iter = RecordBatchIterator::new(batches.into_iter().map(Ok), schema.clone());
-    let mut builder = table.add(batch_iter);
-    if matches!(request.mode, WriteDataMode::Overwrite) {
-        builder = builder.mode(AddDataMode::Overwrite);
-    }
-
-    let result = match builder.execute().await {
-        Ok(result) => result,
+    let written = LanceTableBackend::new(table.clone())
+        .add(batches, request.mode.clone())
+        .await;
+    let version = match written {
+        Ok(version) => version,
         Err(error) => {
             error!(
                 "write_rows_v1 failed table_id={} error={}",
                 request.table_id, error
             );
-            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+            return ResultEnvelope::err(error.code(), error.to_string());
         }
     };
+    if let Err(error) = record_normalization(&table, &normalized_columns).await {
+        warn!(
+            "write_rows_v1 failed to record normalization table_id={} columns={:?} error={}",
+            request.table_id, normalized_columns, error
+        );
+    }
+
+    after_table_write(state, &request.table_id);
+    auto_checkpoint(
+        state,
+        &request.table_id,
+        "write",
+        Some(version),
+        request.checkpoint_note.as_deref(),
+    )
+    .await;
 
     info!(
         "write_rows_v1 ok table_id={} rows={} version={} elapsed_ms={}",
         request.table_id,
         request.rows.len(),
-        result.version,
+        version,
         started_at.elapsed().as_millis()
     );
 
     ResultEnvelope::ok(WriteRowsResponseV1 {
         table_id: request.table_id,
         rows: request.rows.len(),
-        version: result.version,
+        version,
+        normalized_columns,
     })
 }
 
 pub async fn update_rows_v1(
     state: &AppState,
     request: UpdateRowsRequestV1,
+) -> ResultEnvelope<UpdateRowsResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::edit_table("update_rows_v1", &request.table_id),
+        update_rows(state, request),
+    )
+    .await
+}
+
+async fn update_rows(
+    state: &AppState,
+    request: UpdateRowsRequestV1,
 ) -> ResultEnvelope<UpdateRowsResponseV1> {
     let started_at = Instant::now();
     info!(
@@ -1932,20 +3885,9 @@ pub async fn update_rows_v1(
         return ResultEnvelope::err(ErrorCode::InvalidArgument, "no updates specified");
     }
 
-    let table = match state.connections.lock() {
-        Ok(manager) => manager.get_table(&request.table_id),
-        Err(_) => {
-            error!("update_rows_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
-        }
-    };
-
-    let Some(table) = table else {
-        warn!(
-            "update_rows_v1 table not found table_id={}",
-            request.table_id
-        );
-        return ResultEnvelope::err(ErrorCode::NotFound, "table not found");
+    let table = match resolve_table(state, &request.table_id, "update_rows_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
     };
 
     let filter = match validate_mutation_filter(
@@ -1957,6 +3899,11 @@ pub async fn update_rows_v1(
         Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
     };
 
+    let column_keys = match table_column_keys(state, &request.table_id, false) {
+        Ok(keys) => keys,
+        Err(error) => return ResultEnvelope::err_envelope(error),
+    };
+
     let mut builder = table.update();
     if let Some(filter) = filter {
         builder = builder.only_if(filter);
@@ -1971,7 +3918,29 @@ pub async fn update_rows_v1(
                 "update column and expression cannot be empty",
             );
         }
-        builder = builder.column(column.to_string(), expr.to_string());
+        if let Some(key_id) = column_keys.locked_key(column) {
+            warn!(
+                "update_rows_v1 rejected locked encrypted column table_id={} column={}",
+                request.table_id, column
+            );
+            return ResultEnvelope::err_message(
+                ErrorCode::PermissionDenied,
+                messages::message_with(
+                    MessageCodeV1::EncryptedColumnLocked,
+                    messages::current_locale(),
+                    HashMap::from([
+                        ("column".to_string(), column.to_string()),
+                        ("key_id".to_string(), key_id.to_string()),
+                    ]),
+                ),
+            );
+        }
+        let expr = match column_keys.encrypt_update_expr(column, expr) {
+            Some(Ok(encrypted)) => encrypted,
+            Some(Err(error)) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
+            None => expr.to_string(),
+        };
+        builder = builder.column(column.to_string(), expr);
     }
 
     let result = match builder.execute().await {
@@ -1985,6 +3954,16 @@ pub async fn update_rows_v1(
         }
     };
 
+    after_table_write(state, &request.table_id);
+    auto_checkpoint(
+        state,
+        &request.table_id,
+        "update",
+        Some(result.version),
+        request.checkpoint_note.as_deref(),
+    )
+    .await;
+
     info!(
         "update_rows_v1 ok table_id={} rows_updated={} version={} elapsed_ms={}",
         request.table_id,
@@ -2000,9 +3979,164 @@ pub async fn update_rows_v1(
     })
 }
 
+pub async fn preview_update_v1(
+    state: &AppState,
+    request: PreviewUpdateRequestV1,
+) -> ResultEnvelope<PreviewUpdateResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("preview_update_v1"),
+        preview_update(state, request),
+    )
+    .await
+}
+
+async fn preview_update(
+    state: &AppState,
+    request: PreviewUpdateRequestV1,
+) -> ResultEnvelope<PreviewUpdateResponseV1> {
+    let started_at = Instant::now();
+    info!(
+        "preview_update_v1 start table_id={} updates={}",
+        request.table_id,
+        request.updates.len()
+    );
+
+    let table = match resolve_table(state, &request.table_id, "preview_update_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+
+    let filter = match validate_mutation_filter(
+        "update",
+        request.filter.as_deref(),
+        request.allow_full_table,
+    ) {
+        Ok(filter) => filter,
+        Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
+    };
+
+    let schema = match table.schema().await {
+        Ok(schema) => schema,
+        Err(error) => {
+            error!(
+                "preview_update_v1 failed to read schema table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    };
+    let updates = match update_preview::validate_updates(&request.updates, schema.as_ref()) {
+        Ok(updates) => updates,
+        Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
+    };
+
+    let masking_rules = match table_masking_rules(state, &request.table_id) {
+        Ok(rules) => rules,
+        Err(message) => {
+            error!("preview_update_v1 failed to read masking rules");
+            return ResultEnvelope::err_message(ErrorCode::Internal, message);
+        }
+    };
+    let projection = update_preview::projection(schema.as_ref(), &updates);
+    if let Err(error) = projection::check_masked_columns(&projection, &masking_rules) {
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+    }
+
+    let limit = request
+        .limit
+        .unwrap_or(update_preview::DEFAULT_SAMPLE_ROWS)
+        .clamp(1, update_preview::MAX_SAMPLE_ROWS);
+    let options = QueryOptions {
+        projection: Some(projection),
+        filter: filter.clone(),
+        limit: Some(limit),
+        offset: None,
+    };
+    let query = apply_query_options(table.query(), &options);
+    let sampled = with_timeout(app_config(state).query_timeout(), async {
+        let batches = execute_query_batches(query).await?;
+        let matched_rows = table
+            .count_rows(filter)
+            .await
+            .map_err(|error| error.to_string())?;
+        Ok((batches, matched_rows))
+    })
+    .await;
+    let (batches, matched_rows) = match sampled {
+        Ok(sampled) => sampled,
+        Err(error) => {
+            error!(
+                "preview_update_v1 query failed table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    };
+
+    let mut before = Vec::with_capacity(batches.len());
+    let mut after = Vec::with_capacity(batches.len());
+    for batch in &batches {
+        match update_preview::split_batch(batch, schema.as_ref(), &updates) {
+            Ok((before_batch, after_batch)) => {
+                before.push(before_batch);
+                after.push(after_batch);
+            }
+            Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
+        }
+    }
+    let rows = masking::mask_batches(before, &masking_rules)
+        .and_then(|batches| batches_to_json_rows(&batches))
+        .and_then(|before| {
+            let after = batches_to_json_rows(&masking::mask_batches(after, &masking_rules)?)?;
+            Ok(before
+                .into_iter()
+                .zip(after)
+                .map(|(before, after)| UpdatePreviewRowV1 { before, after })
+                .collect::<Vec<_>>())
+        });
+    let rows = match rows {
+        Ok(rows) => rows,
+        Err(error) => {
+            error!(
+                "preview_update_v1 failed to encode rows table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    };
+
+    info!(
+        "preview_update_v1 ok table_id={} rows={} matched_rows={} elapsed_ms={}",
+        request.table_id,
+        rows.len(),
+        matched_rows,
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(PreviewUpdateResponseV1 {
+        table_id: request.table_id,
+        columns: updates.into_iter().map(|update| update.column).collect(),
+        rows,
+        matched_rows,
+    })
+}
+
 pub async fn delete_rows_v1(
     state: &AppState,
     request: DeleteRowsRequestV1,
+) -> ResultEnvelope<DeleteRowsResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::edit_table("delete_rows_v1", &request.table_id),
+        delete_rows(state, request),
+    )
+    .await
+}
+
+async fn delete_rows(
+    state: &AppState,
+    request: DeleteRowsRequestV1,
 ) -> ResultEnvelope<DeleteRowsResponseV1> {
     let started_at = Instant::now();
     info!("delete_rows_v1 start table_id={}", request.table_id);
@@ -2022,77 +4156,271 @@ pub async fn delete_rows_v1(
         Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
     };
 
-    let table = match state.connections.lock() {
-        Ok(manager) => manager.get_table(&request.table_id),
-        Err(_) => {
-            error!("delete_rows_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
-        }
+    let table = match resolve_table(state, &request.table_id, "delete_rows_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
     };
 
-    let Some(table) = table else {
-        warn!(
-            "delete_rows_v1 table not found table_id={}",
-            request.table_id
-        );
-        return ResultEnvelope::err(ErrorCode::NotFound, "table not found");
-    };
+    if let Some(chunk_size) = request.chunk_size {
+        return delete_rows_in_chunks(state, request, table, filter, chunk_size, started_at).await;
+    }
 
-    let result = match table.delete(&filter).await {
-        Ok(result) => result,
+    let version = match LanceTableBackend::new(table).delete(&filter).await {
+        Ok(version) => version,
         Err(error) => {
             error!(
                 "delete_rows_v1 failed table_id={} error={}",
                 request.table_id, error
             );
-            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+            return ResultEnvelope::err(error.code(), error.to_string());
         }
     };
 
+    after_table_write(state, &request.table_id);
+    auto_checkpoint(
+        state,
+        &request.table_id,
+        "delete",
+        Some(version),
+        request.checkpoint_note.as_deref(),
+    )
+    .await;
+
     info!(
         "delete_rows_v1 ok table_id={} version={} elapsed_ms={}",
         request.table_id,
-        result.version,
+        version,
         started_at.elapsed().as_millis()
     );
 
     ResultEnvelope::ok(DeleteRowsResponseV1 {
         table_id: request.table_id,
-        version: result.version,
+        version,
+        job_id: None,
+        rows_deleted: None,
+        cancelled: false,
     })
 }
 
-pub async fn import_data_v1(
-    state: &AppState,
-    request: ImportDataRequestV1,
-) -> ResultEnvelope<ImportDataResponseV1> {
-    let started_at = Instant::now();
-    let path = request.path.trim();
-    info!(
-        "import_data_v1 start table_id={} format={:?} path=\"{}\"",
-        request.table_id, request.format, path
-    );
-    if path.is_empty() {
-        return ResultEnvelope::err(ErrorCode::InvalidArgument, "path cannot be empty");
+/// Row id column LanceDB returns for `with_row_id` queries.
+const ROW_ID_COLUMN: &str = "_rowid";
+/// Largest chunk a chunked delete accepts; each chunk becomes one `IN` list.
+const MAX_DELETE_CHUNK_ROWS: usize = 50_000;
+
+struct ChunkedDelete {
+    rows_deleted: u64,
+    version: u64,
+    cancelled: bool,
+}
+
+fn row_id_filter(ids: &[u64]) -> String {
+    format!(
+        "{ROW_ID_COLUMN} IN ({})",
+        ids.iter()
+            .map(u64::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+fn row_ids(batches: &[RecordBatch]) -> Result<Vec<u64>, String> {
+    let mut ids = Vec::new();
+    for batch in batches {
+        let column = batch
+            .column_by_name(ROW_ID_COLUMN)
+            .and_then(|column| column.as_any().downcast_ref::<UInt64Array>())
+            .ok_or_else(|| format!("query result is missing {ROW_ID_COLUMN}"))?;
+        ids.extend(column.iter().flatten());
     }
+    Ok(ids)
+}
 
-    let table = match state.connections.lock() {
-        Ok(manager) => manager.get_table(&request.table_id),
-        Err(_) => {
-            error!("import_data_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
-        }
-    };
+/// Deletes matching rows `chunk_size` at a time by row id. Each chunk is its own
+/// commit, so a cancelled job leaves the earlier chunks deleted.
+async fn run_chunked_delete(
+    state: &AppState,
+    job_id: &str,
+    table: &Table,
+    filter: &str,
+    chunk_size: usize,
+) -> Result<ChunkedDelete, String> {
+    let total_rows = table
+        .count_rows(Some(filter.to_string()))
+        .await
+        .map_err(|error| error.to_string())? as u64;
+    if let Ok(mut jobs) = state.jobs.lock() {
+        jobs.set_total(job_id, Some(total_rows));
+        jobs.set_stage(job_id, ProgressStageV1::Writing);
+    }
 
-    let Some(table) = table else {
-        warn!(
-            "import_data_v1 table not found table_id={}",
-            request.table_id
-        );
-        return ResultEnvelope::err(ErrorCode::NotFound, "table not found");
+    let mut outcome = ChunkedDelete {
+        rows_deleted: 0,
+        version: table.version().await.map_err(|error| error.to_string())?,
+        cancelled: false,
     };
-
-    let schema = match table.schema().await {
+    let mut previous_first = None;
+    loop {
+        let query = table
+            .query()
+            .only_if(filter)
+            .with_row_id()
+            .select(Select::columns(&[ROW_ID_COLUMN]))
+            .limit(chunk_size);
+        let ids = row_ids(&execute_query_batches(query).await?)?;
+        let Some(first) = ids.first().copied() else {
+            break;
+        };
+        if previous_first == Some(first) {
+            return Err(format!(
+                "rows with {ROW_ID_COLUMN} {first} were not deleted"
+            ));
+        }
+        previous_first = Some(first);
+
+        outcome.version = table
+            .delete(&row_id_filter(&ids))
+            .await
+            .map_err(|error| error.to_string())?
+            .version;
+        outcome.rows_deleted += ids.len() as u64;
+        if let Err(message) = update_job_progress(state, job_id, outcome.rows_deleted) {
+            info!(
+                "delete_rows_v1 stopped job_id={} rows_deleted={} reason={}",
+                job_id, outcome.rows_deleted, message
+            );
+            outcome.cancelled = true;
+            break;
+        }
+        if ids.len() < chunk_size {
+            break;
+        }
+    }
+    Ok(outcome)
+}
+
+async fn delete_rows_in_chunks(
+    state: &AppState,
+    request: DeleteRowsRequestV1,
+    table: Table,
+    filter: String,
+    chunk_size: usize,
+    started_at: Instant,
+) -> ResultEnvelope<DeleteRowsResponseV1> {
+    if chunk_size == 0 || chunk_size > MAX_DELETE_CHUNK_ROWS {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            format!("chunk_size must be between 1 and {MAX_DELETE_CHUNK_ROWS}"),
+        );
+    }
+
+    let job_id = match start_job(
+        state,
+        "delete_rows_v1",
+        request.job_id.clone(),
+        JobKindV1::Delete,
+    ) {
+        Ok(job_id) => job_id,
+        Err(envelope) => return envelope,
+    };
+
+    let result = run_chunked_delete(state, &job_id, &table, &filter, chunk_size).await;
+    after_table_write(state, &request.table_id);
+    let outcome = match result {
+        Ok(outcome) => outcome,
+        Err(error) => {
+            error!(
+                "delete_rows_v1 failed table_id={} job_id={} error={}",
+                request.table_id, job_id, error
+            );
+            finish_job(state, &job_id, None, Err(error.clone()));
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    };
+    finish_job(state, &job_id, None, Ok(()));
+    auto_checkpoint(
+        state,
+        &request.table_id,
+        "delete",
+        Some(outcome.version),
+        request.checkpoint_note.as_deref(),
+    )
+    .await;
+
+    info!(
+        "delete_rows_v1 ok table_id={} job_id={} rows_deleted={} cancelled={} version={} elapsed_ms={}",
+        request.table_id,
+        job_id,
+        outcome.rows_deleted,
+        outcome.cancelled,
+        outcome.version,
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(DeleteRowsResponseV1 {
+        table_id: request.table_id,
+        version: outcome.version,
+        job_id: Some(job_id),
+        rows_deleted: Some(outcome.rows_deleted),
+        cancelled: outcome.cancelled,
+    })
+}
+
+pub async fn import_data_v1(
+    state: &AppState,
+    request: ImportDataRequestV1,
+) -> ResultEnvelope<ImportDataResponseV1> {
+    run_as_job(
+        state,
+        request.job_id.clone(),
+        JobKindV1::Import,
+        None,
+        middleware::run(
+            state,
+            CommandContext::edit_table("import_data_v1", &request.table_id),
+            import_data(state, request),
+        ),
+    )
+    .await
+}
+
+async fn import_data(
+    state: &AppState,
+    request: ImportDataRequestV1,
+) -> ResultEnvelope<ImportDataResponseV1> {
+    let started_at = Instant::now();
+    let path = request.path.trim();
+    info!(
+        "import_data_v1 start table_id={} format={:?} path=\"{}\"",
+        request.table_id, request.format, path
+    );
+    if path.is_empty() {
+        return ResultEnvelope::err_message(
+            ErrorCode::InvalidArgument,
+            messages::message(MessageCodeV1::PathEmpty),
+        );
+    }
+
+    // Verify before touching the table so a corrupted transfer never writes rows.
+    let sha256 = match request.expected_sha256.as_deref() {
+        Some(expected) => match checksum::verify_sha256(Path::new(path), expected) {
+            Ok(actual) => Some(actual),
+            Err(error) => {
+                warn!(
+                    "import_data_v1 checksum rejected table_id={} path=\"{}\" error={}",
+                    request.table_id, path, error
+                );
+                return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+            }
+        },
+        None => None,
+    };
+
+    let table = match resolve_table(state, &request.table_id, "import_data_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+
+    let schema = match table.schema().await {
         Ok(schema) => schema,
         Err(error) => {
             error!(
@@ -2103,20 +4431,45 @@ pub async fn import_data_v1(
         }
     };
 
-    let (batches, total_rows) = match request.format {
+    // Lineage columns are filled here rather than read from the file.
+    let (read_schema, missing_lineage) = if request.lineage {
+        match lineage::missing_fields(&schema) {
+            Ok(missing) => (lineage::source_schema(&schema), missing),
+            Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
+        }
+    } else {
+        (schema.clone(), Vec::new())
+    };
+
+    report_stage(state, ProgressStageV1::Reading);
+    let (batches, total_rows, lines) = match request.format {
         DataFileFormatV1::Csv => {
             let has_header = request.has_header.unwrap_or(true);
             let delimiter = match parse_delimiter(request.delimiter.clone(), b',') {
                 Ok(delimiter) => delimiter,
                 Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
             };
+            // Without a header there are no names to compare; columns are positional.
+            let file_schema = if has_header {
+                match infer_csv_schema(path, delimiter) {
+                    Ok(file_schema) => Some(file_schema),
+                    Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
+                }
+            } else {
+                None
+            };
+            if let Some(report) = file_schema.as_ref().and_then(|file_schema| {
+                schema_compat::report(&read_schema, file_schema, false, true)
+            }) {
+                return schema_mismatch_error(report);
+            }
             let file = match File::open(path) {
                 Ok(file) => file,
                 Err(error) => {
                     return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
                 }
             };
-            let mut reader = match CsvReaderBuilder::new(schema.clone())
+            let mut reader = match CsvReaderBuilder::new(read_schema.clone())
                 .with_header(has_header)
                 .with_delimiter(delimiter)
                 .build(file)
@@ -2131,13 +4484,27 @@ pub async fn import_data_v1(
                 let batch = match batch {
                     Ok(batch) => batch,
                     Err(error) => {
-                        return ResultEnvelope::err(ErrorCode::Internal, error.to_string())
+                        // Values that do not parse as the table types usually mean a
+                        // column holds another type than the table expects.
+                        warn!(
+                            "import_data_v1 csv parse failed table_id={} error={}",
+                            request.table_id, error
+                        );
+                        if let Some(report) = file_schema.as_ref().and_then(|file_schema| {
+                            schema_compat::report(&read_schema, file_schema, true, false)
+                        }) {
+                            return schema_mismatch_error(report);
+                        }
+                        return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
                     }
                 };
+                memory::retain(memory::batch_bytes(&batch));
                 batches.push(batch);
             }
             let total = batches.iter().map(|batch| batch.num_rows()).sum::<usize>();
-            (batches, total)
+            let first_line = if has_header { 2 } else { 1 };
+            let lines = (first_line..first_line + total as u64).collect::<Vec<_>>();
+            (batches, total, lines)
         }
         DataFileFormatV1::Parquet => {
             let file = match File::open(path) {
@@ -2146,9 +4513,15 @@ pub async fn import_data_v1(
                     return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
                 }
             };
-            let mut reader = match ParquetRecordBatchReaderBuilder::try_new(file)
-                .and_then(|builder| builder.build())
+            let builder = match ParquetRecordBatchReaderBuilder::try_new(file) {
+                Ok(builder) => builder,
+                Err(error) => return ResultEnvelope::err(ErrorCode::Internal, error.to_string()),
+            };
+            if let Some(report) = schema_compat::report(&read_schema, builder.schema(), true, false)
             {
+                return schema_mismatch_error(report);
+            }
+            let mut reader = match builder.build() {
                 Ok(reader) => reader,
                 Err(error) => return ResultEnvelope::err(ErrorCode::Internal, error.to_string()),
             };
@@ -2160,10 +4533,11 @@ pub async fn import_data_v1(
                         return ResultEnvelope::err(ErrorCode::Internal, error.to_string())
                     }
                 };
+                memory::retain(memory::batch_bytes(&batch));
                 batches.push(batch);
             }
             let total = batches.iter().map(|batch| batch.num_rows()).sum::<usize>();
-            (batches, total)
+            (batches, total, (1..=total as u64).collect())
         }
         DataFileFormatV1::Jsonl => {
             let file = match File::open(path) {
@@ -2174,7 +4548,8 @@ pub async fn import_data_v1(
             };
             let reader = BufReader::new(file);
             let mut rows = Vec::new();
-            for line in reader.lines() {
+            let mut lines = Vec::new();
+            for (index, line) in reader.lines().enumerate() {
                 let line = match line {
                     Ok(line) => line,
                     Err(error) => {
@@ -2192,22 +4567,95 @@ pub async fn import_data_v1(
                     }
                 };
                 rows.push(value);
+                lines.push(index as u64 + 1);
             }
             if rows.is_empty() {
                 return ResultEnvelope::err(ErrorCode::InvalidArgument, "no rows found in file");
             }
-            let batches = match json_rows_to_batches(schema.clone(), &rows) {
+            let batches = match json_rows_to_batches(read_schema.clone(), &rows) {
                 Ok(batches) => batches,
                 Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
             };
             let total = batches.iter().map(|batch| batch.num_rows()).sum::<usize>();
-            (batches, total)
+            (batches, total, lines)
+        }
+        DataFileFormatV1::Geojson => {
+            return ResultEnvelope::err(
+                ErrorCode::InvalidArgument,
+                "geojson is only supported for export",
+            );
         }
     };
 
     if batches.is_empty() || total_rows == 0 {
         return ResultEnvelope::err(ErrorCode::InvalidArgument, "no rows to import");
     }
+    let normalized_columns =
+        normalization::columns_to_normalize(schema.as_ref(), request.normalize_vectors);
+    let batches = match normalize_batches(batches, &normalized_columns) {
+        Ok(batches) => batches,
+        Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
+    };
+    let column_keys = match table_column_keys(state, &request.table_id, true) {
+        Ok(keys) => keys,
+        Err(error) => {
+            warn!(
+                "import_data_v1 cannot encrypt rows table_id={} error={}",
+                request.table_id, error.message
+            );
+            return ResultEnvelope::err_envelope(error);
+        }
+    };
+    let batches = match encryption::encrypt_batches(batches, &column_keys) {
+        Ok(batches) => batches,
+        Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
+    };
+    // Lance files are about the size of the decoded batches; overwrites keep the old
+    // version's files until cleanup, so they need the same room as appends.
+    if let Some(dataset_path) = local_path(table.dataset_uri()) {
+        let estimate = batches.iter().map(memory::batch_bytes).sum::<u64>();
+        if let Err(envelope) = check_disk_space(&dataset_path, estimate, "import_data_v1") {
+            return envelope;
+        }
+    }
+
+    let import_job_id = request.lineage.then(|| Uuid::new_v4().to_string());
+    let batches = match import_job_id.as_deref() {
+        Some(import_job_id) => {
+            let mut fields = schema.fields().iter().cloned().collect::<Vec<_>>();
+            fields.extend(missing_lineage.iter().cloned().map(Arc::new));
+            let target = Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone()));
+            let mut offset = 0;
+            let mut stamped = Vec::with_capacity(batches.len());
+            for batch in &batches {
+                let rows = batch.num_rows();
+                match lineage::stamp(
+                    batch,
+                    &target,
+                    path,
+                    import_job_id,
+                    &lines[offset..offset + rows],
+                ) {
+                    Ok(batch) => stamped.push(batch),
+                    Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
+                }
+                offset += rows;
+            }
+            if !missing_lineage.is_empty() {
+                let transform =
+                    NewColumnTransform::AllNulls(Arc::new(Schema::new(missing_lineage.clone())));
+                if let Err(error) = table.add_columns(transform, None).await {
+                    error!(
+                        "import_data_v1 failed to add lineage columns table_id={} error={}",
+                        request.table_id, error
+                    );
+                    return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+                }
+            }
+            stamped
+        }
+        None => batches,
+    };
 
     let schema_for_batches = batches
         .first()
@@ -2219,6 +4667,8 @@ pub async fn import_data_v1(
         builder = builder.mode(AddDataMode::Overwrite);
     }
 
+    report_stage(state, ProgressStageV1::Writing);
+    report_rows(state, 0, total_rows as u64);
     let result = match builder.execute().await {
         Ok(result) => result,
         Err(error) => {
@@ -2229,6 +4679,23 @@ pub async fn import_data_v1(
             return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
         }
     };
+    report_rows(state, total_rows as u64, total_rows as u64);
+    if let Err(error) = record_normalization(&table, &normalized_columns).await {
+        warn!(
+            "import_data_v1 failed to record normalization table_id={} columns={:?} error={}",
+            request.table_id, normalized_columns, error
+        );
+    }
+
+    after_table_write(state, &request.table_id);
+    auto_checkpoint(
+        state,
+        &request.table_id,
+        "import",
+        Some(result.version),
+        request.checkpoint_note.as_deref(),
+    )
+    .await;
 
     info!(
         "import_data_v1 ok table_id={} rows={} version={} elapsed_ms={}",
@@ -2241,12 +4708,196 @@ pub async fn import_data_v1(
     ResultEnvelope::ok(ImportDataResponseV1 {
         table_id: request.table_id,
         rows: total_rows,
+        sha256,
+        import_job_id,
+        added_columns: missing_lineage
+            .iter()
+            .map(|field| field.name().clone())
+            .collect(),
+        normalized_columns,
+    })
+}
+
+/// Rows read to infer the column types of a CSV file for a compatibility report.
+const CSV_INFER_ROWS: usize = 1_000;
+
+/// Header names and inferred column types of a CSV file.
+fn infer_csv_schema(path: &str, delimiter: u8) -> Result<Schema, String> {
+    let file = File::open(path).map_err(|error| error.to_string())?;
+    let (schema, _) = CsvFormat::default()
+        .with_header(true)
+        .with_delimiter(delimiter)
+        .infer_schema(file, Some(CSV_INFER_ROWS))
+        .map_err(|error| error.to_string())?;
+    Ok(schema)
+}
+
+/// InvalidArgument error for an import file that does not fit the table. `details`
+/// holds the report so the frontend can list the differences.
+fn schema_mismatch_error<T>(report: SchemaCompatibilityReportV1) -> ResultEnvelope<T> {
+    ResultEnvelope::err_envelope(ErrorEnvelope {
+        code: ErrorCode::InvalidArgument,
+        message: schema_compat::summary(&report),
+        message_code: None,
+        params: None,
+        details: serde_json::to_value(&report).ok(),
     })
 }
 
+/// Connection URI and table name of an open table, which key per-table settings.
+fn table_settings_key(
+    state: &AppState,
+    table_id: &str,
+) -> Result<Option<(String, String)>, MessageV1> {
+    match state.connections.lock() {
+        Ok(manager) => Ok(manager
+            .get_table_connection_uri(table_id)
+            .zip(manager.get_table_name(table_id))),
+        Err(_) => Err(messages::message(MessageCodeV1::StateLockFailed)),
+    }
+}
+
+/// Replaces a `projection_preset` name with the columns saved under it for the
+/// table. Sending both a preset and an explicit projection is rejected.
+fn resolve_projection_preset<T>(
+    state: &AppState,
+    table_id: &str,
+    projection: &mut Option<Vec<String>>,
+    projection_preset: &mut Option<String>,
+    command: &str,
+) -> Result<(), ResultEnvelope<T>> {
+    let Some(name) = projection_preset.take() else {
+        return Ok(());
+    };
+    if projection
+        .as_ref()
+        .is_some_and(|columns| !columns.is_empty())
+    {
+        return Err(ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            "projection and projectionPreset cannot both be set",
+        ));
+    }
+
+    let key = table_settings_key(state, table_id).map_err(|message| {
+        error!("{} failed to lock connection manager", command);
+        ResultEnvelope::err_message(ErrorCode::Internal, message)
+    })?;
+    let Some((connection_uri, table_name)) = key else {
+        warn!("{} table not found table_id={}", command, table_id);
+        return Err(ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::TableNotFound),
+        ));
+    };
+    let preset = match state.projection_presets.lock() {
+        Ok(store) => store.get(&connection_uri, &table_name, &name),
+        Err(_) => {
+            error!("{} failed to lock projection preset store", command);
+            return Err(ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            ));
+        }
+    };
+    let Some(preset) = preset else {
+        warn!(
+            "{} projection preset not found table_id={} name={}",
+            command, table_id, name
+        );
+        return Err(ResultEnvelope::err(
+            ErrorCode::NotFound,
+            format!("projection preset '{name}' not found"),
+        ));
+    };
+    *projection = Some(preset.columns);
+    Ok(())
+}
+
+/// Masking rules that apply to an open table, looked up by its connection URI and name.
+fn table_masking_rules(state: &AppState, table_id: &str) -> Result<Vec<MaskingRuleV1>, MessageV1> {
+    let Some((connection_uri, table_name)) = table_settings_key(state, table_id)? else {
+        return Ok(Vec::new());
+    };
+    match state.masking.lock() {
+        Ok(store) => Ok(store.rules_for_table(&connection_uri, &table_name)),
+        Err(_) => Err(messages::message(MessageCodeV1::StateLockFailed)),
+    }
+}
+
+/// Redacts the encrypted columns of a shared table. Shares read without column
+/// keys, so these columns would otherwise be served as ciphertext.
+fn redact_encrypted_columns(
+    state: &AppState,
+    table_id: &str,
+    rules: &mut Vec<MaskingRuleV1>,
+) -> Result<(), MessageV1> {
+    let Some((connection_uri, table_name)) = table_settings_key(state, table_id)? else {
+        return Ok(());
+    };
+    let columns = match state.encryption.lock() {
+        Ok(store) => store.table_columns(&connection_uri, &table_name),
+        Err(_) => return Err(messages::message(MessageCodeV1::StateLockFailed)),
+    };
+    rules.retain(|rule| !columns.contains(&rule.column));
+    rules.extend(columns.into_iter().map(|column| MaskingRuleV1 {
+        table_name: Some(table_name.clone()),
+        column,
+        strategy: MaskingStrategyV1::Redact,
+    }));
+    Ok(())
+}
+
+/// Ciphers for the encrypted columns of an open table. Writes pass `require_all`:
+/// a column whose key is locked would otherwise be written in plaintext.
+fn table_column_keys(
+    state: &AppState,
+    table_id: &str,
+    require_all: bool,
+) -> Result<encryption::ColumnKeys, ErrorEnvelope> {
+    let settings_key = table_settings_key(state, table_id)
+        .map_err(|message| ErrorEnvelope::from_message(ErrorCode::Internal, message))?;
+    let Some((connection_uri, table_name)) = settings_key else {
+        return Ok(encryption::ColumnKeys::default());
+    };
+    let store = state.encryption.lock().map_err(|_| {
+        ErrorEnvelope::from_message(
+            ErrorCode::Internal,
+            messages::message(MessageCodeV1::StateLockFailed),
+        )
+    })?;
+    store
+        .column_keys(&connection_uri, &table_name, require_all)
+        .map_err(|error| ErrorEnvelope {
+            code: ErrorCode::PermissionDenied,
+            message: error,
+            message_code: None,
+            params: None,
+            details: None,
+        })
+}
+
 pub async fn export_data_v1(
     state: &AppState,
     request: ExportDataRequestV1,
+) -> ResultEnvelope<ExportDataResponseV1> {
+    run_as_job(
+        state,
+        request.job_id.clone(),
+        JobKindV1::Export,
+        Some(request.path.trim().to_string()),
+        middleware::run(
+            state,
+            CommandContext::read("export_data_v1"),
+            export_data(state, request),
+        ),
+    )
+    .await
+}
+
+async fn export_data(
+    state: &AppState,
+    request: ExportDataRequestV1,
 ) -> ResultEnvelope<ExportDataResponseV1> {
     let started_at = Instant::now();
     let path = request.path.trim();
@@ -2255,14 +4906,23 @@ pub async fn export_data_v1(
         request.table_id, request.format, path
     );
     if path.is_empty() {
-        return ResultEnvelope::err(ErrorCode::InvalidArgument, "path cannot be empty");
+        return ResultEnvelope::err_message(
+            ErrorCode::InvalidArgument,
+            messages::message(MessageCodeV1::PathEmpty),
+        );
     }
 
-    let table = match state.connections.lock() {
-        Ok(manager) => manager.get_table(&request.table_id),
+    let (table, storage_options) = match state.connections.lock() {
+        Ok(manager) => (
+            manager.get_table(&request.table_id),
+            manager.get_table_storage_options(&request.table_id),
+        ),
         Err(_) => {
             error!("export_data_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
         }
     };
 
@@ -2271,7 +4931,10 @@ pub async fn export_data_v1(
             "export_data_v1 table not found table_id={}",
             request.table_id
         );
-        return ResultEnvelope::err(ErrorCode::NotFound, "table not found");
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::TableNotFound),
+        );
     };
 
     let fallback_schema = match table.schema().await {
@@ -2285,6 +4948,42 @@ pub async fn export_data_v1(
         }
     };
 
+    let masking_rules = match table_masking_rules(state, &request.table_id) {
+        Ok(rules) => rules,
+        Err(message) => {
+            error!("export_data_v1 failed to read masking rules");
+            return ResultEnvelope::err_message(ErrorCode::Internal, message);
+        }
+    };
+    if let Some(projection) = &request.projection {
+        if let Err(error) = projection::check_masked_columns(projection, &masking_rules) {
+            return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+        }
+    }
+    // Without a projection, files follow the display order stored on the table.
+    let display_order = match sanitize_projection(request.projection.clone()) {
+        Some(_) => None,
+        None => column_order::stored(fallback_schema.as_ref()),
+    };
+    let mut fallback_schema = masking::mask_schema(fallback_schema.as_ref(), &masking_rules);
+    if let Some(order) = &display_order {
+        fallback_schema = match column_order::reorder_schema(&fallback_schema, order) {
+            Ok(schema) => schema,
+            Err(error) => return ResultEnvelope::err(ErrorCode::Internal, error),
+        };
+    }
+
+    let geometry_column = match request.format {
+        DataFileFormatV1::Geojson => match geometry::resolve_column(
+            fallback_schema.as_ref(),
+            request.geometry_column.as_deref(),
+        ) {
+            Ok(column) => Some(column),
+            Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
+        },
+        _ => None,
+    };
+
     let options = QueryOptions {
         projection: sanitize_projection(request.projection.clone()),
         filter: sanitize_filter(request.filter.clone()),
@@ -2292,8 +4991,15 @@ pub async fn export_data_v1(
         offset: request.offset,
     };
 
+    report_stage(state, ProgressStageV1::Reading);
     let query = apply_query_options(table.query(), &options);
-    let batches = match execute_query_batches(query).await {
+    let batches = match execute_query_batches(query)
+        .await
+        .and_then(|batches| masking::mask_batches(batches, &masking_rules))
+        .and_then(|batches| match &display_order {
+            Some(order) => column_order::reorder_batches(batches, order),
+            None => Ok(batches),
+        }) {
         Ok(batches) => batches,
         Err(error) => {
             error!(
@@ -2304,6 +5010,75 @@ pub async fn export_data_v1(
         }
     };
     let total_rows = batches.iter().map(|batch| batch.num_rows()).sum::<usize>();
+    report_stage(state, ProgressStageV1::Writing);
+    report_rows(state, 0, total_rows as u64);
+
+    if object_storage::is_object_store_uri(path) {
+        let delimiter = match parse_delimiter(request.delimiter.clone(), b',') {
+            Ok(delimiter) => delimiter,
+            Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
+        };
+        let schema = batches
+            .first()
+            .map(|batch| batch.schema())
+            .unwrap_or_else(|| fallback_schema.clone());
+        let storage_options = storage_options.unwrap_or_default();
+        let uploaded = match &geometry_column {
+            Some((column, encoding)) => match batches_to_geojson(&batches, column, *encoding) {
+                Ok(bytes) => object_storage::upload_bytes(path, &storage_options, &bytes).await,
+                Err(error) => Err(error),
+            },
+            None => {
+                object_storage::export_batches(
+                    path,
+                    &storage_options,
+                    schema,
+                    &batches,
+                    &request.format,
+                    object_storage::CsvOptions {
+                        delimiter,
+                        with_header: request.with_header.unwrap_or(true),
+                    },
+                )
+                .await
+            }
+        };
+        let upload = match uploaded {
+            Ok(upload) => upload,
+            Err(error) => {
+                error!(
+                    "export_data_v1 upload failed table_id={} path=\"{}\" error={}",
+                    request.table_id, path, error
+                );
+                return ResultEnvelope::err(ErrorCode::Internal, error);
+            }
+        };
+
+        info!(
+            "export_data_v1 ok table_id={} rows={} bytes={} object_store=true elapsed_ms={}",
+            request.table_id,
+            total_rows,
+            upload.bytes,
+            started_at.elapsed().as_millis()
+        );
+
+        return ResultEnvelope::ok(ExportDataResponseV1 {
+            path: request.path,
+            rows: total_rows,
+            bytes: upload.bytes,
+            sha256: upload.sha256,
+        });
+    }
+
+    // Text formats spell numbers and vectors out, taking about twice the batch size.
+    let batch_bytes = batches.iter().map(memory::batch_bytes).sum::<u64>();
+    let estimate = match request.format {
+        DataFileFormatV1::Parquet => batch_bytes,
+        _ => batch_bytes.saturating_mul(2),
+    };
+    if let Err(envelope) = check_disk_space(Path::new(path), estimate, "export_data_v1") {
+        return envelope;
+    }
 
     match request.format {
         DataFileFormatV1::Csv => {
@@ -2386,777 +5161,8543 @@ pub async fn export_data_v1(
                 );
             }
         }
+        DataFileFormatV1::Geojson => {
+            let encoded = match &geometry_column {
+                Some((column, encoding)) => batches_to_geojson(&batches, column, *encoding),
+                None => Err("geometry column is required for geojson exports".to_string()),
+            };
+            let bytes = match encoded {
+                Ok(bytes) => bytes,
+                Err(error) => return ResultEnvelope::err(ErrorCode::Internal, error),
+            };
+            if let Err(error) = std::fs::write(path, bytes) {
+                return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+            }
+        }
     }
 
+    report_rows(state, total_rows as u64, total_rows as u64);
+    report_stage(state, ProgressStageV1::Verifying);
+    let (sha256, bytes) = match checksum::sha256_file(Path::new(path)) {
+        Ok(result) => result,
+        Err(error) => {
+            error!(
+                "export_data_v1 failed to hash output path=\"{}\" error={}",
+                path, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    };
+
     info!(
-        "export_data_v1 ok table_id={} rows={} elapsed_ms={}",
+        "export_data_v1 ok table_id={} rows={} bytes={} elapsed_ms={}",
         request.table_id,
         total_rows,
+        bytes,
         started_at.elapsed().as_millis()
     );
 
     ResultEnvelope::ok(ExportDataResponseV1 {
         path: request.path,
         rows: total_rows,
+        bytes,
+        sha256,
     })
 }
 
-pub async fn optimize_table_v1(
+/// Columns an export of `table` writes: the masked schema in display order, or the
+/// output of the projection read from one row.
+async fn export_schema(
+    table: &Table,
+    projection: Option<Vec<String>>,
+    masking_rules: &[MaskingRuleV1],
+) -> Result<SchemaRef, String> {
+    let schema = table.schema().await.map_err(|error| error.to_string())?;
+    let display_order = match projection {
+        Some(_) => None,
+        None => column_order::stored(schema.as_ref()),
+    };
+    let mut fallback_schema = masking::mask_schema(schema.as_ref(), masking_rules);
+    if let Some(order) = &display_order {
+        fallback_schema = column_order::reorder_schema(&fallback_schema, order)?;
+    }
+    let Some(projection) = projection else {
+        return Ok(fallback_schema);
+    };
+    let options = QueryOptions {
+        projection: Some(projection),
+        filter: None,
+        limit: Some(1),
+        offset: None,
+    };
+    let batches = execute_query_batches(apply_query_options(table.query(), &options)).await?;
+    let batches = masking::mask_batches(batches, masking_rules)?;
+    Ok(batches
+        .first()
+        .map(|batch| batch.schema())
+        .unwrap_or(fallback_schema))
+}
+
+/// Exports overwrite their target, so appending to an earlier export is done
+/// outside the app; this check catches files that would end up with mixed columns.
+pub async fn check_export_target_v1(
     state: &AppState,
-    request: OptimizeTableRequestV1,
-) -> ResultEnvelope<OptimizeTableResponseV1> {
-    let started_at = Instant::now();
+    request: CheckExportTargetRequestV1,
+) -> ResultEnvelope<CheckExportTargetResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("check_export_target_v1"),
+        check_export_target(state, request),
+    )
+    .await
+}
+
+async fn check_export_target(
+    state: &AppState,
+    request: CheckExportTargetRequestV1,
+) -> ResultEnvelope<CheckExportTargetResponseV1> {
+    let path = request.path.trim();
     info!(
-        "optimize_table_v1 start table_id={} action={:?}",
-        request.table_id, request.action
+        "check_export_target_v1 start table_id={} format={:?} path=\"{}\"",
+        request.table_id, request.format, path
     );
+    if path.is_empty() {
+        return ResultEnvelope::err_message(
+            ErrorCode::InvalidArgument,
+            messages::message(MessageCodeV1::PathEmpty),
+        );
+    }
+    if object_storage::is_object_store_uri(path) {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            "only local export targets can be checked",
+        );
+    }
+    if !matches!(
+        request.format,
+        DataFileFormatV1::Csv | DataFileFormatV1::Parquet
+    ) {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            "only parquet and csv export targets can be checked",
+        );
+    }
+    let table = match resolve_table(state, &request.table_id, "check_export_target_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+    if !Path::new(path).is_file() {
+        return ResultEnvelope::ok(CheckExportTargetResponseV1 {
+            path: request.path,
+            exists: false,
+            compatible: true,
+            report: None,
+        });
+    }
 
-    let OptimizeTableRequestV1 {
-        table_id,
-        action,
-        target_rows_per_fragment,
-        older_than_days,
-        delete_unverified,
-        error_if_tagged_old_versions,
-    } = request;
-
-    let table = match state.connections.lock() {
-        Ok(manager) => manager.get_table(&table_id),
-        Err(_) => {
-            error!("optimize_table_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
+    let masking_rules = match table_masking_rules(state, &request.table_id) {
+        Ok(rules) => rules,
+        Err(message) => {
+            error!("check_export_target_v1 failed to read masking rules");
+            return ResultEnvelope::err_message(ErrorCode::Internal, message);
         }
     };
-
-    let Some(table) = table else {
-        warn!("optimize_table_v1 table not found table_id={}", table_id);
-        return ResultEnvelope::err(ErrorCode::NotFound, "table not found");
+    let projection = sanitize_projection(request.projection.clone());
+    if let Some(projection) = &projection {
+        if let Err(error) = projection::check_masked_columns(projection, &masking_rules) {
+            return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+        }
+    }
+    let export_schema = match export_schema(&table, projection, &masking_rules).await {
+        Ok(schema) => schema,
+        Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
     };
 
-    let (opt_action, summary) = match action {
-        OptimizeActionV1::Compact => {
-            if let Some(target_rows) = target_rows_per_fragment {
-                if target_rows == 0 {
-                    return ResultEnvelope::err(
-                        ErrorCode::InvalidArgument,
-                        "target_rows_per_fragment must be greater than 0",
-                    );
+    // CSV readers map columns by position and re-infer types; Parquet keeps types.
+    let report = match request.format {
+        DataFileFormatV1::Csv => {
+            let delimiter = match parse_delimiter(request.delimiter.clone(), b',') {
+                Ok(delimiter) => delimiter,
+                Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
+            };
+            match infer_csv_schema(path, delimiter) {
+                Ok(file_schema) => {
+                    schema_compat::report(export_schema.as_ref(), &file_schema, false, true)
                 }
+                Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
             }
-            let mut options = CompactionOptions::default();
-            if let Some(target_rows) = target_rows_per_fragment {
-                let target_rows = match usize::try_from(target_rows) {
-                    Ok(value) => value,
-                    Err(_) => {
-                        return ResultEnvelope::err(
-                            ErrorCode::InvalidArgument,
-                            "target_rows_per_fragment is too large",
-                        );
-                    }
-                };
-                options.target_rows_per_fragment = target_rows;
-            }
-            let summary = target_rows_per_fragment
-                .map(|value| format!("Compact 已提交，目标片段行数={value}"))
-                .unwrap_or_else(|| "Compact 已提交".to_string());
-            (
-                OptimizeAction::Compact {
-                    options,
-                    remap_options: None,
-                },
-                summary,
-            )
         }
-        OptimizeActionV1::Vacuum => {
-            let older_than = match older_than_days {
-                Some(days) => {
-                    let days_i64 = match i64::try_from(days) {
-                        Ok(value) => value,
-                        Err(_) => {
-                            return ResultEnvelope::err(
-                                ErrorCode::InvalidArgument,
-                                "older_than_days is too large",
-                            );
-                        }
-                    };
-                    Some(LanceDuration::days(days_i64))
-                }
-                None => None,
+        _ => {
+            let builder = match File::open(path)
+                .map_err(|error| error.to_string())
+                .and_then(|file| {
+                    ParquetRecordBatchReaderBuilder::try_new(file)
+                        .map_err(|error| error.to_string())
+                }) {
+                Ok(builder) => builder,
+                Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
             };
-            let summary = older_than_days
-                .map(|value| format!("Vacuum 已提交，清理超过 {value} 天的历史版本"))
-                .unwrap_or_else(|| "Vacuum 已提交".to_string());
-            (
-                OptimizeAction::Prune {
-                    older_than,
-                    delete_unverified,
-                    error_if_tagged_old_versions,
-                },
-                summary,
-            )
+            schema_compat::report(export_schema.as_ref(), builder.schema(), true, false)
         }
     };
 
-    if let Err(error) = table.optimize(opt_action).await {
-        let message = error.to_string();
-        let lower = message.to_lowercase();
-        let code = if lower.contains("not supported") {
-            ErrorCode::NotImplemented
-        } else {
-            ErrorCode::Internal
-        };
-        error!(
-            "optimize_table_v1 failed table_id={} error={}",
-            table_id, message
-        );
-        return ResultEnvelope::err(code, message);
-    }
-
     info!(
-        "optimize_table_v1 ok table_id={} action={:?} elapsed_ms={}",
-        table_id,
-        action,
-        started_at.elapsed().as_millis()
+        "check_export_target_v1 ok table_id={} path=\"{}\" compatible={}",
+        request.table_id,
+        path,
+        report.is_none()
     );
 
-    ResultEnvelope::ok(OptimizeTableResponseV1 {
-        table_id,
-        action,
-        summary,
+    ResultEnvelope::ok(CheckExportTargetResponseV1 {
+        path: request.path,
+        exists: true,
+        compatible: report.is_none(),
+        report,
     })
 }
 
-pub async fn open_table_v1(
+/// Largest selection `export_rows_v1` accepts; the ids become one `IN` list.
+const MAX_EXPORT_ROW_IDS: usize = 50_000;
+
+pub async fn export_rows_v1(
     state: &AppState,
-    request: OpenTableRequestV1,
-) -> ResultEnvelope<TableHandle> {
-    let started_at = Instant::now();
+    request: ExportRowsRequestV1,
+) -> ResultEnvelope<ExportDataResponseV1> {
+    run_as_job(
+        state,
+        request.job_id.clone(),
+        JobKindV1::Export,
+        Some(request.path.trim().to_string()),
+        middleware::run(
+            state,
+            CommandContext::read("export_rows_v1"),
+            export_rows(state, request),
+        ),
+    )
+    .await
+}
+
+/// Exports through `export_data` with a row id filter, so masking, the display
+/// column order and every file format and destination behave the same.
+async fn export_rows(
+    state: &AppState,
+    request: ExportRowsRequestV1,
+) -> ResultEnvelope<ExportDataResponseV1> {
+    let mut row_ids = request.row_ids;
+    row_ids.sort_unstable();
+    row_ids.dedup();
     info!(
-        "open_table_v1 start connection_id={} table=\"{}\"",
-        request.connection_id, request.table_name
+        "export_rows_v1 start table_id={} rows={} format={:?}",
+        request.table_id,
+        row_ids.len(),
+        request.format
     );
-    let connection = match state.connections.lock() {
-        Ok(manager) => manager.get_connection(&request.connection_id),
-        Err(_) => {
-            error!("open_table_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
-        }
-    };
-
-    let Some(connection) = connection else {
-        warn!(
-            "open_table_v1 connection not found connection_id={}",
-            request.connection_id
+    if row_ids.is_empty() {
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, "rowIds cannot be empty");
+    }
+    if row_ids.len() > MAX_EXPORT_ROW_IDS {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            format!("at most {MAX_EXPORT_ROW_IDS} rows can be exported by row id"),
         );
-        return ResultEnvelope::err(ErrorCode::NotFound, "connection not found");
-    };
+    }
 
-    let table = match connection.open_table(&request.table_name).execute().await {
-        Ok(table) => table,
-        Err(error) => {
-            error!(
-                "open_table_v1 failed connection_id={} table=\"{}\" error={}",
-                request.connection_id, request.table_name, error
+    let table_id = request.table_id.clone();
+    let exported = export_data(
+        state,
+        ExportDataRequestV1 {
+            table_id: request.table_id,
+            path: request.path,
+            format: request.format,
+            projection: None,
+            filter: Some(row_id_filter(&row_ids)),
+            limit: None,
+            offset: None,
+            delimiter: request.delimiter,
+            with_header: request.with_header,
+            geometry_column: request.geometry_column,
+            job_id: None,
+        },
+    )
+    .await;
+    if let Some(data) = &exported.data {
+        if data.rows < row_ids.len() {
+            warn!(
+                "export_rows_v1 rows no longer present table_id={} requested={} exported={}",
+                table_id,
+                row_ids.len(),
+                data.rows
             );
-            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
         }
-    };
+    }
+    exported
+}
 
-    let table_id = match state.connections.lock() {
-        Ok(mut manager) => manager.insert_table(
-            request.table_name.clone(),
-            table,
-            request.connection_id.clone(),
+pub async fn export_bundle_v1(
+    state: &AppState,
+    request: ExportBundleRequestV1,
+) -> ResultEnvelope<ExportBundleResponseV1> {
+    run_as_job(
+        state,
+        request.job_id.clone(),
+        JobKindV1::Export,
+        Some(request.path.trim().to_string()),
+        middleware::run(
+            state,
+            CommandContext::read("export_bundle_v1"),
+            export_bundle(state, request),
         ),
-        Err(_) => {
-            error!("open_table_v1 failed to lock table manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock table manager");
-        }
-    };
-
-    info!(
-        "open_table_v1 ok connection_id={} table_id={} table=\"{}\" elapsed_ms={}",
-        request.connection_id,
-        table_id,
-        request.table_name,
-        started_at.elapsed().as_millis()
-    );
-
-    ResultEnvelope::ok(TableHandle {
-        table_id,
-        name: request.table_name,
-    })
+    )
+    .await
 }
 
-pub async fn get_schema_v1(
+async fn export_bundle(
     state: &AppState,
-    request: GetSchemaRequestV1,
-) -> ResultEnvelope<SchemaDefinition> {
+    request: ExportBundleRequestV1,
+) -> ResultEnvelope<ExportBundleResponseV1> {
     let started_at = Instant::now();
-    info!("get_schema_v1 start table_id={}", request.table_id);
-    let table = match state.connections.lock() {
-        Ok(manager) => manager.get_table(&request.table_id),
+    let path = request.path.trim();
+    info!(
+        "export_bundle_v1 start table_id={} path=\"{}\"",
+        request.table_id, path
+    );
+    if path.is_empty() {
+        return ResultEnvelope::err_message(
+            ErrorCode::InvalidArgument,
+            messages::message(MessageCodeV1::PathEmpty),
+        );
+    }
+    if request.rows_per_shard == Some(0) {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            "rowsPerShard must be greater than 0",
+        );
+    }
+
+    let (table, table_name) = match state.connections.lock() {
+        Ok(manager) => (
+            manager.get_table(&request.table_id),
+            manager.get_table_name(&request.table_id),
+        ),
         Err(_) => {
-            error!("get_schema_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
+            error!("export_bundle_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
         }
     };
 
-    let Some(table) = table else {
+    let (Some(table), Some(table_name)) = (table, table_name) else {
         warn!(
-            "get_schema_v1 table not found table_id={}",
+            "export_bundle_v1 table not found table_id={}",
             request.table_id
         );
-        return ResultEnvelope::err(ErrorCode::NotFound, "table not found");
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::TableNotFound),
+        );
     };
 
-    let schema = match table.schema().await {
-        Ok(schema) => schema,
+    let dir = Path::new(path);
+    if let Err(error) = bundle::prepare_bundle_dir(dir, request.overwrite) {
+        warn!("export_bundle_v1 invalid target error={}", error);
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+    }
+
+    let source_version = match table.version().await {
+        Ok(version) => version,
         Err(error) => {
             error!(
-                "get_schema_v1 failed table_id={} error={}",
+                "export_bundle_v1 failed to read version table_id={} error={}",
                 request.table_id, error
             );
             return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
         }
     };
 
-    let definition = SchemaDefinition::from_arrow_schema(schema.as_ref());
-    info!(
-        "get_schema_v1 ok table_id={} fields={} elapsed_ms={}",
-        request.table_id,
-        definition.fields.len(),
-        started_at.elapsed().as_millis()
-    );
-
-    ResultEnvelope::ok(definition)
-}
-
-pub async fn list_versions_v1(
-    state: &AppState,
-    request: ListVersionsRequestV1,
-) -> ResultEnvelope<ListVersionsResponseV1> {
-    let started_at = Instant::now();
-    info!("list_versions_v1 start table_id={}", request.table_id);
-
-    let table = match state.connections.lock() {
-        Ok(manager) => manager.get_table(&request.table_id),
-        Err(_) => {
-            error!("list_versions_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
+    let fallback_schema = match table.schema().await {
+        Ok(schema) => schema,
+        Err(error) => {
+            error!(
+                "export_bundle_v1 failed to read schema table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
         }
     };
 
-    let Some(table) = table else {
-        warn!(
-            "list_versions_v1 table not found table_id={}",
-            request.table_id
-        );
-        return ResultEnvelope::err(ErrorCode::NotFound, "table not found");
+    let options = QueryOptions {
+        projection: sanitize_projection(request.projection.clone()),
+        filter: sanitize_filter(request.filter.clone()),
+        limit: None,
+        offset: None,
     };
 
-    let versions = match table.list_versions().await {
-        Ok(versions) => versions
-            .into_iter()
-            .map(to_version_info)
-            .collect::<Vec<_>>(),
+    report_stage(state, ProgressStageV1::Reading);
+    let query = apply_query_options(table.query(), &options);
+    let batches = match execute_query_batches(query).await {
+        Ok(batches) => batches,
         Err(error) => {
             error!(
-                "list_versions_v1 failed table_id={} error={}",
+                "export_bundle_v1 query failed table_id={} error={}",
                 request.table_id, error
             );
-            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+            return ResultEnvelope::err(ErrorCode::Internal, error);
         }
     };
+    let total_rows = batches.iter().map(|batch| batch.num_rows()).sum::<usize>();
+    let schema = batches
+        .first()
+        .map(|batch| batch.schema())
+        .unwrap_or_else(|| match options.projection.as_ref() {
+            Some(columns) => fallback_schema
+                .project(
+                    &columns
+                        .iter()
+                        .filter_map(|name| fallback_schema.index_of(name).ok())
+                        .collect::<Vec<_>>(),
+                )
+                .map(Arc::new)
+                .unwrap_or_else(|_| fallback_schema.clone()),
+            None => fallback_schema.clone(),
+        });
 
-    info!(
-        "list_versions_v1 ok table_id={} versions={} elapsed_ms={}",
-        request.table_id,
-        versions.len(),
-        started_at.elapsed().as_millis()
-    );
-
-    ResultEnvelope::ok(ListVersionsResponseV1 { versions })
-}
-
-pub async fn get_table_version_v1(
-    state: &AppState,
-    request: GetTableVersionRequestV1,
-) -> ResultEnvelope<GetTableVersionResponseV1> {
-    let started_at = Instant::now();
-    info!("get_table_version_v1 start table_id={}", request.table_id);
-
-    let table = match state.connections.lock() {
-        Ok(manager) => manager.get_table(&request.table_id),
-        Err(_) => {
-            error!("get_table_version_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
+    report_stage(state, ProgressStageV1::Writing);
+    report_rows(state, 0, total_rows as u64);
+    let shards = match bundle::write_parquet_shards(
+        dir,
+        schema.clone(),
+        &batches,
+        request
+            .rows_per_shard
+            .unwrap_or(bundle::DEFAULT_ROWS_PER_SHARD),
+    ) {
+        Ok(shards) => shards,
+        Err(error) => {
+            error!(
+                "export_bundle_v1 failed to write shards table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
         }
     };
 
-    let Some(table) = table else {
-        warn!(
-            "get_table_version_v1 table not found table_id={}",
-            request.table_id
-        );
-        return ResultEnvelope::err(ErrorCode::NotFound, "table not found");
-    };
-
-    let version = match table.version().await {
-        Ok(version) => version,
+    let exported_columns: Vec<&str> = schema
+        .fields()
+        .iter()
+        .map(|field| field.name().as_str())
+        .collect();
+    let indexes = match table.list_indices().await {
+        Ok(configs) => configs
+            .into_iter()
+            .filter(|config| {
+                config
+                    .columns
+                    .iter()
+                    .all(|column| exported_columns.contains(&column.as_str()))
+            })
+            .map(|config| BundleIndexV1 {
+                name: config.name,
+                index_type: to_index_type_v1(&config.index_type),
+                columns: config.columns,
+            })
+            .collect(),
         Err(error) => {
-            error!(
-                "get_table_version_v1 failed table_id={} error={}",
+            warn!(
+                "export_bundle_v1 failed to list indexes table_id={} error={}",
                 request.table_id, error
             );
-            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+            Vec::new()
         }
     };
 
+    let manifest = BundleManifestV1 {
+        format_version: bundle::BUNDLE_FORMAT_VERSION,
+        table_name,
+        source_version,
+        created_at_ms: clock::now_unix_millis(),
+        rows: total_rows,
+        shards,
+        indexes,
+        filter: options.filter.clone(),
+        projection: options.projection.clone(),
+        metadata: request.metadata.clone().unwrap_or_default(),
+    };
+
+    let schema_definition = SchemaDefinition::from_arrow_schema(schema.as_ref());
+    if let Err(error) =
+        bundle::write_json_file(&dir.join(bundle::BUNDLE_SCHEMA_FILE), &schema_definition).and_then(
+            |_| bundle::write_json_file(&dir.join(bundle::BUNDLE_MANIFEST_FILE), &manifest),
+        )
+    {
+        error!(
+            "export_bundle_v1 failed to write manifest table_id={} error={}",
+            request.table_id, error
+        );
+        return ResultEnvelope::err(ErrorCode::Internal, error);
+    }
+
     info!(
-        "get_table_version_v1 ok table_id={} version={} elapsed_ms={}",
+        "export_bundle_v1 ok table_id={} rows={} shards={} indexes={} elapsed_ms={}",
         request.table_id,
-        version,
+        total_rows,
+        manifest.shards.len(),
+        manifest.indexes.len(),
         started_at.elapsed().as_millis()
     );
 
-    ResultEnvelope::ok(GetTableVersionResponseV1 {
-        table_id: request.table_id,
-        version,
+    ResultEnvelope::ok(ExportBundleResponseV1 {
+        path: request.path,
+        manifest,
     })
 }
 
-pub async fn checkout_table_version_v1(
+pub async fn export_repro_sample_v1(
     state: &AppState,
-    request: CheckoutTableVersionRequestV1,
-) -> ResultEnvelope<CheckoutTableVersionResponseV1> {
-    let started_at = Instant::now();
+    request: ExportReproSampleRequestV1,
+) -> ResultEnvelope<ExportReproSampleResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("export_repro_sample_v1"),
+        export_repro_sample(state, request),
+    )
+    .await
+}
+
+async fn export_repro_sample(
+    state: &AppState,
+    request: ExportReproSampleRequestV1,
+) -> ResultEnvelope<ExportReproSampleResponseV1> {
+    let path = request.path.trim().to_string();
     info!(
-        "checkout_table_version_v1 start table_id={} version={}",
-        request.table_id, request.version
+        "export_repro_sample_v1 start table_id={} rows={} path=\"{}\"",
+        request.table_id, request.rows, path
     );
+    if path.is_empty() {
+        return ResultEnvelope::err_message(
+            ErrorCode::InvalidArgument,
+            messages::message(MessageCodeV1::PathEmpty),
+        );
+    }
+    if request.rows == 0 || request.rows > repro_sample::MAX_SAMPLE_ROWS {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            format!(
+                "rows must be between 1 and {}",
+                repro_sample::MAX_SAMPLE_ROWS
+            ),
+        );
+    }
+    if Path::new(&path).exists() && !request.overwrite {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            "file already exists at path; set overwrite to true to replace it",
+        );
+    }
 
-    let table = match state.connections.lock() {
-        Ok(manager) => manager.get_table(&request.table_id),
-        Err(_) => {
-            error!("checkout_table_version_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
+    let table = match resolve_table(state, &request.table_id, "export_repro_sample_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+    let masking_rules = match table_masking_rules(state, &request.table_id) {
+        Ok(rules) => rules,
+        Err(message) => {
+            error!("export_repro_sample_v1 failed to read masking rules");
+            return ResultEnvelope::err_message(ErrorCode::Internal, message);
         }
     };
-
-    let Some(table) = table else {
-        warn!(
-            "checkout_table_version_v1 table not found table_id={}",
-            request.table_id
-        );
-        return ResultEnvelope::err(ErrorCode::NotFound, "table not found");
+    let filter = sanitize_filter(request.filter.clone());
+    let sampled = with_timeout(app_config(state).query_timeout(), async {
+        let source_version = table.version().await.map_err(|error| error.to_string())?;
+        let source_rows = table
+            .count_rows(filter.clone())
+            .await
+            .map_err(|error| error.to_string())?;
+        let schema = table.schema().await.map_err(|error| error.to_string())?;
+        let options = QueryOptions {
+            projection: None,
+            filter: filter.clone(),
+            limit: Some(request.rows),
+            offset: None,
+        };
+        let batches = execute_query_batches(apply_query_options(table.query(), &options)).await?;
+        let batches = masking::mask_batches(batches, &masking_rules)?;
+        let schema = masking::mask_schema(schema.as_ref(), &masking_rules);
+        let indexes = table
+            .list_indices()
+            .await
+            .map_err(|error| error.to_string())?;
+        Ok((source_version, source_rows, schema, batches, indexes))
+    })
+    .await;
+    let (source_version, source_rows, schema, batches, indexes) = match sampled {
+        Ok(sampled) => sampled,
+        Err(error) => {
+            error!(
+                "export_repro_sample_v1 query failed table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
     };
 
-    if let Err(error) = table.checkout(request.version).await {
-        error!(
-            "checkout_table_version_v1 failed table_id={} error={}",
-            request.table_id, error
-        );
-        return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
-    }
+    let seed = request
+        .seed
+        .unwrap_or_else(|| Uuid::new_v4().as_u64_pair().0);
+    let sample = arrow_select::concat::concat_batches(&schema, &batches)
+        .map_err(|error| error.to_string())
+        .and_then(|batch| {
+            repro_sample::scramble_batch(&batch, &mut repro_sample::Scrambler::new(seed))
+        });
+    let sample = match sample {
+        Ok(sample) => sample,
+        Err(error) => {
+            error!(
+                "export_repro_sample_v1 failed to scramble rows table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    };
 
-    let version = match table.version().await {
-        Ok(version) => version,
+    let manifest = ReproSampleManifestV1 {
+        format_version: repro_sample::SAMPLE_FORMAT_VERSION,
+        viewer_version: env!("CARGO_PKG_VERSION").to_string(),
+        created_at_ms: clock::now_unix_millis(),
+        source_version,
+        source_rows,
+        rows: sample.num_rows(),
+        seed,
+        filter,
+        indexes: indexes
+            .into_iter()
+            .map(|config| BundleIndexV1 {
+                name: config.name,
+                index_type: to_index_type_v1(&config.index_type),
+                columns: config.columns,
+            })
+            .collect(),
+    };
+    let written = write_repro_sample(Path::new(&path), &manifest, schema.as_ref(), &sample);
+    let (sha256, bytes) = match written {
+        Ok(written) => written,
         Err(error) => {
             error!(
-                "checkout_table_version_v1 read version failed table_id={} error={}",
+                "export_repro_sample_v1 failed to write archive table_id={} error={}",
                 request.table_id, error
             );
-            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+            return ResultEnvelope::err(ErrorCode::Internal, error);
         }
     };
 
     info!(
-        "checkout_table_version_v1 ok table_id={} version={} elapsed_ms={}",
-        request.table_id,
-        version,
-        started_at.elapsed().as_millis()
+        "export_repro_sample_v1 ok table_id={} rows={} bytes={}",
+        request.table_id, manifest.rows, bytes
     );
+    ResultEnvelope::ok(ExportReproSampleResponseV1 {
+        path,
+        bytes,
+        sha256,
+        manifest,
+    })
+}
 
-    ResultEnvelope::ok(CheckoutTableVersionResponseV1 {
-        table_id: request.table_id,
-        version,
+/// Writes the repro archive and returns its SHA-256 and size.
+fn write_repro_sample(
+    path: &Path,
+    manifest: &ReproSampleManifestV1,
+    schema: &Schema,
+    sample: &RecordBatch,
+) -> Result<(String, u64), String> {
+    let manifest = serde_json::to_vec_pretty(manifest).map_err(|error| error.to_string())?;
+    let schema_definition = serde_json::to_vec_pretty(&SchemaDefinition::from_arrow_schema(schema))
+        .map_err(|error| error.to_string())?;
+    let rows = batches_to_arrow_ipc(std::slice::from_ref(sample), schema)?;
+    repro_sample::write_zip(
+        path,
+        &[
+            (repro_sample::MANIFEST_FILE, manifest.as_slice()),
+            (repro_sample::SCHEMA_FILE, schema_definition.as_slice()),
+            (repro_sample::ROWS_FILE, rows.as_slice()),
+        ],
+    )?;
+    checksum::sha256_file(path)
+}
+
+fn to_bundle_lancedb_index(index: &BundleIndexV1) -> Result<Index, String> {
+    to_lancedb_index(&CreateIndexRequestV1 {
+        table_id: String::new(),
+        columns: index.columns.clone(),
+        index_type: index.index_type.clone(),
+        name: Some(index.name.clone()),
+        replace: true,
+        distance_type: None,
+        num_partitions: None,
+        sample_rate: None,
+        max_iterations: None,
+        target_partition_size: None,
+        num_sub_vectors: None,
+        num_bits: None,
+        num_edges: None,
+        ef_construction: None,
+        accelerator: None,
+        fts_tokenizer: None,
+        job_id: None,
+        preset: None,
     })
 }
 
-pub async fn checkout_table_latest_v1(
+pub async fn list_export_templates_v1(
     state: &AppState,
-    request: CheckoutTableLatestRequestV1,
-) -> ResultEnvelope<CheckoutTableLatestResponseV1> {
-    let started_at = Instant::now();
-    info!(
-        "checkout_table_latest_v1 start table_id={}",
-        request.table_id
-    );
+) -> ResultEnvelope<ListExportTemplatesResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("list_export_templates_v1"),
+        list_export_templates(state),
+    )
+    .await
+}
 
-    let table = match state.connections.lock() {
-        Ok(manager) => manager.get_table(&request.table_id),
+async fn list_export_templates(state: &AppState) -> ResultEnvelope<ListExportTemplatesResponseV1> {
+    match state.export_templates.lock() {
+        Ok(store) => ResultEnvelope::ok(ListExportTemplatesResponseV1 {
+            templates: store.list(),
+        }),
         Err(_) => {
-            error!("checkout_table_latest_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
+            error!("list_export_templates_v1 failed to lock export templates");
+            ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            )
         }
-    };
+    }
+}
 
-    let Some(table) = table else {
-        warn!(
-            "checkout_table_latest_v1 table not found table_id={}",
-            request.table_id
-        );
-        return ResultEnvelope::err(ErrorCode::NotFound, "table not found");
+pub async fn save_export_template_v1(
+    state: &AppState,
+    request: SaveExportTemplateRequestV1,
+) -> ResultEnvelope<SaveExportTemplateResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::settings("save_export_template_v1"),
+        save_export_template(state, request),
+    )
+    .await
+}
+
+async fn save_export_template(
+    state: &AppState,
+    request: SaveExportTemplateRequestV1,
+) -> ResultEnvelope<SaveExportTemplateResponseV1> {
+    info!(
+        "save_export_template_v1 start name=\"{}\" format={:?}",
+        request.template.name, request.template.format
+    );
+    let template = match export_templates::normalize(request.template) {
+        Ok(template) => template,
+        Err(error) => {
+            warn!("save_export_template_v1 invalid template error={}", error);
+            return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+        }
     };
+    if let Err(error) = parse_delimiter(template.delimiter.clone(), b',') {
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+    }
 
-    if let Err(error) = table.checkout_latest().await {
+    let saved = match state.export_templates.lock() {
+        Ok(mut store) => store.save(template.clone()),
+        Err(_) => {
+            error!("save_export_template_v1 failed to lock export templates");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+    if let Err(error) = saved {
         error!(
-            "checkout_table_latest_v1 failed table_id={} error={}",
-            request.table_id, error
+            "save_export_template_v1 failed name=\"{}\" error={}",
+            template.name, error
         );
-        return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        return ResultEnvelope::err(ErrorCode::Internal, error);
     }
 
-    let version = match table.version().await {
-        Ok(version) => version,
+    info!("save_export_template_v1 ok name=\"{}\"", template.name);
+    ResultEnvelope::ok(SaveExportTemplateResponseV1 { template })
+}
+
+pub async fn delete_export_template_v1(
+    state: &AppState,
+    request: DeleteExportTemplateRequestV1,
+) -> ResultEnvelope<DeleteExportTemplateResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::settings("delete_export_template_v1"),
+        delete_export_template(state, request),
+    )
+    .await
+}
+
+async fn delete_export_template(
+    state: &AppState,
+    request: DeleteExportTemplateRequestV1,
+) -> ResultEnvelope<DeleteExportTemplateResponseV1> {
+    let name = request.name.trim().to_string();
+    let deleted = match state.export_templates.lock() {
+        Ok(mut store) => store.delete(&name),
+        Err(_) => {
+            error!("delete_export_template_v1 failed to lock export templates");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+    match deleted {
+        Ok(true) => {
+            info!("delete_export_template_v1 ok name=\"{}\"", name);
+            ResultEnvelope::ok(DeleteExportTemplateResponseV1 { name })
+        }
+        Ok(false) => ResultEnvelope::err(ErrorCode::NotFound, "export template not found"),
         Err(error) => {
             error!(
-                "checkout_table_latest_v1 read version failed table_id={} error={}",
-                request.table_id, error
+                "delete_export_template_v1 failed name=\"{}\" error={}",
+                name, error
+            );
+            ResultEnvelope::err(ErrorCode::Internal, error)
+        }
+    }
+}
+
+pub async fn run_export_template_v1(
+    state: &AppState,
+    request: RunExportTemplateRequestV1,
+) -> ResultEnvelope<ExportDataResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("run_export_template_v1"),
+        run_export_template(state, request),
+    )
+    .await
+}
+
+async fn run_export_template(
+    state: &AppState,
+    request: RunExportTemplateRequestV1,
+) -> ResultEnvelope<ExportDataResponseV1> {
+    let name = request.name.trim();
+    let template = match state.export_templates.lock() {
+        Ok(store) => store.get(name),
+        Err(_) => {
+            error!("run_export_template_v1 failed to lock export templates");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+    let Some(template) = template else {
+        return ResultEnvelope::err(ErrorCode::NotFound, "export template not found");
+    };
+
+    let table_name = match state.connections.lock() {
+        Ok(manager) => manager.get_table_name(&request.table_id),
+        Err(_) => {
+            error!("run_export_template_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
             );
-            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
         }
     };
+    let Some(table_name) = table_name else {
+        warn!(
+            "run_export_template_v1 table not found table_id={}",
+            request.table_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::TableNotFound),
+        );
+    };
 
+    let path =
+        export_templates::expand_path(&template.path_pattern, &table_name, &chrono::Local::now());
     info!(
-        "checkout_table_latest_v1 ok table_id={} version={} elapsed_ms={}",
-        request.table_id,
-        version,
-        started_at.elapsed().as_millis()
+        "run_export_template_v1 start name=\"{}\" table_id={} path=\"{}\"",
+        template.name, request.table_id, path
     );
+    export_data_v1(
+        state,
+        ExportDataRequestV1 {
+            table_id: request.table_id,
+            path,
+            format: template.format,
+            projection: template.projection,
+            filter: template.filter,
+            limit: template.limit,
+            offset: None,
+            delimiter: template.delimiter,
+            with_header: template.with_header,
+            geometry_column: None,
+            job_id: None,
+        },
+    )
+    .await
+}
 
-    ResultEnvelope::ok(CheckoutTableLatestResponseV1 {
-        table_id: request.table_id,
-        version,
+pub async fn start_share_v1(
+    state: &AppState,
+    request: StartShareRequestV1,
+) -> ResultEnvelope<ShareSessionV1> {
+    middleware::run(
+        state,
+        CommandContext::admin("start_share_v1"),
+        start_share(state, request),
+    )
+    .await
+}
+
+/// Loads share pages through the regular scan path, with the share's projection,
+/// filter and masking rules fixed in `page`.
+fn share_page_loader(table: Table, page: ScanPage, timeout: Option<Duration>) -> share::PageLoader {
+    Arc::new(move |offset, limit| {
+        let table = table.clone();
+        let page = ScanPage {
+            offset,
+            limit,
+            ..page.clone()
+        };
+        Box::pin(async move {
+            let response = with_timeout(timeout, scan_page(table, page)).await?;
+            let DataChunk::Json(chunk) = response.chunk else {
+                return Err("share pages must be scanned as JSON".to_string());
+            };
+            Ok(share::SharePage {
+                columns: chunk
+                    .schema
+                    .fields
+                    .iter()
+                    .map(|field| field.name.clone())
+                    .collect(),
+                rows: chunk.rows,
+                offset: chunk.offset,
+                limit: chunk.limit,
+                next_offset: response.next_offset,
+            })
+        })
     })
 }
 
-pub async fn clone_table_v1(
+async fn start_share(
     state: &AppState,
-    request: CloneTableRequestV1,
-) -> ResultEnvelope<CloneTableResponseV1> {
-    let started_at = Instant::now();
+    request: StartShareRequestV1,
+) -> ResultEnvelope<ShareSessionV1> {
     info!(
-        "clone_table_v1 start connection_id={} table_id={} target=\"{}\"",
-        request.connection_id, request.table_id, request.target_table_name
+        "start_share_v1 start tables={} expires_in_seconds={:?} port={:?} local_only={}",
+        request.tables.len(),
+        request.expires_in_seconds,
+        request.port,
+        request.local_only
     );
-
-    let target_name = request.target_table_name.trim();
-    if target_name.is_empty() {
+    if request.tables.is_empty() {
         return ResultEnvelope::err(
             ErrorCode::InvalidArgument,
-            "target table name cannot be empty",
+            "select at least one table to share",
         );
     }
-
-    let (connection, table) = match state.connections.lock() {
-        Ok(manager) => {
-            let connection = manager.get_connection(&request.connection_id);
-            let table = manager.get_table(&request.table_id);
-            (connection, table)
-        }
-        Err(_) => {
-            error!("clone_table_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
+    let ttl = match request.expires_in_seconds {
+        None => share::DEFAULT_SHARE_TTL,
+        Some(0) => {
+            return ResultEnvelope::err(
+                ErrorCode::InvalidArgument,
+                "expiresInSeconds must be greater than zero",
+            )
         }
+        Some(seconds) => Duration::from_secs(seconds),
     };
-
-    let Some(connection) = connection else {
-        warn!(
-            "clone_table_v1 connection not found connection_id={}",
-            request.connection_id
-        );
-        return ResultEnvelope::err(ErrorCode::NotFound, "connection not found");
-    };
-
-    let Some(table) = table else {
-        warn!(
-            "clone_table_v1 table not found table_id={}",
-            request.table_id
+    if ttl > share::MAX_SHARE_TTL {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            format!(
+                "shares expire after at most {} seconds",
+                share::MAX_SHARE_TTL.as_secs()
+            ),
         );
-        return ResultEnvelope::err(ErrorCode::NotFound, "table not found");
-    };
+    }
 
-    let source_uri = table.dataset_uri().to_string();
-    let mut builder = connection.clone_table(target_name.to_string(), source_uri);
-    if let Some(version) = request.source_version {
-        builder = builder.source_version(version);
+    let timeout = app_config(state).query_timeout();
+    let mut tables: Vec<share::SharedTable> = Vec::new();
+    for shared in request.tables {
+        let table = match resolve_table(state, &shared.table_id, "start_share_v1") {
+            Ok(table) => table,
+            Err(envelope) => return envelope,
+        };
+        let name = table.name().to_string();
+        if tables.iter().any(|existing| existing.name == name) {
+            return ResultEnvelope::err(
+                ErrorCode::InvalidArgument,
+                format!("table '{name}' is shared more than once"),
+            );
+        }
+        let masking_rules =
+            match table_masking_rules(state, &shared.table_id).and_then(|mut rules| {
+                redact_encrypted_columns(state, &shared.table_id, &mut rules)?;
+                Ok(rules)
+            }) {
+                Ok(rules) => rules,
+                Err(message) => {
+                    error!("start_share_v1 failed to read masking rules");
+                    return ResultEnvelope::err_message(ErrorCode::Internal, message);
+                }
+            };
+        let projection = shared
+            .projection
+            .filter(|projection| !projection.is_empty());
+        if let Some(projection) = &projection {
+            if let Err(error) = projection::check_masked_columns(projection, &masking_rules) {
+                return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+            }
+        }
+        let filter = shared
+            .filter
+            .map(|filter| filter.trim().to_string())
+            .filter(|filter| !filter.is_empty());
+
+        let page = ScanPage {
+            table_id: shared.table_id.clone(),
+            format: DataFormat::Json,
+            projection,
+            filter: filter.clone(),
+            limit: 1,
+            offset: 0,
+            column_keys: encryption::ColumnKeys::default(),
+            masking_rules,
+            include_stats: false,
+            json_options: JsonOptionsV1::default(),
+            version: None,
+            max_chunk_bytes: None,
+        };
+        let load = share_page_loader(table, page, timeout);
+        // A bad filter or projection should fail here, not in the colleague's browser.
+        if let Err(error) = load(0, 1).await {
+            warn!(
+                "start_share_v1 invalid table table_id={} error={}",
+                shared.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+        }
+        tables.push(share::SharedTable { name, filter, load });
     }
-    if let Some(tag) = request
-        .source_tag
+
+    let started = match state.shares.lock() {
+        Ok(mut registry) => share::start(
+            &mut registry,
+            tables,
+            ttl,
+            request.port.unwrap_or(0),
+            request.local_only,
+            state.access_log.clone(),
+        ),
+        Err(_) => {
+            error!("start_share_v1 failed to lock share registry");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+    match started {
+        Ok(session) => {
+            info!(
+                "start_share_v1 ok share_id={} tables={:?} expires_at_ms={}",
+                session.share_id, session.tables, session.expires_at_ms
+            );
+            ResultEnvelope::ok(session)
+        }
+        Err(error) => {
+            error!("start_share_v1 failed error={}", error);
+            ResultEnvelope::err(ErrorCode::Internal, error)
+        }
+    }
+}
+
+pub async fn list_shares_v1(state: &AppState) -> ResultEnvelope<ListSharesResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("list_shares_v1"),
+        list_shares(state),
+    )
+    .await
+}
+
+async fn list_shares(state: &AppState) -> ResultEnvelope<ListSharesResponseV1> {
+    match state.shares.lock() {
+        Ok(mut registry) => ResultEnvelope::ok(ListSharesResponseV1 {
+            shares: registry.list(),
+        }),
+        Err(_) => {
+            error!("list_shares_v1 failed to lock share registry");
+            ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            )
+        }
+    }
+}
+
+pub async fn stop_share_v1(
+    state: &AppState,
+    request: StopShareRequestV1,
+) -> ResultEnvelope<StopShareResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::admin("stop_share_v1"),
+        stop_share(state, request),
+    )
+    .await
+}
+
+async fn stop_share(
+    state: &AppState,
+    request: StopShareRequestV1,
+) -> ResultEnvelope<StopShareResponseV1> {
+    let stopped = match state.shares.lock() {
+        Ok(mut registry) => registry.stop(&request.share_id),
+        Err(_) => {
+            error!("stop_share_v1 failed to lock share registry");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+    if !stopped {
+        return ResultEnvelope::err(ErrorCode::NotFound, "share not found");
+    }
+    info!("stop_share_v1 ok share_id={}", request.share_id);
+    ResultEnvelope::ok(StopShareResponseV1 {
+        share_id: request.share_id,
+    })
+}
+
+pub async fn import_bundle_v1(
+    state: &AppState,
+    request: ImportBundleRequestV1,
+) -> ResultEnvelope<ImportBundleResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::write_connection("import_bundle_v1", &request.connection_id),
+        import_bundle(state, request),
+    )
+    .await
+}
+
+async fn import_bundle(
+    state: &AppState,
+    request: ImportBundleRequestV1,
+) -> ResultEnvelope<ImportBundleResponseV1> {
+    let started_at = Instant::now();
+    let path = request.path.trim();
+    info!(
+        "import_bundle_v1 start connection_id={} path=\"{}\"",
+        request.connection_id, path
+    );
+    if path.is_empty() {
+        return ResultEnvelope::err_message(
+            ErrorCode::InvalidArgument,
+            messages::message(MessageCodeV1::PathEmpty),
+        );
+    }
+
+    let connection = match state.connections.lock() {
+        Ok(manager) => manager.get_connection(&request.connection_id),
+        Err(_) => {
+            error!("import_bundle_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    let Some(connection) = connection else {
+        warn!(
+            "import_bundle_v1 connection not found connection_id={}",
+            request.connection_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::ConnectionNotFound),
+        );
+    };
+
+    let dir = Path::new(path);
+    let manifest = match bundle::read_manifest(dir) {
+        Ok(manifest) => manifest,
+        Err(error) => {
+            warn!("import_bundle_v1 invalid bundle error={}", error);
+            return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+        }
+    };
+
+    let table_name = request
+        .table_name
         .as_deref()
         .map(str::trim)
         .filter(|value| !value.is_empty())
-    {
-        builder = builder.source_tag(tag.to_string());
-    }
-    if let Some(is_shallow) = request.is_shallow {
-        builder = builder.is_shallow(is_shallow);
-    }
+        .unwrap_or(manifest.table_name.as_str())
+        .to_string();
 
-    let cloned = match builder.execute().await {
+    let (batches, schema) = match bundle::read_parquet_shards(dir, &manifest) {
+        Ok(result) => result,
+        Err(error) => {
+            error!("import_bundle_v1 failed to read shards error={}", error);
+            return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+        }
+    };
+    let total_rows = batches.iter().map(|batch| batch.num_rows()).sum::<usize>();
+
+    let table = match connection
+        .create_empty_table(&table_name, schema.clone())
+        .execute()
+        .await
+    {
         Ok(table) => table,
         Err(error) => {
-            error!("clone_table_v1 failed error={}", error);
+            error!(
+                "import_bundle_v1 failed to create table=\"{}\" error={}",
+                table_name, error
+            );
             return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
         }
     };
 
+    if total_rows > 0 {
+        let batch_iter = RecordBatchIterator::new(batches.into_iter().map(Ok), schema);
+        if let Err(error) = table.add(batch_iter).execute().await {
+            error!(
+                "import_bundle_v1 failed to write rows table=\"{}\" error={}",
+                table_name, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    }
+
+    let mut indexes_created = Vec::new();
+    let mut index_warnings = Vec::new();
+    if !request.skip_indexes {
+        for index in &manifest.indexes {
+            let result = match to_bundle_lancedb_index(index) {
+                Ok(lancedb_index) => table
+                    .create_index(&index.columns, lancedb_index)
+                    .name(index.name.clone())
+                    .replace(true)
+                    .execute()
+                    .await
+                    .map_err(|error| error.to_string()),
+                Err(error) => Err(error),
+            };
+            match result {
+                Ok(()) => indexes_created.push(index.name.clone()),
+                Err(error) => {
+                    warn!(
+                        "import_bundle_v1 failed to recreate index table=\"{}\" index={} error={}",
+                        table_name, index.name, error
+                    );
+                    index_warnings.push(format!("{}: {}", index.name, error));
+                }
+            }
+        }
+    }
+
     let table_id = match state.connections.lock() {
-        Ok(mut manager) => manager.insert_table(
-            target_name.to_string(),
-            cloned,
-            request.connection_id.clone(),
-        ),
+        Ok(mut manager) => {
+            manager.insert_table(table_name.clone(), table, request.connection_id.clone())
+        }
         Err(_) => {
-            error!("clone_table_v1 failed to lock table manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock table manager");
+            error!("import_bundle_v1 failed to lock table manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
         }
     };
 
     info!(
-        "clone_table_v1 ok table_id={} name=\"{}\" elapsed_ms={}",
+        "import_bundle_v1 ok table_id={} table=\"{}\" rows={} indexes={} elapsed_ms={}",
         table_id,
-        target_name,
+        table_name,
+        total_rows,
+        indexes_created.len(),
         started_at.elapsed().as_millis()
     );
 
-    ResultEnvelope::ok(CloneTableResponseV1 {
+    ResultEnvelope::ok(ImportBundleResponseV1 {
         table_id,
-        name: target_name.to_string(),
+        name: table_name,
+        rows: total_rows,
+        indexes_created,
+        index_warnings,
     })
 }
 
-pub async fn scan_v1(state: &AppState, request: ScanRequestV1) -> ResultEnvelope<ScanResponseV1> {
+pub async fn optimize_table_v1(
+    state: &AppState,
+    request: OptimizeTableRequestV1,
+) -> ResultEnvelope<OptimizeTableResponseV1> {
+    run_as_job(
+        state,
+        request.job_id.clone(),
+        JobKindV1::Optimize,
+        None,
+        middleware::run(
+            state,
+            CommandContext::write_table("optimize_table_v1", &request.table_id),
+            optimize_table(state, request),
+        ),
+    )
+    .await
+}
+
+async fn optimize_table(
+    state: &AppState,
+    request: OptimizeTableRequestV1,
+) -> ResultEnvelope<OptimizeTableResponseV1> {
     let started_at = Instant::now();
     info!(
-        "scan_v1 start table_id={} format={:?} limit={:?} offset={:?}",
-        request.table_id, request.format, request.limit, request.offset
+        "optimize_table_v1 start table_id={} action={:?}",
+        request.table_id, request.action
     );
-    if let Some(ref filter) = request.filter {
-        trace!("scan_v1 filter=\"{}\"", filter);
+
+    let OptimizeTableRequestV1 {
+        table_id,
+        action,
+        target_rows_per_fragment,
+        older_than_days,
+        delete_unverified,
+        error_if_tagged_old_versions,
+        locale,
+        wait_timeout_ms,
+        job_id: _,
+    } = request;
+    let locale = locale.unwrap_or_else(messages::current_locale);
+    if wait_timeout_ms.is_some() && !matches!(action, OptimizeActionV1::Index) {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            "waitTimeoutMs only applies to the index action",
+        );
     }
-    if let Some(ref projection) = request.projection {
-        trace!("scan_v1 projection={:?}", projection);
+
+    let table = match state.connections.lock() {
+        Ok(manager) => manager.get_table(&table_id),
+        Err(_) => {
+            error!("optimize_table_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    let Some(table) = table else {
+        warn!("optimize_table_v1 table not found table_id={}", table_id);
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::TableNotFound),
+        );
+    };
+
+    let (opt_action, summary) = match action {
+        OptimizeActionV1::Compact => {
+            if let Some(target_rows) = target_rows_per_fragment {
+                if target_rows == 0 {
+                    return ResultEnvelope::err(
+                        ErrorCode::InvalidArgument,
+                        "target_rows_per_fragment must be greater than 0",
+                    );
+                }
+            }
+            let mut options = CompactionOptions::default();
+            if let Some(target_rows) = target_rows_per_fragment {
+                let target_rows = match usize::try_from(target_rows) {
+                    Ok(value) => value,
+                    Err(_) => {
+                        return ResultEnvelope::err(
+                            ErrorCode::InvalidArgument,
+                            "target_rows_per_fragment is too large",
+                        );
+                    }
+                };
+                options.target_rows_per_fragment = target_rows;
+            }
+            // Rewritten fragments sit next to the old ones until cleanup, so at worst
+            // compaction needs room for a second copy of the table.
+            if let Some(dataset_path) = local_path(table.dataset_uri()) {
+                match table.stats().await {
+                    Ok(stats) => {
+                        if let Err(envelope) = check_disk_space(
+                            &dataset_path,
+                            stats.total_bytes as u64,
+                            "optimize_table_v1",
+                        ) {
+                            return envelope;
+                        }
+                    }
+                    Err(error) => warn!(
+                        "optimize_table_v1 skipped disk space check table_id={} error={}",
+                        table_id, error
+                    ),
+                }
+            }
+            let summary = match target_rows_per_fragment {
+                Some(value) => messages::message_with(
+                    MessageCodeV1::CompactSubmittedWithTarget,
+                    locale,
+                    HashMap::from([("target_rows".to_string(), value.to_string())]),
+                ),
+                None => {
+                    messages::message_with(MessageCodeV1::CompactSubmitted, locale, HashMap::new())
+                }
+            };
+            (
+                OptimizeAction::Compact {
+                    options,
+                    remap_options: None,
+                },
+                Some(summary),
+            )
+        }
+        OptimizeActionV1::Vacuum => {
+            let older_than = match older_than_days {
+                Some(days) => {
+                    let days_i64 = match i64::try_from(days) {
+                        Ok(value) => value,
+                        Err(_) => {
+                            return ResultEnvelope::err(
+                                ErrorCode::InvalidArgument,
+                                "older_than_days is too large",
+                            );
+                        }
+                    };
+                    Some(LanceDuration::days(days_i64))
+                }
+                None => None,
+            };
+            let summary = match older_than_days {
+                Some(value) => messages::message_with(
+                    MessageCodeV1::VacuumSubmittedOlderThan,
+                    locale,
+                    HashMap::from([("days".to_string(), value.to_string())]),
+                ),
+                None => {
+                    messages::message_with(MessageCodeV1::VacuumSubmitted, locale, HashMap::new())
+                }
+            };
+            (
+                OptimizeAction::Prune {
+                    older_than,
+                    delete_unverified,
+                    error_if_tagged_old_versions,
+                },
+                Some(summary),
+            )
+        }
+        // The summary reports the coverage reached, so it is built afterwards.
+        OptimizeActionV1::Index => (OptimizeAction::Index(OptimizeOptions::default()), None),
+    };
+
+    report_stage(
+        state,
+        match action {
+            OptimizeActionV1::Compact => ProgressStageV1::Compacting,
+            OptimizeActionV1::Vacuum => ProgressStageV1::Pruning,
+            OptimizeActionV1::Index => ProgressStageV1::Indexing,
+        },
+    );
+    let optimized = match table.optimize(opt_action).await {
+        Ok(optimized) => optimized,
+        Err(error) => {
+            let message = error.to_string();
+            let lower = message.to_lowercase();
+            let code = if lower.contains("not supported") {
+                ErrorCode::NotImplemented
+            } else {
+                ErrorCode::Internal
+            };
+            error!(
+                "optimize_table_v1 failed table_id={} error={}",
+                table_id, message
+            );
+            return ResultEnvelope::err(code, message);
+        }
+    };
+
+    let mut stats = OptimizeStatsV1::default();
+    if let Some(compaction) = optimized.compaction {
+        stats.fragments_removed = Some(compaction.fragments_removed);
+        stats.fragments_added = Some(compaction.fragments_added);
+        stats.files_removed = Some(compaction.files_removed);
+        stats.files_added = Some(compaction.files_added);
+    }
+    if let Some(prune) = optimized.prune {
+        stats.bytes_removed = Some(prune.bytes_removed);
+        stats.old_versions_removed = Some(prune.old_versions);
+    }
+    if matches!(action, OptimizeActionV1::Index) {
+        let wait_timeout = wait_timeout_ms.map(Duration::from_millis);
+        (stats.indexes, stats.index_pending) =
+            match optimized_index_stats(&table, wait_timeout).await {
+                Ok(indexes) => indexes,
+                Err(error) => {
+                    error!(
+                        "optimize_table_v1 failed to read index stats table_id={} error={}",
+                        table_id, error
+                    );
+                    return ResultEnvelope::err(ErrorCode::Internal, error);
+                }
+            };
+    }
+    stats.elapsed_ms = started_at.elapsed().as_millis() as u64;
+    let summary = summary.unwrap_or_else(|| {
+        let unindexed_rows = stats
+            .indexes
+            .iter()
+            .map(|index| index.num_unindexed_rows)
+            .sum::<u64>();
+        messages::message_with(
+            MessageCodeV1::IndexesOptimized,
+            locale,
+            HashMap::from([
+                ("indexes".to_string(), stats.indexes.len().to_string()),
+                ("unindexed_rows".to_string(), unindexed_rows.to_string()),
+            ]),
+        )
+    });
+
+    info!(
+        "optimize_table_v1 ok table_id={} action={:?} elapsed_ms={}",
+        table_id,
+        action,
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(OptimizeTableResponseV1 {
+        table_id,
+        action,
+        summary: summary.text.clone(),
+        summary_message: summary,
+        stats,
+    })
+}
+
+/// Waits up to `wait_timeout` for every index to cover the table, then reads how
+/// many rows each one covers. Running out of time is not an error, since the
+/// optimization has already committed; it is reported as still pending.
+async fn optimized_index_stats(
+    table: &Table,
+    wait_timeout: Option<Duration>,
+) -> Result<(Vec<OptimizedIndexV1>, bool), String> {
+    let configs = table
+        .list_indices()
+        .await
+        .map_err(|error| error.to_string())?;
+    let mut pending = false;
+    if let Some(timeout) = wait_timeout {
+        let names = configs
+            .iter()
+            .map(|config| config.name.as_str())
+            .collect::<Vec<_>>();
+        match table.wait_for_index(&names, timeout).await {
+            Ok(()) => {}
+            Err(lancedb::Error::Timeout { message }) => {
+                info!("optimize_table_v1 indexes still pending: {}", message);
+                pending = true;
+            }
+            Err(error) => return Err(error.to_string()),
+        }
+    }
+    let mut indexes = Vec::with_capacity(configs.len());
+    for config in configs {
+        let Some(stats) = table
+            .index_stats(&config.name)
+            .await
+            .map_err(|error| error.to_string())?
+        else {
+            continue;
+        };
+        indexes.push(OptimizedIndexV1 {
+            name: config.name,
+            num_indexed_rows: stats.num_indexed_rows as u64,
+            num_unindexed_rows: stats.num_unindexed_rows as u64,
+        });
+    }
+    Ok((indexes, pending))
+}
+
+pub async fn open_table_v1(
+    state: &AppState,
+    request: OpenTableRequestV1,
+) -> ResultEnvelope<TableHandle> {
+    middleware::run(
+        state,
+        CommandContext::read("open_table_v1"),
+        open_table(state, request),
+    )
+    .await
+}
+
+async fn open_table(state: &AppState, request: OpenTableRequestV1) -> ResultEnvelope<TableHandle> {
+    let started_at = Instant::now();
+    info!(
+        "open_table_v1 start connection_id={} table=\"{}\"",
+        request.connection_id, request.table_name
+    );
+    let connection = match state.connections.lock() {
+        Ok(manager) => manager.get_connection(&request.connection_id),
+        Err(_) => {
+            error!("open_table_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    let Some(connection) = connection else {
+        warn!(
+            "open_table_v1 connection not found connection_id={}",
+            request.connection_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::ConnectionNotFound),
+        );
+    };
+
+    let table = match connection.open_table(&request.table_name).execute().await {
+        Ok(table) => table,
+        Err(error) => {
+            error!(
+                "open_table_v1 failed connection_id={} table=\"{}\" error={}",
+                request.connection_id, request.table_name, error
+            );
+            if matches!(error, lancedb::Error::TableNotFound { .. }) {
+                let backend = LanceBackend::new(connection);
+                return ResultEnvelope::err_envelope(
+                    table_not_found_error(&backend, &request.table_name).await,
+                );
+            }
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    };
+
+    let table_id = match state.connections.lock() {
+        Ok(mut manager) => manager.insert_table(
+            request.table_name.clone(),
+            table.clone(),
+            request.connection_id.clone(),
+        ),
+        Err(_) => {
+            error!("open_table_v1 failed to lock table manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    spawn_scan_warmup(state, &table_id, table);
+
+    info!(
+        "open_table_v1 ok connection_id={} table_id={} table=\"{}\" elapsed_ms={}",
+        request.connection_id,
+        table_id,
+        request.table_name,
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(TableHandle {
+        table_id,
+        name: request.table_name,
+    })
+}
+
+/// Opens several tables of one connection concurrently. A table that fails to open
+/// is reported in its entry and does not fail the batch.
+pub async fn open_tables_v1(
+    state: &AppState,
+    request: OpenTablesRequestV1,
+) -> ResultEnvelope<OpenTablesResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("open_tables_v1"),
+        open_tables(state, request),
+    )
+    .await
+}
+
+async fn open_tables(
+    state: &AppState,
+    request: OpenTablesRequestV1,
+) -> ResultEnvelope<OpenTablesResponseV1> {
+    let started_at = Instant::now();
+    info!(
+        "open_tables_v1 start connection_id={} tables={}",
+        request.connection_id,
+        request.table_names.len()
+    );
+    let known = match state.connections.lock() {
+        Ok(manager) => manager.get_connection(&request.connection_id).is_some(),
+        Err(_) => {
+            error!("open_tables_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+    if !known {
+        warn!(
+            "open_tables_v1 connection not found connection_id={}",
+            request.connection_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::ConnectionNotFound),
+        );
+    }
+
+    let opened = futures_util::future::join_all(request.table_names.iter().map(|table_name| {
+        open_table(
+            state,
+            OpenTableRequestV1 {
+                connection_id: request.connection_id.clone(),
+                table_name: table_name.clone(),
+            },
+        )
+    }))
+    .await;
+    let tables = request
+        .table_names
+        .into_iter()
+        .zip(opened)
+        .map(|(table_name, result)| OpenTableResultV1 {
+            table_name,
+            handle: result.data,
+            error: result.error,
+        })
+        .collect::<Vec<_>>();
+    let opened = tables.iter().filter(|table| table.handle.is_some()).count();
+    let failed = tables.len() - opened;
+
+    info!(
+        "open_tables_v1 ok connection_id={} opened={} failed={} elapsed_ms={}",
+        request.connection_id,
+        opened,
+        failed,
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(OpenTablesResponseV1 {
+        tables,
+        opened,
+        failed,
+    })
+}
+
+pub async fn open_dataset_v1(
+    state: &AppState,
+    request: OpenDatasetRequestV1,
+) -> ResultEnvelope<OpenDatasetResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("open_dataset_v1"),
+        open_dataset(state, request),
+    )
+    .await
+}
+
+async fn open_dataset(
+    state: &AppState,
+    request: OpenDatasetRequestV1,
+) -> ResultEnvelope<OpenDatasetResponseV1> {
+    let started_at = Instant::now();
+    let uri = request.uri.trim();
+    info!("open_dataset_v1 start uri=\"{}\"", uri);
+
+    let Some((parent_uri, table_name)) = split_dataset_uri(uri) else {
+        warn!("open_dataset_v1 not a .lance directory uri=\"{}\"", uri);
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            "dataset path must point to a directory ending in .lance",
+        );
+    };
+    if matches!(infer_backend_kind(uri), BackendKind::Local) {
+        let path = Path::new(uri);
+        if !path.join("_versions").is_dir() {
+            warn!("open_dataset_v1 missing _versions uri=\"{}\"", uri);
+            return ResultEnvelope::err(
+                ErrorCode::InvalidArgument,
+                format!("{} is not a Lance dataset", path.display()),
+            );
+        }
+    }
+
+    let connected = connect_v1(
+        state,
+        ConnectRequestV1 {
+            profile: ConnectProfile {
+                name: table_name.clone(),
+                uri: parent_uri,
+                storage_options: request.storage_options,
+                options: request.options,
+                auth: AuthDescriptor::None,
+            },
+        },
+    )
+    .await;
+    let connection = match connected.into_result() {
+        Ok(connection) => connection,
+        Err(error) => return ResultEnvelope::err_envelope(error),
+    };
+
+    let opened = open_table_v1(
+        state,
+        OpenTableRequestV1 {
+            connection_id: connection.connection_id.clone(),
+            table_name,
+        },
+    )
+    .await;
+    let table = match opened.into_result() {
+        Ok(table) => table,
+        Err(error) => {
+            // The synthetic connection is useless without its dataset.
+            if let Ok(mut manager) = state.connections.lock() {
+                manager.remove_connection(&connection.connection_id);
+            }
+            return ResultEnvelope::err_envelope(error);
+        }
+    };
+
+    info!(
+        "open_dataset_v1 ok connection_id={} table_id={} elapsed_ms={}",
+        connection.connection_id,
+        table.table_id,
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(OpenDatasetResponseV1 { connection, table })
+}
+
+pub async fn get_schema_v1(
+    state: &AppState,
+    request: GetSchemaRequestV1,
+) -> ResultEnvelope<SchemaDefinition> {
+    middleware::run(
+        state,
+        CommandContext::read("get_schema_v1"),
+        get_schema(state, request),
+    )
+    .await
+}
+
+async fn get_schema(
+    state: &AppState,
+    request: GetSchemaRequestV1,
+) -> ResultEnvelope<SchemaDefinition> {
+    let started_at = Instant::now();
+    info!("get_schema_v1 start table_id={}", request.table_id);
+    let table = match resolve_table(state, &request.table_id, "get_schema_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+
+    let schema = match LanceTableBackend::new(table).schema().await {
+        Ok(schema) => schema,
+        Err(error) => {
+            error!(
+                "get_schema_v1 failed table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(error.code(), error.to_string());
+        }
+    };
+
+    let definition = column_order::definition(schema.as_ref());
+    info!(
+        "get_schema_v1 ok table_id={} fields={} elapsed_ms={}",
+        request.table_id,
+        definition.fields.len(),
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(definition)
+}
+
+pub async fn list_versions_v1(
+    state: &AppState,
+    request: ListVersionsRequestV1,
+) -> ResultEnvelope<ListVersionsResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("list_versions_v1"),
+        list_versions(state, request),
+    )
+    .await
+}
+
+async fn list_versions(
+    state: &AppState,
+    request: ListVersionsRequestV1,
+) -> ResultEnvelope<ListVersionsResponseV1> {
+    let started_at = Instant::now();
+    info!("list_versions_v1 start table_id={}", request.table_id);
+
+    let table = match resolve_table(state, &request.table_id, "list_versions_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+
+    let versions = match LanceTableBackend::new(table).list_versions().await {
+        Ok(versions) => versions,
+        Err(error) => {
+            error!(
+                "list_versions_v1 failed table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(error.code(), error.to_string());
+        }
+    };
+
+    info!(
+        "list_versions_v1 ok table_id={} versions={} elapsed_ms={}",
+        request.table_id,
+        versions.len(),
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(ListVersionsResponseV1 { versions })
+}
+
+pub async fn get_table_version_v1(
+    state: &AppState,
+    request: GetTableVersionRequestV1,
+) -> ResultEnvelope<GetTableVersionResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("get_table_version_v1"),
+        get_table_version(state, request),
+    )
+    .await
+}
+
+async fn get_table_version(
+    state: &AppState,
+    request: GetTableVersionRequestV1,
+) -> ResultEnvelope<GetTableVersionResponseV1> {
+    let started_at = Instant::now();
+    info!("get_table_version_v1 start table_id={}", request.table_id);
+
+    let table = match resolve_table(state, &request.table_id, "get_table_version_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+
+    let version = match LanceTableBackend::new(table).version().await {
+        Ok(version) => version,
+        Err(error) => {
+            error!(
+                "get_table_version_v1 failed table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(error.code(), error.to_string());
+        }
+    };
+
+    info!(
+        "get_table_version_v1 ok table_id={} version={} elapsed_ms={}",
+        request.table_id,
+        version,
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(GetTableVersionResponseV1 {
+        table_id: request.table_id,
+        version,
+    })
+}
+
+pub async fn checkout_table_version_v1(
+    state: &AppState,
+    request: CheckoutTableVersionRequestV1,
+) -> ResultEnvelope<CheckoutTableVersionResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("checkout_table_version_v1"),
+        checkout_table_version(state, request),
+    )
+    .await
+}
+
+async fn checkout_table_version(
+    state: &AppState,
+    request: CheckoutTableVersionRequestV1,
+) -> ResultEnvelope<CheckoutTableVersionResponseV1> {
+    let started_at = Instant::now();
+    info!(
+        "checkout_table_version_v1 start table_id={} version={:?} tag={:?}",
+        request.table_id, request.version, request.tag
+    );
+
+    let table = match resolve_table(state, &request.table_id, "checkout_table_version_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+
+    let target = match (request.version, request.tag.as_deref().map(str::trim)) {
+        (Some(version), None) => version,
+        (None, Some(tag)) => match tag_version(&table, tag).await {
+            Ok(Some(version)) => version,
+            Ok(None) => {
+                return ResultEnvelope::err(ErrorCode::NotFound, format!("tag '{tag}' not found"))
+            }
+            Err(error) => {
+                error!(
+                    "checkout_table_version_v1 failed to read tags table_id={} error={}",
+                    request.table_id, error
+                );
+                return ResultEnvelope::err(ErrorCode::Internal, error);
+            }
+        },
+        _ => {
+            return ResultEnvelope::err(
+                ErrorCode::InvalidArgument,
+                "set either version or tag to check out",
+            )
+        }
+    };
+
+    if let Err(error) = table.checkout(target).await {
+        error!(
+            "checkout_table_version_v1 failed table_id={} error={}",
+            request.table_id, error
+        );
+        return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+    }
+
+    let version = match table.version().await {
+        Ok(version) => version,
+        Err(error) => {
+            error!(
+                "checkout_table_version_v1 read version failed table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    };
+
+    if let Ok(mut manager) = state.connections.lock() {
+        manager.set_checked_out_version(&request.table_id, Some(version));
+    }
+    after_table_write(state, &request.table_id);
+
+    info!(
+        "checkout_table_version_v1 ok table_id={} version={} elapsed_ms={}",
+        request.table_id,
+        version,
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(CheckoutTableVersionResponseV1 {
+        table_id: request.table_id,
+        version,
+    })
+}
+
+pub async fn restore_table_version_v1(
+    state: &AppState,
+    request: RestoreTableVersionRequestV1,
+) -> ResultEnvelope<RestoreTableVersionResponseV1> {
+    let context = CommandContext::write_table("restore_table_version_v1", &request.table_id);
+    middleware::run(state, context, restore_table_version(state, request)).await
+}
+
+async fn restore_table_version(
+    state: &AppState,
+    request: RestoreTableVersionRequestV1,
+) -> ResultEnvelope<RestoreTableVersionResponseV1> {
+    let started_at = Instant::now();
+    info!(
+        "restore_table_version_v1 start table_id={} version={}",
+        request.table_id, request.version
+    );
+
+    let table = match resolve_table(state, &request.table_id, "restore_table_version_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+
+    match has_version(&table, request.version).await {
+        Ok(true) => {}
+        Ok(false) => {
+            return ResultEnvelope::err(
+                ErrorCode::NotFound,
+                format!("version {} does not exist", request.version),
+            )
+        }
+        Err(error) => {
+            error!(
+                "restore_table_version_v1 failed to list versions table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    }
+
+    // `restore` commits the checked out version on top of the latest one.
+    let restored = async {
+        table
+            .checkout(request.version)
+            .await
+            .map_err(|error| error.to_string())?;
+        table.restore().await.map_err(|error| error.to_string())?;
+        table.version().await.map_err(|error| error.to_string())
+    };
+    let version = match restored.await {
+        Ok(version) => version,
+        Err(error) => {
+            if let Err(checkout_error) = table.checkout_latest().await {
+                warn!(
+                    "restore_table_version_v1 failed to return to latest table_id={} error={}",
+                    request.table_id, checkout_error
+                );
+            }
+            error!(
+                "restore_table_version_v1 failed table_id={} version={} error={}",
+                request.table_id, request.version, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    };
+
+    // `restore` leaves the handle on the new latest version.
+    if let Ok(mut manager) = state.connections.lock() {
+        manager.set_checked_out_version(&request.table_id, None);
+    }
+    after_table_write(state, &request.table_id);
+    auto_checkpoint(
+        state,
+        &request.table_id,
+        "restore",
+        Some(version),
+        request.checkpoint_note.as_deref(),
+    )
+    .await;
+
+    info!(
+        "restore_table_version_v1 ok table_id={} restored_version={} version={} elapsed_ms={}",
+        request.table_id,
+        request.version,
+        version,
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(RestoreTableVersionResponseV1 {
+        table_id: request.table_id,
+        restored_version: request.version,
+        version,
+    })
+}
+
+/// Tags of `table`, newest version first.
+async fn list_version_tags(table: &Table) -> Result<Vec<VersionTagV1>, String> {
+    let tags = table.tags().await.map_err(|error| error.to_string())?;
+    let listed = tags.list().await.map_err(|error| error.to_string())?;
+    Ok(version_tags::sorted(listed.into_iter().map(
+        |(name, contents)| VersionTagV1 {
+            name,
+            version: contents.version,
+            note: None,
+        },
+    )))
+}
+
+/// Fills in the notes saved for the automatic checkpoints among `tags`. Notes are
+/// left out when the table or the note store can't be read.
+fn with_checkpoint_notes(
+    state: &AppState,
+    table_id: &str,
+    mut tags: Vec<VersionTagV1>,
+) -> Vec<VersionTagV1> {
+    let Ok(Some((connection_uri, table_name))) = table_settings_key(state, table_id) else {
+        return tags;
+    };
+    let notes = match state.checkpoint_notes.lock() {
+        Ok(store) => store.for_table(&connection_uri, &table_name),
+        Err(_) => {
+            warn!("failed to lock checkpoint note store table_id={}", table_id);
+            return tags;
+        }
+    };
+    for tag in &mut tags {
+        tag.note = notes.get(&tag.name).cloned();
+    }
+    tags
+}
+
+/// Saves or, with `None`, removes the note of tag `name`. Failures are only
+/// logged; the tag itself has already changed.
+fn set_checkpoint_note(state: &AppState, table_id: &str, name: &str, note: Option<&str>) {
+    let Ok(Some((connection_uri, table_name))) = table_settings_key(state, table_id) else {
+        return;
+    };
+    let saved = match state.checkpoint_notes.lock() {
+        Ok(mut store) => store.set(&connection_uri, &table_name, name, note),
+        Err(_) => Err("failed to lock checkpoint note store".to_string()),
+    };
+    if let Err(error) = saved {
+        warn!(
+            "failed to save checkpoint note table_id={} tag=\"{}\" error={}",
+            table_id, name, error
+        );
+    }
+}
+
+async fn tag_version(table: &Table, name: &str) -> Result<Option<u64>, String> {
+    Ok(list_version_tags(table)
+        .await?
+        .into_iter()
+        .find(|tag| tag.name == name)
+        .map(|tag| tag.version))
+}
+
+async fn has_version(table: &Table, version: u64) -> Result<bool, String> {
+    let versions = table
+        .list_versions()
+        .await
+        .map_err(|error| error.to_string())?;
+    Ok(versions.iter().any(|info| info.version == version))
+}
+
+pub async fn list_tags_v1(
+    state: &AppState,
+    request: ListTagsRequestV1,
+) -> ResultEnvelope<ListTagsResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("list_tags_v1"),
+        list_tags(state, request),
+    )
+    .await
+}
+
+async fn list_tags(
+    state: &AppState,
+    request: ListTagsRequestV1,
+) -> ResultEnvelope<ListTagsResponseV1> {
+    let table = match resolve_table(state, &request.table_id, "list_tags_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+    match list_version_tags(&table).await {
+        Ok(tags) => ResultEnvelope::ok(ListTagsResponseV1 {
+            tags: with_checkpoint_notes(state, &request.table_id, tags),
+            table_id: request.table_id,
+        }),
+        Err(error) => {
+            error!(
+                "list_tags_v1 failed table_id={} error={}",
+                request.table_id, error
+            );
+            ResultEnvelope::err(ErrorCode::Internal, error)
+        }
+    }
+}
+
+pub async fn create_tag_v1(
+    state: &AppState,
+    request: CreateTagRequestV1,
+) -> ResultEnvelope<TagResponseV1> {
+    let context = CommandContext::write_table("create_tag_v1", &request.table_id);
+    middleware::run(state, context, create_tag(state, request)).await
+}
+
+async fn create_tag(
+    state: &AppState,
+    request: CreateTagRequestV1,
+) -> ResultEnvelope<TagResponseV1> {
+    let name = request.name.trim().to_string();
+    info!(
+        "create_tag_v1 start table_id={} name=\"{}\" version={:?}",
+        request.table_id, name, request.version
+    );
+    if let Err(error) = version_tags::validate_tag_name(&name) {
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+    }
+    let table = match resolve_table(state, &request.table_id, "create_tag_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+
+    let created = async {
+        if tag_version(&table, &name).await?.is_some() {
+            return Ok(Err(format!(
+                "tag '{name}' already exists; use update_tag_v1 to move it"
+            )));
+        }
+        let version = match request.version {
+            Some(version) => version,
+            None => table.version().await.map_err(|error| error.to_string())?,
+        };
+        if !has_version(&table, version).await? {
+            return Ok(Err(format!("version {version} does not exist")));
+        }
+        let mut tags = table.tags().await.map_err(|error| error.to_string())?;
+        tags.create(&name, version)
+            .await
+            .map_err(|error| error.to_string())?;
+        Ok::<_, String>(Ok(version))
+    };
+    let version = match created.await {
+        Ok(Ok(version)) => version,
+        Ok(Err(error)) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
+        Err(error) => {
+            error!(
+                "create_tag_v1 failed table_id={} name=\"{}\" error={}",
+                request.table_id, name, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    };
+
+    info!(
+        "create_tag_v1 ok table_id={} name=\"{}\" version={}",
+        request.table_id, name, version
+    );
+    ResultEnvelope::ok(TagResponseV1 {
+        table_id: request.table_id,
+        tag: VersionTagV1 {
+            name,
+            version,
+            note: None,
+        },
+    })
+}
+
+pub async fn update_tag_v1(
+    state: &AppState,
+    request: UpdateTagRequestV1,
+) -> ResultEnvelope<TagResponseV1> {
+    let context = CommandContext::write_table("update_tag_v1", &request.table_id);
+    middleware::run(state, context, update_tag(state, request)).await
+}
+
+async fn update_tag(
+    state: &AppState,
+    request: UpdateTagRequestV1,
+) -> ResultEnvelope<TagResponseV1> {
+    let name = request.name.trim().to_string();
+    info!(
+        "update_tag_v1 start table_id={} name=\"{}\" version={}",
+        request.table_id, name, request.version
+    );
+    let table = match resolve_table(state, &request.table_id, "update_tag_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+
+    let updated = async {
+        if tag_version(&table, &name).await?.is_none() {
+            return Ok(Err((
+                ErrorCode::NotFound,
+                format!("tag '{name}' not found"),
+            )));
+        }
+        if !has_version(&table, request.version).await? {
+            return Ok(Err((
+                ErrorCode::InvalidArgument,
+                format!("version {} does not exist", request.version),
+            )));
+        }
+        let mut tags = table.tags().await.map_err(|error| error.to_string())?;
+        tags.update(&name, request.version)
+            .await
+            .map_err(|error| error.to_string())?;
+        Ok::<_, String>(Ok(()))
+    };
+    match updated.await {
+        Ok(Ok(())) => {}
+        Ok(Err((code, error))) => return ResultEnvelope::err(code, error),
+        Err(error) => {
+            error!(
+                "update_tag_v1 failed table_id={} name=\"{}\" error={}",
+                request.table_id, name, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    }
+
+    info!(
+        "update_tag_v1 ok table_id={} name=\"{}\" version={}",
+        request.table_id, name, request.version
+    );
+    let tag = VersionTagV1 {
+        name,
+        version: request.version,
+        note: None,
+    };
+    let mut tags = with_checkpoint_notes(state, &request.table_id, vec![tag]);
+    ResultEnvelope::ok(TagResponseV1 {
+        table_id: request.table_id,
+        tag: tags.remove(0),
+    })
+}
+
+pub async fn delete_tag_v1(
+    state: &AppState,
+    request: DeleteTagRequestV1,
+) -> ResultEnvelope<DeleteTagResponseV1> {
+    let context = CommandContext::write_table("delete_tag_v1", &request.table_id);
+    middleware::run(state, context, delete_tag(state, request)).await
+}
+
+async fn delete_tag(
+    state: &AppState,
+    request: DeleteTagRequestV1,
+) -> ResultEnvelope<DeleteTagResponseV1> {
+    let name = request.name.trim().to_string();
+    info!(
+        "delete_tag_v1 start table_id={} name=\"{}\"",
+        request.table_id, name
+    );
+    let table = match resolve_table(state, &request.table_id, "delete_tag_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+
+    let deleted = async {
+        if tag_version(&table, &name).await?.is_none() {
+            return Ok(false);
+        }
+        let mut tags = table.tags().await.map_err(|error| error.to_string())?;
+        tags.delete(&name)
+            .await
+            .map_err(|error| error.to_string())?;
+        Ok::<_, String>(true)
+    };
+    match deleted.await {
+        Ok(true) => set_checkpoint_note(state, &request.table_id, &name, None),
+        Ok(false) => {
+            return ResultEnvelope::err(ErrorCode::NotFound, format!("tag '{name}' not found"))
+        }
+        Err(error) => {
+            error!(
+                "delete_tag_v1 failed table_id={} name=\"{}\" error={}",
+                request.table_id, name, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    }
+
+    info!(
+        "delete_tag_v1 ok table_id={} name=\"{}\"",
+        request.table_id, name
+    );
+    ResultEnvelope::ok(DeleteTagResponseV1 {
+        table_id: request.table_id,
+        name,
+    })
+}
+
+pub async fn checkout_table_latest_v1(
+    state: &AppState,
+    request: CheckoutTableLatestRequestV1,
+) -> ResultEnvelope<CheckoutTableLatestResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("checkout_table_latest_v1"),
+        checkout_table_latest(state, request),
+    )
+    .await
+}
+
+async fn checkout_table_latest(
+    state: &AppState,
+    request: CheckoutTableLatestRequestV1,
+) -> ResultEnvelope<CheckoutTableLatestResponseV1> {
+    let started_at = Instant::now();
+    info!(
+        "checkout_table_latest_v1 start table_id={}",
+        request.table_id
+    );
+
+    let table = match resolve_table(state, &request.table_id, "checkout_table_latest_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+
+    if let Err(error) = table.checkout_latest().await {
+        error!(
+            "checkout_table_latest_v1 failed table_id={} error={}",
+            request.table_id, error
+        );
+        return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+    }
+
+    let version = match table.version().await {
+        Ok(version) => version,
+        Err(error) => {
+            error!(
+                "checkout_table_latest_v1 read version failed table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    };
+
+    if let Ok(mut manager) = state.connections.lock() {
+        manager.set_checked_out_version(&request.table_id, None);
+    }
+    after_table_write(state, &request.table_id);
+
+    info!(
+        "checkout_table_latest_v1 ok table_id={} version={} elapsed_ms={}",
+        request.table_id,
+        version,
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(CheckoutTableLatestResponseV1 {
+        table_id: request.table_id,
+        version,
+    })
+}
+
+pub async fn clone_table_v1(
+    state: &AppState,
+    request: CloneTableRequestV1,
+) -> ResultEnvelope<CloneTableResponseV1> {
+    run_as_job(
+        state,
+        request.job_id.clone(),
+        JobKindV1::Clone,
+        None,
+        middleware::run(
+            state,
+            CommandContext::write_connection("clone_table_v1", &request.connection_id),
+            clone_table(state, request),
+        ),
+    )
+    .await
+}
+
+async fn clone_table(
+    state: &AppState,
+    request: CloneTableRequestV1,
+) -> ResultEnvelope<CloneTableResponseV1> {
+    let started_at = Instant::now();
+    info!(
+        "clone_table_v1 start connection_id={} table_id={} target=\"{}\"",
+        request.connection_id, request.table_id, request.target_table_name
+    );
+
+    let target_name = request.target_table_name.trim();
+    if target_name.is_empty() {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            "target table name cannot be empty",
+        );
+    }
+
+    let (connection, table) = match state.connections.lock() {
+        Ok(manager) => {
+            let connection = manager.get_connection(&request.connection_id);
+            let table = manager.get_table(&request.table_id);
+            (connection, table)
+        }
+        Err(_) => {
+            error!("clone_table_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    let Some(connection) = connection else {
+        warn!(
+            "clone_table_v1 connection not found connection_id={}",
+            request.connection_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::ConnectionNotFound),
+        );
+    };
+
+    let Some(table) = table else {
+        warn!(
+            "clone_table_v1 table not found table_id={}",
+            request.table_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::TableNotFound),
+        );
+    };
+
+    let source_uri = table.dataset_uri().to_string();
+    let mut builder = connection.clone_table(target_name.to_string(), source_uri);
+    if let Some(version) = request.source_version {
+        builder = builder.source_version(version);
+    }
+    if let Some(tag) = request
+        .source_tag
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        builder = builder.source_tag(tag.to_string());
+    }
+    if let Some(is_shallow) = request.is_shallow {
+        builder = builder.is_shallow(is_shallow);
+    }
+
+    report_stage(state, ProgressStageV1::Copying);
+    let cloned = match builder.execute().await {
+        Ok(table) => table,
+        Err(error) => {
+            error!("clone_table_v1 failed error={}", error);
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    };
+
+    let table_id = match state.connections.lock() {
+        Ok(mut manager) => manager.insert_table(
+            target_name.to_string(),
+            cloned,
+            request.connection_id.clone(),
+        ),
+        Err(_) => {
+            error!("clone_table_v1 failed to lock table manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    info!(
+        "clone_table_v1 ok table_id={} name=\"{}\" elapsed_ms={}",
+        table_id,
+        target_name,
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(CloneTableResponseV1 {
+        table_id,
+        name: target_name.to_string(),
+    })
+}
+
+pub async fn materialize_query_v1(
+    state: &AppState,
+    request: MaterializeQueryRequestV1,
+) -> ResultEnvelope<MaterializeQueryResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::write_connection("materialize_query_v1", &request.connection_id),
+        materialize_query(state, request),
+    )
+    .await
+}
+
+/// Rejects the inputs the matching search command would reject.
+fn validate_materialize_source(source: &MaterializeSourceV1) -> Result<(), String> {
+    match source {
+        MaterializeSourceV1::Filter(request) if request.filter.trim().is_empty() => {
+            Err("filter expression cannot be empty".to_string())
+        }
+        MaterializeSourceV1::VectorSearch(request) if request.vector.is_empty() => {
+            Err("vector must not be empty".to_string())
+        }
+        MaterializeSourceV1::FtsSearch(request) if request.query.trim().is_empty() => {
+            Err("query text cannot be empty".to_string())
+        }
+        MaterializeSourceV1::CombinedSearch(request)
+            if request.vector.as_ref().is_none_or(Vec::is_empty)
+                || request
+                    .query
+                    .as_deref()
+                    .is_none_or(|query| query.trim().is_empty()) =>
+        {
+            Err("hybrid search requires both vector and query text".to_string())
+        }
+        MaterializeSourceV1::FtsSearch(FtsSearchRequestV1 {
+            reranker: Some(_), ..
+        })
+        | MaterializeSourceV1::CombinedSearch(CombinedSearchRequestV1 {
+            reranker: Some(RerankerV1::CrossEncoder(_) | RerankerV1::Expression(_)),
+            ..
+        }) => Err("reranked search results cannot be saved or compared yet".to_string()),
+        _ => Ok(()),
+    }
+}
+
+fn full_text_query(
+    query: String,
+    columns: Option<Vec<String>>,
+) -> Result<FullTextSearchQuery, String> {
+    let fts_query = FullTextSearchQuery::new(query);
+    match columns.filter(|columns| !columns.is_empty()) {
+        Some(columns) => fts_query
+            .with_columns(&columns)
+            .map_err(|error| error.to_string()),
+        None => Ok(fts_query),
+    }
+}
+
+/// Runs the query of a materialize source with the same defaults as the search
+/// commands and collects every result batch.
+async fn materialize_source_batches(
+    table: &Table,
+    source: MaterializeSourceV1,
+) -> Result<Vec<RecordBatch>, String> {
+    match source {
+        MaterializeSourceV1::Filter(request) => {
+            let options = QueryOptions {
+                projection: request.projection,
+                filter: Some(request.filter),
+                limit: request.limit,
+                offset: request.offset,
+            };
+            execute_query_batches(apply_query_options(table.query(), &options)).await
+        }
+        MaterializeSourceV1::VectorSearch(request) => {
+            let mut query = table
+                .query()
+                .nearest_to(request.vector)
+                .map_err(|error| error.to_string())?;
+            if let Some(column) = request.column.as_deref() {
+                query = query.column(column);
+            }
+            let params = resolve_vector_search_params(
+                table,
+                request.column.as_deref(),
+                request.nprobes,
+                request.refine_factor,
+            )
+            .await;
+            if let Some(nprobes) = params.nprobes {
+                query = query.nprobes(nprobes);
+            }
+            if let Some(refine_factor) = params.refine_factor {
+                query = query.refine_factor(refine_factor);
+            }
+            if let Some(distance_type) = request.distance_type.as_ref() {
+                query = query.distance_type(to_lancedb_distance_type(distance_type));
+            }
+            let options = QueryOptions {
+                projection: request.projection,
+                filter: request.filter,
+                limit: Some(request.top_k.unwrap_or(10)),
+                offset: request.offset,
+            };
+            execute_query_batches(apply_query_options(query, &options)).await
+        }
+        MaterializeSourceV1::FtsSearch(request) => {
+            let fts_query = full_text_query(request.query, request.columns)?;
+            let options = QueryOptions {
+                projection: request.projection,
+                filter: request.filter,
+                limit: Some(request.limit.unwrap_or(100)),
+                offset: request.offset,
+            };
+            execute_query_batches(apply_query_options(
+                table.query().full_text_search(fts_query),
+                &options,
+            ))
+            .await
+        }
+        MaterializeSourceV1::CombinedSearch(request) => {
+            let fts_query = full_text_query(request.query.unwrap_or_default(), request.columns)?;
+            let vector_column = request
+                .vector_column
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty());
+            let mut query = table
+                .query()
+                .nearest_to(request.vector.unwrap_or_default())
+                .map_err(|error| error.to_string())?;
+            if let Some(column) = vector_column {
+                query = query.column(column);
+            }
+            let params = resolve_vector_search_params(
+                table,
+                vector_column,
+                request.nprobes,
+                request.refine_factor,
+            )
+            .await;
+            if let Some(nprobes) = params.nprobes {
+                query = query.nprobes(nprobes);
+            }
+            if let Some(refine_factor) = params.refine_factor {
+                query = query.refine_factor(refine_factor);
+            }
+            let rrf = match request.reranker {
+                Some(RerankerV1::Rrf(config)) => config.k.map(RRFReranker::new).unwrap_or_default(),
+                _ => RRFReranker::default(),
+            };
+            let options = QueryOptions {
+                projection: request.projection,
+                filter: sanitize_filter(request.filter),
+                limit: Some(request.limit.unwrap_or(50)),
+                offset: request.offset,
+            };
+            execute_query_batches(apply_query_options(
+                query
+                    .full_text_search(fts_query)
+                    .rerank(Arc::new(rrf))
+                    .norm(NormalizeMethod::Rank),
+                &options,
+            ))
+            .await
+        }
+    }
+}
+
+async fn materialize_query(
+    state: &AppState,
+    mut request: MaterializeQueryRequestV1,
+) -> ResultEnvelope<MaterializeQueryResponseV1> {
+    let started_at = Instant::now();
+    let source_table_id = request.source.table_id().to_string();
+    info!(
+        "materialize_query_v1 start connection_id={} table_id={} target=\"{}\"",
+        request.connection_id, source_table_id, request.table_name
+    );
+    let (projection, preset) = request.source.projection_fields_mut();
+    if let Err(envelope) = resolve_projection_preset(
+        state,
+        &source_table_id,
+        projection,
+        preset,
+        "materialize_query_v1",
+    ) {
+        return envelope;
+    }
+
+    let table_name = request.table_name.trim().to_string();
+    if table_name.is_empty() {
+        return ResultEnvelope::err_message(
+            ErrorCode::InvalidArgument,
+            messages::message(MessageCodeV1::TableNameEmpty),
+        );
+    }
+    if let Err(error) = validate_materialize_source(&request.source) {
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+    }
+
+    let (connection, table) = match state.connections.lock() {
+        Ok(manager) => (
+            manager.get_connection(&request.connection_id),
+            manager.get_table(&source_table_id),
+        ),
+        Err(_) => {
+            error!("materialize_query_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    let Some(connection) = connection else {
+        warn!(
+            "materialize_query_v1 connection not found connection_id={}",
+            request.connection_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::ConnectionNotFound),
+        );
+    };
+
+    let Some(table) = table else {
+        warn!(
+            "materialize_query_v1 table not found table_id={}",
+            source_table_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::TableNotFound),
+        );
+    };
+
+    if table.name() == table_name {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            "cannot materialize a query into its own source table",
+        );
+    }
+
+    let table = match request.source.as_of_version() {
+        Some(version) => match open_table_version(&connection, table.name(), version).await {
+            Ok(table) => table,
+            Err(error) => {
+                warn!(
+                    "materialize_query_v1 failed to open version table_id={} version={} error={}",
+                    source_table_id, version, error
+                );
+                return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+            }
+        },
+        None => table,
+    };
+
+    let masking_rules = match table_masking_rules(state, &source_table_id) {
+        Ok(rules) => rules,
+        Err(message) => {
+            error!("materialize_query_v1 failed to read masking rules");
+            return ResultEnvelope::err_message(ErrorCode::Internal, message);
+        }
+    };
+    if let Some(projection) = request.source.projection() {
+        if let Err(error) = projection::check_masked_columns(projection, &masking_rules) {
+            return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+        }
+    }
+
+    let existing = match connection.table_names().execute().await {
+        Ok(names) => names.contains(&table_name),
+        Err(error) => {
+            error!("materialize_query_v1 failed to list tables error={}", error);
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    };
+    if existing && !request.overwrite {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            format!("table '{table_name}' already exists; set overwrite to true to replace it"),
+        );
+    }
+
+    let batches = match with_timeout(
+        app_config(state).query_timeout(),
+        materialize_source_batches(&table, request.source),
+    )
+    .await
+    .and_then(|batches| masking::mask_batches(batches, &masking_rules))
+    {
+        Ok(batches) => batches,
+        Err(error) => {
+            error!(
+                "materialize_query_v1 query failed table_id={} error={}",
+                source_table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    };
+    let schema = match batches.first() {
+        Some(batch) => batch.schema(),
+        None => match table.schema().await {
+            Ok(schema) => masking::mask_schema(schema.as_ref(), &masking_rules),
+            Err(error) => {
+                error!(
+                    "materialize_query_v1 failed to read schema table_id={} error={}",
+                    source_table_id, error
+                );
+                return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+            }
+        },
+    };
+    let rows = batches.iter().map(|batch| batch.num_rows()).sum::<usize>();
+
+    if existing {
+        if let Err(error) = connection.drop_table(&table_name, &[]).await {
+            error!(
+                "materialize_query_v1 failed to drop table=\"{}\" error={}",
+                table_name, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    }
+
+    let target = match connection
+        .create_empty_table(&table_name, schema.clone())
+        .execute()
+        .await
+    {
+        Ok(target) => target,
+        Err(error) => {
+            error!(
+                "materialize_query_v1 failed to create table=\"{}\" error={}",
+                table_name, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    };
+
+    if rows > 0 {
+        let batch_iter = RecordBatchIterator::new(batches.into_iter().map(Ok), schema);
+        if let Err(error) = target.add(batch_iter).execute().await {
+            error!(
+                "materialize_query_v1 failed to write rows table=\"{}\" error={}",
+                table_name, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    }
+
+    let table_id = match state.connections.lock() {
+        Ok(mut manager) => {
+            manager.insert_table(table_name.clone(), target, request.connection_id.clone())
+        }
+        Err(_) => {
+            error!("materialize_query_v1 failed to lock table manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    info!(
+        "materialize_query_v1 ok table_id={} name=\"{}\" rows={} elapsed_ms={}",
+        table_id,
+        table_name,
+        rows,
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(MaterializeQueryResponseV1 {
+        table_id,
+        name: table_name,
+        rows,
+    })
+}
+
+pub async fn compare_query_versions_v1(
+    state: &AppState,
+    request: CompareQueryVersionsRequestV1,
+) -> ResultEnvelope<CompareQueryVersionsResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("compare_query_versions_v1"),
+        compare_query_versions(state, request),
+    )
+    .await
+}
+
+/// Runs a materialize source on one table version and encodes the rows as JSON.
+async fn version_query_rows(
+    table: &Table,
+    source: MaterializeSourceV1,
+    column_keys: &encryption::ColumnKeys,
+    masking_rules: &[MaskingRuleV1],
+    json_options: &JsonOptionsV1,
+) -> Result<Vec<serde_json::Value>, String> {
+    let batches = materialize_source_batches(table, source).await?;
+    let batches = encryption::decrypt_batches(batches, column_keys)?;
+    let batches = masking::mask_batches(batches, masking_rules)?;
+    batches_to_json_rows_with(&batches, json_options).map(|(rows, _)| rows)
+}
+
+async fn compare_query_versions(
+    state: &AppState,
+    mut request: CompareQueryVersionsRequestV1,
+) -> ResultEnvelope<CompareQueryVersionsResponseV1> {
+    let started_at = Instant::now();
+    info!(
+        "compare_query_versions_v1 start table_id={} version_a={} version_b={}",
+        request.table_id, request.version_a, request.version_b
+    );
+    let source_table_id = request.query.table_id().to_string();
+    let (projection, preset) = request.query.projection_fields_mut();
+    if let Err(envelope) = resolve_projection_preset(
+        state,
+        &source_table_id,
+        projection,
+        preset,
+        "compare_query_versions_v1",
+    ) {
+        return envelope;
+    }
+
+    if let Err(error) = validate_materialize_source(&request.query) {
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+    }
+    let key_columns = request
+        .key_columns
+        .iter()
+        .map(|column| column.trim().to_string())
+        .collect::<Vec<_>>();
+    if key_columns.iter().any(String::is_empty) {
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, "key columns cannot be empty");
+    }
+    let max_rows = request
+        .max_rows
+        .unwrap_or(version_diff::DEFAULT_MAX_ROWS)
+        .min(version_diff::MAX_ROWS_LIMIT);
+
+    let table_a = match resolve_table_version(
+        state,
+        &request.table_id,
+        Some(request.version_a),
+        "compare_query_versions_v1",
+    )
+    .await
+    {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+    let table_b = match resolve_table_version(
+        state,
+        &request.table_id,
+        Some(request.version_b),
+        "compare_query_versions_v1",
+    )
+    .await
+    {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+
+    let masking_rules = match table_masking_rules(state, &request.table_id) {
+        Ok(rules) => rules,
+        Err(message) => {
+            error!("compare_query_versions_v1 failed to read masking rules");
+            return ResultEnvelope::err_message(ErrorCode::Internal, message);
+        }
+    };
+    if let Some(projection) = request.query.projection() {
+        if let Err(error) = projection::check_masked_columns(projection, &masking_rules) {
+            return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+        }
+    }
+    let column_keys = match table_column_keys(state, &request.table_id, false) {
+        Ok(keys) => keys,
+        Err(error) => {
+            error!("compare_query_versions_v1 failed to read encryption keys");
+            return ResultEnvelope::err_envelope(error);
+        }
+    };
+
+    let json_options = request.query.json_options().cloned().unwrap_or_default();
+    let timeout = app_config(state).query_timeout();
+    let rows = with_timeout(timeout, async {
+        let rows_a = version_query_rows(
+            &table_a,
+            request.query.clone(),
+            &column_keys,
+            &masking_rules,
+            &json_options,
+        )
+        .await?;
+        let rows_b = version_query_rows(
+            &table_b,
+            request.query,
+            &column_keys,
+            &masking_rules,
+            &json_options,
+        )
+        .await?;
+        Ok::<_, String>((rows_a, rows_b))
+    })
+    .await;
+    let (rows_a, rows_b) = match rows {
+        Ok(rows) => rows,
+        Err(error) => {
+            error!(
+                "compare_query_versions_v1 query failed table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    };
+
+    let diff = match version_diff::diff_rows(
+        version_diff::VersionRows {
+            version: request.version_a,
+            rows: rows_a,
+        },
+        version_diff::VersionRows {
+            version: request.version_b,
+            rows: rows_b,
+        },
+        &key_columns,
+        max_rows,
+    ) {
+        Ok(diff) => diff,
+        Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
+    };
+
+    info!(
+        "compare_query_versions_v1 ok table_id={} added={} removed={} changed={} elapsed_ms={}",
+        request.table_id,
+        diff.added_count,
+        diff.removed_count,
+        diff.changed_count,
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(diff)
+}
+
+async fn connect_uri(
+    uri: &str,
+    storage_options: Option<&HashMap<String, String>>,
+) -> Result<lancedb::Connection, String> {
+    let mut builder = lancedb::connect(uri);
+    if let Some(storage_options) = storage_options.filter(|options| !options.is_empty()) {
+        builder = builder.storage_options(
+            storage_options
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone())),
+        );
+    }
+    builder.execute().await.map_err(|error| error.to_string())
+}
+
+/// Records progress and fails once the job has been cancelled, which is how
+/// long-running copies notice a shutdown.
+fn update_job_progress(state: &AppState, job_id: &str, processed_rows: u64) -> Result<(), String> {
+    if let Ok(mut jobs) = state.jobs.lock() {
+        if !jobs.is_running(job_id) {
+            return Err(jobs
+                .get(job_id)
+                .and_then(|job| job.message)
+                .unwrap_or_else(|| "job cancelled".to_string()));
+        }
+        jobs.set_progress(job_id, processed_rows);
+    }
+    Ok(())
+}
+
+fn set_job_stage(state: &AppState, job_id: &str, stage: ProgressStageV1) {
+    if let Ok(mut jobs) = state.jobs.lock() {
+        jobs.set_stage(job_id, stage);
+    }
+}
+
+/// Moves the job the current command runs as, if any, to `stage`.
+fn report_stage(state: &AppState, stage: ProgressStageV1) {
+    if let Some(job_id) = jobs::current() {
+        set_job_stage(state, &job_id, stage);
+    }
+}
+
+/// Records row progress on the job the current command runs as, if any.
+fn report_rows(state: &AppState, processed_rows: u64, total_rows: u64) {
+    let Some(job_id) = jobs::current() else {
+        return;
+    };
+    if let Ok(mut jobs) = state.jobs.lock() {
+        jobs.set_total(&job_id, Some(total_rows));
+        jobs.set_progress(&job_id, processed_rows);
+    }
+}
+
+/// Records the outcome of a job and notifies the job webhook, if one is configured.
+fn finish_job(
+    state: &AppState,
+    job_id: &str,
+    output_path: Option<&str>,
+    result: Result<(), String>,
+) {
+    if let Ok(mut jobs) = state.jobs.lock() {
+        jobs.finish(job_id, result);
+    }
+    notifications::job_finished(state, job_id, output_path.map(str::to_string));
+}
+
+/// Registers the job of a command that reports its own progress. A `job_id` that
+/// belongs to a job still running is rejected.
+fn start_job<T>(
+    state: &AppState,
+    command: &str,
+    job_id: Option<String>,
+    kind: JobKindV1,
+) -> Result<String, ResultEnvelope<T>> {
+    let Ok(mut jobs) = state.jobs.lock() else {
+        error!("{} failed to lock job registry", command);
+        return Err(ResultEnvelope::err_message(
+            ErrorCode::Internal,
+            messages::message(MessageCodeV1::StateLockFailed),
+        ));
+    };
+    jobs.start(job_id, kind)
+        .map_err(|error| ResultEnvelope::err(ErrorCode::InvalidArgument, error))
+}
+
+/// Tracks a command that has no job of its own as one, so it is listed by `health_v1`
+/// while it runs and notifies the job webhook when it finishes. Cancelling the job
+/// drops `command` where it is waiting and returns a `cancelled` error.
+async fn run_as_job<T>(
+    state: &AppState,
+    job_id: Option<String>,
+    kind: JobKindV1,
+    output_path: Option<String>,
+    command: impl std::future::Future<Output = ResultEnvelope<T>>,
+) -> ResultEnvelope<T> {
+    let job = match state.jobs.lock() {
+        Ok(mut jobs) => match jobs.start(job_id, kind) {
+            Ok(job_id) => jobs.token(&job_id).map(|token| (job_id, token)),
+            Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
+        },
+        Err(_) => None,
+    };
+    let Some((job_id, token)) = job else {
+        return command.await;
+    };
+    let command = jobs::scope(job_id.clone(), command);
+    let envelope = match token.run_until_cancelled(command).await {
+        Some(envelope) => envelope,
+        None => {
+            info!("job cancelled job_id={}", job_id);
+            let message = state
+                .jobs
+                .lock()
+                .ok()
+                .and_then(|jobs| jobs.get(&job_id))
+                .and_then(|job| job.message)
+                .unwrap_or_else(|| "job cancelled".to_string());
+            ResultEnvelope::err(ErrorCode::Cancelled, message)
+        }
+    };
+    let result = match &envelope.error {
+        Some(error) => Err(error.message.clone()),
+        None => Ok(()),
+    };
+    finish_job(state, &job_id, output_path.as_deref(), result);
+    envelope
+}
+
+/// Runs a read query so `list_active_queries_v1` lists it and `kill_query_v1` can
+/// drop it mid-flight, e.g. a scan stuck on a slow object store.
+async fn run_as_query<T>(
+    state: &AppState,
+    command: &'static str,
+    table_id: &str,
+    summary: String,
+    query: impl std::future::Future<Output = ResultEnvelope<T>>,
+) -> ResultEnvelope<T> {
+    let started = state
+        .active_queries
+        .lock()
+        .ok()
+        .map(|mut queries| queries.start(command, table_id, &summary));
+    let Some((query_id, token)) = started else {
+        return query.await;
+    };
+    let envelope = match token.run_until_cancelled(query).await {
+        Some(envelope) => envelope,
+        None => {
+            info!("{} killed query_id={}", command, query_id);
+            ResultEnvelope::err(ErrorCode::Cancelled, "query killed")
+        }
+    };
+    if let Ok(mut queries) = state.active_queries.lock() {
+        queries.finish(&query_id);
+    }
+    envelope
+}
+
+pub async fn cancel_job_v1(
+    state: &AppState,
+    request: CancelJobRequestV1,
+) -> ResultEnvelope<JobStatusV1> {
+    middleware::run(
+        state,
+        CommandContext::admin("cancel_job_v1"),
+        cancel_job(state, request),
+    )
+    .await
+}
+
+async fn cancel_job(state: &AppState, request: CancelJobRequestV1) -> ResultEnvelope<JobStatusV1> {
+    let job = match state.jobs.lock() {
+        Ok(mut jobs) => {
+            if jobs.cancel(&request.job_id, "job cancelled") {
+                info!("cancel_job_v1 cancelled job_id={}", request.job_id);
+            }
+            jobs.get(&request.job_id)
+        }
+        Err(_) => {
+            error!("cancel_job_v1 failed to lock job registry");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    match job {
+        Some(job) => ResultEnvelope::ok(job),
+        None => ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::JobNotFound),
+        ),
+    }
+}
+
+pub async fn list_jobs_v1(
+    state: &AppState,
+    request: ListJobsRequestV1,
+) -> ResultEnvelope<ListJobsResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("list_jobs_v1"),
+        list_jobs(state, request),
+    )
+    .await
+}
+
+async fn list_jobs(
+    state: &AppState,
+    request: ListJobsRequestV1,
+) -> ResultEnvelope<ListJobsResponseV1> {
+    if request.limit == Some(0) {
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, "limit must be greater than 0");
+    }
+    let jobs = match state.jobs.lock() {
+        Ok(jobs) => jobs.list(request.state, request.kind, request.limit),
+        Err(_) => {
+            error!("list_jobs_v1 failed to lock job registry");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+    ResultEnvelope::ok(ListJobsResponseV1 { jobs })
+}
+
+pub async fn list_active_queries_v1(
+    state: &AppState,
+) -> ResultEnvelope<ListActiveQueriesResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("list_active_queries_v1"),
+        list_active_queries(state),
+    )
+    .await
+}
+
+async fn list_active_queries(state: &AppState) -> ResultEnvelope<ListActiveQueriesResponseV1> {
+    match state.active_queries.lock() {
+        Ok(queries) => ResultEnvelope::ok(ListActiveQueriesResponseV1 {
+            queries: queries.list(),
+        }),
+        Err(_) => {
+            error!("list_active_queries_v1 failed to lock query registry");
+            ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            )
+        }
+    }
+}
+
+pub async fn kill_query_v1(
+    state: &AppState,
+    request: KillQueryRequestV1,
+) -> ResultEnvelope<ActiveQueryV1> {
+    middleware::run(
+        state,
+        CommandContext::admin("kill_query_v1"),
+        kill_query(state, request),
+    )
+    .await
+}
+
+async fn kill_query(
+    state: &AppState,
+    request: KillQueryRequestV1,
+) -> ResultEnvelope<ActiveQueryV1> {
+    let killed = match state.active_queries.lock() {
+        Ok(mut queries) => queries.kill(&request.query_id),
+        Err(_) => {
+            error!("kill_query_v1 failed to lock query registry");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+    match killed {
+        Some(query) => {
+            info!(
+                "kill_query_v1 killed query_id={} command={} table_id={} elapsed_ms={}",
+                query.query_id, query.command, query.table_id, query.elapsed_ms
+            );
+            ResultEnvelope::ok(query)
+        }
+        None => ResultEnvelope::err(ErrorCode::NotFound, "query not found"),
+    }
+}
+
+pub async fn get_job_status_v1(
+    state: &AppState,
+    request: GetJobStatusRequestV1,
+) -> ResultEnvelope<JobStatusV1> {
+    middleware::run(
+        state,
+        CommandContext::read("get_job_status_v1"),
+        get_job_status(state, request),
+    )
+    .await
+}
+
+async fn get_job_status(
+    state: &AppState,
+    request: GetJobStatusRequestV1,
+) -> ResultEnvelope<JobStatusV1> {
+    let job = match state.jobs.lock() {
+        Ok(jobs) => jobs.get(&request.job_id),
+        Err(_) => {
+            error!("get_job_status_v1 failed to lock job registry");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    match job {
+        Some(job) => ResultEnvelope::ok(job),
+        None => ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::JobNotFound),
+        ),
+    }
+}
+
+struct BackupOutcome {
+    source_version: u64,
+    rows: u64,
+    versions: usize,
+}
+
+async fn run_table_backup(
+    state: &AppState,
+    job_id: &str,
+    table: &Table,
+    table_name: &str,
+    backup_table_name: &str,
+    request: &BackupTableRequestV1,
+) -> Result<BackupOutcome, String> {
+    let source_version = table.version().await.map_err(|error| error.to_string())?;
+    let versions: Vec<VersionInfoV1> = table
+        .list_versions()
+        .await
+        .map_err(|error| error.to_string())?
+        .into_iter()
+        .map(to_version_info)
+        .collect();
+    let total_rows = table
+        .count_rows(None)
+        .await
+        .map_err(|error| error.to_string())? as u64;
+    if let Ok(mut jobs) = state.jobs.lock() {
+        jobs.set_total(job_id, Some(total_rows));
+        jobs.set_stage(job_id, ProgressStageV1::Copying);
+    }
+
+    let destination = connect_uri(
+        request.destination_uri.trim(),
+        request.storage_options.as_ref(),
+    )
+    .await?;
+
+    let rows = match request.mode {
+        BackupModeV1::Shallow => {
+            destination
+                .clone_table(
+                    backup_table_name.to_string(),
+                    table.dataset_uri().to_string(),
+                )
+                .source_version(source_version)
+                .is_shallow(true)
+                .execute()
+                .await
+                .map_err(|error| error.to_string())?;
+            update_job_progress(state, job_id, total_rows)?;
+            total_rows
+        }
+        BackupModeV1::Full => {
+            let schema = table.schema().await.map_err(|error| error.to_string())?;
+            let backup_schema = backup::schema_with_backup_metadata(
+                schema.as_ref(),
+                &backup::BackupMetadata {
+                    source_table: Some(table_name.to_string()),
+                    source_version: Some(source_version),
+                    created_at_ms: Some(clock::now_unix_millis()),
+                    mode: Some(request.mode),
+                    versions: versions.clone(),
+                },
+            )?;
+            let target = destination
+                .create_empty_table(backup_table_name, backup_schema.clone())
+                .execute()
+                .await
+                .map_err(|error| error.to_string())?;
+            backup::copy_table_rows(table, &target, backup_schema, |rows| {
+                update_job_progress(state, job_id, rows)
+            })
+            .await?
+        }
+    };
+
+    Ok(BackupOutcome {
+        source_version,
+        rows,
+        versions: versions.len(),
+    })
+}
+
+pub async fn backup_table_v1(
+    state: &AppState,
+    request: BackupTableRequestV1,
+) -> ResultEnvelope<BackupTableResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::admin("backup_table_v1"),
+        backup_table(state, request),
+    )
+    .await
+}
+
+async fn backup_table(
+    state: &AppState,
+    request: BackupTableRequestV1,
+) -> ResultEnvelope<BackupTableResponseV1> {
+    let started_at = Instant::now();
+    info!(
+        "backup_table_v1 start table_id={} mode={:?} destination=\"{}\"",
+        request.table_id, request.mode, request.destination_uri
+    );
+
+    if request.destination_uri.trim().is_empty() {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            "destination uri cannot be empty",
+        );
+    }
+
+    let (table, table_name) = match state.connections.lock() {
+        Ok(manager) => (
+            manager.get_table(&request.table_id),
+            manager.get_table_name(&request.table_id),
+        ),
+        Err(_) => {
+            error!("backup_table_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    let (Some(table), Some(table_name)) = (table, table_name) else {
+        warn!(
+            "backup_table_v1 table not found table_id={}",
+            request.table_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::TableNotFound),
+        );
+    };
+
+    let backup_table_name = request
+        .backup_table_name
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .unwrap_or(table_name.as_str())
+        .to_string();
+
+    let job_id = match start_job(
+        state,
+        "backup_table_v1",
+        request.job_id.clone(),
+        JobKindV1::Backup,
+    ) {
+        Ok(job_id) => job_id,
+        Err(envelope) => return envelope,
+    };
+
+    let outcome = match run_table_backup(
+        state,
+        &job_id,
+        &table,
+        &table_name,
+        &backup_table_name,
+        &request,
+    )
+    .await
+    {
+        Ok(outcome) => outcome,
+        Err(error) => {
+            error!(
+                "backup_table_v1 failed table_id={} job_id={} error={}",
+                request.table_id, job_id, error
+            );
+            finish_job(
+                state,
+                &job_id,
+                Some(request.destination_uri.trim()),
+                Err(error.clone()),
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    };
+    finish_job(state, &job_id, Some(request.destination_uri.trim()), Ok(()));
+
+    info!(
+        "backup_table_v1 ok table_id={} job_id={} rows={} source_version={} elapsed_ms={}",
+        request.table_id,
+        job_id,
+        outcome.rows,
+        outcome.source_version,
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(BackupTableResponseV1 {
+        job_id,
+        destination_uri: request.destination_uri,
+        backup_table_name,
+        mode: request.mode,
+        source_version: outcome.source_version,
+        rows: outcome.rows,
+        versions: outcome.versions,
+    })
+}
+
+async fn run_backup_restore(
+    state: &AppState,
+    job_id: &str,
+    connection: &lancedb::Connection,
+    backup_table: &Table,
+    target_table_name: &str,
+    schema: SchemaRef,
+    overwrite: bool,
+) -> Result<(Table, u64), String> {
+    let total_rows = backup_table
+        .count_rows(None)
+        .await
+        .map_err(|error| error.to_string())? as u64;
+    if let Ok(mut jobs) = state.jobs.lock() {
+        jobs.set_total(job_id, Some(total_rows));
+        jobs.set_stage(job_id, ProgressStageV1::Copying);
+    }
+
+    if overwrite {
+        connection
+            .drop_table(target_table_name, &[])
+            .await
+            .map_err(|error| error.to_string())?;
+    }
+
+    let target = connection
+        .create_empty_table(target_table_name, schema.clone())
+        .execute()
+        .await
+        .map_err(|error| error.to_string())?;
+    let rows = backup::copy_table_rows(backup_table, &target, schema, |rows| {
+        update_job_progress(state, job_id, rows)
+    })
+    .await?;
+
+    Ok((target, rows))
+}
+
+pub async fn restore_backup_v1(
+    state: &AppState,
+    request: RestoreBackupRequestV1,
+) -> ResultEnvelope<RestoreBackupResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::write_connection("restore_backup_v1", &request.connection_id),
+        restore_backup(state, request),
+    )
+    .await
+}
+
+async fn restore_backup(
+    state: &AppState,
+    request: RestoreBackupRequestV1,
+) -> ResultEnvelope<RestoreBackupResponseV1> {
+    let started_at = Instant::now();
+    info!(
+        "restore_backup_v1 start connection_id={} backup=\"{}\" table=\"{}\"",
+        request.connection_id, request.backup_uri, request.backup_table_name
+    );
+
+    if request.backup_uri.trim().is_empty() {
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, "backup uri cannot be empty");
+    }
+    if request.backup_table_name.trim().is_empty() {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            "backup table name cannot be empty",
+        );
+    }
+
+    let connection = match state.connections.lock() {
+        Ok(manager) => manager.get_connection(&request.connection_id),
+        Err(_) => {
+            error!("restore_backup_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    let Some(connection) = connection else {
+        warn!(
+            "restore_backup_v1 connection not found connection_id={}",
+            request.connection_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::ConnectionNotFound),
+        );
+    };
+
+    let backup_connection =
+        match connect_uri(request.backup_uri.trim(), request.storage_options.as_ref()).await {
+            Ok(connection) => connection,
+            Err(error) => {
+                error!(
+                    "restore_backup_v1 failed to open backup location error={}",
+                    error
+                );
+                return ResultEnvelope::err(ErrorCode::Internal, error);
+            }
+        };
+
+    let backup_table = match backup_connection
+        .open_table(request.backup_table_name.trim())
+        .execute()
+        .await
+    {
+        Ok(table) => table,
+        Err(error) => {
+            warn!(
+                "restore_backup_v1 backup table not found table=\"{}\" error={}",
+                request.backup_table_name, error
+            );
+            return ResultEnvelope::err(ErrorCode::NotFound, error.to_string());
+        }
+    };
+
+    let (schema, metadata) = match backup_table.schema().await {
+        Ok(schema) => backup::split_backup_metadata(schema.as_ref()),
+        Err(error) => {
+            error!(
+                "restore_backup_v1 failed to read backup schema error={}",
+                error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    };
+
+    let target_table_name = request
+        .target_table_name
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .or(metadata.source_table.as_deref())
+        .unwrap_or(request.backup_table_name.trim())
+        .to_string();
+
+    let existing = match connection.table_names().execute().await {
+        Ok(names) => names.contains(&target_table_name),
+        Err(error) => {
+            error!("restore_backup_v1 failed to list tables error={}", error);
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    };
+    if existing && !request.overwrite {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            format!(
+                "table '{target_table_name}' already exists; set overwrite to true to replace it"
+            ),
+        );
+    }
+
+    let job_id = match start_job(
+        state,
+        "restore_backup_v1",
+        request.job_id.clone(),
+        JobKindV1::Restore,
+    ) {
+        Ok(job_id) => job_id,
+        Err(envelope) => return envelope,
+    };
+
+    let (table, rows) = match run_backup_restore(
+        state,
+        &job_id,
+        &connection,
+        &backup_table,
+        &target_table_name,
+        schema,
+        existing,
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(error) => {
+            error!(
+                "restore_backup_v1 failed job_id={} table=\"{}\" error={}",
+                job_id, target_table_name, error
+            );
+            finish_job(state, &job_id, Some(&target_table_name), Err(error.clone()));
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    };
+    finish_job(state, &job_id, Some(&target_table_name), Ok(()));
+
+    let table_id = match state.connections.lock() {
+        Ok(mut manager) => manager.insert_table(
+            target_table_name.clone(),
+            table,
+            request.connection_id.clone(),
+        ),
+        Err(_) => {
+            error!("restore_backup_v1 failed to lock table manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    info!(
+        "restore_backup_v1 ok job_id={} table_id={} table=\"{}\" rows={} elapsed_ms={}",
+        job_id,
+        table_id,
+        target_table_name,
+        rows,
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(RestoreBackupResponseV1 {
+        job_id,
+        table_id,
+        name: target_table_name,
+        rows,
+        mode: metadata.mode,
+        source_table: metadata.source_table,
+        source_version: metadata.source_version,
+        backed_up_at_ms: metadata.created_at_ms,
+        versions: metadata.versions,
+    })
+}
+
+/// Newest Lance data storage version `migrate_table_format_v1` targets by default.
+const LATEST_STORAGE_VERSION: &str = "2.1";
+const SUPPORTED_STORAGE_VERSIONS: [&str; 2] = ["2.0", "2.1"];
+/// LanceDB connection options applied to tables created through that connection.
+const NEW_TABLE_STORAGE_VERSION_OPTION: &str = "new_table_data_storage_version";
+const NEW_TABLE_STABLE_ROW_IDS_OPTION: &str = "new_table_enable_stable_row_ids";
+const NEW_TABLE_V2_MANIFEST_PATHS_OPTION: &str = "new_table_enable_v2_manifest_paths";
+
+fn validate_storage_version(version: &str) -> Result<(), String> {
+    if SUPPORTED_STORAGE_VERSIONS.contains(&version) {
+        Ok(())
+    } else {
+        Err(format!(
+            "unsupported storage version '{version}'; expected one of {}",
+            SUPPORTED_STORAGE_VERSIONS.join(", ")
+        ))
+    }
+}
+
+/// Connection options that make LanceDB create tables with `options`. Empty when the
+/// defaults apply and the regular connection can be used.
+fn table_creation_options(
+    options: &CreateTableOptionsV1,
+) -> Result<HashMap<String, String>, String> {
+    let mut values = HashMap::new();
+    if let Some(version) = options
+        .data_storage_version
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        validate_storage_version(version)?;
+        values.insert(
+            NEW_TABLE_STORAGE_VERSION_OPTION.to_string(),
+            version.to_string(),
+        );
+    }
+    if options.enable_stable_row_ids {
+        values.insert(
+            NEW_TABLE_STABLE_ROW_IDS_OPTION.to_string(),
+            "true".to_string(),
+        );
+    }
+    if options.enable_v2_manifest_paths {
+        values.insert(
+            NEW_TABLE_V2_MANIFEST_PATHS_OPTION.to_string(),
+            "true".to_string(),
+        );
+    }
+    Ok(values)
+}
+
+/// Parses `major.minor`; `legacy` is the pre-2.0 format written as `0.1`.
+fn parse_storage_version(version: &str) -> Option<(u32, u32)> {
+    let version = version.trim();
+    if version.eq_ignore_ascii_case("legacy") {
+        return Some((0, 1));
+    }
+    let (major, minor) = version.split_once('.')?;
+    Some((major.parse().ok()?, minor.parse().ok()?))
+}
+
+fn storage_version_is_older(current: &str, target: &str) -> bool {
+    match (
+        parse_storage_version(current),
+        parse_storage_version(target),
+    ) {
+        (Some(current), Some(target)) => current < target,
+        _ => false,
+    }
+}
+
+async fn read_table_format(table: &Table, table_id: &str) -> Result<TableFormatInfoV1, String> {
+    let native = table
+        .as_native()
+        .ok_or_else(|| "storage format is not available for remote tables".to_string())?;
+    let manifest = native.manifest().await.map_err(|error| error.to_string())?;
+    let storage_version = manifest.data_storage_format.version.clone();
+    Ok(TableFormatInfoV1 {
+        table_id: table_id.to_string(),
+        version: manifest.version,
+        file_format: manifest.data_storage_format.file_format.clone(),
+        upgrade_available: storage_version_is_older(&storage_version, LATEST_STORAGE_VERSION),
+        stable_row_ids: manifest.uses_stable_row_ids(),
+        storage_version,
+        latest_storage_version: LATEST_STORAGE_VERSION.to_string(),
+        writer_library: manifest
+            .writer_version
+            .as_ref()
+            .map(|writer| writer.library.clone()),
+        writer_version: manifest
+            .writer_version
+            .as_ref()
+            .map(|writer| writer.version.clone()),
+    })
+}
+
+pub async fn table_format_info_v1(
+    state: &AppState,
+    request: TableFormatInfoRequestV1,
+) -> ResultEnvelope<TableFormatInfoV1> {
+    middleware::run(
+        state,
+        CommandContext::read("table_format_info_v1"),
+        table_format_info(state, request),
+    )
+    .await
+}
+
+async fn table_format_info(
+    state: &AppState,
+    request: TableFormatInfoRequestV1,
+) -> ResultEnvelope<TableFormatInfoV1> {
+    let started_at = Instant::now();
+    info!("table_format_info_v1 start table_id={}", request.table_id);
+
+    let table = match resolve_table(state, &request.table_id, "table_format_info_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+
+    let info = match read_table_format(&table, &request.table_id).await {
+        Ok(info) => info,
+        Err(error) => {
+            error!(
+                "table_format_info_v1 failed table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    };
+
+    info!(
+        "table_format_info_v1 ok table_id={} storage_version={} elapsed_ms={}",
+        request.table_id,
+        info.storage_version,
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(info)
+}
+
+/// Directories of the lance dataset layout under the dataset root.
+const DATASET_DIRECTORIES: [(DatasetDirectoryKindV1, &str); 5] = [
+    (DatasetDirectoryKindV1::Data, "data"),
+    (DatasetDirectoryKindV1::Indices, "_indices"),
+    (DatasetDirectoryKindV1::Versions, "_versions"),
+    (DatasetDirectoryKindV1::Deletions, "_deletions"),
+    (DatasetDirectoryKindV1::Transactions, "_transactions"),
+];
+
+fn dataset_location(table: &Table, table_id: &str) -> TableLocationV1 {
+    let dataset_uri = table.dataset_uri().to_string();
+    let root = local_path(&dataset_uri);
+    let backend_kind = match root {
+        Some(_) => BackendKind::Local,
+        None => infer_backend_kind(&dataset_uri),
+    };
+    let directories = DATASET_DIRECTORIES
+        .into_iter()
+        .map(|(kind, name)| {
+            let path = root.as_ref().map(|root| root.join(name));
+            DatasetDirectoryV1 {
+                kind,
+                uri: format!("{}/{name}", dataset_uri.trim_end_matches('/')),
+                exists: path.as_ref().map(|path| path.is_dir()),
+                local_path: path.map(|path| path.to_string_lossy().to_string()),
+            }
+        })
+        .collect();
+    TableLocationV1 {
+        table_id: table_id.to_string(),
+        name: table.name().to_string(),
+        dataset_uri,
+        backend_kind,
+        local_path: root.map(|root| root.to_string_lossy().to_string()),
+        directories,
+    }
+}
+
+pub async fn table_location_v1(
+    state: &AppState,
+    request: TableLocationRequestV1,
+) -> ResultEnvelope<TableLocationV1> {
+    middleware::run(
+        state,
+        CommandContext::read("table_location_v1"),
+        table_location(state, request),
+    )
+    .await
+}
+
+async fn table_location(
+    state: &AppState,
+    request: TableLocationRequestV1,
+) -> ResultEnvelope<TableLocationV1> {
+    info!("table_location_v1 start table_id={}", request.table_id);
+
+    let table = match resolve_table(state, &request.table_id, "table_location_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+    let location = dataset_location(&table, &request.table_id);
+
+    info!(
+        "table_location_v1 ok table_id={} uri={}",
+        request.table_id, location.dataset_uri
+    );
+
+    ResultEnvelope::ok(location)
+}
+
+/// Lance keeps a single data storage version per dataset, so a migration copies the
+/// rows into a new table created with the target version instead of rewriting in place.
+pub async fn migrate_table_format_v1(
+    state: &AppState,
+    request: MigrateTableFormatRequestV1,
+) -> ResultEnvelope<MigrateTableFormatResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::write_table("migrate_table_format_v1", &request.table_id),
+        migrate_table_format(state, request),
+    )
+    .await
+}
+
+async fn migrate_table_format(
+    state: &AppState,
+    request: MigrateTableFormatRequestV1,
+) -> ResultEnvelope<MigrateTableFormatResponseV1> {
+    let started_at = Instant::now();
+    info!(
+        "migrate_table_format_v1 start table_id={} target_version={:?}",
+        request.table_id, request.target_version
+    );
+
+    let target_version = request
+        .target_version
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .unwrap_or(LATEST_STORAGE_VERSION)
+        .to_string();
+    if let Err(error) = validate_storage_version(&target_version) {
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+    }
+
+    let (table, table_name, connection_id, connection_uri, storage_options) =
+        match state.connections.lock() {
+            Ok(manager) => (
+                manager.get_table(&request.table_id),
+                manager.get_table_name(&request.table_id),
+                manager.get_table_connection_id(&request.table_id),
+                manager.get_table_connection_uri(&request.table_id),
+                manager
+                    .get_table_storage_options(&request.table_id)
+                    .unwrap_or_default(),
+            ),
+            Err(_) => {
+                error!("migrate_table_format_v1 failed to lock connection manager");
+                return ResultEnvelope::err_message(
+                    ErrorCode::Internal,
+                    messages::message(MessageCodeV1::StateLockFailed),
+                );
+            }
+        };
+
+    let (Some(table), Some(table_name), Some(connection_id), Some(connection_uri)) =
+        (table, table_name, connection_id, connection_uri)
+    else {
+        warn!(
+            "migrate_table_format_v1 table not found table_id={}",
+            request.table_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::TableNotFound),
+        );
+    };
+
+    let source_format = match read_table_format(&table, &request.table_id).await {
+        Ok(info) => info,
+        Err(error) => {
+            error!(
+                "migrate_table_format_v1 failed to read format table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    };
+    if !storage_version_is_older(&source_format.storage_version, &target_version) {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            format!(
+                "table already uses storage version {}",
+                source_format.storage_version
+            ),
+        );
+    }
+
+    let target_table_name = request
+        .target_table_name
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(ToOwned::to_owned)
+        .unwrap_or_else(|| format!("{table_name}_v{}", target_version.replace('.', "_")));
+    if target_table_name == table_name {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            "target table name must differ from the source table",
+        );
+    }
+
+    let mut target_options = storage_options;
+    target_options.insert(
+        NEW_TABLE_STORAGE_VERSION_OPTION.to_string(),
+        target_version.clone(),
+    );
+    let connection = match connect_uri(&connection_uri, Some(&target_options)).await {
+        Ok(connection) => connection,
+        Err(error) => {
+            error!(
+                "migrate_table_format_v1 failed to connect uri=\"{}\" error={}",
+                connection_uri, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    };
+
+    let existing = match connection.table_names().execute().await {
+        Ok(names) => names.contains(&target_table_name),
+        Err(error) => {
+            error!(
+                "migrate_table_format_v1 failed to list tables error={}",
+                error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    };
+    if existing && !request.overwrite {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            format!(
+                "table '{target_table_name}' already exists; set overwrite to true to replace it"
+            ),
+        );
+    }
+
+    let (schema, indexes_to_rebuild) = match (table.schema().await, table.list_indices().await) {
+        (Ok(schema), Ok(indexes)) => (
+            schema,
+            indexes
+                .into_iter()
+                .map(|index| index.name)
+                .collect::<Vec<_>>(),
+        ),
+        (Err(error), _) | (_, Err(error)) => {
+            error!(
+                "migrate_table_format_v1 failed to read table table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    };
+
+    let job_id = match start_job(
+        state,
+        "migrate_table_format_v1",
+        request.job_id.clone(),
+        JobKindV1::MigrateFormat,
+    ) {
+        Ok(job_id) => job_id,
+        Err(envelope) => return envelope,
+    };
+
+    let (target, rows) = match run_backup_restore(
+        state,
+        &job_id,
+        &connection,
+        &table,
+        &target_table_name,
+        schema,
+        existing,
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(error) => {
+            error!(
+                "migrate_table_format_v1 failed job_id={} table=\"{}\" error={}",
+                job_id, target_table_name, error
+            );
+            finish_job(state, &job_id, Some(&target_table_name), Err(error.clone()));
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    };
+    finish_job(state, &job_id, Some(&target_table_name), Ok(()));
+
+    let to_version = match read_table_format(&target, "").await {
+        Ok(info) => info.storage_version,
+        Err(error) => {
+            warn!(
+                "migrate_table_format_v1 failed to verify format table=\"{}\" error={}",
+                target_table_name, error
+            );
+            target_version
+        }
+    };
+
+    let table_id = match state.connections.lock() {
+        Ok(mut manager) => manager.insert_table(target_table_name.clone(), target, connection_id),
+        Err(_) => {
+            error!("migrate_table_format_v1 failed to lock table manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    info!(
+        "migrate_table_format_v1 ok job_id={} table_id={} from={} to={} rows={} elapsed_ms={}",
+        job_id,
+        table_id,
+        source_format.storage_version,
+        to_version,
+        rows,
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(MigrateTableFormatResponseV1 {
+        job_id,
+        table_id,
+        name: target_table_name,
+        rows,
+        from_version: source_format.storage_version,
+        to_version,
+        indexes_to_rebuild,
+    })
+}
+
+fn validate_schedule_action(action: &ScheduleActionV1) -> Result<(), String> {
+    match action {
+        ScheduleActionV1::Backup {
+            destination_uri, ..
+        } if destination_uri.trim().is_empty() => {
+            Err("backup destination uri cannot be empty".to_string())
+        }
+        ScheduleActionV1::Export { path, .. } if path.trim().is_empty() => {
+            Err("export path cannot be empty".to_string())
+        }
+        _ => Ok(()),
+    }
+}
+
+pub async fn list_schedules_v1(
+    state: &AppState,
+    request: ListSchedulesRequestV1,
+) -> ResultEnvelope<ListSchedulesResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("list_schedules_v1"),
+        list_schedules(state, request),
+    )
+    .await
+}
+
+async fn list_schedules(
+    state: &AppState,
+    request: ListSchedulesRequestV1,
+) -> ResultEnvelope<ListSchedulesResponseV1> {
+    let connection_uri = match request.connection_id.as_deref() {
+        Some(connection_id) => {
+            let connection = match state.connections.lock() {
+                Ok(manager) => manager.get_connection(connection_id),
+                Err(_) => {
+                    error!("list_schedules_v1 failed to lock connection manager");
+                    return ResultEnvelope::err_message(
+                        ErrorCode::Internal,
+                        messages::message(MessageCodeV1::StateLockFailed),
+                    );
+                }
+            };
+            let Some(connection) = connection else {
+                warn!(
+                    "list_schedules_v1 connection not found connection_id={}",
+                    connection_id
+                );
+                return ResultEnvelope::err_message(
+                    ErrorCode::NotFound,
+                    messages::message(MessageCodeV1::ConnectionNotFound),
+                );
+            };
+            Some(connection.uri().to_string())
+        }
+        None => None,
+    };
+
+    let schedules = match state.schedules.lock() {
+        Ok(schedules) => schedules.list(connection_uri.as_deref()),
+        Err(_) => {
+            error!("list_schedules_v1 failed to lock schedule store");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    ResultEnvelope::ok(ListSchedulesResponseV1 { schedules })
+}
+
+pub async fn set_schedule_v1(
+    state: &AppState,
+    request: SetScheduleRequestV1,
+) -> ResultEnvelope<SetScheduleResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::settings("set_schedule_v1"),
+        set_schedule(state, request),
+    )
+    .await
+}
+
+async fn set_schedule(
+    state: &AppState,
+    request: SetScheduleRequestV1,
+) -> ResultEnvelope<SetScheduleResponseV1> {
+    info!(
+        "set_schedule_v1 start connection_id={} table=\"{}\" cron=\"{}\"",
+        request.connection_id, request.table_name, request.cron
+    );
+
+    let table_name = request.table_name.trim();
+    if table_name.is_empty() {
+        return ResultEnvelope::err_message(
+            ErrorCode::InvalidArgument,
+            messages::message(MessageCodeV1::TableNameEmpty),
+        );
+    }
+    if let Err(error) = CronExpr::parse(&request.cron) {
+        warn!("set_schedule_v1 invalid cron error={}", error);
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+    }
+    if let Err(error) = validate_schedule_action(&request.action) {
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+    }
+
+    let connection = match state.connections.lock() {
+        Ok(manager) => manager.get_connection(&request.connection_id),
+        Err(_) => {
+            error!("set_schedule_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    let Some(connection) = connection else {
+        warn!(
+            "set_schedule_v1 connection not found connection_id={}",
+            request.connection_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::ConnectionNotFound),
+        );
+    };
+
+    let id = request
+        .id
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+    let result = match state.schedules.lock() {
+        Ok(mut schedules) => {
+            let previous = schedules.get(&id);
+            let schedule = ScheduleV1 {
+                id: id.clone(),
+                connection_uri: connection.uri().to_string(),
+                table_name: table_name.to_string(),
+                cron: request.cron.trim().to_string(),
+                action: request.action,
+                enabled: request.enabled.unwrap_or(true),
+                last_run_at_ms: previous.as_ref().and_then(|item| item.last_run_at_ms),
+                last_status: previous.as_ref().and_then(|item| item.last_status),
+                last_message: previous.and_then(|item| item.last_message),
+            };
+            schedules.upsert(schedule.clone()).map(|_| schedule)
+        }
+        Err(_) => {
+            error!("set_schedule_v1 failed to lock schedule store");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    match result {
+        Ok(schedule) => {
+            info!("set_schedule_v1 ok id={}", schedule.id);
+            ResultEnvelope::ok(SetScheduleResponseV1 { schedule })
+        }
+        Err(error) => {
+            error!("set_schedule_v1 failed id={} error={}", id, error);
+            ResultEnvelope::err(ErrorCode::Internal, error)
+        }
+    }
+}
+
+pub async fn delete_schedule_v1(
+    state: &AppState,
+    request: DeleteScheduleRequestV1,
+) -> ResultEnvelope<DeleteScheduleResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::settings("delete_schedule_v1"),
+        delete_schedule(state, request),
+    )
+    .await
+}
+
+async fn delete_schedule(
+    state: &AppState,
+    request: DeleteScheduleRequestV1,
+) -> ResultEnvelope<DeleteScheduleResponseV1> {
+    info!("delete_schedule_v1 start id={}", request.id);
+
+    let removed = match state.schedules.lock() {
+        Ok(mut schedules) => schedules.remove(&request.id),
+        Err(_) => {
+            error!("delete_schedule_v1 failed to lock schedule store");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    match removed {
+        Ok(true) => ResultEnvelope::ok(DeleteScheduleResponseV1 { id: request.id }),
+        Ok(false) => ResultEnvelope::err(ErrorCode::NotFound, "schedule not found"),
+        Err(error) => {
+            error!(
+                "delete_schedule_v1 failed id={} error={}",
+                request.id, error
+            );
+            ResultEnvelope::err(ErrorCode::Internal, error)
+        }
+    }
+}
+
+pub async fn list_watch_folders_v1(
+    state: &AppState,
+    request: ListWatchFoldersRequestV1,
+) -> ResultEnvelope<ListWatchFoldersResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("list_watch_folders_v1"),
+        list_watch_folders(state, request),
+    )
+    .await
+}
+
+async fn list_watch_folders(
+    state: &AppState,
+    request: ListWatchFoldersRequestV1,
+) -> ResultEnvelope<ListWatchFoldersResponseV1> {
+    let connection_uri = match request.connection_id.as_deref() {
+        Some(connection_id) => {
+            let connection = match state.connections.lock() {
+                Ok(manager) => manager.get_connection(connection_id),
+                Err(_) => {
+                    error!("list_watch_folders_v1 failed to lock connection manager");
+                    return ResultEnvelope::err_message(
+                        ErrorCode::Internal,
+                        messages::message(MessageCodeV1::StateLockFailed),
+                    );
+                }
+            };
+            let Some(connection) = connection else {
+                warn!(
+                    "list_watch_folders_v1 connection not found connection_id={}",
+                    connection_id
+                );
+                return ResultEnvelope::err_message(
+                    ErrorCode::NotFound,
+                    messages::message(MessageCodeV1::ConnectionNotFound),
+                );
+            };
+            Some(connection.uri().to_string())
+        }
+        None => None,
+    };
+
+    let watch_folders = match state.watch_folders.lock() {
+        Ok(folders) => folders.list(connection_uri.as_deref()),
+        Err(_) => {
+            error!("list_watch_folders_v1 failed to lock watch folder store");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    ResultEnvelope::ok(ListWatchFoldersResponseV1 { watch_folders })
+}
+
+pub async fn set_watch_folder_v1(
+    state: &AppState,
+    request: SetWatchFolderRequestV1,
+) -> ResultEnvelope<SetWatchFolderResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::settings("set_watch_folder_v1"),
+        set_watch_folder(state, request),
+    )
+    .await
+}
+
+async fn set_watch_folder(
+    state: &AppState,
+    request: SetWatchFolderRequestV1,
+) -> ResultEnvelope<SetWatchFolderResponseV1> {
+    info!(
+        "set_watch_folder_v1 start connection_id={} table=\"{}\" directory=\"{}\"",
+        request.connection_id, request.table_name, request.directory
+    );
+
+    let table_name = request.table_name.trim();
+    if table_name.is_empty() {
+        return ResultEnvelope::err_message(
+            ErrorCode::InvalidArgument,
+            messages::message(MessageCodeV1::TableNameEmpty),
+        );
+    }
+    let directory = request.directory.trim();
+    if directory.is_empty() {
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, "directory cannot be empty");
+    }
+    if !Path::new(directory).is_dir() {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            format!("directory {directory} does not exist"),
+        );
+    }
+    if let Err(error) = watch_folders::validate_format(&request.format) {
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+    }
+
+    let connection = match state.connections.lock() {
+        Ok(manager) => manager.get_connection(&request.connection_id),
+        Err(_) => {
+            error!("set_watch_folder_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+    let Some(connection) = connection else {
+        warn!(
+            "set_watch_folder_v1 connection not found connection_id={}",
+            request.connection_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::ConnectionNotFound),
+        );
+    };
+
+    let id = request
+        .id
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    let existing_files = if request.include_existing {
+        Vec::new()
+    } else {
+        match watch_folders::matching_files(Path::new(directory), &request.format, None) {
+            Ok(files) => files,
+            Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
+        }
+    };
+
+    let result = match state.watch_folders.lock() {
+        Ok(mut folders) => {
+            let previous = folders.get(&id);
+            let mut seen_files = previous
+                .as_ref()
+                .map(|item| item.seen_files.clone())
+                .unwrap_or_default();
+            for file in existing_files {
+                if !seen_files.contains(&file) {
+                    seen_files.push(file);
+                }
+            }
+            let folder = WatchFolderV1 {
+                id: id.clone(),
+                connection_uri: connection.uri().to_string(),
+                table_name: table_name.to_string(),
+                directory: directory.to_string(),
+                format: request.format,
+                has_header: request.has_header,
+                delimiter: request.delimiter,
+                lineage: request.lineage,
+                enabled: request.enabled.unwrap_or(true),
+                seen_files,
+                last_ingested_at_ms: previous.as_ref().and_then(|item| item.last_ingested_at_ms),
+                last_error: previous.and_then(|item| item.last_error),
+            };
+            folders.upsert(folder.clone()).map(|_| folder)
+        }
+        Err(_) => {
+            error!("set_watch_folder_v1 failed to lock watch folder store");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    match result {
+        Ok(watch_folder) => {
+            info!(
+                "set_watch_folder_v1 ok id={} seen_files={}",
+                watch_folder.id,
+                watch_folder.seen_files.len()
+            );
+            ResultEnvelope::ok(SetWatchFolderResponseV1 { watch_folder })
+        }
+        Err(error) => {
+            error!("set_watch_folder_v1 failed id={} error={}", id, error);
+            ResultEnvelope::err(ErrorCode::Internal, error)
+        }
+    }
+}
+
+pub async fn delete_watch_folder_v1(
+    state: &AppState,
+    request: DeleteWatchFolderRequestV1,
+) -> ResultEnvelope<DeleteWatchFolderResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::settings("delete_watch_folder_v1"),
+        delete_watch_folder(state, request),
+    )
+    .await
+}
+
+async fn delete_watch_folder(
+    state: &AppState,
+    request: DeleteWatchFolderRequestV1,
+) -> ResultEnvelope<DeleteWatchFolderResponseV1> {
+    info!("delete_watch_folder_v1 start id={}", request.id);
+
+    let removed = match state.watch_folders.lock() {
+        Ok(mut folders) => folders.remove(&request.id),
+        Err(_) => {
+            error!("delete_watch_folder_v1 failed to lock watch folder store");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    match removed {
+        Ok(true) => ResultEnvelope::ok(DeleteWatchFolderResponseV1 { id: request.id }),
+        Ok(false) => ResultEnvelope::err(ErrorCode::NotFound, "watch folder not found"),
+        Err(error) => {
+            error!(
+                "delete_watch_folder_v1 failed id={} error={}",
+                request.id, error
+            );
+            ResultEnvelope::err(ErrorCode::Internal, error)
+        }
+    }
+}
+
+pub async fn health_v1(state: &AppState) -> ResultEnvelope<HealthResponseV1> {
+    middleware::run(state, CommandContext::read("health_v1"), health(state)).await
+}
+
+async fn health(state: &AppState) -> ResultEnvelope<HealthResponseV1> {
+    let (active_connections, open_tables) = match state.connections.lock() {
+        Ok(manager) => (manager.connection_count(), manager.table_count()),
+        Err(_) => {
+            error!("health_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+    let (running_jobs, tracked_jobs) = match state.jobs.lock() {
+        Ok(jobs) => (jobs.running(), jobs.job_count()),
+        Err(_) => {
+            error!("health_v1 failed to lock job registry");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+    let schedules = match state.schedules.lock() {
+        Ok(store) => store.list(None).len(),
+        Err(_) => {
+            error!("health_v1 failed to lock schedule store");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+    let prefetched_pages = match state.prefetch.lock() {
+        Ok(cache) => cache.page_count(),
+        Err(_) => {
+            error!("health_v1 failed to lock prefetch cache");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    let warm_tables = match state.warm_scans.lock() {
+        Ok(cache) => cache.table_count(),
+        Err(_) => {
+            error!("health_v1 failed to lock warm scan cache");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    let response = HealthResponseV1 {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        uptime_ms: state.started_at.elapsed().as_millis() as u64,
+        active_connections,
+        open_tables,
+        running_jobs,
+        tracked_jobs,
+        schedules,
+        prefetched_pages,
+        warm_tables,
+        resident_memory_bytes: health::resident_memory_bytes(),
+    };
+    debug!(
+        "health_v1 connections={} tables={} running_jobs={} rss={:?}",
+        response.active_connections,
+        response.open_tables,
+        response.running_jobs.len(),
+        response.resident_memory_bytes
+    );
+
+    ResultEnvelope::ok(response)
+}
+
+pub async fn export_diagnostics_v1(
+    state: &AppState,
+    request: ExportDiagnosticsRequestV1,
+) -> ResultEnvelope<ExportDiagnosticsResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("export_diagnostics_v1"),
+        export_diagnostics(state, request),
+    )
+    .await
+}
+
+/// Open connections with their URIs redacted and storage option values dropped.
+fn diagnostics_connections(manager: &ConnectionManager) -> Vec<DiagnosticsConnectionV1> {
+    let mut connections = manager
+        .connection_ids()
+        .into_iter()
+        .filter_map(|connection_id| {
+            let connection = manager.get_connection(&connection_id)?;
+            let mut storage_option_keys = manager
+                .get_storage_options(&connection_id)
+                .unwrap_or_default()
+                .into_keys()
+                .collect::<Vec<_>>();
+            storage_option_keys.sort();
+            Some(DiagnosticsConnectionV1 {
+                uri: diagnostics::redact_uri(connection.uri()),
+                read_only: manager.is_read_only(&connection_id),
+                open_tables: manager.connection_table_count(&connection_id),
+                storage_option_keys,
+                connection_id,
+            })
+        })
+        .collect::<Vec<_>>();
+    connections.sort_by(|left, right| left.connection_id.cmp(&right.connection_id));
+    connections
+}
+
+/// Snapshot of the app for `export_diagnostics_v1`. `None` when a lock is poisoned.
+fn diagnostics_report(state: &AppState, logs: Vec<String>) -> Option<DiagnosticsReportV1> {
+    Some(DiagnosticsReportV1 {
+        created_at_ms: clock::now_unix_millis(),
+        viewer_version: env!("CARGO_PKG_VERSION").to_string(),
+        tauri_version: tauri::VERSION.to_string(),
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        uptime_ms: state.started_at.elapsed().as_millis() as u64,
+        resident_memory_bytes: health::resident_memory_bytes(),
+        log_settings: state.log_settings.lock().ok()?.get(),
+        commands: state.command_metrics.lock().ok()?.list(),
+        command_memory: state.memory_stats.lock().ok()?.list(),
+        connections: diagnostics_connections(&state.connections.lock().ok()?),
+        running_jobs: state.jobs.lock().ok()?.running(),
+        logs,
+    })
+}
+
+async fn export_diagnostics(
+    state: &AppState,
+    request: ExportDiagnosticsRequestV1,
+) -> ResultEnvelope<ExportDiagnosticsResponseV1> {
+    let path = request.path.trim().to_string();
+    info!("export_diagnostics_v1 start path=\"{}\"", path);
+    if path.is_empty() {
+        return ResultEnvelope::err_message(
+            ErrorCode::InvalidArgument,
+            messages::message(MessageCodeV1::PathEmpty),
+        );
+    }
+    let log_lines = request.log_lines.unwrap_or(diagnostics::DEFAULT_LOG_LINES);
+    if log_lines > diagnostics::MAX_LOG_LINES {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            format!("logLines cannot exceed {}", diagnostics::MAX_LOG_LINES),
+        );
+    }
+    if Path::new(&path).exists() && !request.overwrite {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            "file already exists at path; set overwrite to true to replace it",
+        );
+    }
+
+    let logs = match state.log_dir.as_deref() {
+        Some(log_dir) => match diagnostics::tail_log(log_dir, log_lines) {
+            Ok(logs) => logs,
+            Err(error) => {
+                warn!("export_diagnostics_v1 failed to read logs error={}", error);
+                vec![format!("failed to read logs: {error}")]
+            }
+        },
+        None => Vec::new(),
+    };
+    let Some(report) = diagnostics_report(state, logs) else {
+        error!("export_diagnostics_v1 failed to lock app state");
+        return ResultEnvelope::err_message(
+            ErrorCode::Internal,
+            messages::message(MessageCodeV1::StateLockFailed),
+        );
+    };
+    let written = bundle::write_json_file(Path::new(&path), &report).and_then(|_| {
+        std::fs::metadata(&path)
+            .map(|metadata| metadata.len())
+            .map_err(|error| error.to_string())
+    });
+    let bytes = match written {
+        Ok(bytes) => bytes,
+        Err(error) => {
+            error!(
+                "export_diagnostics_v1 failed to write report error={}",
+                error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    };
+
+    info!(
+        "export_diagnostics_v1 ok path=\"{}\" bytes={} log_lines={}",
+        path,
+        bytes,
+        report.logs.len()
+    );
+    ResultEnvelope::ok(ExportDiagnosticsResponseV1 {
+        path,
+        bytes,
+        log_lines: report.logs.len(),
+        commands: report.commands.len(),
+        connections: report.connections.len(),
+    })
+}
+
+pub async fn get_access_log_v1(
+    state: &AppState,
+    request: GetAccessLogRequestV1,
+) -> ResultEnvelope<GetAccessLogResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("get_access_log_v1"),
+        get_access_log(state, request),
+    )
+    .await
+}
+
+async fn get_access_log(
+    state: &AppState,
+    request: GetAccessLogRequestV1,
+) -> ResultEnvelope<GetAccessLogResponseV1> {
+    if request
+        .limit
+        .is_some_and(|limit| limit == 0 || limit > access_log::MAX_ENTRIES)
+    {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            format!("limit must be between 1 and {}", access_log::MAX_ENTRIES),
+        );
+    }
+    match state.access_log.lock() {
+        Ok(log) => ResultEnvelope::ok(GetAccessLogResponseV1 {
+            entries: log.list(&request),
+        }),
+        Err(_) => {
+            error!("get_access_log_v1 failed to lock access log");
+            ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            )
+        }
+    }
+}
+
+/// Budget of each connection in `overview_v1` when neither the request nor the
+/// config sets one.
+const DEFAULT_OVERVIEW_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub async fn overview_v1(
+    state: &AppState,
+    request: OverviewRequestV1,
+) -> ResultEnvelope<OverviewResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("overview_v1"),
+        overview(state, request),
+    )
+    .await
+}
+
+struct ConnectionSummary {
+    table_count: usize,
+    total_rows: u64,
+    total_size_bytes: u64,
+    failed_tables: Vec<String>,
+}
+
+async fn summarize_connection(
+    backend: &dyn DatastoreBackend,
+    row_counts: &Mutex<RowCountCache>,
+) -> Result<ConnectionSummary, BackendError> {
+    let tables = list_table_infos(backend, HashMap::new(), true, row_counts).await?;
+    let mut summary = ConnectionSummary {
+        table_count: tables.len(),
+        total_rows: 0,
+        total_size_bytes: 0,
+        failed_tables: Vec::new(),
+    };
+    for table in tables {
+        match (table.row_count, table.size_bytes) {
+            (Some(row_count), Some(size_bytes)) => {
+                summary.total_rows += row_count;
+                summary.total_size_bytes += size_bytes;
+            }
+            _ => summary.failed_tables.push(table.name),
+        }
+    }
+    Ok(summary)
+}
+
+async fn connection_overview(
+    state: &AppState,
+    connection_id: String,
+    connection: lancedb::Connection,
+    read_only: bool,
+    open_tables: usize,
+    timeout: Duration,
+) -> ConnectionOverviewV1 {
+    let started_at = Instant::now();
+    let uri = connection.uri().to_string();
+    let backend = LanceBackend::new(connection);
+    let summary =
+        tokio::time::timeout(timeout, summarize_connection(&backend, &state.row_counts)).await;
+    let mut overview = ConnectionOverviewV1 {
+        connection_id,
+        uri,
+        read_only,
+        health: ConnectionHealthV1::Ok,
+        open_tables,
+        table_count: None,
+        total_rows: None,
+        total_size_bytes: None,
+        failed_tables: Vec::new(),
+        elapsed_ms: 0,
+        error: None,
+    };
+    match summary {
+        Ok(Ok(summary)) => {
+            if !summary.failed_tables.is_empty() {
+                overview.health = ConnectionHealthV1::Degraded;
+            }
+            overview.table_count = Some(summary.table_count);
+            overview.total_rows = Some(summary.total_rows);
+            overview.total_size_bytes = Some(summary.total_size_bytes);
+            overview.failed_tables = summary.failed_tables;
+        }
+        Ok(Err(error)) => {
+            warn!(
+                "overview_v1 connection failed connection_id={} error={}",
+                overview.connection_id, error
+            );
+            overview.health = ConnectionHealthV1::Error;
+            overview.error = Some(error.to_string());
+        }
+        Err(_) => {
+            warn!(
+                "overview_v1 connection timed out connection_id={} timeout_ms={}",
+                overview.connection_id,
+                timeout.as_millis()
+            );
+            overview.health = ConnectionHealthV1::Timeout;
+            overview.error = Some(format!("timed out after {} ms", timeout.as_millis()));
+        }
+    }
+    overview.elapsed_ms = started_at.elapsed().as_millis() as u64;
+    overview
+}
+
+async fn overview(
+    state: &AppState,
+    request: OverviewRequestV1,
+) -> ResultEnvelope<OverviewResponseV1> {
+    let started_at = Instant::now();
+    if request.timeout_ms == Some(0) {
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, "timeout_ms must be positive");
+    }
+    let timeout = request
+        .timeout_ms
+        .map(Duration::from_millis)
+        .or_else(|| app_config(state).query_timeout())
+        .unwrap_or(DEFAULT_OVERVIEW_TIMEOUT);
+
+    let connections: Vec<_> = match state.connections.lock() {
+        Ok(manager) => manager
+            .connection_ids()
+            .into_iter()
+            .filter_map(|connection_id| {
+                let connection = manager.get_connection(&connection_id)?;
+                let read_only = manager.is_read_only(&connection_id);
+                let open_tables = manager.connection_table_count(&connection_id);
+                Some((connection_id, connection, read_only, open_tables))
+            })
+            .collect(),
+        Err(_) => {
+            error!("overview_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+    info!(
+        "overview_v1 start connections={} timeout_ms={}",
+        connections.len(),
+        timeout.as_millis()
+    );
+
+    let mut overviews = futures_util::future::join_all(connections.into_iter().map(
+        |(connection_id, connection, read_only, open_tables)| {
+            connection_overview(
+                state,
+                connection_id,
+                connection,
+                read_only,
+                open_tables,
+                timeout,
+            )
+        },
+    ))
+    .await;
+    overviews.sort_by(|left, right| left.uri.cmp(&right.uri));
+
+    let elapsed_ms = started_at.elapsed().as_millis() as u64;
+    info!(
+        "overview_v1 ok connections={} elapsed_ms={}",
+        overviews.len(),
+        elapsed_ms
+    );
+    ResultEnvelope::ok(OverviewResponseV1 {
+        connections: overviews,
+        elapsed_ms,
+    })
+}
+
+pub async fn set_log_level_v1(
+    state: &AppState,
+    request: SetLogLevelRequestV1,
+) -> ResultEnvelope<SetLogLevelResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::settings("set_log_level_v1"),
+        set_log_level(state, request),
+    )
+    .await
+}
+
+async fn set_log_level(
+    state: &AppState,
+    request: SetLogLevelRequestV1,
+) -> ResultEnvelope<SetLogLevelResponseV1> {
+    info!(
+        "set_log_level_v1 start level={:?} module_filter={:?}",
+        request.level, request.module_filter
+    );
+
+    let modules = log_control::parse_module_filter(request.module_filter.as_deref());
+    let settings = LogSettingsV1 {
+        level: request.level,
+        module_filter: if modules.is_empty() {
+            None
+        } else {
+            Some(modules.join(","))
+        },
+    };
+
+    let saved = match state.log_settings.lock() {
+        Ok(mut store) => store.set(settings.clone()),
+        Err(_) => {
+            error!("set_log_level_v1 failed to lock log settings");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+    if let Err(error) = saved {
+        error!(
+            "set_log_level_v1 failed to persist settings error={}",
+            error
+        );
+        return ResultEnvelope::err(ErrorCode::Internal, error);
+    }
+
+    log_control::apply(&settings);
+    info!(
+        "set_log_level_v1 ok level={:?} module_filter={:?}",
+        settings.level, settings.module_filter
+    );
+
+    ResultEnvelope::ok(SetLogLevelResponseV1 { settings })
+}
+
+fn config_response(state: &AppState) -> Result<ConfigResponseV1, MessageV1> {
+    let store = state
+        .config
+        .lock()
+        .map_err(|_| messages::message(MessageCodeV1::StateLockFailed))?;
+    Ok(ConfigResponseV1 {
+        config: store.get(),
+        path: store.path().map(|path| path.display().to_string()),
+    })
+}
+
+pub async fn get_config_v1(state: &AppState) -> ResultEnvelope<ConfigResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("get_config_v1"),
+        get_config(state),
+    )
+    .await
+}
+
+async fn get_config(state: &AppState) -> ResultEnvelope<ConfigResponseV1> {
+    match config_response(state) {
+        Ok(response) => ResultEnvelope::ok(response),
+        Err(message) => {
+            error!("get_config_v1 failed to lock config");
+            ResultEnvelope::err_message(ErrorCode::Internal, message)
+        }
+    }
+}
+
+pub async fn set_config_v1(
+    state: &AppState,
+    request: SetConfigRequestV1,
+) -> ResultEnvelope<ConfigResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::settings("set_config_v1"),
+        set_config(state, request),
+    )
+    .await
+}
+
+async fn set_config(
+    state: &AppState,
+    request: SetConfigRequestV1,
+) -> ResultEnvelope<ConfigResponseV1> {
+    info!("set_config_v1 start config={:?}", request.config);
+
+    if let Err(error) = config::validate_config(&request.config) {
+        warn!("set_config_v1 invalid config error={}", error);
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+    }
+
+    let saved = match state.config.lock() {
+        Ok(mut store) => store.set(request.config.clone()),
+        Err(_) => {
+            error!("set_config_v1 failed to lock config");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+    if let Err(error) = saved {
+        error!("set_config_v1 failed to persist config error={}", error);
+        return ResultEnvelope::err(ErrorCode::Internal, error);
+    }
+
+    let config = request.config;
+    if let Ok(mut cache) = state.prefetch.lock() {
+        cache.set_limits(config.prefetch_cache_pages, config.prefetch_ttl());
+    }
+    // A level saved through `set_log_level_v1` takes precedence over the config file.
+    let has_log_settings = state
+        .log_settings
+        .lock()
+        .is_ok_and(|store| store.get().is_some());
+    if !has_log_settings {
+        log_control::restore(log_control::config_settings(config.log_level).as_ref());
+    }
+
+    info!("set_config_v1 ok");
+    match config_response(state) {
+        Ok(response) => ResultEnvelope::ok(response),
+        Err(message) => ResultEnvelope::err_message(ErrorCode::Internal, message),
+    }
+}
+
+pub async fn list_masking_rules_v1(
+    state: &AppState,
+    request: ListMaskingRulesRequestV1,
+) -> ResultEnvelope<ListMaskingRulesResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("list_masking_rules_v1"),
+        list_masking_rules(state, request),
+    )
+    .await
+}
+
+async fn list_masking_rules(
+    state: &AppState,
+    request: ListMaskingRulesRequestV1,
+) -> ResultEnvelope<ListMaskingRulesResponseV1> {
+    let connection = match state.connections.lock() {
+        Ok(manager) => manager.get_connection(&request.connection_id),
+        Err(_) => {
+            error!("list_masking_rules_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    let Some(connection) = connection else {
+        warn!(
+            "list_masking_rules_v1 connection not found connection_id={}",
+            request.connection_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::ConnectionNotFound),
+        );
+    };
+
+    let rules = match state.masking.lock() {
+        Ok(store) => store.list(connection.uri()),
+        Err(_) => {
+            error!("list_masking_rules_v1 failed to lock masking store");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    ResultEnvelope::ok(ListMaskingRulesResponseV1 { rules })
+}
+
+pub async fn set_masking_rules_v1(
+    state: &AppState,
+    request: SetMaskingRulesRequestV1,
+) -> ResultEnvelope<SetMaskingRulesResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::settings("set_masking_rules_v1"),
+        set_masking_rules(state, request),
+    )
+    .await
+}
+
+async fn set_masking_rules(
+    state: &AppState,
+    request: SetMaskingRulesRequestV1,
+) -> ResultEnvelope<SetMaskingRulesResponseV1> {
+    info!(
+        "set_masking_rules_v1 start connection_id={} rules={}",
+        request.connection_id,
+        request.rules.len()
+    );
+
+    let mut rules = Vec::with_capacity(request.rules.len());
+    for rule in request.rules {
+        if let Err(error) = masking::validate_rule(&rule) {
+            warn!("set_masking_rules_v1 invalid rule error={}", error);
+            return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+        }
+        rules.push(MaskingRuleV1 {
+            table_name: rule.table_name.map(|name| name.trim().to_string()),
+            column: rule.column.trim().to_string(),
+            strategy: rule.strategy,
+        });
+    }
+
+    let connection = match state.connections.lock() {
+        Ok(manager) => manager.get_connection(&request.connection_id),
+        Err(_) => {
+            error!("set_masking_rules_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    let Some(connection) = connection else {
+        warn!(
+            "set_masking_rules_v1 connection not found connection_id={}",
+            request.connection_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::ConnectionNotFound),
+        );
+    };
+
+    let saved = match state.masking.lock() {
+        Ok(mut store) => store.set(connection.uri(), rules.clone()),
+        Err(_) => {
+            error!("set_masking_rules_v1 failed to lock masking store");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    if let Err(error) = saved {
+        error!(
+            "set_masking_rules_v1 failed connection_id={} error={}",
+            request.connection_id, error
+        );
+        return ResultEnvelope::err(ErrorCode::Internal, error);
+    }
+
+    info!(
+        "set_masking_rules_v1 ok connection_id={} rules={}",
+        request.connection_id,
+        rules.len()
+    );
+
+    ResultEnvelope::ok(SetMaskingRulesResponseV1 { rules })
+}
+
+pub async fn list_encrypted_columns_v1(
+    state: &AppState,
+    request: ListEncryptedColumnsRequestV1,
+) -> ResultEnvelope<ListEncryptedColumnsResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("list_encrypted_columns_v1"),
+        list_encrypted_columns(state, request),
+    )
+    .await
+}
+
+async fn list_encrypted_columns(
+    state: &AppState,
+    request: ListEncryptedColumnsRequestV1,
+) -> ResultEnvelope<ListEncryptedColumnsResponseV1> {
+    let connection = match state.connections.lock() {
+        Ok(manager) => manager.get_connection(&request.connection_id),
+        Err(_) => {
+            error!("list_encrypted_columns_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    let Some(connection) = connection else {
+        warn!(
+            "list_encrypted_columns_v1 connection not found connection_id={}",
+            request.connection_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::ConnectionNotFound),
+        );
+    };
+
+    let (columns, locked_key_ids) = match state.encryption.lock() {
+        Ok(store) => {
+            let columns = store.list(connection.uri());
+            let mut locked_key_ids = columns
+                .iter()
+                .filter(|column| !store.is_unlocked(&column.key_id))
+                .map(|column| column.key_id.clone())
+                .collect::<Vec<_>>();
+            locked_key_ids.sort();
+            locked_key_ids.dedup();
+            (columns, locked_key_ids)
+        }
+        Err(_) => {
+            error!("list_encrypted_columns_v1 failed to lock encryption store");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    ResultEnvelope::ok(ListEncryptedColumnsResponseV1 {
+        columns,
+        locked_key_ids,
+    })
+}
+
+pub async fn set_encrypted_columns_v1(
+    state: &AppState,
+    request: SetEncryptedColumnsRequestV1,
+) -> ResultEnvelope<SetEncryptedColumnsResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::settings("set_encrypted_columns_v1"),
+        set_encrypted_columns(state, request),
+    )
+    .await
+}
+
+async fn set_encrypted_columns(
+    state: &AppState,
+    request: SetEncryptedColumnsRequestV1,
+) -> ResultEnvelope<SetEncryptedColumnsResponseV1> {
+    info!(
+        "set_encrypted_columns_v1 start connection_id={} columns={}",
+        request.connection_id,
+        request.columns.len()
+    );
+
+    let mut columns = Vec::with_capacity(request.columns.len());
+    for column in request.columns {
+        if let Err(error) = encryption::validate_column(&column) {
+            warn!("set_encrypted_columns_v1 invalid column error={}", error);
+            return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+        }
+        columns.push(EncryptedColumnV1 {
+            table_name: column.table_name.map(|name| name.trim().to_string()),
+            column: column.column.trim().to_string(),
+            key_id: column.key_id.trim().to_string(),
+            column_id: column.column_id.map(|id| id.trim().to_string()),
+        });
+    }
+
+    let connection = match state.connections.lock() {
+        Ok(manager) => manager.get_connection(&request.connection_id),
+        Err(_) => {
+            error!("set_encrypted_columns_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    let Some(connection) = connection else {
+        warn!(
+            "set_encrypted_columns_v1 connection not found connection_id={}",
+            request.connection_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::ConnectionNotFound),
+        );
+    };
+
+    let saved = match state.encryption.lock() {
+        Ok(mut store) => store.set(connection.uri(), columns.clone()),
+        Err(_) => {
+            error!("set_encrypted_columns_v1 failed to lock encryption store");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    if let Err(error) = saved {
+        error!(
+            "set_encrypted_columns_v1 failed connection_id={} error={}",
+            request.connection_id, error
+        );
+        return ResultEnvelope::err(ErrorCode::Internal, error);
+    }
+    // Prefetched pages were decrypted with the previous settings.
+    if let Ok(mut prefetch) = state.prefetch.lock() {
+        prefetch.clear();
+    }
+    if let Ok(mut warm_scans) = state.warm_scans.lock() {
+        warm_scans.clear();
+    }
+
+    info!(
+        "set_encrypted_columns_v1 ok connection_id={} columns={}",
+        request.connection_id,
+        columns.len()
+    );
+
+    ResultEnvelope::ok(SetEncryptedColumnsResponseV1 { columns })
+}
+
+pub async fn set_encryption_key_v1(
+    state: &AppState,
+    request: SetEncryptionKeyRequestV1,
+) -> ResultEnvelope<SetEncryptionKeyResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::settings("set_encryption_key_v1"),
+        set_encryption_key(state, request),
+    )
+    .await
+}
+
+/// Never logs the key itself.
+async fn set_encryption_key(
+    state: &AppState,
+    request: SetEncryptionKeyRequestV1,
+) -> ResultEnvelope<SetEncryptionKeyResponseV1> {
+    let key_id = request.key_id.trim().to_string();
+    if key_id.is_empty() {
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, "keyId cannot be empty");
+    }
+
+    let unlocked = request.key.is_some();
+    let updated = match state.encryption.lock() {
+        Ok(mut store) => store.set_key(&key_id, request.key.as_deref()),
+        Err(_) => {
+            error!("set_encryption_key_v1 failed to lock encryption store");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+    if let Err(error) = updated {
+        warn!(
+            "set_encryption_key_v1 invalid key key_id={} error={}",
+            key_id, error
+        );
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+    }
+    if let Ok(mut prefetch) = state.prefetch.lock() {
+        prefetch.clear();
+    }
+    if let Ok(mut warm_scans) = state.warm_scans.lock() {
+        warm_scans.clear();
+    }
+
+    info!(
+        "set_encryption_key_v1 ok key_id={} unlocked={}",
+        key_id, unlocked
+    );
+    ResultEnvelope::ok(SetEncryptionKeyResponseV1 { key_id, unlocked })
+}
+
+pub async fn get_retention_policy_v1(
+    state: &AppState,
+    request: GetRetentionPolicyRequestV1,
+) -> ResultEnvelope<RetentionPolicyResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("get_retention_policy_v1"),
+        get_retention_policy(state, request),
+    )
+    .await
+}
+
+async fn get_retention_policy(
+    state: &AppState,
+    request: GetRetentionPolicyRequestV1,
+) -> ResultEnvelope<RetentionPolicyResponseV1> {
+    let key = match table_settings_key(state, &request.table_id) {
+        Ok(key) => key,
+        Err(message) => {
+            error!("get_retention_policy_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(ErrorCode::Internal, message);
+        }
+    };
+
+    let Some((connection_uri, table_name)) = key else {
+        warn!(
+            "get_retention_policy_v1 table not found table_id={}",
+            request.table_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::TableNotFound),
+        );
+    };
+
+    let policy = match state.retention.lock() {
+        Ok(store) => store.get(&connection_uri, &table_name),
+        Err(_) => {
+            error!("get_retention_policy_v1 failed to lock retention store");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    ResultEnvelope::ok(RetentionPolicyResponseV1 {
+        table_id: request.table_id,
+        policy,
+    })
+}
+
+pub async fn set_retention_policy_v1(
+    state: &AppState,
+    request: SetRetentionPolicyRequestV1,
+) -> ResultEnvelope<RetentionPolicyResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::settings("set_retention_policy_v1"),
+        set_retention_policy(state, request),
+    )
+    .await
+}
+
+async fn set_retention_policy(
+    state: &AppState,
+    request: SetRetentionPolicyRequestV1,
+) -> ResultEnvelope<RetentionPolicyResponseV1> {
+    info!(
+        "set_retention_policy_v1 start table_id={} policy={:?}",
+        request.table_id, request.policy
+    );
+
+    let (table, key) = match state.connections.lock() {
+        Ok(manager) => (
+            manager.get_table(&request.table_id),
+            manager
+                .get_table_connection_uri(&request.table_id)
+                .zip(manager.get_table_name(&request.table_id)),
+        ),
+        Err(_) => {
+            error!("set_retention_policy_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    let (Some(table), Some((connection_uri, table_name))) = (table, key) else {
+        warn!(
+            "set_retention_policy_v1 table not found table_id={}",
+            request.table_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::TableNotFound),
+        );
+    };
+
+    let policy = request.policy.map(|policy| RetentionPolicyV1 {
+        timestamp_column: policy.timestamp_column.trim().to_string(),
+        max_age_seconds: policy.max_age_seconds,
+    });
+    if let Some(policy) = policy.as_ref() {
+        let schema = match table.schema().await {
+            Ok(schema) => schema,
+            Err(error) => {
+                error!(
+                    "set_retention_policy_v1 failed to read schema table_id={} error={}",
+                    request.table_id, error
+                );
+                return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+            }
+        };
+        if let Err(error) = retention::validate_policy(schema.as_ref(), policy) {
+            warn!("set_retention_policy_v1 invalid policy error={}", error);
+            return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+        }
+    }
+
+    let saved = match state.retention.lock() {
+        Ok(mut store) => store.set(&connection_uri, &table_name, policy.clone()),
+        Err(_) => {
+            error!("set_retention_policy_v1 failed to lock retention store");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    if let Err(error) = saved {
+        error!(
+            "set_retention_policy_v1 failed table_id={} error={}",
+            request.table_id, error
+        );
+        return ResultEnvelope::err(ErrorCode::Internal, error);
+    }
+
+    info!("set_retention_policy_v1 ok table_id={}", request.table_id);
+
+    ResultEnvelope::ok(RetentionPolicyResponseV1 {
+        table_id: request.table_id,
+        policy,
+    })
+}
+
+pub async fn get_table_description_v1(
+    state: &AppState,
+    request: GetTableDescriptionRequestV1,
+) -> ResultEnvelope<TableDescriptionResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("get_table_description_v1"),
+        get_table_description(state, request),
+    )
+    .await
+}
+
+async fn get_table_description(
+    state: &AppState,
+    request: GetTableDescriptionRequestV1,
+) -> ResultEnvelope<TableDescriptionResponseV1> {
+    let key = match table_settings_key(state, &request.table_id) {
+        Ok(key) => key,
+        Err(message) => {
+            error!("get_table_description_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(ErrorCode::Internal, message);
+        }
+    };
+
+    let Some((connection_uri, table_name)) = key else {
+        warn!(
+            "get_table_description_v1 table not found table_id={}",
+            request.table_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::TableNotFound),
+        );
+    };
+
+    let metadata = match state.descriptions.lock() {
+        Ok(store) => store.get(&connection_uri, &table_name),
+        Err(_) => {
+            error!("get_table_description_v1 failed to lock description store");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    ResultEnvelope::ok(TableDescriptionResponseV1 {
+        table_id: request.table_id,
+        metadata,
+    })
+}
+
+pub async fn completion_metadata_v1(
+    state: &AppState,
+    request: CompletionMetadataRequestV1,
+) -> ResultEnvelope<CompletionMetadataResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("completion_metadata_v1"),
+        completion_metadata(state, request),
+    )
+    .await
+}
+
+async fn completion_metadata(
+    state: &AppState,
+    request: CompletionMetadataRequestV1,
+) -> ResultEnvelope<CompletionMetadataResponseV1> {
+    let table = match resolve_table(state, &request.table_id, "completion_metadata_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+    let schema = match table.schema().await {
+        Ok(schema) => schema,
+        Err(error) => {
+            error!(
+                "completion_metadata_v1 failed to read schema table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    };
+
+    let columns = completion::columns(schema.as_ref());
+    debug!(
+        "completion_metadata_v1 ok table_id={} columns={}",
+        request.table_id,
+        columns.len()
+    );
+    ResultEnvelope::ok(CompletionMetadataResponseV1 {
+        table_id: request.table_id,
+        columns,
+        operators: completion::operator_table(),
+        functions: completion::functions(),
+        keywords: completion::KEYWORDS
+            .iter()
+            .map(|keyword| keyword.to_string())
+            .collect(),
+    })
+}
+
+pub async fn validate_filter_v1(
+    state: &AppState,
+    request: ValidateFilterRequestV1,
+) -> ResultEnvelope<ValidateFilterResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("validate_filter_v1"),
+        validate_filter(state, request),
+    )
+    .await
+}
+
+/// Plans the filter without reading rows. When it fails and references a vector
+/// column, the response says how vectors can be filtered instead.
+async fn validate_filter(
+    state: &AppState,
+    request: ValidateFilterRequestV1,
+) -> ResultEnvelope<ValidateFilterResponseV1> {
+    let filter = request.filter.trim().to_string();
+    if filter.is_empty() {
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, "filter is required");
+    }
+    let table = match resolve_table(state, &request.table_id, "validate_filter_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+    let schema = match table.schema().await {
+        Ok(schema) => schema,
+        Err(error) => {
+            error!(
+                "validate_filter_v1 failed to read schema table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    };
+
+    let config = app_config(state);
+    let planned = with_timeout(config.query_timeout(), async {
+        table
+            .query()
+            .only_if(time_filters::expand(&filter))
+            .explain_plan(false)
+            .await
+            .map_err(|error| error.to_string())
+    })
+    .await;
+    let error = planned.err();
+    let hint = error.as_ref().and_then(|_| {
+        completion::vector_columns(schema.as_ref(), &filter)
+            .into_iter()
+            .next()
+            .map(|column| {
+                messages::message_with(
+                    MessageCodeV1::VectorFilterHint,
+                    messages::current_locale(),
+                    HashMap::from([("column".to_string(), column)]),
+                )
+            })
+    });
+    debug!(
+        "validate_filter_v1 ok table_id={} valid={}",
+        request.table_id,
+        error.is_none()
+    );
+    ResultEnvelope::ok(ValidateFilterResponseV1 {
+        table_id: request.table_id,
+        valid: error.is_none(),
+        error,
+        hint,
+    })
+}
+
+const DEFAULT_RECENT_FILTERS: usize = 20;
+
+/// Adds a filter that ran successfully to the history of its table. Failing to
+/// save the history never fails the query.
+fn record_filter(state: &AppState, table_id: &str, filter: &str) {
+    let Ok(Some((connection_uri, table_name))) = table_settings_key(state, table_id) else {
+        return;
+    };
+    let recorded = match state.filter_history.lock() {
+        Ok(mut history) => history.record(
+            &connection_uri,
+            &table_name,
+            filter,
+            clock::now_unix_millis(),
+        ),
+        Err(_) => Err("failed to lock filter history".to_string()),
+    };
+    if let Err(error) = recorded {
+        warn!(
+            "failed to record filter table_id={} error={}",
+            table_id, error
+        );
+    }
+}
+
+pub async fn recent_filters_v1(
+    state: &AppState,
+    request: RecentFiltersRequestV1,
+) -> ResultEnvelope<RecentFiltersResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("recent_filters_v1"),
+        recent_filters(state, request),
+    )
+    .await
+}
+
+async fn recent_filters(
+    state: &AppState,
+    request: RecentFiltersRequestV1,
+) -> ResultEnvelope<RecentFiltersResponseV1> {
+    let key = match table_settings_key(state, &request.table_id) {
+        Ok(key) => key,
+        Err(message) => {
+            error!("recent_filters_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(ErrorCode::Internal, message);
+        }
+    };
+    let Some((connection_uri, table_name)) = key else {
+        warn!(
+            "recent_filters_v1 table not found table_id={}",
+            request.table_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::TableNotFound),
+        );
+    };
+
+    let filters = match state.filter_history.lock() {
+        Ok(history) => history.recent(
+            &connection_uri,
+            &table_name,
+            request.prefix.as_deref().unwrap_or_default(),
+            request.sort.unwrap_or(RecentFilterSortV1::Recent),
+            request.limit.unwrap_or(DEFAULT_RECENT_FILTERS),
+        ),
+        Err(_) => {
+            error!("recent_filters_v1 failed to lock filter history");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    ResultEnvelope::ok(RecentFiltersResponseV1 {
+        table_id: request.table_id,
+        filters,
+    })
+}
+
+pub async fn set_table_description_v1(
+    state: &AppState,
+    request: SetTableDescriptionRequestV1,
+) -> ResultEnvelope<TableDescriptionResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::edit_table("set_table_description_v1", &request.table_id),
+        set_table_description(state, request),
+    )
+    .await
+}
+
+async fn set_table_description(
+    state: &AppState,
+    request: SetTableDescriptionRequestV1,
+) -> ResultEnvelope<TableDescriptionResponseV1> {
+    info!(
+        "set_table_description_v1 start table_id={} cleared={}",
+        request.table_id,
+        request.metadata.is_none()
+    );
+
+    let key = match table_settings_key(state, &request.table_id) {
+        Ok(key) => key,
+        Err(message) => {
+            error!("set_table_description_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(ErrorCode::Internal, message);
+        }
+    };
+
+    let Some((connection_uri, table_name)) = key else {
+        warn!(
+            "set_table_description_v1 table not found table_id={}",
+            request.table_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::TableNotFound),
+        );
+    };
+
+    let metadata = match request.metadata.map(descriptions::normalize).transpose() {
+        Ok(metadata) => metadata.flatten().map(|metadata| TableDescriptionV1 {
+            updated_at_ms: Some(clock::now_unix_millis()),
+            ..metadata
+        }),
+        Err(error) => {
+            warn!("set_table_description_v1 invalid metadata error={}", error);
+            return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+        }
+    };
+
+    let saved = match state.descriptions.lock() {
+        Ok(mut store) => store.set(&connection_uri, &table_name, metadata.clone()),
+        Err(_) => {
+            error!("set_table_description_v1 failed to lock description store");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    if let Err(error) = saved {
+        error!(
+            "set_table_description_v1 failed table_id={} error={}",
+            request.table_id, error
+        );
+        return ResultEnvelope::err(ErrorCode::Internal, error);
+    }
+
+    info!("set_table_description_v1 ok table_id={}", request.table_id);
+
+    ResultEnvelope::ok(TableDescriptionResponseV1 {
+        table_id: request.table_id,
+        metadata,
+    })
+}
+
+pub async fn get_search_defaults_v1(
+    state: &AppState,
+    request: GetSearchDefaultsRequestV1,
+) -> ResultEnvelope<SearchDefaultsResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("get_search_defaults_v1"),
+        get_search_defaults(state, request),
+    )
+    .await
+}
+
+async fn get_search_defaults(
+    state: &AppState,
+    request: GetSearchDefaultsRequestV1,
+) -> ResultEnvelope<SearchDefaultsResponseV1> {
+    let key = match table_settings_key(state, &request.table_id) {
+        Ok(key) => key,
+        Err(message) => {
+            error!("get_search_defaults_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(ErrorCode::Internal, message);
+        }
+    };
+
+    let Some((connection_uri, table_name)) = key else {
+        warn!(
+            "get_search_defaults_v1 table not found table_id={}",
+            request.table_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::TableNotFound),
+        );
+    };
+
+    let defaults = match state.search_defaults.lock() {
+        Ok(store) => store.get(&connection_uri, &table_name),
+        Err(_) => {
+            error!("get_search_defaults_v1 failed to lock search defaults store");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    ResultEnvelope::ok(SearchDefaultsResponseV1 {
+        table_id: request.table_id,
+        defaults,
+    })
+}
+
+pub async fn set_search_defaults_v1(
+    state: &AppState,
+    request: SetSearchDefaultsRequestV1,
+) -> ResultEnvelope<SearchDefaultsResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::edit_table("set_search_defaults_v1", &request.table_id),
+        set_search_defaults(state, request),
+    )
+    .await
+}
+
+async fn set_search_defaults(
+    state: &AppState,
+    request: SetSearchDefaultsRequestV1,
+) -> ResultEnvelope<SearchDefaultsResponseV1> {
+    info!(
+        "set_search_defaults_v1 start table_id={} cleared={}",
+        request.table_id,
+        request.defaults.is_none()
+    );
+
+    let key = match table_settings_key(state, &request.table_id) {
+        Ok(key) => key,
+        Err(message) => {
+            error!("set_search_defaults_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(ErrorCode::Internal, message);
+        }
+    };
+
+    let Some((connection_uri, table_name)) = key else {
+        warn!(
+            "set_search_defaults_v1 table not found table_id={}",
+            request.table_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::TableNotFound),
+        );
+    };
+
+    let defaults = match request.defaults {
+        Some(defaults) => {
+            let table = match resolve_table(state, &request.table_id, "set_search_defaults_v1") {
+                Ok(table) => table,
+                Err(envelope) => return envelope,
+            };
+            let schema = match table.schema().await {
+                Ok(schema) => schema,
+                Err(error) => {
+                    error!(
+                        "set_search_defaults_v1 failed to read schema table_id={} error={}",
+                        request.table_id, error
+                    );
+                    return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+                }
+            };
+            match search_defaults::normalize(defaults, schema.as_ref()) {
+                Ok(defaults) => defaults,
+                Err(error) => {
+                    warn!("set_search_defaults_v1 invalid defaults error={}", error);
+                    return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+                }
+            }
+        }
+        None => None,
+    };
+
+    let saved = match state.search_defaults.lock() {
+        Ok(mut store) => store.set(&connection_uri, &table_name, defaults.clone()),
+        Err(_) => {
+            error!("set_search_defaults_v1 failed to lock search defaults store");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    if let Err(error) = saved {
+        error!(
+            "set_search_defaults_v1 failed table_id={} error={}",
+            request.table_id, error
+        );
+        return ResultEnvelope::err(ErrorCode::Internal, error);
+    }
+
+    info!("set_search_defaults_v1 ok table_id={}", request.table_id);
+
+    ResultEnvelope::ok(SearchDefaultsResponseV1 {
+        table_id: request.table_id,
+        defaults,
+    })
+}
+
+pub async fn list_projection_presets_v1(
+    state: &AppState,
+    request: ListProjectionPresetsRequestV1,
+) -> ResultEnvelope<ListProjectionPresetsResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("list_projection_presets_v1"),
+        list_projection_presets(state, request),
+    )
+    .await
+}
+
+async fn list_projection_presets(
+    state: &AppState,
+    request: ListProjectionPresetsRequestV1,
+) -> ResultEnvelope<ListProjectionPresetsResponseV1> {
+    let key = match table_settings_key(state, &request.table_id) {
+        Ok(key) => key,
+        Err(message) => {
+            error!("list_projection_presets_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(ErrorCode::Internal, message);
+        }
+    };
+
+    let Some((connection_uri, table_name)) = key else {
+        warn!(
+            "list_projection_presets_v1 table not found table_id={}",
+            request.table_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::TableNotFound),
+        );
+    };
+
+    let presets = match state.projection_presets.lock() {
+        Ok(store) => store.list(&connection_uri, &table_name),
+        Err(_) => {
+            error!("list_projection_presets_v1 failed to lock projection preset store");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    ResultEnvelope::ok(ListProjectionPresetsResponseV1 {
+        table_id: request.table_id,
+        presets,
+    })
+}
+
+pub async fn save_projection_preset_v1(
+    state: &AppState,
+    request: SaveProjectionPresetRequestV1,
+) -> ResultEnvelope<SaveProjectionPresetResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::write_table("save_projection_preset_v1", &request.table_id),
+        save_projection_preset(state, request),
+    )
+    .await
+}
+
+async fn save_projection_preset(
+    state: &AppState,
+    request: SaveProjectionPresetRequestV1,
+) -> ResultEnvelope<SaveProjectionPresetResponseV1> {
+    info!(
+        "save_projection_preset_v1 start table_id={} name={} columns={}",
+        request.table_id,
+        request.preset.name,
+        request.preset.columns.len()
+    );
+
+    let key = match table_settings_key(state, &request.table_id) {
+        Ok(key) => key,
+        Err(message) => {
+            error!("save_projection_preset_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(ErrorCode::Internal, message);
+        }
+    };
+
+    let Some((connection_uri, table_name)) = key else {
+        warn!(
+            "save_projection_preset_v1 table not found table_id={}",
+            request.table_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::TableNotFound),
+        );
+    };
+
+    let table = match resolve_table(state, &request.table_id, "save_projection_preset_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+    let schema = match table.schema().await {
+        Ok(schema) => schema,
+        Err(error) => {
+            error!(
+                "save_projection_preset_v1 failed to read schema table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    };
+    let preset = match projection_presets::normalize(request.preset, schema.as_ref()) {
+        Ok(preset) => preset,
+        Err(error) => {
+            warn!("save_projection_preset_v1 invalid preset error={}", error);
+            return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+        }
+    };
+
+    let saved = match state.projection_presets.lock() {
+        Ok(mut store) => store.save(&connection_uri, &table_name, preset.clone()),
+        Err(_) => {
+            error!("save_projection_preset_v1 failed to lock projection preset store");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    if let Err(error) = saved {
+        error!(
+            "save_projection_preset_v1 failed table_id={} error={}",
+            request.table_id, error
+        );
+        return ResultEnvelope::err(ErrorCode::Internal, error);
+    }
+
+    info!(
+        "save_projection_preset_v1 ok table_id={} name={}",
+        request.table_id, preset.name
+    );
+
+    ResultEnvelope::ok(SaveProjectionPresetResponseV1 {
+        table_id: request.table_id,
+        preset,
+    })
+}
+
+pub async fn delete_projection_preset_v1(
+    state: &AppState,
+    request: DeleteProjectionPresetRequestV1,
+) -> ResultEnvelope<DeleteProjectionPresetResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::write_table("delete_projection_preset_v1", &request.table_id),
+        delete_projection_preset(state, request),
+    )
+    .await
+}
+
+async fn delete_projection_preset(
+    state: &AppState,
+    request: DeleteProjectionPresetRequestV1,
+) -> ResultEnvelope<DeleteProjectionPresetResponseV1> {
+    info!(
+        "delete_projection_preset_v1 start table_id={} name={}",
+        request.table_id, request.name
+    );
+
+    let key = match table_settings_key(state, &request.table_id) {
+        Ok(key) => key,
+        Err(message) => {
+            error!("delete_projection_preset_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(ErrorCode::Internal, message);
+        }
+    };
+
+    let Some((connection_uri, table_name)) = key else {
+        warn!(
+            "delete_projection_preset_v1 table not found table_id={}",
+            request.table_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::TableNotFound),
+        );
+    };
+
+    let deleted = match state.projection_presets.lock() {
+        Ok(mut store) => store.delete(&connection_uri, &table_name, &request.name),
+        Err(_) => {
+            error!("delete_projection_preset_v1 failed to lock projection preset store");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    match deleted {
+        Ok(true) => {}
+        Ok(false) => {
+            return ResultEnvelope::err(
+                ErrorCode::NotFound,
+                format!("projection preset '{}' not found", request.name),
+            );
+        }
+        Err(error) => {
+            error!(
+                "delete_projection_preset_v1 failed table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    }
+
+    info!(
+        "delete_projection_preset_v1 ok table_id={} name={}",
+        request.table_id, request.name
+    );
+
+    ResultEnvelope::ok(DeleteProjectionPresetResponseV1 {
+        table_id: request.table_id,
+        name: request.name,
+    })
+}
+
+pub async fn stats_history_v1(
+    state: &AppState,
+    request: StatsHistoryRequestV1,
+) -> ResultEnvelope<StatsHistoryResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("stats_history_v1"),
+        stats_history(state, request),
+    )
+    .await
+}
+
+async fn stats_history(
+    state: &AppState,
+    request: StatsHistoryRequestV1,
+) -> ResultEnvelope<StatsHistoryResponseV1> {
+    let table = match resolve_table(state, &request.table_id, "stats_history_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+    let connection_uri = match table_settings_key(state, &request.table_id) {
+        Ok(Some((connection_uri, _))) => connection_uri,
+        Ok(None) => {
+            return ResultEnvelope::err_message(
+                ErrorCode::NotFound,
+                messages::message(MessageCodeV1::TableNotFound),
+            );
+        }
+        Err(message) => {
+            error!("stats_history_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(ErrorCode::Internal, message);
+        }
+    };
+    let table_name = table.name().to_string();
+
+    // A due snapshot is taken now so a table opened for the first time has a point
+    // to chart; failing to read stats still returns the saved history.
+    if let Err(error) =
+        stats_history::snapshot_if_due(state, &connection_uri, table, clock::now_unix_millis())
+            .await
+    {
+        warn!(
+            "stats_history_v1 snapshot failed table_id={} error={}",
+            request.table_id, error
+        );
+    }
+
+    let snapshots = match state.stats_history.lock() {
+        Ok(store) => store.history(&connection_uri, &table_name, request.since_ms),
+        Err(_) => {
+            error!("stats_history_v1 failed to lock stats history");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+    debug!(
+        "stats_history_v1 table_id={} snapshots={}",
+        request.table_id,
+        snapshots.len()
+    );
+
+    ResultEnvelope::ok(StatsHistoryResponseV1 {
+        table_id: request.table_id,
+        snapshots,
+    })
+}
+
+pub async fn run_retention_v1(
+    state: &AppState,
+    request: RunRetentionRequestV1,
+) -> ResultEnvelope<RunRetentionResponseV1> {
+    let context = if request.dry_run {
+        CommandContext::read("run_retention_v1")
+    } else {
+        CommandContext::write_table("run_retention_v1", &request.table_id)
+    };
+    middleware::run(state, context, run_retention(state, request)).await
+}
+
+async fn run_retention(
+    state: &AppState,
+    request: RunRetentionRequestV1,
+) -> ResultEnvelope<RunRetentionResponseV1> {
+    let started_at = Instant::now();
+    info!(
+        "run_retention_v1 start table_id={} dry_run={}",
+        request.table_id, request.dry_run
+    );
+
+    let (table, key) = match state.connections.lock() {
+        Ok(manager) => (
+            manager.get_table(&request.table_id),
+            manager
+                .get_table_connection_uri(&request.table_id)
+                .zip(manager.get_table_name(&request.table_id)),
+        ),
+        Err(_) => {
+            error!("run_retention_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    let (Some(table), Some((connection_uri, table_name))) = (table, key) else {
+        warn!(
+            "run_retention_v1 table not found table_id={}",
+            request.table_id
+        );
+        return ResultEnvelope::err_message(
+            ErrorCode::NotFound,
+            messages::message(MessageCodeV1::TableNotFound),
+        );
+    };
+
+    let policy = match state.retention.lock() {
+        Ok(store) => store.get(&connection_uri, &table_name),
+        Err(_) => {
+            error!("run_retention_v1 failed to lock retention store");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    let Some(policy) = policy else {
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, "table has no retention policy");
+    };
+
+    let schema = match table.schema().await {
+        Ok(schema) => schema,
+        Err(error) => {
+            error!(
+                "run_retention_v1 failed to read schema table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    };
+
+    let cutoff = retention::cutoff(chrono::Utc::now(), &policy);
+    let filter = match retention::expired_filter(schema.as_ref(), &policy, cutoff) {
+        Ok(filter) => filter,
+        Err(error) => {
+            warn!(
+                "run_retention_v1 policy no longer matches schema table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+        }
+    };
+    debug!("run_retention_v1 filter=\"{}\"", filter);
+
+    let rows_expired = match table.count_rows(Some(filter.clone())).await {
+        Ok(count) => count,
+        Err(error) => {
+            error!(
+                "run_retention_v1 count failed table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    };
+
+    let mut version = None;
+    let mut rows_deleted = 0;
+    if !request.dry_run && rows_expired > 0 {
+        match table.delete(&filter).await {
+            Ok(result) => {
+                version = Some(result.version);
+                rows_deleted = rows_expired;
+            }
+            Err(error) => {
+                error!(
+                    "run_retention_v1 delete failed table_id={} error={}",
+                    request.table_id, error
+                );
+                return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+            }
+        }
+    }
+
+    after_table_write(state, &request.table_id);
+
+    info!(
+        "run_retention_v1 ok table_id={} expired={} deleted={} elapsed_ms={}",
+        request.table_id,
+        rows_expired,
+        rows_deleted,
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(RunRetentionResponseV1 {
+        table_id: request.table_id,
+        timestamp_column: policy.timestamp_column,
+        cutoff: cutoff.to_rfc3339(),
+        rows_expired,
+        rows_deleted,
+        version,
+    })
+}
+
+/// One page of a scan, detached from the request so it can be re-run for prefetching.
+#[derive(Debug, Clone)]
+struct ScanPage {
+    table_id: String,
+    format: DataFormat,
+    projection: Option<Vec<String>>,
+    filter: Option<String>,
+    limit: usize,
+    offset: usize,
+    /// Decrypts encrypted columns whose key is unlocked; shares never get keys.
+    column_keys: encryption::ColumnKeys,
+    masking_rules: Vec<MaskingRuleV1>,
+    include_stats: bool,
+    json_options: JsonOptionsV1,
+    /// Version the scan is pinned to, so pages of different versions are cached
+    /// apart. Shares leave it unset and always read the latest version.
+    version: Option<u64>,
+    max_chunk_bytes: Option<usize>,
+}
+
+impl ScanPage {
+    fn cursor_key(&self) -> String {
+        format!(
+            "{}@{:?}|{:?}|{:?}|{:?}|{}|{}|{}|{}|{}|{:?}|{:?}",
+            self.table_id,
+            self.version,
+            self.format,
+            self.projection,
+            self.filter,
+            self.limit,
+            self.offset,
+            self.column_keys.fingerprint(),
+            !self.masking_rules.is_empty(),
+            self.include_stats,
+            self.json_options,
+            self.max_chunk_bytes
+        )
+    }
+
+    fn next(&self, next_offset: usize) -> Self {
+        Self {
+            offset: next_offset,
+            ..self.clone()
+        }
+    }
+}
+
+async fn scan_page(table: Table, page: ScanPage) -> Result<ScanResponseV1, String> {
+    let ScanPage {
+        table_id,
+        format,
+        projection,
+        filter,
+        limit,
+        offset,
+        column_keys,
+        masking_rules,
+        include_stats,
+        json_options,
+        version: _,
+        max_chunk_bytes,
+    } = page;
+    let version = table.version().await.map_err(|error| error.to_string())?;
+    let fallback_schema = table.schema().await.map_err(|error| error.to_string())?;
+    let fallback_schema = masking::mask_schema(fallback_schema.as_ref(), &masking_rules);
+
+    let options = QueryOptions {
+        projection,
+        filter,
+        limit: Some(limit.saturating_add(1)),
+        offset: Some(offset),
+    };
+
+    let query = apply_query_options(table.query(), &options);
+    let stats = if include_stats {
+        collect_query_stats(&query, "scan_v1", &table_id).await
+    } else {
+        None
+    };
+
+    match format {
+        DataFormat::Json => {
+            let fallback_definition = SchemaDefinition::from_arrow_schema(fallback_schema.as_ref());
+            let (mut rows, schema) = execute_masked_query_json(
+                query,
+                fallback_definition,
+                &column_keys,
+                &masking_rules,
+                &json_options,
+            )
+            .await?;
+
+            let has_more = rows.len() > limit;
+            if has_more {
+                rows.truncate(limit);
+            }
+            let next_offset = if has_more {
+                Some(offset.saturating_add(limit))
+            } else {
+                None
+            };
+
+            Ok(ScanResponseV1 {
+                chunk: DataChunk::Json(JsonChunk {
+                    rows,
+                    schema,
+                    offset,
+                    limit,
+                }),
+                next_offset,
+                version,
+                split: None,
+                stats,
+            })
+        }
+        DataFormat::Arrow => {
+            let scan = TableScan {
+                filter: options.filter.as_deref().map(time_filters::expand),
+                projection: options.projection.clone(),
+                limit: options.limit,
+                offset: options.offset,
+            };
+            let batches = LanceTableBackend::new(table.clone())
+                .scan(&scan)
+                .await
+                .map_err(|error| error.to_string())?;
+            for batch in &batches {
+                memory::retain(memory::batch_bytes(batch));
+            }
+            let batches = encryption::decrypt_batches(batches, &column_keys)?;
+            let batches = masking::mask_batches(batches, &masking_rules)?;
+
+            let output_schema = batches
+                .first()
+                .map(|batch| batch.schema())
+                .unwrap_or_else(|| fallback_schema.clone());
+            let total_rows: usize = batches.iter().map(|batch| batch.num_rows()).sum();
+            let has_more = total_rows > limit;
+            let trimmed = if has_more {
+                truncate_batches(&batches, limit)
+            } else {
+                batches
+            };
+
+            let page_rows = total_rows.min(limit);
+            let (ipc_base64, rows) = match max_chunk_bytes {
+                Some(max_bytes) => {
+                    batches_to_arrow_ipc_within(&trimmed, output_schema.as_ref(), max_bytes)?
+                }
+                None => (
+                    batches_to_arrow_ipc_base64(&trimmed, output_schema.as_ref())?,
+                    page_rows,
+                ),
+            };
+            let split = (rows < page_rows).then(|| ChunkSplitV1 {
+                rows,
+                remaining_rows: page_rows - rows,
+                bytes: ipc_base64.len(),
+            });
+
+            let next_offset = if split.is_some() {
+                Some(offset.saturating_add(rows))
+            } else if has_more {
+                Some(offset.saturating_add(limit))
+            } else {
+                None
+            };
+
+            Ok(ScanResponseV1 {
+                chunk: DataChunk::Arrow(ArrowChunk {
+                    ipc_base64,
+                    compression: None,
+                }),
+                next_offset,
+                version,
+                split,
+                stats,
+            })
+        }
+    }
+}
+
+/// Starts the query for `page` in the background unless it is already cached.
+fn spawn_prefetch(state: &AppState, table: Table, page: ScanPage) {
+    let key = page.cursor_key();
+    let prefetched = match state.prefetch.lock() {
+        Ok(mut cache) => {
+            if cache.contains(&key) {
+                return;
+            }
+            let table_id = page.table_id.clone();
+            cache.insert(key, &table_id, scan_page(table, page).boxed())
+        }
+        Err(_) => {
+            warn!("scan_v1 failed to lock prefetch cache");
+            return;
+        }
+    };
+    tauri::async_runtime::spawn(prefetched);
+}
+
+/// Drops prefetched pages of a table after it has been modified and recounts its
+/// rows in the background.
+fn after_table_write(state: &AppState, table_id: &str) {
+    if let Ok(mut cache) = state.prefetch.lock() {
+        cache.invalidate_table(table_id);
+    }
+    if let Ok(mut cache) = state.warm_scans.lock() {
+        cache.invalidate_table(table_id);
+    }
+    spawn_row_count_refresh(state, table_id);
+    if let Some(table) = state
+        .connections
+        .lock()
+        .ok()
+        .and_then(|manager| manager.get_table(table_id))
+    {
+        spawn_scan_warmup(state, table_id, table);
+    }
+}
+
+/// Warms a local table's scanner in the background: its indexes are loaded into
+/// the session cache and its first rows are read, which pulls their data pages into
+/// the OS page cache. Runs once per table version, after opens and writes.
+fn spawn_scan_warmup(state: &AppState, table_id: &str, table: Table) {
+    if local_path(table.dataset_uri()).is_none() {
+        return;
+    }
+    let cache = state.warm_scans.clone();
+    let table_id = table_id.to_string();
+    tauri::async_runtime::spawn(async move {
+        let Ok(version) = table.version().await else {
+            return;
+        };
+        let claimed = cache
+            .lock()
+            .map(|mut cache| cache.start_warmup(&table_id, version))
+            .unwrap_or(false);
+        if !claimed {
+            return;
+        }
+        let started_at = Instant::now();
+        match warm_table(&table).await {
+            Ok(()) => debug!(
+                "scan warmup ok table_id={} version={} elapsed_ms={}",
+                table_id,
+                version,
+                started_at.elapsed().as_millis()
+            ),
+            Err(error) => {
+                debug!(
+                    "scan warmup failed table_id={} version={} error={}",
+                    table_id, version, error
+                );
+                if let Ok(mut cache) = cache.lock() {
+                    cache.cancel_warmup(&table_id, version);
+                }
+            }
+        }
+    });
+}
+
+async fn warm_table(table: &Table) -> Result<(), String> {
+    let indices = table
+        .list_indices()
+        .await
+        .map_err(|error| error.to_string())?;
+    for index in indices {
+        // Not every index type can be prewarmed; the scan below still helps.
+        if let Err(error) = table.prewarm_index(&index.name).await {
+            debug!("scan warmup skipped index={} error={}", index.name, error);
+        }
+    }
+    let scan = TableScan {
+        limit: Some(warm_scans::WARMUP_ROWS),
+        ..TableScan::default()
+    };
+    LanceTableBackend::new(table.clone())
+        .scan(&scan)
+        .await
+        .map_err(|error| error.to_string())?;
+    Ok(())
+}
+
+/// Tags `version`, the version a mutation reported committing, when
+/// `auto_checkpoints` is on; `note` comes from the mutation's request. Without a
+/// reported version (`None`, or `0` from servers that predate commit versions) the
+/// handle's version is tagged instead. A version that already carries an automatic
+/// tag was not produced by this call, e.g. an update that matched nothing, and is
+/// left alone. The note is saved beside the tag, since the name only keeps its
+/// slug. Failures are only logged, since the mutation has already committed.
+async fn auto_checkpoint(
+    state: &AppState,
+    table_id: &str,
+    operation: &str,
+    version: Option<u64>,
+    note: Option<&str>,
+) {
+    if !app_config(state).auto_checkpoints {
+        return;
+    }
+    let table = match state.connections.lock() {
+        Ok(manager) => manager.get_table(table_id),
+        Err(_) => return,
+    };
+    let Some(table) = table else {
+        return;
+    };
+    let tagged = async {
+        let version = match version.filter(|version| *version > 0) {
+            Some(version) => version,
+            None => table.version().await.map_err(|error| error.to_string())?,
+        };
+        let existing = list_version_tags(&table).await?;
+        if existing
+            .iter()
+            .any(|tag| tag.version == version && checkpoints::is_auto_tag(&tag.name))
+        {
+            return Ok(None);
+        }
+        let name = checkpoints::tag_name(version, operation, chrono::Utc::now(), note);
+        let mut tags = table.tags().await.map_err(|error| error.to_string())?;
+        tags.create(&name, version)
+            .await
+            .map_err(|error| error.to_string())?;
+        Ok::<_, String>(Some(name))
+    };
+    match tagged.await {
+        Ok(Some(name)) => {
+            set_checkpoint_note(state, table_id, &name, note);
+            info!(
+                "auto checkpoint table_id={} operation={} tag=\"{}\"",
+                table_id, operation, name
+            );
+        }
+        Ok(None) => {}
+        Err(error) => warn!(
+            "auto checkpoint failed table_id={} operation={} error={}",
+            table_id, operation, error
+        ),
+    }
+}
+
+/// Returns the version of `table` and its row count at that version.
+async fn count_table_rows(table: &dyn TableBackend) -> Result<(u64, u64), BackendError> {
+    let version = table.version().await?;
+    let row_count = table.count_rows(None).await?;
+    Ok((version, row_count))
+}
+
+fn spawn_row_count_refresh(state: &AppState, table_id: &str) {
+    let (table, uri) = match state.connections.lock() {
+        Ok(manager) => (
+            manager.get_table(table_id),
+            manager.get_table_connection_uri(table_id),
+        ),
+        Err(_) => return,
+    };
+    let (Some(table), Some(uri)) = (table, uri) else {
+        return;
+    };
+    let row_counts = state.row_counts.clone();
+    let table_id = table_id.to_string();
+    tauri::async_runtime::spawn(async move {
+        match count_table_rows(&LanceTableBackend::new(table.clone())).await {
+            Ok((version, row_count)) => {
+                if let Ok(mut cache) = row_counts.lock() {
+                    cache.record(&uri, table.name(), version, row_count);
+                }
+            }
+            Err(error) => warn!(
+                "failed to refresh row count table_id={} error={}",
+                table_id, error
+            ),
+        }
+    });
+}
+
+pub async fn get_row_count_v1(
+    state: &AppState,
+    request: GetRowCountRequestV1,
+) -> ResultEnvelope<RowCountResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("get_row_count_v1"),
+        get_row_count(state, request),
+    )
+    .await
+}
+
+async fn get_row_count(
+    state: &AppState,
+    request: GetRowCountRequestV1,
+) -> ResultEnvelope<RowCountResponseV1> {
+    let started_at = Instant::now();
+    info!("get_row_count_v1 start table_id={}", request.table_id);
+
+    let table = match resolve_table(state, &request.table_id, "get_row_count_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+    let uri = match state.connections.lock() {
+        Ok(manager) => manager
+            .get_table_connection_uri(&request.table_id)
+            .unwrap_or_default(),
+        Err(_) => {
+            error!("get_row_count_v1 failed to lock connection manager");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+
+    let version = match table.version().await {
+        Ok(version) => version,
+        Err(error) => {
+            error!(
+                "get_row_count_v1 failed to read version table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    };
+    let cached = state
+        .row_counts
+        .lock()
+        .ok()
+        .and_then(|cache| cache.get_at_version(&uri, table.name(), version));
+    if let Some(row_count) = cached {
+        return ResultEnvelope::ok(RowCountResponseV1 {
+            table_id: request.table_id,
+            row_count,
+            version,
+            cached: true,
+        });
+    }
+
+    // The version moved since the last count, so count again.
+    let backend = LanceTableBackend::new(table.clone());
+    let counting =
+        count_table_rows(&backend).map(|counted| counted.map_err(|error| error.to_string()));
+    let (version, row_count) = match with_timeout(app_config(state).query_timeout(), counting).await
+    {
+        Ok(counted) => counted,
+        Err(error) => {
+            error!(
+                "get_row_count_v1 failed table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    };
+    if let Ok(mut cache) = state.row_counts.lock() {
+        cache.record(&uri, table.name(), version, row_count);
+    }
+
+    info!(
+        "get_row_count_v1 ok table_id={} rows={} version={} elapsed_ms={}",
+        request.table_id,
+        row_count,
+        version,
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(RowCountResponseV1 {
+        table_id: request.table_id,
+        row_count,
+        version,
+        cached: false,
+    })
+}
+
+pub async fn scan_v1(state: &AppState, request: ScanRequestV1) -> ResultEnvelope<ScanResponseV1> {
+    let job_id = request.job_id.clone();
+    let table_id = request.table_id.clone();
+    let summary = active_queries::summary("scan", None, request.filter.as_deref());
+    let command = middleware::run(
+        state,
+        CommandContext::read("scan_v1"),
+        run_as_query(state, "scan_v1", &table_id, summary, scan(state, request)),
+    );
+    match job_id {
+        Some(job_id) => run_as_job(state, Some(job_id), JobKindV1::Scan, None, command).await,
+        None => command.await,
+    }
+}
+
+async fn scan(state: &AppState, mut request: ScanRequestV1) -> ResultEnvelope<ScanResponseV1> {
+    let started_at = Instant::now();
+    info!(
+        "scan_v1 start table_id={} format={:?} limit={:?} offset={:?} as_of_version={:?} prefetch={}",
+        request.table_id,
+        request.format,
+        request.limit,
+        request.offset,
+        request.as_of_version,
+        request.prefetch
+    );
+    if let Err(envelope) = resolve_projection_preset(
+        state,
+        &request.table_id,
+        &mut request.projection,
+        &mut request.projection_preset,
+        "scan_v1",
+    ) {
+        return envelope;
+    }
+    if let Some(ref filter) = request.filter {
+        trace!("scan_v1 filter=\"{}\"", filter);
+    }
+    if let Some(ref projection) = request.projection {
+        trace!("scan_v1 projection={:?}", projection);
+    }
+    if request.max_chunk_bytes == Some(0) {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            "maxChunkBytes must be greater than zero",
+        );
+    }
+
+    let table =
+        match resolve_table_version(state, &request.table_id, request.as_of_version, "scan_v1")
+            .await
+        {
+            Ok(table) => table,
+            Err(envelope) => return envelope,
+        };
+    let version = match request.as_of_version {
+        Some(version) => version,
+        None => match table.version().await {
+            Ok(version) => version,
+            Err(error) => {
+                error!(
+                    "scan_v1 failed to read version table_id={} error={}",
+                    request.table_id, error
+                );
+                return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+            }
+        },
+    };
+
+    let masking_rules = if request.apply_masking {
+        match table_masking_rules(state, &request.table_id) {
+            Ok(rules) => rules,
+            Err(message) => {
+                error!("scan_v1 failed to read masking rules");
+                return ResultEnvelope::err_message(ErrorCode::Internal, message);
+            }
+        }
+    } else {
+        Vec::new()
+    };
+    if let Some(projection) = &request.projection {
+        if let Err(error) = projection::check_masked_columns(projection, &masking_rules) {
+            return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+        }
+    }
+
+    let column_keys = match table_column_keys(state, &request.table_id, false) {
+        Ok(keys) => keys,
+        Err(error) => {
+            error!("scan_v1 failed to read encryption keys");
+            return ResultEnvelope::err_envelope(error);
+        }
+    };
+
+    let config = app_config(state);
+    let page = ScanPage {
+        table_id: request.table_id.clone(),
+        format: request.format.clone(),
+        projection: request.projection.clone(),
+        filter: request.filter.clone(),
+        limit: request.limit.unwrap_or(config.default_page_size),
+        offset: request.offset.unwrap_or(0),
+        column_keys,
+        masking_rules,
+        include_stats: request.include_stats,
+        json_options: request.json_options.clone().unwrap_or_default(),
+        version: Some(version),
+        max_chunk_bytes: request.max_chunk_bytes,
+    };
+
+    // First pages of local tables stay warm, so switching back to a table whose
+    // version and scan parameters are unchanged skips the query. Filters with time
+    // helpers match different rows as the clock moves, so their pages are never
+    // cached or prefetched.
+    let cacheable = !page
+        .filter
+        .as_deref()
+        .is_some_and(time_filters::uses_helpers);
+    let warm_key = (cacheable
+        && page.offset == 0
+        && !page.include_stats
+        && request.as_of_version.is_none()
+        && local_path(table.dataset_uri()).is_some())
+    .then(|| format!("{}|{:?}", page.cursor_key(), page.masking_rules));
+    let warm_page = warm_key.as_deref().and_then(|key| {
+        state
+            .warm_scans
+            .lock()
+            .ok()
+            .and_then(|mut cache| cache.get(&request.table_id, version, key))
+    });
+    let from_warm = warm_page.is_some();
+
+    let prefetched = match state.prefetch.lock() {
+        Ok(mut cache) if cacheable && !from_warm => cache.take(&page.cursor_key()),
+        _ => None,
+    };
+    let mut from_prefetch = false;
+    let query = async {
+        match prefetched {
+            Some(prefetched) => match prefetched.await {
+                Ok(response) => {
+                    from_prefetch = true;
+                    Ok(response)
+                }
+                Err(error) => {
+                    debug!(
+                        "scan_v1 prefetched page failed, retrying table_id={} error={}",
+                        request.table_id, error
+                    );
+                    scan_page(table.clone(), page.clone()).await
+                }
+            },
+            None => scan_page(table.clone(), page.clone()).await,
+        }
+    };
+    let result = match warm_page {
+        Some(response) => Ok(response),
+        None => with_timeout(config.query_timeout(), query).await,
+    };
+
+    let response = match result {
+        Ok(response) => response,
+        Err(error) => {
+            error!(
+                "scan_v1 query failed table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    };
+    if let Some(key) = warm_key.filter(|_| !from_warm) {
+        if let Ok(mut cache) = state.warm_scans.lock() {
+            cache.insert(&request.table_id, key, response.clone());
+        }
+    }
+
+    if request.prefetch && cacheable {
+        if let Some(next_offset) = response.next_offset {
+            spawn_prefetch(state, table, page.next(next_offset));
+        }
+    }
+    if page.offset == 0 {
+        if let Some(filter) = &page.filter {
+            record_filter(state, &request.table_id, filter);
+        }
+    }
+
+    info!(
+        "scan_v1 ok table_id={} format={:?} version={} next_offset={:?} prefetched={} warm={} elapsed_ms={}",
+        request.table_id,
+        request.format,
+        version,
+        response.next_offset,
+        from_prefetch,
+        from_warm,
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(response)
+}
+
+pub async fn scan_stream_v1(
+    state: &AppState,
+    request: ScanStreamRequestV1,
+    send: impl FnMut(ScanStreamMessage) -> Result<(), String>,
+) -> ResultEnvelope<ScanStreamSummaryV1> {
+    middleware::run(
+        state,
+        CommandContext::read("scan_stream_v1"),
+        scan_stream(state, request, send),
+    )
+    .await
+}
+
+/// Decrypts, masks and encodes one batch of a scan stream.
+fn encode_stream_batch(
+    batch: RecordBatch,
+    column_keys: &encryption::ColumnKeys,
+    masking_rules: &[MaskingRuleV1],
+    max_chunk_bytes: usize,
+) -> Result<Vec<(Vec<u8>, usize)>, String> {
+    let batches = encryption::decrypt_batches(vec![batch], column_keys)?;
+    let mut chunks = Vec::new();
+    for batch in masking::mask_batches(batches, masking_rules)? {
+        if batch.num_rows() > 0 {
+            chunks.extend(arrow_ipc_chunks(&batch, max_chunk_bytes)?);
+        }
+    }
+    Ok(chunks)
+}
+
+/// Waits until the frontend has acknowledged enough chunks for `sent + 1` to go out.
+/// Returns false when the stream was cancelled; fails once `stall` passes without
+/// an acknowledgement.
+async fn wait_for_credit(
+    state: &AppState,
+    stream_id: &str,
+    sent: u64,
+    window: u32,
+    stall: Duration,
+) -> Result<bool, String> {
+    let started_at = Instant::now();
+    loop {
+        let (acked, cancelled) = match state.scan_streams.lock() {
+            Ok(streams) => streams.cursor(stream_id).unwrap_or((sent, true)),
+            Err(_) => return Err("failed to lock scan stream registry".to_string()),
+        };
+        if cancelled {
+            return Ok(false);
+        }
+        if scan_stream::has_credit(sent, acked, window) {
+            return Ok(true);
+        }
+        if started_at.elapsed() >= stall {
+            return Err(format!(
+                "no chunk acknowledged for {} ms after chunk {acked}",
+                stall.as_millis()
+            ));
+        }
+        tokio::time::sleep(scan_stream::ACK_POLL_INTERVAL).await;
+    }
+}
+
+async fn scan_stream(
+    state: &AppState,
+    mut request: ScanStreamRequestV1,
+    mut send: impl FnMut(ScanStreamMessage) -> Result<(), String>,
+) -> ResultEnvelope<ScanStreamSummaryV1> {
+    let started_at = Instant::now();
+    info!(
+        "scan_stream_v1 start table_id={} limit={:?} offset={:?} as_of_version={:?} window={:?}",
+        request.table_id, request.limit, request.offset, request.as_of_version, request.window
+    );
+    if let Err(envelope) = resolve_projection_preset(
+        state,
+        &request.table_id,
+        &mut request.projection,
+        &mut request.projection_preset,
+        "scan_stream_v1",
+    ) {
+        return envelope;
+    }
+    if request.max_chunk_bytes == Some(0) {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            "maxChunkBytes must be greater than zero",
+        );
+    }
+    let window = request.window.unwrap_or(scan_stream::DEFAULT_WINDOW);
+    if window == 0 || window > scan_stream::MAX_WINDOW {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            format!("window must be between 1 and {}", scan_stream::MAX_WINDOW),
+        );
+    }
+
+    let table = match resolve_table_version(
+        state,
+        &request.table_id,
+        request.as_of_version,
+        "scan_stream_v1",
+    )
+    .await
+    {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+    let version = match request.as_of_version {
+        Some(version) => version,
+        None => match table.version().await {
+            Ok(version) => version,
+            Err(error) => {
+                error!(
+                    "scan_stream_v1 failed to read version table_id={} error={}",
+                    request.table_id, error
+                );
+                return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+            }
+        },
+    };
+
+    let masking_rules = if request.apply_masking {
+        match table_masking_rules(state, &request.table_id) {
+            Ok(rules) => rules,
+            Err(message) => {
+                error!("scan_stream_v1 failed to read masking rules");
+                return ResultEnvelope::err_message(ErrorCode::Internal, message);
+            }
+        }
+    } else {
+        Vec::new()
+    };
+    if let Some(projection) = &request.projection {
+        if let Err(error) = projection::check_masked_columns(projection, &masking_rules) {
+            return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
+        }
+    }
+    let column_keys = match table_column_keys(state, &request.table_id, false) {
+        Ok(keys) => keys,
+        Err(error) => {
+            error!("scan_stream_v1 failed to read encryption keys");
+            return ResultEnvelope::err_envelope(error);
+        }
+    };
+
+    let stream_id = match state.scan_streams.lock() {
+        Ok(mut streams) => streams.open(),
+        Err(_) => {
+            error!("scan_stream_v1 failed to lock scan stream registry");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+    let options = QueryOptions {
+        projection: request.projection,
+        filter: sanitize_filter(request.filter),
+        limit: request.limit,
+        offset: request.offset,
+    };
+    let query = apply_query_options(table.query(), &options);
+    let max_chunk_bytes = request
+        .max_chunk_bytes
+        .unwrap_or(scan_stream::DEFAULT_CHUNK_BYTES);
+    // A stream may run for longer than a query timeout; the timeout bounds starting
+    // the query and each wait for the frontend instead.
+    let query_timeout = app_config(state).query_timeout();
+    let stall = query_timeout.unwrap_or(scan_stream::ACK_TIMEOUT);
+    let mut summary = ScanStreamSummaryV1 {
+        stream_id: stream_id.clone(),
+        version,
+        total_rows: 0,
+        chunks: 0,
+        total_bytes: 0,
+        cancelled: false,
+    };
+
+    let streamed = async {
+        send(ScanStreamMessage::Event(ScanStreamEventV1::Started(
+            ScanStreamStartedV1 {
+                stream_id: stream_id.clone(),
+                version,
+                window,
+            },
+        )))?;
+        let mut batches = with_timeout(query_timeout, async {
+            query.execute().await.map_err(|error| error.to_string())
+        })
+        .await?;
+        'batches: while let Some(batch) = batches
+            .try_next()
+            .await
+            .map_err(|error| error.to_string())?
+        {
+            let bytes = memory::batch_bytes(&batch);
+            memory::retain(bytes);
+            let chunks = encode_stream_batch(batch, &column_keys, &masking_rules, max_chunk_bytes);
+            memory::release(bytes);
+            for (chunk, rows) in chunks? {
+                if !wait_for_credit(state, &stream_id, summary.chunks, window, stall).await? {
+                    summary.cancelled = true;
+                    break 'batches;
+                }
+                summary.total_bytes += chunk.len() as u64;
+                send(ScanStreamMessage::Chunk(chunk))?;
+                summary.chunks += 1;
+                summary.total_rows += rows as u64;
+            }
+        }
+        send(ScanStreamMessage::Event(ScanStreamEventV1::Completed(
+            summary.clone(),
+        )))
+    }
+    .await;
+    if let Ok(mut streams) = state.scan_streams.lock() {
+        streams.close(&stream_id);
+    }
+
+    match streamed {
+        Ok(()) => {
+            info!(
+                "scan_stream_v1 ok table_id={} stream_id={} rows={} chunks={} bytes={} cancelled={} elapsed_ms={}",
+                request.table_id,
+                stream_id,
+                summary.total_rows,
+                summary.chunks,
+                summary.total_bytes,
+                summary.cancelled,
+                started_at.elapsed().as_millis()
+            );
+            ResultEnvelope::ok(summary)
+        }
+        Err(error) => {
+            error!(
+                "scan_stream_v1 failed table_id={} stream_id={} chunks={} error={}",
+                request.table_id, stream_id, summary.chunks, error
+            );
+            ResultEnvelope::err(ErrorCode::Internal, error)
+        }
+    }
+}
+
+pub async fn ack_scan_stream_v1(
+    state: &AppState,
+    request: AckScanStreamRequestV1,
+) -> ResultEnvelope<ScanStreamControlResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("ack_scan_stream_v1"),
+        control_scan_stream(state, request.stream_id, Some(request.sequence)),
+    )
+    .await
+}
+
+pub async fn cancel_scan_stream_v1(
+    state: &AppState,
+    request: CancelScanStreamRequestV1,
+) -> ResultEnvelope<ScanStreamControlResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("cancel_scan_stream_v1"),
+        control_scan_stream(state, request.stream_id, None),
+    )
+    .await
+}
+
+/// Acknowledges chunks up to `sequence`, or cancels the stream without one.
+async fn control_scan_stream(
+    state: &AppState,
+    stream_id: String,
+    sequence: Option<u64>,
+) -> ResultEnvelope<ScanStreamControlResponseV1> {
+    let active = match state.scan_streams.lock() {
+        Ok(mut streams) => match sequence {
+            Some(sequence) => streams.ack(&stream_id, sequence),
+            None => streams.cancel(&stream_id),
+        },
+        Err(_) => {
+            error!("scan stream control failed to lock scan stream registry");
+            return ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            );
+        }
+    };
+    if sequence.is_none() && active {
+        info!("cancel_scan_stream_v1 cancelled stream_id={}", stream_id);
+    }
+    ResultEnvelope::ok(ScanStreamControlResponseV1 { stream_id, active })
+}
+
+pub async fn estimate_query_v1(
+    state: &AppState,
+    request: EstimateQueryRequestV1,
+) -> ResultEnvelope<EstimateQueryResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("estimate_query_v1"),
+        estimate_query(state, request),
+    )
+    .await
+}
+
+/// Plans the query without running it and scales the table statistics by what the
+/// planned scan reads.
+async fn plan_estimate(
+    table: &Table,
+    table_id: String,
+    options: &QueryOptions,
+) -> Result<EstimateQueryResponseV1, String> {
+    let stats = table.stats().await.map_err(|error| error.to_string())?;
+    let schema = table.schema().await.map_err(|error| error.to_string())?;
+    let plan = apply_query_options(table.query(), options)
+        .explain_plan(true)
+        .await
+        .map_err(|error| error.to_string())?;
+
+    let footprint = query_stats::TableFootprint {
+        rows: stats.num_rows as u64,
+        fragments: stats.fragment_stats.num_fragments as u64,
+        bytes: stats.total_bytes as u64,
+    };
+    let read = query_stats::planned_read(&plan);
+    // Without a filter the scan stops once offset + limit rows are read.
+    let row_cap = match (&options.filter, options.limit) {
+        (None, Some(limit)) => Some(options.offset.unwrap_or(0).saturating_add(limit) as u64),
+        _ => None,
+    };
+    let estimate = query_stats::estimate_scan(schema.as_ref(), footprint, &read, row_cap);
+    Ok(EstimateQueryResponseV1 {
+        table_id,
+        total_rows: footprint.rows,
+        total_fragments: footprint.fragments,
+        total_bytes: footprint.bytes,
+        estimated_rows_scanned: estimate.rows,
+        estimated_fragments: estimate.fragments,
+        estimated_bytes_read: estimate.bytes,
+        columns_read: read.columns,
+        index_used: read.index_used,
+        full_scan: estimate.full_scan,
+        plan,
+    })
+}
+
+async fn estimate_query(
+    state: &AppState,
+    request: EstimateQueryRequestV1,
+) -> ResultEnvelope<EstimateQueryResponseV1> {
+    info!(
+        "estimate_query_v1 start table_id={} limit={:?} offset={:?}",
+        request.table_id, request.limit, request.offset
+    );
+    let table = match resolve_table(state, &request.table_id, "estimate_query_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+    let options = QueryOptions {
+        projection: request
+            .projection
+            .filter(|projection| !projection.is_empty()),
+        filter: request
+            .filter
+            .map(|filter| filter.trim().to_string())
+            .filter(|filter| !filter.is_empty()),
+        limit: request.limit,
+        offset: request.offset,
+    };
+
+    let config = app_config(state);
+    let estimate = with_timeout(
+        config.query_timeout(),
+        plan_estimate(&table, request.table_id.clone(), &options),
+    )
+    .await;
+    match estimate {
+        Ok(estimate) => {
+            info!(
+                "estimate_query_v1 ok table_id={} rows={} fragments={} bytes={} full_scan={}",
+                request.table_id,
+                estimate.estimated_rows_scanned,
+                estimate.estimated_fragments,
+                estimate.estimated_bytes_read,
+                estimate.full_scan
+            );
+            ResultEnvelope::ok(estimate)
+        }
+        Err(error) => {
+            warn!(
+                "estimate_query_v1 failed table_id={} error={}",
+                request.table_id, error
+            );
+            ResultEnvelope::err(ErrorCode::InvalidArgument, error)
+        }
+    }
+}
+
+pub async fn memory_stats_v1(state: &AppState) -> ResultEnvelope<MemoryStatsResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("memory_stats_v1"),
+        memory_stats(state),
+    )
+    .await
+}
+
+async fn memory_stats(state: &AppState) -> ResultEnvelope<MemoryStatsResponseV1> {
+    match state.memory_stats.lock() {
+        Ok(stats) => ResultEnvelope::ok(MemoryStatsResponseV1 {
+            commands: stats.list(),
+        }),
+        Err(_) => {
+            error!("memory_stats_v1 failed to lock memory stats");
+            ResultEnvelope::err_message(
+                ErrorCode::Internal,
+                messages::message(MessageCodeV1::StateLockFailed),
+            )
+        }
+    }
+}
+
+pub async fn list_extensions_v1(state: &AppState) -> ResultEnvelope<ListExtensionsResponseV1> {
+    middleware::run(state, CommandContext::read("list_extensions_v1"), async {
+        ResultEnvelope::ok(ListExtensionsResponseV1 {
+            extensions: extensions::extensions()
+                .iter()
+                .map(|extension| extension.describe())
+                .collect(),
+        })
+    })
+    .await
+}
+
+pub async fn query_filter_v1(
+    state: &AppState,
+    request: QueryFilterRequestV1,
+) -> ResultEnvelope<QueryResponseV1> {
+    let table_id = request.table_id.clone();
+    let summary = active_queries::summary("filter", None, Some(&request.filter));
+    middleware::run(
+        state,
+        CommandContext::read("query_filter_v1"),
+        run_as_query(
+            state,
+            "query_filter_v1",
+            &table_id,
+            summary,
+            query_filter(state, request),
+        ),
+    )
+    .await
+}
+
+async fn query_filter(
+    state: &AppState,
+    mut request: QueryFilterRequestV1,
+) -> ResultEnvelope<QueryResponseV1> {
+    let started_at = Instant::now();
+    info!(
+        "query_filter_v1 start table_id={} limit={:?} offset={:?}",
+        request.table_id, request.limit, request.offset
+    );
+    if let Err(envelope) = resolve_projection_preset(
+        state,
+        &request.table_id,
+        &mut request.projection,
+        &mut request.projection_preset,
+        "query_filter_v1",
+    ) {
+        return envelope;
+    }
+    trace!("query_filter_v1 filter=\"{}\"", request.filter);
+    if let Some(ref projection) = request.projection {
+        trace!("query_filter_v1 projection={:?}", projection);
+    }
+
+    if request.filter.trim().is_empty() {
+        warn!("query_filter_v1 empty filter table_id={}", request.table_id);
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            "filter expression cannot be empty",
+        );
+    }
+
+    let table = match resolve_table(state, &request.table_id, "query_filter_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
+    };
+
+    let fallback_schema = match table.schema().await {
+        Ok(schema) => SchemaDefinition::from_arrow_schema(schema.as_ref()),
+        Err(error) => {
+            error!(
+                "query_filter_v1 failed to read schema table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    };
+
+    let config = app_config(state);
+    let limit = request.limit.unwrap_or(config.default_page_size);
+    let offset = request.offset.unwrap_or(0);
+    let query_limit = limit.saturating_add(1);
+    let options = QueryOptions {
+        projection: request.projection,
+        filter: Some(request.filter.clone()),
+        limit: Some(query_limit),
+        offset: Some(offset),
+    };
+
+    let query = apply_query_options(table.query(), &options);
+    let stats = if request.include_stats {
+        collect_query_stats(&query, "query_filter_v1", &request.table_id).await
+    } else {
+        None
+    };
+    let json_options = request.json_options.unwrap_or_default();
+    let (mut rows, schema) = match with_timeout(
+        config.query_timeout(),
+        execute_query_json(query, fallback_schema, &json_options),
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(error) => {
+            error!(
+                "query_filter_v1 query failed table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error);
+        }
+    };
+
+    let has_more = rows.len() > limit;
+    if has_more {
+        rows.truncate(limit);
+    }
+    let next_offset = if has_more {
+        Some(offset.saturating_add(limit))
+    } else {
+        None
+    };
+
+    if offset == 0 {
+        record_filter(state, &request.table_id, &request.filter);
+    }
+
+    info!(
+        "query_filter_v1 ok table_id={} rows={} elapsed_ms={}",
+        request.table_id,
+        rows.len(),
+        started_at.elapsed().as_millis()
+    );
+
+    ResultEnvelope::ok(QueryResponseV1 {
+        chunk: DataChunk::Json(JsonChunk {
+            rows,
+            schema,
+            offset,
+            limit,
+        }),
+        next_offset,
+        search_params: None,
+        stats,
+        warnings: Vec::new(),
+        highlights: Vec::new(),
+    })
+}
+
+pub async fn combined_search_v1(
+    state: &AppState,
+    request: CombinedSearchRequestV1,
+) -> ResultEnvelope<QueryResponseV1> {
+    let table_id = request.table_id.clone();
+    let summary = active_queries::summary(
+        "combined search",
+        request.query.as_deref(),
+        request.filter.as_deref(),
+    );
+    middleware::run(
+        state,
+        CommandContext::read("combined_search_v1"),
+        run_as_query(
+            state,
+            "combined_search_v1",
+            &table_id,
+            summary,
+            combined_search(state, request),
+        ),
+    )
+    .await
+}
+
+async fn combined_search(
+    state: &AppState,
+    mut request: CombinedSearchRequestV1,
+) -> ResultEnvelope<QueryResponseV1> {
+    let started_at = Instant::now();
+    info!(
+        "combined_search_v1 start table_id={} limit={:?} offset={:?}",
+        request.table_id, request.limit, request.offset
+    );
+    if let Err(envelope) = resolve_projection_preset(
+        state,
+        &request.table_id,
+        &mut request.projection,
+        &mut request.projection_preset,
+        "combined_search_v1",
+    ) {
+        return envelope;
+    }
+
+    let has_vector = request
+        .vector
+        .as_ref()
+        .map(|vector| !vector.is_empty())
+        .unwrap_or(false);
+    let query_text = request
+        .query
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+
+    if !has_vector || query_text.is_none() {
+        warn!(
+            "combined_search_v1 missing hybrid input table_id={} has_vector={} has_query={}",
+            request.table_id,
+            has_vector,
+            query_text.is_some()
+        );
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            "hybrid search requires both vector and query text; use vector_search_v1 or fts_search_v1 for single-mode search",
+        );
+    }
+    let query_text = query_text.unwrap_or_default().to_string();
+
+    if request.vector.as_ref().map(Vec::is_empty).unwrap_or(true) {
+        warn!(
+            "combined_search_v1 empty vector table_id={}",
+            request.table_id
+        );
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            "hybrid search requires a non-empty vector",
+        );
+    }
+    if let Some(Err(error)) = request
+        .reranker
+        .as_ref()
+        .map(|reranker| reranking::validate(reranker, true))
+    {
+        warn!(
+            "combined_search_v1 invalid reranker table_id={} error={}",
+            request.table_id, error
+        );
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
     }
 
-    let table = match state.connections.lock() {
-        Ok(manager) => manager.get_table(&request.table_id),
-        Err(_) => {
-            error!("scan_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
-        }
+    let table = match resolve_table_version(
+        state,
+        &request.table_id,
+        request.as_of_version,
+        "combined_search_v1",
+    )
+    .await
+    {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
     };
 
-    let Some(table) = table else {
-        warn!("scan_v1 table not found table_id={}", request.table_id);
-        return ResultEnvelope::err(ErrorCode::NotFound, "table not found");
+    let arrow_schema = match table.schema().await {
+        Ok(schema) => schema,
+        Err(error) => {
+            error!(
+                "combined_search_v1 failed to read schema table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
     };
+    let fallback_schema = SchemaDefinition::from_arrow_schema(arrow_schema.as_ref());
 
-    let limit = request.limit.unwrap_or(100);
+    let limit = request.limit.unwrap_or(50);
     let offset = request.offset.unwrap_or(0);
-    let projection = request.projection.clone();
-    let filter = request.filter.clone();
-    let query_limit = limit.saturating_add(1);
+    // RRF runs inside the hybrid query. Other rerankers reorder a pool of
+    // RRF-fused candidates, which is paged afterwards.
+    let (rrf, custom_reranker) = match request.reranker {
+        None => (RRFReranker::default(), None),
+        Some(RerankerV1::Rrf(config)) => (config.k.map(RRFReranker::new).unwrap_or_default(), None),
+        Some(reranker) => (RRFReranker::default(), Some(reranker)),
+    };
+    let (query_limit, query_offset) = match custom_reranker {
+        Some(_) => match reranking::candidate_limit(offset, limit) {
+            Ok(candidates) => (candidates, 0),
+            Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
+        },
+        None => (limit.saturating_add(1), offset),
+    };
+    let projection = request
+        .projection
+        .as_ref()
+        .filter(|value| !value.is_empty())
+        .cloned();
+    let filter = request.filter.as_ref().and_then(|value| {
+        let trimmed = value.trim();
+        if trimmed.is_empty() {
+            None
+        } else {
+            Some(trimmed.to_string())
+        }
+    });
 
-    let fallback_schema = match table.schema().await {
-        Ok(schema) => schema,
+    let vector_column = request
+        .vector_column
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+    let fts_columns = request.columns.unwrap_or_default();
+    if let Some(indexed) = fts_indexed_columns(&table).await {
+        if let Err(envelope) = check_fts_columns(&arrow_schema, &indexed, &fts_columns) {
+            warn!(
+                "combined_search_v1 columns not indexed table_id={} columns={:?} indexed={:?}",
+                request.table_id, fts_columns, indexed
+            );
+            return envelope;
+        }
+    }
+    let model_warning = embedding_model_warning(
+        &fallback_schema,
+        vector_column.as_deref(),
+        request.query_embedding.as_ref(),
+    );
+    let mut vector = request.vector.unwrap_or_default();
+    if request.normalize_query {
+        normalization::l2_normalize(&mut vector);
+    }
+    let normalization_warning =
+        normalization_warning(&fallback_schema, vector_column.as_deref(), &vector, None);
+    let mut fts_query = FullTextSearchQuery::new(query_text.clone());
+    if !fts_columns.is_empty() {
+        fts_query = match fts_query.with_columns(&fts_columns) {
+            Ok(query) => query,
+            Err(error) => {
+                error!(
+                    "combined_search_v1 invalid columns table_id={} error={}",
+                    request.table_id, error
+                );
+                return ResultEnvelope::err(ErrorCode::InvalidArgument, error.to_string());
+            }
+        };
+    }
+
+    let mut hybrid_query = match table.query().nearest_to(vector) {
+        Ok(query) => query,
         Err(error) => {
             error!(
-                "scan_v1 failed to read schema table_id={} error={}",
+                "combined_search_v1 invalid vector query table_id={} error={}",
                 request.table_id, error
             );
-            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+            return ResultEnvelope::err(ErrorCode::InvalidArgument, error.to_string());
         }
     };
 
+    if let Some(column) = vector_column.as_deref() {
+        hybrid_query = hybrid_query.column(column);
+    }
+    let search_params = resolve_vector_search_params(
+        &table,
+        vector_column.as_deref(),
+        request.nprobes,
+        request.refine_factor,
+    )
+    .await;
+    if let Some(nprobes) = search_params.nprobes {
+        hybrid_query = hybrid_query.nprobes(nprobes);
+    }
+    if let Some(refine_factor) = search_params.refine_factor {
+        hybrid_query = hybrid_query.refine_factor(refine_factor);
+    }
+
     let options = QueryOptions {
         projection,
         filter,
         limit: Some(query_limit),
-        offset: Some(offset),
+        offset: Some(query_offset),
     };
+    let query = apply_query_options(
+        hybrid_query
+            .full_text_search(fts_query)
+            .rerank(Arc::new(rrf))
+            .norm(NormalizeMethod::Rank),
+        &options,
+    );
+    let stats = if request.include_stats {
+        collect_query_stats(&query, "combined_search_v1", &request.table_id).await
+    } else {
+        None
+    };
+    let json_options = request.json_options.unwrap_or_default();
+    let (mut rows, mut schema) =
+        match execute_query_json(query, fallback_schema, &json_options).await {
+            Ok(result) => result,
+            Err(error) => {
+                error!(
+                    "combined_search_v1 hybrid query failed table_id={} error={}",
+                    request.table_id, error
+                );
+                return ResultEnvelope::err(ErrorCode::Internal, error);
+            }
+        };
 
-    let query = apply_query_options(table.query(), &options);
-
-    match request.format {
-        DataFormat::Json => {
-            let fallback_definition = SchemaDefinition::from_arrow_schema(fallback_schema.as_ref());
-            let (mut rows, schema) = match execute_query_json(query, fallback_definition).await {
-                Ok(result) => result,
+    let has_more = match custom_reranker.as_ref() {
+        Some(reranker) => {
+            if let Some(column) = missing_rerank_column(reranker, &schema) {
+                return ResultEnvelope::err(
+                    ErrorCode::InvalidArgument,
+                    format!("reranker column {column} is not in the search results"),
+                );
+            }
+            match rerank_rows(reranker, &query_text, rows, &mut schema, offset, limit).await {
+                Ok((page, has_more)) => {
+                    rows = page;
+                    has_more
+                }
                 Err(error) => {
                     error!(
-                        "scan_v1 query failed table_id={} error={}",
-                        request.table_id, error
+                        "combined_search_v1 rerank failed table_id={} reranker={} error={}",
+                        request.table_id,
+                        reranker.name(),
+                        error
                     );
                     return ResultEnvelope::err(ErrorCode::Internal, error);
                 }
-            };
-
+            }
+        }
+        None => {
             let has_more = rows.len() > limit;
             if has_more {
                 rows.truncate(limit);
             }
-            let next_offset = if has_more {
-                Some(offset.saturating_add(limit))
-            } else {
-                None
-            };
-
-            info!(
-                "scan_v1 ok table_id={} rows={} next_offset={:?} elapsed_ms={}",
-                request.table_id,
-                rows.len(),
-                next_offset,
-                started_at.elapsed().as_millis()
-            );
-
-            ResultEnvelope::ok(ScanResponseV1 {
-                chunk: DataChunk::Json(JsonChunk {
-                    rows,
-                    schema,
-                    offset,
-                    limit,
-                }),
-                next_offset,
-            })
+            has_more
         }
-        DataFormat::Arrow => {
-            let batches = match execute_query_batches(query).await {
-                Ok(result) => result,
-                Err(error) => {
-                    error!(
-                        "scan_v1 query failed table_id={} error={}",
-                        request.table_id, error
-                    );
-                    return ResultEnvelope::err(ErrorCode::Internal, error);
-                }
-            };
-
-            let output_schema = batches
-                .first()
-                .map(|batch| batch.schema())
-                .unwrap_or_else(|| fallback_schema.clone());
-            let total_rows: usize = batches.iter().map(|batch| batch.num_rows()).sum();
-            let has_more = total_rows > limit;
-            let trimmed = if has_more {
-                truncate_batches(&batches, limit)
-            } else {
-                batches
-            };
-
-            let ipc_base64 = match batches_to_arrow_ipc_base64(&trimmed, output_schema.as_ref()) {
-                Ok(payload) => payload,
-                Err(error) => {
-                    error!(
-                        "scan_v1 arrow encode failed table_id={} error={}",
-                        request.table_id, error
-                    );
-                    return ResultEnvelope::err(ErrorCode::Internal, error);
-                }
-            };
+    };
+    let source = custom_reranker.as_ref().map_or("rrf", RerankerV1::name);
+    annotate_hybrid_rows(&mut rows, &mut schema, offset, source);
+    let next_offset = if has_more {
+        Some(offset.saturating_add(limit))
+    } else {
+        None
+    };
+    let mut warnings = index_coverage_warnings(
+        &table,
+        SearchIndexKindV1::Vector,
+        &vector_column.into_iter().collect::<Vec<_>>(),
+    )
+    .await;
+    warnings.extend(index_coverage_warnings(&table, SearchIndexKindV1::Fts, &fts_columns).await);
+    warnings.extend(model_warning);
+    warnings.extend(normalization_warning);
 
-            let next_offset = if has_more {
-                Some(offset.saturating_add(limit))
-            } else {
-                None
-            };
+    info!(
+        "combined_search_v1 ok table_id={} rows={} nprobes={:?} refine_factor={:?} elapsed_ms={}",
+        request.table_id,
+        rows.len(),
+        search_params.nprobes,
+        search_params.refine_factor,
+        started_at.elapsed().as_millis()
+    );
 
-            info!(
-                "scan_v1 ok arrow table_id={} rows={} next_offset={:?} elapsed_ms={}",
-                request.table_id,
-                total_rows.min(limit),
-                next_offset,
-                started_at.elapsed().as_millis()
-            );
+    ResultEnvelope::ok(QueryResponseV1 {
+        chunk: DataChunk::Json(JsonChunk {
+            rows,
+            schema,
+            offset,
+            limit,
+        }),
+        next_offset,
+        search_params: Some(search_params),
+        stats,
+        warnings,
+        highlights: Vec::new(),
+    })
+}
 
-            ResultEnvelope::ok(ScanResponseV1 {
-                chunk: DataChunk::Arrow(ArrowChunk {
-                    ipc_base64,
-                    compression: None,
-                }),
-                next_offset,
-            })
-        }
-    }
+pub async fn vector_search_v1(
+    state: &AppState,
+    request: VectorSearchRequestV1,
+) -> ResultEnvelope<QueryResponseV1> {
+    let table_id = request.table_id.clone();
+    let summary = active_queries::summary(
+        "vector search",
+        request.column.as_deref(),
+        request.filter.as_deref(),
+    );
+    middleware::run(
+        state,
+        CommandContext::read("vector_search_v1"),
+        run_as_query(
+            state,
+            "vector_search_v1",
+            &table_id,
+            summary,
+            vector_search(state, request),
+        ),
+    )
+    .await
 }
 
-pub async fn query_filter_v1(
+async fn vector_search(
     state: &AppState,
-    request: QueryFilterRequestV1,
+    mut request: VectorSearchRequestV1,
 ) -> ResultEnvelope<QueryResponseV1> {
     let started_at = Instant::now();
     info!(
-        "query_filter_v1 start table_id={} limit={:?} offset={:?}",
-        request.table_id, request.limit, request.offset
+        "vector_search_v1 start table_id={} vector_len={} top_k={:?} offset={:?}",
+        request.table_id,
+        request.vector.len(),
+        request.top_k,
+        request.offset
     );
-    trace!("query_filter_v1 filter=\"{}\"", request.filter);
+    if let Err(envelope) = resolve_projection_preset(
+        state,
+        &request.table_id,
+        &mut request.projection,
+        &mut request.projection_preset,
+        "vector_search_v1",
+    ) {
+        return envelope;
+    }
+    if let Some(ref column) = request.column {
+        trace!("vector_search_v1 column=\"{}\"", column);
+    }
     if let Some(ref projection) = request.projection {
-        trace!("query_filter_v1 projection={:?}", projection);
+        trace!("vector_search_v1 projection={:?}", projection);
     }
-
-    if request.filter.trim().is_empty() {
-        warn!("query_filter_v1 empty filter table_id={}", request.table_id);
-        return ResultEnvelope::err(
-            ErrorCode::InvalidArgument,
-            "filter expression cannot be empty",
-        );
+    if let Some(ref filter) = request.filter {
+        trace!("vector_search_v1 filter=\"{}\"", filter);
+    }
+    if let Some(nprobes) = request.nprobes {
+        trace!("vector_search_v1 nprobes={}", nprobes);
+    }
+    if let Some(refine_factor) = request.refine_factor {
+        trace!("vector_search_v1 refine_factor={}", refine_factor);
+    }
+    if let Some(ref distance_type) = request.distance_type {
+        trace!("vector_search_v1 distance_type={:?}", distance_type);
     }
 
-    let table = match state.connections.lock() {
-        Ok(manager) => manager.get_table(&request.table_id),
-        Err(_) => {
-            error!("query_filter_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
-        }
-    };
-
-    let Some(table) = table else {
+    if request.vector.is_empty() {
         warn!(
-            "query_filter_v1 table not found table_id={}",
+            "vector_search_v1 empty vector table_id={}",
             request.table_id
         );
-        return ResultEnvelope::err(ErrorCode::NotFound, "table not found");
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, "vector must not be empty");
+    }
+
+    let table = match resolve_table_version(
+        state,
+        &request.table_id,
+        request.as_of_version,
+        "vector_search_v1",
+    )
+    .await
+    {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
     };
 
     let fallback_schema = match table.schema().await {
         Ok(schema) => SchemaDefinition::from_arrow_schema(schema.as_ref()),
         Err(error) => {
             error!(
-                "query_filter_v1 failed to read schema table_id={} error={}",
+                "vector_search_v1 failed to read schema table_id={} error={}",
                 request.table_id, error
             );
             return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
         }
     };
 
-    let limit = request.limit.unwrap_or(100);
+    let model_warning = embedding_model_warning(
+        &fallback_schema,
+        request.column.as_deref(),
+        request.query_embedding.as_ref(),
+    );
+    let mut vector = request.vector;
+    if request.normalize_query {
+        normalization::l2_normalize(&mut vector);
+    }
+    let normalization_warning = normalization_warning(
+        &fallback_schema,
+        request.column.as_deref(),
+        &vector,
+        request.distance_type.as_ref(),
+    );
+
+    let mut vector_query = match table.query().nearest_to(vector) {
+        Ok(query) => query,
+        Err(error) => {
+            error!(
+                "vector_search_v1 invalid vector query table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::InvalidArgument, error.to_string());
+        }
+    };
+
+    if let Some(column) = request.column.as_deref() {
+        vector_query = vector_query.column(column);
+    }
+
+    let search_params = resolve_vector_search_params(
+        &table,
+        request.column.as_deref(),
+        request.nprobes,
+        request.refine_factor,
+    )
+    .await;
+    if let Some(nprobes) = search_params.nprobes {
+        vector_query = vector_query.nprobes(nprobes);
+    }
+
+    if let Some(refine_factor) = search_params.refine_factor {
+        vector_query = vector_query.refine_factor(refine_factor);
+    }
+
+    if let Some(distance_type) = request.distance_type.as_ref() {
+        vector_query = vector_query.distance_type(to_lancedb_distance_type(distance_type));
+    }
+
+    let limit = request.top_k.unwrap_or(10);
     let offset = request.offset.unwrap_or(0);
     let query_limit = limit.saturating_add(1);
     let options = QueryOptions {
         projection: request.projection,
-        filter: Some(request.filter),
+        filter: request.filter,
         limit: Some(query_limit),
         offset: Some(offset),
     };
 
-    let query = apply_query_options(table.query(), &options);
-    let (mut rows, schema) = match execute_query_json(query, fallback_schema).await {
+    let query = apply_query_options(vector_query, &options);
+    let stats = if request.include_stats {
+        collect_query_stats(&query, "vector_search_v1", &request.table_id).await
+    } else {
+        None
+    };
+    let json_options = request.json_options.unwrap_or_default();
+    let (mut rows, schema) = match execute_query_json(query, fallback_schema, &json_options).await {
         Ok(result) => result,
         Err(error) => {
             error!(
-                "query_filter_v1 query failed table_id={} error={}",
+                "vector_search_v1 query failed table_id={} error={}",
                 request.table_id, error
             );
             return ResultEnvelope::err(ErrorCode::Internal, error);
@@ -3172,11 +13713,21 @@ pub async fn query_filter_v1(
     } else {
         None
     };
+    let mut warnings = index_coverage_warnings(
+        &table,
+        SearchIndexKindV1::Vector,
+        &request.column.into_iter().collect::<Vec<_>>(),
+    )
+    .await;
+    warnings.extend(model_warning);
+    warnings.extend(normalization_warning);
 
     info!(
-        "query_filter_v1 ok table_id={} rows={} elapsed_ms={}",
+        "vector_search_v1 ok table_id={} rows={} nprobes={:?} refine_factor={:?} elapsed_ms={}",
         request.table_id,
         rows.len(),
+        search_params.nprobes,
+        search_params.refine_factor,
         started_at.elapsed().as_millis()
     );
 
@@ -3188,316 +13739,584 @@ pub async fn query_filter_v1(
             limit,
         }),
         next_offset,
+        search_params: Some(search_params),
+        stats,
+        warnings,
+        highlights: Vec::new(),
     })
 }
 
-pub async fn combined_search_v1(
+/// Bounds of `evaluate_recall_v1`. Every sampled query also runs as an exact search
+/// over the whole table, so the sample is kept small.
+const DEFAULT_RECALL_SAMPLES: usize = 20;
+const MAX_RECALL_SAMPLES: usize = 200;
+const DEFAULT_RECALL_K: usize = 10;
+const MAX_RECALL_K: usize = 100;
+const MAX_RECALL_SETTINGS: usize = 16;
+
+pub async fn evaluate_recall_v1(
     state: &AppState,
-    request: CombinedSearchRequestV1,
-) -> ResultEnvelope<QueryResponseV1> {
+    request: EvaluateRecallRequestV1,
+) -> ResultEnvelope<EvaluateRecallResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("evaluate_recall_v1"),
+        evaluate_recall(state, request),
+    )
+    .await
+}
+
+/// Row ids of the `k` nearest rows and how long the search took.
+async fn nearest_row_ids(query: VectorQuery, k: usize) -> Result<(Vec<u64>, Duration), String> {
+    let started_at = Instant::now();
+    let batches = execute_query_batches(
+        query
+            .with_row_id()
+            .select(Select::columns(&[ROW_ID_COLUMN]))
+            .limit(k),
+    )
+    .await?;
+    Ok((row_ids(&batches)?, started_at.elapsed()))
+}
+
+async fn evaluate_recall(
+    state: &AppState,
+    request: EvaluateRecallRequestV1,
+) -> ResultEnvelope<EvaluateRecallResponseV1> {
     let started_at = Instant::now();
     info!(
-        "combined_search_v1 start table_id={} limit={:?} offset={:?}",
-        request.table_id, request.limit, request.offset
+        "evaluate_recall_v1 start table_id={} column=\"{}\" sample_queries={:?} k={:?}",
+        request.table_id, request.column, request.sample_queries, request.k
     );
 
-    let has_vector = request
-        .vector
-        .as_ref()
-        .map(|vector| !vector.is_empty())
-        .unwrap_or(false);
-    let query_text = request
-        .query
-        .as_deref()
-        .map(str::trim)
-        .filter(|value| !value.is_empty());
-
-    if !has_vector || query_text.is_none() {
-        warn!(
-            "combined_search_v1 missing hybrid input table_id={} has_vector={} has_query={}",
-            request.table_id,
-            has_vector,
-            query_text.is_some()
-        );
+    let column = request.column.trim().to_string();
+    if column.is_empty() {
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, "column cannot be empty");
+    }
+    let samples = request.sample_queries.unwrap_or(DEFAULT_RECALL_SAMPLES);
+    if samples == 0 || samples > MAX_RECALL_SAMPLES {
         return ResultEnvelope::err(
             ErrorCode::InvalidArgument,
-            "hybrid search requires both vector and query text; use vector_search_v1 or fts_search_v1 for single-mode search",
+            format!("sampleQueries must be between 1 and {MAX_RECALL_SAMPLES}"),
         );
     }
-    let query_text = query_text.unwrap_or_default().to_string();
-
-    if request.vector.as_ref().map(Vec::is_empty).unwrap_or(true) {
-        warn!(
-            "combined_search_v1 empty vector table_id={}",
-            request.table_id
-        );
+    let k = request.k.unwrap_or(DEFAULT_RECALL_K);
+    if k == 0 || k > MAX_RECALL_K {
         return ResultEnvelope::err(
             ErrorCode::InvalidArgument,
-            "hybrid search requires a non-empty vector",
+            format!("k must be between 1 and {MAX_RECALL_K}"),
         );
     }
-
-    let table = match state.connections.lock() {
-        Ok(manager) => manager.get_table(&request.table_id),
-        Err(_) => {
-            error!("combined_search_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
+    if let Some(settings) = request.nprobes.as_ref() {
+        if settings.is_empty() || settings.len() > MAX_RECALL_SETTINGS {
+            return ResultEnvelope::err(
+                ErrorCode::InvalidArgument,
+                format!("nprobes must list between 1 and {MAX_RECALL_SETTINGS} settings"),
+            );
         }
-    };
+        if settings.contains(&0) {
+            return ResultEnvelope::err(
+                ErrorCode::InvalidArgument,
+                "nprobes must be greater than 0",
+            );
+        }
+    }
 
-    let Some(table) = table else {
-        warn!(
-            "combined_search_v1 table not found table_id={}",
-            request.table_id
-        );
-        return ResultEnvelope::err(ErrorCode::NotFound, "table not found");
+    let table = match resolve_table(state, &request.table_id, "evaluate_recall_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
     };
 
-    let fallback_schema = match table.schema().await {
-        Ok(schema) => SchemaDefinition::from_arrow_schema(schema.as_ref()),
+    let configs = match table.list_indices().await {
+        Ok(configs) => configs,
         Err(error) => {
             error!(
-                "combined_search_v1 failed to read schema table_id={} error={}",
+                "evaluate_recall_v1 failed to list indexes table_id={} error={}",
                 request.table_id, error
             );
             return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
         }
     };
-
-    let limit = request.limit.unwrap_or(50);
-    let offset = request.offset.unwrap_or(0);
-    let query_limit = limit.saturating_add(1);
-    let projection = request
-        .projection
-        .as_ref()
-        .filter(|value| !value.is_empty())
-        .cloned();
-    let filter = request.filter.as_ref().and_then(|value| {
-        let trimmed = value.trim();
-        if trimmed.is_empty() {
+    let Some(config) = configs.into_iter().find(|config| {
+        is_vector_index_type(&config.index_type)
+            && config.columns.len() == 1
+            && config.columns[0] == column
+    }) else {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            format!("column {column} has no vector index to evaluate"),
+        );
+    };
+    let stats = match table.index_stats(&config.name).await {
+        Ok(stats) => stats,
+        Err(error) => {
+            warn!(
+                "evaluate_recall_v1 failed to read index stats table_id={} index={} error={}",
+                request.table_id, config.name, error
+            );
             None
-        } else {
-            Some(trimmed.to_string())
         }
-    });
+    };
+    let distance_type = stats
+        .as_ref()
+        .and_then(|stats| stats.distance_type)
+        .unwrap_or(DistanceType::L2);
+    let estimated_partitions = stats
+        .as_ref()
+        .map(|stats| estimated_num_partitions(stats.num_indexed_rows));
+    let refine_factor = request
+        .refine_factor
+        .or_else(|| default_refine_factor(&config.index_type));
+    let mut settings = request
+        .nprobes
+        .unwrap_or_else(|| recall::nprobes_ladder(estimated_partitions.unwrap_or(1)));
+    settings.sort_unstable();
+    settings.dedup();
+
+    let row_count = match table.count_rows(None).await {
+        Ok(count) => count,
+        Err(error) => {
+            error!(
+                "evaluate_recall_v1 failed to count rows table_id={} error={}",
+                request.table_id, error
+            );
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
+        }
+    };
+    let mut queries = Vec::new();
+    for offset in recall::sample_offsets(row_count, samples) {
+        let sample = table
+            .query()
+            .select(Select::columns(&[column.as_str()]))
+            .offset(offset)
+            .limit(1);
+        let vectors = match execute_query_batches(sample).await {
+            Ok(batches) => recall::vectors(&batches, &column),
+            Err(error) => Err(error),
+        };
+        match vectors {
+            Ok(vectors) => queries.extend(vectors),
+            Err(error) => {
+                error!(
+                    "evaluate_recall_v1 failed to sample vectors table_id={} error={}",
+                    request.table_id, error
+                );
+                return ResultEnvelope::err(ErrorCode::Internal, error);
+            }
+        }
+    }
+    if queries.is_empty() {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            format!("column {column} has no vectors to sample"),
+        );
+    }
 
-    let mut fts_query = FullTextSearchQuery::new(query_text);
-    if let Some(columns) = request.columns.as_ref() {
-        if !columns.is_empty() {
-            fts_query = match fts_query.with_columns(columns) {
-                Ok(query) => query,
+    let search = |vector: &[f32]| -> Result<VectorQuery, String> {
+        Ok(table
+            .query()
+            .nearest_to(vector.to_vec())
+            .map_err(|error| error.to_string())?
+            .column(&column)
+            .distance_type(distance_type))
+    };
+    let mut truths = Vec::with_capacity(queries.len());
+    let mut flat_elapsed = Duration::ZERO;
+    for vector in &queries {
+        let exact = match search(vector) {
+            Ok(query) => nearest_row_ids(query.bypass_vector_index(), k).await,
+            Err(error) => Err(error),
+        };
+        match exact {
+            Ok((ids, elapsed)) => {
+                truths.push(ids);
+                flat_elapsed += elapsed;
+            }
+            Err(error) => {
+                error!(
+                    "evaluate_recall_v1 exact search failed table_id={} error={}",
+                    request.table_id, error
+                );
+                return ResultEnvelope::err(ErrorCode::Internal, error);
+            }
+        }
+    }
+    let expected = truths.iter().map(Vec::len).sum::<usize>();
+
+    let mut results = Vec::with_capacity(settings.len());
+    for nprobes in settings {
+        let mut found = 0;
+        let mut elapsed = Duration::ZERO;
+        for (vector, truth) in queries.iter().zip(&truths) {
+            let approximate = match search(vector) {
+                Ok(mut query) => {
+                    query = query.nprobes(nprobes);
+                    if let Some(refine_factor) = refine_factor {
+                        query = query.refine_factor(refine_factor);
+                    }
+                    nearest_row_ids(query, k).await
+                }
+                Err(error) => Err(error),
+            };
+            match approximate {
+                Ok((ids, query_elapsed)) => {
+                    found += recall::hits(truth, &ids);
+                    elapsed += query_elapsed;
+                }
                 Err(error) => {
                     error!(
-                        "combined_search_v1 invalid columns table_id={} error={}",
-                        request.table_id, error
+                        "evaluate_recall_v1 indexed search failed table_id={} nprobes={} error={}",
+                        request.table_id, nprobes, error
                     );
-                    return ResultEnvelope::err(ErrorCode::InvalidArgument, error.to_string());
+                    return ResultEnvelope::err(ErrorCode::Internal, error);
                 }
-            };
+            }
         }
+        results.push(RecallAtNprobesV1 {
+            nprobes,
+            recall: if expected == 0 {
+                1.0
+            } else {
+                found as f64 / expected as f64
+            },
+            mean_latency_ms: elapsed.as_secs_f64() * 1000.0 / queries.len() as f64,
+        });
     }
 
-    let mut hybrid_query = match table.query().nearest_to(request.vector.unwrap_or_default()) {
-        Ok(query) => query,
-        Err(error) => {
-            error!(
-                "combined_search_v1 invalid vector query table_id={} error={}",
-                request.table_id, error
-            );
-            return ResultEnvelope::err(ErrorCode::InvalidArgument, error.to_string());
-        }
-    };
+    info!(
+        "evaluate_recall_v1 ok table_id={} index={} queries={} settings={} elapsed_ms={}",
+        request.table_id,
+        config.name,
+        queries.len(),
+        results.len(),
+        started_at.elapsed().as_millis()
+    );
 
-    if let Some(column) = request
-        .vector_column
-        .as_deref()
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-    {
-        hybrid_query = hybrid_query.column(column);
-    }
-    if let Some(nprobes) = request.nprobes {
-        hybrid_query = hybrid_query.nprobes(nprobes);
+    ResultEnvelope::ok(EvaluateRecallResponseV1 {
+        table_id: request.table_id,
+        column,
+        index_name: config.name,
+        index_type: to_index_type_v1(&config.index_type),
+        distance_type: stats
+            .as_ref()
+            .and_then(|stats| stats.distance_type.as_ref().map(to_distance_type_v1)),
+        estimated_partitions,
+        refine_factor,
+        k,
+        sample_size: queries.len(),
+        flat_mean_latency_ms: flat_elapsed.as_secs_f64() * 1000.0 / queries.len() as f64,
+        results,
+    })
+}
+
+pub async fn estimate_embedding_job_v1(
+    state: &AppState,
+    request: EstimateEmbeddingJobRequestV1,
+) -> ResultEnvelope<EstimateEmbeddingJobResponseV1> {
+    middleware::run(
+        state,
+        CommandContext::read("estimate_embedding_job_v1"),
+        estimate_embedding_job(state, request),
+    )
+    .await
+}
+
+async fn estimate_embedding_job(
+    state: &AppState,
+    request: EstimateEmbeddingJobRequestV1,
+) -> ResultEnvelope<EstimateEmbeddingJobResponseV1> {
+    info!(
+        "estimate_embedding_job_v1 start table_id={} text_column=\"{}\" provider={} model={}",
+        request.table_id, request.text_column, request.provider.name, request.provider.model
+    );
+    let column = request.text_column.trim().to_string();
+    if column.is_empty() {
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, "textColumn cannot be empty");
     }
-    if let Some(refine_factor) = request.refine_factor {
-        hybrid_query = hybrid_query.refine_factor(refine_factor);
+    if let Err(error) = embedding_cost::validate(&request.provider) {
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
     }
 
-    let options = QueryOptions {
-        projection,
-        filter,
-        limit: Some(query_limit),
-        offset: Some(offset),
+    let table = match resolve_table(state, &request.table_id, "estimate_embedding_job_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
     };
-    let query = apply_query_options(
-        hybrid_query
-            .full_text_search(fts_query)
-            .rerank(Arc::new(RRFReranker::default()))
-            .norm(NormalizeMethod::Rank),
-        &options,
-    );
-    let (mut rows, mut schema) = match execute_query_json(query, fallback_schema).await {
-        Ok(result) => result,
+    let schema = match table.schema().await {
+        Ok(schema) => schema,
         Err(error) => {
             error!(
-                "combined_search_v1 hybrid query failed table_id={} error={}",
+                "estimate_embedding_job_v1 failed to read schema table_id={} error={}",
                 request.table_id, error
             );
-            return ResultEnvelope::err(ErrorCode::Internal, error);
+            return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
         }
     };
+    match schema.field_with_name(&column).map(Field::data_type) {
+        Ok(DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View) => {}
+        Ok(data_type) => {
+            return ResultEnvelope::err(
+                ErrorCode::InvalidArgument,
+                format!("column {column} holds {data_type}, not text"),
+            );
+        }
+        Err(_) => {
+            return ResultEnvelope::err(
+                ErrorCode::NotFound,
+                format!("column {column} does not exist"),
+            );
+        }
+    }
 
-    let has_more = rows.len() > limit;
-    if has_more {
-        rows.truncate(limit);
+    let mut query = table.query().select(Select::columns(&[column.as_str()]));
+    if let Some(filter) = sanitize_filter(request.filter) {
+        query = query.only_if(filter);
+    }
+    let max_tokens = request.provider.max_tokens_per_input;
+    let mut tally = embedding_cost::TokenTally::default();
+    let scanned = with_timeout(app_config(state).query_timeout(), async {
+        let mut stream = query.execute().await.map_err(|error| error.to_string())?;
+        while let Some(batch) = stream.try_next().await.map_err(|error| error.to_string())? {
+            let values = batch
+                .column_by_name(&column)
+                .ok_or_else(|| format!("column {column} is missing from the scan"))?;
+            tally.add_array(values, max_tokens)?;
+        }
+        Ok(())
+    })
+    .await;
+    if let Err(error) = scanned {
+        error!(
+            "estimate_embedding_job_v1 scan failed table_id={} error={}",
+            request.table_id, error
+        );
+        return ResultEnvelope::err(ErrorCode::Internal, error);
     }
-    annotate_hybrid_rows(&mut rows, &mut schema, offset);
-    let next_offset = if has_more {
-        Some(offset.saturating_add(limit))
-    } else {
-        None
-    };
 
+    let estimated_cost = tally.cost(&request.provider);
+    let estimated_seconds = tally.seconds(&request.provider);
     info!(
-        "combined_search_v1 ok table_id={} rows={} elapsed_ms={}",
-        request.table_id,
-        rows.len(),
-        started_at.elapsed().as_millis()
+        "estimate_embedding_job_v1 ok table_id={} rows={} tokens={} cost={:.4}",
+        request.table_id, tally.rows, tally.tokens, estimated_cost
     );
-
-    ResultEnvelope::ok(QueryResponseV1 {
-        chunk: DataChunk::Json(JsonChunk {
-            rows,
-            schema,
-            offset,
-            limit,
-        }),
-        next_offset,
+    ResultEnvelope::ok(EstimateEmbeddingJobResponseV1 {
+        table_id: request.table_id,
+        text_column: column,
+        provider: request.provider.name,
+        model: request.provider.model,
+        row_count: tally.rows,
+        skipped_rows: tally.skipped_rows,
+        total_chars: tally.chars,
+        estimated_tokens: tally.tokens,
+        truncated_rows: tally.truncated_rows,
+        estimated_cost,
+        estimated_seconds,
     })
 }
 
-pub async fn vector_search_v1(
+pub async fn fts_search_v1(
     state: &AppState,
-    request: VectorSearchRequestV1,
+    request: FtsSearchRequestV1,
+) -> ResultEnvelope<QueryResponseV1> {
+    let table_id = request.table_id.clone();
+    let summary = active_queries::summary(
+        "full-text search",
+        Some(&format!("{:?}", request.query)),
+        request.filter.as_deref(),
+    );
+    middleware::run(
+        state,
+        CommandContext::read("fts_search_v1"),
+        run_as_query(
+            state,
+            "fts_search_v1",
+            &table_id,
+            summary,
+            fts_search(state, request),
+        ),
+    )
+    .await
+}
+
+async fn fts_search(
+    state: &AppState,
+    mut request: FtsSearchRequestV1,
 ) -> ResultEnvelope<QueryResponseV1> {
     let started_at = Instant::now();
     info!(
-        "vector_search_v1 start table_id={} vector_len={} top_k={:?} offset={:?}",
-        request.table_id,
-        request.vector.len(),
-        request.top_k,
-        request.offset
+        "fts_search_v1 start table_id={} limit={:?} offset={:?}",
+        request.table_id, request.limit, request.offset
     );
-    if let Some(ref column) = request.column {
-        trace!("vector_search_v1 column=\"{}\"", column);
-    }
-    if let Some(ref projection) = request.projection {
-        trace!("vector_search_v1 projection={:?}", projection);
+    if let Err(envelope) = resolve_projection_preset(
+        state,
+        &request.table_id,
+        &mut request.projection,
+        &mut request.projection_preset,
+        "fts_search_v1",
+    ) {
+        return envelope;
     }
-    if let Some(ref filter) = request.filter {
-        trace!("vector_search_v1 filter=\"{}\"", filter);
+    trace!("fts_search_v1 query=\"{}\"", request.query);
+    if let Some(ref columns) = request.columns {
+        trace!("fts_search_v1 columns={:?}", columns);
     }
-    if let Some(nprobes) = request.nprobes {
-        trace!("vector_search_v1 nprobes={}", nprobes);
+    if let Some(ref projection) = request.projection {
+        trace!("fts_search_v1 projection={:?}", projection);
     }
-    if let Some(refine_factor) = request.refine_factor {
-        trace!("vector_search_v1 refine_factor={}", refine_factor);
+    if let Some(ref filter) = request.filter {
+        trace!("fts_search_v1 filter=\"{}\"", filter);
     }
 
-    if request.vector.is_empty() {
+    if request.query.trim().is_empty() {
+        warn!("fts_search_v1 empty query table_id={}", request.table_id);
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, "query text cannot be empty");
+    }
+    if let Some(Err(error)) = request
+        .reranker
+        .as_ref()
+        .map(|reranker| reranking::validate(reranker, false))
+    {
         warn!(
-            "vector_search_v1 empty vector table_id={}",
-            request.table_id
+            "fts_search_v1 invalid reranker table_id={} error={}",
+            request.table_id, error
         );
-        return ResultEnvelope::err(ErrorCode::InvalidArgument, "vector must not be empty");
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
     }
 
-    let table = match state.connections.lock() {
-        Ok(manager) => manager.get_table(&request.table_id),
-        Err(_) => {
-            error!("vector_search_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
-        }
-    };
-
-    let Some(table) = table else {
-        warn!(
-            "vector_search_v1 table not found table_id={}",
-            request.table_id
-        );
-        return ResultEnvelope::err(ErrorCode::NotFound, "table not found");
+    let table = match resolve_table_version(
+        state,
+        &request.table_id,
+        request.as_of_version,
+        "fts_search_v1",
+    )
+    .await
+    {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
     };
 
-    let fallback_schema = match table.schema().await {
-        Ok(schema) => SchemaDefinition::from_arrow_schema(schema.as_ref()),
+    let arrow_schema = match table.schema().await {
+        Ok(schema) => schema,
         Err(error) => {
             error!(
-                "vector_search_v1 failed to read schema table_id={} error={}",
+                "fts_search_v1 failed to read schema table_id={} error={}",
                 request.table_id, error
             );
             return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
         }
     };
+    let fallback_schema = SchemaDefinition::from_arrow_schema(arrow_schema.as_ref());
 
-    let mut vector_query = match table.query().nearest_to(request.vector) {
+    let mut columns = request.columns.unwrap_or_default();
+    if let Some(indexed) = fts_indexed_columns(&table).await {
+        if let Err(envelope) = check_fts_columns(&arrow_schema, &indexed, &columns) {
+            warn!(
+                "fts_search_v1 columns not indexed table_id={} columns={:?} indexed={:?}",
+                request.table_id, columns, indexed
+            );
+            return envelope;
+        }
+        if columns.is_empty() {
+            trace!("fts_search_v1 indexed columns={:?}", indexed);
+            columns = indexed;
+        }
+    }
+    let query_text = request.query.clone();
+    let fts_query = match FullTextSearchQuery::new(request.query).with_columns(&columns) {
         Ok(query) => query,
         Err(error) => {
             error!(
-                "vector_search_v1 invalid vector query table_id={} error={}",
+                "fts_search_v1 invalid columns table_id={} error={}",
                 request.table_id, error
             );
             return ResultEnvelope::err(ErrorCode::InvalidArgument, error.to_string());
         }
     };
 
-    if let Some(column) = request.column.as_deref() {
-        vector_query = vector_query.column(column);
-    }
-
-    if let Some(nprobes) = request.nprobes {
-        vector_query = vector_query.nprobes(nprobes);
-    }
-
-    if let Some(refine_factor) = request.refine_factor {
-        vector_query = vector_query.refine_factor(refine_factor);
-    }
-
-    let limit = request.top_k.unwrap_or(10);
+    let limit = request.limit.unwrap_or(100);
     let offset = request.offset.unwrap_or(0);
-    let query_limit = limit.saturating_add(1);
+    // A reranker reorders a pool of candidates, which is paged afterwards.
+    let (query_limit, query_offset) = match request.reranker {
+        Some(_) => match reranking::candidate_limit(offset, limit) {
+            Ok(candidates) => (candidates, 0),
+            Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
+        },
+        None => (limit.saturating_add(1), offset),
+    };
     let options = QueryOptions {
         projection: request.projection,
         filter: request.filter,
         limit: Some(query_limit),
-        offset: Some(offset),
+        offset: Some(query_offset),
     };
 
-    let query = apply_query_options(vector_query, &options);
-    let (mut rows, schema) = match execute_query_json(query, fallback_schema).await {
-        Ok(result) => result,
-        Err(error) => {
-            error!(
-                "vector_search_v1 query failed table_id={} error={}",
-                request.table_id, error
-            );
-            return ResultEnvelope::err(ErrorCode::Internal, error);
-        }
+    let query = apply_query_options(table.query().full_text_search(fts_query), &options);
+    let stats = if request.include_stats {
+        collect_query_stats(&query, "fts_search_v1", &request.table_id).await
+    } else {
+        None
     };
+    let json_options = request.json_options.unwrap_or_default();
+    let (mut rows, mut schema) =
+        match execute_query_json(query, fallback_schema, &json_options).await {
+            Ok(result) => result,
+            Err(error) => {
+                error!(
+                    "fts_search_v1 query failed table_id={} error={}",
+                    request.table_id, error
+                );
+                return ResultEnvelope::err(ErrorCode::Internal, error);
+            }
+        };
 
-    let has_more = rows.len() > limit;
-    if has_more {
-        rows.truncate(limit);
-    }
+    let has_more = match request.reranker.as_ref() {
+        Some(reranker) => {
+            if let Some(column) = missing_rerank_column(reranker, &schema) {
+                return ResultEnvelope::err(
+                    ErrorCode::InvalidArgument,
+                    format!("reranker column {column} is not in the search results"),
+                );
+            }
+            match rerank_rows(reranker, &query_text, rows, &mut schema, offset, limit).await {
+                Ok((page, has_more)) => {
+                    rows = page;
+                    has_more
+                }
+                Err(error) => {
+                    error!(
+                        "fts_search_v1 rerank failed table_id={} reranker={} error={}",
+                        request.table_id,
+                        reranker.name(),
+                        error
+                    );
+                    return ResultEnvelope::err(ErrorCode::Internal, error);
+                }
+            }
+        }
+        None => {
+            let has_more = rows.len() > limit;
+            if has_more {
+                rows.truncate(limit);
+            }
+            has_more
+        }
+    };
     let next_offset = if has_more {
         Some(offset.saturating_add(limit))
     } else {
         None
     };
+    let warnings = index_coverage_warnings(&table, SearchIndexKindV1::Fts, &columns).await;
+    let highlights = if request.highlight {
+        highlight::highlight_rows(&rows, &columns, &highlight::query_terms(&query_text))
+    } else {
+        Vec::new()
+    };
 
     info!(
-        "vector_search_v1 ok table_id={} rows={} elapsed_ms={}",
+        "fts_search_v1 ok table_id={} rows={} highlights={} elapsed_ms={}",
         request.table_id,
         rows.len(),
+        highlights.len(),
         started_at.elapsed().as_millis()
     );
 
@@ -3509,135 +14328,161 @@ pub async fn vector_search_v1(
             limit,
         }),
         next_offset,
+        search_params: None,
+        stats,
+        warnings,
+        highlights,
     })
 }
 
-pub async fn fts_search_v1(
+pub async fn pivot_v1(
     state: &AppState,
-    request: FtsSearchRequestV1,
-) -> ResultEnvelope<QueryResponseV1> {
+    request: PivotRequestV1,
+) -> ResultEnvelope<PivotResponseV1> {
+    let table_id = request.table_id.clone();
+    let summary = active_queries::summary(
+        "pivot by",
+        Some(&request.column_key),
+        request.filter.as_deref(),
+    );
+    middleware::run(
+        state,
+        CommandContext::read("pivot_v1"),
+        run_as_query(
+            state,
+            "pivot_v1",
+            &table_id,
+            summary,
+            pivot_table(state, request),
+        ),
+    )
+    .await
+}
+
+async fn pivot_table(state: &AppState, request: PivotRequestV1) -> ResultEnvelope<PivotResponseV1> {
     let started_at = Instant::now();
     info!(
-        "fts_search_v1 start table_id={} limit={:?} offset={:?}",
-        request.table_id, request.limit, request.offset
+        "pivot_v1 start table_id={} row_keys={:?} column_key={} aggregation={:?}",
+        request.table_id, request.row_keys, request.column_key, request.aggregation
     );
-    trace!("fts_search_v1 query=\"{}\"", request.query);
-    if let Some(ref columns) = request.columns {
-        trace!("fts_search_v1 columns={:?}", columns);
-    }
-    if let Some(ref projection) = request.projection {
-        trace!("fts_search_v1 projection={:?}", projection);
-    }
     if let Some(ref filter) = request.filter {
-        trace!("fts_search_v1 filter=\"{}\"", filter);
-    }
-
-    if request.query.trim().is_empty() {
-        warn!("fts_search_v1 empty query table_id={}", request.table_id);
-        return ResultEnvelope::err(ErrorCode::InvalidArgument, "query text cannot be empty");
+        trace!("pivot_v1 filter=\"{}\"", filter);
     }
 
-    let table = match state.connections.lock() {
-        Ok(manager) => manager.get_table(&request.table_id),
-        Err(_) => {
-            error!("fts_search_v1 failed to lock connection manager");
-            return ResultEnvelope::err(ErrorCode::Internal, "failed to lock connection manager");
-        }
+    let spec = match pivot::PivotSpec::new(
+        request.row_keys,
+        request.column_key,
+        request.value_column,
+        request.aggregation,
+    ) {
+        Ok(spec) => spec,
+        Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
     };
-
-    let Some(table) = table else {
-        warn!(
-            "fts_search_v1 table not found table_id={}",
-            request.table_id
+    let max_columns = request.max_columns.unwrap_or(pivot::DEFAULT_MAX_COLUMNS);
+    if max_columns == 0 {
+        return ResultEnvelope::err(
+            ErrorCode::InvalidArgument,
+            "max_columns must be greater than 0",
         );
-        return ResultEnvelope::err(ErrorCode::NotFound, "table not found");
+    }
+    let max_columns = max_columns.min(pivot::MAX_COLUMNS_LIMIT);
+
+    let table = match resolve_table(state, &request.table_id, "pivot_v1") {
+        Ok(table) => table,
+        Err(envelope) => return envelope,
     };
 
-    let fallback_schema = match table.schema().await {
-        Ok(schema) => SchemaDefinition::from_arrow_schema(schema.as_ref()),
+    let schema = match table.schema().await {
+        Ok(schema) => schema,
         Err(error) => {
             error!(
-                "fts_search_v1 failed to read schema table_id={} error={}",
+                "pivot_v1 failed to read schema table_id={} error={}",
                 request.table_id, error
             );
             return ResultEnvelope::err(ErrorCode::Internal, error.to_string());
         }
     };
-
-    let mut fts_query = FullTextSearchQuery::new(request.query);
-    if let Some(columns) = request.columns {
-        if !columns.is_empty() {
-            fts_query = match fts_query.with_columns(&columns) {
-                Ok(query) => query,
-                Err(error) => {
-                    error!(
-                        "fts_search_v1 invalid columns table_id={} error={}",
-                        request.table_id, error
-                    );
-                    return ResultEnvelope::err(ErrorCode::InvalidArgument, error.to_string());
-                }
-            };
+    let masking_rules = match table_masking_rules(state, &request.table_id) {
+        Ok(rules) => rules,
+        Err(message) => {
+            error!("pivot_v1 failed to read masking rules");
+            return ResultEnvelope::err_message(ErrorCode::Internal, message);
         }
+    };
+    // Masked columns turn into strings, so they cannot be summed.
+    let schema = masking::mask_schema(schema.as_ref(), &masking_rules);
+    if let Err(error) = spec.validate(schema.as_ref()) {
+        return ResultEnvelope::err(ErrorCode::InvalidArgument, error);
     }
 
-    let limit = request.limit.unwrap_or(100);
-    let offset = request.offset.unwrap_or(0);
-    let query_limit = limit.saturating_add(1);
     let options = QueryOptions {
-        projection: request.projection,
-        filter: request.filter,
-        limit: Some(query_limit),
-        offset: Some(offset),
+        projection: Some(spec.projection()),
+        filter: sanitize_filter(request.filter),
+        limit: None,
+        offset: None,
     };
-
-    let query = apply_query_options(table.query().full_text_search(fts_query), &options);
-    let (mut rows, schema) = match execute_query_json(query, fallback_schema).await {
-        Ok(result) => result,
-        Err(error) => {
-            error!(
-                "fts_search_v1 query failed table_id={} error={}",
-                request.table_id, error
-            );
-            return ResultEnvelope::err(ErrorCode::Internal, error);
+    let query = apply_query_options(table.query(), &options);
+    let mut builder = pivot::PivotBuilder::new(spec);
+    let scanned = with_timeout(app_config(state).query_timeout(), async {
+        let mut stream = query.execute().await.map_err(|error| error.to_string())?;
+        while let Some(batch) = stream.try_next().await.map_err(|error| error.to_string())? {
+            let bytes = memory::batch_bytes(&batch);
+            memory::retain(bytes);
+            let batches = masking::mask_batches(vec![batch], &masking_rules)?;
+            builder.add_rows(&batches_to_json_rows(&batches)?);
+            memory::release(bytes);
         }
-    };
-
-    let has_more = rows.len() > limit;
-    if has_more {
-        rows.truncate(limit);
+        Ok(())
+    })
+    .await;
+    if let Err(error) = scanned {
+        error!(
+            "pivot_v1 query failed table_id={} error={}",
+            request.table_id, error
+        );
+        return ResultEnvelope::err(ErrorCode::Internal, error);
     }
-    let next_offset = if has_more {
-        Some(offset.saturating_add(limit))
-    } else {
-        None
+
+    let response = match builder.finish(max_columns) {
+        Ok(response) => response,
+        Err(error) => return ResultEnvelope::err(ErrorCode::InvalidArgument, error),
     };
 
     info!(
-        "fts_search_v1 ok table_id={} rows={} elapsed_ms={}",
+        "pivot_v1 ok table_id={} rows={} columns={} omitted_columns={} scanned_rows={} elapsed_ms={}",
         request.table_id,
-        rows.len(),
+        response.rows.len(),
+        response.columns.len(),
+        response.omitted_columns,
+        response.scanned_rows,
         started_at.elapsed().as_millis()
     );
 
-    ResultEnvelope::ok(QueryResponseV1 {
-        chunk: DataChunk::Json(JsonChunk {
-            rows,
-            schema,
-            offset,
-            limit,
-        }),
-        next_offset,
-    })
+    ResultEnvelope::ok(response)
 }
 
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
 
-    use arrow_array::Int32Array;
+    use arrow_array::{Int32Array, RecordBatch, StringArray};
     use arrow_schema::{DataType, Field, Schema};
 
-    use super::truncate_batches;
+    use lancedb::index::IndexType;
+
+    use super::{
+        batches_to_geojson, batches_to_json_rows, count_table_rows, decode_dictionaries,
+        default_nprobes, default_refine_factor, embedding_model_warning, estimated_num_partitions,
+        json_rows_to_batches, list_table_infos, locate_invalid_row, parse_storage_version,
+        storage_version_is_older, to_arrow_data_type, to_arrow_field, truncate_batches,
+    };
+    use crate::ipc::v1::FieldDataType;
+    use crate::ipc::v1::TableDescriptionV1;
+    use crate::ipc::v1::{
+        EmbeddingModelV1, GeometryEncodingV1, SchemaDefinition, SchemaField, SchemaFieldInput,
+        WriteDataMode, EXTENSION_NAME_KEY, GEOARROW_WKB_EXTENSION,
+    };
+    use crate::services::backend::{MemoryBackend, MemoryTable, TableBackend};
 
     fn make_batch(values: &[i32]) -> arrow_array::RecordBatch {
         let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int32, false)]));
@@ -3658,4 +14503,319 @@ mod tests {
         assert_eq!(total_rows, 3);
         assert_eq!(trimmed[1].num_rows(), 1);
     }
+
+    #[test]
+    fn arrow_chunks_stay_within_the_byte_budget() {
+        let batches = [
+            make_batch(&(0..1_000).collect::<Vec<_>>()),
+            make_batch(&(1_000..2_000).collect::<Vec<_>>()),
+        ];
+        let schema = batches[0].schema();
+        let half = batches_to_arrow_ipc_base64(&truncate_batches(&batches, 1_200), &schema)
+            .expect("encode")
+            .len();
+
+        let (encoded, rows) = batches_to_arrow_ipc_within(&batches, &schema, half).expect("fit");
+        assert!(encoded.len() <= half);
+        assert!((1_200..2_000).contains(&rows));
+        let (_, all) = batches_to_arrow_ipc_within(&batches, &schema, usize::MAX).expect("fit");
+        assert_eq!(all, 2_000);
+        let (_, one) = batches_to_arrow_ipc_within(&batches, &schema, 1).expect("fit");
+        assert_eq!(one, 1);
+    }
+
+    #[test]
+    fn maps_temporal_and_null_field_types() {
+        use arrow_schema::{IntervalUnit, TimeUnit};
+
+        let cases = [
+            (
+                FieldDataType::Time32Millisecond,
+                DataType::Time32(TimeUnit::Millisecond),
+            ),
+            (
+                FieldDataType::Time64Nanosecond,
+                DataType::Time64(TimeUnit::Nanosecond),
+            ),
+            (
+                FieldDataType::DurationSecond,
+                DataType::Duration(TimeUnit::Second),
+            ),
+            (
+                FieldDataType::IntervalMonthDayNano,
+                DataType::Interval(IntervalUnit::MonthDayNano),
+            ),
+            (FieldDataType::Null, DataType::Null),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                to_arrow_data_type(&input, None).expect("map type"),
+                expected
+            );
+        }
+        let parsed: FieldDataType =
+            serde_json::from_str("\"time64_microsecond\"").expect("parse field type");
+        assert_eq!(
+            to_arrow_data_type(&parsed, None).expect("map type"),
+            DataType::Time64(TimeUnit::Microsecond)
+        );
+    }
+
+    #[test]
+    fn invalid_rows_are_located_by_row_and_field() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("text", DataType::Utf8, true),
+        ]));
+        let rows = vec![
+            serde_json::json!({"id": 1, "text": "a"}),
+            serde_json::json!({"id": 2, "text": "b"}),
+            serde_json::json!({"id": "three", "text": "c"}),
+            serde_json::json!([4, "d"]),
+        ];
+        assert!(json_rows_to_batches(schema.clone(), &rows).is_err());
+
+        let invalid_row = locate_invalid_row(&schema, &rows).expect("invalid row");
+        assert_eq!(invalid_row.row_index, 2);
+        assert_eq!(invalid_row.field.as_deref(), Some("id"));
+
+        let invalid_row = locate_invalid_row(&schema, &rows[3..]).expect("invalid row");
+        assert_eq!(invalid_row.row_index, 0);
+        assert_eq!(invalid_row.field, None);
+        assert!(locate_invalid_row(&schema, &rows[..2]).is_none());
+    }
+
+    #[test]
+    fn dictionary_columns_round_trip_through_json() {
+        let data_type = to_arrow_data_type(&FieldDataType::DictionaryUtf8, None).expect("map");
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("category", data_type.clone(), true),
+        ]));
+        let rows = vec![
+            serde_json::json!({"id": 1, "category": "red"}),
+            serde_json::json!({"id": 2, "category": null}),
+            serde_json::json!({"id": 3, "category": "red"}),
+        ];
+
+        let batches = json_rows_to_batches(schema, &rows).expect("encode rows");
+        assert_eq!(batches[0].schema().field(1).data_type(), &data_type);
+
+        let decoded = decode_dictionaries(&batches[0]).expect("decode");
+        assert_eq!(decoded.schema().field(1).data_type(), &DataType::Utf8);
+        assert_eq!(
+            batches_to_json_rows(&batches).expect("rows"),
+            vec![
+                serde_json::json!({"id": 1, "category": "red"}),
+                serde_json::json!({"id": 2}),
+                serde_json::json!({"id": 3, "category": "red"}),
+            ]
+        );
+    }
+
+    #[test]
+    fn embedding_models_are_stored_with_vector_columns() {
+        let input = SchemaFieldInput {
+            name: "vector".to_string(),
+            data_type: FieldDataType::FixedSizeListFloat32,
+            nullable: true,
+            metadata: None,
+            vector_length: Some(3),
+            embedding: Some(EmbeddingModelV1 {
+                model: " text-embedding-3-small ".to_string(),
+                version: Some("1".to_string()),
+                dimension: None,
+            }),
+        };
+        let field = to_arrow_field(&input).expect("map field");
+        let stored = EmbeddingModelV1::from_field(&field).expect("embedding");
+        assert_eq!(stored.model, "text-embedding-3-small");
+        assert_eq!(stored.dimension, Some(3));
+
+        let schema = SchemaDefinition::from_arrow_schema(&Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            field,
+        ]));
+        let other = EmbeddingModelV1 {
+            model: "all-MiniLM-L6-v2".to_string(),
+            version: None,
+            dimension: Some(3),
+        };
+        let warning = embedding_model_warning(&schema, None, Some(&other)).expect("warning");
+        assert_eq!(warning.column.as_deref(), Some("vector"));
+        assert!(warning.message.contains("text-embedding-3-small@1"));
+        assert!(embedding_model_warning(&schema, Some("vector"), Some(&stored)).is_none());
+        assert!(embedding_model_warning(&schema, Some("id"), Some(&other)).is_none());
+
+        let mismatched = SchemaFieldInput {
+            embedding: Some(EmbeddingModelV1 {
+                dimension: Some(4),
+                ..other.clone()
+            }),
+            ..input.clone()
+        };
+        assert!(to_arrow_field(&mismatched).is_err());
+        let scalar = SchemaFieldInput {
+            data_type: FieldDataType::Utf8,
+            vector_length: None,
+            embedding: Some(other),
+            ..input
+        };
+        assert!(to_arrow_field(&scalar).is_err());
+    }
+
+    #[test]
+    fn geometry_fields_are_tagged_and_exported_as_geojson() {
+        let field = to_arrow_field(&SchemaFieldInput {
+            name: "footprint".to_string(),
+            data_type: FieldDataType::Geometry,
+            nullable: true,
+            metadata: None,
+            vector_length: None,
+            embedding: None,
+        })
+        .expect("map field");
+        assert_eq!(field.data_type(), &DataType::Binary);
+        assert_eq!(
+            field.metadata().get(EXTENSION_NAME_KEY).map(String::as_str),
+            Some(GEOARROW_WKB_EXTENSION)
+        );
+        assert_eq!(
+            SchemaField::from_arrow_field(&field).geometry,
+            Some(GeometryEncodingV1::Wkb)
+        );
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("wkt", DataType::Utf8, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2])),
+                Arc::new(StringArray::from(vec![Some("POINT (1 2)"), None])),
+            ],
+        )
+        .expect("batch");
+        let bytes = batches_to_geojson(&[batch], "wkt", GeometryEncodingV1::Wkt).expect("geojson");
+        let collection: serde_json::Value = serde_json::from_slice(&bytes).expect("parse");
+        assert_eq!(
+            collection["features"][0],
+            serde_json::json!({
+                "type": "Feature",
+                "geometry": { "type": "Point", "coordinates": [1.0, 2.0] },
+                "properties": { "id": 1 },
+            })
+        );
+        assert_eq!(
+            collection["features"][1]["geometry"],
+            serde_json::Value::Null
+        );
+    }
+
+    #[test]
+    fn derives_search_defaults_from_index_size() {
+        assert_eq!(estimated_num_partitions(0), 1);
+        assert_eq!(estimated_num_partitions(1_000_000), 1000);
+        assert_eq!(default_nprobes(1), 1);
+        assert_eq!(default_nprobes(100), 8);
+        assert_eq!(default_nprobes(1000), 80);
+        assert_eq!(default_refine_factor(&IndexType::IvfPq), Some(5));
+        assert_eq!(default_refine_factor(&IndexType::IvfHnswSq), Some(2));
+        assert_eq!(default_refine_factor(&IndexType::IvfFlat), None);
+    }
+
+    #[test]
+    fn compares_storage_versions() {
+        assert_eq!(parse_storage_version("legacy"), Some((0, 1)));
+        assert_eq!(parse_storage_version(" 2.1 "), Some((2, 1)));
+        assert_eq!(parse_storage_version("stable"), None);
+        assert!(storage_version_is_older("0.1", "2.0"));
+        assert!(storage_version_is_older("2.0", "2.1"));
+        assert!(!storage_version_is_older("2.1", "2.1"));
+        assert!(!storage_version_is_older("next", "2.1"));
+    }
+
+    #[tokio::test]
+    async fn lists_tables_with_descriptions_and_stats() {
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int32, false)]));
+        let rows = |count: i32| {
+            RecordBatch::try_new(
+                schema.clone(),
+                vec![Arc::new(Int32Array::from_iter_values(0..count))],
+            )
+            .expect("batch")
+        };
+        let backend = MemoryBackend::new("memory://catalog")
+            .with_table(
+                MemoryTable::new("items", schema.clone())
+                    .with_batch(rows(50))
+                    .with_size_bytes(4096),
+            )
+            .with_table(
+                MemoryTable::new("events", schema.clone())
+                    .with_batch(rows(7))
+                    .with_version("2026-01-02T00:00:00+00:00"),
+            );
+        let described = [(
+            "items".to_string(),
+            TableDescriptionV1 {
+                description: Some("Sample items".to_string()),
+                owner: None,
+                tags: Default::default(),
+                updated_at_ms: None,
+            },
+        )]
+        .into_iter()
+        .collect();
+
+        let row_counts = Mutex::new(RowCountCache::new());
+        let plain = list_table_infos(&backend, Default::default(), false, &row_counts)
+            .await
+            .expect("list");
+        assert_eq!(
+            plain
+                .iter()
+                .map(|table| table.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["events", "items"]
+        );
+        assert!(plain.iter().all(|table| table.row_count.is_none()));
+
+        let tables = list_table_infos(&backend, described, true, &row_counts)
+            .await
+            .expect("list");
+        let events = &tables[0];
+        assert_eq!(events.row_count, Some(7));
+        assert_eq!(events.version, Some(2));
+        assert!(events.description.is_none());
+        let items = &tables[1];
+        assert_eq!(items.size_bytes, Some(4096));
+        assert_eq!(
+            items
+                .description
+                .as_ref()
+                .and_then(|meta| meta.description.as_deref()),
+            Some("Sample items")
+        );
+
+        let cached = list_table_infos(&backend, Default::default(), false, &row_counts)
+            .await
+            .expect("list");
+        assert_eq!(cached[0].row_count, Some(7));
+        assert!(cached.iter().all(|table| table.row_count_cached));
+    }
+
+    #[tokio::test]
+    async fn counts_rows_at_the_current_version() {
+        let table =
+            MemoryTable::new("items", make_batch(&[]).schema()).with_batch(make_batch(&[1, 2, 3]));
+        assert_eq!(count_table_rows(&table).await.expect("count"), (1, 3));
+
+        table
+            .add(vec![make_batch(&[4, 5])], WriteDataMode::Append)
+            .await
+            .expect("append");
+        assert_eq!(count_table_rows(&table).await.expect("count"), (2, 5));
+    }
 }
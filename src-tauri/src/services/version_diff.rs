@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+
+use crate::ipc::v1::{ChangedRowV1, CompareQueryVersionsResponseV1};
+
+pub const DEFAULT_MAX_ROWS: usize = 200;
+pub const MAX_ROWS_LIMIT: usize = 5_000;
+
+/// Query results of one table version.
+pub struct VersionRows {
+    pub version: u64,
+    pub rows: Vec<Value>,
+}
+
+fn row_key(row: &Value, key_columns: &[String]) -> Result<Value, String> {
+    let Some(object) = row.as_object() else {
+        return Err("query results are not rows".to_string());
+    };
+    let mut key = Map::new();
+    for column in key_columns {
+        let Some(value) = object.get(column) else {
+            return Err(format!("key column '{column}' is not in the query results"));
+        };
+        key.insert(column.clone(), value.clone());
+    }
+    Ok(Value::Object(key))
+}
+
+/// Index of each row of `rows` by its key. Keys must be unique within a version.
+fn index_by_key(
+    rows: &[Value],
+    key_columns: &[String],
+    version: u64,
+) -> Result<HashMap<String, usize>, String> {
+    let mut index = HashMap::with_capacity(rows.len());
+    for (position, row) in rows.iter().enumerate() {
+        let key = row_key(row, key_columns)?.to_string();
+        if index.insert(key.clone(), position).is_some() {
+            return Err(format!(
+                "key {key} matches more than one row in version {version}"
+            ));
+        }
+    }
+    Ok(index)
+}
+
+/// Columns of `before` and `after` with different values, in `before`'s order
+/// followed by columns only `after` has.
+fn changed_columns(before: &Value, after: &Value) -> Vec<String> {
+    let empty = Map::new();
+    let before = before.as_object().unwrap_or(&empty);
+    let after = after.as_object().unwrap_or(&empty);
+    let mut columns = before
+        .iter()
+        .filter(|(column, value)| after.get(*column) != Some(*value))
+        .map(|(column, _)| column.clone())
+        .collect::<Vec<_>>();
+    columns.extend(
+        after
+            .keys()
+            .filter(|column| !before.contains_key(*column))
+            .cloned(),
+    );
+    columns
+}
+
+/// Diffs the results of the same query on two versions. With `key_columns`, rows are
+/// matched by key and matched rows with other values count as changed. Without them,
+/// identical rows are matched one to one and every other row is added or removed.
+/// At most `max_rows` rows are listed per kind of difference.
+pub fn diff_rows(
+    a: VersionRows,
+    b: VersionRows,
+    key_columns: &[String],
+    max_rows: usize,
+) -> Result<CompareQueryVersionsResponseV1, String> {
+    let mut response = CompareQueryVersionsResponseV1 {
+        version_a: a.version,
+        version_b: b.version,
+        rows_a: a.rows.len(),
+        rows_b: b.rows.len(),
+        added: Vec::new(),
+        removed: Vec::new(),
+        changed: Vec::new(),
+        added_count: 0,
+        removed_count: 0,
+        changed_count: 0,
+        unchanged_count: 0,
+        truncated: false,
+    };
+    let mut matched = vec![false; a.rows.len()];
+
+    if key_columns.is_empty() {
+        let mut unmatched = HashMap::<String, Vec<usize>>::new();
+        for (position, row) in a.rows.iter().enumerate().rev() {
+            unmatched.entry(row.to_string()).or_default().push(position);
+        }
+        for row in b.rows {
+            match unmatched
+                .get_mut(&row.to_string())
+                .and_then(|positions| positions.pop())
+            {
+                Some(position) => {
+                    matched[position] = true;
+                    response.unchanged_count += 1;
+                }
+                None => {
+                    response.added_count += 1;
+                    if response.added.len() < max_rows {
+                        response.added.push(row);
+                    }
+                }
+            }
+        }
+    } else {
+        let index = index_by_key(&a.rows, key_columns, a.version)?;
+        index_by_key(&b.rows, key_columns, b.version)?;
+        for row in b.rows {
+            let key = row_key(&row, key_columns)?;
+            let Some(&position) = index.get(&key.to_string()) else {
+                response.added_count += 1;
+                if response.added.len() < max_rows {
+                    response.added.push(row);
+                }
+                continue;
+            };
+            matched[position] = true;
+            let before = &a.rows[position];
+            if *before == row {
+                response.unchanged_count += 1;
+                continue;
+            }
+            response.changed_count += 1;
+            if response.changed.len() < max_rows {
+                response.changed.push(ChangedRowV1 {
+                    key,
+                    columns: changed_columns(before, &row),
+                    before: before.clone(),
+                    after: row,
+                });
+            }
+        }
+    }
+
+    for (row, matched) in a.rows.into_iter().zip(matched) {
+        if matched {
+            continue;
+        }
+        response.removed_count += 1;
+        if response.removed.len() < max_rows {
+            response.removed.push(row);
+        }
+    }
+    response.truncated = response.added.len() < response.added_count
+        || response.removed.len() < response.removed_count
+        || response.changed.len() < response.changed_count;
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn version(version: u64, rows: Vec<Value>) -> VersionRows {
+        VersionRows { version, rows }
+    }
+
+    #[test]
+    fn diffs_rows_by_key_and_by_value() {
+        let before = vec![
+            json!({"id": 1, "text": "a"}),
+            json!({"id": 2, "text": "b"}),
+            json!({"id": 3, "text": "c"}),
+        ];
+        let after = vec![
+            json!({"id": 1, "text": "a"}),
+            json!({"id": 3, "text": "C"}),
+            json!({"id": 4, "text": "d"}),
+        ];
+        let keys = vec!["id".to_string()];
+
+        let diff = diff_rows(
+            version(1, before.clone()),
+            version(2, after.clone()),
+            &keys,
+            10,
+        )
+        .expect("diff");
+        assert_eq!(diff.added, vec![json!({"id": 4, "text": "d"})]);
+        assert_eq!(diff.removed, vec![json!({"id": 2, "text": "b"})]);
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].key, json!({"id": 3}));
+        assert_eq!(diff.changed[0].columns, vec!["text"]);
+        assert_eq!(diff.unchanged_count, 1);
+        assert!(!diff.truncated);
+
+        let diff = diff_rows(version(1, before.clone()), version(2, after), &[], 1).expect("diff");
+        assert_eq!((diff.added_count, diff.removed_count), (2, 2));
+        assert_eq!((diff.added.len(), diff.changed_count), (1, 0));
+        assert!(diff.truncated);
+
+        let duplicated = vec![json!({"id": 1}), json!({"id": 1})];
+        assert!(diff_rows(
+            version(1, duplicated),
+            version(2, before.clone()),
+            &keys,
+            10
+        )
+        .is_err());
+        let missing = vec!["missing".to_string()];
+        assert!(diff_rows(version(1, before.clone()), version(2, before), &missing, 10).is_err());
+    }
+}
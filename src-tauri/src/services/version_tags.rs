@@ -0,0 +1,60 @@
+use crate::ipc::v1::VersionTagV1;
+
+/// Checks `name` against the tag names Lance accepts: letters, digits, `-`, `_`
+/// and `.`, not starting with `.`, without `..` and not ending in `.lock`.
+pub fn validate_tag_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("tag name cannot be empty".to_string());
+    }
+    if let Some(character) = name
+        .chars()
+        .find(|character| !character.is_ascii_alphanumeric() && !"-_.".contains(*character))
+    {
+        return Err(format!("tag name cannot contain '{character}'"));
+    }
+    if name.starts_with('.') || name.contains("..") || name.ends_with(".lock") {
+        return Err(format!(
+            "tag name '{name}' cannot start with '.', contain '..' or end with '.lock'"
+        ));
+    }
+    Ok(())
+}
+
+/// Newest version first; tags of the same version by name.
+pub fn sorted(tags: impl IntoIterator<Item = VersionTagV1>) -> Vec<VersionTagV1> {
+    let mut tags = tags.into_iter().collect::<Vec<_>>();
+    tags.sort_by(|left, right| {
+        right
+            .version
+            .cmp(&left.version)
+            .then_with(|| left.name.cmp(&right.name))
+    });
+    tags
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(name: &str, version: u64) -> VersionTagV1 {
+        VersionTagV1 {
+            name: name.to_string(),
+            version,
+            note: None,
+        }
+    }
+
+    #[test]
+    fn validates_and_sorts_tags() {
+        assert!(validate_tag_name("release-1.2_rc").is_ok());
+        for name in ["", "has space", ".hidden", "a..b", "main.lock", "标签"] {
+            assert!(
+                validate_tag_name(name).is_err(),
+                "{name} should be rejected"
+            );
+        }
+
+        let tags = sorted(vec![tag("b", 1), tag("z", 3), tag("a", 1)]);
+        assert_eq!(tags, vec![tag("z", 3), tag("a", 1), tag("b", 1)]);
+    }
+}
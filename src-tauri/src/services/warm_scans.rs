@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+use std::time::Instant;
+
+use crate::ipc::v1::ScanResponseV1;
+
+/// Local tables whose first page stays warm; older ones are evicted first.
+pub const DEFAULT_WARM_TABLES: usize = 8;
+/// Rows the scanner warmup reads, so the data pages of a table's first page are in
+/// the OS page cache before it is scanned.
+pub const WARMUP_ROWS: usize = 100;
+
+struct WarmPage {
+    version: u64,
+    key: String,
+    response: ScanResponseV1,
+    used_at: Instant,
+}
+
+/// First pages of recently scanned local tables, keyed by table id and version.
+/// Unlike the prefetch cache, a page is not consumed when served, so flipping back
+/// to a table skips the query while its version and scan parameters are unchanged.
+/// Also tracks which version of each table the scanner warmup last loaded.
+pub struct WarmScanCache {
+    pages: HashMap<String, WarmPage>,
+    /// Version each recently opened or written table was warmed at.
+    warmed: HashMap<String, (u64, Instant)>,
+    max_tables: usize,
+}
+
+impl Default for WarmScanCache {
+    fn default() -> Self {
+        Self {
+            pages: HashMap::new(),
+            warmed: HashMap::new(),
+            max_tables: DEFAULT_WARM_TABLES,
+        }
+    }
+}
+
+impl WarmScanCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The warm page of `table_id` when it was stored for the same `version` and
+    /// scan `key`.
+    pub fn get(&mut self, table_id: &str, version: u64, key: &str) -> Option<ScanResponseV1> {
+        let page = self.pages.get_mut(table_id)?;
+        if page.version != version || page.key != key {
+            return None;
+        }
+        page.used_at = Instant::now();
+        Some(page.response.clone())
+    }
+
+    /// Keeps `response` as the warm page of `table_id`, replacing any other scan of
+    /// the table and evicting the least recently used table when full.
+    pub fn insert(&mut self, table_id: &str, key: String, response: ScanResponseV1) {
+        if !self.pages.contains_key(table_id) {
+            while self.pages.len() >= self.max_tables {
+                let oldest = self
+                    .pages
+                    .iter()
+                    .min_by_key(|(_, page)| page.used_at)
+                    .map(|(table_id, _)| table_id.clone());
+                match oldest {
+                    Some(table_id) => self.pages.remove(&table_id),
+                    None => break,
+                };
+            }
+        }
+        self.pages.insert(
+            table_id.to_string(),
+            WarmPage {
+                version: response.version,
+                key,
+                response,
+                used_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Claims the warmup of `table_id` at `version`. Returns false when that version
+    /// is already warm, so each table version is loaded once; the least recently
+    /// warmed table is forgotten when full.
+    pub fn start_warmup(&mut self, table_id: &str, version: u64) -> bool {
+        if self
+            .warmed
+            .get(table_id)
+            .is_some_and(|(warmed, _)| *warmed == version)
+        {
+            return false;
+        }
+        if !self.warmed.contains_key(table_id) && self.warmed.len() >= self.max_tables {
+            let oldest = self
+                .warmed
+                .iter()
+                .min_by_key(|(_, (_, at))| *at)
+                .map(|(table_id, _)| table_id.clone());
+            if let Some(oldest) = oldest {
+                self.warmed.remove(&oldest);
+            }
+        }
+        self.warmed
+            .insert(table_id.to_string(), (version, Instant::now()));
+        true
+    }
+
+    /// Forgets a warmup that failed, so the next open or write tries again.
+    pub fn cancel_warmup(&mut self, table_id: &str, version: u64) {
+        if self
+            .warmed
+            .get(table_id)
+            .is_some_and(|(warmed, _)| *warmed == version)
+        {
+            self.warmed.remove(table_id);
+        }
+    }
+
+    pub fn invalidate_table(&mut self, table_id: &str) {
+        self.pages.remove(table_id);
+    }
+
+    pub fn clear(&mut self) {
+        self.pages.clear();
+        self.warmed.clear();
+    }
+
+    pub fn table_count(&self) -> usize {
+        self.pages.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ipc::v1::{DataChunk, JsonChunk, SchemaDefinition};
+
+    use super::*;
+
+    fn page(version: u64) -> ScanResponseV1 {
+        ScanResponseV1 {
+            chunk: DataChunk::Json(JsonChunk {
+                rows: Vec::new(),
+                schema: SchemaDefinition { fields: Vec::new() },
+                offset: 0,
+                limit: 10,
+            }),
+            next_offset: None,
+            version,
+            split: None,
+            stats: None,
+        }
+    }
+
+    #[test]
+    fn keeps_first_pages_of_recent_tables() {
+        let mut cache = WarmScanCache {
+            max_tables: 2,
+            ..WarmScanCache::new()
+        };
+        cache.insert("a", "a@1".to_string(), page(1));
+        cache.insert("b", "b@1".to_string(), page(1));
+        assert!(cache.get("a", 1, "a@1").is_some());
+        assert!(cache.get("a", 1, "a@1").is_some(), "pages are not consumed");
+        assert!(cache.get("a", 1, "a@2").is_none());
+        assert!(
+            cache.get("a", 2, "a@1").is_none(),
+            "a new version misses the page"
+        );
+
+        cache.insert("c", "c@1".to_string(), page(1));
+        assert_eq!(cache.table_count(), 2);
+        assert!(
+            cache.get("b", 1, "b@1").is_none(),
+            "least recently used is evicted"
+        );
+        assert!(cache.get("a", 1, "a@1").is_some());
+
+        cache.insert("a", "a@2".to_string(), page(2));
+        assert_eq!(cache.get("a", 2, "a@2").map(|page| page.version), Some(2));
+        cache.invalidate_table("a");
+        assert!(cache.get("a", 2, "a@2").is_none());
+    }
+
+    #[test]
+    fn warms_each_table_version_once() {
+        let mut cache = WarmScanCache {
+            max_tables: 2,
+            ..WarmScanCache::new()
+        };
+        assert!(cache.start_warmup("a", 1));
+        assert!(!cache.start_warmup("a", 1));
+        assert!(cache.start_warmup("a", 2), "writes warm the new version");
+        cache.cancel_warmup("a", 2);
+        assert!(cache.start_warmup("a", 2), "failed warmups are retried");
+
+        assert!(cache.start_warmup("b", 1));
+        assert!(cache.start_warmup("c", 1));
+        assert!(
+            cache.start_warmup("a", 2),
+            "least recently warmed is forgotten"
+        );
+        cache.clear();
+        assert!(cache.start_warmup("b", 1));
+    }
+}
@@ -0,0 +1,396 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use log::{debug, error, info, warn};
+use serde::{Deserialize, Serialize};
+
+use crate::ipc::v1::{
+    DataFileFormatV1, ImportDataRequestV1, WatchFolderEventV1, WatchFolderV1, WriteDataMode,
+};
+use crate::services::{clock, shutdown, store, v1};
+use crate::state::AppState;
+
+pub const WATCH_FOLDERS_FILE: &str = "watch_folders.json";
+/// Tauri event carrying a `WatchFolderEventV1`.
+pub const WATCH_FOLDER_EVENT: &str = "watch-folder-ingested";
+const WATCH_TICK: Duration = Duration::from_secs(10);
+/// Files modified more recently than this may still be being written; they wait
+/// for a later tick.
+const SETTLE_TIME: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WatchFoldersFile {
+    #[serde(default)]
+    watch_folders: Vec<WatchFolderV1>,
+}
+
+/// Watch folders keyed by connection URI, persisted to `watch_folders.json` in the
+/// app data directory when one is configured.
+#[derive(Default)]
+pub struct WatchFolderStore {
+    path: Option<PathBuf>,
+    folders: Vec<WatchFolderV1>,
+}
+
+impl WatchFolderStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load(path: PathBuf) -> Self {
+        let folders = match store::load_json::<WatchFoldersFile>(&path) {
+            Ok(file) => file.watch_folders,
+            Err(error) => {
+                warn!(
+                    "failed to load watch folders path={:?} error={}",
+                    path, error
+                );
+                Vec::new()
+            }
+        };
+        Self {
+            path: Some(path),
+            folders,
+        }
+    }
+
+    pub fn list(&self, connection_uri: Option<&str>) -> Vec<WatchFolderV1> {
+        self.folders
+            .iter()
+            .filter(|folder| match connection_uri {
+                Some(uri) => folder.connection_uri == uri,
+                None => true,
+            })
+            .cloned()
+            .collect()
+    }
+
+    pub fn get(&self, id: &str) -> Option<WatchFolderV1> {
+        self.folders.iter().find(|folder| folder.id == id).cloned()
+    }
+
+    pub fn upsert(&mut self, folder: WatchFolderV1) -> Result<(), String> {
+        match self.folders.iter_mut().find(|item| item.id == folder.id) {
+            Some(existing) => *existing = folder,
+            None => self.folders.push(folder),
+        }
+        self.persist()
+    }
+
+    pub fn remove(&mut self, id: &str) -> Result<bool, String> {
+        let before = self.folders.len();
+        self.folders.retain(|folder| folder.id != id);
+        if self.folders.len() == before {
+            return Ok(false);
+        }
+        self.persist().map(|_| true)
+    }
+
+    /// Records that `event`'s file was handled so it is never imported again.
+    pub fn record_file(&mut self, event: &WatchFolderEventV1) {
+        let Some(folder) = self
+            .folders
+            .iter_mut()
+            .find(|item| item.id == event.watch_folder_id)
+        else {
+            return;
+        };
+        if !folder.seen_files.contains(&event.file_name) {
+            folder.seen_files.push(event.file_name.clone());
+        }
+        match &event.error {
+            Some(error) => folder.last_error = Some(error.clone()),
+            None => {
+                folder.last_ingested_at_ms = Some(event.ingested_at_ms);
+                folder.last_error = None;
+            }
+        }
+        if let Err(error) = self.persist() {
+            warn!(
+                "failed to persist watch folder id={} error={}",
+                event.watch_folder_id, error
+            );
+        }
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        let Some(path) = self.path.as_ref() else {
+            return Ok(());
+        };
+        store::save_json(
+            path,
+            &WatchFoldersFile {
+                watch_folders: self.folders.clone(),
+            },
+        )
+    }
+}
+
+/// File extensions a watch folder picks up for `format`.
+fn extensions(format: &DataFileFormatV1) -> &'static [&'static str] {
+    match format {
+        DataFileFormatV1::Csv => &["csv", "tsv"],
+        DataFileFormatV1::Parquet => &["parquet"],
+        DataFileFormatV1::Jsonl => &["jsonl", "ndjson"],
+        DataFileFormatV1::Geojson => &[],
+    }
+}
+
+pub fn validate_format(format: &DataFileFormatV1) -> Result<(), String> {
+    if extensions(format).is_empty() {
+        return Err("watch folders import csv, parquet or jsonl files".to_string());
+    }
+    Ok(())
+}
+
+/// Names of the files in `directory` that match `format`, sorted. Files modified
+/// after `settled_before` are left out when it is set.
+pub fn matching_files(
+    directory: &Path,
+    format: &DataFileFormatV1,
+    settled_before: Option<SystemTime>,
+) -> Result<Vec<String>, String> {
+    let extensions = extensions(format);
+    let entries = fs::read_dir(directory)
+        .map_err(|error| format!("failed to read {}: {error}", directory.display()))?;
+    let mut names = entries
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            let path = entry.path();
+            path.extension()
+                .and_then(|extension| extension.to_str())
+                .is_some_and(|extension| {
+                    extensions.contains(&extension.to_ascii_lowercase().as_str())
+                })
+        })
+        .filter(|entry| {
+            let Ok(metadata) = entry.metadata() else {
+                return false;
+            };
+            metadata.is_file()
+                && match settled_before {
+                    Some(cutoff) => metadata.modified().is_ok_and(|modified| modified <= cutoff),
+                    None => true,
+                }
+        })
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect::<Vec<_>>();
+    names.sort();
+    Ok(names)
+}
+
+async fn import_file(
+    state: &AppState,
+    table_id: &str,
+    folder: &WatchFolderV1,
+    file_name: &str,
+) -> Result<usize, String> {
+    let path = Path::new(&folder.directory).join(file_name);
+    let envelope = v1::import_data_v1(
+        state,
+        ImportDataRequestV1 {
+            table_id: table_id.to_string(),
+            path: path.to_string_lossy().to_string(),
+            format: folder.format.clone(),
+            mode: WriteDataMode::Append,
+            has_header: folder.has_header,
+            delimiter: folder.delimiter.clone(),
+            expected_sha256: None,
+            lineage: folder.lineage,
+            normalize_vectors: false,
+            job_id: None,
+            checkpoint_note: None,
+        },
+    )
+    .await;
+    match (envelope.data, envelope.error) {
+        (Some(data), _) => Ok(data.rows),
+        (_, Some(error)) => Err(error.message),
+        _ => Err("import returned no result".to_string()),
+    }
+}
+
+/// Imports the settled, unseen files of `folder` through a table handle that is
+/// closed again afterwards. Returns one event per file.
+async fn ingest_folder(
+    state: &AppState,
+    folder: &WatchFolderV1,
+    now: SystemTime,
+) -> Result<Vec<WatchFolderEventV1>, String> {
+    let pending = matching_files(
+        Path::new(&folder.directory),
+        &folder.format,
+        now.checked_sub(SETTLE_TIME),
+    )?
+    .into_iter()
+    .filter(|name| !folder.seen_files.contains(name))
+    .collect::<Vec<_>>();
+    if pending.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let connection = match state.connections.lock() {
+        Ok(manager) => manager.find_connection_by_uri(&folder.connection_uri),
+        Err(_) => return Err("failed to lock connection manager".to_string()),
+    };
+    let Some((connection_id, connection)) = connection else {
+        debug!(
+            "watch folder skipped while its connection is closed id={}",
+            folder.id
+        );
+        return Ok(Vec::new());
+    };
+    let table = connection
+        .open_table(&folder.table_name)
+        .execute()
+        .await
+        .map_err(|error| error.to_string())?;
+    let table_id = match state.connections.lock() {
+        Ok(mut manager) => manager.insert_table(folder.table_name.clone(), table, connection_id),
+        Err(_) => return Err("failed to lock connection manager".to_string()),
+    };
+
+    let mut events = Vec::with_capacity(pending.len());
+    for file_name in pending {
+        if shutdown::is_shutting_down(state) {
+            break;
+        }
+        let result = import_file(state, &table_id, folder, &file_name).await;
+        match &result {
+            Ok(rows) => info!(
+                "watch folder imported id={} file=\"{}\" rows={}",
+                folder.id, file_name, rows
+            ),
+            Err(error) => warn!(
+                "watch folder import failed id={} file=\"{}\" error={}",
+                folder.id, file_name, error
+            ),
+        }
+        let event = WatchFolderEventV1 {
+            watch_folder_id: folder.id.clone(),
+            table_name: folder.table_name.clone(),
+            file_name,
+            rows: result.as_ref().copied().unwrap_or_default(),
+            error: result.err(),
+            ingested_at_ms: clock::now_unix_millis(),
+        };
+        if let Ok(mut folders) = state.watch_folders.lock() {
+            folders.record_file(&event);
+        }
+        events.push(event);
+    }
+
+    if let Ok(mut manager) = state.connections.lock() {
+        manager.remove_table(&table_id);
+    }
+    Ok(events)
+}
+
+/// Imports new files of every enabled watch folder whose connection is open, passing
+/// each file's event to `emit`. Returns the number of files handled.
+pub async fn run_watch_folders(
+    state: &AppState,
+    now: SystemTime,
+    emit: &mut impl FnMut(&WatchFolderEventV1),
+) -> usize {
+    let folders = match state.watch_folders.lock() {
+        Ok(folders) => folders
+            .list(None)
+            .into_iter()
+            .filter(|folder| folder.enabled)
+            .collect::<Vec<_>>(),
+        Err(_) => {
+            error!("watch folders failed to lock watch folder store");
+            return 0;
+        }
+    };
+
+    let mut handled = 0;
+    for folder in &folders {
+        match ingest_folder(state, folder, now).await {
+            Ok(events) => {
+                handled += events.len();
+                for event in &events {
+                    emit(event);
+                }
+            }
+            Err(error) => warn!("watch folder scan failed id={} error={}", folder.id, error),
+        }
+    }
+    handled
+}
+
+/// Polls the watch folders for as long as the app is running.
+pub async fn run_loop(state: &AppState, mut emit: impl FnMut(&WatchFolderEventV1)) {
+    let mut interval = tokio::time::interval(WATCH_TICK);
+    loop {
+        interval.tick().await;
+        if shutdown::is_shutting_down(state) {
+            break;
+        }
+        run_watch_folders(state, SystemTime::now(), &mut emit).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_files_by_format_and_settle_time() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        for name in ["b.csv", "a.CSV", "notes.txt", "rows.jsonl"] {
+            fs::write(dir.path().join(name), "id\n1\n").expect("write");
+        }
+        fs::create_dir(dir.path().join("nested.csv")).expect("dir");
+
+        assert_eq!(
+            matching_files(dir.path(), &DataFileFormatV1::Csv, None).expect("csv"),
+            vec!["a.CSV", "b.csv"]
+        );
+        assert_eq!(
+            matching_files(dir.path(), &DataFileFormatV1::Jsonl, None).expect("jsonl"),
+            vec!["rows.jsonl"]
+        );
+        let long_ago = SystemTime::UNIX_EPOCH;
+        assert!(
+            matching_files(dir.path(), &DataFileFormatV1::Csv, Some(long_ago))
+                .expect("settled")
+                .is_empty()
+        );
+        assert!(validate_format(&DataFileFormatV1::Geojson).is_err());
+
+        let mut store = WatchFolderStore::new();
+        store
+            .upsert(WatchFolderV1 {
+                id: "w1".to_string(),
+                connection_uri: "/data".to_string(),
+                table_name: "items".to_string(),
+                directory: dir.path().to_string_lossy().to_string(),
+                format: DataFileFormatV1::Csv,
+                has_header: None,
+                delimiter: None,
+                lineage: false,
+                enabled: true,
+                seen_files: Vec::new(),
+                last_ingested_at_ms: None,
+                last_error: None,
+            })
+            .expect("upsert");
+        let event = WatchFolderEventV1 {
+            watch_folder_id: "w1".to_string(),
+            table_name: "items".to_string(),
+            file_name: "a.CSV".to_string(),
+            rows: 1,
+            error: None,
+            ingested_at_ms: 7,
+        };
+        store.record_file(&event);
+        store.record_file(&event);
+        let folder = store.get("w1").expect("folder");
+        assert_eq!(folder.seen_files, vec!["a.CSV"]);
+        assert_eq!(folder.last_ingested_at_ms, Some(7));
+    }
+}
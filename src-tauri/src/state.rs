@@ -1,15 +1,171 @@
-use std::sync::Mutex;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Instant;
 
+use crate::services::access_log::{AccessLog, ACCESS_LOG_FILE};
+use crate::services::active_queries::ActiveQueryRegistry;
+use crate::services::checkpoints::{CheckpointNoteStore, CHECKPOINT_NOTES_FILE};
+use crate::services::config::ConfigStore;
 use crate::services::connection_manager::ConnectionManager;
+use crate::services::descriptions::{DescriptionStore, DESCRIPTIONS_FILE};
+use crate::services::diagnostics::CommandMetrics;
+use crate::services::encryption::{EncryptionStore, ENCRYPTED_COLUMNS_FILE};
+use crate::services::export_templates::{ExportTemplateStore, EXPORT_TEMPLATES_FILE};
+use crate::services::filter_history::{FilterHistoryStore, FILTER_HISTORY_FILE};
+use crate::services::jobs::JobRegistry;
+use crate::services::log_control::{LogSettingsStore, LOG_SETTINGS_FILE};
+use crate::services::masking::{MaskingStore, MASKING_RULES_FILE};
+use crate::services::memory::MemoryStats;
+use crate::services::middleware::Pipeline;
+use crate::services::prefetch::PrefetchCache;
+use crate::services::projection_presets::{ProjectionPresetStore, PROJECTION_PRESETS_FILE};
+use crate::services::retention::{RetentionStore, RETENTION_FILE};
+use crate::services::row_counts::RowCountCache;
+use crate::services::scan_stream::ScanStreamRegistry;
+use crate::services::scheduler::{ScheduleStore, SCHEDULES_FILE};
+use crate::services::search_defaults::{SearchDefaultsStore, SEARCH_DEFAULTS_FILE};
+use crate::services::share::ShareRegistry;
+use crate::services::stats_history::{StatsHistoryStore, STATS_HISTORY_FILE};
+use crate::services::warm_scans::WarmScanCache;
+use crate::services::watch_folders::{WatchFolderStore, WATCH_FOLDERS_FILE};
 
 pub struct AppState {
     pub connections: Mutex<ConnectionManager>,
+    pub jobs: Mutex<JobRegistry>,
+    /// Scans, filter queries and searches still executing, killable by id.
+    pub active_queries: Mutex<ActiveQueryRegistry>,
+    pub schedules: Mutex<ScheduleStore>,
+    pub watch_folders: Mutex<WatchFolderStore>,
+    pub masking: Mutex<MaskingStore>,
+    /// Encrypted columns, plus the keys unlocked in this session.
+    pub encryption: Mutex<EncryptionStore>,
+    pub retention: Mutex<RetentionStore>,
+    pub descriptions: Mutex<DescriptionStore>,
+    /// Notes of automatic checkpoint tags, which tag names only carry as a slug.
+    pub checkpoint_notes: Mutex<CheckpointNoteStore>,
+    pub search_defaults: Mutex<SearchDefaultsStore>,
+    pub projection_presets: Mutex<ProjectionPresetStore>,
+    pub stats_history: Mutex<StatsHistoryStore>,
+    pub export_templates: Mutex<ExportTemplateStore>,
+    pub filter_history: Mutex<FilterHistoryStore>,
+    pub prefetch: Mutex<PrefetchCache>,
+    /// First pages of recently scanned local tables, shared with the scanner warmup
+    /// tasks.
+    pub warm_scans: Arc<Mutex<WarmScanCache>>,
+    /// Lance caches (manifests, file metadata, indexes) shared by every local
+    /// connection, so reconnecting or opening a directory twice reads warm metadata.
+    pub local_session: Arc<lancedb::Session>,
+    /// Acknowledgements of running `scan_stream_v1` streams.
+    pub scan_streams: Mutex<ScanStreamRegistry>,
+    /// Running read-only share servers.
+    pub shares: Mutex<ShareRegistry>,
+    /// Shared with the background tasks that recount tables after writes.
+    pub row_counts: Arc<Mutex<RowCountCache>>,
+    /// Peak batch memory per command, filled by the `MemoryAccounting` hook.
+    pub memory_stats: Mutex<MemoryStats>,
+    /// Calls, failures and latency per command, filled by the `CommandTimings` hook.
+    pub command_metrics: Mutex<CommandMetrics>,
+    /// Calls made by remote clients, shared with the share server threads.
+    pub access_log: Arc<Mutex<AccessLog>>,
+    pub log_settings: Mutex<LogSettingsStore>,
+    pub config: Mutex<ConfigStore>,
+    /// Hooks every `services::v1` command runs through.
+    pub pipeline: RwLock<Pipeline>,
+    pub data_dir: Option<PathBuf>,
+    /// Where the log plugin writes, read by `export_diagnostics_v1`.
+    pub log_dir: Option<PathBuf>,
+    pub started_at: Instant,
+    /// Set once the exit hook starts; background loops stop picking up new work.
+    pub shutting_down: AtomicBool,
 }
 
 impl AppState {
     pub fn new() -> Self {
         Self {
             connections: Mutex::new(ConnectionManager::new()),
+            jobs: Mutex::new(JobRegistry::new()),
+            active_queries: Mutex::new(ActiveQueryRegistry::new()),
+            schedules: Mutex::new(ScheduleStore::new()),
+            watch_folders: Mutex::new(WatchFolderStore::new()),
+            masking: Mutex::new(MaskingStore::new()),
+            encryption: Mutex::new(EncryptionStore::new()),
+            retention: Mutex::new(RetentionStore::new()),
+            descriptions: Mutex::new(DescriptionStore::new()),
+            checkpoint_notes: Mutex::new(CheckpointNoteStore::new()),
+            search_defaults: Mutex::new(SearchDefaultsStore::new()),
+            projection_presets: Mutex::new(ProjectionPresetStore::new()),
+            stats_history: Mutex::new(StatsHistoryStore::new()),
+            export_templates: Mutex::new(ExportTemplateStore::new()),
+            filter_history: Mutex::new(FilterHistoryStore::new()),
+            prefetch: Mutex::new(PrefetchCache::new()),
+            warm_scans: Arc::new(Mutex::new(WarmScanCache::new())),
+            local_session: Arc::new(lancedb::Session::default()),
+            scan_streams: Mutex::new(ScanStreamRegistry::new()),
+            shares: Mutex::new(ShareRegistry::new()),
+            row_counts: Arc::new(Mutex::new(RowCountCache::new())),
+            memory_stats: Mutex::new(MemoryStats::new()),
+            command_metrics: Mutex::new(CommandMetrics::new()),
+            access_log: Arc::new(Mutex::new(AccessLog::new())),
+            log_settings: Mutex::new(LogSettingsStore::new()),
+            config: Mutex::new(ConfigStore::new()),
+            pipeline: RwLock::new(Pipeline::new()),
+            data_dir: None,
+            log_dir: None,
+            started_at: Instant::now(),
+            shutting_down: AtomicBool::new(false),
+        }
+    }
+
+    /// Creates state backed by the app data directory, loading persisted settings.
+    pub fn with_data_dir(data_dir: PathBuf) -> Self {
+        Self {
+            schedules: Mutex::new(ScheduleStore::load(data_dir.join(SCHEDULES_FILE))),
+            watch_folders: Mutex::new(WatchFolderStore::load(data_dir.join(WATCH_FOLDERS_FILE))),
+            masking: Mutex::new(MaskingStore::load(data_dir.join(MASKING_RULES_FILE))),
+            encryption: Mutex::new(EncryptionStore::load(data_dir.join(ENCRYPTED_COLUMNS_FILE))),
+            retention: Mutex::new(RetentionStore::load(data_dir.join(RETENTION_FILE))),
+            descriptions: Mutex::new(DescriptionStore::load(data_dir.join(DESCRIPTIONS_FILE))),
+            checkpoint_notes: Mutex::new(CheckpointNoteStore::load(
+                data_dir.join(CHECKPOINT_NOTES_FILE),
+            )),
+            search_defaults: Mutex::new(SearchDefaultsStore::load(
+                data_dir.join(SEARCH_DEFAULTS_FILE),
+            )),
+            projection_presets: Mutex::new(ProjectionPresetStore::load(
+                data_dir.join(PROJECTION_PRESETS_FILE),
+            )),
+            stats_history: Mutex::new(StatsHistoryStore::load(data_dir.join(STATS_HISTORY_FILE))),
+            export_templates: Mutex::new(ExportTemplateStore::load(
+                data_dir.join(EXPORT_TEMPLATES_FILE),
+            )),
+            filter_history: Mutex::new(FilterHistoryStore::load(
+                data_dir.join(FILTER_HISTORY_FILE),
+            )),
+            log_settings: Mutex::new(LogSettingsStore::load(data_dir.join(LOG_SETTINGS_FILE))),
+            data_dir: Some(data_dir),
+            ..Self::new()
+        }
+    }
+
+    /// Loads `config.toml` and applies its cache limits.
+    pub fn with_config(self, config_path: PathBuf) -> Self {
+        let store = ConfigStore::load(config_path);
+        let config = store.get();
+        if let Ok(mut prefetch) = self.prefetch.lock() {
+            prefetch.set_limits(config.prefetch_cache_pages, config.prefetch_ttl());
+        }
+        if let Ok(mut current) = self.config.lock() {
+            *current = store;
+        }
+        self
+    }
+
+    pub fn with_log_dir(self, log_dir: PathBuf) -> Self {
+        Self {
+            access_log: Arc::new(Mutex::new(AccessLog::load(log_dir.join(ACCESS_LOG_FILE)))),
+            log_dir: Some(log_dir),
+            ..self
         }
     }
 }
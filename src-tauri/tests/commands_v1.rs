@@ -1,26 +1,77 @@
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
-use std::io::Cursor;
+use std::io::{BufRead, BufReader, Cursor, Read, Write};
+use std::net::{TcpListener, TcpStream};
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, SystemTime};
 
 use arrow_array::types::Float32Type;
-use arrow_array::{FixedSizeListArray, Int32Array, RecordBatch, RecordBatchIterator, StringArray};
+use arrow_array::{
+    FixedSizeListArray, Int32Array, RecordBatch, RecordBatchIterator, StringArray,
+    TimestampMicrosecondArray, UInt64Array,
+};
 use arrow_ipc::reader::StreamReader;
-use arrow_schema::{DataType, Field, Schema};
+use arrow_schema::{DataType, Field, Schema, TimeUnit};
 use base64::{engine::general_purpose, Engine as _};
+use chrono::Local;
+use futures_util::TryStreamExt;
 use lancedb::index::Index;
+use lancedb::query::{ExecutableQuery, QueryBase, Select};
+use tauri_plugin_stronghold::stronghold::Stronghold;
 use tempfile::tempdir;
 
 use lancedb_viewer_lib::ipc::v1::{
-    AddColumnsRequestV1, AlterColumnsRequestV1, ColumnAlterationInput, CombinedSearchRequestV1,
-    ConnectProfile, ConnectRequestV1, CreateIndexRequestV1, CreateTableRequestV1, DataFormat,
-    DeleteRowsRequestV1, DropColumnsRequestV1, DropIndexRequestV1, DropTableRequestV1, ErrorCode,
-    FieldDataType, FtsSearchRequestV1, GetSchemaRequestV1, IndexTypeV1, ListIndexesRequestV1,
-    ListTablesRequestV1, OpenTableRequestV1, QueryFilterRequestV1, ScanRequestV1,
-    SchemaDefinitionInput, SchemaFieldInput, UpdateColumnInputV1, UpdateRowsRequestV1,
-    VectorSearchRequestV1, WriteDataMode, WriteRowsRequestV1,
+    AccessTransportV1, AckScanStreamRequestV1, AddColumnsRequestV1, AlterColumnsRequestV1,
+    AuthDescriptor, BackendKind, BackupModeV1, BackupTableRequestV1, CancelJobRequestV1,
+    CheckExportTargetRequestV1, CheckoutTableVersionRequestV1, ColumnAlterationInput,
+    CombinedSearchRequestV1, CompareQueryVersionsRequestV1, CompletionMetadataRequestV1,
+    ConnectOptions, ConnectProfile, ConnectRequestV1, ConnectionHealthV1, CreateIndexRequestV1,
+    CreateTableOptionsV1, CreateTableRequestV1, CreateTagRequestV1, DataChunk, DataFileFormatV1,
+    DataFormat, DatasetDirectoryKindV1, DeleteExportTemplateRequestV1,
+    DeleteProjectionPresetRequestV1, DeleteRowsRequestV1, DeleteScheduleRequestV1,
+    DeleteTagRequestV1, DeleteWatchFolderRequestV1, DiagnosticsReportV1, DistanceTypeV1,
+    DropColumnsRequestV1, DropIndexRequestV1, DropTableRequestV1, EmbeddingProviderV1,
+    EncryptedColumnV1, ErrorCode, EstimateEmbeddingJobRequestV1, EstimateQueryRequestV1,
+    EvaluateRecallRequestV1, ExportBundleRequestV1, ExportDataRequestV1,
+    ExportDiagnosticsRequestV1, ExportReproSampleRequestV1, ExportRowsRequestV1, ExportTemplateV1,
+    ExpressionRerankerV1, FieldDataType, FtsColumnsNotIndexedV1, FtsLanguageV1, FtsSearchRequestV1,
+    FtsTokenizerOptionsV1, GeometryEncodingV1, GetAccessLogRequestV1, GetJobStatusRequestV1,
+    GetRetentionPolicyRequestV1, GetRowCountRequestV1, GetSchemaRequestV1,
+    GetSearchDefaultsRequestV1, GetTableDescriptionRequestV1, ImportBundleRequestV1,
+    ImportDataRequestV1, IndexAcceleratorV1, IndexPresetV1, IndexReadinessV1, IndexRebuildReasonV1,
+    IndexRebuildStateV1, IndexStatsRequestV1, IndexTypeV1, JobKindV1, JobStateV1, JsonOptionsV1,
+    KillQueryRequestV1, LargeIntegerFormatV1, ListEncryptedColumnsRequestV1, ListIndexesRequestV1,
+    ListMaskingRulesRequestV1, ListProjectionPresetsRequestV1, ListSchedulesRequestV1,
+    ListTablesRequestV1, ListTagsRequestV1, ListWatchFoldersRequestV1, LogLevelV1, MaskingRuleV1,
+    MaskingStrategyV1, MaterializeQueryRequestV1, MaterializeSourceV1, MessageCodeV1,
+    MigrateTableFormatRequestV1, MissingFtsIndexV1, ObjectStoreTuningV1, OpenDatasetRequestV1,
+    OpenTableRequestV1, OpenTablesRequestV1, OptimizeActionV1, OptimizeTableRequestV1,
+    OverviewRequestV1, PivotAggregationV1, PivotRequestV1, PivotRowV1, PreviewUpdateRequestV1,
+    ProjectionPresetV1, QueryFilterRequestV1, QueryResponseV1, RecentFilterSortV1,
+    RecentFiltersRequestV1, ReorderColumnsRequestV1, RerankerV1, RestoreBackupRequestV1,
+    RestoreTableVersionRequestV1, ResultEnvelope, RetentionPolicyV1, RoleV1, RotateSecretRequestV1,
+    RowValidationErrorV1, RrfRerankerV1, RunExportTemplateRequestV1, RunRetentionRequestV1,
+    SaveExportTemplateRequestV1, SaveProjectionPresetRequestV1, SavedArtifactKindV1, ScanRequestV1,
+    ScanResponseV1, ScanStreamEventV1, ScanStreamRequestV1, ScheduleActionV1, ScheduleRunStatusV1,
+    SchemaDefinitionInput, SchemaFieldInput, SearchDefaultsV1, SearchIndexKindV1,
+    SearchWarningKindV1, SetConfigRequestV1, SetEncryptedColumnsRequestV1,
+    SetEncryptionKeyRequestV1, SetLogLevelRequestV1, SetMaskingRulesRequestV1,
+    SetRetentionPolicyRequestV1, SetScheduleRequestV1, SetSearchDefaultsRequestV1,
+    SetTableDescriptionRequestV1, SetWatchFolderRequestV1, ShareTableV1, StartShareRequestV1,
+    StatsHistoryRequestV1, StopShareRequestV1, TableDescriptionV1, TableFormatInfoRequestV1,
+    TableLocationRequestV1, UpdateColumnInputV1, UpdateRowsRequestV1, UpdateTagRequestV1,
+    ValidateFilterRequestV1, ValueCategoryV1, VectorSearchRequestV1, WriteDataMode,
+    WriteRowsRequestV1,
 };
+use lancedb_viewer_lib::services::scan_stream::ScanStreamMessage;
+use lancedb_viewer_lib::services::scheduler;
+use lancedb_viewer_lib::services::secrets;
+use lancedb_viewer_lib::services::shutdown;
+use lancedb_viewer_lib::services::stats_history;
 use lancedb_viewer_lib::services::v1 as services_v1;
+use lancedb_viewer_lib::services::watch_folders;
 use lancedb_viewer_lib::state::AppState;
 
 struct SampleDb {
@@ -179,6 +230,7 @@ async fn list_tables_and_get_schema() {
         &harness.state,
         ListTablesRequestV1 {
             connection_id: harness.connection_id.clone(),
+            include_stats: false,
         },
     )
     .await;
@@ -210,6 +262,68 @@ async fn list_tables_and_get_schema() {
     );
 }
 
+#[tokio::test]
+async fn get_schema_describes_nested_types() {
+    let harness = create_command_harness().await;
+    let schema = services_v1::get_schema_v1(
+        &harness.state,
+        GetSchemaRequestV1 {
+            table_id: harness.table_id.clone(),
+        },
+    )
+    .await;
+    assert!(schema.ok, "get_schema should succeed: {:?}", schema.error);
+    let schema = schema.data.expect("schema");
+
+    let vector = schema
+        .fields
+        .iter()
+        .find(|field| field.name == "vector")
+        .expect("vector field");
+    assert_eq!(vector.type_descriptor.kind, "fixed_size_list");
+    assert_eq!(vector.type_descriptor.params["listSize"], 3);
+    assert_eq!(vector.type_descriptor.children.len(), 1);
+    assert_eq!(
+        vector.type_descriptor.children[0].type_descriptor.kind,
+        "float32"
+    );
+}
+
+#[tokio::test]
+async fn list_tables_reports_stats_on_request() {
+    let harness = create_command_harness().await;
+    let list = |include_stats| {
+        services_v1::list_tables_v1(
+            &harness.state,
+            ListTablesRequestV1 {
+                connection_id: harness.connection_id.clone(),
+                include_stats,
+            },
+        )
+    };
+
+    let listed = list(false).await;
+    assert!(listed.ok, "list_tables should succeed: {:?}", listed.error);
+    let tables = listed.data.expect("tables").tables;
+    let items = tables
+        .iter()
+        .find(|table| table.name == harness.table_name)
+        .expect("expected sample table to exist");
+    assert!(items.row_count.is_none() && items.size_bytes.is_none());
+
+    let listed = list(true).await;
+    assert!(listed.ok, "list_tables should succeed: {:?}", listed.error);
+    let tables = listed.data.expect("tables").tables;
+    let items = tables
+        .iter()
+        .find(|table| table.name == harness.table_name)
+        .expect("expected sample table to exist");
+    assert_eq!(items.row_count, Some(50));
+    assert_eq!(items.version, Some(1));
+    assert!(items.last_modified_ms.is_some_and(|ms| ms > 0));
+    assert!(items.size_bytes.is_some_and(|bytes| bytes > 0));
+}
+
 #[tokio::test]
 async fn drop_table_removes_table() {
     let harness = create_command_harness().await;
@@ -230,6 +344,7 @@ async fn drop_table_removes_table() {
         &harness.state,
         ListTablesRequestV1 {
             connection_id: harness.connection_id.clone(),
+            include_stats: false,
         },
     )
     .await;
@@ -259,6 +374,7 @@ async fn create_table_and_schema_evolution() {
                         nullable: false,
                         metadata: None,
                         vector_length: None,
+                        embedding: None,
                     },
                     SchemaFieldInput {
                         name: "name".to_string(),
@@ -266,9 +382,11 @@ async fn create_table_and_schema_evolution() {
                         nullable: true,
                         metadata: None,
                         vector_length: None,
+                        embedding: None,
                     },
                 ],
             },
+            options: Default::default(),
         },
     )
     .await;
@@ -291,8 +409,10 @@ async fn create_table_and_schema_evolution() {
                     nullable: true,
                     metadata: None,
                     vector_length: None,
+                    embedding: None,
                 }],
             },
+            checkpoint_note: None,
         },
     )
     .await;
@@ -319,6 +439,8 @@ async fn create_table_and_schema_evolution() {
                 data_type: None,
                 vector_length: None,
             }],
+            rebuild_indexes: false,
+            checkpoint_note: None,
         },
     )
     .await;
@@ -351,6 +473,7 @@ async fn create_table_and_schema_evolution() {
         DropColumnsRequestV1 {
             table_id: created.table_id.clone(),
             columns: vec!["notes_text".to_string()],
+            checkpoint_note: None,
         },
     )
     .await;
@@ -400,6 +523,8 @@ async fn write_update_delete_rows() {
                 serde_json::json!({"id": 1000, "text": "new", "vector": [0.2, 0.3, 0.4]}),
             ],
             mode: WriteDataMode::Append,
+            normalize_vectors: false,
+            checkpoint_note: None,
         },
     )
     .await;
@@ -416,6 +541,7 @@ async fn write_update_delete_rows() {
                 expr: "'updated'".to_string(),
             }],
             allow_full_table: false,
+            checkpoint_note: None,
         },
     )
     .await;
@@ -434,6 +560,9 @@ async fn write_update_delete_rows() {
             table_id: harness.table_id.clone(),
             filter: "id = 999".to_string(),
             allow_full_table: false,
+            chunk_size: None,
+            job_id: None,
+            checkpoint_note: None,
         },
     )
     .await;
@@ -459,6 +588,7 @@ async fn update_delete_rows_reject_broad_mutations_without_opt_in() {
                 expr: "'unsafe'".to_string(),
             }],
             allow_full_table: false,
+            checkpoint_note: None,
         },
     )
     .await;
@@ -482,6 +612,7 @@ async fn update_delete_rows_reject_broad_mutations_without_opt_in() {
                 expr: "'unsafe'".to_string(),
             }],
             allow_full_table: false,
+            checkpoint_note: None,
         },
     )
     .await;
@@ -501,6 +632,9 @@ async fn update_delete_rows_reject_broad_mutations_without_opt_in() {
             table_id: harness.table_id.clone(),
             filter: " ".to_string(),
             allow_full_table: false,
+            chunk_size: None,
+            job_id: None,
+            checkpoint_note: None,
         },
     )
     .await;
@@ -520,6 +654,9 @@ async fn update_delete_rows_reject_broad_mutations_without_opt_in() {
             table_id: harness.table_id.clone(),
             filter: "true".to_string(),
             allow_full_table: false,
+            chunk_size: None,
+            job_id: None,
+            checkpoint_note: None,
         },
     )
     .await;
@@ -544,9 +681,17 @@ async fn scan_json_and_arrow() {
             table_id: harness.table_id.clone(),
             format: DataFormat::Json,
             projection: None,
+            projection_preset: None,
             filter: None,
             limit: Some(2),
             offset: Some(0),
+            apply_masking: false,
+            prefetch: false,
+            include_stats: false,
+            json_options: None,
+            as_of_version: None,
+            max_chunk_bytes: None,
+            job_id: None,
         },
     )
     .await;
@@ -569,9 +714,17 @@ async fn scan_json_and_arrow() {
             table_id: harness.table_id.clone(),
             format: DataFormat::Arrow,
             projection: None,
+            projection_preset: None,
             filter: None,
             limit: Some(3),
             offset: Some(0),
+            apply_masking: false,
+            prefetch: false,
+            include_stats: false,
+            json_options: None,
+            as_of_version: None,
+            max_chunk_bytes: None,
+            job_id: None,
         },
     )
     .await;
@@ -610,8 +763,11 @@ async fn query_filter_vector_search_and_fts() {
             table_id: harness.table_id.clone(),
             filter: "id >= 2".to_string(),
             projection: None,
+            projection_preset: None,
             limit: Some(2),
             offset: Some(0),
+            include_stats: false,
+            json_options: None,
         },
     )
     .await;
@@ -637,10 +793,17 @@ async fn query_filter_vector_search_and_fts() {
             column: Some("vector".to_string()),
             top_k: Some(2),
             projection: None,
+            projection_preset: None,
             filter: None,
             nprobes: None,
             refine_factor: None,
             offset: Some(0),
+            include_stats: false,
+            json_options: None,
+            distance_type: None,
+            as_of_version: None,
+            query_embedding: None,
+            normalize_query: false,
         },
     )
     .await;
@@ -681,7 +844,13 @@ async fn query_filter_vector_search_and_fts() {
             limit: Some(5),
             offset: Some(0),
             projection: None,
+            projection_preset: None,
             filter: None,
+            include_stats: false,
+            json_options: None,
+            reranker: None,
+            as_of_version: None,
+            highlight: false,
         },
     )
     .await;
@@ -706,9 +875,16 @@ async fn query_filter_vector_search_and_fts() {
             limit: Some(5),
             offset: Some(0),
             projection: None,
+            projection_preset: None,
             filter: None,
             nprobes: None,
             refine_factor: None,
+            include_stats: false,
+            json_options: None,
+            reranker: None,
+            as_of_version: None,
+            query_embedding: None,
+            normalize_query: false,
         },
     )
     .await;
@@ -774,6 +950,56 @@ async fn query_filter_vector_search_and_fts() {
     }
 }
 
+#[tokio::test]
+async fn fts_search_highlights_matched_terms() {
+    let harness = create_command_harness().await;
+    harness
+        .state
+        .connections
+        .lock()
+        .expect("lock")
+        .get_table(&harness.table_id)
+        .expect("table")
+        .create_index(&["text"], Index::FTS(Default::default()))
+        .execute()
+        .await
+        .expect("create fts index");
+
+    let fts = services_v1::fts_search_v1(
+        &harness.state,
+        FtsSearchRequestV1 {
+            table_id: harness.table_id.clone(),
+            query: "item 1".to_string(),
+            columns: Some(vec!["text".to_string()]),
+            limit: Some(5),
+            offset: Some(0),
+            projection: None,
+            projection_preset: None,
+            filter: None,
+            include_stats: false,
+            json_options: None,
+            reranker: None,
+            as_of_version: None,
+            highlight: true,
+        },
+    )
+    .await;
+    assert!(fts.ok, "fts_search should succeed: {:?}", fts.error);
+    let fts = fts.data.expect("fts data");
+    let lancedb_viewer_lib::ipc::v1::DataChunk::Json(chunk) = fts.chunk else {
+        panic!("expected json chunk");
+    };
+    assert!(!chunk.rows.is_empty());
+    assert_eq!(fts.highlights.len(), chunk.rows.len());
+    let first = &fts.highlights[0];
+    assert_eq!(first.column, "text");
+    assert_eq!(
+        chunk.rows[first.row_index]["text"],
+        serde_json::json!(first.snippet)
+    );
+    assert_eq!((first.ranges[0].start, first.ranges[0].end), (0, 4));
+}
+
 #[tokio::test]
 async fn list_create_drop_indexes() {
     let harness = create_command_harness().await;
@@ -805,6 +1031,10 @@ async fn list_create_drop_indexes() {
             num_bits: None,
             num_edges: None,
             ef_construction: None,
+            accelerator: None,
+            fts_tokenizer: None,
+            job_id: None,
+            preset: None,
         },
     )
     .await;
@@ -866,39 +1096,6535 @@ async fn list_create_drop_indexes() {
 }
 
 #[tokio::test]
-async fn validates_error_conditions() {
+async fn index_stats_report_coverage_and_readiness() {
     let harness = create_command_harness().await;
 
-    let invalid_filter = services_v1::query_filter_v1(
+    let created = services_v1::create_index_v1(
         &harness.state,
-        QueryFilterRequestV1 {
+        CreateIndexRequestV1 {
             table_id: harness.table_id.clone(),
-            filter: " ".to_string(),
-            projection: None,
-            limit: None,
-            offset: None,
+            columns: vec!["id".to_string()],
+            index_type: IndexTypeV1::BTree,
+            name: Some("id_btree".to_string()),
+            replace: true,
+            distance_type: None,
+            num_partitions: None,
+            sample_rate: None,
+            max_iterations: None,
+            target_partition_size: None,
+            num_sub_vectors: None,
+            num_bits: None,
+            num_edges: None,
+            ef_construction: None,
+            accelerator: None,
+            fts_tokenizer: None,
+            job_id: None,
+            preset: None,
         },
     )
     .await;
+    assert!(created.ok, "create_index failed: {:?}", created.error);
 
-    assert!(!invalid_filter.ok);
+    let stats = |index_name: &str| IndexStatsRequestV1 {
+        table_id: harness.table_id.clone(),
+        index_name: index_name.to_string(),
+    };
+
+    let fresh = services_v1::index_stats_v1(&harness.state, stats("id_btree")).await;
+    assert!(fresh.ok, "index_stats failed: {:?}", fresh.error);
+    let fresh = fresh.data.expect("index stats");
+    assert!(matches!(fresh.index_type, IndexTypeV1::BTree));
+    assert_eq!(fresh.columns, vec!["id".to_string()]);
+    assert!(fresh.num_indexed_rows > 0);
+    assert_eq!(fresh.num_unindexed_rows, 0);
+    assert_eq!(fresh.coverage, 1.0);
+    assert_eq!(fresh.readiness, IndexReadinessV1::Ready);
+    assert!(!fresh.needs_optimize);
+    assert!(fresh.estimated_partitions.is_none());
+
+    let write = services_v1::write_rows_v1(
+        &harness.state,
+        WriteRowsRequestV1 {
+            table_id: harness.table_id.clone(),
+            rows: (0..10)
+                .map(|offset| {
+                    serde_json::json!({
+                        "id": 5000 + offset,
+                        "text": "late",
+                        "vector": [0.1, 0.2, 0.3],
+                    })
+                })
+                .collect(),
+            mode: WriteDataMode::Append,
+            normalize_vectors: false,
+            checkpoint_note: None,
+        },
+    )
+    .await;
+    assert!(write.ok, "write_rows failed: {:?}", write.error);
+
+    let behind = services_v1::index_stats_v1(&harness.state, stats("id_btree")).await;
+    assert!(behind.ok, "index_stats failed: {:?}", behind.error);
+    let behind = behind.data.expect("index stats");
+    assert_eq!(behind.num_unindexed_rows, 10);
+    assert!(behind.coverage < 1.0);
+    assert_ne!(behind.readiness, IndexReadinessV1::Ready);
+    assert!(behind.needs_optimize);
+
+    let optimize = |action, wait_timeout_ms| OptimizeTableRequestV1 {
+        table_id: harness.table_id.clone(),
+        action,
+        target_rows_per_fragment: None,
+        older_than_days: None,
+        delete_unverified: None,
+        error_if_tagged_old_versions: None,
+        locale: None,
+        wait_timeout_ms,
+        job_id: None,
+    };
+    let misplaced_wait = services_v1::optimize_table_v1(
+        &harness.state,
+        optimize(OptimizeActionV1::Compact, Some(1)),
+    )
+    .await;
     assert_eq!(
-        invalid_filter.error.expect("error").code,
+        misplaced_wait.error.expect("wait on compact").code,
         ErrorCode::InvalidArgument
     );
+    let optimized = services_v1::optimize_table_v1(
+        &harness.state,
+        optimize(OptimizeActionV1::Index, Some(10_000)),
+    )
+    .await;
+    assert!(optimized.ok, "optimize index failed: {:?}", optimized.error);
+    let optimized = optimized.data.expect("optimize data");
+    assert_eq!(
+        optimized.summary_message.code,
+        MessageCodeV1::IndexesOptimized
+    );
+    let id_index = optimized
+        .stats
+        .indexes
+        .iter()
+        .find(|index| index.name == "id_btree")
+        .expect("id_btree stats");
+    assert_eq!(id_index.num_unindexed_rows, 0);
+    assert_eq!(id_index.num_indexed_rows, behind.num_indexed_rows + 10);
+    assert!(!optimized.stats.index_pending);
+    assert!(optimized.stats.fragments_removed.is_none());
 
-    let empty_vector = services_v1::vector_search_v1(
+    let merged = services_v1::index_stats_v1(&harness.state, stats("id_btree")).await;
+    assert_eq!(
+        merged.data.expect("index stats").readiness,
+        IndexReadinessV1::Ready
+    );
+
+    let missing = services_v1::index_stats_v1(&harness.state, stats("missing_idx")).await;
+    assert_eq!(
+        missing.error.expect("unknown index").code,
+        ErrorCode::NotFound
+    );
+}
+
+#[tokio::test]
+async fn export_and_import_bundle_round_trip() {
+    let harness = create_command_harness().await;
+    let bundle_dir = tempdir().expect("create bundle dir");
+    let bundle_path = bundle_dir.path().join("items-bundle");
+
+    let created = services_v1::create_index_v1(
         &harness.state,
-        VectorSearchRequestV1 {
+        CreateIndexRequestV1 {
             table_id: harness.table_id.clone(),
-            vector: vec![],
-            column: Some("vector".to_string()),
-            top_k: None,
+            columns: vec!["id".to_string()],
+            index_type: IndexTypeV1::BTree,
+            name: Some("id_btree".to_string()),
+            replace: true,
+            distance_type: None,
+            num_partitions: None,
+            sample_rate: None,
+            max_iterations: None,
+            target_partition_size: None,
+            num_sub_vectors: None,
+            num_bits: None,
+            num_edges: None,
+            ef_construction: None,
+            accelerator: None,
+            fts_tokenizer: None,
+            job_id: None,
+            preset: None,
+        },
+    )
+    .await;
+    assert!(
+        created.ok,
+        "create_index should succeed: {:?}",
+        created.error
+    );
+
+    let exported = services_v1::export_bundle_v1(
+        &harness.state,
+        ExportBundleRequestV1 {
+            table_id: harness.table_id.clone(),
+            path: bundle_path.to_string_lossy().to_string(),
+            projection: None,
+            filter: Some("id < 20".to_string()),
+            rows_per_shard: Some(8),
+            metadata: None,
+            overwrite: false,
+            job_id: None,
+        },
+    )
+    .await;
+
+    assert!(
+        exported.ok,
+        "export_bundle should succeed: {:?}",
+        exported.error
+    );
+    let manifest = exported.data.expect("export bundle data").manifest;
+    assert_eq!(manifest.rows, 20);
+    assert_eq!(manifest.shards.len(), 3);
+    assert!(manifest
+        .indexes
+        .iter()
+        .any(|index| index.name == "id_btree"));
+    assert!(bundle_path.join("manifest.json").exists());
+    assert!(bundle_path.join("schema.json").exists());
+
+    let repeated = services_v1::export_bundle_v1(
+        &harness.state,
+        ExportBundleRequestV1 {
+            table_id: harness.table_id.clone(),
+            path: bundle_path.to_string_lossy().to_string(),
             projection: None,
             filter: None,
-            nprobes: None,
-            refine_factor: None,
+            rows_per_shard: None,
+            metadata: None,
+            overwrite: false,
+            job_id: None,
+        },
+    )
+    .await;
+    assert!(
+        !repeated.ok,
+        "export_bundle should not overwrite by default"
+    );
+
+    let imported = services_v1::import_bundle_v1(
+        &harness.state,
+        ImportBundleRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            path: bundle_path.to_string_lossy().to_string(),
+            table_name: Some("items_copy".to_string()),
+            skip_indexes: false,
+        },
+    )
+    .await;
+
+    assert!(
+        imported.ok,
+        "import_bundle should succeed: {:?}",
+        imported.error
+    );
+    let imported = imported.data.expect("import bundle data");
+    assert_eq!(imported.name, "items_copy");
+    assert_eq!(imported.rows, 20);
+    assert_eq!(imported.indexes_created, vec!["id_btree".to_string()]);
+
+    let scanned = services_v1::scan_v1(
+        &harness.state,
+        ScanRequestV1 {
+            table_id: imported.table_id,
+            format: DataFormat::Json,
+            projection: None,
+            projection_preset: None,
+            filter: None,
+            limit: Some(100),
             offset: None,
+            apply_masking: false,
+            prefetch: false,
+            include_stats: false,
+            json_options: None,
+            as_of_version: None,
+            max_chunk_bytes: None,
+            job_id: None,
+        },
+    )
+    .await;
+    assert!(scanned.ok, "scan should succeed: {:?}", scanned.error);
+    match scanned.data.expect("scan data").chunk {
+        lancedb_viewer_lib::ipc::v1::DataChunk::Json(chunk) => assert_eq!(chunk.rows.len(), 20),
+        _ => panic!("expected json chunk"),
+    }
+}
+
+#[tokio::test]
+async fn backup_and_restore_table() {
+    let harness = create_command_harness().await;
+    let backup_dir = tempdir().expect("create backup dir");
+    let backup_uri = backup_dir.path().to_string_lossy().to_string();
+
+    let backed_up = services_v1::backup_table_v1(
+        &harness.state,
+        BackupTableRequestV1 {
+            table_id: harness.table_id.clone(),
+            destination_uri: backup_uri.clone(),
+            backup_table_name: Some("items_backup".to_string()),
+            mode: BackupModeV1::Full,
+            storage_options: None,
+            job_id: Some("backup-job".to_string()),
+        },
+    )
+    .await;
+
+    assert!(
+        backed_up.ok,
+        "backup_table should succeed: {:?}",
+        backed_up.error
+    );
+    let backed_up = backed_up.data.expect("backup data");
+    assert_eq!(backed_up.job_id, "backup-job");
+    assert_eq!(backed_up.rows, 50);
+
+    let status = services_v1::get_job_status_v1(
+        &harness.state,
+        GetJobStatusRequestV1 {
+            job_id: "backup-job".to_string(),
+        },
+    )
+    .await;
+    assert!(
+        status.ok,
+        "get_job_status should succeed: {:?}",
+        status.error
+    );
+    let status = status.data.expect("job status");
+    assert_eq!(status.state, JobStateV1::Succeeded);
+    assert_eq!(status.processed_rows, 50);
+    assert_eq!(status.total_rows, Some(50));
+
+    let restored = services_v1::restore_backup_v1(
+        &harness.state,
+        RestoreBackupRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            backup_uri: backup_uri.clone(),
+            backup_table_name: "items_backup".to_string(),
+            target_table_name: None,
+            overwrite: false,
+            storage_options: None,
+            job_id: None,
+        },
+    )
+    .await;
+    assert!(
+        !restored.ok,
+        "restore should refuse to replace the source table without overwrite"
+    );
+
+    let restored = services_v1::restore_backup_v1(
+        &harness.state,
+        RestoreBackupRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            backup_uri,
+            backup_table_name: "items_backup".to_string(),
+            target_table_name: Some("items_restored".to_string()),
+            overwrite: false,
+            storage_options: None,
+            job_id: None,
+        },
+    )
+    .await;
+
+    assert!(
+        restored.ok,
+        "restore_backup should succeed: {:?}",
+        restored.error
+    );
+    let restored = restored.data.expect("restore data");
+    assert_eq!(restored.name, "items_restored");
+    assert_eq!(restored.rows, 50);
+    assert_eq!(restored.source_table.as_deref(), Some("items"));
+    assert_eq!(restored.source_version, Some(backed_up.source_version));
+    assert_eq!(restored.mode, Some(BackupModeV1::Full));
+
+    let schema = services_v1::get_schema_v1(
+        &harness.state,
+        GetSchemaRequestV1 {
+            table_id: restored.table_id,
+        },
+    )
+    .await;
+    assert!(schema.ok, "get_schema should succeed: {:?}", schema.error);
+    assert_eq!(schema.data.expect("schema").fields.len(), 3);
+}
+
+#[tokio::test]
+async fn schedules_persist_and_run_when_due() {
+    let harness = create_command_harness().await;
+
+    let invalid = services_v1::set_schedule_v1(
+        &harness.state,
+        SetScheduleRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            id: None,
+            table_name: harness.table_name.clone(),
+            cron: "every minute".to_string(),
+            action: ScheduleActionV1::Compact {
+                target_rows_per_fragment: None,
+            },
+            enabled: None,
+        },
+    )
+    .await;
+    assert!(!invalid.ok, "invalid cron should be rejected");
+    assert_eq!(
+        invalid.error.expect("error").code,
+        ErrorCode::InvalidArgument
+    );
+
+    let created = services_v1::set_schedule_v1(
+        &harness.state,
+        SetScheduleRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            id: Some("nightly-compact".to_string()),
+            table_name: harness.table_name.clone(),
+            cron: "* * * * *".to_string(),
+            action: ScheduleActionV1::Compact {
+                target_rows_per_fragment: None,
+            },
+            enabled: None,
+        },
+    )
+    .await;
+    assert!(
+        created.ok,
+        "set_schedule should succeed: {:?}",
+        created.error
+    );
+    assert!(created.data.expect("schedule").schedule.enabled);
+
+    let now = Local::now();
+    assert_eq!(scheduler::run_due_schedules(&harness.state, now).await, 1);
+    assert_eq!(
+        scheduler::run_due_schedules(&harness.state, now).await,
+        0,
+        "a schedule should run at most once per minute"
+    );
+
+    let listed = services_v1::list_schedules_v1(
+        &harness.state,
+        ListSchedulesRequestV1 {
+            connection_id: Some(harness.connection_id.clone()),
+        },
+    )
+    .await;
+    assert!(
+        listed.ok,
+        "list_schedules should succeed: {:?}",
+        listed.error
+    );
+    let schedules = listed.data.expect("schedules").schedules;
+    assert_eq!(schedules.len(), 1);
+    assert_eq!(
+        schedules[0].last_status,
+        Some(ScheduleRunStatusV1::Succeeded),
+        "last run message: {:?}",
+        schedules[0].last_message
+    );
+
+    let deleted = services_v1::delete_schedule_v1(
+        &harness.state,
+        DeleteScheduleRequestV1 {
+            id: "nightly-compact".to_string(),
+        },
+    )
+    .await;
+    assert!(
+        deleted.ok,
+        "delete_schedule should succeed: {:?}",
+        deleted.error
+    );
+}
+
+#[tokio::test]
+async fn export_reports_checksum_and_import_verifies_it() {
+    let harness = create_command_harness().await;
+    let export_dir = tempdir().expect("create export dir");
+    let export_path = export_dir.path().join("items.parquet");
+    let export_path = export_path.to_string_lossy().to_string();
+
+    let exported = services_v1::export_data_v1(
+        &harness.state,
+        ExportDataRequestV1 {
+            table_id: harness.table_id.clone(),
+            path: export_path.clone(),
+            format: DataFileFormatV1::Parquet,
+            projection: None,
+            filter: Some("id < 5".to_string()),
+            limit: None,
+            offset: None,
+            delimiter: None,
+            with_header: None,
+            geometry_column: None,
+            job_id: None,
+        },
+    )
+    .await;
+
+    assert!(
+        exported.ok,
+        "export_data should succeed: {:?}",
+        exported.error
+    );
+    let exported = exported.data.expect("export data");
+    assert_eq!(exported.rows, 5);
+    assert_eq!(
+        exported.bytes,
+        fs::metadata(&export_path).expect("stat").len()
+    );
+    assert_eq!(exported.sha256.len(), 64);
+
+    let mismatched = services_v1::import_data_v1(
+        &harness.state,
+        ImportDataRequestV1 {
+            table_id: harness.table_id.clone(),
+            path: export_path.clone(),
+            format: DataFileFormatV1::Parquet,
+            mode: WriteDataMode::Append,
+            has_header: None,
+            delimiter: None,
+            expected_sha256: Some("0".repeat(64)),
+            lineage: false,
+            normalize_vectors: false,
+            job_id: None,
+            checkpoint_note: None,
+        },
+    )
+    .await;
+    assert!(!mismatched.ok, "import should reject a checksum mismatch");
+    assert_eq!(
+        mismatched.error.expect("error").code,
+        ErrorCode::InvalidArgument
+    );
+
+    let unchanged = services_v1::export_data_v1(
+        &harness.state,
+        ExportDataRequestV1 {
+            table_id: harness.table_id.clone(),
+            path: export_dir
+                .path()
+                .join("all.parquet")
+                .to_string_lossy()
+                .to_string(),
+            format: DataFileFormatV1::Parquet,
+            projection: None,
+            filter: None,
+            limit: None,
+            offset: None,
+            delimiter: None,
+            with_header: None,
+            geometry_column: None,
+            job_id: None,
+        },
+    )
+    .await;
+    assert_eq!(unchanged.data.expect("export data").rows, 50);
+
+    let imported = services_v1::import_data_v1(
+        &harness.state,
+        ImportDataRequestV1 {
+            table_id: harness.table_id.clone(),
+            path: export_path,
+            format: DataFileFormatV1::Parquet,
+            mode: WriteDataMode::Append,
+            has_header: None,
+            delimiter: None,
+            expected_sha256: Some(format!("sha256:{}", exported.sha256.to_uppercase())),
+            lineage: false,
+            normalize_vectors: false,
+            job_id: None,
+            checkpoint_note: None,
+        },
+    )
+    .await;
+
+    assert!(
+        imported.ok,
+        "import_data should succeed: {:?}",
+        imported.error
+    );
+    let imported = imported.data.expect("import data");
+    assert_eq!(imported.rows, 5);
+    assert_eq!(imported.sha256, Some(exported.sha256));
+}
+
+#[tokio::test]
+async fn masking_rules_apply_to_export_and_scan() {
+    let harness = create_command_harness().await;
+
+    let invalid = services_v1::set_masking_rules_v1(
+        &harness.state,
+        SetMaskingRulesRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            rules: vec![MaskingRuleV1 {
+                table_name: None,
+                column: " ".to_string(),
+                strategy: MaskingStrategyV1::Redact,
+            }],
+        },
+    )
+    .await;
+    assert!(!invalid.ok, "empty masking column should be rejected");
+
+    let saved = services_v1::set_masking_rules_v1(
+        &harness.state,
+        SetMaskingRulesRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            rules: vec![
+                MaskingRuleV1 {
+                    table_name: Some(harness.table_name.clone()),
+                    column: "text".to_string(),
+                    strategy: MaskingStrategyV1::Redact,
+                },
+                MaskingRuleV1 {
+                    table_name: Some("other".to_string()),
+                    column: "id".to_string(),
+                    strategy: MaskingStrategyV1::Hash,
+                },
+            ],
+        },
+    )
+    .await;
+    assert!(
+        saved.ok,
+        "set_masking_rules should succeed: {:?}",
+        saved.error
+    );
+
+    let listed = services_v1::list_masking_rules_v1(
+        &harness.state,
+        ListMaskingRulesRequestV1 {
+            connection_id: harness.connection_id.clone(),
+        },
+    )
+    .await;
+    assert_eq!(listed.data.expect("masking rules").rules.len(), 2);
+
+    let scan = |apply_masking| ScanRequestV1 {
+        table_id: harness.table_id.clone(),
+        format: DataFormat::Json,
+        projection: None,
+        projection_preset: None,
+        filter: Some("id = 1".to_string()),
+        limit: Some(1),
+        offset: None,
+        apply_masking,
+        prefetch: false,
+        include_stats: false,
+        json_options: None,
+        as_of_version: None,
+        max_chunk_bytes: None,
+        job_id: None,
+    };
+
+    let unmasked = services_v1::scan_v1(&harness.state, scan(false)).await;
+    let unmasked = match unmasked.data.expect("scan data").chunk {
+        lancedb_viewer_lib::ipc::v1::DataChunk::Json(chunk) => chunk.rows[0].clone(),
+        _ => panic!("expected json chunk"),
+    };
+    assert_ne!(unmasked["text"], "****");
+
+    let masked = services_v1::scan_v1(&harness.state, scan(true)).await;
+    let masked = match masked.data.expect("scan data").chunk {
+        lancedb_viewer_lib::ipc::v1::DataChunk::Json(chunk) => chunk.rows[0].clone(),
+        _ => panic!("expected json chunk"),
+    };
+    assert_eq!(masked["text"], "****");
+    assert_eq!(masked["id"], 1);
+
+    let export_dir = tempdir().expect("create export dir");
+    let export_path = export_dir.path().join("items.jsonl");
+    let exported = services_v1::export_data_v1(
+        &harness.state,
+        ExportDataRequestV1 {
+            table_id: harness.table_id.clone(),
+            path: export_path.to_string_lossy().to_string(),
+            format: DataFileFormatV1::Jsonl,
+            projection: None,
+            filter: Some("id < 3".to_string()),
+            limit: None,
+            offset: None,
+            delimiter: None,
+            with_header: None,
+            geometry_column: None,
+            job_id: None,
+        },
+    )
+    .await;
+    assert!(
+        exported.ok,
+        "export_data should succeed: {:?}",
+        exported.error
+    );
+    let contents = fs::read_to_string(&export_path).expect("read export");
+    assert_eq!(contents.lines().count(), 3);
+    for line in contents.lines() {
+        let row: serde_json::Value = serde_json::from_str(line).expect("json line");
+        assert_eq!(row["text"], "****");
+    }
+}
+
+#[tokio::test]
+async fn retention_policy_deletes_expired_rows() {
+    let harness = create_command_harness().await;
+
+    let now_us = Local::now().timestamp_micros();
+    let day_us = 86_400_000_000_i64;
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int32, false),
+        Field::new(
+            "created_at",
+            DataType::Timestamp(TimeUnit::Microsecond, None),
+            true,
+        ),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5])),
+            Arc::new(TimestampMicrosecondArray::from(vec![
+                now_us - 30 * day_us,
+                now_us - 10 * day_us,
+                now_us - 8 * day_us,
+                now_us - day_us,
+                now_us,
+            ])),
+        ],
+    )
+    .expect("create record batch");
+    let db = harness
+        .state
+        .connections
+        .lock()
+        .expect("lock connections")
+        .get_connection(&harness.connection_id)
+        .expect("open connection");
+    db.create_table(
+        "events",
+        Box::new(RecordBatchIterator::new(
+            vec![batch].into_iter().map(Ok),
+            schema,
+        )),
+    )
+    .execute()
+    .await
+    .expect("create events table");
+
+    let opened = services_v1::open_table_v1(
+        &harness.state,
+        OpenTableRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            table_name: "events".to_string(),
+        },
+    )
+    .await;
+    let table_id = opened.data.expect("events table").table_id;
+
+    let missing = services_v1::run_retention_v1(
+        &harness.state,
+        RunRetentionRequestV1 {
+            table_id: table_id.clone(),
+            dry_run: true,
+        },
+    )
+    .await;
+    assert!(!missing.ok, "retention without a policy should fail");
+
+    let invalid = services_v1::set_retention_policy_v1(
+        &harness.state,
+        SetRetentionPolicyRequestV1 {
+            table_id: table_id.clone(),
+            policy: Some(RetentionPolicyV1 {
+                timestamp_column: "id".to_string(),
+                max_age_seconds: 7 * 86_400,
+            }),
+        },
+    )
+    .await;
+    assert_eq!(
+        invalid.error.expect("error").code,
+        ErrorCode::InvalidArgument
+    );
+
+    let saved = services_v1::set_retention_policy_v1(
+        &harness.state,
+        SetRetentionPolicyRequestV1 {
+            table_id: table_id.clone(),
+            policy: Some(RetentionPolicyV1 {
+                timestamp_column: "created_at".to_string(),
+                max_age_seconds: 7 * 86_400,
+            }),
+        },
+    )
+    .await;
+    assert!(
+        saved.ok,
+        "set_retention_policy should succeed: {:?}",
+        saved.error
+    );
+
+    let policy = services_v1::get_retention_policy_v1(
+        &harness.state,
+        GetRetentionPolicyRequestV1 {
+            table_id: table_id.clone(),
+        },
+    )
+    .await;
+    assert_eq!(
+        policy
+            .data
+            .expect("policy")
+            .policy
+            .map(|policy| policy.max_age_seconds),
+        Some(7 * 86_400)
+    );
+
+    let dry_run = services_v1::run_retention_v1(
+        &harness.state,
+        RunRetentionRequestV1 {
+            table_id: table_id.clone(),
+            dry_run: true,
+        },
+    )
+    .await;
+    assert!(dry_run.ok, "dry run should succeed: {:?}", dry_run.error);
+    let dry_run = dry_run.data.expect("dry run data");
+    assert_eq!(dry_run.rows_expired, 3);
+    assert_eq!(dry_run.rows_deleted, 0);
+    assert_eq!(dry_run.version, None);
+
+    let applied = services_v1::run_retention_v1(
+        &harness.state,
+        RunRetentionRequestV1 {
+            table_id: table_id.clone(),
+            dry_run: false,
+        },
+    )
+    .await;
+    assert!(
+        applied.ok,
+        "run_retention should succeed: {:?}",
+        applied.error
+    );
+    let applied = applied.data.expect("retention data");
+    assert_eq!(applied.rows_deleted, 3);
+    assert!(applied.version.is_some());
+
+    let repeated = services_v1::run_retention_v1(
+        &harness.state,
+        RunRetentionRequestV1 {
+            table_id: table_id.clone(),
+            dry_run: false,
+        },
+    )
+    .await;
+    assert_eq!(repeated.data.expect("retention data").rows_expired, 0);
+
+    let mut config = services_v1::get_config_v1(&harness.state)
+        .await
+        .data
+        .expect("config")
+        .config;
+    config.role = RoleV1::Viewer;
+    let updated = services_v1::set_config_v1(&harness.state, SetConfigRequestV1 { config }).await;
+    assert!(updated.ok, "set_config failed: {:?}", updated.error);
+    let preview = services_v1::run_retention_v1(
+        &harness.state,
+        RunRetentionRequestV1 {
+            table_id: table_id.clone(),
+            dry_run: true,
+        },
+    )
+    .await;
+    assert!(preview.ok, "viewer dry run failed: {:?}", preview.error);
+    let rejected = services_v1::run_retention_v1(
+        &harness.state,
+        RunRetentionRequestV1 {
+            table_id,
+            dry_run: false,
+        },
+    )
+    .await;
+    assert_eq!(
+        rejected.error.expect("viewer cannot delete rows").code,
+        ErrorCode::PermissionDenied
+    );
+}
+
+/// Opens an `events` table whose `created_at` values are 30 days, 8 days, 2 days
+/// and one minute old, for ids 1 to 4.
+async fn create_recent_events_table(harness: &CommandHarness) -> String {
+    let now_us = Local::now().timestamp_micros();
+    let day_us = 86_400_000_000_i64;
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int32, false),
+        Field::new(
+            "created_at",
+            DataType::Timestamp(TimeUnit::Microsecond, None),
+            true,
+        ),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int32Array::from(vec![1, 2, 3, 4])),
+            Arc::new(TimestampMicrosecondArray::from(vec![
+                now_us - 30 * day_us,
+                now_us - 8 * day_us,
+                now_us - 2 * day_us,
+                now_us - 60_000_000,
+            ])),
+        ],
+    )
+    .expect("create record batch");
+    let db = harness
+        .state
+        .connections
+        .lock()
+        .expect("lock connections")
+        .get_connection(&harness.connection_id)
+        .expect("open connection");
+    db.create_table(
+        "events",
+        Box::new(RecordBatchIterator::new(
+            vec![batch].into_iter().map(Ok),
+            schema,
+        )),
+    )
+    .execute()
+    .await
+    .expect("create events table");
+    services_v1::open_table_v1(
+        &harness.state,
+        OpenTableRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            table_name: "events".to_string(),
+        },
+    )
+    .await
+    .data
+    .expect("events table")
+    .table_id
+}
+
+#[tokio::test]
+async fn relative_time_helpers_filter_recent_rows() {
+    let harness = create_command_harness().await;
+    let table_id = create_recent_events_table(&harness).await;
+
+    let validated = services_v1::validate_filter_v1(
+        &harness.state,
+        ValidateFilterRequestV1 {
+            table_id: table_id.clone(),
+            filter: "last_7_days(created_at)".to_string(),
+        },
+    )
+    .await
+    .data
+    .expect("validation");
+    assert!(validated.valid, "{:?}", validated.error);
+
+    let query = |filter: &str| QueryFilterRequestV1 {
+        table_id: table_id.clone(),
+        filter: filter.to_string(),
+        projection: Some(vec!["id".to_string()]),
+        projection_preset: None,
+        limit: None,
+        offset: None,
+        include_stats: false,
+        json_options: None,
+    };
+    let ids = |response: ResultEnvelope<QueryResponseV1>| match response
+        .data
+        .expect("query data")
+        .chunk
+    {
+        DataChunk::Json(chunk) => chunk
+            .rows
+            .iter()
+            .map(|row| row["id"].as_i64().expect("id"))
+            .collect::<Vec<_>>(),
+        _ => panic!("expected json chunk"),
+    };
+
+    let last_week =
+        services_v1::query_filter_v1(&harness.state, query("last_7_days(created_at)")).await;
+    assert!(last_week.ok, "query failed: {:?}", last_week.error);
+    assert_eq!(ids(last_week), vec![3, 4]);
+    let last_hour =
+        services_v1::query_filter_v1(&harness.state, query("id > 1 AND last_1_hours(created_at)"))
+            .await;
+    assert_eq!(ids(last_hour), vec![4]);
+
+    // The saved history keeps the helper, not the expanded timestamps.
+    let recent = services_v1::recent_filters_v1(
+        &harness.state,
+        RecentFiltersRequestV1 {
+            table_id: table_id.clone(),
+            prefix: None,
+            sort: None,
+            limit: None,
+        },
+    )
+    .await
+    .data
+    .expect("recent filters");
+    assert!(recent
+        .filters
+        .iter()
+        .any(|entry| entry.filter == "last_7_days(created_at)"));
+}
+
+#[tokio::test]
+async fn scans_with_time_helpers_skip_the_page_caches() {
+    let harness = create_command_harness().await;
+    let table_id = create_recent_events_table(&harness).await;
+    let scan = |filter: &str| ScanRequestV1 {
+        table_id: table_id.clone(),
+        format: DataFormat::Json,
+        projection: Some(vec!["id".to_string()]),
+        projection_preset: None,
+        filter: Some(filter.to_string()),
+        limit: Some(1),
+        offset: Some(0),
+        apply_masking: false,
+        prefetch: true,
+        include_stats: false,
+        json_options: None,
+        as_of_version: None,
+        max_chunk_bytes: None,
+        job_id: None,
+    };
+
+    let recent = services_v1::scan_v1(&harness.state, scan("last_7_days(created_at)")).await;
+    assert!(recent.ok, "scan should succeed: {:?}", recent.error);
+    assert_eq!(recent.data.expect("scan data").next_offset, Some(1));
+    assert_eq!(
+        harness.state.warm_scans.lock().expect("lock").table_count(),
+        0
+    );
+    assert_eq!(harness.state.prefetch.lock().expect("lock").page_count(), 0);
+
+    let plain = services_v1::scan_v1(&harness.state, scan("id > 2")).await;
+    assert!(plain.ok, "scan should succeed: {:?}", plain.error);
+    assert_eq!(
+        harness.state.warm_scans.lock().expect("lock").table_count(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn scan_prefetch_serves_next_page_and_invalidates_on_write() {
+    let harness = create_command_harness().await;
+    let scan = |offset| ScanRequestV1 {
+        table_id: harness.table_id.clone(),
+        format: DataFormat::Json,
+        projection: Some(vec!["id".to_string()]),
+        projection_preset: None,
+        filter: None,
+        limit: Some(10),
+        offset: Some(offset),
+        apply_masking: false,
+        prefetch: true,
+        include_stats: false,
+        json_options: None,
+        as_of_version: None,
+        max_chunk_bytes: None,
+        job_id: None,
+    };
+    let page_ids = |response: lancedb_viewer_lib::ipc::v1::ScanResponseV1| match response.chunk {
+        lancedb_viewer_lib::ipc::v1::DataChunk::Json(chunk) => chunk
+            .rows
+            .iter()
+            .map(|row| row["id"].as_i64().expect("id"))
+            .collect::<Vec<_>>(),
+        _ => panic!("expected json chunk"),
+    };
+
+    let first = services_v1::scan_v1(&harness.state, scan(0)).await;
+    assert!(first.ok, "scan should succeed: {:?}", first.error);
+    let first = first.data.expect("scan data");
+    assert_eq!(first.next_offset, Some(10));
+
+    let second = services_v1::scan_v1(&harness.state, scan(10)).await;
+    assert!(second.ok, "scan should succeed: {:?}", second.error);
+    let second = second.data.expect("scan data");
+    assert_eq!(second.next_offset, Some(20));
+    assert_eq!(page_ids(second), (10..20).collect::<Vec<_>>());
+
+    let deleted = services_v1::delete_rows_v1(
+        &harness.state,
+        DeleteRowsRequestV1 {
+            table_id: harness.table_id.clone(),
+            filter: "id >= 20 AND id < 30".to_string(),
+            allow_full_table: false,
+            chunk_size: None,
+            job_id: None,
+            checkpoint_note: None,
+        },
+    )
+    .await;
+    assert!(
+        deleted.ok,
+        "delete_rows should succeed: {:?}",
+        deleted.error
+    );
+
+    let third = services_v1::scan_v1(&harness.state, scan(20)).await;
+    assert!(third.ok, "scan should succeed: {:?}", third.error);
+    assert_eq!(
+        page_ids(third.data.expect("scan data")),
+        (30..40).collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test]
+async fn finished_queries_leave_the_active_list() {
+    let harness = create_command_harness().await;
+    let queried = services_v1::query_filter_v1(
+        &harness.state,
+        QueryFilterRequestV1 {
+            table_id: harness.table_id.clone(),
+            filter: "id < 5".to_string(),
+            projection: Some(vec!["id".to_string()]),
+            projection_preset: None,
+            limit: Some(10),
+            offset: Some(0),
+            include_stats: false,
+            json_options: None,
+        },
+    )
+    .await;
+    assert!(queried.ok, "query should succeed: {:?}", queried.error);
+
+    let active = services_v1::list_active_queries_v1(&harness.state)
+        .await
+        .data
+        .expect("active queries");
+    assert!(active.queries.is_empty());
+
+    let missing = services_v1::kill_query_v1(
+        &harness.state,
+        KillQueryRequestV1 {
+            query_id: "missing".to_string(),
+        },
+    )
+    .await;
+    assert_eq!(missing.error.expect("error").code, ErrorCode::NotFound);
+}
+
+#[tokio::test]
+async fn local_first_pages_stay_warm_until_the_table_changes() {
+    let harness = create_command_harness().await;
+    let scan = || ScanRequestV1 {
+        table_id: harness.table_id.clone(),
+        format: DataFormat::Json,
+        projection: Some(vec!["id".to_string()]),
+        projection_preset: None,
+        filter: None,
+        limit: Some(10),
+        offset: Some(0),
+        apply_masking: false,
+        prefetch: false,
+        include_stats: false,
+        json_options: None,
+        as_of_version: None,
+        max_chunk_bytes: None,
+        job_id: None,
+    };
+
+    let first = services_v1::scan_v1(&harness.state, scan()).await;
+    assert!(first.ok, "scan should succeed: {:?}", first.error);
+    assert_eq!(
+        services_v1::health_v1(&harness.state)
+            .await
+            .data
+            .expect("health data")
+            .warm_tables,
+        1
+    );
+    let again = services_v1::scan_v1(&harness.state, scan()).await;
+    assert!(again.ok, "scan should succeed: {:?}", again.error);
+    assert_eq!(
+        again.data.expect("scan data").version,
+        first.data.expect("scan data").version
+    );
+
+    let deleted = services_v1::delete_rows_v1(
+        &harness.state,
+        DeleteRowsRequestV1 {
+            table_id: harness.table_id.clone(),
+            filter: "id < 5".to_string(),
+            allow_full_table: false,
+            chunk_size: None,
+            job_id: None,
+            checkpoint_note: None,
+        },
+    )
+    .await;
+    assert!(
+        deleted.ok,
+        "delete_rows should succeed: {:?}",
+        deleted.error
+    );
+    assert_eq!(
+        services_v1::health_v1(&harness.state)
+            .await
+            .data
+            .expect("health data")
+            .warm_tables,
+        0
+    );
+
+    let after = services_v1::scan_v1(&harness.state, scan()).await;
+    assert!(after.ok, "scan should succeed: {:?}", after.error);
+    let ids = match after.data.expect("scan data").chunk {
+        DataChunk::Json(chunk) => chunk
+            .rows
+            .iter()
+            .map(|row| row["id"].as_i64().expect("id"))
+            .collect::<Vec<_>>(),
+        _ => panic!("expected json chunk"),
+    };
+    assert_eq!(ids, (5..15).collect::<Vec<_>>());
+}
+
+#[tokio::test]
+async fn dictionary_columns_can_be_created_written_and_scanned() {
+    let harness = create_command_harness().await;
+
+    let created = services_v1::create_table_v1(
+        &harness.state,
+        CreateTableRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            table_name: "labels".to_string(),
+            schema: SchemaDefinitionInput {
+                fields: vec![
+                    SchemaFieldInput {
+                        name: "id".to_string(),
+                        data_type: FieldDataType::Int32,
+                        nullable: false,
+                        metadata: None,
+                        vector_length: None,
+                        embedding: None,
+                    },
+                    SchemaFieldInput {
+                        name: "label".to_string(),
+                        data_type: FieldDataType::DictionaryUtf8,
+                        nullable: true,
+                        metadata: None,
+                        vector_length: None,
+                        embedding: None,
+                    },
+                ],
+            },
+            options: Default::default(),
+        },
+    )
+    .await;
+    assert!(
+        created.ok,
+        "create_table should succeed: {:?}",
+        created.error
+    );
+    let table_id = created.data.expect("create table data").table_id;
+
+    let write = services_v1::write_rows_v1(
+        &harness.state,
+        WriteRowsRequestV1 {
+            table_id: table_id.clone(),
+            rows: vec![
+                serde_json::json!({"id": 1, "label": "cat"}),
+                serde_json::json!({"id": 2, "label": "dog"}),
+                serde_json::json!({"id": 3, "label": "cat"}),
+            ],
+            mode: WriteDataMode::Append,
+            normalize_vectors: false,
+            checkpoint_note: None,
+        },
+    )
+    .await;
+    assert!(write.ok, "write_rows should succeed: {:?}", write.error);
+
+    let scan_json = services_v1::scan_v1(
+        &harness.state,
+        ScanRequestV1 {
+            table_id: table_id.clone(),
+            format: DataFormat::Json,
+            projection: None,
+            projection_preset: None,
+            filter: Some("id = 3".to_string()),
+            limit: Some(10),
+            offset: Some(0),
+            apply_masking: false,
+            prefetch: false,
+            include_stats: false,
+            json_options: None,
+            as_of_version: None,
+            max_chunk_bytes: None,
+            job_id: None,
+        },
+    )
+    .await;
+    assert!(
+        scan_json.ok,
+        "scan json should succeed: {:?}",
+        scan_json.error
+    );
+    match scan_json.data.expect("scan data").chunk {
+        lancedb_viewer_lib::ipc::v1::DataChunk::Json(chunk) => {
+            assert_eq!(chunk.rows.len(), 1);
+            assert_eq!(chunk.rows[0]["label"], serde_json::json!("cat"));
+        }
+        _ => panic!("expected json chunk"),
+    }
+
+    let scan_arrow = services_v1::scan_v1(
+        &harness.state,
+        ScanRequestV1 {
+            table_id,
+            format: DataFormat::Arrow,
+            projection: None,
+            projection_preset: None,
+            filter: None,
+            limit: Some(10),
+            offset: Some(0),
+            apply_masking: false,
+            prefetch: false,
+            include_stats: false,
+            json_options: None,
+            as_of_version: None,
+            max_chunk_bytes: None,
+            job_id: None,
+        },
+    )
+    .await;
+    assert!(
+        scan_arrow.ok,
+        "scan arrow should succeed: {:?}",
+        scan_arrow.error
+    );
+    let ipc_base64 = match scan_arrow.data.expect("scan arrow").chunk {
+        lancedb_viewer_lib::ipc::v1::DataChunk::Arrow(chunk) => chunk.ipc_base64,
+        _ => panic!("expected arrow chunk"),
+    };
+    let decoded = general_purpose::STANDARD
+        .decode(ipc_base64)
+        .expect("decode base64");
+    let reader = StreamReader::try_new(Cursor::new(decoded), None).expect("open stream reader");
+    assert_eq!(
+        reader
+            .schema()
+            .field_with_name("label")
+            .expect("label")
+            .data_type(),
+        &DataType::Utf8
+    );
+    let row_count: usize = reader
+        .map(|batch| batch.expect("read batch").num_rows())
+        .sum();
+    assert_eq!(row_count, 3);
+}
+
+#[tokio::test]
+async fn vector_search_reports_defaulted_search_params() {
+    let harness = create_command_harness().await;
+
+    let unindexed = services_v1::vector_search_v1(
+        &harness.state,
+        VectorSearchRequestV1 {
+            table_id: harness.table_id.clone(),
+            vector: vec![0.1, 0.2, 0.3],
+            column: Some("vector".to_string()),
+            top_k: Some(3),
+            projection: None,
+            projection_preset: None,
+            filter: None,
+            nprobes: None,
+            refine_factor: None,
+            offset: None,
+            include_stats: false,
+            json_options: None,
+            distance_type: None,
+            as_of_version: None,
+            query_embedding: None,
+            normalize_query: false,
+        },
+    )
+    .await;
+    assert!(
+        unindexed.ok,
+        "vector_search should succeed: {:?}",
+        unindexed.error
+    );
+    let params = unindexed
+        .data
+        .expect("vector search data")
+        .search_params
+        .expect("search params");
+    assert!(params.index_name.is_none());
+    assert!(params.nprobes.is_none());
+    assert!(!params.nprobes_defaulted);
+
+    let created = services_v1::create_index_v1(
+        &harness.state,
+        CreateIndexRequestV1 {
+            table_id: harness.table_id.clone(),
+            columns: vec!["vector".to_string()],
+            index_type: IndexTypeV1::IvfFlat,
+            name: Some("vector_ivf".to_string()),
+            replace: true,
+            distance_type: None,
+            num_partitions: Some(2),
+            sample_rate: None,
+            max_iterations: None,
+            target_partition_size: None,
+            num_sub_vectors: None,
+            num_bits: None,
+            num_edges: None,
+            ef_construction: None,
+            accelerator: None,
+            fts_tokenizer: None,
+            job_id: None,
+            preset: None,
+        },
+    )
+    .await;
+    assert!(
+        created.ok,
+        "create_index should succeed: {:?}",
+        created.error
+    );
+
+    let defaulted = services_v1::vector_search_v1(
+        &harness.state,
+        VectorSearchRequestV1 {
+            table_id: harness.table_id.clone(),
+            vector: vec![0.1, 0.2, 0.3],
+            column: Some("vector".to_string()),
+            top_k: Some(3),
+            projection: None,
+            projection_preset: None,
+            filter: None,
+            nprobes: None,
+            refine_factor: None,
+            offset: None,
+            include_stats: false,
+            json_options: None,
+            distance_type: None,
+            as_of_version: None,
+            query_embedding: None,
+            normalize_query: false,
+        },
+    )
+    .await;
+    assert!(
+        defaulted.ok,
+        "vector_search should succeed: {:?}",
+        defaulted.error
+    );
+    let params = defaulted
+        .data
+        .expect("vector search data")
+        .search_params
+        .expect("search params");
+    assert_eq!(params.index_name.as_deref(), Some("vector_ivf"));
+    assert!(params.nprobes_defaulted);
+    assert!(params.nprobes.is_some_and(|nprobes| nprobes >= 1));
+    assert!(params.refine_factor.is_none());
+
+    let explicit = services_v1::vector_search_v1(
+        &harness.state,
+        VectorSearchRequestV1 {
+            table_id: harness.table_id.clone(),
+            vector: vec![0.1, 0.2, 0.3],
+            column: Some("vector".to_string()),
+            top_k: Some(3),
+            projection: None,
+            projection_preset: None,
+            filter: None,
+            nprobes: Some(2),
+            refine_factor: None,
+            offset: None,
+            include_stats: false,
+            json_options: None,
+            distance_type: None,
+            as_of_version: None,
+            query_embedding: None,
+            normalize_query: false,
+        },
+    )
+    .await;
+    let params = explicit
+        .data
+        .expect("vector search data")
+        .search_params
+        .expect("search params");
+    assert_eq!(params.nprobes, Some(2));
+    assert!(!params.nprobes_defaulted);
+}
+
+#[tokio::test]
+async fn include_stats_returns_execution_report() {
+    let harness = create_command_harness().await;
+
+    let scan = services_v1::scan_v1(
+        &harness.state,
+        ScanRequestV1 {
+            table_id: harness.table_id.clone(),
+            format: DataFormat::Json,
+            projection: None,
+            projection_preset: None,
+            filter: Some("id > 10".to_string()),
+            limit: Some(5),
+            offset: Some(0),
+            apply_masking: false,
+            prefetch: false,
+            include_stats: true,
+            json_options: None,
+            as_of_version: None,
+            max_chunk_bytes: None,
+            job_id: None,
+        },
+    )
+    .await;
+    assert!(scan.ok, "scan should succeed: {:?}", scan.error);
+    let stats = scan.data.expect("scan data").stats.expect("scan stats");
+    assert!(!stats.plan.is_empty());
+    assert!(!stats.stages.is_empty());
+    assert!(!stats.index_used);
+    assert!(stats.flat_scan);
+
+    let search = services_v1::vector_search_v1(
+        &harness.state,
+        VectorSearchRequestV1 {
+            table_id: harness.table_id.clone(),
+            vector: vec![0.1, 0.2, 0.3],
+            column: Some("vector".to_string()),
+            top_k: Some(3),
+            projection: None,
+            projection_preset: None,
+            filter: None,
+            nprobes: None,
+            refine_factor: None,
+            offset: None,
+            include_stats: true,
+            json_options: None,
+            distance_type: None,
+            as_of_version: None,
+            query_embedding: None,
+            normalize_query: false,
+        },
+    )
+    .await;
+    assert!(
+        search.ok,
+        "vector_search should succeed: {:?}",
+        search.error
+    );
+    let search = search.data.expect("vector search data");
+    let stats = search.stats.expect("vector search stats");
+    assert!(stats.flat_scan);
+    assert!(stats.stages.iter().any(|stage| stage.output_rows.is_some()));
+
+    let without_stats = services_v1::query_filter_v1(
+        &harness.state,
+        QueryFilterRequestV1 {
+            table_id: harness.table_id.clone(),
+            filter: "id < 5".to_string(),
+            projection: None,
+            projection_preset: None,
+            limit: Some(5),
+            offset: None,
+            include_stats: false,
+            json_options: None,
+        },
+    )
+    .await;
+    assert!(without_stats.data.expect("query data").stats.is_none());
+}
+
+#[tokio::test]
+async fn set_log_level_persists_settings() {
+    let data_dir = tempdir().expect("create data dir");
+    let state = AppState::with_data_dir(data_dir.path().to_path_buf());
+
+    let updated = services_v1::set_log_level_v1(
+        &state,
+        SetLogLevelRequestV1 {
+            level: LogLevelV1::Debug,
+            module_filter: Some(" lancedb_viewer_lib::services , ".to_string()),
+        },
+    )
+    .await;
+    assert!(
+        updated.ok,
+        "set_log_level should succeed: {:?}",
+        updated.error
+    );
+    let settings = updated.data.expect("log settings").settings;
+    assert_eq!(settings.level, LogLevelV1::Debug);
+    assert_eq!(
+        settings.module_filter.as_deref(),
+        Some("lancedb_viewer_lib::services")
+    );
+
+    let reloaded = AppState::with_data_dir(data_dir.path().to_path_buf());
+    let persisted = reloaded
+        .log_settings
+        .lock()
+        .expect("lock log settings")
+        .get()
+        .expect("persisted log settings");
+    assert_eq!(persisted.level, LogLevelV1::Debug);
+    assert_eq!(
+        persisted.module_filter.as_deref(),
+        Some("lancedb_viewer_lib::services")
+    );
+}
+
+#[tokio::test]
+async fn health_reports_backend_state() {
+    let harness = create_command_harness().await;
+
+    let health = services_v1::health_v1(&harness.state).await;
+    assert!(health.ok, "health should succeed: {:?}", health.error);
+    let health = health.data.expect("health data");
+    assert!(!health.version.is_empty());
+    assert_eq!(health.active_connections, 1);
+    assert_eq!(health.open_tables, 1);
+    assert!(health.running_jobs.is_empty());
+    assert_eq!(health.schedules, 0);
+    if cfg!(target_os = "linux") {
+        assert!(health.resident_memory_bytes.is_some_and(|bytes| bytes > 0));
+    }
+}
+
+/// Accepts one HTTP request on a local port and hands back its JSON body.
+fn spawn_webhook_receiver() -> (String, mpsc::Receiver<serde_json::Value>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind webhook receiver");
+    let url = format!("http://{}/jobs", listener.local_addr().expect("address"));
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let (mut stream, _) = listener.accept().expect("accept webhook");
+        let mut reader = BufReader::new(stream.try_clone().expect("clone stream"));
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).expect("read header");
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().expect("content length");
+                }
+            }
+        }
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).expect("read body");
+        stream
+            .write_all(b"HTTP/1.1 204 No Content\r\nContent-Length: 0\r\n\r\n")
+            .expect("respond");
+        let _ = sender.send(serde_json::from_slice(&body).expect("json body"));
+    });
+    (url, receiver)
+}
+
+#[tokio::test]
+async fn index_presets_size_parameters_from_the_table() {
+    let harness = create_command_harness().await;
+    let request = |index_type: IndexTypeV1, column: &str| CreateIndexRequestV1 {
+        table_id: harness.table_id.clone(),
+        columns: vec![column.to_string()],
+        index_type,
+        name: None,
+        replace: true,
+        distance_type: None,
+        num_partitions: None,
+        sample_rate: None,
+        max_iterations: Some(5),
+        target_partition_size: None,
+        num_sub_vectors: None,
+        num_bits: None,
+        num_edges: None,
+        ef_construction: None,
+        accelerator: None,
+        fts_tokenizer: None,
+        job_id: None,
+        preset: Some(IndexPresetV1::FastBuild),
+    };
+
+    let created =
+        services_v1::create_index_v1(&harness.state, request(IndexTypeV1::IvfFlat, "vector")).await;
+    assert!(created.ok, "preset index failed: {:?}", created.error);
+    let parameters = created
+        .data
+        .expect("index")
+        .preset_parameters
+        .expect("preset parameters");
+    assert_eq!(parameters.preset, IndexPresetV1::FastBuild);
+    assert_eq!(parameters.num_partitions, 1);
+    assert_eq!(parameters.sample_rate, 64);
+    assert!(parameters.num_sub_vectors.is_none());
+
+    let scalar =
+        services_v1::create_index_v1(&harness.state, request(IndexTypeV1::BTree, "id")).await;
+    assert_eq!(
+        scalar.error.expect("error").code,
+        ErrorCode::InvalidArgument
+    );
+    let not_vector =
+        services_v1::create_index_v1(&harness.state, request(IndexTypeV1::IvfFlat, "id")).await;
+    assert_eq!(
+        not_vector.error.expect("error").code,
+        ErrorCode::InvalidArgument
+    );
+}
+
+#[tokio::test]
+async fn restore_table_version_commits_old_state_as_latest() {
+    let harness = create_command_harness().await;
+    let table = harness
+        .state
+        .connections
+        .lock()
+        .expect("lock")
+        .get_table(&harness.table_id)
+        .expect("table");
+    let original = table.version().await.expect("version");
+    table.delete("id < 10").await.expect("delete rows");
+    let deleted = table.version().await.expect("version");
+
+    let restored = services_v1::restore_table_version_v1(
+        &harness.state,
+        RestoreTableVersionRequestV1 {
+            table_id: harness.table_id.clone(),
+            version: original,
+            checkpoint_note: None,
+        },
+    )
+    .await;
+    assert!(restored.ok, "restore should succeed: {:?}", restored.error);
+    let restored = restored.data.expect("restore data");
+    assert_eq!(restored.restored_version, original);
+    assert!(restored.version > deleted);
+    assert_eq!(table.version().await.expect("version"), restored.version);
+    assert_eq!(table.count_rows(None).await.expect("count"), 50);
+
+    table.delete("id = 0").await.expect("table stays writable");
+    assert_eq!(table.count_rows(None).await.expect("count"), 49);
+
+    let missing = services_v1::restore_table_version_v1(
+        &harness.state,
+        RestoreTableVersionRequestV1 {
+            table_id: harness.table_id.clone(),
+            version: 999,
+            checkpoint_note: None,
+        },
+    )
+    .await;
+    assert_eq!(missing.error.expect("error").code, ErrorCode::NotFound);
+}
+
+#[tokio::test]
+async fn version_tags_can_be_managed_and_checked_out() {
+    let harness = create_command_harness().await;
+    let table = harness
+        .state
+        .connections
+        .lock()
+        .expect("lock")
+        .get_table(&harness.table_id)
+        .expect("table");
+    let first = table.version().await.expect("version");
+    table.delete("id = 1").await.expect("delete row");
+    let latest = table.version().await.expect("version");
+
+    let created = services_v1::create_tag_v1(
+        &harness.state,
+        CreateTagRequestV1 {
+            table_id: harness.table_id.clone(),
+            name: "baseline".to_string(),
+            version: Some(first),
+        },
+    )
+    .await;
+    assert!(created.ok, "create tag should succeed: {:?}", created.error);
+    let created = services_v1::create_tag_v1(
+        &harness.state,
+        CreateTagRequestV1 {
+            table_id: harness.table_id.clone(),
+            name: "current".to_string(),
+            version: None,
+        },
+    )
+    .await;
+    assert_eq!(created.data.expect("tag").tag.version, latest);
+
+    let duplicate = services_v1::create_tag_v1(
+        &harness.state,
+        CreateTagRequestV1 {
+            table_id: harness.table_id.clone(),
+            name: "baseline".to_string(),
+            version: None,
+        },
+    )
+    .await;
+    assert_eq!(
+        duplicate.error.expect("error").code,
+        ErrorCode::InvalidArgument
+    );
+    let invalid = services_v1::create_tag_v1(
+        &harness.state,
+        CreateTagRequestV1 {
+            table_id: harness.table_id.clone(),
+            name: "no spaces".to_string(),
+            version: None,
+        },
+    )
+    .await;
+    assert_eq!(
+        invalid.error.expect("error").code,
+        ErrorCode::InvalidArgument
+    );
+
+    let listed = services_v1::list_tags_v1(
+        &harness.state,
+        ListTagsRequestV1 {
+            table_id: harness.table_id.clone(),
+        },
+    )
+    .await;
+    let tags = listed.data.expect("tags").tags;
+    assert_eq!(
+        tags.iter()
+            .map(|tag| (tag.name.as_str(), tag.version))
+            .collect::<Vec<_>>(),
+        vec![("current", latest), ("baseline", first)]
+    );
+
+    let updated = services_v1::update_tag_v1(
+        &harness.state,
+        UpdateTagRequestV1 {
+            table_id: harness.table_id.clone(),
+            name: "current".to_string(),
+            version: first,
+        },
+    )
+    .await;
+    assert!(updated.ok, "update tag should succeed: {:?}", updated.error);
+
+    let checkout = services_v1::checkout_table_version_v1(
+        &harness.state,
+        CheckoutTableVersionRequestV1 {
+            table_id: harness.table_id.clone(),
+            version: None,
+            tag: Some("baseline".to_string()),
+        },
+    )
+    .await;
+    assert!(
+        checkout.ok,
+        "checkout by tag should succeed: {:?}",
+        checkout.error
+    );
+    assert_eq!(checkout.data.expect("checkout").version, first);
+    assert_eq!(table.count_rows(None).await.expect("count"), 50);
+
+    let ambiguous = services_v1::checkout_table_version_v1(
+        &harness.state,
+        CheckoutTableVersionRequestV1 {
+            table_id: harness.table_id.clone(),
+            version: Some(first),
+            tag: Some("baseline".to_string()),
+        },
+    )
+    .await;
+    assert_eq!(
+        ambiguous.error.expect("error").code,
+        ErrorCode::InvalidArgument
+    );
+
+    let deleted = services_v1::delete_tag_v1(
+        &harness.state,
+        DeleteTagRequestV1 {
+            table_id: harness.table_id.clone(),
+            name: "baseline".to_string(),
+        },
+    )
+    .await;
+    assert!(deleted.ok, "delete tag should succeed: {:?}", deleted.error);
+    let missing = services_v1::checkout_table_version_v1(
+        &harness.state,
+        CheckoutTableVersionRequestV1 {
+            table_id: harness.table_id.clone(),
+            version: None,
+            tag: Some("baseline".to_string()),
+        },
+    )
+    .await;
+    assert_eq!(missing.error.expect("error").code, ErrorCode::NotFound);
+}
+
+#[tokio::test]
+async fn access_log_records_share_requests_only() {
+    let harness = create_command_harness().await;
+    let local = services_v1::get_schema_v1(
+        &harness.state,
+        GetSchemaRequestV1 {
+            table_id: harness.table_id.clone(),
+        },
+    )
+    .await;
+    assert!(local.ok, "local schema should succeed: {:?}", local.error);
+
+    let session = services_v1::start_share_v1(
+        &harness.state,
+        StartShareRequestV1 {
+            tables: vec![ShareTableV1 {
+                table_id: harness.table_id.clone(),
+                projection: None,
+                filter: None,
+            }],
+            expires_in_seconds: Some(60),
+            port: None,
+            local_only: true,
+        },
+    )
+    .await
+    .data
+    .expect("session");
+    let base = session.url.split('?').next().expect("base url").to_string();
+    let (status, _) = http_get(&format!(
+        "{base}api/tables/{}?token={}",
+        harness.table_name, session.token
+    ));
+    assert_eq!(status, "HTTP/1.1 200 OK");
+    let (status, _) = http_get(&format!("{base}?token=wrong"));
+    assert_eq!(status, "HTTP/1.1 403 Forbidden");
+
+    let request = GetAccessLogRequestV1 {
+        transport: None,
+        client: None,
+        command: None,
+        since_ms: None,
+        limit: None,
+    };
+    let log = services_v1::get_access_log_v1(&harness.state, request.clone())
+        .await
+        .data
+        .expect("access log");
+    let commands = log
+        .entries
+        .iter()
+        .map(|entry| entry.command.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(commands, vec!["share_index", "share_api"]);
+    for entry in &log.entries {
+        assert_eq!(entry.transport, AccessTransportV1::Http);
+        assert!(entry.client.starts_with("127.0.0.1:"), "{}", entry.client);
+    }
+    assert_eq!(log.entries[0].error_code, Some(ErrorCode::PermissionDenied));
+    assert!(log.entries[1].ok);
+    assert_eq!(
+        log.entries[1].table.as_deref(),
+        Some(harness.table_name.as_str())
+    );
+
+    let zero_limit = services_v1::get_access_log_v1(
+        &harness.state,
+        GetAccessLogRequestV1 {
+            limit: Some(0),
+            ..request
+        },
+    )
+    .await;
+    assert_eq!(
+        zero_limit.error.as_ref().map(|error| &error.code),
+        Some(&ErrorCode::InvalidArgument)
+    );
+}
+
+#[tokio::test]
+async fn compare_query_versions_diffs_rows_by_key() {
+    let harness = create_command_harness().await;
+    let table = harness
+        .state
+        .connections
+        .lock()
+        .expect("lock")
+        .get_table(&harness.table_id)
+        .expect("table");
+    let before = table.version().await.expect("version");
+    table.delete("id = 1").await.expect("delete row");
+    table
+        .update()
+        .only_if("id = 2")
+        .column("text", "'edited'")
+        .execute()
+        .await
+        .expect("update row");
+    let after = table.version().await.expect("version");
+
+    let request = |key_columns: Vec<String>| CompareQueryVersionsRequestV1 {
+        table_id: harness.table_id.clone(),
+        query: MaterializeSourceV1::Filter(QueryFilterRequestV1 {
+            table_id: harness.table_id.clone(),
+            filter: "id < 5".to_string(),
+            projection: Some(vec!["id".to_string(), "text".to_string()]),
+            projection_preset: None,
+            limit: None,
+            offset: None,
+            include_stats: false,
+            json_options: None,
+        }),
+        version_a: before,
+        version_b: after,
+        key_columns,
+        max_rows: None,
+    };
+
+    let compared =
+        services_v1::compare_query_versions_v1(&harness.state, request(vec!["id".to_string()]))
+            .await;
+    assert!(compared.ok, "compare should succeed: {:?}", compared.error);
+    let compared = compared.data.expect("compare data");
+    assert_eq!((compared.rows_a, compared.rows_b), (5, 4));
+    assert!(compared.added.is_empty());
+    assert_eq!(
+        compared.removed,
+        vec![serde_json::json!({"id": 1, "text": "item 1"})]
+    );
+    assert_eq!(compared.changed.len(), 1);
+    assert_eq!(compared.changed[0].key, serde_json::json!({"id": 2}));
+    assert_eq!(
+        compared.changed[0].after["text"],
+        serde_json::json!("edited")
+    );
+    assert_eq!(compared.changed[0].columns, vec!["text"]);
+    assert_eq!(compared.unchanged_count, 3);
+
+    let by_value = services_v1::compare_query_versions_v1(&harness.state, request(Vec::new()))
+        .await
+        .data
+        .expect("compare data");
+    assert_eq!((by_value.added_count, by_value.removed_count), (1, 2));
+    assert_eq!(by_value.changed_count, 0);
+
+    let missing_key =
+        services_v1::compare_query_versions_v1(&harness.state, request(vec!["vector".to_string()]))
+            .await;
+    assert_eq!(
+        missing_key.error.as_ref().map(|error| &error.code),
+        Some(&ErrorCode::InvalidArgument)
+    );
+}
+
+#[tokio::test]
+async fn alter_columns_rewrites_saved_settings_of_renamed_columns() {
+    let harness = create_command_harness().await;
+    let reordered = services_v1::reorder_columns_v1(
+        &harness.state,
+        ReorderColumnsRequestV1 {
+            table_id: harness.table_id.clone(),
+            order: vec!["text".to_string(), "id".to_string()],
+            checkpoint_note: None,
+        },
+    )
+    .await;
+    assert!(reordered.ok, "reorder failed: {:?}", reordered.error);
+    let defaults = services_v1::set_search_defaults_v1(
+        &harness.state,
+        SetSearchDefaultsRequestV1 {
+            table_id: harness.table_id.clone(),
+            defaults: Some(SearchDefaultsV1 {
+                vector_column: Some("vector".to_string()),
+                distance_type: None,
+                top_k: None,
+                fts_columns: vec!["text".to_string()],
+            }),
+        },
+    )
+    .await;
+    assert!(defaults.ok, "set defaults failed: {:?}", defaults.error);
+    let masking = services_v1::set_masking_rules_v1(
+        &harness.state,
+        SetMaskingRulesRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            rules: vec![MaskingRuleV1 {
+                table_name: None,
+                column: "text".to_string(),
+                strategy: MaskingStrategyV1::Redact,
+            }],
+        },
+    )
+    .await;
+    assert!(masking.ok, "set masking failed: {:?}", masking.error);
+    let template = services_v1::save_export_template_v1(
+        &harness.state,
+        SaveExportTemplateRequestV1 {
+            template: ExportTemplateV1 {
+                name: "items".to_string(),
+                format: DataFileFormatV1::Csv,
+                path_pattern: "/exports/{table}.csv".to_string(),
+                projection: Some(vec!["id".to_string(), "text".to_string()]),
+                filter: Some("`text` LIKE 'text%'".to_string()),
+                limit: None,
+                delimiter: None,
+                with_header: None,
+            },
+        },
+    )
+    .await;
+    assert!(template.ok, "save template failed: {:?}", template.error);
+    let scanned = services_v1::scan_v1(
+        &harness.state,
+        ScanRequestV1 {
+            table_id: harness.table_id.clone(),
+            format: DataFormat::Json,
+            projection: None,
+            projection_preset: None,
+            filter: Some("text = 'item 1'".to_string()),
+            limit: Some(1),
+            offset: None,
+            apply_masking: false,
+            prefetch: false,
+            include_stats: false,
+            json_options: None,
+            as_of_version: None,
+            max_chunk_bytes: None,
+            job_id: None,
+        },
+    )
+    .await;
+    assert!(scanned.ok, "scan failed: {:?}", scanned.error);
+
+    let altered = services_v1::alter_columns_v1(
+        &harness.state,
+        AlterColumnsRequestV1 {
+            table_id: harness.table_id.clone(),
+            columns: vec![ColumnAlterationInput {
+                path: "text".to_string(),
+                rename: Some("label".to_string()),
+                nullable: None,
+                data_type: None,
+                vector_length: None,
+            }],
+            rebuild_indexes: false,
+            checkpoint_note: None,
+        },
+    )
+    .await;
+    assert!(altered.ok, "alter failed: {:?}", altered.error);
+    let altered = altered.data.expect("alter columns");
+    let kinds = altered
+        .updated_artifacts
+        .iter()
+        .map(|artifact| artifact.kind)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        vec![
+            SavedArtifactKindV1::ColumnOrder,
+            SavedArtifactKindV1::SearchDefaults,
+            SavedArtifactKindV1::MaskingRule,
+            SavedArtifactKindV1::RecentFilter,
+            SavedArtifactKindV1::ExportTemplate,
+        ]
+    );
+    assert_eq!(altered.schema.fields[0].name, "label");
+
+    let defaults = services_v1::get_search_defaults_v1(
+        &harness.state,
+        GetSearchDefaultsRequestV1 {
+            table_id: harness.table_id.clone(),
+        },
+    )
+    .await
+    .data
+    .and_then(|data| data.defaults)
+    .expect("search defaults");
+    assert_eq!(defaults.fts_columns, vec!["label"]);
+    assert_eq!(defaults.vector_column.as_deref(), Some("vector"));
+
+    let rules = services_v1::list_masking_rules_v1(
+        &harness.state,
+        ListMaskingRulesRequestV1 {
+            connection_id: harness.connection_id.clone(),
+        },
+    )
+    .await
+    .data
+    .expect("masking rules")
+    .rules;
+    assert_eq!(rules.len(), 2);
+    assert_eq!(
+        rules[1].table_name.as_deref(),
+        Some(harness.table_name.as_str())
+    );
+    assert_eq!(rules[1].column, "label");
+
+    let recent = services_v1::recent_filters_v1(
+        &harness.state,
+        RecentFiltersRequestV1 {
+            table_id: harness.table_id.clone(),
+            prefix: None,
+            sort: None,
+            limit: None,
+        },
+    )
+    .await
+    .data
+    .expect("recent filters");
+    assert_eq!(recent.filters[0].filter, "label = 'item 1'");
+
+    let template = services_v1::list_export_templates_v1(&harness.state)
+        .await
+        .data
+        .expect("templates")
+        .templates
+        .remove(0);
+    assert_eq!(
+        template.projection,
+        Some(vec!["id".to_string(), "label".to_string()])
+    );
+    assert_eq!(template.filter.as_deref(), Some("`label` LIKE 'text%'"));
+}
+
+#[tokio::test]
+async fn watch_folders_append_new_files_once() {
+    let harness = create_command_harness().await;
+    let watch_dir = tempdir().expect("create watch dir");
+    fs::write(
+        watch_dir.path().join("existing.jsonl"),
+        "{\"id\": 90, \"text\": \"old\", \"vector\": [0.0, 0.0, 0.0]}\n",
+    )
+    .expect("write existing");
+
+    let created = services_v1::set_watch_folder_v1(
+        &harness.state,
+        SetWatchFolderRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            id: None,
+            table_name: harness.table_name.clone(),
+            directory: watch_dir.path().to_string_lossy().to_string(),
+            format: DataFileFormatV1::Jsonl,
+            has_header: None,
+            delimiter: None,
+            lineage: false,
+            enabled: None,
+            include_existing: false,
+        },
+    )
+    .await;
+    assert!(created.ok, "set_watch_folder failed: {:?}", created.error);
+    let created = created.data.expect("watch folder").watch_folder;
+    assert!(created.enabled);
+    assert_eq!(created.seen_files, vec!["existing.jsonl"]);
+
+    fs::write(
+        watch_dir.path().join("new.jsonl"),
+        "{\"id\": 100, \"text\": \"first\", \"vector\": [1.0, 2.0, 3.0]}\n{\"id\": 101, \"text\": \"second\", \"vector\": [1.0, 1.0, 1.0]}\n",
+    )
+    .expect("write new");
+    // Past the settle time of the file just written.
+    let later = SystemTime::now() + Duration::from_secs(60);
+    let mut events = Vec::new();
+    let handled = watch_folders::run_watch_folders(&harness.state, later, &mut |event| {
+        events.push(event.clone())
+    })
+    .await;
+    assert_eq!(handled, 1);
+    assert_eq!(events[0].file_name, "new.jsonl");
+    assert_eq!(events[0].rows, 2);
+    assert!(
+        events[0].error.is_none(),
+        "import failed: {:?}",
+        events[0].error
+    );
+    assert_eq!(
+        watch_folders::run_watch_folders(&harness.state, later, &mut |_| {}).await,
+        0
+    );
+
+    let count = services_v1::get_row_count_v1(
+        &harness.state,
+        GetRowCountRequestV1 {
+            table_id: harness.table_id.clone(),
+        },
+    )
+    .await;
+    assert_eq!(count.data.expect("row count").row_count, 52);
+
+    let listed = services_v1::list_watch_folders_v1(
+        &harness.state,
+        ListWatchFoldersRequestV1 {
+            connection_id: Some(harness.connection_id.clone()),
+        },
+    )
+    .await
+    .data
+    .expect("watch folders")
+    .watch_folders;
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].seen_files, vec!["existing.jsonl", "new.jsonl"]);
+    assert!(listed[0].last_ingested_at_ms.is_some());
+
+    let deleted = services_v1::delete_watch_folder_v1(
+        &harness.state,
+        DeleteWatchFolderRequestV1 {
+            id: created.id.clone(),
+        },
+    )
+    .await;
+    assert!(
+        deleted.ok,
+        "delete_watch_folder failed: {:?}",
+        deleted.error
+    );
+    let missing = services_v1::delete_watch_folder_v1(
+        &harness.state,
+        DeleteWatchFolderRequestV1 { id: created.id },
+    )
+    .await;
+    assert_eq!(missing.error.expect("error").code, ErrorCode::NotFound);
+}
+
+#[tokio::test]
+async fn diagnostics_export_bundles_metrics_connections_and_logs() {
+    let mut harness = create_command_harness().await;
+    let log_dir = tempdir().expect("create log dir");
+    fs::write(
+        log_dir.path().join("lancedb-viewer.log.log"),
+        "[info] first\n[warn] second\n[error] third\n",
+    )
+    .expect("write log");
+    harness.state.log_dir = Some(log_dir.path().to_path_buf());
+    let out_dir = tempdir().expect("create output dir");
+    let path = out_dir.path().join("diagnostics.json");
+
+    let exported = services_v1::export_diagnostics_v1(
+        &harness.state,
+        ExportDiagnosticsRequestV1 {
+            path: path.to_string_lossy().to_string(),
+            log_lines: Some(2),
+            overwrite: false,
+        },
+    )
+    .await;
+    assert!(
+        exported.ok,
+        "export_diagnostics failed: {:?}",
+        exported.error
+    );
+    let exported = exported.data.expect("diagnostics data");
+    assert_eq!(exported.log_lines, 2);
+    assert_eq!(exported.connections, 1);
+
+    let report: DiagnosticsReportV1 =
+        serde_json::from_slice(&fs::read(&path).expect("read report")).expect("report json");
+    assert_eq!(report.logs, vec!["[warn] second", "[error] third"]);
+    assert_eq!(report.connections[0].connection_id, harness.connection_id);
+    assert_eq!(report.connections[0].open_tables, 1);
+    let open_table = report
+        .commands
+        .iter()
+        .find(|metrics| metrics.command == "open_table_v1")
+        .expect("open_table_v1 metrics");
+    assert_eq!((open_table.calls, open_table.errors), (1, 0));
+
+    let repeated = services_v1::export_diagnostics_v1(
+        &harness.state,
+        ExportDiagnosticsRequestV1 {
+            path: path.to_string_lossy().to_string(),
+            log_lines: None,
+            overwrite: false,
+        },
+    )
+    .await;
+    assert!(
+        !repeated.ok,
+        "export_diagnostics should not overwrite by default"
+    );
+}
+
+#[tokio::test]
+async fn repro_sample_exports_scrambled_rows_as_zip() {
+    let harness = create_command_harness().await;
+    let dir = tempdir().expect("create sample dir");
+    let path = dir.path().join("items-sample.zip");
+    let export = |overwrite| {
+        services_v1::export_repro_sample_v1(
+            &harness.state,
+            ExportReproSampleRequestV1 {
+                table_id: harness.table_id.clone(),
+                path: path.to_string_lossy().to_string(),
+                rows: 10,
+                filter: Some("id < 30".to_string()),
+                seed: Some(42),
+                overwrite,
+            },
+        )
+    };
+
+    let exported = export(false).await;
+    assert!(
+        exported.ok,
+        "export_repro_sample failed: {:?}",
+        exported.error
+    );
+    let exported = exported.data.expect("sample data");
+    assert_eq!(exported.manifest.rows, 10);
+    assert_eq!(exported.manifest.source_rows, 30);
+    assert_eq!(exported.manifest.seed, 42);
+    assert_eq!(exported.sha256.len(), 64);
+    assert_eq!(exported.bytes, fs::metadata(&path).expect("zip").len());
+
+    let mut archive = zip::ZipArchive::new(fs::File::open(&path).expect("open zip")).expect("zip");
+    let mut names = archive.file_names().map(str::to_string).collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(names, vec!["manifest.json", "sample.arrows", "schema.json"]);
+    let mut rows = Vec::new();
+    archive
+        .by_name("sample.arrows")
+        .expect("rows entry")
+        .read_to_end(&mut rows)
+        .expect("read rows");
+    let batches = StreamReader::try_new(Cursor::new(rows), None)
+        .expect("arrow stream")
+        .collect::<Result<Vec<_>, _>>()
+        .expect("batches");
+    let texts = batches
+        .iter()
+        .flat_map(|batch| {
+            let column = batch.column_by_name("text").expect("text column");
+            let column = column
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .expect("strings");
+            column
+                .iter()
+                .flatten()
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(texts.len(), 10);
+    assert!(texts.iter().all(|text| !text.starts_with("item ")));
+
+    let repeated = export(false).await;
+    assert!(
+        !repeated.ok,
+        "export_repro_sample should not overwrite by default"
+    );
+    let replaced = export(true).await;
+    assert!(replaced.ok, "overwrite failed: {:?}", replaced.error);
+}
+
+#[tokio::test]
+async fn normalized_writes_are_recorded_and_checked_by_searches() {
+    let harness = create_command_harness().await;
+    let write = |id: i32, vector: [f32; 3], normalize_vectors: bool| WriteRowsRequestV1 {
+        table_id: harness.table_id.clone(),
+        rows: vec![serde_json::json!({"id": id, "text": "normalized", "vector": vector})],
+        mode: WriteDataMode::Append,
+        normalize_vectors,
+        checkpoint_note: None,
+    };
+
+    let written = services_v1::write_rows_v1(&harness.state, write(900, [0.0, 3.0, 4.0], true))
+        .await
+        .data
+        .expect("normalized write");
+    assert_eq!(written.normalized_columns, vec!["vector"]);
+    let schema = services_v1::get_schema_v1(
+        &harness.state,
+        GetSchemaRequestV1 {
+            table_id: harness.table_id.clone(),
+        },
+    )
+    .await
+    .data
+    .expect("schema");
+    let vector = schema
+        .fields
+        .iter()
+        .find(|field| field.name == "vector")
+        .expect("vector field");
+    assert!(vector.normalized);
+
+    // Columns that record normalization stay normalized without the flag.
+    let later = services_v1::write_rows_v1(&harness.state, write(901, [0.0, 0.0, 2.0], false))
+        .await
+        .data
+        .expect("later write");
+    assert_eq!(later.normalized_columns, vec!["vector"]);
+    let rows = services_v1::query_filter_v1(
+        &harness.state,
+        QueryFilterRequestV1 {
+            table_id: harness.table_id.clone(),
+            filter: "id >= 900".to_string(),
+            projection: Some(vec!["id".to_string(), "vector".to_string()]),
+            projection_preset: None,
+            limit: Some(10),
+            offset: None,
+            include_stats: false,
+            json_options: None,
+        },
+    )
+    .await
+    .data
+    .expect("written rows");
+    let DataChunk::Json(chunk) = rows.chunk else {
+        panic!("expected json rows");
+    };
+    let mut vectors = chunk
+        .rows
+        .iter()
+        .map(|row| {
+            let values = row["vector"].as_array().expect("vector");
+            (
+                row["id"].as_i64().expect("id"),
+                values
+                    .iter()
+                    .map(|value| value.as_f64().expect("number"))
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect::<Vec<_>>();
+    vectors.sort_by_key(|(id, _)| *id);
+    let expected = [(900, [0.0, 0.6, 0.8]), (901, [0.0, 0.0, 1.0])];
+    assert_eq!(vectors.len(), expected.len());
+    for ((id, values), (expected_id, expected_values)) in vectors.iter().zip(expected) {
+        assert_eq!(*id, expected_id);
+        for (value, expected) in values.iter().zip(expected_values) {
+            assert!((value - expected).abs() < 1e-6, "{values:?}");
+        }
+    }
+
+    let search = |normalize_query: bool| VectorSearchRequestV1 {
+        table_id: harness.table_id.clone(),
+        vector: vec![0.0, 3.0, 4.0],
+        column: Some("vector".to_string()),
+        top_k: Some(1),
+        projection: None,
+        projection_preset: None,
+        filter: None,
+        nprobes: None,
+        refine_factor: None,
+        offset: None,
+        include_stats: false,
+        json_options: None,
+        distance_type: None,
+        as_of_version: None,
+        query_embedding: None,
+        normalize_query,
+    };
+    let raw = services_v1::vector_search_v1(&harness.state, search(false))
+        .await
+        .data
+        .expect("raw search");
+    assert!(raw
+        .warnings
+        .iter()
+        .any(|warning| warning.kind == SearchWarningKindV1::UnnormalizedQuery));
+    let normalized = services_v1::vector_search_v1(&harness.state, search(true))
+        .await
+        .data
+        .expect("normalized search");
+    assert!(normalized
+        .warnings
+        .iter()
+        .all(|warning| warning.kind != SearchWarningKindV1::UnnormalizedQuery));
+    let DataChunk::Json(nearest) = normalized.chunk else {
+        panic!("expected json rows");
+    };
+    assert_eq!(nearest.rows[0]["id"], 900);
+}
+
+#[tokio::test]
+async fn scan_stream_sends_acknowledged_arrow_chunks() {
+    let harness = create_command_harness().await;
+    let request = |window| ScanStreamRequestV1 {
+        table_id: harness.table_id.clone(),
+        projection: Some(vec!["id".to_string(), "text".to_string()]),
+        projection_preset: None,
+        filter: None,
+        limit: None,
+        offset: None,
+        apply_masking: false,
+        as_of_version: None,
+        // Every row goes out as its own chunk.
+        max_chunk_bytes: Some(1),
+        window: Some(window),
+    };
+
+    let mut events = Vec::new();
+    let mut received = 0u64;
+    let mut rows = 0usize;
+    let summary = services_v1::scan_stream_v1(&harness.state, request(1), |message| {
+        match message {
+            ScanStreamMessage::Event(event) => events.push(event),
+            ScanStreamMessage::Chunk(bytes) => {
+                let reader = StreamReader::try_new(Cursor::new(bytes), None).expect("chunk");
+                rows += reader
+                    .map(|batch| batch.expect("batch").num_rows())
+                    .sum::<usize>();
+                received += 1;
+                let Some(ScanStreamEventV1::Started(started)) = events.first() else {
+                    panic!("chunk before the started event");
+                };
+                // A window of one blocks until each chunk is acknowledged.
+                assert!(harness
+                    .state
+                    .scan_streams
+                    .lock()
+                    .expect("scan streams")
+                    .ack(&started.stream_id, received));
+            }
+        }
+        Ok(())
+    })
+    .await
+    .data
+    .expect("stream summary");
+    assert_eq!((summary.total_rows, summary.chunks), (50, 50));
+    assert_eq!(rows, 50);
+    assert!(!summary.cancelled);
+    match events.as_slice() {
+        [ScanStreamEventV1::Started(started), ScanStreamEventV1::Completed(completed)] => {
+            assert_eq!(started.window, 1);
+            assert_eq!(completed.total_rows, 50);
+        }
+        other => panic!("unexpected events: {other:?}"),
+    }
+    let late = services_v1::ack_scan_stream_v1(
+        &harness.state,
+        AckScanStreamRequestV1 {
+            stream_id: summary.stream_id,
+            sequence: 51,
+        },
+    )
+    .await
+    .data
+    .expect("late ack");
+    assert!(!late.active);
+
+    // A stream cancelled before its first chunk sends none.
+    let cancelled = services_v1::scan_stream_v1(&harness.state, request(2), |message| {
+        if let ScanStreamMessage::Event(ScanStreamEventV1::Started(started)) = message {
+            harness
+                .state
+                .scan_streams
+                .lock()
+                .expect("scan streams")
+                .cancel(&started.stream_id);
+        }
+        Ok(())
+    })
+    .await
+    .data
+    .expect("cancelled summary");
+    assert!(cancelled.cancelled);
+    assert_eq!(cancelled.chunks, 0);
+
+    let invalid = services_v1::scan_stream_v1(&harness.state, request(0), |_| Ok(())).await;
+    assert_eq!(
+        invalid.error.expect("invalid window").code,
+        ErrorCode::InvalidArgument
+    );
+}
+
+#[tokio::test]
+async fn estimate_embedding_job_tallies_text_tokens() {
+    let harness = create_command_harness().await;
+    let request = |text_column: &str, filter: Option<&str>| EstimateEmbeddingJobRequestV1 {
+        table_id: harness.table_id.clone(),
+        text_column: text_column.to_string(),
+        provider: EmbeddingProviderV1 {
+            name: "openai".to_string(),
+            model: "text-embedding-3-small".to_string(),
+            price_per_million_tokens: 0.02,
+            tokens_per_minute: Some(600),
+            max_tokens_per_input: None,
+        },
+        filter: filter.map(str::to_string),
+    };
+
+    let estimate = services_v1::estimate_embedding_job_v1(&harness.state, request("text", None))
+        .await
+        .data
+        .expect("estimate");
+    assert_eq!(estimate.row_count, 50);
+    assert_eq!(estimate.skipped_rows, 0);
+    // "item N" is six or seven characters, two tokens either way.
+    assert_eq!(estimate.estimated_tokens, 100);
+    assert!((estimate.estimated_cost - 0.000_002).abs() < 1e-12);
+    assert_eq!(estimate.estimated_seconds, Some(10.0));
+
+    let filtered =
+        services_v1::estimate_embedding_job_v1(&harness.state, request("text", Some("id < 10")))
+            .await
+            .data
+            .expect("filtered estimate");
+    assert_eq!(filtered.row_count, 10);
+
+    let vector =
+        services_v1::estimate_embedding_job_v1(&harness.state, request("vector", None)).await;
+    assert_eq!(
+        vector.error.expect("not text").code,
+        ErrorCode::InvalidArgument
+    );
+    let missing =
+        services_v1::estimate_embedding_job_v1(&harness.state, request("missing", None)).await;
+    assert_eq!(missing.error.expect("missing").code, ErrorCode::NotFound);
+}
+
+#[tokio::test]
+async fn connect_reports_missing_secret_refs() {
+    let harness = create_command_harness().await;
+    let data_dir = tempfile::tempdir().expect("data dir");
+    let state = AppState::with_data_dir(data_dir.path().to_path_buf());
+    let request = || ConnectRequestV1 {
+        profile: ConnectProfile {
+            name: "vaulted".to_string(),
+            uri: harness._db.uri.clone(),
+            storage_options: Default::default(),
+            options: Default::default(),
+            auth: AuthDescriptor::SecretRef {
+                provider: "s3".to_string(),
+                reference: "cred_missing".to_string(),
+            },
+        },
+    };
+
+    for state in [&harness.state, &state] {
+        let missing = services_v1::connect_v1(state, request()).await;
+        assert!(!missing.ok);
+        let error = missing.error.expect("error");
+        assert_eq!(error.code, ErrorCode::NotFound);
+        assert!(error.message.contains("cred_missing"));
+    }
+}
+
+#[tokio::test]
+async fn alter_columns_rebuilds_indexes_on_renamed_columns() {
+    let harness = create_command_harness().await;
+    let created = services_v1::create_index_v1(
+        &harness.state,
+        CreateIndexRequestV1 {
+            table_id: harness.table_id.clone(),
+            columns: vec!["text".to_string()],
+            index_type: IndexTypeV1::BTree,
+            name: None,
+            replace: true,
+            distance_type: None,
+            num_partitions: None,
+            sample_rate: None,
+            max_iterations: None,
+            target_partition_size: None,
+            num_sub_vectors: None,
+            num_bits: None,
+            num_edges: None,
+            ef_construction: None,
+            accelerator: None,
+            fts_tokenizer: None,
+            job_id: None,
+            preset: None,
+        },
+    )
+    .await;
+    assert!(created.ok, "{:?}", created.error);
+
+    let altered = services_v1::alter_columns_v1(
+        &harness.state,
+        AlterColumnsRequestV1 {
+            table_id: harness.table_id.clone(),
+            columns: vec![ColumnAlterationInput {
+                path: "text".to_string(),
+                rename: Some("label".to_string()),
+                nullable: None,
+                data_type: None,
+                vector_length: None,
+            }],
+            rebuild_indexes: true,
+            checkpoint_note: None,
+        },
+    )
+    .await
+    .data
+    .expect("alter columns");
+    assert_eq!(altered.index_rebuilds.len(), 1);
+    let rebuild = &altered.index_rebuilds[0];
+    assert_eq!(rebuild.index_name, "text_idx");
+    assert_eq!(rebuild.new_index_name, "label_idx");
+    assert_eq!(rebuild.columns, vec!["label"]);
+    assert_eq!(rebuild.reason, IndexRebuildReasonV1::Renamed);
+    assert_eq!(
+        rebuild.state,
+        IndexRebuildStateV1::Rebuilt,
+        "{:?}",
+        rebuild.error
+    );
+    let job_id = altered.rebuild_job_id.expect("rebuild job");
+    let job = harness
+        .state
+        .jobs
+        .lock()
+        .expect("jobs")
+        .get(&job_id)
+        .expect("job status");
+    assert_eq!(job.state, JobStateV1::Succeeded);
+
+    let indexes = services_v1::list_indexes_v1(
+        &harness.state,
+        ListIndexesRequestV1 {
+            table_id: harness.table_id.clone(),
+        },
+    )
+    .await
+    .data
+    .expect("index list")
+    .indexes;
+    assert!(indexes
+        .iter()
+        .any(|index| index.name == "label_idx" && index.columns == vec!["label"]));
+    assert!(!indexes.iter().any(|index| index.name == "text_idx"));
+}
+
+#[tokio::test]
+async fn connect_validates_object_store_tuning() {
+    let harness = create_command_harness().await;
+    let connect = |request_timeout_ms: u64| {
+        services_v1::connect_v1(
+            &harness.state,
+            ConnectRequestV1 {
+                profile: ConnectProfile {
+                    name: "tuned".to_string(),
+                    uri: harness._db.uri.clone(),
+                    storage_options: Default::default(),
+                    options: ConnectOptions {
+                        object_store: Some(ObjectStoreTuningV1 {
+                            connect_timeout_ms: Some(2_000),
+                            request_timeout_ms: Some(request_timeout_ms),
+                        }),
+                        ..Default::default()
+                    },
+                    auth: Default::default(),
+                },
+            },
+        )
+    };
+
+    let tuned = connect(60_000).await;
+    assert!(tuned.ok, "{:?}", tuned.error);
+
+    let rejected = connect(0).await;
+    assert!(!rejected.ok);
+    assert_eq!(
+        rejected.error.expect("error").code,
+        ErrorCode::InvalidArgument
+    );
+}
+
+#[tokio::test]
+async fn validate_filter_hints_at_vector_helpers() {
+    let harness = create_command_harness().await;
+    let validate = |filter: &str| {
+        services_v1::validate_filter_v1(
+            &harness.state,
+            ValidateFilterRequestV1 {
+                table_id: harness.table_id.clone(),
+                filter: filter.to_string(),
+            },
+        )
+    };
+
+    let valid = validate("id > 3 AND text LIKE 'item%'")
+        .await
+        .data
+        .expect("validation");
+    assert!(valid.valid);
+    assert!(valid.error.is_none() && valid.hint.is_none());
+
+    let vector = validate("array_length(vector) = 3")
+        .await
+        .data
+        .expect("validation");
+    assert!(vector.valid, "{:?}", vector.error);
+
+    let invalid = validate("vector > 'item'").await.data.expect("validation");
+    assert!(!invalid.valid);
+    assert!(invalid.error.is_some());
+    let hint = invalid.hint.expect("vector hint");
+    assert_eq!(hint.code, MessageCodeV1::VectorFilterHint);
+    assert_eq!(
+        hint.params.get("column").map(String::as_str),
+        Some("vector")
+    );
+
+    let unknown = validate("missing_column = 1")
+        .await
+        .data
+        .expect("validation");
+    assert!(!unknown.valid);
+    assert!(unknown.hint.is_none());
+
+    assert!(!validate("  ").await.ok);
+}
+
+#[tokio::test]
+async fn arrow_scan_splits_pages_over_the_chunk_budget() {
+    let harness = create_command_harness().await;
+    let scan = |offset, max_chunk_bytes| ScanRequestV1 {
+        table_id: harness.table_id.clone(),
+        format: DataFormat::Arrow,
+        projection: None,
+        projection_preset: None,
+        filter: None,
+        limit: Some(20),
+        offset: Some(offset),
+        apply_masking: false,
+        prefetch: false,
+        include_stats: false,
+        json_options: None,
+        as_of_version: None,
+        max_chunk_bytes,
+        job_id: None,
+    };
+    let chunk_rows = |response: ScanResponseV1| {
+        let DataChunk::Arrow(chunk) = response.chunk else {
+            panic!("expected arrow chunk");
+        };
+        let decoded = general_purpose::STANDARD
+            .decode(chunk.ipc_base64)
+            .expect("decode base64");
+        StreamReader::try_new(Cursor::new(decoded), None)
+            .expect("open stream reader")
+            .map(|batch| batch.expect("read batch").num_rows())
+            .sum::<usize>()
+    };
+
+    let whole = services_v1::scan_v1(&harness.state, scan(0, None)).await;
+    let whole = whole.data.expect("whole page");
+    assert!(whole.split.is_none());
+    assert_eq!(whole.next_offset, Some(20));
+
+    let first = services_v1::scan_v1(&harness.state, scan(0, Some(1))).await;
+    assert!(first.ok, "scan should succeed: {:?}", first.error);
+    let first = first.data.expect("first chunk");
+    let split = first.split.clone().expect("split");
+    assert_eq!((split.rows, split.remaining_rows), (1, 19));
+    assert_eq!(first.next_offset, Some(1));
+    assert_eq!(chunk_rows(first), 1);
+
+    let zero = services_v1::scan_v1(&harness.state, scan(0, Some(0))).await;
+    assert_eq!(
+        zero.error.expect("zero budget").code,
+        ErrorCode::InvalidArgument
+    );
+}
+
+#[tokio::test]
+async fn fts_search_reports_columns_without_an_index() {
+    let harness = create_command_harness().await;
+    let request = |columns: &[&str]| FtsSearchRequestV1 {
+        table_id: harness.table_id.clone(),
+        query: "item".to_string(),
+        columns: Some(columns.iter().map(|column| column.to_string()).collect()),
+        limit: Some(5),
+        offset: None,
+        projection: None,
+        projection_preset: None,
+        filter: None,
+        reranker: None,
+        as_of_version: None,
+        include_stats: false,
+        json_options: None,
+        highlight: false,
+    };
+
+    let no_index = services_v1::fts_search_v1(&harness.state, request(&["text"])).await;
+    assert_eq!(
+        no_index.error.expect("no index").message_code,
+        Some(MessageCodeV1::FtsIndexMissing)
+    );
+
+    let table = harness
+        .state
+        .connections
+        .lock()
+        .expect("lock")
+        .get_table(&harness.table_id)
+        .expect("table");
+    table
+        .create_index(&["text"], Index::FTS(Default::default()))
+        .execute()
+        .await
+        .expect("create fts index");
+
+    let unindexed = services_v1::fts_search_v1(&harness.state, request(&["text", "id"])).await;
+    let error = unindexed.error.expect("unindexed column error");
+    assert_eq!(error.code, ErrorCode::InvalidArgument);
+    assert_eq!(
+        error.message_code,
+        Some(MessageCodeV1::FtsColumnsNotIndexed)
+    );
+    let details: FtsColumnsNotIndexedV1 =
+        serde_json::from_value(error.details.expect("details")).expect("unindexed details");
+    assert_eq!(details.columns, vec!["id"]);
+    assert_eq!(details.indexed_columns, vec!["text"]);
+
+    let indexed = services_v1::fts_search_v1(&harness.state, request(&["text"])).await;
+    assert!(indexed.ok, "fts_search failed: {:?}", indexed.error);
+}
+
+#[tokio::test]
+async fn fts_index_applies_tokenizer_options() {
+    let harness = create_command_harness().await;
+    let index = |index_type: IndexTypeV1, column: &str| CreateIndexRequestV1 {
+        table_id: harness.table_id.clone(),
+        columns: vec![column.to_string()],
+        index_type,
+        name: None,
+        replace: true,
+        distance_type: None,
+        num_partitions: None,
+        sample_rate: None,
+        max_iterations: None,
+        target_partition_size: None,
+        num_sub_vectors: None,
+        num_bits: None,
+        num_edges: None,
+        ef_construction: None,
+        accelerator: None,
+        fts_tokenizer: Some(FtsTokenizerOptionsV1 {
+            language: Some(FtsLanguageV1::French),
+            stem: Some(false),
+            ascii_folding: Some(true),
+            max_token_length: Some(2),
+            ..Default::default()
+        }),
+        job_id: None,
+        preset: None,
+    };
+
+    let btree = services_v1::create_index_v1(&harness.state, index(IndexTypeV1::BTree, "id")).await;
+    assert_eq!(
+        btree.error.expect("tokenizer on btree").code,
+        ErrorCode::InvalidArgument
+    );
+
+    let created =
+        services_v1::create_index_v1(&harness.state, index(IndexTypeV1::Fts, "text")).await;
+    assert!(created.ok, "create fts index failed: {:?}", created.error);
+
+    let search = |query: &str| FtsSearchRequestV1 {
+        table_id: harness.table_id.clone(),
+        query: query.to_string(),
+        columns: Some(vec!["text".to_string()]),
+        limit: Some(100),
+        offset: None,
+        projection: None,
+        projection_preset: None,
+        filter: None,
+        reranker: None,
+        as_of_version: None,
+        include_stats: false,
+        json_options: None,
+        highlight: false,
+    };
+    let row_count = |response: ResultEnvelope<QueryResponseV1>| {
+        let response = response.data.expect("search data");
+        match response.chunk {
+            DataChunk::Json(chunk) => chunk.rows.len(),
+            _ => panic!("expected json chunk"),
+        }
+    };
+
+    // "item" is longer than the two-byte limit, so only the numbers are indexed.
+    let long_token = services_v1::fts_search_v1(&harness.state, search("item")).await;
+    assert!(long_token.ok, "fts_search failed: {:?}", long_token.error);
+    assert_eq!(row_count(long_token), 0);
+    let short_token = services_v1::fts_search_v1(&harness.state, search("42")).await;
+    assert!(short_token.ok, "fts_search failed: {:?}", short_token.error);
+    assert_eq!(row_count(short_token), 1);
+}
+
+#[tokio::test]
+async fn check_export_target_compares_existing_files() {
+    let harness = create_command_harness().await;
+    let export_dir = tempdir().expect("create export dir");
+    let parquet_path = export_dir
+        .path()
+        .join("items.parquet")
+        .to_string_lossy()
+        .to_string();
+    let check = |path: &str, format, projection: Option<Vec<String>>| CheckExportTargetRequestV1 {
+        table_id: harness.table_id.clone(),
+        path: path.to_string(),
+        format,
+        projection,
+        delimiter: None,
+    };
+
+    let missing = services_v1::check_export_target_v1(
+        &harness.state,
+        check(&parquet_path, DataFileFormatV1::Parquet, None),
+    )
+    .await;
+    let missing = missing.data.expect("missing target");
+    assert!(!missing.exists && missing.compatible);
+
+    let exported = services_v1::export_data_v1(
+        &harness.state,
+        ExportDataRequestV1 {
+            table_id: harness.table_id.clone(),
+            path: parquet_path.clone(),
+            format: DataFileFormatV1::Parquet,
+            projection: None,
+            filter: None,
+            limit: Some(5),
+            offset: None,
+            delimiter: None,
+            with_header: None,
+            geometry_column: None,
+            job_id: None,
+        },
+    )
+    .await;
+    assert!(exported.ok, "export should succeed: {:?}", exported.error);
+
+    let same = services_v1::check_export_target_v1(
+        &harness.state,
+        check(&parquet_path, DataFileFormatV1::Parquet, None),
+    )
+    .await;
+    assert!(same.ok, "check should succeed: {:?}", same.error);
+    let same = same.data.expect("same schema");
+    assert!(same.exists && same.compatible && same.report.is_none());
+
+    let narrower = services_v1::check_export_target_v1(
+        &harness.state,
+        check(
+            &parquet_path,
+            DataFileFormatV1::Parquet,
+            Some(vec!["id".to_string(), "text".to_string()]),
+        ),
+    )
+    .await;
+    let narrower = narrower.data.expect("projected check");
+    assert!(!narrower.compatible);
+    assert_eq!(
+        narrower.report.expect("report").extra_columns,
+        vec!["vector".to_string()]
+    );
+
+    let jsonl = services_v1::check_export_target_v1(
+        &harness.state,
+        check(&parquet_path, DataFileFormatV1::Jsonl, None),
+    )
+    .await;
+    assert_eq!(
+        jsonl.error.expect("unsupported format").code,
+        ErrorCode::InvalidArgument
+    );
+}
+
+#[tokio::test]
+async fn scan_pages_stay_on_the_pinned_version() {
+    let harness = create_command_harness().await;
+    let scan = |offset, as_of_version| ScanRequestV1 {
+        table_id: harness.table_id.clone(),
+        format: DataFormat::Json,
+        projection: Some(vec!["id".to_string()]),
+        projection_preset: None,
+        filter: None,
+        limit: Some(10),
+        offset: Some(offset),
+        apply_masking: false,
+        prefetch: true,
+        include_stats: false,
+        json_options: None,
+        as_of_version,
+        max_chunk_bytes: None,
+        job_id: None,
+    };
+    let page_ids = |response: ScanResponseV1| match response.chunk {
+        DataChunk::Json(chunk) => chunk
+            .rows
+            .iter()
+            .map(|row| row["id"].as_i64().expect("id"))
+            .collect::<Vec<_>>(),
+        _ => panic!("expected json chunk"),
+    };
+
+    let first = services_v1::scan_v1(&harness.state, scan(0, None)).await;
+    assert!(first.ok, "scan should succeed: {:?}", first.error);
+    let pinned = first.data.expect("scan data").version;
+
+    let deleted = services_v1::delete_rows_v1(
+        &harness.state,
+        DeleteRowsRequestV1 {
+            table_id: harness.table_id.clone(),
+            filter: "id < 10".to_string(),
+            allow_full_table: false,
+            chunk_size: None,
+            job_id: None,
+            checkpoint_note: None,
+        },
+    )
+    .await;
+    assert!(deleted.ok, "delete should succeed: {:?}", deleted.error);
+
+    let second = services_v1::scan_v1(&harness.state, scan(10, Some(pinned))).await;
+    assert!(second.ok, "scan should succeed: {:?}", second.error);
+    let second = second.data.expect("scan data");
+    assert_eq!(second.version, pinned);
+    assert_eq!(page_ids(second), (10..20).collect::<Vec<_>>());
+
+    let latest = services_v1::scan_v1(&harness.state, scan(10, None)).await;
+    let latest = latest.data.expect("scan data");
+    assert!(latest.version > pinned);
+    assert_eq!(page_ids(latest), (20..30).collect::<Vec<_>>());
+}
+
+#[tokio::test]
+async fn open_tables_reports_each_table() {
+    let harness = create_command_harness().await;
+
+    let opened = services_v1::open_tables_v1(
+        &harness.state,
+        OpenTablesRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            table_names: vec![harness.table_name.clone(), "missing".to_string()],
+        },
+    )
+    .await;
+    assert!(opened.ok, "open_tables failed: {:?}", opened.error);
+    let opened = opened.data.expect("opened");
+    assert_eq!((opened.opened, opened.failed), (1, 1));
+    let handle = opened.tables[0].handle.as_ref().expect("handle");
+    assert_eq!(handle.name, harness.table_name);
+    assert_ne!(handle.table_id, harness.table_id);
+    assert_eq!(opened.tables[1].table_name, "missing");
+    assert_eq!(
+        opened.tables[1].error.as_ref().expect("error").code,
+        ErrorCode::NotFound
+    );
+
+    let unknown = services_v1::open_tables_v1(
+        &harness.state,
+        OpenTablesRequestV1 {
+            connection_id: "unknown".to_string(),
+            table_names: vec![harness.table_name.clone()],
+        },
+    )
+    .await;
+    assert_eq!(
+        unknown.error.expect("unknown connection").code,
+        ErrorCode::NotFound
+    );
+}
+
+#[tokio::test]
+async fn table_location_resolves_local_dataset_directories() {
+    let harness = create_command_harness().await;
+
+    let location = services_v1::table_location_v1(
+        &harness.state,
+        TableLocationRequestV1 {
+            table_id: harness.table_id.clone(),
+        },
+    )
+    .await;
+    assert!(location.ok, "table_location failed: {:?}", location.error);
+    let location = location.data.expect("location");
+    assert_eq!(location.name, harness.table_name);
+    assert!(matches!(location.backend_kind, BackendKind::Local));
+    assert!(location.dataset_uri.ends_with("items.lance"));
+    let local_path = std::path::PathBuf::from(location.local_path.expect("local path"));
+    assert!(local_path.is_dir());
+
+    let directory = |kind: DatasetDirectoryKindV1| {
+        location
+            .directories
+            .iter()
+            .find(|directory| directory.kind == kind)
+            .expect("directory")
+    };
+    let data = directory(DatasetDirectoryKindV1::Data);
+    assert_eq!(data.exists, Some(true));
+    assert!(data.uri.ends_with("items.lance/data"));
+    assert_eq!(
+        directory(DatasetDirectoryKindV1::Versions).exists,
+        Some(true)
+    );
+    assert_eq!(location.directories.len(), 5);
+}
+
+/// Saves `params` under `reference` in the vault the frontend writes to.
+fn save_vault_credential(data_dir: &Path, reference: &str, params: serde_json::Value) {
+    fs::write(
+        data_dir.join(secrets::VAULT_CONFIG_FILE),
+        r#"{"strongholdPassphrase":"pass-1"}"#,
+    )
+    .expect("write vault config");
+    let vault = Stronghold::new(
+        data_dir.join(secrets::VAULT_FILE),
+        secrets::password_hash("pass-1"),
+    )
+    .expect("vault");
+    let client = vault
+        .load_client(secrets::VAULT_CLIENT)
+        .or_else(|_| vault.create_client(secrets::VAULT_CLIENT))
+        .expect("client");
+    client
+        .store()
+        .insert(
+            reference.as_bytes().to_vec(),
+            serde_json::to_vec(&serde_json::json!({ "params": params })).expect("record"),
+            None,
+        )
+        .expect("insert");
+    vault
+        .write_client(secrets::VAULT_CLIENT)
+        .expect("write client");
+    vault.save().expect("save vault");
+}
+
+#[tokio::test]
+async fn rotate_secret_reconnects_live_connections_in_place() {
+    let sample = prepare_sample_db().await;
+    let data_dir = tempdir().expect("data dir");
+    let state = AppState::with_data_dir(data_dir.path().to_path_buf());
+    let profile = |auth: AuthDescriptor| ConnectProfile {
+        name: "sample".to_string(),
+        uri: sample.uri.clone(),
+        storage_options: Default::default(),
+        options: Default::default(),
+        auth,
+    };
+    let connection_id = services_v1::connect_v1(
+        &state,
+        ConnectRequestV1 {
+            profile: profile(AuthDescriptor::None),
+        },
+    )
+    .await
+    .data
+    .expect("connect")
+    .connection_id;
+    let table_id = services_v1::open_table_v1(
+        &state,
+        OpenTableRequestV1 {
+            connection_id: connection_id.clone(),
+            table_name: sample.table_name.clone(),
+        },
+    )
+    .await
+    .data
+    .expect("open table")
+    .table_id;
+    let table = state
+        .connections
+        .lock()
+        .expect("lock")
+        .get_table(&table_id)
+        .expect("table");
+    let pinned = table.version().await.expect("version");
+    let written = services_v1::write_rows_v1(
+        &state,
+        WriteRowsRequestV1 {
+            table_id: table_id.clone(),
+            rows: vec![serde_json::json!({"id": 999, "text": "new", "vector": [0.1, 0.2, 0.3]})],
+            mode: WriteDataMode::Append,
+            normalize_vectors: false,
+            checkpoint_note: None,
+        },
+    )
+    .await;
+    assert!(written.ok, "write failed: {:?}", written.error);
+    let checked_out = services_v1::checkout_table_version_v1(
+        &state,
+        CheckoutTableVersionRequestV1 {
+            table_id: table_id.clone(),
+            version: Some(pinned),
+            tag: None,
+        },
+    )
+    .await;
+    assert!(checked_out.ok, "checkout failed: {:?}", checked_out.error);
+
+    let inline = services_v1::rotate_secret_v1(
+        &state,
+        RotateSecretRequestV1 {
+            profile: profile(AuthDescriptor::Inline {
+                provider: "local".to_string(),
+                params: HashMap::from([("token".to_string(), "rotated".to_string())]),
+            }),
+        },
+    )
+    .await;
+    assert_eq!(
+        inline.error.expect("secret_ref required").code,
+        ErrorCode::InvalidArgument
+    );
+    let vaulted = |reference: &str| {
+        profile(AuthDescriptor::SecretRef {
+            provider: "local".to_string(),
+            reference: reference.to_string(),
+        })
+    };
+    let missing = services_v1::rotate_secret_v1(
+        &state,
+        RotateSecretRequestV1 {
+            profile: vaulted("cred_missing"),
+        },
+    )
+    .await;
+    assert_eq!(missing.error.expect("missing").code, ErrorCode::NotFound);
+
+    save_vault_credential(
+        data_dir.path(),
+        "cred_1",
+        serde_json::json!({"token": "rotated"}),
+    );
+    let rotated = services_v1::rotate_secret_v1(
+        &state,
+        RotateSecretRequestV1 {
+            profile: vaulted("cred_1"),
+        },
+    )
+    .await;
+    assert!(rotated.ok, "rotate_secret failed: {:?}", rotated.error);
+    let rotated = rotated.data.expect("rotated");
+    assert_eq!(rotated.connection_ids, vec![connection_id.clone()]);
+    assert_eq!(rotated.reopened_tables, 1);
+
+    let storage_options = state
+        .connections
+        .lock()
+        .expect("lock")
+        .get_storage_options(&connection_id)
+        .expect("storage options");
+    assert_eq!(
+        storage_options.get("token").map(String::as_str),
+        Some("rotated")
+    );
+    let reopened = state
+        .connections
+        .lock()
+        .expect("lock")
+        .get_table(&table_id)
+        .expect("table");
+    assert_eq!(reopened.version().await.expect("version"), pinned);
+    let count = services_v1::get_row_count_v1(
+        &state,
+        GetRowCountRequestV1 {
+            table_id: table_id.clone(),
+        },
+    )
+    .await;
+    assert_eq!(count.data.expect("row count").row_count, 50);
+}
+
+#[tokio::test]
+async fn fts_search_without_columns_uses_indexed_columns() {
+    let harness = create_command_harness().await;
+    let request = || FtsSearchRequestV1 {
+        table_id: harness.table_id.clone(),
+        query: "item".to_string(),
+        columns: None,
+        limit: Some(5),
+        offset: None,
+        projection: None,
+        projection_preset: None,
+        filter: None,
+        reranker: None,
+        as_of_version: None,
+        include_stats: false,
+        json_options: None,
+        highlight: false,
+    };
+
+    let missing = services_v1::fts_search_v1(&harness.state, request()).await;
+    let error = missing.error.expect("missing index error");
+    assert_eq!(error.code, ErrorCode::NotFound);
+    assert_eq!(error.message_code, Some(MessageCodeV1::FtsIndexMissing));
+    let details: MissingFtsIndexV1 =
+        serde_json::from_value(error.details.expect("details")).expect("missing index details");
+    assert_eq!(details.string_columns, vec!["text"]);
+
+    let table = harness
+        .state
+        .connections
+        .lock()
+        .expect("lock")
+        .get_table(&harness.table_id)
+        .expect("table");
+    table
+        .create_index(&["text"], Index::FTS(Default::default()))
+        .execute()
+        .await
+        .expect("create fts index");
+
+    let found = services_v1::fts_search_v1(&harness.state, request()).await;
+    assert!(found.ok, "fts_search failed: {:?}", found.error);
+    assert!(found.data.expect("fts data").warnings.is_empty());
+}
+
+#[tokio::test]
+async fn evaluate_recall_compares_index_with_exact_search() {
+    let harness = create_command_harness().await;
+    let request = |nprobes: Option<Vec<usize>>| EvaluateRecallRequestV1 {
+        table_id: harness.table_id.clone(),
+        column: "vector".to_string(),
+        sample_queries: Some(5),
+        k: Some(3),
+        nprobes,
+        refine_factor: None,
+    };
+
+    let unindexed = services_v1::evaluate_recall_v1(&harness.state, request(None)).await;
+    assert_eq!(
+        unindexed.error.expect("no index").code,
+        ErrorCode::InvalidArgument
+    );
+
+    let created = services_v1::create_index_v1(
+        &harness.state,
+        CreateIndexRequestV1 {
+            table_id: harness.table_id.clone(),
+            columns: vec!["vector".to_string()],
+            index_type: IndexTypeV1::IvfFlat,
+            name: Some("vector_ivf".to_string()),
+            replace: true,
+            distance_type: None,
+            num_partitions: Some(2),
+            sample_rate: None,
+            max_iterations: None,
+            target_partition_size: None,
+            num_sub_vectors: None,
+            num_bits: None,
+            num_edges: None,
+            ef_construction: None,
+            accelerator: None,
+            fts_tokenizer: None,
+            job_id: None,
+            preset: None,
+        },
+    )
+    .await;
+    assert!(created.ok, "create_index failed: {:?}", created.error);
+
+    let evaluated =
+        services_v1::evaluate_recall_v1(&harness.state, request(Some(vec![2, 1, 2]))).await;
+    assert!(
+        evaluated.ok,
+        "evaluate_recall failed: {:?}",
+        evaluated.error
+    );
+    let report = evaluated.data.expect("recall report");
+    assert_eq!(report.index_name, "vector_ivf");
+    assert_eq!(report.sample_size, 5);
+    assert_eq!(report.k, 3);
+    assert_eq!(
+        report
+            .results
+            .iter()
+            .map(|result| result.nprobes)
+            .collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+    assert!(report.results[0].recall <= 1.0);
+    // Probing every partition of a flat IVF index is an exact search.
+    assert_eq!(report.results[1].recall, 1.0);
+
+    let invalid = services_v1::evaluate_recall_v1(&harness.state, request(Some(vec![0]))).await;
+    assert_eq!(
+        invalid.error.expect("invalid nprobes").code,
+        ErrorCode::InvalidArgument
+    );
+}
+
+#[tokio::test]
+async fn auto_checkpoints_tag_the_versions_mutations_produce() {
+    let harness = create_command_harness().await;
+    let update = |checkpoint_note: Option<&str>| UpdateRowsRequestV1 {
+        table_id: harness.table_id.clone(),
+        filter: Some("id = 1".to_string()),
+        updates: vec![UpdateColumnInputV1 {
+            column: "text".to_string(),
+            expr: "'checkpointed'".to_string(),
+        }],
+        allow_full_table: false,
+        checkpoint_note: checkpoint_note.map(str::to_string),
+    };
+
+    let untagged = services_v1::update_rows_v1(&harness.state, update(None)).await;
+    assert!(untagged.ok, "update failed: {:?}", untagged.error);
+    let tags = services_v1::list_tags_v1(
+        &harness.state,
+        ListTagsRequestV1 {
+            table_id: harness.table_id.clone(),
+        },
+    )
+    .await
+    .data
+    .expect("tags")
+    .tags;
+    assert!(tags.is_empty(), "checkpoints are opt-in");
+
+    let mut config = services_v1::get_config_v1(&harness.state)
+        .await
+        .data
+        .expect("config")
+        .config;
+    config.auto_checkpoints = true;
+    let updated = services_v1::set_config_v1(&harness.state, SetConfigRequestV1 { config }).await;
+    assert!(updated.ok, "set_config failed: {:?}", updated.error);
+
+    let version = services_v1::update_rows_v1(&harness.state, update(Some("Nightly fix")))
+        .await
+        .data
+        .expect("update")
+        .version;
+    let tags = services_v1::list_tags_v1(
+        &harness.state,
+        ListTagsRequestV1 {
+            table_id: harness.table_id.clone(),
+        },
+    )
+    .await
+    .data
+    .expect("tags")
+    .tags;
+    assert_eq!(tags.len(), 1);
+    assert_eq!(tags[0].version, version);
+    assert!(
+        tags[0]
+            .name
+            .starts_with(&format!("auto-v{version}-update-")),
+        "{}",
+        tags[0].name
+    );
+    assert!(tags[0].name.ends_with("-nightly-fix"), "{}", tags[0].name);
+    assert_eq!(tags[0].note.as_deref(), Some("Nightly fix"));
+
+    let version = services_v1::update_rows_v1(&harness.state, update(Some("价格")))
+        .await
+        .data
+        .expect("update")
+        .version;
+    let tags = services_v1::list_tags_v1(
+        &harness.state,
+        ListTagsRequestV1 {
+            table_id: harness.table_id.clone(),
+        },
+    )
+    .await
+    .data
+    .expect("tags")
+    .tags;
+    let tag = tags
+        .iter()
+        .find(|tag| tag.version == version)
+        .expect("checkpoint");
+    assert!(tag.name.ends_with('Z'), "{}", tag.name);
+    assert_eq!(tag.note.as_deref(), Some("价格"));
+}
+
+#[tokio::test]
+async fn roles_limit_which_commands_run() {
+    let harness = create_command_harness().await;
+    let mut config = services_v1::get_config_v1(&harness.state)
+        .await
+        .data
+        .expect("config")
+        .config;
+    config.role = RoleV1::Editor;
+    let updated = services_v1::set_config_v1(
+        &harness.state,
+        SetConfigRequestV1 {
+            config: config.clone(),
+        },
+    )
+    .await;
+    assert!(updated.ok, "set_config failed: {:?}", updated.error);
+
+    let dropped = services_v1::drop_table_v1(
+        &harness.state,
+        DropTableRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            table_name: harness.table_name.clone(),
+            namespace: None,
+        },
+    )
+    .await;
+    let error = dropped.error.expect("drop rejected");
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+    assert_eq!(error.message_code, Some(MessageCodeV1::RoleForbidden));
+
+    let written = services_v1::write_rows_v1(
+        &harness.state,
+        WriteRowsRequestV1 {
+            table_id: harness.table_id.clone(),
+            rows: vec![serde_json::json!({"id": 999, "text": "new", "vector": [0.1, 0.2, 0.3]})],
+            mode: WriteDataMode::Append,
+            normalize_vectors: false,
+            checkpoint_note: None,
+        },
+    )
+    .await;
+    assert!(written.ok, "editor write failed: {:?}", written.error);
+
+    config.role = RoleV1::Viewer;
+    let promoted = services_v1::set_config_v1(&harness.state, SetConfigRequestV1 { config }).await;
+    assert_eq!(
+        promoted.error.expect("editor cannot change settings").code,
+        ErrorCode::PermissionDenied
+    );
+    let count = services_v1::get_row_count_v1(
+        &harness.state,
+        GetRowCountRequestV1 {
+            table_id: harness.table_id.clone(),
+        },
+    )
+    .await;
+    assert_eq!(count.data.expect("row count").row_count, 51);
+}
+
+#[tokio::test]
+async fn encrypted_columns_round_trip_with_unlocked_keys() {
+    let harness = create_command_harness().await;
+    let set = services_v1::set_encrypted_columns_v1(
+        &harness.state,
+        SetEncryptedColumnsRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            columns: vec![EncryptedColumnV1 {
+                table_name: Some(harness.table_name.clone()),
+                column: "text".to_string(),
+                key_id: "vault-key".to_string(),
+                column_id: None,
+            }],
+        },
+    )
+    .await;
+    assert!(set.ok, "set failed: {:?}", set.error);
+
+    let write = || WriteRowsRequestV1 {
+        table_id: harness.table_id.clone(),
+        rows: vec![serde_json::json!({"id": 999, "text": "secret", "vector": [0.1, 0.2, 0.3]})],
+        mode: WriteDataMode::Append,
+        normalize_vectors: false,
+        checkpoint_note: None,
+    };
+    let locked = services_v1::write_rows_v1(&harness.state, write()).await;
+    assert_eq!(
+        locked.error.map(|error| error.code),
+        Some(ErrorCode::PermissionDenied)
+    );
+
+    let set_key = |key: Option<&str>| SetEncryptionKeyRequestV1 {
+        key_id: "vault-key".to_string(),
+        key: key.map(str::to_string),
+    };
+    let unlocked = services_v1::set_encryption_key_v1(
+        &harness.state,
+        set_key(Some("AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=")),
+    )
+    .await;
+    assert!(unlocked.ok, "unlock failed: {:?}", unlocked.error);
+    let written = services_v1::write_rows_v1(&harness.state, write()).await;
+    assert!(written.ok, "write failed: {:?}", written.error);
+
+    let scan_text = || async {
+        let scanned = services_v1::scan_v1(
+            &harness.state,
+            ScanRequestV1 {
+                table_id: harness.table_id.clone(),
+                format: DataFormat::Json,
+                projection: Some(vec!["text".to_string()]),
+                projection_preset: None,
+                filter: Some("id = 999".to_string()),
+                limit: Some(1),
+                offset: Some(0),
+                apply_masking: false,
+                prefetch: false,
+                include_stats: false,
+                json_options: None,
+                as_of_version: None,
+                max_chunk_bytes: None,
+                job_id: None,
+            },
+        )
+        .await;
+        let lancedb_viewer_lib::ipc::v1::DataChunk::Json(chunk) = scanned.data.expect("scan").chunk
+        else {
+            panic!("expected json chunk");
+        };
+        chunk.rows[0]["text"].as_str().expect("text").to_string()
+    };
+    assert_eq!(scan_text().await, "secret");
+
+    let relocked = services_v1::set_encryption_key_v1(&harness.state, set_key(None)).await;
+    assert!(relocked.ok, "lock failed: {:?}", relocked.error);
+    assert!(scan_text().await.starts_with("lvenc:v1:"));
+    let listed = services_v1::list_encrypted_columns_v1(
+        &harness.state,
+        ListEncryptedColumnsRequestV1 {
+            connection_id: harness.connection_id.clone(),
+        },
+    )
+    .await
+    .data
+    .expect("encrypted columns");
+    assert_eq!(listed.locked_key_ids, vec!["vault-key".to_string()]);
+}
+
+#[tokio::test]
+async fn encrypted_columns_decrypt_after_a_rename() {
+    let harness = create_command_harness().await;
+    let set = services_v1::set_encrypted_columns_v1(
+        &harness.state,
+        SetEncryptedColumnsRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            columns: vec![EncryptedColumnV1 {
+                table_name: Some(harness.table_name.clone()),
+                column: "text".to_string(),
+                key_id: "vault-key".to_string(),
+                column_id: None,
+            }],
+        },
+    )
+    .await;
+    assert!(set.ok, "set failed: {:?}", set.error);
+    let unlocked = services_v1::set_encryption_key_v1(
+        &harness.state,
+        SetEncryptionKeyRequestV1 {
+            key_id: "vault-key".to_string(),
+            key: Some("AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=".to_string()),
+        },
+    )
+    .await;
+    assert!(unlocked.ok, "unlock failed: {:?}", unlocked.error);
+    let written = services_v1::write_rows_v1(
+        &harness.state,
+        WriteRowsRequestV1 {
+            table_id: harness.table_id.clone(),
+            rows: vec![serde_json::json!({"id": 999, "text": "secret", "vector": [0.1, 0.2, 0.3]})],
+            mode: WriteDataMode::Append,
+            normalize_vectors: false,
+            checkpoint_note: None,
+        },
+    )
+    .await;
+    assert!(written.ok, "write failed: {:?}", written.error);
+
+    let altered = services_v1::alter_columns_v1(
+        &harness.state,
+        AlterColumnsRequestV1 {
+            table_id: harness.table_id.clone(),
+            columns: vec![ColumnAlterationInput {
+                path: "text".to_string(),
+                rename: Some("label".to_string()),
+                nullable: None,
+                data_type: None,
+                vector_length: None,
+            }],
+            rebuild_indexes: false,
+            checkpoint_note: None,
+        },
+    )
+    .await;
+    assert!(altered.ok, "alter failed: {:?}", altered.error);
+    let listed = services_v1::list_encrypted_columns_v1(
+        &harness.state,
+        ListEncryptedColumnsRequestV1 {
+            connection_id: harness.connection_id.clone(),
+        },
+    )
+    .await
+    .data
+    .expect("encrypted columns");
+    assert_eq!(listed.columns[0].column, "label");
+    assert_eq!(listed.columns[0].column_id.as_deref(), Some("text"));
+
+    let scanned = services_v1::scan_v1(
+        &harness.state,
+        ScanRequestV1 {
+            table_id: harness.table_id.clone(),
+            format: DataFormat::Json,
+            projection: Some(vec!["label".to_string()]),
+            projection_preset: None,
+            filter: Some("id = 999".to_string()),
+            limit: Some(1),
+            offset: Some(0),
+            apply_masking: false,
+            prefetch: false,
+            include_stats: false,
+            json_options: None,
+            as_of_version: None,
+            max_chunk_bytes: None,
+            job_id: None,
+        },
+    )
+    .await;
+    assert!(scanned.ok, "scan failed: {:?}", scanned.error);
+    let lancedb_viewer_lib::ipc::v1::DataChunk::Json(chunk) = scanned.data.expect("scan").chunk
+    else {
+        panic!("expected json chunk");
+    };
+    assert_eq!(chunk.rows[0]["label"], "secret");
+}
+
+#[tokio::test]
+async fn updates_to_encrypted_columns_store_ciphertext() {
+    let harness = create_command_harness().await;
+    let set = services_v1::set_encrypted_columns_v1(
+        &harness.state,
+        SetEncryptedColumnsRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            columns: vec![EncryptedColumnV1 {
+                table_name: Some(harness.table_name.clone()),
+                column: "text".to_string(),
+                key_id: "vault-key".to_string(),
+                column_id: None,
+            }],
+        },
+    )
+    .await;
+    assert!(set.ok, "set failed: {:?}", set.error);
+
+    let update = |expr: &str| UpdateRowsRequestV1 {
+        table_id: harness.table_id.clone(),
+        filter: Some("id = 1".to_string()),
+        updates: vec![UpdateColumnInputV1 {
+            column: "text".to_string(),
+            expr: expr.to_string(),
+        }],
+        allow_full_table: false,
+        checkpoint_note: None,
+    };
+    let locked = services_v1::update_rows_v1(&harness.state, update("'classified'"))
+        .await
+        .error
+        .expect("locked key");
+    assert_eq!(locked.code, ErrorCode::PermissionDenied);
+    assert_eq!(
+        locked.message_code,
+        Some(MessageCodeV1::EncryptedColumnLocked)
+    );
+
+    let unlocked = services_v1::set_encryption_key_v1(
+        &harness.state,
+        SetEncryptionKeyRequestV1 {
+            key_id: "vault-key".to_string(),
+            key: Some("AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=".to_string()),
+        },
+    )
+    .await;
+    assert!(unlocked.ok, "unlock failed: {:?}", unlocked.error);
+    let computed = services_v1::update_rows_v1(&harness.state, update("upper(text)")).await;
+    assert_eq!(
+        computed.error.map(|error| error.code),
+        Some(ErrorCode::InvalidArgument)
+    );
+    let updated = services_v1::update_rows_v1(&harness.state, update("'classified'")).await;
+    assert!(updated.ok, "update failed: {:?}", updated.error);
+
+    let table = harness
+        .state
+        .connections
+        .lock()
+        .expect("lock")
+        .get_table(&harness.table_id)
+        .expect("table");
+    let batches = table
+        .query()
+        .only_if("id = 1")
+        .select(Select::columns(&["text"]))
+        .execute()
+        .await
+        .expect("query text")
+        .try_collect::<Vec<_>>()
+        .await
+        .expect("collect text");
+    let stored = batches[0]
+        .column_by_name("text")
+        .and_then(|column| column.as_any().downcast_ref::<StringArray>())
+        .expect("text column")
+        .value(0)
+        .to_string();
+    assert!(stored.starts_with("lvenc:v1:"), "stored {stored}");
+    assert!(!stored.contains("classified"));
+
+    let scanned = services_v1::scan_v1(
+        &harness.state,
+        ScanRequestV1 {
+            table_id: harness.table_id.clone(),
+            format: DataFormat::Json,
+            projection: Some(vec!["text".to_string()]),
+            projection_preset: None,
+            filter: Some("id = 1".to_string()),
+            limit: Some(1),
+            offset: Some(0),
+            apply_masking: false,
+            prefetch: false,
+            include_stats: false,
+            json_options: None,
+            as_of_version: None,
+            max_chunk_bytes: None,
+            job_id: None,
+        },
+    )
+    .await;
+    let lancedb_viewer_lib::ipc::v1::DataChunk::Json(chunk) = scanned.data.expect("scan").chunk
+    else {
+        panic!("expected json chunk");
+    };
+    assert_eq!(chunk.rows[0]["text"], "classified");
+}
+
+#[tokio::test]
+async fn export_rows_writes_only_the_selected_rows() {
+    let harness = create_command_harness().await;
+    let table = harness
+        .state
+        .connections
+        .lock()
+        .expect("lock")
+        .get_table(&harness.table_id)
+        .expect("table");
+    let batches = table
+        .query()
+        .only_if("id IN (3, 7)")
+        .with_row_id()
+        .select(Select::columns(&["_rowid"]))
+        .execute()
+        .await
+        .expect("query row ids")
+        .try_collect::<Vec<_>>()
+        .await
+        .expect("collect row ids");
+    let mut row_ids = batches
+        .iter()
+        .flat_map(|batch| {
+            batch
+                .column_by_name("_rowid")
+                .and_then(|column| column.as_any().downcast_ref::<UInt64Array>())
+                .expect("row ids")
+                .values()
+                .to_vec()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(row_ids.len(), 2);
+    row_ids.push(row_ids[0]);
+
+    let export_dir = tempdir().expect("export dir");
+    let export_path = export_dir.path().join("selection.jsonl");
+    let export = |row_ids: Vec<u64>| ExportRowsRequestV1 {
+        table_id: harness.table_id.clone(),
+        row_ids,
+        format: DataFileFormatV1::Jsonl,
+        path: export_path.to_string_lossy().to_string(),
+        delimiter: None,
+        with_header: None,
+        geometry_column: None,
+        job_id: None,
+    };
+    let exported = services_v1::export_rows_v1(&harness.state, export(row_ids)).await;
+    assert!(exported.ok, "export failed: {:?}", exported.error);
+    assert_eq!(exported.data.expect("export").rows, 2);
+    let ids = fs::read_to_string(&export_path)
+        .expect("read export")
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).expect("row")["id"].clone())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec![serde_json::json!(3), serde_json::json!(7)]);
+
+    let empty = services_v1::export_rows_v1(&harness.state, export(Vec::new())).await;
+    assert_eq!(
+        empty.error.map(|error| error.code),
+        Some(ErrorCode::InvalidArgument)
+    );
+}
+
+#[tokio::test]
+async fn write_rows_report_the_offending_row() {
+    let harness = create_command_harness().await;
+    let write = services_v1::write_rows_v1(
+        &harness.state,
+        WriteRowsRequestV1 {
+            table_id: harness.table_id.clone(),
+            rows: vec![
+                serde_json::json!({"id": 999, "text": "ok", "vector": [0.1, 0.2, 0.3]}),
+                serde_json::json!({"id": 1000, "text": "short", "vector": [0.1, 0.2]}),
+            ],
+            mode: WriteDataMode::Append,
+            normalize_vectors: false,
+            checkpoint_note: None,
+        },
+    )
+    .await;
+
+    assert!(!write.ok);
+    let error = write.error.expect("error");
+    assert!(matches!(error.code, ErrorCode::InvalidArgument));
+    let details = serde_json::from_value::<RowValidationErrorV1>(error.details.expect("details"))
+        .expect("row details");
+    assert_eq!(details.row_index, 1);
+    assert_eq!(details.field.as_deref(), Some("vector"));
+    assert!(details.message.contains("row 1"), "{}", details.message);
+    assert_eq!(error.message, details.message);
+}
+
+#[tokio::test]
+async fn completion_metadata_lists_columns_and_functions() {
+    let harness = create_command_harness().await;
+    let metadata = services_v1::completion_metadata_v1(
+        &harness.state,
+        CompletionMetadataRequestV1 {
+            table_id: harness.table_id.clone(),
+        },
+    )
+    .await
+    .data
+    .expect("completion metadata");
+
+    let columns = metadata
+        .columns
+        .iter()
+        .map(|column| (column.name.as_str(), column.category))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        columns,
+        vec![
+            ("id", ValueCategoryV1::Numeric),
+            ("text", ValueCategoryV1::String),
+            ("vector", ValueCategoryV1::Vector),
+        ]
+    );
+    let text_operators = metadata
+        .operators
+        .iter()
+        .find(|entry| entry.category == ValueCategoryV1::String)
+        .expect("string operators");
+    assert!(text_operators.operators.iter().any(|op| op == "LIKE"));
+    assert!(metadata
+        .functions
+        .iter()
+        .any(|function| function.name == "lower"
+            && function.applies_to == vec![ValueCategoryV1::String]));
+    assert!(metadata.keywords.iter().any(|keyword| keyword == "AND"));
+
+    let missing = services_v1::completion_metadata_v1(
+        &harness.state,
+        CompletionMetadataRequestV1 {
+            table_id: "missing".to_string(),
+        },
+    )
+    .await;
+    assert!(!missing.ok);
+}
+
+#[tokio::test]
+async fn recent_filters_remember_first_page_filters() {
+    let harness = create_command_harness().await;
+    let scan = |filter: &str, offset: usize| ScanRequestV1 {
+        table_id: harness.table_id.clone(),
+        format: DataFormat::Json,
+        projection: None,
+        projection_preset: None,
+        filter: Some(filter.to_string()),
+        limit: Some(2),
+        offset: Some(offset),
+        apply_masking: false,
+        prefetch: false,
+        include_stats: false,
+        json_options: None,
+        as_of_version: None,
+        max_chunk_bytes: None,
+        job_id: None,
+    };
+    for (filter, offset) in [
+        ("id > 10", 0),
+        ("id  > 10", 0),
+        ("id > 10", 2),
+        ("id < 5", 0),
+    ] {
+        let scanned = services_v1::scan_v1(&harness.state, scan(filter, offset)).await;
+        assert!(scanned.ok, "scan failed: {:?}", scanned.error);
+    }
+    let failed = services_v1::scan_v1(&harness.state, scan("missing > 1", 0)).await;
+    assert!(!failed.ok);
+
+    let recent = |prefix: Option<&str>, sort: Option<RecentFilterSortV1>| RecentFiltersRequestV1 {
+        table_id: harness.table_id.clone(),
+        prefix: prefix.map(str::to_string),
+        sort,
+        limit: None,
+    };
+    let by_recency = services_v1::recent_filters_v1(&harness.state, recent(None, None))
+        .await
+        .data
+        .expect("recent filters");
+    let filters = by_recency
+        .filters
+        .iter()
+        .map(|filter| (filter.filter.as_str(), filter.uses))
+        .collect::<Vec<_>>();
+    assert_eq!(filters, vec![("id < 5", 1), ("id > 10", 2)]);
+
+    let frequent = services_v1::recent_filters_v1(
+        &harness.state,
+        recent(Some("ID >"), Some(RecentFilterSortV1::Frequent)),
+    )
+    .await
+    .data
+    .expect("recent filters");
+    assert_eq!(frequent.filters.len(), 1);
+    assert_eq!(frequent.filters[0].filter, "id > 10");
+}
+
+#[tokio::test]
+async fn reorder_columns_sets_the_display_order() {
+    let harness = create_command_harness().await;
+    let reorder = |order: &[&str]| ReorderColumnsRequestV1 {
+        table_id: harness.table_id.clone(),
+        order: order.iter().map(|name| name.to_string()).collect(),
+        checkpoint_note: None,
+    };
+
+    let reordered = services_v1::reorder_columns_v1(&harness.state, reorder(&["text"])).await;
+    assert!(reordered.ok, "reorder failed: {:?}", reordered.error);
+    let reordered = reordered.data.expect("reorder");
+    assert_eq!(reordered.order, vec!["text", "id", "vector"]);
+
+    let schema = services_v1::get_schema_v1(
+        &harness.state,
+        GetSchemaRequestV1 {
+            table_id: harness.table_id.clone(),
+        },
+    )
+    .await
+    .data
+    .expect("schema");
+    let names = schema
+        .fields
+        .iter()
+        .map(|field| field.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["text", "id", "vector"]);
+
+    let export_dir = tempdir().expect("export dir");
+    let export_path = export_dir.path().join("ordered.parquet");
+    let exported = services_v1::export_data_v1(
+        &harness.state,
+        ExportDataRequestV1 {
+            table_id: harness.table_id.clone(),
+            path: export_path.to_string_lossy().to_string(),
+            format: DataFileFormatV1::Parquet,
+            projection: None,
+            filter: Some("id < 2".to_string()),
+            limit: None,
+            offset: None,
+            delimiter: None,
+            with_header: None,
+            geometry_column: None,
+            job_id: None,
+        },
+    )
+    .await;
+    assert!(exported.ok, "export failed: {:?}", exported.error);
+    let file = fs::File::open(&export_path).expect("open export");
+    let exported_schema =
+        parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder::try_new(file)
+            .expect("read export")
+            .schema()
+            .clone();
+    let exported_names = exported_schema
+        .fields()
+        .iter()
+        .map(|field| field.name().as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(exported_names, vec!["text", "id", "vector"]);
+
+    let invalid =
+        services_v1::reorder_columns_v1(&harness.state, reorder(&["text", "missing"])).await;
+    assert_eq!(
+        invalid.error.map(|error| error.code),
+        Some(ErrorCode::InvalidArgument)
+    );
+}
+
+#[tokio::test]
+async fn extensions_are_opt_in() {
+    let harness = create_command_harness().await;
+    let listed = services_v1::list_extensions_v1(&harness.state).await;
+    assert!(listed.ok, "list failed: {:?}", listed.error);
+    assert!(listed.data.expect("extensions").extensions.is_empty());
+}
+
+#[tokio::test]
+async fn memory_stats_record_peak_batch_memory() {
+    let harness = create_command_harness().await;
+    let filtered = services_v1::query_filter_v1(
+        &harness.state,
+        QueryFilterRequestV1 {
+            table_id: harness.table_id.clone(),
+            filter: "id >= 0".to_string(),
+            projection: None,
+            projection_preset: None,
+            limit: None,
+            offset: None,
+            include_stats: false,
+            json_options: None,
+        },
+    )
+    .await;
+    assert!(filtered.ok, "query failed: {:?}", filtered.error);
+
+    let stats = services_v1::memory_stats_v1(&harness.state)
+        .await
+        .data
+        .expect("memory stats");
+    let query = stats
+        .commands
+        .iter()
+        .find(|command| command.command == "query_filter_v1")
+        .expect("query_filter_v1 stats");
+    assert_eq!(query.calls, 1);
+    assert!(query.max_peak_bytes > 0);
+    assert_eq!(query.last_peak_bytes, query.max_peak_bytes);
+    assert!(stats
+        .commands
+        .windows(2)
+        .all(|pair| pair[0].max_peak_bytes >= pair[1].max_peak_bytes));
+}
+
+#[tokio::test]
+async fn estimate_query_bounds_planned_scans() {
+    let harness = create_command_harness().await;
+    let estimate = |filter: Option<&str>, limit: Option<usize>| EstimateQueryRequestV1 {
+        table_id: harness.table_id.clone(),
+        filter: filter.map(str::to_string),
+        projection: Some(vec!["id".to_string()]),
+        limit,
+        offset: None,
+    };
+
+    let filtered =
+        services_v1::estimate_query_v1(&harness.state, estimate(Some("id < 5"), None)).await;
+    assert!(filtered.ok, "estimate failed: {:?}", filtered.error);
+    let filtered = filtered.data.expect("estimate");
+    assert_eq!(filtered.total_rows, 50);
+    assert_eq!(filtered.estimated_rows_scanned, 50);
+    assert!(filtered.full_scan);
+    assert!(filtered.estimated_bytes_read > 0);
+    assert!(filtered.estimated_bytes_read <= filtered.total_bytes);
+    assert!(!filtered.plan.is_empty());
+
+    let limited = services_v1::estimate_query_v1(&harness.state, estimate(None, Some(10)))
+        .await
+        .data
+        .expect("estimate");
+    assert_eq!(limited.estimated_rows_scanned, 10);
+    assert!(!limited.full_scan);
+    assert!(limited.estimated_bytes_read < filtered.estimated_bytes_read);
+
+    let invalid =
+        services_v1::estimate_query_v1(&harness.state, estimate(Some("missing > 1"), None)).await;
+    assert_eq!(
+        invalid.error.map(|error| error.code),
+        Some(ErrorCode::InvalidArgument)
+    );
+}
+
+fn http_get(url: &str) -> (String, String) {
+    let url = url::Url::parse(url).expect("share url");
+    let address = format!(
+        "{}:{}",
+        url.host_str().expect("host"),
+        url.port().expect("port")
+    );
+    let mut stream = TcpStream::connect(address).expect("connect to share");
+    let target = &url[url::Position::BeforePath..];
+    write!(stream, "GET {target} HTTP/1.1\r\nHost: share\r\n\r\n").expect("send request");
+    let mut response = String::new();
+    stream.read_to_string(&mut response).expect("read response");
+    let (head, body) = response.split_once("\r\n\r\n").expect("response head");
+    (
+        head.lines().next().unwrap_or_default().to_string(),
+        body.to_string(),
+    )
+}
+
+#[tokio::test]
+async fn shares_redact_encrypted_columns() {
+    let harness = create_command_harness().await;
+    let set = services_v1::set_encrypted_columns_v1(
+        &harness.state,
+        SetEncryptedColumnsRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            columns: vec![EncryptedColumnV1 {
+                table_name: Some(harness.table_name.clone()),
+                column: "text".to_string(),
+                key_id: "vault-key".to_string(),
+                column_id: None,
+            }],
+        },
+    )
+    .await;
+    assert!(set.ok, "set failed: {:?}", set.error);
+
+    let session = services_v1::start_share_v1(
+        &harness.state,
+        StartShareRequestV1 {
+            tables: vec![ShareTableV1 {
+                table_id: harness.table_id.clone(),
+                projection: Some(vec!["id".to_string(), "text".to_string()]),
+                filter: Some("id < 2".to_string()),
+            }],
+            expires_in_seconds: Some(60),
+            port: None,
+            local_only: true,
+        },
+    )
+    .await
+    .data
+    .expect("session");
+    let base = session.url.split('?').next().expect("base url").to_string();
+    let (status, body) = http_get(&format!(
+        "{base}api/tables/{}?token={}",
+        harness.table_name, session.token
+    ));
+    assert_eq!(status, "HTTP/1.1 200 OK");
+    let page: serde_json::Value = serde_json::from_str(&body).expect("page json");
+    let rows = page["rows"].as_array().expect("rows");
+    assert_eq!(rows.len(), 2);
+    assert!(rows.iter().all(|row| row["text"] == "****"), "{body}");
+}
+
+#[tokio::test]
+async fn shares_serve_filtered_rows_read_only() {
+    let harness = create_command_harness().await;
+    let session = services_v1::start_share_v1(
+        &harness.state,
+        StartShareRequestV1 {
+            tables: vec![ShareTableV1 {
+                table_id: harness.table_id.clone(),
+                projection: Some(vec!["id".to_string(), "text".to_string()]),
+                filter: Some("id < 3".to_string()),
+            }],
+            expires_in_seconds: Some(60),
+            port: None,
+            local_only: true,
+        },
+    )
+    .await;
+    assert!(session.ok, "share failed: {:?}", session.error);
+    let session = session.data.expect("session");
+    assert_eq!(session.tables, vec![harness.table_name.clone()]);
+
+    let base = session.url.split('?').next().expect("base url").to_string();
+    let (status, body) = http_get(&format!(
+        "{base}api/tables/{}?token={}",
+        harness.table_name, session.token
+    ));
+    assert_eq!(status, "HTTP/1.1 200 OK");
+    let page: serde_json::Value = serde_json::from_str(&body).expect("page json");
+    assert_eq!(page["columns"], serde_json::json!(["id", "text"]));
+    assert_eq!(page["rows"].as_array().map(Vec::len), Some(3));
+
+    let (status, _) = http_get(&format!("{base}?token=wrong"));
+    assert_eq!(status, "HTTP/1.1 403 Forbidden");
+    let (status, index) = http_get(&session.url);
+    assert_eq!(status, "HTTP/1.1 200 OK");
+    assert!(index.contains(&harness.table_name));
+
+    let bad_filter = services_v1::start_share_v1(
+        &harness.state,
+        StartShareRequestV1 {
+            tables: vec![ShareTableV1 {
+                table_id: harness.table_id.clone(),
+                projection: None,
+                filter: Some("missing_column > 1".to_string()),
+            }],
+            expires_in_seconds: None,
+            port: None,
+            local_only: true,
+        },
+    )
+    .await;
+    assert_eq!(
+        bad_filter.error.map(|error| error.code),
+        Some(ErrorCode::InvalidArgument)
+    );
+
+    let shares = services_v1::list_shares_v1(&harness.state)
+        .await
+        .data
+        .expect("shares");
+    assert_eq!(shares.shares.len(), 1);
+    let stopped = services_v1::stop_share_v1(
+        &harness.state,
+        StopShareRequestV1 {
+            share_id: session.share_id.clone(),
+        },
+    )
+    .await;
+    assert!(stopped.ok, "stop failed: {:?}", stopped.error);
+    let listed = services_v1::list_shares_v1(&harness.state)
+        .await
+        .data
+        .expect("shares");
+    assert!(listed.shares.is_empty());
+}
+
+#[tokio::test]
+async fn imports_report_schema_mismatches() {
+    let harness = create_command_harness().await;
+    let dir = tempdir().expect("create import dir");
+    let parquet_path = dir
+        .path()
+        .join("partial.parquet")
+        .to_string_lossy()
+        .to_string();
+    let export = services_v1::export_data_v1(
+        &harness.state,
+        ExportDataRequestV1 {
+            table_id: harness.table_id.clone(),
+            path: parquet_path.clone(),
+            format: DataFileFormatV1::Parquet,
+            projection: Some(vec!["id".to_string(), "text".to_string()]),
+            filter: None,
+            limit: None,
+            offset: None,
+            delimiter: None,
+            with_header: None,
+            geometry_column: None,
+            job_id: None,
+        },
+    )
+    .await;
+    assert!(export.ok, "export failed: {:?}", export.error);
+
+    let csv_path = dir.path().join("extra.csv");
+    fs::write(&csv_path, "id,text,vector,note\n1,a,x,b\n").expect("write csv");
+    let csv_path = csv_path.to_string_lossy().to_string();
+
+    let import = |path: String, format: DataFileFormatV1| ImportDataRequestV1 {
+        table_id: harness.table_id.clone(),
+        path,
+        format,
+        mode: WriteDataMode::Append,
+        has_header: None,
+        delimiter: None,
+        expected_sha256: None,
+        lineage: false,
+        normalize_vectors: false,
+        job_id: None,
+        checkpoint_note: None,
+    };
+
+    let parquet = services_v1::import_data_v1(
+        &harness.state,
+        import(parquet_path, DataFileFormatV1::Parquet),
+    )
+    .await;
+    assert!(!parquet.ok);
+    let error = parquet.error.expect("error");
+    assert_eq!(error.code, ErrorCode::InvalidArgument);
+    let details = error.details.expect("details");
+    assert_eq!(details["missingColumns"], serde_json::json!(["vector"]));
+    assert_eq!(details["extraColumns"], serde_json::json!([]));
+
+    let csv =
+        services_v1::import_data_v1(&harness.state, import(csv_path, DataFileFormatV1::Csv)).await;
+    assert!(!csv.ok);
+    let error = csv.error.expect("error");
+    assert_eq!(error.code, ErrorCode::InvalidArgument);
+    assert!(error.message.contains("extra columns: note"));
+    let details = error.details.expect("details");
+    assert_eq!(details["extraColumns"], serde_json::json!(["note"]));
+
+    let table = harness
+        .state
+        .connections
+        .lock()
+        .expect("lock")
+        .get_table(&harness.table_id)
+        .expect("table");
+    assert_eq!(table.count_rows(None).await.expect("count rows"), 50);
+}
+
+#[tokio::test]
+async fn lineage_imports_stamp_rows_with_their_source() {
+    let harness = create_command_harness().await;
+    let import_dir = tempdir().expect("create import dir");
+    let import_path = import_dir.path().join("rows.jsonl");
+    fs::write(
+        &import_path,
+        "{\"id\": 100, \"text\": \"first\", \"vector\": [0.0, 0.0, 0.0]}\n\n{\"id\": 101, \"text\": \"second\", \"vector\": [1.0, 1.0, 1.0]}\n",
+    )
+    .expect("write rows");
+    let import_path = import_path.to_string_lossy().to_string();
+    let request = || ImportDataRequestV1 {
+        table_id: harness.table_id.clone(),
+        path: import_path.clone(),
+        format: DataFileFormatV1::Jsonl,
+        mode: WriteDataMode::Append,
+        has_header: None,
+        delimiter: None,
+        expected_sha256: None,
+        lineage: true,
+        normalize_vectors: false,
+        job_id: None,
+        checkpoint_note: None,
+    };
+
+    let first = services_v1::import_data_v1(&harness.state, request()).await;
+    assert!(first.ok, "import failed: {:?}", first.error);
+    let first = first.data.expect("import");
+    assert_eq!(first.rows, 2);
+    assert_eq!(
+        first.added_columns,
+        vec!["_source_file", "_import_job_id", "_source_line"]
+    );
+    let import_job_id = first.import_job_id.expect("import job id");
+
+    let table = harness
+        .state
+        .connections
+        .lock()
+        .expect("lock")
+        .get_table(&harness.table_id)
+        .expect("table");
+    let stamped = table
+        .count_rows(Some(format!(
+            "_import_job_id = '{import_job_id}' AND _source_line = 3 AND id = 101 AND _source_file = '{import_path}'"
+        )))
+        .await
+        .expect("count stamped rows");
+    assert_eq!(stamped, 1);
+    let unstamped = table
+        .count_rows(Some("_import_job_id IS NULL".to_string()))
+        .await
+        .expect("count existing rows");
+    assert_eq!(unstamped, 50);
+
+    let second = services_v1::import_data_v1(&harness.state, request()).await;
+    assert!(second.ok, "second import failed: {:?}", second.error);
+    let second = second.data.expect("import");
+    assert!(second.added_columns.is_empty());
+    assert_ne!(
+        second.import_job_id.as_deref(),
+        Some(import_job_id.as_str())
+    );
+}
+
+#[tokio::test]
+async fn export_templates_run_by_name() {
+    let harness = create_command_harness().await;
+    let export_dir = tempdir().expect("create export dir");
+    let pattern = export_dir.path().join("{table}-{date}.csv");
+    let template = |path_pattern: String| ExportTemplateV1 {
+        name: " small items ".to_string(),
+        format: DataFileFormatV1::Csv,
+        path_pattern,
+        projection: Some(vec!["id".to_string(), "text".to_string()]),
+        filter: Some("id < 5".to_string()),
+        limit: None,
+        delimiter: None,
+        with_header: Some(true),
+    };
+
+    let invalid = services_v1::save_export_template_v1(
+        &harness.state,
+        SaveExportTemplateRequestV1 {
+            template: template("/exports/{day}.csv".to_string()),
+        },
+    )
+    .await;
+    assert_eq!(
+        invalid.error.expect("error").code,
+        ErrorCode::InvalidArgument
+    );
+
+    let saved = services_v1::save_export_template_v1(
+        &harness.state,
+        SaveExportTemplateRequestV1 {
+            template: template(pattern.to_string_lossy().to_string()),
+        },
+    )
+    .await;
+    assert!(saved.ok, "save_export_template failed: {:?}", saved.error);
+    assert_eq!(saved.data.expect("template").template.name, "small items");
+    let listed = services_v1::list_export_templates_v1(&harness.state).await;
+    assert_eq!(listed.data.expect("templates").templates.len(), 1);
+
+    let exported = services_v1::run_export_template_v1(
+        &harness.state,
+        RunExportTemplateRequestV1 {
+            table_id: harness.table_id.clone(),
+            name: "small items".to_string(),
+        },
+    )
+    .await;
+    assert!(
+        exported.ok,
+        "run_export_template failed: {:?}",
+        exported.error
+    );
+    let exported = exported.data.expect("export");
+    assert_eq!(exported.rows, 5);
+    let expected = export_dir
+        .path()
+        .join(format!("items-{}.csv", Local::now().format("%Y-%m-%d")));
+    assert_eq!(exported.path, expected.to_string_lossy());
+    assert!(expected.exists());
+
+    let deleted = services_v1::delete_export_template_v1(
+        &harness.state,
+        DeleteExportTemplateRequestV1 {
+            name: "small items".to_string(),
+        },
+    )
+    .await;
+    assert!(
+        deleted.ok,
+        "delete_export_template failed: {:?}",
+        deleted.error
+    );
+    let missing = services_v1::run_export_template_v1(
+        &harness.state,
+        RunExportTemplateRequestV1 {
+            table_id: harness.table_id.clone(),
+            name: "small items".to_string(),
+        },
+    )
+    .await;
+    assert_eq!(missing.error.expect("error").code, ErrorCode::NotFound);
+}
+
+#[tokio::test]
+async fn finished_exports_notify_the_job_webhook() {
+    let harness = create_command_harness().await;
+    let (url, receiver) = spawn_webhook_receiver();
+
+    let mut config = services_v1::get_config_v1(&harness.state)
+        .await
+        .data
+        .expect("config")
+        .config;
+    config.job_webhook_url = Some("ftp://example.com/jobs".to_string());
+    let invalid = services_v1::set_config_v1(
+        &harness.state,
+        SetConfigRequestV1 {
+            config: config.clone(),
+        },
+    )
+    .await;
+    assert_eq!(
+        invalid.error.expect("error").code,
+        ErrorCode::InvalidArgument
+    );
+    config.job_webhook_url = Some(url);
+    let updated = services_v1::set_config_v1(&harness.state, SetConfigRequestV1 { config }).await;
+    assert!(updated.ok, "set_config failed: {:?}", updated.error);
+
+    let export_dir = tempdir().expect("create export dir");
+    let export_path = export_dir.path().join("items.csv");
+    let export_path = export_path.to_string_lossy().to_string();
+    let exported = services_v1::export_data_v1(
+        &harness.state,
+        ExportDataRequestV1 {
+            table_id: harness.table_id.clone(),
+            path: export_path.clone(),
+            format: DataFileFormatV1::Csv,
+            projection: Some(vec!["id".to_string()]),
+            filter: None,
+            limit: None,
+            offset: None,
+            delimiter: None,
+            with_header: None,
+            geometry_column: None,
+            job_id: None,
+        },
+    )
+    .await;
+    assert!(exported.ok, "export failed: {:?}", exported.error);
+
+    let payload = receiver
+        .recv_timeout(Duration::from_secs(10))
+        .expect("webhook payload");
+    assert_eq!(payload["kind"], "export");
+    assert_eq!(payload["status"], "succeeded");
+    assert_eq!(payload["outputPath"], export_path);
+    let job_id = payload["jobId"].as_str().expect("job id");
+    let job = services_v1::get_job_status_v1(
+        &harness.state,
+        GetJobStatusRequestV1 {
+            job_id: job_id.to_string(),
+        },
+    )
+    .await;
+    assert_eq!(job.data.expect("job").kind, JobKindV1::Export);
+}
+
+#[tokio::test]
+async fn overview_reports_every_connection() {
+    let harness = create_command_harness().await;
+
+    let response = services_v1::overview_v1(&harness.state, OverviewRequestV1::default()).await;
+    assert!(response.ok, "overview failed: {:?}", response.error);
+    let overview = response.data.expect("overview");
+    assert_eq!(overview.connections.len(), 1);
+    let connection = &overview.connections[0];
+    assert_eq!(connection.connection_id, harness.connection_id);
+    assert_eq!(connection.health, ConnectionHealthV1::Ok);
+    assert_eq!(connection.open_tables, 1);
+    assert_eq!(connection.table_count, Some(1));
+    assert_eq!(connection.total_rows, Some(50));
+    assert!(connection.total_size_bytes.unwrap_or(0) > 0);
+    assert!(connection.failed_tables.is_empty());
+
+    let invalid = services_v1::overview_v1(
+        &harness.state,
+        OverviewRequestV1 {
+            timeout_ms: Some(0),
+        },
+    )
+    .await;
+    assert!(!invalid.ok);
+    assert_eq!(
+        invalid.error.expect("error").code,
+        ErrorCode::InvalidArgument
+    );
+}
+
+#[tokio::test]
+async fn stats_history_records_a_snapshot_per_interval() {
+    let harness = create_command_harness().await;
+    let request = || StatsHistoryRequestV1 {
+        table_id: harness.table_id.clone(),
+        since_ms: None,
+    };
+
+    let first = services_v1::stats_history_v1(&harness.state, request()).await;
+    assert!(first.ok, "stats_history failed: {:?}", first.error);
+    let first = first.data.expect("history");
+    assert_eq!(first.snapshots.len(), 1);
+    assert_eq!(first.snapshots[0].row_count, 50);
+    assert!(first.snapshots[0].size_bytes > 0);
+
+    // The next snapshot is only due an interval later, from the background loop.
+    let table = harness
+        .state
+        .connections
+        .lock()
+        .expect("lock")
+        .get_table(&harness.table_id)
+        .expect("table");
+    table.delete("id < 10").await.expect("delete rows");
+    let second = services_v1::stats_history_v1(&harness.state, request()).await;
+    assert_eq!(second.data.expect("history").snapshots.len(), 1);
+
+    let later = first.snapshots[0].taken_at_ms + stats_history::SNAPSHOT_INTERVAL_MS;
+    assert_eq!(
+        stats_history::snapshot_open_tables(&harness.state, later).await,
+        1
+    );
+    let history = services_v1::stats_history_v1(&harness.state, request()).await;
+    let history = history.data.expect("history");
+    assert_eq!(
+        history
+            .snapshots
+            .iter()
+            .map(|snapshot| snapshot.row_count)
+            .collect::<Vec<_>>(),
+        vec![50, 40]
+    );
+}
+
+#[tokio::test]
+async fn index_builds_reject_unavailable_accelerators() {
+    let harness = create_command_harness().await;
+
+    let capabilities = services_v1::get_index_capabilities_v1(&harness.state).await;
+    let capabilities = capabilities.data.expect("capabilities");
+    assert_eq!(capabilities.default_accelerator, IndexAcceleratorV1::Cpu);
+    assert!(!capabilities.accelerators.contains(&IndexAcceleratorV1::Gpu));
+    assert!(capabilities.cpu_threads > 0);
+
+    let request = |index_type: IndexTypeV1, column: &str| CreateIndexRequestV1 {
+        table_id: harness.table_id.clone(),
+        columns: vec![column.to_string()],
+        index_type,
+        name: None,
+        replace: true,
+        distance_type: None,
+        num_partitions: Some(1),
+        sample_rate: None,
+        max_iterations: None,
+        target_partition_size: None,
+        num_sub_vectors: None,
+        num_bits: None,
+        num_edges: None,
+        ef_construction: None,
+        accelerator: Some(IndexAcceleratorV1::Gpu),
+        fts_tokenizer: None,
+        job_id: None,
+        preset: None,
+    };
+
+    let gpu =
+        services_v1::create_index_v1(&harness.state, request(IndexTypeV1::IvfFlat, "vector")).await;
+    assert!(!gpu.ok);
+    assert_eq!(gpu.error.expect("error").code, ErrorCode::InvalidArgument);
+
+    // Scalar indexes do no vector training, so the accelerator does not apply.
+    let scalar =
+        services_v1::create_index_v1(&harness.state, request(IndexTypeV1::BTree, "id")).await;
+    assert!(scalar.ok, "btree index failed: {:?}", scalar.error);
+    assert!(scalar.data.expect("index").accelerator.is_none());
+}
+
+#[tokio::test]
+async fn searches_read_a_pinned_table_version() {
+    let harness = create_command_harness().await;
+    let table = harness
+        .state
+        .connections
+        .lock()
+        .expect("lock")
+        .get_table(&harness.table_id)
+        .expect("table");
+    let pinned = table.version().await.expect("version");
+    table.delete("id < 20").await.expect("delete rows");
+
+    let search = |as_of_version: Option<u64>| VectorSearchRequestV1 {
+        table_id: harness.table_id.clone(),
+        vector: vec![0.0, 0.1, 0.2],
+        column: Some("vector".to_string()),
+        top_k: Some(100),
+        projection: None,
+        projection_preset: None,
+        filter: None,
+        nprobes: None,
+        refine_factor: None,
+        distance_type: None,
+        offset: None,
+        as_of_version,
+        include_stats: false,
+        json_options: None,
+        query_embedding: None,
+        normalize_query: false,
+    };
+    let row_count = |response: ResultEnvelope<_>| {
+        let response: lancedb_viewer_lib::ipc::v1::QueryResponseV1 =
+            response.data.expect("search data");
+        match response.chunk {
+            lancedb_viewer_lib::ipc::v1::DataChunk::Json(chunk) => chunk.rows.len(),
+            _ => panic!("expected json chunk"),
+        }
+    };
+
+    let snapshot = services_v1::vector_search_v1(&harness.state, search(Some(pinned))).await;
+    assert!(snapshot.ok, "pinned search failed: {:?}", snapshot.error);
+    assert_eq!(row_count(snapshot), 50);
+
+    // The open handle keeps following the latest version.
+    let latest = services_v1::vector_search_v1(&harness.state, search(None)).await;
+    assert_eq!(row_count(latest), 30);
+
+    let missing = services_v1::vector_search_v1(&harness.state, search(Some(pinned + 100))).await;
+    assert!(!missing.ok);
+    assert_eq!(
+        missing.error.expect("error").code,
+        ErrorCode::InvalidArgument
+    );
+}
+
+#[tokio::test]
+async fn fts_search_reranks_candidates_with_an_expression() {
+    let harness = create_command_harness().await;
+    let table = harness
+        .state
+        .connections
+        .lock()
+        .expect("lock")
+        .get_table(&harness.table_id)
+        .expect("table");
+    table
+        .create_index(&["text"], Index::FTS(Default::default()))
+        .execute()
+        .await
+        .expect("create fts index");
+
+    let request = |reranker: RerankerV1, offset: usize| FtsSearchRequestV1 {
+        table_id: harness.table_id.clone(),
+        query: "item".to_string(),
+        columns: Some(vec!["text".to_string()]),
+        limit: Some(5),
+        offset: Some(offset),
+        projection: None,
+        projection_preset: None,
+        filter: None,
+        include_stats: false,
+        json_options: None,
+        reranker: Some(reranker),
+        as_of_version: None,
+        highlight: false,
+    };
+    let by_id = || {
+        RerankerV1::Expression(ExpressionRerankerV1 {
+            expression: "-id".to_string(),
+        })
+    };
+
+    let first = services_v1::fts_search_v1(&harness.state, request(by_id(), 0)).await;
+    assert!(first.ok, "reranked fts_search failed: {:?}", first.error);
+    let first = first.data.expect("reranked data");
+    assert_eq!(first.next_offset, Some(5));
+    let lancedb_viewer_lib::ipc::v1::DataChunk::Json(chunk) = first.chunk else {
+        panic!("expected json chunk");
+    };
+    let ids: Vec<_> = chunk.rows.iter().map(|row| row["id"].clone()).collect();
+    assert_eq!(ids, (0..5).map(serde_json::Value::from).collect::<Vec<_>>());
+    assert_eq!(chunk.rows[1]["_rerank_score"], serde_json::json!(-1.0));
+
+    let second = services_v1::fts_search_v1(&harness.state, request(by_id(), 5)).await;
+    let second = second.data.expect("second page");
+    let lancedb_viewer_lib::ipc::v1::DataChunk::Json(chunk) = second.chunk else {
+        panic!("expected json chunk");
+    };
+    assert_eq!(chunk.rows[0]["id"], serde_json::json!(5));
+
+    let rrf = services_v1::fts_search_v1(
+        &harness.state,
+        request(RerankerV1::Rrf(RrfRerankerV1 { k: None }), 0),
+    )
+    .await;
+    assert!(!rrf.ok);
+    assert_eq!(rrf.error.expect("error").code, ErrorCode::InvalidArgument);
+}
+
+#[tokio::test]
+async fn search_defaults_are_saved_per_table() {
+    let harness = create_command_harness().await;
+
+    let empty = services_v1::get_search_defaults_v1(
+        &harness.state,
+        GetSearchDefaultsRequestV1 {
+            table_id: harness.table_id.clone(),
+        },
+    )
+    .await;
+    assert!(empty.ok, "get defaults failed: {:?}", empty.error);
+    assert!(empty.data.expect("defaults data").defaults.is_none());
+
+    let saved = services_v1::set_search_defaults_v1(
+        &harness.state,
+        SetSearchDefaultsRequestV1 {
+            table_id: harness.table_id.clone(),
+            defaults: Some(SearchDefaultsV1 {
+                vector_column: Some(" vector ".to_string()),
+                distance_type: Some(DistanceTypeV1::Cosine),
+                top_k: Some(25),
+                fts_columns: vec!["text".to_string()],
+            }),
+        },
+    )
+    .await;
+    assert!(saved.ok, "set defaults failed: {:?}", saved.error);
+
+    let loaded = services_v1::get_search_defaults_v1(
+        &harness.state,
+        GetSearchDefaultsRequestV1 {
+            table_id: harness.table_id.clone(),
+        },
+    )
+    .await;
+    let defaults = loaded
+        .data
+        .and_then(|data| data.defaults)
+        .expect("saved defaults");
+    assert_eq!(defaults.vector_column.as_deref(), Some("vector"));
+    assert_eq!(defaults.distance_type, Some(DistanceTypeV1::Cosine));
+    assert_eq!(defaults.top_k, Some(25));
+    assert_eq!(defaults.fts_columns, vec!["text"]);
+
+    let invalid = services_v1::set_search_defaults_v1(
+        &harness.state,
+        SetSearchDefaultsRequestV1 {
+            table_id: harness.table_id.clone(),
+            defaults: Some(SearchDefaultsV1 {
+                vector_column: Some("text".to_string()),
+                ..Default::default()
+            }),
+        },
+    )
+    .await;
+    assert!(!invalid.ok);
+    assert_eq!(
+        invalid.error.expect("error").code,
+        ErrorCode::InvalidArgument
+    );
+
+    let cleared = services_v1::set_search_defaults_v1(
+        &harness.state,
+        SetSearchDefaultsRequestV1 {
+            table_id: harness.table_id.clone(),
+            defaults: None,
+        },
+    )
+    .await;
+    assert!(cleared.ok, "clear defaults failed: {:?}", cleared.error);
+    assert!(cleared.data.expect("cleared data").defaults.is_none());
+}
+
+#[tokio::test]
+async fn scans_and_searches_use_saved_projection_presets() {
+    let harness = create_command_harness().await;
+
+    let saved = services_v1::save_projection_preset_v1(
+        &harness.state,
+        SaveProjectionPresetRequestV1 {
+            table_id: harness.table_id.clone(),
+            preset: ProjectionPresetV1 {
+                name: " minimal ".to_string(),
+                columns: vec!["id".to_string()],
+            },
+        },
+    )
+    .await;
+    assert!(saved.ok, "save preset failed: {:?}", saved.error);
+    assert_eq!(saved.data.expect("saved data").preset.name, "minimal");
+
+    let missing_column = services_v1::save_projection_preset_v1(
+        &harness.state,
+        SaveProjectionPresetRequestV1 {
+            table_id: harness.table_id.clone(),
+            preset: ProjectionPresetV1 {
+                name: "broken".to_string(),
+                columns: vec!["missing".to_string()],
+            },
+        },
+    )
+    .await;
+    assert_eq!(
+        missing_column.error.expect("error").code,
+        ErrorCode::InvalidArgument
+    );
+
+    let listed = services_v1::list_projection_presets_v1(
+        &harness.state,
+        ListProjectionPresetsRequestV1 {
+            table_id: harness.table_id.clone(),
+        },
+    )
+    .await;
+    let presets = listed.data.expect("list data").presets;
+    assert_eq!(presets.len(), 1);
+    assert_eq!(presets[0].columns, vec!["id"]);
+
+    let scan = |projection: Option<Vec<String>>, preset: &str| ScanRequestV1 {
+        table_id: harness.table_id.clone(),
+        format: DataFormat::Json,
+        projection,
+        projection_preset: Some(preset.to_string()),
+        filter: None,
+        limit: Some(2),
+        offset: Some(0),
+        apply_masking: false,
+        prefetch: false,
+        include_stats: false,
+        json_options: None,
+        as_of_version: None,
+        max_chunk_bytes: None,
+        job_id: None,
+    };
+    let scanned = services_v1::scan_v1(&harness.state, scan(None, "minimal")).await;
+    assert!(scanned.ok, "scan failed: {:?}", scanned.error);
+    let DataChunk::Json(chunk) = scanned.data.expect("scan data").chunk else {
+        panic!("expected json chunk");
+    };
+    for row in &chunk.rows {
+        let keys = row
+            .as_object()
+            .expect("row object")
+            .keys()
+            .collect::<Vec<_>>();
+        assert_eq!(keys, vec!["id"]);
+    }
+
+    let both = services_v1::scan_v1(
+        &harness.state,
+        scan(Some(vec!["text".to_string()]), "minimal"),
+    )
+    .await;
+    assert_eq!(both.error.expect("error").code, ErrorCode::InvalidArgument);
+
+    let unknown = services_v1::query_filter_v1(
+        &harness.state,
+        QueryFilterRequestV1 {
+            table_id: harness.table_id.clone(),
+            filter: "id >= 0".to_string(),
+            projection: None,
+            projection_preset: Some("with vectors".to_string()),
+            limit: Some(2),
+            offset: None,
+            include_stats: false,
+            json_options: None,
+        },
+    )
+    .await;
+    assert_eq!(unknown.error.expect("error").code, ErrorCode::NotFound);
+
+    let delete = || DeleteProjectionPresetRequestV1 {
+        table_id: harness.table_id.clone(),
+        name: "minimal".to_string(),
+    };
+    let deleted = services_v1::delete_projection_preset_v1(&harness.state, delete()).await;
+    assert!(deleted.ok, "delete preset failed: {:?}", deleted.error);
+    let deleted_again = services_v1::delete_projection_preset_v1(&harness.state, delete()).await;
+    assert_eq!(
+        deleted_again.error.expect("error").code,
+        ErrorCode::NotFound
+    );
+}
+
+#[tokio::test]
+async fn searches_without_an_index_report_missing_index_warnings() {
+    let harness = create_command_harness().await;
+
+    let response = services_v1::vector_search_v1(
+        &harness.state,
+        VectorSearchRequestV1 {
+            table_id: harness.table_id.clone(),
+            vector: vec![0.1, 0.2, 0.3],
+            column: Some("vector".to_string()),
+            top_k: Some(3),
+            projection: None,
+            projection_preset: None,
+            filter: None,
+            nprobes: None,
+            refine_factor: None,
+            offset: None,
+            include_stats: false,
+            json_options: None,
+            distance_type: None,
+            as_of_version: None,
+            query_embedding: None,
+            normalize_query: false,
+        },
+    )
+    .await;
+    assert!(response.ok, "vector search failed: {:?}", response.error);
+    let warnings = response.data.expect("vector search data").warnings;
+    assert_eq!(warnings.len(), 1, "unexpected warnings: {warnings:?}");
+    assert_eq!(warnings[0].kind, SearchWarningKindV1::MissingIndex);
+    assert_eq!(warnings[0].index_kind, SearchIndexKindV1::Vector);
+    assert_eq!(warnings[0].column.as_deref(), Some("vector"));
+
+    let filtered = services_v1::query_filter_v1(
+        &harness.state,
+        QueryFilterRequestV1 {
+            table_id: harness.table_id.clone(),
+            filter: "id < 3".to_string(),
+            projection: None,
+            projection_preset: None,
+            limit: Some(3),
+            offset: None,
+            include_stats: false,
+            json_options: None,
+        },
+    )
+    .await;
+    assert!(filtered.ok, "filter failed: {:?}", filtered.error);
+    assert!(filtered.data.expect("filter data").warnings.is_empty());
+}
+
+#[tokio::test]
+async fn row_counts_are_cached_until_the_table_changes() {
+    let harness = &create_command_harness().await;
+    let row_count = move || async move {
+        services_v1::get_row_count_v1(
+            &harness.state,
+            GetRowCountRequestV1 {
+                table_id: harness.table_id.clone(),
+            },
+        )
+        .await
+        .data
+        .expect("row count")
+    };
+
+    let first = row_count().await;
+    assert_eq!(first.row_count, 50);
+    assert!(!first.cached);
+    let second = row_count().await;
+    assert_eq!(second.row_count, 50);
+    assert_eq!(second.version, first.version);
+    assert!(second.cached);
+
+    let listed = services_v1::list_tables_v1(
+        &harness.state,
+        ListTablesRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            include_stats: false,
+        },
+    )
+    .await
+    .data
+    .expect("tables");
+    assert_eq!(listed.tables[0].row_count, Some(50));
+    assert!(listed.tables[0].row_count_cached);
+
+    let deleted = services_v1::delete_rows_v1(
+        &harness.state,
+        DeleteRowsRequestV1 {
+            table_id: harness.table_id.clone(),
+            filter: "id < 10".to_string(),
+            allow_full_table: false,
+            chunk_size: None,
+            job_id: None,
+            checkpoint_note: None,
+        },
+    )
+    .await;
+    assert!(deleted.ok, "delete should succeed: {:?}", deleted.error);
+
+    let after_delete = row_count().await;
+    assert_eq!(after_delete.row_count, 40);
+    assert!(after_delete.version > first.version);
+}
+
+#[tokio::test]
+async fn materialize_query_saves_results_as_a_table() {
+    let harness = create_command_harness().await;
+    let request = |overwrite: bool, limit: Option<usize>| MaterializeQueryRequestV1 {
+        connection_id: harness.connection_id.clone(),
+        source: MaterializeSourceV1::Filter(QueryFilterRequestV1 {
+            table_id: harness.table_id.clone(),
+            filter: "id < 20".to_string(),
+            projection: Some(vec!["id".to_string(), "text".to_string()]),
+            projection_preset: None,
+            limit,
+            offset: None,
+            include_stats: false,
+            json_options: None,
+        }),
+        table_name: "items_subset".to_string(),
+        overwrite,
+    };
+
+    let saved = services_v1::materialize_query_v1(&harness.state, request(false, None)).await;
+    assert!(saved.ok, "materialize should succeed: {:?}", saved.error);
+    let saved = saved.data.expect("materialize data");
+    assert_eq!(saved.name, "items_subset");
+    assert_eq!(saved.rows, 20);
+
+    let schema = services_v1::get_schema_v1(
+        &harness.state,
+        GetSchemaRequestV1 {
+            table_id: saved.table_id.clone(),
+        },
+    )
+    .await
+    .data
+    .expect("schema");
+    let names = schema
+        .fields
+        .iter()
+        .map(|field| field.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["id", "text"]);
+
+    let existing = services_v1::materialize_query_v1(&harness.state, request(false, None)).await;
+    assert_eq!(
+        existing.error.as_ref().map(|error| &error.code),
+        Some(&ErrorCode::InvalidArgument)
+    );
+
+    let replaced = services_v1::materialize_query_v1(&harness.state, request(true, Some(5)))
+        .await
+        .data
+        .expect("overwrite data");
+    assert_eq!(replaced.rows, 5);
+
+    let own_table = services_v1::materialize_query_v1(
+        &harness.state,
+        MaterializeQueryRequestV1 {
+            table_name: harness.table_name.clone(),
+            ..request(true, None)
+        },
+    )
+    .await;
+    assert_eq!(
+        own_table.error.as_ref().map(|error| &error.code),
+        Some(&ErrorCode::InvalidArgument)
+    );
+}
+
+#[tokio::test]
+async fn missing_tables_report_not_found_with_available_names() {
+    let harness = create_command_harness().await;
+
+    let opened = services_v1::open_table_v1(
+        &harness.state,
+        OpenTableRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            table_name: "itmes".to_string(),
+        },
+    )
+    .await;
+    assert!(!opened.ok, "opening a missing table should fail");
+    let error = opened.error.expect("open error");
+    assert_eq!(error.code, ErrorCode::NotFound);
+    let details = error.details.expect("error details");
+    assert_eq!(details["tableName"], "itmes");
+    assert_eq!(details["availableTables"], serde_json::json!(["items"]));
+
+    let dropped = services_v1::drop_table_v1(
+        &harness.state,
+        DropTableRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            table_name: "itmes".to_string(),
+            namespace: None,
+        },
+    )
+    .await;
+    assert_eq!(
+        dropped.error.as_ref().map(|error| &error.code),
+        Some(&ErrorCode::NotFound)
+    );
+}
+
+#[tokio::test]
+async fn chunked_delete_runs_as_a_job() {
+    let harness = create_command_harness().await;
+
+    let deleted = services_v1::delete_rows_v1(
+        &harness.state,
+        DeleteRowsRequestV1 {
+            table_id: harness.table_id.clone(),
+            filter: "id >= 10".to_string(),
+            allow_full_table: false,
+            chunk_size: Some(15),
+            job_id: Some("delete-job".to_string()),
+            checkpoint_note: None,
+        },
+    )
+    .await;
+    assert!(
+        deleted.ok,
+        "chunked delete should succeed: {:?}",
+        deleted.error
+    );
+    let deleted = deleted.data.expect("delete data");
+    assert_eq!(deleted.job_id.as_deref(), Some("delete-job"));
+    assert_eq!(deleted.rows_deleted, Some(40));
+    assert!(!deleted.cancelled);
+
+    let job = services_v1::get_job_status_v1(
+        &harness.state,
+        GetJobStatusRequestV1 {
+            job_id: "delete-job".to_string(),
+        },
+    )
+    .await
+    .data
+    .expect("job status");
+    assert_eq!(job.kind, JobKindV1::Delete);
+    assert_eq!(job.state, JobStateV1::Succeeded);
+    assert_eq!(job.processed_rows, 40);
+    assert_eq!(job.total_rows, Some(40));
+
+    let remaining = services_v1::query_filter_v1(
+        &harness.state,
+        QueryFilterRequestV1 {
+            table_id: harness.table_id.clone(),
+            filter: "id >= 0".to_string(),
+            projection: Some(vec!["id".to_string()]),
+            projection_preset: None,
+            limit: Some(100),
+            offset: Some(0),
+            include_stats: false,
+            json_options: None,
+        },
+    )
+    .await;
+    match remaining.data.expect("query data").chunk {
+        lancedb_viewer_lib::ipc::v1::DataChunk::Json(chunk) => assert_eq!(chunk.rows.len(), 10),
+        _ => panic!("expected json chunk"),
+    }
+
+    let finished = services_v1::cancel_job_v1(
+        &harness.state,
+        CancelJobRequestV1 {
+            job_id: "delete-job".to_string(),
+        },
+    )
+    .await;
+    assert_eq!(
+        finished.data.expect("job status").state,
+        JobStateV1::Succeeded
+    );
+    let missing = services_v1::cancel_job_v1(
+        &harness.state,
+        CancelJobRequestV1 {
+            job_id: "missing".to_string(),
+        },
+    )
+    .await;
+    assert_eq!(missing.error.expect("error").code, ErrorCode::NotFound);
+
+    let rejected = services_v1::delete_rows_v1(
+        &harness.state,
+        DeleteRowsRequestV1 {
+            table_id: harness.table_id.clone(),
+            filter: "id = 1".to_string(),
+            allow_full_table: false,
+            chunk_size: Some(0),
+            job_id: None,
+            checkpoint_note: None,
+        },
+    )
+    .await;
+    assert_eq!(
+        rejected.error.expect("error").code,
+        ErrorCode::InvalidArgument
+    );
+}
+
+#[tokio::test]
+async fn preview_update_returns_before_and_after_without_writing() {
+    let harness = create_command_harness().await;
+    let request = |updates: Vec<(&str, &str)>| PreviewUpdateRequestV1 {
+        table_id: harness.table_id.clone(),
+        filter: Some("id < 3".to_string()),
+        updates: updates
+            .into_iter()
+            .map(|(column, expr)| UpdateColumnInputV1 {
+                column: column.to_string(),
+                expr: expr.to_string(),
+            })
+            .collect(),
+        allow_full_table: false,
+        limit: Some(2),
+    };
+
+    let preview = services_v1::preview_update_v1(
+        &harness.state,
+        request(vec![("id", "id * 10 + 0.4"), ("text", "text || '!'")]),
+    )
+    .await;
+    assert!(preview.ok, "preview should succeed: {:?}", preview.error);
+    let preview = preview.data.expect("preview data");
+    assert_eq!(preview.columns, vec!["id", "text"]);
+    assert_eq!(preview.matched_rows, 3);
+    assert_eq!(preview.rows.len(), 2);
+    for row in &preview.rows {
+        let id = row.before["id"].as_i64().expect("id");
+        assert_eq!(row.after["id"], serde_json::json!(id * 10));
+        assert_eq!(row.after["text"], serde_json::json!(format!("item {id}!")));
+        assert_eq!(row.before["text"], serde_json::json!(format!("item {id}")));
+        assert_eq!(row.after["vector"], row.before["vector"]);
+    }
+
+    let rejected =
+        services_v1::preview_update_v1(&harness.state, request(vec![("id", "'not a number'")]))
+            .await;
+    assert_eq!(
+        rejected.error.expect("cast error").code,
+        ErrorCode::InvalidArgument
+    );
+
+    let unchanged = services_v1::query_filter_v1(
+        &harness.state,
+        QueryFilterRequestV1 {
+            table_id: harness.table_id.clone(),
+            filter: "text = 'item 1!'".to_string(),
+            projection: None,
+            projection_preset: None,
+            limit: Some(10),
+            offset: Some(0),
+            include_stats: false,
+            json_options: None,
+        },
+    )
+    .await;
+    match unchanged.data.expect("query data").chunk {
+        lancedb_viewer_lib::ipc::v1::DataChunk::Json(chunk) => assert!(chunk.rows.is_empty()),
+        _ => panic!("expected json chunk"),
+    }
+}
+
+#[tokio::test]
+async fn auto_large_integers_are_stringified_and_annotated() {
+    let harness = create_command_harness().await;
+
+    let response = services_v1::query_filter_v1(
+        &harness.state,
+        QueryFilterRequestV1 {
+            table_id: harness.table_id.clone(),
+            filter: "id = 3".to_string(),
+            projection: Some(vec![
+                "CAST(id AS BIGINT) AS small".to_string(),
+                "CAST(id AS BIGINT) + 9007199254740990 AS big".to_string(),
+            ]),
+            projection_preset: None,
+            limit: Some(10),
+            offset: Some(0),
+            include_stats: false,
+            json_options: Some(JsonOptionsV1 {
+                large_integers: LargeIntegerFormatV1::Auto,
+                ..Default::default()
+            }),
+        },
+    )
+    .await;
+    assert!(response.ok, "query should succeed: {:?}", response.error);
+    match response.data.expect("query data").chunk {
+        lancedb_viewer_lib::ipc::v1::DataChunk::Json(chunk) => {
+            assert_eq!(
+                chunk.rows,
+                vec![serde_json::json!({"small": 3, "big": "9007199254740993"})]
+            );
+            let encoded = chunk
+                .schema
+                .fields
+                .iter()
+                .filter(|field| field.string_encoded)
+                .map(|field| field.name.as_str())
+                .collect::<Vec<_>>();
+            assert_eq!(encoded, vec!["big"]);
+        }
+        _ => panic!("expected json chunk"),
+    }
+}
+
+#[tokio::test]
+async fn json_options_render_large_integers_as_strings() {
+    let harness = create_command_harness().await;
+    let request = |json_options| ScanRequestV1 {
+        table_id: harness.table_id.clone(),
+        format: DataFormat::Json,
+        projection: Some(vec![
+            "CAST(id AS BIGINT) + 9007199254740990 AS big".to_string(),
+            "nullif(text, text) AS missing".to_string(),
+        ]),
+        projection_preset: None,
+        filter: Some("id = 3".to_string()),
+        limit: Some(10),
+        offset: Some(0),
+        apply_masking: false,
+        prefetch: false,
+        include_stats: false,
+        json_options,
+        as_of_version: None,
+        max_chunk_bytes: None,
+        job_id: None,
+    };
+
+    let scan_row = |response: ResultEnvelope<ScanResponseV1>| {
+        assert!(response.ok, "scan should succeed: {:?}", response.error);
+        match response.data.expect("scan data").chunk {
+            lancedb_viewer_lib::ipc::v1::DataChunk::Json(mut chunk) => {
+                assert_eq!(chunk.rows.len(), 1);
+                chunk.rows.remove(0)
+            }
+            _ => panic!("expected json chunk"),
+        }
+    };
+
+    let default_row = scan_row(services_v1::scan_v1(&harness.state, request(None)).await);
+    assert_eq!(
+        default_row,
+        serde_json::json!({"big": 9007199254740993_i64})
+    );
+
+    let options = JsonOptionsV1 {
+        large_integers: LargeIntegerFormatV1::String,
+        explicit_nulls: true,
+        ..Default::default()
+    };
+    let string_row = scan_row(services_v1::scan_v1(&harness.state, request(Some(options))).await);
+    assert_eq!(
+        string_row,
+        serde_json::json!({"big": "9007199254740993", "missing": null})
+    );
+}
+
+#[tokio::test]
+async fn projection_accepts_sql_expressions_with_aliases() {
+    let harness = create_command_harness().await;
+
+    let scanned = services_v1::scan_v1(
+        &harness.state,
+        ScanRequestV1 {
+            table_id: harness.table_id.clone(),
+            format: DataFormat::Json,
+            projection: Some(vec![
+                "id".to_string(),
+                "id * 2 AS doubled".to_string(),
+                "upper(text) as shout".to_string(),
+            ]),
+            projection_preset: None,
+            filter: Some("id = 3".to_string()),
+            limit: Some(10),
+            offset: Some(0),
+            apply_masking: false,
+            prefetch: false,
+            include_stats: false,
+            json_options: None,
+            as_of_version: None,
+            max_chunk_bytes: None,
+            job_id: None,
+        },
+    )
+    .await;
+    assert!(scanned.ok, "scan should succeed: {:?}", scanned.error);
+    match scanned.data.expect("scan data").chunk {
+        lancedb_viewer_lib::ipc::v1::DataChunk::Json(chunk) => {
+            assert_eq!(chunk.rows.len(), 1);
+            assert_eq!(
+                chunk.rows[0],
+                serde_json::json!({"id": 3, "doubled": 6, "shout": "ITEM 3"})
+            );
+        }
+        _ => panic!("expected json chunk"),
+    }
+
+    let filtered = services_v1::query_filter_v1(
+        &harness.state,
+        QueryFilterRequestV1 {
+            table_id: harness.table_id.clone(),
+            filter: "id < 2".to_string(),
+            projection: Some(vec!["id + 100 AS shifted".to_string()]),
+            projection_preset: None,
+            limit: Some(10),
+            offset: Some(0),
+            include_stats: false,
+            json_options: None,
+        },
+    )
+    .await;
+    assert!(filtered.ok, "query should succeed: {:?}", filtered.error);
+    match filtered.data.expect("query data").chunk {
+        lancedb_viewer_lib::ipc::v1::DataChunk::Json(chunk) => {
+            let mut shifted = chunk
+                .rows
+                .iter()
+                .map(|row| row["shifted"].as_i64().expect("shifted"))
+                .collect::<Vec<_>>();
+            shifted.sort_unstable();
+            assert_eq!(shifted, vec![100, 101]);
+        }
+        _ => panic!("expected json chunk"),
+    }
+}
+
+#[tokio::test]
+async fn pivot_aggregates_values_by_row_and_column_keys() {
+    let harness = create_command_harness().await;
+
+    let field = |name: &str, data_type: FieldDataType| SchemaFieldInput {
+        name: name.to_string(),
+        data_type,
+        nullable: true,
+        metadata: None,
+        vector_length: None,
+        embedding: None,
+    };
+    let created = services_v1::create_table_v1(
+        &harness.state,
+        CreateTableRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            table_name: "sales".to_string(),
+            schema: SchemaDefinitionInput {
+                fields: vec![
+                    field("region", FieldDataType::Utf8),
+                    field("product", FieldDataType::Utf8),
+                    field("amount", FieldDataType::Int64),
+                ],
+            },
+            options: Default::default(),
+        },
+    )
+    .await;
+    assert!(
+        created.ok,
+        "create_table should succeed: {:?}",
+        created.error
+    );
+    let table_id = created.data.expect("create table data").table_id;
+
+    let write = services_v1::write_rows_v1(
+        &harness.state,
+        WriteRowsRequestV1 {
+            table_id: table_id.clone(),
+            rows: vec![
+                serde_json::json!({"region": "west", "product": "tea", "amount": 3}),
+                serde_json::json!({"region": "east", "product": "tea", "amount": 5}),
+                serde_json::json!({"region": "west", "product": "tea", "amount": 7}),
+                serde_json::json!({"region": "west", "product": "coffee", "amount": 1}),
+                serde_json::json!({"region": "east", "product": "cocoa", "amount": 4}),
+            ],
+            mode: WriteDataMode::Append,
+            normalize_vectors: false,
+            checkpoint_note: None,
+        },
+    )
+    .await;
+    assert!(write.ok, "write_rows should succeed: {:?}", write.error);
+
+    let request = |aggregation, value_column: Option<&str>, max_columns| PivotRequestV1 {
+        table_id: table_id.clone(),
+        row_keys: vec!["region".to_string()],
+        column_key: "product".to_string(),
+        value_column: value_column.map(str::to_string),
+        aggregation,
+        filter: Some("amount > 1".to_string()),
+        max_columns,
+    };
+
+    let summed = services_v1::pivot_v1(
+        &harness.state,
+        request(PivotAggregationV1::Sum, Some("amount"), None),
+    )
+    .await;
+    assert!(summed.ok, "pivot should succeed: {:?}", summed.error);
+    let summed = summed.data.expect("pivot data");
+    assert_eq!(summed.columns, vec!["tea", "cocoa"]);
+    assert_eq!(summed.scanned_rows, 4);
+    assert_eq!(
+        summed.rows,
+        vec![
+            PivotRowV1 {
+                keys: vec![serde_json::json!("east")],
+                values: vec![Some(5.0), Some(4.0)],
+            },
+            PivotRowV1 {
+                keys: vec![serde_json::json!("west")],
+                values: vec![Some(10.0), None],
+            },
+        ]
+    );
+
+    let capped = services_v1::pivot_v1(
+        &harness.state,
+        request(PivotAggregationV1::Count, None, Some(1)),
+    )
+    .await
+    .data
+    .expect("pivot data");
+    assert_eq!(capped.columns, vec!["tea"]);
+    assert_eq!(capped.omitted_columns, 1);
+
+    let non_numeric = services_v1::pivot_v1(
+        &harness.state,
+        request(PivotAggregationV1::Avg, Some("product"), None),
+    )
+    .await;
+    assert_eq!(
+        non_numeric.error.expect("error").code,
+        ErrorCode::InvalidArgument
+    );
+}
+
+#[tokio::test]
+async fn geometry_columns_are_detected_and_exported_as_geojson() {
+    let harness = create_command_harness().await;
+
+    let created = services_v1::create_table_v1(
+        &harness.state,
+        CreateTableRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            table_name: "places".to_string(),
+            schema: SchemaDefinitionInput {
+                fields: vec![
+                    SchemaFieldInput {
+                        name: "id".to_string(),
+                        data_type: FieldDataType::Int32,
+                        nullable: false,
+                        metadata: None,
+                        vector_length: None,
+                        embedding: None,
+                    },
+                    SchemaFieldInput {
+                        name: "geometry".to_string(),
+                        data_type: FieldDataType::Utf8,
+                        nullable: true,
+                        metadata: None,
+                        vector_length: None,
+                        embedding: None,
+                    },
+                    SchemaFieldInput {
+                        name: "footprint".to_string(),
+                        data_type: FieldDataType::Geometry,
+                        nullable: true,
+                        metadata: None,
+                        vector_length: None,
+                        embedding: None,
+                    },
+                ],
+            },
+            options: Default::default(),
+        },
+    )
+    .await;
+    assert!(
+        created.ok,
+        "create_table should succeed: {:?}",
+        created.error
+    );
+    let table_id = created.data.expect("create table data").table_id;
+
+    let schema = services_v1::get_schema_v1(
+        &harness.state,
+        GetSchemaRequestV1 {
+            table_id: table_id.clone(),
+        },
+    )
+    .await
+    .data
+    .expect("schema");
+    let geometry_of = |name: &str| {
+        schema
+            .fields
+            .iter()
+            .find(|field| field.name == name)
+            .and_then(|field| field.geometry)
+    };
+    assert_eq!(geometry_of("id"), None);
+    assert_eq!(geometry_of("geometry"), Some(GeometryEncodingV1::Wkt));
+    assert_eq!(geometry_of("footprint"), Some(GeometryEncodingV1::Wkb));
+
+    let write = services_v1::write_rows_v1(
+        &harness.state,
+        WriteRowsRequestV1 {
+            table_id: table_id.clone(),
+            rows: vec![
+                serde_json::json!({"id": 1, "geometry": "POINT (13.4 52.5)"}),
+                serde_json::json!({"id": 2, "geometry": "LINESTRING (0 0, 1 1)"}),
+                serde_json::json!({"id": 3}),
+            ],
+            mode: WriteDataMode::Append,
+            normalize_vectors: false,
+            checkpoint_note: None,
+        },
+    )
+    .await;
+    assert!(write.ok, "write_rows should succeed: {:?}", write.error);
+
+    let export_dir = tempdir().expect("create export dir");
+    let export_path = export_dir.path().join("places.geojson");
+    let export_path = export_path.to_string_lossy().to_string();
+    let exported = services_v1::export_data_v1(
+        &harness.state,
+        ExportDataRequestV1 {
+            table_id: table_id.clone(),
+            path: export_path.clone(),
+            format: DataFileFormatV1::Geojson,
+            projection: None,
+            filter: Some("id < 3".to_string()),
+            limit: None,
+            offset: None,
+            delimiter: None,
+            with_header: None,
+            geometry_column: None,
+            job_id: None,
+        },
+    )
+    .await;
+    assert!(
+        exported.ok,
+        "geojson export should succeed: {:?}",
+        exported.error
+    );
+    assert_eq!(exported.data.expect("export data").rows, 2);
+
+    let collection: serde_json::Value =
+        serde_json::from_slice(&fs::read(&export_path).expect("read geojson")).expect("parse");
+    assert_eq!(collection["type"], "FeatureCollection");
+    let mut features = collection["features"].as_array().expect("features").clone();
+    features.sort_by_key(|feature| feature["properties"]["id"].as_i64());
+    assert_eq!(
+        features[0]["geometry"],
+        serde_json::json!({"type": "Point", "coordinates": [13.4, 52.5]})
+    );
+    assert_eq!(features[1]["geometry"]["type"], "LineString");
+    assert!(features[0]["properties"].get("geometry").is_none());
+
+    let missing_column = services_v1::export_data_v1(
+        &harness.state,
+        ExportDataRequestV1 {
+            table_id: harness.table_id.clone(),
+            path: export_path.clone(),
+            format: DataFileFormatV1::Geojson,
+            projection: None,
+            filter: None,
+            limit: None,
+            offset: None,
+            delimiter: None,
+            with_header: None,
+            geometry_column: None,
+            job_id: None,
+        },
+    )
+    .await;
+    assert_eq!(
+        missing_column.error.expect("error").code,
+        ErrorCode::InvalidArgument
+    );
+
+    let import = services_v1::import_data_v1(
+        &harness.state,
+        ImportDataRequestV1 {
+            table_id,
+            path: export_path,
+            format: DataFileFormatV1::Geojson,
+            mode: WriteDataMode::Append,
+            has_header: None,
+            delimiter: None,
+            expected_sha256: None,
+            lineage: false,
+            normalize_vectors: false,
+            job_id: None,
+            checkpoint_note: None,
+        },
+    )
+    .await;
+    assert_eq!(
+        import.error.expect("error").code,
+        ErrorCode::InvalidArgument
+    );
+}
+
+#[tokio::test]
+async fn table_descriptions_round_trip_and_list_with_tables() {
+    let harness = create_command_harness().await;
+    let table_id = harness.table_id.clone();
+
+    let saved = services_v1::set_table_description_v1(
+        &harness.state,
+        SetTableDescriptionRequestV1 {
+            table_id: table_id.clone(),
+            metadata: Some(TableDescriptionV1 {
+                description: Some("  Sample items with embeddings ".to_string()),
+                owner: Some("search-team".to_string()),
+                tags: BTreeMap::from([("tier".to_string(), "gold".to_string())]),
+                updated_at_ms: None,
+            }),
+        },
+    )
+    .await;
+    assert!(
+        saved.ok,
+        "set_table_description should succeed: {:?}",
+        saved.error
+    );
+    let saved = saved.data.expect("data").metadata.expect("metadata");
+    assert_eq!(
+        saved.description.as_deref(),
+        Some("Sample items with embeddings")
+    );
+    assert!(saved.updated_at_ms.is_some());
+
+    let fetched = services_v1::get_table_description_v1(
+        &harness.state,
+        GetTableDescriptionRequestV1 {
+            table_id: table_id.clone(),
+        },
+    )
+    .await;
+    assert_eq!(fetched.data.expect("data").metadata.as_ref(), Some(&saved));
+
+    let listed = services_v1::list_tables_v1(
+        &harness.state,
+        ListTablesRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            include_stats: false,
+        },
+    )
+    .await;
+    let tables = listed.data.expect("data").tables;
+    let items = tables
+        .iter()
+        .find(|table| table.name == harness.table_name)
+        .expect("items table");
+    assert_eq!(
+        items
+            .description
+            .as_ref()
+            .and_then(|meta| meta.owner.as_deref()),
+        Some("search-team")
+    );
+    assert_eq!(
+        items
+            .description
+            .as_ref()
+            .and_then(|meta| meta.tags.get("tier"))
+            .map(String::as_str),
+        Some("gold")
+    );
+
+    let cleared = services_v1::set_table_description_v1(
+        &harness.state,
+        SetTableDescriptionRequestV1 {
+            table_id: table_id.clone(),
+            metadata: None,
+        },
+    )
+    .await;
+    assert!(cleared.ok);
+    let fetched = services_v1::get_table_description_v1(
+        &harness.state,
+        GetTableDescriptionRequestV1 { table_id },
+    )
+    .await;
+    assert!(fetched.data.expect("data").metadata.is_none());
+}
+
+#[tokio::test]
+async fn create_table_applies_creation_options() {
+    let harness = create_command_harness().await;
+    let schema = SchemaDefinitionInput {
+        fields: vec![SchemaFieldInput {
+            name: "id".to_string(),
+            data_type: FieldDataType::Int32,
+            nullable: false,
+            metadata: None,
+            vector_length: None,
+            embedding: None,
+        }],
+    };
+
+    let created = services_v1::create_table_v1(
+        &harness.state,
+        CreateTableRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            table_name: "stable_rows".to_string(),
+            schema: schema.clone(),
+            options: CreateTableOptionsV1 {
+                enable_stable_row_ids: true,
+                data_storage_version: Some("2.1".to_string()),
+                enable_v2_manifest_paths: false,
+            },
+        },
+    )
+    .await;
+    assert!(
+        created.ok,
+        "create_table should succeed: {:?}",
+        created.error
+    );
+
+    let info = services_v1::table_format_info_v1(
+        &harness.state,
+        TableFormatInfoRequestV1 {
+            table_id: created.data.expect("created table").table_id,
+        },
+    )
+    .await
+    .data
+    .expect("format info");
+    assert!(info.stable_row_ids);
+    assert_eq!(info.storage_version, "2.1");
+
+    let invalid = services_v1::create_table_v1(
+        &harness.state,
+        CreateTableRequestV1 {
+            connection_id: harness.connection_id.clone(),
+            table_name: "bad_version".to_string(),
+            schema,
+            options: CreateTableOptionsV1 {
+                enable_stable_row_ids: false,
+                data_storage_version: Some("9.9".to_string()),
+                enable_v2_manifest_paths: false,
+            },
+        },
+    )
+    .await;
+    assert_eq!(
+        invalid.error.expect("error").code,
+        ErrorCode::InvalidArgument
+    );
+}
+
+#[tokio::test]
+async fn table_format_info_and_migration() {
+    let harness = create_command_harness().await;
+
+    let info = services_v1::table_format_info_v1(
+        &harness.state,
+        TableFormatInfoRequestV1 {
+            table_id: harness.table_id.clone(),
+        },
+    )
+    .await;
+    assert!(
+        info.ok,
+        "table_format_info should succeed: {:?}",
+        info.error
+    );
+    let info = info.data.expect("format info");
+    assert_eq!(info.file_format, "lance");
+    assert!(!info.storage_version.is_empty());
+
+    let migrated = services_v1::migrate_table_format_v1(
+        &harness.state,
+        MigrateTableFormatRequestV1 {
+            table_id: harness.table_id.clone(),
+            target_version: None,
+            target_table_name: None,
+            overwrite: false,
+            job_id: Some("migrate-job".to_string()),
+        },
+    )
+    .await;
+    if !info.upgrade_available {
+        assert_eq!(
+            migrated.error.expect("error").code,
+            ErrorCode::InvalidArgument
+        );
+        return;
+    }
+
+    assert!(
+        migrated.ok,
+        "migration should succeed: {:?}",
+        migrated.error
+    );
+    let migrated = migrated.data.expect("migration data");
+    assert_eq!(migrated.rows, 50);
+    assert_eq!(migrated.name, "items_v2_1");
+    assert_eq!(migrated.to_version, info.latest_storage_version);
+
+    let job = services_v1::get_job_status_v1(
+        &harness.state,
+        GetJobStatusRequestV1 {
+            job_id: migrated.job_id,
+        },
+    )
+    .await
+    .data
+    .expect("job status");
+    assert_eq!(job.kind, JobKindV1::MigrateFormat);
+    assert_eq!(job.state, JobStateV1::Succeeded);
+
+    let unsupported = services_v1::migrate_table_format_v1(
+        &harness.state,
+        MigrateTableFormatRequestV1 {
+            table_id: migrated.table_id,
+            target_version: Some("3.0".to_string()),
+            target_table_name: None,
+            overwrite: false,
+            job_id: None,
+        },
+    )
+    .await;
+    assert_eq!(
+        unsupported.error.expect("error").code,
+        ErrorCode::InvalidArgument
+    );
+}
+
+#[tokio::test]
+async fn open_dataset_opens_bare_lance_directory() {
+    let harness = create_command_harness().await;
+    let dataset_uri = Path::new(&harness._db.uri)
+        .join("items.lance")
+        .to_string_lossy()
+        .to_string();
+
+    let opened = services_v1::open_dataset_v1(
+        &harness.state,
+        OpenDatasetRequestV1 {
+            uri: format!("{dataset_uri}/"),
+            storage_options: Default::default(),
+            options: Default::default(),
+        },
+    )
+    .await;
+    assert!(opened.ok, "open_dataset should succeed: {:?}", opened.error);
+    let opened = opened.data.expect("dataset handle");
+    assert_eq!(opened.table.name, "items");
+    assert_eq!(opened.connection.uri, harness._db.uri);
+
+    let scan = services_v1::scan_v1(
+        &harness.state,
+        ScanRequestV1 {
+            table_id: opened.table.table_id.clone(),
+            format: DataFormat::Json,
+            projection: None,
+            projection_preset: None,
+            filter: None,
+            limit: Some(5),
+            offset: None,
+            apply_masking: false,
+            prefetch: false,
+            include_stats: false,
+            json_options: None,
+            as_of_version: None,
+            max_chunk_bytes: None,
+            job_id: None,
+        },
+    )
+    .await;
+    assert!(scan.ok, "scan should succeed: {:?}", scan.error);
+
+    for uri in [
+        harness._db.uri.clone(),
+        Path::new(&harness._db.uri)
+            .join("missing.lance")
+            .to_string_lossy()
+            .to_string(),
+    ] {
+        let rejected = services_v1::open_dataset_v1(
+            &harness.state,
+            OpenDatasetRequestV1 {
+                uri,
+                storage_options: Default::default(),
+                options: Default::default(),
+            },
+        )
+        .await;
+        assert_eq!(
+            rejected.error.expect("error").code,
+            ErrorCode::InvalidArgument
+        );
+    }
+}
+
+#[tokio::test]
+async fn config_controls_page_size_and_read_only_default() {
+    let harness = create_command_harness().await;
+    let config_dir = tempdir().expect("create config dir");
+    let config_path = config_dir.path().join("config.toml");
+    fs::write(
+        &config_path,
+        "default_page_size = 7\nread_only_default = true\n",
+    )
+    .expect("write config");
+    let state = AppState::new().with_config(config_path.clone());
+
+    let loaded = services_v1::get_config_v1(&state).await;
+    assert!(loaded.ok, "get_config should succeed: {:?}", loaded.error);
+    let loaded = loaded.data.expect("config data");
+    assert_eq!(loaded.config.default_page_size, 7);
+    assert!(loaded.config.read_only_default);
+    assert_eq!(
+        loaded.path.as_deref(),
+        Some(config_path.to_string_lossy().as_ref())
+    );
+
+    let connect = services_v1::connect_v1(
+        &state,
+        ConnectRequestV1 {
+            profile: ConnectProfile {
+                name: "sample".to_string(),
+                uri: harness._db.uri.clone(),
+                storage_options: Default::default(),
+                options: Default::default(),
+                auth: Default::default(),
+            },
+        },
+    )
+    .await;
+    let connect = connect.data.expect("connect data");
+    assert!(connect.read_only);
+    let opened = services_v1::open_table_v1(
+        &state,
+        OpenTableRequestV1 {
+            connection_id: connect.connection_id.clone(),
+            table_name: harness.table_name.clone(),
+        },
+    )
+    .await;
+    let table_id = opened.data.expect("table handle").table_id;
+
+    let scan = services_v1::scan_v1(
+        &state,
+        ScanRequestV1 {
+            table_id: table_id.clone(),
+            format: DataFormat::Json,
+            projection: None,
+            projection_preset: None,
+            filter: None,
+            limit: None,
+            offset: None,
+            apply_masking: false,
+            prefetch: false,
+            include_stats: false,
+            json_options: None,
+            as_of_version: None,
+            max_chunk_bytes: None,
+            job_id: None,
+        },
+    )
+    .await;
+    let scan = scan.data.expect("scan data");
+    assert_eq!(scan.next_offset, Some(7));
+
+    let write = services_v1::write_rows_v1(
+        &state,
+        WriteRowsRequestV1 {
+            table_id,
+            rows: vec![
+                serde_json::json!({ "id": 100, "text": "blocked", "vector": [0.0, 0.0, 0.0] }),
+            ],
+            mode: WriteDataMode::Append,
+            normalize_vectors: false,
+            checkpoint_note: None,
+        },
+    )
+    .await;
+    assert!(!write.ok);
+    assert_eq!(
+        write.error.expect("error").code,
+        ErrorCode::PermissionDenied
+    );
+
+    let mut config = loaded.config;
+    config.default_page_size = 0;
+    let invalid = services_v1::set_config_v1(
+        &state,
+        SetConfigRequestV1 {
+            config: config.clone(),
+        },
+    )
+    .await;
+    assert_eq!(
+        invalid.error.expect("error").code,
+        ErrorCode::InvalidArgument
+    );
+
+    config.default_page_size = 25;
+    config.query_timeout_ms = Some(30_000);
+    let updated = services_v1::set_config_v1(&state, SetConfigRequestV1 { config }).await;
+    assert!(updated.ok, "set_config should succeed: {:?}", updated.error);
+    let reloaded = AppState::new().with_config(config_path.clone());
+    let persisted = reloaded.config.lock().expect("lock config").get();
+    assert_eq!(persisted.default_page_size, 25);
+    assert_eq!(persisted.query_timeout_ms, Some(30_000));
+    let text = fs::read_to_string(&config_path).expect("read config");
+    assert!(text.contains("default_page_size = 25"));
+}
+
+#[tokio::test]
+async fn shutdown_cancels_jobs_and_closes_connections() {
+    let harness = create_command_harness().await;
+    let job_id = harness
+        .state
+        .jobs
+        .lock()
+        .expect("jobs")
+        .start(Some("shutdown-job".to_string()), JobKindV1::Backup)
+        .expect("start job");
+
+    let report = shutdown::shutdown(&harness.state, Duration::from_millis(20)).await;
+    assert_eq!(report.cancelled_jobs, 1);
+    assert_eq!(report.abandoned_jobs, 1);
+    assert_eq!(report.closed_connections, 1);
+    assert_eq!(report.closed_tables, 1);
+    assert!(!shutdown::begin(&harness.state));
+
+    {
+        let mut jobs = harness.state.jobs.lock().expect("jobs");
+        jobs.finish(&job_id, Ok(()));
+        let job = jobs.get(&job_id).expect("job");
+        assert_eq!(job.state, JobStateV1::Cancelled);
+        assert_eq!(job.message.as_deref(), Some(shutdown::SHUTDOWN_MESSAGE));
+        assert_eq!(jobs.active_count(), 0);
+    }
+
+    let scan = services_v1::scan_v1(
+        &harness.state,
+        ScanRequestV1 {
+            table_id: harness.table_id.clone(),
+            format: DataFormat::Json,
+            projection: None,
+            projection_preset: None,
+            filter: None,
+            limit: Some(1),
+            offset: None,
+            apply_masking: false,
+            prefetch: false,
+            include_stats: false,
+            json_options: None,
+            as_of_version: None,
+            max_chunk_bytes: None,
+            job_id: None,
+        },
+    )
+    .await;
+    assert!(!scan.ok);
+    assert_eq!(scan.error.expect("error").code, ErrorCode::NotFound);
+}
+
+#[tokio::test]
+async fn validates_error_conditions() {
+    let harness = create_command_harness().await;
+
+    let invalid_filter = services_v1::query_filter_v1(
+        &harness.state,
+        QueryFilterRequestV1 {
+            table_id: harness.table_id.clone(),
+            filter: " ".to_string(),
+            projection: None,
+            projection_preset: None,
+            limit: None,
+            offset: None,
+            include_stats: false,
+            json_options: None,
+        },
+    )
+    .await;
+
+    assert!(!invalid_filter.ok);
+    assert_eq!(
+        invalid_filter.error.expect("error").code,
+        ErrorCode::InvalidArgument
+    );
+
+    let empty_vector = services_v1::vector_search_v1(
+        &harness.state,
+        VectorSearchRequestV1 {
+            table_id: harness.table_id.clone(),
+            vector: vec![],
+            column: Some("vector".to_string()),
+            top_k: None,
+            projection: None,
+            projection_preset: None,
+            filter: None,
+            nprobes: None,
+            refine_factor: None,
+            offset: None,
+            include_stats: false,
+            json_options: None,
+            distance_type: None,
+            as_of_version: None,
+            query_embedding: None,
+            normalize_query: false,
         },
     )
     .await;
@@ -920,9 +7646,16 @@ async fn validates_error_conditions() {
             limit: None,
             offset: None,
             projection: None,
+            projection_preset: None,
             filter: None,
             nprobes: None,
             refine_factor: None,
+            include_stats: false,
+            json_options: None,
+            reranker: None,
+            as_of_version: None,
+            query_embedding: None,
+            normalize_query: false,
         },
     )
     .await;
@@ -944,9 +7677,16 @@ async fn validates_error_conditions() {
             limit: None,
             offset: None,
             projection: None,
+            projection_preset: None,
             filter: None,
             nprobes: None,
             refine_factor: None,
+            include_stats: false,
+            json_options: None,
+            reranker: None,
+            as_of_version: None,
+            query_embedding: None,
+            normalize_query: false,
         },
     )
     .await;
@@ -3,6 +3,8 @@
  * compatible with the LanceDB scan API.
  */
 
+import type { DataTypeDescriptorV1 } from "../../ipc/v1"
+
 const NUMERIC_TYPES = new Set([
 	"int8",
 	"int16",
@@ -37,6 +39,24 @@ export function isBinaryType(dataType?: string): boolean {
 	return lower === "binary" || lower === "largebinary" || lower === "large_binary"
 }
 
+const NESTED_KINDS = new Set([
+	"list",
+	"large_list",
+	"list_view",
+	"large_list_view",
+	"fixed_size_list",
+	"struct",
+	"map",
+	"union",
+])
+
+const BINARY_KINDS = new Set(["binary", "large_binary", "binary_view", "fixed_size_binary"])
+
+/** Check if a structured type descriptor can be edited inline. */
+export function isEditableDescriptor(descriptor: DataTypeDescriptorV1): boolean {
+	return !NESTED_KINDS.has(descriptor.kind) && !BINARY_KINDS.has(descriptor.kind)
+}
+
 /** Check if a column is editable based on its data type. */
 export function isEditableType(dataType?: string): boolean {
 	if (!dataType) return false
@@ -1,6 +1,6 @@
 import { computed, type Ref } from "vue"
 import type { SchemaDefinition } from "../../ipc/v1"
-import { isEditableType } from "./filterParser"
+import { isEditableDescriptor, isEditableType } from "./filterParser"
 import type { DataGridColumn } from "./types"
 
 /**
@@ -14,7 +14,9 @@ export function useDataGridColumns(schema: Ref<SchemaDefinition | null>) {
 			label: field.name,
 			dataType: field.dataType,
 			nullable: field.nullable,
-			editable: isEditableType(field.dataType),
+			editable: field.typeDescriptor
+				? isEditableDescriptor(field.typeDescriptor)
+				: isEditableType(field.dataType),
 		}))
 	})
 
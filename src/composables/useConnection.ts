@@ -2,10 +2,10 @@ import type { Ref } from "vue"
 import { computed, ref, shallowRef, watch } from "vue"
 
 import type { SchemaDefinition, TableInfo } from "../ipc/v1"
-import { resolveAuthDescriptor } from "../lib/authDescriptor"
 import {
 	connectV1,
 	disconnectV1,
+	getRowCountV1,
 	getSchemaV1,
 	listTablesV1,
 	openTableV1,
@@ -131,8 +131,8 @@ export function useConnection(
 			state.isConnecting.value = true
 			resetConnection(profileId)
 			const connectProfile = toConnectProfile(profile)
+			// `secret_ref` credentials are read from the vault by the backend.
 			connectProfile.auth ??= { type: "none" }
-			connectProfile.auth = await resolveAuthDescriptor(connectProfile.auth)
 			const response = unwrapEnvelope(await connectV1(connectProfile))
 			state.connectionId.value = response.connectionId
 			try {
@@ -196,6 +196,11 @@ export function useConnection(
 			state.isRefreshing.value = true
 			const response = unwrapEnvelope(await listTablesV1(id))
 			state.tables.value = response.tables
+			// Sizes need every table opened, so they follow the plain listing.
+			const withStats = await listTablesV1(id, true)
+			if (withStats.ok && withStats.data && state.connectionId.value === id) {
+				state.tables.value = withStats.data.tables
+			}
 		} catch (error) {
 			const message = error instanceof Error ? error.message : "拉取表列表失败"
 			options.onError?.(message)
@@ -222,6 +227,7 @@ export function useConnection(
 			const handle = unwrapEnvelope(await openTableV1(id, name))
 			state.activeTableId.value = handle.tableId
 			state.schema.value = unwrapEnvelope(await getSchemaV1(handle.tableId))
+			void refreshRowCount(state, name, handle.tableId)
 		} catch (error) {
 			const message = error instanceof Error ? error.message : "打开表失败"
 			options.onError?.(message)
@@ -230,6 +236,18 @@ export function useConnection(
 		}
 	}
 
+	/** Updates the listed size of a table; the backend only counts after a version change. */
+	async function refreshRowCount(state: ConnectionState, name: string, tableId: string) {
+		const response = await getRowCountV1(tableId).catch(() => null)
+		if (!response?.ok || !response.data) {
+			return
+		}
+		const { rowCount } = response.data
+		state.tables.value = state.tables.value.map((table) =>
+			table.name === name ? { ...table, rowCount, rowCountCached: false } : table
+		)
+	}
+
 	async function refreshSchema(profileId: string) {
 		const state = getState(profileId)
 		const tableId = state.activeTableId.value
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AccessTransportV1 } from "./AccessTransportV1";
+import type { ErrorCode } from "./ErrorCode";
+
+/**
+ * One call made by a remote client. Calls from the app window are not logged.
+ */
+export type AccessLogEntryV1 = { atMs: number, transport: AccessTransportV1, 
+/**
+ * Peer address or client name as the transport reports it.
+ */
+client: string, command: string, table?: string, ok: boolean, errorCode?: ErrorCode, elapsedMs: number, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How a remote client reached the service layer. Share servers are the only
+ * remote front end so far.
+ */
+export type AccessTransportV1 = "http";
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type AckScanStreamRequestV1 = { streamId: string, 
+/**
+ * Every chunk up to and including this one has been handled.
+ */
+sequence: number, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A scan, filter query or search that is still executing.
+ */
+export type ActiveQueryV1 = { queryId: string, 
+/**
+ * Command running the query, e.g. `scan_v1`.
+ */
+command: string, tableId: string, 
+/**
+ * What the query runs, such as `filter where id > 3`, cut to 200 characters.
+ */
+summary: string, startedAtMs: number, elapsedMs: number, 
+/**
+ * Set once the query was killed; it leaves the list when its command returns.
+ */
+killed: boolean, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SchemaDefinitionInput } from "./SchemaDefinitionInput";
+
+export type AddColumnsRequestV1 = { tableId: string, columns: SchemaDefinitionInput, 
+/**
+ * Appended to the automatic checkpoint tag of this change; only ASCII letters
+ * and digits are kept.
+ */
+checkpointNote?: string, };
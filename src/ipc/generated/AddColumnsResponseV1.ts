@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SchemaDefinition } from "./SchemaDefinition";
+
+export type AddColumnsResponseV1 = { tableId: string, added: Array<string>, schema: SchemaDefinition, };
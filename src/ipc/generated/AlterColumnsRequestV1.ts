@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ColumnAlterationInput } from "./ColumnAlterationInput";
+
+export type AlterColumnsRequestV1 = { tableId: string, columns: Array<ColumnAlterationInput>, 
+/**
+ * Drops and recreates the indexes on renamed or retyped columns once the
+ * alteration is committed. Otherwise they are only reported.
+ */
+rebuildIndexes?: boolean, 
+/**
+ * Appended to the automatic checkpoint tag of this change; only ASCII letters
+ * and digits are kept.
+ */
+checkpointNote?: string, };
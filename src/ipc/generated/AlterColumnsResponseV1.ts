@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IndexRebuildV1 } from "./IndexRebuildV1";
+import type { SchemaDefinition } from "./SchemaDefinition";
+import type { UpdatedArtifactV1 } from "./UpdatedArtifactV1";
+
+export type AlterColumnsResponseV1 = { tableId: string, updated: Array<string>, schema: SchemaDefinition, 
+/**
+ * Indexes affected by the alteration.
+ */
+indexRebuilds: Array<IndexRebuildV1>, 
+/**
+ * The `index_build` job that recreated them, when `rebuildIndexes` was set.
+ */
+rebuildJobId?: string, 
+/**
+ * Saved settings rewritten to use the new names of renamed columns.
+ */
+updatedArtifacts: Array<UpdatedArtifactV1>, };
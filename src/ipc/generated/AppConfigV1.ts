@@ -0,0 +1,34 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LocaleV1 } from "./LocaleV1";
+import type { LogLevelV1 } from "./LogLevelV1";
+import type { RoleV1 } from "./RoleV1";
+
+export type AppConfigV1 = { 
+/**
+ * Row limit used by scans and filter queries that omit `limit`.
+ */
+defaultPageSize: number, queryTimeoutMs?: number, connectTimeoutMs?: number, prefetchCachePages: number, prefetchTtlSeconds: number, 
+/**
+ * Opens connections read-only unless the profile says otherwise.
+ */
+readOnlyDefault: boolean, 
+/**
+ * Startup log level when `set_log_level_v1` has not saved one.
+ */
+logLevel?: LogLevelV1, 
+/**
+ * Receives a JSON POST with the job id, status and output path whenever a
+ * backup, restore, export, index build or other job finishes.
+ */
+jobWebhookUrl?: string, role: RoleV1, 
+/**
+ * Tags the version every mutation produces with its operation and time, so the
+ * versions panel reads like a change history.
+ */
+autoCheckpoints: boolean, 
+/**
+ * Language of the messages commands return. Every command also takes an
+ * optional `locale` argument next to `request`, as does the request of
+ * `optimize_table_v1`; both win over this one.
+ */
+locale: LocaleV1, };
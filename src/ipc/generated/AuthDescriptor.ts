@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type AuthDescriptor = { "type": "none" } | { "type": "inline", provider: string, params: { [key in string]: string }, } | { "type": "secret_ref", provider: string, reference: string, };
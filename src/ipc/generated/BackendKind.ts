@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Storage behind a connection URI, inferred from its scheme.
+ */
+export type BackendKind = "local" | "s3" | "gcs" | "azure" | "remote" | "unknown";
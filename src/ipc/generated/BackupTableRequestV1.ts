@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BackupModeV1 } from "./BackupModeV1";
+
+export type BackupTableRequestV1 = { tableId: string, destinationUri: string, backupTableName?: string, mode?: BackupModeV1, storageOptions?: { [key in string]: string }, jobId?: string, };
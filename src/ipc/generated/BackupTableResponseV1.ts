@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BackupModeV1 } from "./BackupModeV1";
+
+export type BackupTableResponseV1 = { jobId: string, destinationUri: string, backupTableName: string, mode: BackupModeV1, sourceVersion: number | string, rows: number | string, versions: number, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IndexTypeV1 } from "./IndexTypeV1";
+
+export type BundleIndexV1 = { name: string, indexType: IndexTypeV1, columns: Array<string>, };
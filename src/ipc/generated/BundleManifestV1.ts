@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BundleIndexV1 } from "./BundleIndexV1";
+import type { BundleShardV1 } from "./BundleShardV1";
+
+export type BundleManifestV1 = { formatVersion: number, tableName: string, sourceVersion: number | string, createdAtMs: number, rows: number, shards: Array<BundleShardV1>, indexes: Array<BundleIndexV1>, filter?: string, projection?: Array<string>, metadata: { [key in string]: string }, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Stops a running job at its next progress checkpoint.
+ */
+export type CancelJobRequestV1 = { jobId: string, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ValueCategoryV1 } from "./ValueCategoryV1";
+
+export type CategoryOperatorsV1 = { category: ValueCategoryV1, operators: Array<string>, };
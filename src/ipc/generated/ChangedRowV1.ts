@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A row whose key matched in both versions with different values.
+ */
+export type ChangedRowV1 = { key: Record<string, unknown>, before: Record<string, unknown>, after: Record<string, unknown>, 
+/**
+ * Columns whose values differ.
+ */
+columns: Array<string>, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DataFileFormatV1 } from "./DataFileFormatV1";
+
+/**
+ * Compares an existing Parquet or CSV file with the columns an export of the table
+ * would write, before appending to it.
+ */
+export type CheckExportTargetRequestV1 = { tableId: string, path: string, format: DataFileFormatV1, projection?: Array<string>, delimiter?: string, };
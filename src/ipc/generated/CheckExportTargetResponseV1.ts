@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SchemaCompatibilityReportV1 } from "./SchemaCompatibilityReportV1";
+
+export type CheckExportTargetResponseV1 = { path: string, exists: boolean, 
+/**
+ * True when the file is missing or has the columns the export writes.
+ */
+compatible: boolean, 
+/**
+ * Differences between the export (the "table" side) and the existing file.
+ */
+report?: SchemaCompatibilityReportV1, };
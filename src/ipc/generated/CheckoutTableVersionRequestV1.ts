@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Checks out `version`, or the version `tag` points to. Exactly one is set.
+ */
+export type CheckoutTableVersionRequestV1 = { tableId: string, version?: number | string | null, tag?: string, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How a page was split to stay under `maxChunkBytes`. The rest of the page is read
+ * from `nextOffset` with `limit` set to `remainingRows`.
+ */
+export type ChunkSplitV1 = { 
+/**
+ * Rows in this chunk.
+ */
+rows: number, 
+/**
+ * Rows of the requested page left for the following chunks.
+ */
+remainingRows: number, 
+/**
+ * Size of this chunk's IPC payload.
+ */
+bytes: number, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CloneTableRequestV1 = { connectionId: string, tableId: string, targetTableName: string, sourceVersion?: number | string | null, sourceTag?: string, isShallow?: boolean, 
+/**
+ * Tracks the clone as a job, so its progress events carry this id.
+ */
+jobId?: string, };
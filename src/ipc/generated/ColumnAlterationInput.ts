@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FieldDataType } from "./FieldDataType";
+
+export type ColumnAlterationInput = { path: string, rename?: string, nullable?: boolean, dataType?: FieldDataType, vectorLength?: number, };
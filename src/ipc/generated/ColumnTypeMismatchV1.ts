@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ColumnTypeMismatchV1 = { column: string, tableType: string, fileType: string, 
+/**
+ * Arrow type to cast the file column to, when Arrow can cast between the two.
+ */
+suggestedCast?: string, };
@@ -0,0 +1,28 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EmbeddingModelV1 } from "./EmbeddingModelV1";
+import type { JsonOptionsV1 } from "./JsonOptionsV1";
+import type { RerankerV1 } from "./RerankerV1";
+
+export type CombinedSearchRequestV1 = { tableId: string, vector?: Array<number>, vectorColumn?: string, query?: string, columns?: Array<string>, projection?: Array<string>, 
+/**
+ * Name of a saved projection preset of the table, used instead of `projection`.
+ */
+projectionPreset?: string, filter?: string, limit?: number, offset?: number, nprobes?: number, refineFactor?: number, 
+/**
+ * Defaults to reciprocal rank fusion.
+ */
+reranker?: RerankerV1, 
+/**
+ * Searches this table version instead of the latest one.
+ */
+asOfVersion?: number | string | null, includeStats?: boolean, jsonOptions?: JsonOptionsV1, 
+/**
+ * Model that embedded the query vector; a mismatch with the column's model is
+ * reported as a warning.
+ */
+queryEmbedding?: EmbeddingModelV1, 
+/**
+ * L2-normalizes the query vector before searching. Without it, a query that is
+ * not unit length against a normalized column is reported as a warning.
+ */
+normalizeQuery?: boolean, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Most batch memory one command held at once. Only query paths that report their
+ * batches are counted, so this is a lower bound of what the process used.
+ */
+export type CommandMemoryV1 = { command: string, calls: number, lastPeakBytes: number, maxPeakBytes: number, };
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MaterializeSourceV1 } from "./MaterializeSourceV1";
+
+/**
+ * Runs `query` against two versions of a table and diffs the results. The table id
+ * and version pin inside `query` are ignored. Rows are matched by `keyColumns`;
+ * without keys, whole rows are compared and nothing is reported as changed.
+ */
+export type CompareQueryVersionsRequestV1 = { tableId: string, query: MaterializeSourceV1, versionA: number | string, versionB: number | string, keyColumns?: Array<string>, 
+/**
+ * Most rows listed per kind of difference; counts always cover every row.
+ */
+maxRows?: number, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChangedRowV1 } from "./ChangedRowV1";
+
+export type CompareQueryVersionsResponseV1 = { versionA: number | string, versionB: number | string, rowsA: number, rowsB: number, 
+/**
+ * Rows only `versionB` returns.
+ */
+added: Record<string, unknown>[], 
+/**
+ * Rows only `versionA` returns.
+ */
+removed: Record<string, unknown>[], changed: Array<ChangedRowV1>, addedCount: number, removedCount: number, changedCount: number, unchangedCount: number, 
+/**
+ * Set when `maxRows` left rows out of a list.
+ */
+truncated: boolean, };
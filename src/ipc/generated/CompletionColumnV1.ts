@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ValueCategoryV1 } from "./ValueCategoryV1";
+
+export type CompletionColumnV1 = { 
+/**
+ * Column name, or a dotted path for struct fields.
+ */
+name: string, 
+/**
+ * `name` quoted where a filter needs it, e.g. `` meta.`source file` ``.
+ */
+insertText: string, dataType: string, category: ValueCategoryV1, nullable: boolean, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CategoryOperatorsV1 } from "./CategoryOperatorsV1";
+import type { CompletionColumnV1 } from "./CompletionColumnV1";
+import type { SqlFunctionV1 } from "./SqlFunctionV1";
+
+/**
+ * What a filter or SQL editor needs to suggest completions for a table.
+ */
+export type CompletionMetadataResponseV1 = { tableId: string, columns: Array<CompletionColumnV1>, operators: Array<CategoryOperatorsV1>, functions: Array<SqlFunctionV1>, keywords: Array<string>, };
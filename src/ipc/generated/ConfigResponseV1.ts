@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AppConfigV1 } from "./AppConfigV1";
+
+export type ConfigResponseV1 = { config: AppConfigV1, path?: string, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ObjectStoreTuningV1 } from "./ObjectStoreTuningV1";
+
+export type ConnectOptions = { readConsistencyIntervalSeconds?: number, 
+/**
+ * Rejects writes through this connection. Defaults to `readOnlyDefault` from the
+ * app config.
+ */
+readOnly?: boolean, objectStore?: ObjectStoreTuningV1, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AuthDescriptor } from "./AuthDescriptor";
+import type { ConnectOptions } from "./ConnectOptions";
+
+export type ConnectProfile = { name: string, uri: string, storageOptions?: { [key in string]: string }, options?: ConnectOptions, auth?: AuthDescriptor, };
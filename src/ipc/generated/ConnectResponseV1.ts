@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BackendKind } from "./BackendKind";
+
+export type ConnectResponseV1 = { connectionId: string, backendKind: BackendKind, name: string, uri: string, readOnly: boolean, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ConnectionHealthV1 } from "./ConnectionHealthV1";
+
+export type ConnectionOverviewV1 = { connectionId: string, uri: string, readOnly: boolean, health: ConnectionHealthV1, 
+/**
+ * Table handles the app holds open on this connection.
+ */
+openTables: number, tableCount?: number, totalRows?: number | string | null, totalSizeBytes?: number, 
+/**
+ * Tables whose stats could not be read.
+ */
+failedTables: Array<string>, elapsedMs: number, error?: string, };
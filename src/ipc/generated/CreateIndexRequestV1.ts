@@ -0,0 +1,35 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DistanceTypeV1 } from "./DistanceTypeV1";
+import type { FtsTokenizerOptionsV1 } from "./FtsTokenizerOptionsV1";
+import type { IndexAcceleratorV1 } from "./IndexAcceleratorV1";
+import type { IndexPresetV1 } from "./IndexPresetV1";
+import type { IndexTypeV1 } from "./IndexTypeV1";
+
+export type CreateIndexRequestV1 = { tableId: string, columns: Array<string>, indexType: IndexTypeV1, name?: string, replace?: boolean, 
+/**
+ * Also read from `metric`. The alias is accepted on input only; the bindings
+ * and responses use `distanceType`.
+ */
+distanceType?: DistanceTypeV1, numPartitions?: number, sampleRate?: number, maxIterations?: number, targetPartitionSize?: number, numSubVectors?: number, numBits?: number, 
+/**
+ * HNSW graph degree, called `m` in the HNSW paper. Also read from `m` on input
+ * only; the bindings use `numEdges`.
+ */
+numEdges?: number, efConstruction?: number, 
+/**
+ * Hardware that trains vector indexes; ignored by scalar and FTS indexes.
+ */
+accelerator?: IndexAcceleratorV1, 
+/**
+ * Fills the IVF parameters left unset from the table's row count and vector
+ * dimension.
+ */
+preset?: IndexPresetV1, 
+/**
+ * Tokenizer settings of an FTS index; rejected for other index types.
+ */
+ftsTokenizer?: FtsTokenizerOptionsV1, 
+/**
+ * Job id for the build, so `cancel_job_v1` can stop it.
+ */
+jobId?: string, };
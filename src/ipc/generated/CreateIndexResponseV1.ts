@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IndexAcceleratorV1 } from "./IndexAcceleratorV1";
+import type { IndexPresetParametersV1 } from "./IndexPresetParametersV1";
+import type { IndexTypeV1 } from "./IndexTypeV1";
+
+export type CreateIndexResponseV1 = { tableId: string, indexType: IndexTypeV1, columns: Array<string>, name?: string, 
+/**
+ * Accelerator that trained the index; absent for scalar and FTS indexes.
+ */
+accelerator?: IndexAcceleratorV1, presetParameters?: IndexPresetParametersV1, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Dataset settings fixed at creation time.
+ */
+export type CreateTableOptionsV1 = { 
+/**
+ * Keeps `_rowid` values stable across compaction and updates.
+ */
+enableStableRowIds?: boolean, 
+/**
+ * Lance data storage version such as `2.1`; defaults to the library default.
+ */
+dataStorageVersion?: string, enableV2ManifestPaths?: boolean, };
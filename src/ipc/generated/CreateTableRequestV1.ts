@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CreateTableOptionsV1 } from "./CreateTableOptionsV1";
+import type { SchemaDefinitionInput } from "./SchemaDefinitionInput";
+
+export type CreateTableRequestV1 = { connectionId: string, tableName: string, schema: SchemaDefinitionInput, options?: CreateTableOptionsV1, };
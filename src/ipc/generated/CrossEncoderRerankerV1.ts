@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A cross-encoder served over HTTP. The endpoint receives
+ * `{"query": ..., "documents": [...]}` and answers `{"scores": [...]}`, one score
+ * per document.
+ */
+export type CrossEncoderRerankerV1 = { endpoint: string, 
+/**
+ * Text column sent as the documents.
+ */
+column: string, timeoutMs?: number, };
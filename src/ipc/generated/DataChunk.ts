@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ArrowChunk } from "./ArrowChunk";
+import type { JsonChunk } from "./JsonChunk";
+
+export type DataChunk = { "format": "json" } & JsonChunk | { "format": "arrow" } & ArrowChunk;
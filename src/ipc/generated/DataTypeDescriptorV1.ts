@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SchemaField } from "./SchemaField";
+
+/**
+ * Structured Arrow data type. `kind` is the snake_case type name (`int32`,
+ * `fixed_size_list`, `timestamp`, `struct`, ...); `params` carries type parameters
+ * such as `listSize`, `unit`, `timezone`, `precision` or `scale`; `children` holds
+ * the child fields of nested types.
+ */
+export type DataTypeDescriptorV1 = { kind: string, params?: Record<string, unknown>, children?: Array<SchemaField>, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DatasetDirectoryKindV1 } from "./DatasetDirectoryKindV1";
+
+/**
+ * A directory of the lance dataset layout.
+ */
+export type DatasetDirectoryV1 = { kind: DatasetDirectoryKindV1, uri: string, 
+/**
+ * Filesystem path on local backends.
+ */
+localPath?: string, 
+/**
+ * Whether the directory exists; only checked on local backends.
+ */
+exists?: boolean, };
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type DeleteRowsRequestV1 = { tableId: string, filter: string, allowFullTable?: boolean, 
+/**
+ * Deletes at most this many rows per commit and tracks the delete as a job
+ * that can be cancelled between chunks.
+ */
+chunkSize?: number, 
+/**
+ * Job id for a chunked delete, so progress can be polled while it runs.
+ */
+jobId?: string, 
+/**
+ * Appended to the automatic checkpoint tag of this change; only ASCII letters
+ * and digits are kept.
+ */
+checkpointNote?: string, };
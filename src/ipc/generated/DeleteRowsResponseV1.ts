@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type DeleteRowsResponseV1 = { tableId: string, version: number | string, 
+/**
+ * Set for chunked deletes.
+ */
+jobId?: string, 
+/**
+ * Rows removed by a chunked delete.
+ */
+rowsDeleted?: number | string | null, 
+/**
+ * True when a chunked delete was cancelled; chunks committed before that
+ * stay deleted.
+ */
+cancelled: boolean, };
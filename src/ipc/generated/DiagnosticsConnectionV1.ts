@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * An open connection with credentials left out: the URI loses its user info and
+ * query, and only the names of storage options are kept.
+ */
+export type DiagnosticsConnectionV1 = { connectionId: string, uri: string, readOnly: boolean, openTables: number, storageOptionKeys: Array<string>, };
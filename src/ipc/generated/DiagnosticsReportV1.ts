@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CommandMemoryV1 } from "./CommandMemoryV1";
+import type { CommandMetricsV1 } from "./CommandMetricsV1";
+import type { DiagnosticsConnectionV1 } from "./DiagnosticsConnectionV1";
+import type { JobStatusV1 } from "./JobStatusV1";
+import type { LogSettingsV1 } from "./LogSettingsV1";
+
+/**
+ * Everything `export_diagnostics_v1` writes, as one JSON document.
+ */
+export type DiagnosticsReportV1 = { createdAtMs: number, viewerVersion: string, tauriVersion: string, os: string, arch: string, uptimeMs: number, residentMemoryBytes?: number, logSettings?: LogSettingsV1, commands: Array<CommandMetricsV1>, commandMemory: Array<CommandMemoryV1>, connections: Array<DiagnosticsConnectionV1>, runningJobs: Array<JobStatusV1>, 
+/**
+ * Last lines of the app log, oldest first.
+ */
+logs: Array<string>, };
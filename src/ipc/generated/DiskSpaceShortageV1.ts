@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * `details` of the `internal` error an import, export or compaction returns when
+ * the local volume it writes to is too full to finish.
+ */
+export type DiskSpaceShortageV1 = { 
+/**
+ * The dataset directory or file being written.
+ */
+path: string, 
+/**
+ * Estimated bytes the write needs, headroom included.
+ */
+neededBytes: number, availableBytes: number, };
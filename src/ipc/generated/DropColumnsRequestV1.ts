@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type DropColumnsRequestV1 = { tableId: string, columns: Array<string>, 
+/**
+ * Appended to the automatic checkpoint tag of this change; only ASCII letters
+ * and digits are kept.
+ */
+checkpointNote?: string, };
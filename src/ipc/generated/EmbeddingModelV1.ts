@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Embedding model behind a vector column, kept in the column metadata so searches
+ * can tell whether a query vector comes from the same model.
+ */
+export type EmbeddingModelV1 = { model: string, version?: string, dimension?: number, };
@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Pricing and throughput of an embedding provider. The viewer ships no price
+ * list, so the caller supplies what its provider charges.
+ */
+export type EmbeddingProviderV1 = { name: string, model: string, 
+/**
+ * Price of one million input tokens, in the provider's billing currency.
+ */
+pricePerMillionTokens: number, 
+/**
+ * Rate limit used for the time estimate; without it no time is estimated.
+ */
+tokensPerMinute?: number, 
+/**
+ * Inputs longer than this are truncated by the provider and billed at the
+ * limit.
+ */
+maxTokensPerInput?: number, };
@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A string column encrypted client-side with AES-256-GCM under the key `key_id`.
+ */
+export type EncryptedColumnV1 = { 
+/**
+ * Applies to every table of the connection when omitted.
+ */
+tableName?: string, column: string, 
+/**
+ * Reference of the key in the credential vault.
+ */
+keyId: string, 
+/**
+ * Authenticated with every value in place of `column`, which it defaults to.
+ * Renaming the column sets it to the old name, so existing ciphertext still
+ * decrypts.
+ */
+columnId?: string, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ErrorCode } from "./ErrorCode";
+import type { MessageCodeV1 } from "./MessageCodeV1";
+
+export type ErrorEnvelope = { code: ErrorCode, message: string, messageCode?: MessageCodeV1, params?: { [key in string]: string }, details?: unknown, };
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EmbeddingProviderV1 } from "./EmbeddingProviderV1";
+
+/**
+ * Estimates what embedding every value of `text_column` would cost before a paid
+ * run is started. Tokens are approximated from the text, not counted by the
+ * provider's tokenizer.
+ */
+export type EstimateEmbeddingJobRequestV1 = { tableId: string, textColumn: string, provider: EmbeddingProviderV1, 
+/**
+ * Limits the estimate to the rows a filtered run would embed.
+ */
+filter?: string, };
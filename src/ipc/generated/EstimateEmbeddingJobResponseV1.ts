@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type EstimateEmbeddingJobResponseV1 = { tableId: string, textColumn: string, provider: string, model: string, 
+/**
+ * Rows with text to embed.
+ */
+rowCount: number | string, 
+/**
+ * Rows with a null or blank value, which are not sent to the provider.
+ */
+skippedRows: number | string, totalChars: number, estimatedTokens: number, 
+/**
+ * Rows billed at `max_tokens_per_input` because their text is longer.
+ */
+truncatedRows: number | string, estimatedCost: number, estimatedSeconds?: number, };
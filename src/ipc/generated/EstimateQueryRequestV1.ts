@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A scan or filter query to estimate without running it.
+ */
+export type EstimateQueryRequestV1 = { tableId: string, filter?: string, projection?: Array<string>, limit?: number, offset?: number, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Upper bounds for what a query would read, from the table statistics and the
+ * planned scan. Bytes are split across columns by their value width.
+ */
+export type EstimateQueryResponseV1 = { tableId: string, totalRows: number | string, totalFragments: number, totalBytes: number, estimatedRowsScanned: number | string, estimatedFragments: number, estimatedBytesRead: number, 
+/**
+ * Columns the planned scan loads.
+ */
+columnsRead: Array<string>, indexUsed: boolean, 
+/**
+ * Every row is read and no index narrows the scan.
+ */
+fullScan: boolean, plan: string, };
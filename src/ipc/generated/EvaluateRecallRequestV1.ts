@@ -0,0 +1,25 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Measures how many exact nearest neighbours the vector index of `column` finds.
+ * Vectors of sampled rows serve as queries; each is searched once without the
+ * index for the ground truth and once per `nprobes` setting.
+ */
+export type EvaluateRecallRequestV1 = { tableId: string, column: string, 
+/**
+ * Number of rows, spread evenly over the table, used as queries; defaults to 20.
+ */
+sampleQueries?: number, 
+/**
+ * Neighbours compared per query; defaults to 10.
+ */
+k?: number, 
+/**
+ * Settings to compare. Defaults to powers of two up to the estimated partition
+ * count.
+ */
+nprobes?: Array<number>, 
+/**
+ * Applied to every setting; defaults as in `vector_search_v1`.
+ */
+refineFactor?: number, };
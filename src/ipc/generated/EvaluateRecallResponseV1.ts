@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DistanceTypeV1 } from "./DistanceTypeV1";
+import type { IndexTypeV1 } from "./IndexTypeV1";
+import type { RecallAtNprobesV1 } from "./RecallAtNprobesV1";
+
+export type EvaluateRecallResponseV1 = { tableId: string, column: string, indexName: string, indexType: IndexTypeV1, distanceType?: DistanceTypeV1, estimatedPartitions?: number, refineFactor?: number, k: number, 
+/**
+ * Queries evaluated; rows with a null vector are skipped.
+ */
+sampleSize: number, 
+/**
+ * Mean latency of the exact searches that gave the ground truth.
+ */
+flatMeanLatencyMs: number, results: Array<RecallAtNprobesV1>, };
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ExportBundleRequestV1 = { tableId: string, path: string, projection?: Array<string>, filter?: string, rowsPerShard?: number, metadata?: { [key in string]: string }, overwrite?: boolean, jobId?: string, };
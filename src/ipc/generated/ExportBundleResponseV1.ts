@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BundleManifestV1 } from "./BundleManifestV1";
+
+export type ExportBundleResponseV1 = { path: string, manifest: BundleManifestV1, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DataFileFormatV1 } from "./DataFileFormatV1";
+
+export type ExportDataRequestV1 = { tableId: string, path: string, format: DataFileFormatV1, projection?: Array<string>, filter?: string, limit?: number, offset?: number, delimiter?: string, withHeader?: boolean, 
+/**
+ * Geometry column for `geojson` exports; detected from the schema when omitted.
+ */
+geometryColumn?: string, 
+/**
+ * Id to poll the export under; a new one is generated when omitted.
+ */
+jobId?: string, };
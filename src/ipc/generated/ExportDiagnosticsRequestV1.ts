@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ExportDiagnosticsRequestV1 = { path: string, 
+/**
+ * Log lines to include; 500 when omitted, at most 5,000.
+ */
+logLines?: number, overwrite?: boolean, };
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ExportDiagnosticsResponseV1 = { path: string, bytes: number, logLines: number, commands: number, connections: number, };
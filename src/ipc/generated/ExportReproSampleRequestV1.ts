@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Writes a zip holding `manifest.json`, `schema.json` and `rows` scrambled rows as
+ * `sample.arrows`, for attaching to bug reports.
+ */
+export type ExportReproSampleRequestV1 = { tableId: string, path: string, rows: number, 
+/**
+ * Narrows the sample to the rows that reproduce the problem.
+ */
+filter?: string, 
+/**
+ * Scrambles the same way as an earlier export; random when omitted.
+ */
+seed?: number | string | null, overwrite?: boolean, };
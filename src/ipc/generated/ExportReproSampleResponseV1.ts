@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ReproSampleManifestV1 } from "./ReproSampleManifestV1";
+
+export type ExportReproSampleResponseV1 = { path: string, bytes: number, sha256: string, manifest: ReproSampleManifestV1, };
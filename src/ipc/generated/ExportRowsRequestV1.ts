@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DataFileFormatV1 } from "./DataFileFormatV1";
+
+/**
+ * Exports the rows with the given `_rowid`s, e.g. the rows selected in the grid.
+ * Rows keep the table order; `rows` of the response counts those still present.
+ */
+export type ExportRowsRequestV1 = { tableId: string, rowIds: Array<number>, format: DataFileFormatV1, path: string, delimiter?: string, withHeader?: boolean, geometryColumn?: string, jobId?: string, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DataFileFormatV1 } from "./DataFileFormatV1";
+
+/**
+ * A saved export configuration. `pathPattern` may contain `{table}`, `{date}`,
+ * `{time}` and `{timestamp}`, expanded in local time when the template runs.
+ */
+export type ExportTemplateV1 = { name: string, format: DataFileFormatV1, pathPattern: string, projection?: Array<string>, filter?: string, limit?: number, delimiter?: string, withHeader?: boolean, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * An arithmetic expression over numeric columns and the search scores
+ * (`_score`, `_distance`, `_relevance_score`), e.g. `_score * 0.8 + ln(1 + votes)`.
+ */
+export type ExpressionRerankerV1 = { expression: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A command module compiled in behind a Cargo feature. Its commands are invoked
+ * as `plugin:<name>|<command>`.
+ */
+export type ExtensionV1 = { name: string, description: string, commands: Array<string>, };
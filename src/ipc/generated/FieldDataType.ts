@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type FieldDataType = "int8" | "int16" | "int32" | "int64" | "uint8" | "uint16" | "uint32" | "uint64" | "float32" | "float64" | "boolean" | "utf8" | "large_utf8" | "binary" | "large_binary" | "fixed_size_list_float32" | "time32_second" | "time32_millisecond" | "time64_microsecond" | "time64_nanosecond" | "duration_second" | "duration_millisecond" | "duration_microsecond" | "duration_nanosecond" | "interval_year_month" | "interval_day_time" | "interval_month_day_nano" | "null" | "dictionary_utf8" | "geometry";
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * `details` of the `invalid_argument` error a full-text search returns when some
+ * requested columns have no FTS index.
+ */
+export type FtsColumnsNotIndexedV1 = { 
+/**
+ * Requested columns without an FTS index.
+ */
+columns: Array<string>, indexedColumns: Array<string>, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TextRangeV1 } from "./TextRangeV1";
+
+/**
+ * Query terms found in one column of a full-text search hit.
+ */
+export type FtsHighlightV1 = { 
+/**
+ * Index of the row in the returned chunk.
+ */
+rowIndex: number, column: string, 
+/**
+ * The value, or an excerpt around the first match for long values.
+ */
+snippet: string, 
+/**
+ * Matched terms within `snippet`.
+ */
+ranges: Array<TextRangeV1>, };
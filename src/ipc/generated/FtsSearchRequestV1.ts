@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonOptionsV1 } from "./JsonOptionsV1";
+import type { RerankerV1 } from "./RerankerV1";
+
+export type FtsSearchRequestV1 = { tableId: string, query: string, columns?: Array<string>, limit?: number, offset?: number, projection?: Array<string>, 
+/**
+ * Name of a saved projection preset of the table, used instead of `projection`.
+ */
+projectionPreset?: string, filter?: string, 
+/**
+ * Reorders the matches by another score; results are ranked by BM25 otherwise.
+ */
+reranker?: RerankerV1, 
+/**
+ * Searches this table version instead of the latest one.
+ */
+asOfVersion?: number | string | null, includeStats?: boolean, jsonOptions?: JsonOptionsV1, 
+/**
+ * Returns `highlights` marking the query terms in the searched columns.
+ */
+highlight?: boolean, };
@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FtsLanguageV1 } from "./FtsLanguageV1";
+
+/**
+ * How an FTS index splits and normalizes text. Unset fields keep LanceDB's
+ * defaults (English, stemming, stop words removed, lowercased, ASCII folded,
+ * tokens up to 40 bytes).
+ */
+export type FtsTokenizerOptionsV1 = { 
+/**
+ * Language of the stemmer and stop-word list.
+ */
+language?: FtsLanguageV1, stem?: boolean, removeStopWords?: boolean, lowerCase?: boolean, 
+/**
+ * Folds accented letters to ASCII, so `café` matches `cafe`.
+ */
+asciiFolding?: boolean, 
+/**
+ * Longer tokens are dropped from the index.
+ */
+maxTokenLength?: number, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AccessTransportV1 } from "./AccessTransportV1";
+
+export type GetAccessLogRequestV1 = { transport?: AccessTransportV1, client?: string, command?: string, 
+/**
+ * Only entries at or after this time.
+ */
+sinceMs?: number, limit?: number, };
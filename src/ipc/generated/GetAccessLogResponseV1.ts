@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AccessLogEntryV1 } from "./AccessLogEntryV1";
+
+export type GetAccessLogResponseV1 = { 
+/**
+ * Newest first.
+ */
+entries: Array<AccessLogEntryV1>, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JobStatusV1 } from "./JobStatusV1";
+
+/**
+ * Snapshot of the backend state for troubleshooting.
+ */
+export type HealthResponseV1 = { version: string, uptimeMs: number, activeConnections: number, openTables: number, runningJobs: Array<JobStatusV1>, 
+/**
+ * Running and recently finished jobs kept for status polling.
+ */
+trackedJobs: number, schedules: number, prefetchedPages: number, 
+/**
+ * Local tables whose first page is kept warm for quick switching.
+ */
+warmTables: number, 
+/**
+ * Resident memory of the process; only reported on Linux.
+ */
+residentMemoryBytes?: number, };
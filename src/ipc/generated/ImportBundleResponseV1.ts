@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ImportBundleResponseV1 = { tableId: string, name: string, rows: number, indexesCreated: Array<string>, indexWarnings: Array<string>, };
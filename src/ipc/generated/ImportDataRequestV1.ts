@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DataFileFormatV1 } from "./DataFileFormatV1";
+import type { WriteDataMode } from "./WriteDataMode";
+
+export type ImportDataRequestV1 = { tableId: string, path: string, format: DataFileFormatV1, mode?: WriteDataMode, hasHeader?: boolean, delimiter?: string, expectedSha256?: string, 
+/**
+ * Stamps every row with `_source_file`, `_import_job_id` and `_source_line`,
+ * adding the columns to the table first when it lacks them.
+ */
+lineage?: boolean, 
+/**
+ * As in `write_rows_v1`.
+ */
+normalizeVectors?: boolean, 
+/**
+ * Job id for the import, so `cancel_job_v1` can stop it. A cancelled import
+ * commits nothing.
+ */
+jobId?: string, 
+/**
+ * Appended to the automatic checkpoint tag of this change; only ASCII letters
+ * and digits are kept.
+ */
+checkpointNote?: string, };
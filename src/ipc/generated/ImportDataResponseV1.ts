@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ImportDataResponseV1 = { tableId: string, rows: number, sha256?: string, 
+/**
+ * Value of `_import_job_id` on the imported rows, set for lineage imports.
+ */
+importJobId?: string, 
+/**
+ * Lineage columns this import added to the table.
+ */
+addedColumns?: Array<string>, normalizedColumns?: Array<string>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Hardware used to train vector indexes. `auto` picks the best one the build supports.
+ */
+export type IndexAcceleratorV1 = "auto" | "cpu" | "gpu";
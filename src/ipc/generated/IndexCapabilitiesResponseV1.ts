@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IndexAcceleratorV1 } from "./IndexAcceleratorV1";
+
+/**
+ * What the running build can use to train vector indexes.
+ */
+export type IndexCapabilitiesResponseV1 = { 
+/**
+ * Accelerators `create_index_v1` accepts, `auto` included.
+ */
+accelerators: Array<IndexAcceleratorV1>, 
+/**
+ * Accelerator `auto` resolves to.
+ */
+defaultAccelerator: IndexAcceleratorV1, 
+/**
+ * Threads Lance uses for CPU-bound index training, set by `LANCE_CPU_THREADS`.
+ */
+cpuThreads: number, 
+/**
+ * Why GPU training is unavailable, when it is.
+ */
+gpuUnavailableReason?: string, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DistanceTypeV1 } from "./DistanceTypeV1";
+import type { IndexTypeV1 } from "./IndexTypeV1";
+
+export type IndexDefinitionV1 = { name: string, indexType: IndexTypeV1, columns: Array<string>, numIndexedRows?: number, numUnindexedRows?: number, distanceType?: DistanceTypeV1, numIndices?: number, loss?: number, };
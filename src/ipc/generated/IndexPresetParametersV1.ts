@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IndexPresetV1 } from "./IndexPresetV1";
+
+/**
+ * Parameters a preset computed. Explicit request values override them.
+ */
+export type IndexPresetParametersV1 = { preset: IndexPresetV1, numPartitions: number, sampleRate: number, maxIterations: number, 
+/**
+ * Set for PQ indexes.
+ */
+numSubVectors?: number, 
+/**
+ * Set for HNSW indexes.
+ */
+numEdges?: number, efConstruction?: number, };
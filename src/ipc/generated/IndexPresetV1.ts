@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Curated vector index tunings, trading build time for recall.
+ */
+export type IndexPresetV1 = "fast_build" | "balanced" | "max_recall";
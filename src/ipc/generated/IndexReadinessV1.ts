@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How much of the table an index covers.
+ */
+export type IndexReadinessV1 = "ready" | "partial" | "stale";
@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DistanceTypeV1 } from "./DistanceTypeV1";
+import type { IndexRebuildReasonV1 } from "./IndexRebuildReasonV1";
+import type { IndexRebuildStateV1 } from "./IndexRebuildStateV1";
+import type { IndexTypeV1 } from "./IndexTypeV1";
+
+/**
+ * An index over a column that `alter_columns_v1` renamed or retyped.
+ */
+export type IndexRebuildV1 = { indexName: string, indexType: IndexTypeV1, 
+/**
+ * Indexed columns under their names after the alteration.
+ */
+columns: Array<string>, reason: IndexRebuildReasonV1, 
+/**
+ * Name of the recreated index: the default name for the renamed column when
+ * the old one was the default, the old name otherwise.
+ */
+newIndexName: string, 
+/**
+ * Metric of a vector index, kept by the rebuild.
+ */
+distanceType?: DistanceTypeV1, state: IndexRebuildStateV1, error?: string, };
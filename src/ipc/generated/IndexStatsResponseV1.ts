@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DistanceTypeV1 } from "./DistanceTypeV1";
+import type { IndexReadinessV1 } from "./IndexReadinessV1";
+import type { IndexTypeV1 } from "./IndexTypeV1";
+
+export type IndexStatsResponseV1 = { tableId: string, indexName: string, indexType: IndexTypeV1, columns: Array<string>, numIndexedRows: number | string, numUnindexedRows: number | string, 
+/**
+ * Share of the rows the index covers, from 0 to 1; 1 for an empty table.
+ */
+coverage: number, readiness: IndexReadinessV1, 
+/**
+ * Some rows are not indexed; `optimize_table_v1` adds them to the index.
+ */
+needsOptimize: boolean, 
+/**
+ * Segments the index is stored in, counting deltas added by optimizes.
+ */
+numIndices?: number, 
+/**
+ * IVF indexes only. Estimated from the number of indexed rows; lance does not
+ * report the partition count of an index.
+ */
+estimatedPartitions?: number, distanceType?: DistanceTypeV1, 
+/**
+ * Training loss of the IVF k-means, when lance reports it.
+ */
+loss?: number, };
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type IndexTypeV1 = "auto" | "btree" | "bitmap" | "label_list" | "fts" | "ivf_flat" | "ivf_sq" | "ivf_pq" | "ivf_rq" | "ivf_hnsw_pq" | "ivf_hnsw_sq";
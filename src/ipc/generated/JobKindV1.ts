@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type JobKindV1 = "backup" | "restore" | "migrate_format" | "delete" | "export" | "index_build" | "scan" | "import" | "optimize" | "clone";
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JobKindV1 } from "./JobKindV1";
+import type { JobStateV1 } from "./JobStateV1";
+import type { ProgressStageV1 } from "./ProgressStageV1";
+
+export type JobStatusV1 = { jobId: string, kind: JobKindV1, state: JobStateV1, processedRows: number | string, totalRows?: number | string | null, message?: string, startedAtMs: number, finishedAtMs?: number, 
+/**
+ * Last stage the job reported.
+ */
+stage?: ProgressStageV1, };
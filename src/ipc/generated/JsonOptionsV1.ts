@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BinaryFormatV1 } from "./BinaryFormatV1";
+import type { LargeIntegerFormatV1 } from "./LargeIntegerFormatV1";
+import type { NonFiniteFloatFormatV1 } from "./NonFiniteFloatFormatV1";
+import type { TimestampFormatV1 } from "./TimestampFormatV1";
+
+/**
+ * Rendering of values in `JsonChunk` rows. The defaults match the output of
+ * earlier versions.
+ */
+export type JsonOptionsV1 = { nonFiniteFloats?: NonFiniteFloatFormatV1, binary?: BinaryFormatV1, timestamps?: TimestampFormatV1, largeIntegers?: LargeIntegerFormatV1, 
+/**
+ * Writes null columns as `null` instead of leaving the key out of the row.
+ */
+explicitNulls?: boolean, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Stops a running query; its command returns a `cancelled` error.
+ */
+export type KillQueryRequestV1 = { queryId: string, };
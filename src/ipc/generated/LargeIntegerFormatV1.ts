@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How Int64 and UInt64 values are rendered. JavaScript numbers lose precision
+ * above 2^53, so `string` keeps ids intact.
+ */
+export type LargeIntegerFormatV1 = "number" | "string" | "auto";
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ActiveQueryV1 } from "./ActiveQueryV1";
+
+export type ListActiveQueriesResponseV1 = { queries: Array<ActiveQueryV1>, };
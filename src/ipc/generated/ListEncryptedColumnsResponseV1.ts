@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EncryptedColumnV1 } from "./EncryptedColumnV1";
+
+export type ListEncryptedColumnsResponseV1 = { columns: Array<EncryptedColumnV1>, 
+/**
+ * Keys of `columns` not unlocked in this session.
+ */
+lockedKeyIds: Array<string>, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ExportTemplateV1 } from "./ExportTemplateV1";
+
+export type ListExportTemplatesResponseV1 = { templates: Array<ExportTemplateV1>, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ExtensionV1 } from "./ExtensionV1";
+
+export type ListExtensionsResponseV1 = { extensions: Array<ExtensionV1>, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JobKindV1 } from "./JobKindV1";
+import type { JobStateV1 } from "./JobStateV1";
+
+/**
+ * Lists tracked jobs, newest first. Running jobs and the last 100 finished ones
+ * are kept.
+ */
+export type ListJobsRequestV1 = { state?: JobStateV1, kind?: JobKindV1, limit?: number, };
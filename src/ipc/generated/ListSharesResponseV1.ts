@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ShareSessionV1 } from "./ShareSessionV1";
+
+export type ListSharesResponseV1 = { shares: Array<ShareSessionV1>, };
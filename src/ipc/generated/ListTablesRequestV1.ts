@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ListTablesRequestV1 = { connectionId: string, 
+/**
+ * Opens every table to read its row count, version and size. Slower on large
+ * catalogs and remote stores.
+ */
+includeStats: boolean, };
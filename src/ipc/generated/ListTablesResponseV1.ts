@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TableInfo } from "./TableInfo";
+
+export type ListTablesResponseV1 = { tables: Array<TableInfo>, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { VersionTagV1 } from "./VersionTagV1";
+
+export type ListTagsResponseV1 = { tableId: string, 
+/**
+ * Newest version first.
+ */
+tags: Array<VersionTagV1>, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { VersionInfoV1 } from "./VersionInfoV1";
+
+export type ListVersionsResponseV1 = { versions: Array<VersionInfoV1>, };
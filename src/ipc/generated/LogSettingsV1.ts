@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LogLevelV1 } from "./LogLevelV1";
+
+/**
+ * Runtime log configuration. `module_filter` is a comma-separated list of module
+ * path prefixes (for example `lancedb_viewer_lib::services,lance`); when set, `level`
+ * applies only to those modules.
+ */
+export type LogSettingsV1 = { level: LogLevelV1, moduleFilter?: string, };
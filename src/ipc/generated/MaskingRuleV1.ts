@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MaskingStrategyV1 } from "./MaskingStrategyV1";
+
+export type MaskingRuleV1 = { 
+/**
+ * Applies to every table of the connection when omitted.
+ */
+tableName?: string, column: string, strategy: MaskingStrategyV1, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MaterializeSourceV1 } from "./MaterializeSourceV1";
+
+export type MaterializeQueryRequestV1 = { connectionId: string, source: MaterializeSourceV1, tableName: string, overwrite?: boolean, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CombinedSearchRequestV1 } from "./CombinedSearchRequestV1";
+import type { FtsSearchRequestV1 } from "./FtsSearchRequestV1";
+import type { QueryFilterRequestV1 } from "./QueryFilterRequestV1";
+import type { VectorSearchRequestV1 } from "./VectorSearchRequestV1";
+
+/**
+ * The filter or search whose result `materialize_query_v1` saves. Paging fields are
+ * honoured; a filter without `limit` saves every matching row.
+ */
+export type MaterializeSourceV1 = { "type": "filter" } & QueryFilterRequestV1 | { "type": "vector_search" } & VectorSearchRequestV1 | { "type": "fts_search" } & FtsSearchRequestV1 | { "type": "combined_search" } & CombinedSearchRequestV1;
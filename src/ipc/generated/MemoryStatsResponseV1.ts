@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CommandMemoryV1 } from "./CommandMemoryV1";
+
+export type MemoryStatsResponseV1 = { 
+/**
+ * Largest peak first.
+ */
+commands: Array<CommandMemoryV1>, };
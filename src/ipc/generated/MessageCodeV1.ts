@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type MessageCodeV1 = "connection_not_found" | "table_not_found" | "state_lock_failed" | "compact_submitted" | "compact_submitted_with_target" | "vacuum_submitted" | "vacuum_submitted_older_than" | "indexes_optimized" | "connection_read_only" | "role_forbidden" | "fts_index_missing" | "fts_columns_not_indexed" | "vector_filter_hint" | "disk_space_insufficient" | "encrypted_column_locked" | "path_empty" | "table_name_empty" | "index_name_empty" | "job_not_found";
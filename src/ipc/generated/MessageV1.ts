@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MessageCodeV1 } from "./MessageCodeV1";
+
+export type MessageV1 = { code: MessageCodeV1, params: { [key in string]: string }, text: string, };
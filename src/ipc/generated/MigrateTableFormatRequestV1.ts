@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type MigrateTableFormatRequestV1 = { tableId: string, 
+/**
+ * Defaults to the latest supported storage version.
+ */
+targetVersion?: string, 
+/**
+ * Defaults to `<table>_v<version>`, e.g. `items_v2_1`.
+ */
+targetTableName?: string, overwrite?: boolean, jobId?: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type MigrateTableFormatResponseV1 = { jobId: string, tableId: string, name: string, rows: number | string, fromVersion: string, toVersion: string, 
+/**
+ * Indexes on the source table; they are not copied and must be rebuilt.
+ */
+indexesToRebuild: Array<string>, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * `details` of the error a full-text search returns when the table has no FTS
+ * index: the string columns `create_index_v1` could index.
+ */
+export type MissingFtsIndexV1 = { stringColumns: Array<string>, };
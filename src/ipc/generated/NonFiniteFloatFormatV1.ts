@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How NaN and infinite floats are rendered; JSON itself has no such numbers.
+ */
+export type NonFiniteFloatFormatV1 = "null" | "string";
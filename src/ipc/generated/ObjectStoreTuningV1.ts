@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Object-store client settings for S3, GCS and Azure connections, translated into
+ * storage options when connecting. Local connections ignore them.
+ */
+export type ObjectStoreTuningV1 = { 
+/**
+ * Time allowed to open a connection to the store.
+ */
+connectTimeoutMs?: number, 
+/**
+ * Time allowed for a whole request, including reading the response.
+ */
+requestTimeoutMs?: number, };
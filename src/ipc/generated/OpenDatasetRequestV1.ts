@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ConnectOptions } from "./ConnectOptions";
+
+/**
+ * Opens a bare `.lance` dataset directory, such as one written by lance-python,
+ * through a synthetic connection to its parent directory.
+ */
+export type OpenDatasetRequestV1 = { uri: string, storageOptions?: { [key in string]: string }, options?: ConnectOptions, };
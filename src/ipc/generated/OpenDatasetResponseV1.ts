@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ConnectResponseV1 } from "./ConnectResponseV1";
+import type { TableHandle } from "./TableHandle";
+
+export type OpenDatasetResponseV1 = { connection: ConnectResponseV1, table: TableHandle, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ErrorEnvelope } from "./ErrorEnvelope";
+import type { TableHandle } from "./TableHandle";
+
+/**
+ * Outcome of one table of an `open_tables_v1` batch: a handle or the error the
+ * single-table `open_table_v1` would have returned.
+ */
+export type OpenTableResultV1 = { tableName: string, handle?: TableHandle, error?: ErrorEnvelope, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OpenTableResultV1 } from "./OpenTableResultV1";
+
+export type OpenTablesResponseV1 = { 
+/**
+ * One entry per requested table, in request order.
+ */
+tables: Array<OpenTableResultV1>, opened: number, failed: number, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OptimizedIndexV1 } from "./OptimizedIndexV1";
+
+/**
+ * What an optimize did, as reported by LanceDB. Each action fills its own fields.
+ */
+export type OptimizeStatsV1 = { fragmentsRemoved?: number, fragmentsAdded?: number, filesRemoved?: number, filesAdded?: number, bytesRemoved?: number, oldVersionsRemoved?: number, indexes?: Array<OptimizedIndexV1>, 
+/**
+ * Set when `waitTimeoutMs` ran out before every index covered the table. The
+ * optimization itself has committed; `indexes` shows how far indexing got.
+ */
+indexPending: boolean, elapsedMs: number, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LocaleV1 } from "./LocaleV1";
+import type { OptimizeActionV1 } from "./OptimizeActionV1";
+
+export type OptimizeTableRequestV1 = { tableId: string, action: OptimizeActionV1, targetRowsPerFragment?: number, olderThanDays?: number, deleteUnverified?: boolean, errorIfTaggedOldVersions?: boolean, locale?: LocaleV1, 
+/**
+ * `index` only: how long to wait for every index to cover the table after the
+ * merge, for backends that index in the background. Unset returns at once.
+ */
+waitTimeoutMs?: number, 
+/**
+ * Tracks the optimize as a job, so its progress events carry this id.
+ */
+jobId?: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MessageV1 } from "./MessageV1";
+import type { OptimizeActionV1 } from "./OptimizeActionV1";
+import type { OptimizeStatsV1 } from "./OptimizeStatsV1";
+
+export type OptimizeTableResponseV1 = { tableId: string, action: OptimizeActionV1, summary: string, summaryMessage: MessageV1, stats: OptimizeStatsV1, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Rows one index covers after an `index` optimize.
+ */
+export type OptimizedIndexV1 = { name: string, numIndexedRows: number | string, numUnindexedRows: number | string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type OverviewRequestV1 = { 
+/**
+ * Time budget of each connection; defaults to the query timeout, or 5 seconds.
+ */
+timeoutMs?: number, };
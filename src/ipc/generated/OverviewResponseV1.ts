@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ConnectionOverviewV1 } from "./ConnectionOverviewV1";
+
+export type OverviewResponseV1 = { 
+/**
+ * Sorted by URI.
+ */
+connections: Array<ConnectionOverviewV1>, elapsedMs: number, };
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PivotAggregationV1 } from "./PivotAggregationV1";
+
+/**
+ * Cross-tab of `value_column` by `row_keys` and the distinct values of `column_key`.
+ * `count` counts rows, or non-null values when `value_column` is set; the other
+ * aggregations need a numeric `value_column`.
+ */
+export type PivotRequestV1 = { tableId: string, rowKeys: Array<string>, columnKey: string, valueColumn?: string, aggregation?: PivotAggregationV1, filter?: string, 
+/**
+ * Keeps the most frequent column values; defaults to 50 and is capped at 500.
+ */
+maxColumns?: number, };
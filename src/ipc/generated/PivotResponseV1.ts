@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PivotAggregationV1 } from "./PivotAggregationV1";
+import type { PivotRowV1 } from "./PivotRowV1";
+
+export type PivotResponseV1 = { rowKeys: Array<string>, columnKey: string, aggregation: PivotAggregationV1, 
+/**
+ * Column key values, most frequent first. Nulls are labelled `null`.
+ */
+columns: Array<string>, rows: Array<PivotRowV1>, 
+/**
+ * Distinct column values dropped by `max_columns`.
+ */
+omittedColumns: number, scannedRows: number, };
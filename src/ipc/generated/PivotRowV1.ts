@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type PivotRowV1 = { 
+/**
+ * Values of the row keys, in request order.
+ */
+keys: unknown[], 
+/**
+ * One cell per entry in `PivotResponseV1::columns`; `None` when no rows matched.
+ */
+values: Array<number | null>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One node of an analyzed query plan. `depth` is the nesting level in the plan tree.
+ */
+export type PlanStageV1 = { name: string, depth: number, outputRows?: number | string | null, elapsedMs?: number, metrics?: { [key in string]: string }, };
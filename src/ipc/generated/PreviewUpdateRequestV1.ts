@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { UpdateColumnInputV1 } from "./UpdateColumnInputV1";
+
+/**
+ * Evaluates `updates` against up to `limit` rows matching `filter` without
+ * writing anything.
+ */
+export type PreviewUpdateRequestV1 = { tableId: string, filter?: string, updates: Array<UpdateColumnInputV1>, allowFullTable?: boolean, limit?: number, };
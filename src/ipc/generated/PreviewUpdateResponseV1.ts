@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { UpdatePreviewRowV1 } from "./UpdatePreviewRowV1";
+
+export type PreviewUpdateResponseV1 = { tableId: string, 
+/**
+ * Updated columns, in request order.
+ */
+columns: Array<string>, rows: Array<UpdatePreviewRowV1>, 
+/**
+ * Rows the update would change.
+ */
+matchedRows: number, };
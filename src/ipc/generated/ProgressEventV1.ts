@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JobKindV1 } from "./JobKindV1";
+import type { JobStateV1 } from "./JobStateV1";
+import type { ProgressStageV1 } from "./ProgressStageV1";
+
+/**
+ * Emitted as the `job-progress` event whenever a job starts, changes stage,
+ * processes rows or finishes, so one progress component can follow imports,
+ * exports, index builds, copies and compactions alike.
+ */
+export type ProgressEventV1 = { jobId: string, kind: JobKindV1, state: JobStateV1, stage: ProgressStageV1, 
+/**
+ * 0 to 100 when the job knows its total rows or has finished; unset while the
+ * amount of work is unknown.
+ */
+percent?: number, message?: string, processedRows: number | string, totalRows?: number | string | null, };
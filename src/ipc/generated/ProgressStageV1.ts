@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Step a long-running job is at, reported in `ProgressEventV1`.
+ */
+export type ProgressStageV1 = "starting" | "reading" | "writing" | "copying" | "indexing" | "compacting" | "pruning" | "verifying" | "finished";
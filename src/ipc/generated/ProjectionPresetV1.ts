@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A named projection saved for one table. Scan and search requests pass its
+ * name as `projectionPreset` instead of the column list.
+ */
+export type ProjectionPresetV1 = { name: string, 
+/**
+ * Projection entries as `projection` takes them, columns or `expr AS alias`.
+ */
+columns: Array<string>, };
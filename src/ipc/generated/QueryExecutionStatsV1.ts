@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PlanStageV1 } from "./PlanStageV1";
+
+/**
+ * Execution report returned when a request sets `includeStats`. The query is run a
+ * second time under `analyze_plan`, so `elapsedMs` covers that run.
+ */
+export type QueryExecutionStatsV1 = { elapsedMs: number, indexUsed: boolean, flatScan: boolean, rowsScanned?: number | string | null, bytesRead?: number, iops?: number, stages: Array<PlanStageV1>, plan: string, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonOptionsV1 } from "./JsonOptionsV1";
+
+export type QueryFilterRequestV1 = { tableId: string, filter: string, projection?: Array<string>, 
+/**
+ * Name of a saved projection preset of the table, used instead of `projection`.
+ */
+projectionPreset?: string, limit?: number, offset?: number, includeStats?: boolean, jsonOptions?: JsonOptionsV1, };
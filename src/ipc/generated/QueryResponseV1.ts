@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DataChunk } from "./DataChunk";
+import type { FtsHighlightV1 } from "./FtsHighlightV1";
+import type { QueryExecutionStatsV1 } from "./QueryExecutionStatsV1";
+import type { SearchWarningV1 } from "./SearchWarningV1";
+import type { VectorSearchParamsV1 } from "./VectorSearchParamsV1";
+
+export type QueryResponseV1 = { chunk: DataChunk, nextOffset?: number, searchParams?: VectorSearchParamsV1, stats?: QueryExecutionStatsV1, warnings?: Array<SearchWarningV1>, highlights?: Array<FtsHighlightV1>, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type RecallAtNprobesV1 = { nprobes: number, 
+/**
+ * Share of the exact top-k neighbours the index returned, from 0 to 1.
+ */
+recall: number, meanLatencyMs: number, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RecentFilterSortV1 } from "./RecentFilterSortV1";
+
+/**
+ * Filters previously used on a table, for autocomplete in the filter bar. Scans
+ * and filter queries record their filter when they fetch the first page.
+ */
+export type RecentFiltersRequestV1 = { tableId: string, 
+/**
+ * Only filters starting with this text, ignoring case.
+ */
+prefix?: string, 
+/**
+ * Defaults to `recent`.
+ */
+sort?: RecentFilterSortV1, limit?: number, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RecentFilterV1 } from "./RecentFilterV1";
+
+export type RecentFiltersResponseV1 = { tableId: string, filters: Array<RecentFilterV1>, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Sets the display order of the columns. Columns `order` leaves out follow in
+ * schema order.
+ */
+export type ReorderColumnsRequestV1 = { tableId: string, order: Array<string>, 
+/**
+ * Appended to the automatic checkpoint tag of this change; only ASCII letters
+ * and digits are kept.
+ */
+checkpointNote?: string, };
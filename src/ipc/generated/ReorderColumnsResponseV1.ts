@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SchemaDefinition } from "./SchemaDefinition";
+
+export type ReorderColumnsResponseV1 = { tableId: string, 
+/**
+ * Every column, in the stored order.
+ */
+order: Array<string>, schema: SchemaDefinition, };
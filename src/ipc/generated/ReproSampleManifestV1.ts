@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BundleIndexV1 } from "./BundleIndexV1";
+
+/**
+ * Leaves out the table name and row values so the archive can be shared.
+ */
+export type ReproSampleManifestV1 = { formatVersion: number, viewerVersion: string, createdAtMs: number, sourceVersion: number | string, sourceRows: number, rows: number, 
+/**
+ * Random seeds use all 64 bits, so this is a `json_u64` like the versions.
+ */
+seed: number | string, filter?: string, indexes: Array<BundleIndexV1>, };
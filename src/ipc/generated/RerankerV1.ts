@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CrossEncoderRerankerV1 } from "./CrossEncoderRerankerV1";
+import type { ExpressionRerankerV1 } from "./ExpressionRerankerV1";
+import type { RrfRerankerV1 } from "./RrfRerankerV1";
+
+/**
+ * Reorders the candidates of a full-text or hybrid search before they are paged.
+ */
+export type RerankerV1 = { "type": "rrf" } & RrfRerankerV1 | { "type": "cross_encoder" } & CrossEncoderRerankerV1 | { "type": "expression" } & ExpressionRerankerV1;
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type RestoreBackupRequestV1 = { connectionId: string, backupUri: string, backupTableName: string, targetTableName?: string, overwrite?: boolean, storageOptions?: { [key in string]: string }, jobId?: string, };
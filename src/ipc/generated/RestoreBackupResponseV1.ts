@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BackupModeV1 } from "./BackupModeV1";
+import type { VersionInfoV1 } from "./VersionInfoV1";
+
+export type RestoreBackupResponseV1 = { jobId: string, tableId: string, name: string, rows: number | string, mode?: BackupModeV1, sourceTable?: string, sourceVersion?: number | string | null, backedUpAtMs?: number, versions: Array<VersionInfoV1>, };
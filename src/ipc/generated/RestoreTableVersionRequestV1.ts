@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Rolls the table back to `version` by committing that version's state as a
+ * new latest version; later versions stay in the history.
+ */
+export type RestoreTableVersionRequestV1 = { tableId: string, version: number | string, 
+/**
+ * Appended to the automatic checkpoint tag of this change; only ASCII letters
+ * and digits are kept.
+ */
+checkpointNote?: string, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ApiVersion } from "./ApiVersion";
+import type { ErrorEnvelope } from "./ErrorEnvelope";
+
+export type ResultEnvelope<T> = { apiVersion: ApiVersion, ok: boolean, data?: T, error?: ErrorEnvelope, };
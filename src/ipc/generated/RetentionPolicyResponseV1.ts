@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RetentionPolicyV1 } from "./RetentionPolicyV1";
+
+export type RetentionPolicyResponseV1 = { tableId: string, policy?: RetentionPolicyV1, };
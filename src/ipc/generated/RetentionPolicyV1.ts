@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Rows whose `timestamp_column` is older than `max_age_seconds` are expired.
+ */
+export type RetentionPolicyV1 = { timestampColumn: string, maxAgeSeconds: number, };
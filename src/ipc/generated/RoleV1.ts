@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What the user of this workstation may change. Viewers only read; editors also
+ * write rows and table settings; admins may also drop, create and alter tables,
+ * build indexes and change app settings.
+ */
+export type RoleV1 = "admin" | "editor" | "viewer";
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ConnectProfile } from "./ConnectProfile";
+
+/**
+ * Reconnects the live connections to `profile.uri` with new credentials. The
+ * profile's `auth` must be `inline` and carry the rotated params.
+ */
+export type RotateSecretRequestV1 = { profile: ConnectProfile, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type RotateSecretResponseV1 = { 
+/**
+ * Connections now using the new credentials; their ids are unchanged.
+ */
+connectionIds: Array<string>, 
+/**
+ * Table handles reopened through them, also under their existing ids.
+ */
+reopenedTables: number, };
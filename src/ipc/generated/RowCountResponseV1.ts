@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type RowCountResponseV1 = { tableId: string, rowCount: number | string, 
+/**
+ * Table version the count belongs to.
+ */
+version: number | string, 
+/**
+ * Served from the row-count cache without counting.
+ */
+cached: boolean, };
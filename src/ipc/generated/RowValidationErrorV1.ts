@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * `details` of the `invalid_argument` error `write_rows_v1` returns when a row does
+ * not fit the table schema.
+ */
+export type RowValidationErrorV1 = { 
+/**
+ * Index of the first offending row in the request.
+ */
+rowIndex: number, 
+/**
+ * Offending field; missing when the row itself is malformed, e.g. not an object.
+ */
+field?: string, message: string, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Reciprocal rank fusion of the vector and full-text rankings.
+ */
+export type RrfRerankerV1 = { 
+/**
+ * Rank constant; defaults to 60.
+ */
+k?: number, };
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type RunRetentionResponseV1 = { tableId: string, timestampColumn: string, cutoff: string, rowsExpired: number, rowsDeleted: number, version?: number | string | null, };
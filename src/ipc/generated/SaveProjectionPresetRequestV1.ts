@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ProjectionPresetV1 } from "./ProjectionPresetV1";
+
+/**
+ * Saves `preset`, replacing the table's preset with the same name.
+ */
+export type SaveProjectionPresetRequestV1 = { tableId: string, preset: ProjectionPresetV1, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ProjectionPresetV1 } from "./ProjectionPresetV1";
+
+export type SaveProjectionPresetResponseV1 = { tableId: string, preset: ProjectionPresetV1, };
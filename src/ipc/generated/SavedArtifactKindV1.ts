@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Settings saved by the backend that can name a column.
+ */
+export type SavedArtifactKindV1 = "column_order" | "search_defaults" | "retention_policy" | "masking_rule" | "encrypted_column" | "recent_filter" | "export_template" | "projection_preset";
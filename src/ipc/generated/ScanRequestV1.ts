@@ -0,0 +1,33 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DataFormat } from "./DataFormat";
+import type { JsonOptionsV1 } from "./JsonOptionsV1";
+
+export type ScanRequestV1 = { tableId: string, format?: DataFormat, projection?: Array<string>, 
+/**
+ * Name of a saved projection preset of the table, used instead of `projection`.
+ */
+projectionPreset?: string, filter?: string, limit?: number, offset?: number, applyMasking?: boolean, 
+/**
+ * Queries the next page in the background so the following request is served
+ * from cache.
+ */
+prefetch?: boolean, 
+/**
+ * Returns an execution report alongside the page.
+ */
+includeStats?: boolean, jsonOptions?: JsonOptionsV1, 
+/**
+ * Reads this table version instead of the latest one. Paging with the `version`
+ * of the first page keeps later pages stable while the table is written to.
+ */
+asOfVersion?: number | string | null, 
+/**
+ * Arrow pages only: the most bytes of encoded IPC one response may carry. A page
+ * over the budget is cut short (to at least one row) and continues at `nextOffset`.
+ */
+maxChunkBytes?: number, 
+/**
+ * Tracks the scan as a job that `cancel_job_v1` can stop. Scans without one are
+ * not tracked.
+ */
+jobId?: string, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChunkSplitV1 } from "./ChunkSplitV1";
+import type { DataChunk } from "./DataChunk";
+import type { QueryExecutionStatsV1 } from "./QueryExecutionStatsV1";
+
+export type ScanResponseV1 = { chunk: DataChunk, nextOffset?: number, 
+/**
+ * Table version the page was read from.
+ */
+version: number | string, 
+/**
+ * Set when `maxChunkBytes` cut the page short.
+ */
+split?: ChunkSplitV1, stats?: QueryExecutionStatsV1, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ScanStreamStartedV1 } from "./ScanStreamStartedV1";
+import type { ScanStreamSummaryV1 } from "./ScanStreamSummaryV1";
+
+/**
+ * JSON messages of a scan stream. Chunks arrive between `started` and
+ * `completed` as raw bytes.
+ */
+export type ScanStreamEventV1 = { "event": "started" } & ScanStreamStartedV1 | { "event": "completed" } & ScanStreamSummaryV1;
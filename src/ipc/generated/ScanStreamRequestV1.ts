@@ -0,0 +1,25 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Streams a whole scan over a channel instead of returning one page. Chunks are
+ * complete Arrow IPC streams sent as raw bytes, numbered from 1 in the order they
+ * arrive. At most `window` chunks are sent ahead of the last one acknowledged
+ * with `ack_scan_stream_v1`.
+ */
+export type ScanStreamRequestV1 = { tableId: string, projection?: Array<string>, 
+/**
+ * Name of a saved projection preset of the table, used instead of `projection`.
+ */
+projectionPreset?: string, filter?: string, 
+/**
+ * Stops after this many rows; the whole table is streamed by default.
+ */
+limit?: number, offset?: number, applyMasking?: boolean, asOfVersion?: number | string | null, 
+/**
+ * Largest encoded chunk; defaults to 4 MiB. Batches over it are split by rows.
+ */
+maxChunkBytes?: number, 
+/**
+ * Unacknowledged chunks allowed in flight; defaults to 4, at most 64.
+ */
+window?: number, };
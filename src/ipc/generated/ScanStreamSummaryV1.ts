@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ScanStreamSummaryV1 = { streamId: string, version: number | string, totalRows: number | string, chunks: number, totalBytes: number, 
+/**
+ * Set when `cancel_scan_stream_v1` stopped the stream early.
+ */
+cancelled: boolean, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BackupModeV1 } from "./BackupModeV1";
+import type { DataFileFormatV1 } from "./DataFileFormatV1";
+
+export type ScheduleActionV1 = { "type": "compact", targetRowsPerFragment?: number, } | { "type": "vacuum", olderThanDays?: number, } | { "type": "backup", destinationUri: string, mode?: BackupModeV1, } | { "type": "export", path: string, format: DataFileFormatV1, } | { "type": "retention" };
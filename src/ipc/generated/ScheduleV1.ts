@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ScheduleActionV1 } from "./ScheduleActionV1";
+import type { ScheduleRunStatusV1 } from "./ScheduleRunStatusV1";
+
+export type ScheduleV1 = { id: string, connectionUri: string, tableName: string, cron: string, action: ScheduleActionV1, enabled: boolean, lastRunAtMs?: number, lastStatus?: ScheduleRunStatusV1, lastMessage?: string, };
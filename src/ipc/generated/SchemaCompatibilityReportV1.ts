@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ColumnTypeMismatchV1 } from "./ColumnTypeMismatchV1";
+
+/**
+ * `details` of the `invalid_argument` error `import_data_v1` returns when the file
+ * does not fit the table.
+ */
+export type SchemaCompatibilityReportV1 = { 
+/**
+ * Table columns the file does not have.
+ */
+missingColumns: Array<string>, 
+/**
+ * File columns the table does not have.
+ */
+extraColumns: Array<string>, typeMismatches: Array<ColumnTypeMismatchV1>, 
+/**
+ * CSV headers name the table columns in another order; CSV columns are read
+ * by position.
+ */
+columnOrderDiffers: boolean, };
@@ -0,0 +1,34 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DataTypeDescriptorV1 } from "./DataTypeDescriptorV1";
+import type { EmbeddingModelV1 } from "./EmbeddingModelV1";
+import type { GeometryEncodingV1 } from "./GeometryEncodingV1";
+
+export type SchemaField = { name: string, 
+/**
+ * Display string kept for compatibility; prefer `type_descriptor`.
+ */
+dataType: string, typeDescriptor: DataTypeDescriptorV1, nullable: boolean, metadata?: { [key in string]: string }, 
+/**
+ * From `PARQUET:field_id` metadata, when present.
+ */
+fieldId?: number, 
+/**
+ * From `ARROW:extension:name` metadata, when present.
+ */
+extensionName?: string, 
+/**
+ * Set when the column holds WKT or WKB geometries.
+ */
+geometry?: GeometryEncodingV1, 
+/**
+ * Set when JSON rows carry this Int64/UInt64 column as decimal strings.
+ */
+stringEncoded?: boolean, 
+/**
+ * Model that produced the vectors, when the column records one.
+ */
+embedding?: EmbeddingModelV1, 
+/**
+ * Set when the column records that its vectors are L2-normalized.
+ */
+normalized?: boolean, };
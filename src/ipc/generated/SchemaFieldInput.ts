@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EmbeddingModelV1 } from "./EmbeddingModelV1";
+import type { FieldDataType } from "./FieldDataType";
+
+export type SchemaFieldInput = { name: string, dataType: FieldDataType, nullable: boolean, metadata?: { [key in string]: string }, vectorLength?: number, 
+/**
+ * Model that produces the vectors of this column, stored in its metadata.
+ */
+embedding?: EmbeddingModelV1, };
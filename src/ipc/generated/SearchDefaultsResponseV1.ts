@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SearchDefaultsV1 } from "./SearchDefaultsV1";
+
+export type SearchDefaultsResponseV1 = { tableId: string, defaults?: SearchDefaultsV1, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DistanceTypeV1 } from "./DistanceTypeV1";
+
+/**
+ * Search settings remembered for a table, used to pre-fill the search forms.
+ */
+export type SearchDefaultsV1 = { vectorColumn?: string, distanceType?: DistanceTypeV1, topK?: number, ftsColumns?: Array<string>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Kind of index a search relies on.
+ */
+export type SearchIndexKindV1 = "vector" | "fts";
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Why a search response carries an index warning.
+ */
+export type SearchWarningKindV1 = "missing_index" | "stale_index" | "embedding_model_mismatch" | "unnormalized_query";
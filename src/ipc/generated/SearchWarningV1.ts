@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IndexTypeV1 } from "./IndexTypeV1";
+import type { SearchIndexKindV1 } from "./SearchIndexKindV1";
+import type { SearchWarningKindV1 } from "./SearchWarningKindV1";
+
+/**
+ * Index coverage or embedding model problem found while running a vector or
+ * full-text search.
+ */
+export type SearchWarningV1 = { kind: SearchWarningKindV1, indexKind: SearchIndexKindV1, 
+/**
+ * Searched column, when the request or the index names one.
+ */
+column?: string, indexName?: string, indexType?: IndexTypeV1, indexedRows?: number | string | null, unindexedRows?: number | string | null, message: string, };
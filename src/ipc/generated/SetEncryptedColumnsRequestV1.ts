@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EncryptedColumnV1 } from "./EncryptedColumnV1";
+
+export type SetEncryptedColumnsRequestV1 = { connectionId: string, columns: Array<EncryptedColumnV1>, };
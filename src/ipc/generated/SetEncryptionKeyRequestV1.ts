@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Unlocks a key for this session, or locks it again when `key` is omitted.
+ */
+export type SetEncryptionKeyRequestV1 = { keyId: string, 
+/**
+ * Base64 encoded 256-bit key.
+ */
+key?: string, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LogLevelV1 } from "./LogLevelV1";
+
+export type SetLogLevelRequestV1 = { level: LogLevelV1, moduleFilter?: string, };
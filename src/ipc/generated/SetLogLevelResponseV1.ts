@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LogSettingsV1 } from "./LogSettingsV1";
+
+export type SetLogLevelResponseV1 = { settings: LogSettingsV1, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MaskingRuleV1 } from "./MaskingRuleV1";
+
+export type SetMaskingRulesRequestV1 = { connectionId: string, rules: Array<MaskingRuleV1>, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MaskingRuleV1 } from "./MaskingRuleV1";
+
+export type SetMaskingRulesResponseV1 = { rules: Array<MaskingRuleV1>, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RetentionPolicyV1 } from "./RetentionPolicyV1";
+
+export type SetRetentionPolicyRequestV1 = { tableId: string, 
+/**
+ * Clears the policy when omitted.
+ */
+policy?: RetentionPolicyV1, };
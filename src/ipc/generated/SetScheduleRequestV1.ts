@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ScheduleActionV1 } from "./ScheduleActionV1";
+
+export type SetScheduleRequestV1 = { connectionId: string, id?: string, tableName: string, cron: string, action: ScheduleActionV1, enabled?: boolean, };
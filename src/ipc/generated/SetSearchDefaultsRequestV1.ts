@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SearchDefaultsV1 } from "./SearchDefaultsV1";
+
+export type SetSearchDefaultsRequestV1 = { tableId: string, 
+/**
+ * Clears the defaults when omitted or empty.
+ */
+defaults?: SearchDefaultsV1, };
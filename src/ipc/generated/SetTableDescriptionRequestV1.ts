@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TableDescriptionV1 } from "./TableDescriptionV1";
+
+export type SetTableDescriptionRequestV1 = { tableId: string, 
+/**
+ * Clears the description when omitted or empty.
+ */
+metadata?: TableDescriptionV1, };
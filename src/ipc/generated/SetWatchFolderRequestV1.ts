@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DataFileFormatV1 } from "./DataFileFormatV1";
+
+export type SetWatchFolderRequestV1 = { connectionId: string, id?: string, tableName: string, directory: string, format: DataFileFormatV1, hasHeader?: boolean, delimiter?: string, lineage?: boolean, enabled?: boolean, 
+/**
+ * Imports the files already in the directory too. Otherwise only files that
+ * appear later are imported.
+ */
+includeExisting?: boolean, };
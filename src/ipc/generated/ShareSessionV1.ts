@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ShareSessionV1 = { shareId: string, 
+/**
+ * Browser URL including the token.
+ */
+url: string, token: string, 
+/**
+ * Names of the shared tables.
+ */
+tables: Array<string>, startedAtMs: number, expiresAtMs: number, };
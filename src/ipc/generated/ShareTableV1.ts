@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A table served by a share. Masking rules of the table always apply.
+ */
+export type ShareTableV1 = { tableId: string, projection?: Array<string>, 
+/**
+ * Only rows matching this filter are visible through the share.
+ */
+filter?: string, };
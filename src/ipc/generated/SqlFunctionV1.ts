@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ValueCategoryV1 } from "./ValueCategoryV1";
+
+export type SqlFunctionV1 = { name: string, signature: string, description: string, 
+/**
+ * Categories of the first argument; empty when any column fits.
+ */
+appliesTo: Array<ValueCategoryV1>, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ShareTableV1 } from "./ShareTableV1";
+
+export type StartShareRequestV1 = { tables: Array<ShareTableV1>, 
+/**
+ * Defaults to one hour; at most one day.
+ */
+expiresInSeconds?: number, 
+/**
+ * A free port is picked when unset.
+ */
+port?: number, 
+/**
+ * Listen on the loopback interface only instead of the LAN.
+ */
+localOnly?: boolean, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type StatsHistoryRequestV1 = { tableId: string, 
+/**
+ * Only returns snapshots taken at or after this time, in Unix milliseconds.
+ */
+sinceMs?: number, };
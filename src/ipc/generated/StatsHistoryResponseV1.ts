@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TableStatsSnapshotV1 } from "./TableStatsSnapshotV1";
+
+export type StatsHistoryResponseV1 = { tableId: string, 
+/**
+ * Oldest first.
+ */
+snapshots: Array<TableStatsSnapshotV1>, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TableDescriptionV1 } from "./TableDescriptionV1";
+
+export type TableDescriptionResponseV1 = { tableId: string, metadata?: TableDescriptionV1, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Free-text documentation attached to a table by the user.
+ */
+export type TableDescriptionV1 = { description?: string, owner?: string, tags: { [key in string]: string }, 
+/**
+ * Set by the backend when the description is saved.
+ */
+updatedAtMs?: number, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Storage format recorded in the table's current manifest.
+ */
+export type TableFormatInfoV1 = { tableId: string, 
+/**
+ * Manifest (table) version the information was read from.
+ */
+version: number | string, fileFormat: string, 
+/**
+ * Lance data storage version, such as `2.0`; `0.1` is the legacy format.
+ */
+storageVersion: string, latestStorageVersion: string, upgradeAvailable: boolean, stableRowIds: boolean, writerLibrary?: string, writerVersion?: string, };
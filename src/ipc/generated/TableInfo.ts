@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TableDescriptionV1 } from "./TableDescriptionV1";
+
+export type TableInfo = { name: string, description?: TableDescriptionV1, rowCount?: number | string | null, 
+/**
+ * `row_count` is the last cached count rather than one read for this listing,
+ * and may lag behind writes made outside the app.
+ */
+rowCountCached?: boolean, 
+/**
+ * Latest version of the table.
+ */
+version?: number | string | null, 
+/**
+ * Commit time of the latest version, in Unix milliseconds.
+ */
+lastModifiedMs?: number, 
+/**
+ * Bytes of data files referenced by the latest version.
+ */
+sizeBytes?: number, };
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BackendKind } from "./BackendKind";
+import type { DatasetDirectoryV1 } from "./DatasetDirectoryV1";
+
+/**
+ * Where a table's dataset is stored.
+ */
+export type TableLocationV1 = { tableId: string, name: string, datasetUri: string, backendKind: BackendKind, 
+/**
+ * Absolute path of the dataset directory on local backends, for opening it in a
+ * file manager.
+ */
+localPath?: string, directories: Array<DatasetDirectoryV1>, };
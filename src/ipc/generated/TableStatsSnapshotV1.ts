@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Table statistics recorded at one point in time.
+ */
+export type TableStatsSnapshotV1 = { 
+/**
+ * Unix milliseconds.
+ */
+takenAtMs: number, rowCount: number | string, sizeBytes: number, version: number | string, };
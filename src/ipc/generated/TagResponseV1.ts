@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { VersionTagV1 } from "./VersionTagV1";
+
+export type TagResponseV1 = { tableId: string, tag: VersionTagV1, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Offsets in UTF-16 code units, as JavaScript indexes strings.
+ */
+export type TextRangeV1 = { start: number, end: number, };
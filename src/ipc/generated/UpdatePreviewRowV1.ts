@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One sampled row before and after the update. Values are cast to the column type
+ * the same way `update_rows_v1` writes them.
+ */
+export type UpdatePreviewRowV1 = { before: Record<string, unknown>, after: Record<string, unknown>, };
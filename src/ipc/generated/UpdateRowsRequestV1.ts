@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { UpdateColumnInputV1 } from "./UpdateColumnInputV1";
+
+export type UpdateRowsRequestV1 = { tableId: string, filter?: string, updates: Array<UpdateColumnInputV1>, allowFullTable?: boolean, 
+/**
+ * Appended to the automatic checkpoint tag of this change; only ASCII letters
+ * and digits are kept.
+ */
+checkpointNote?: string, };
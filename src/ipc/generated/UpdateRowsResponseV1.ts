@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UpdateRowsResponseV1 = { tableId: string, rowsUpdated: number | string, version: number | string, };
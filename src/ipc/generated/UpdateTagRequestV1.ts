@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Points an existing tag at another version.
+ */
+export type UpdateTagRequestV1 = { tableId: string, name: string, version: number | string, };
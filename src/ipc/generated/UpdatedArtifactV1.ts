@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SavedArtifactKindV1 } from "./SavedArtifactKindV1";
+
+/**
+ * A saved setting `alter_columns_v1` rewrote to follow a renamed column.
+ */
+export type UpdatedArtifactV1 = { kind: SavedArtifactKindV1, 
+/**
+ * Template name, the filter as rewritten, or the column of a masking or
+ * encryption rule. Per-table settings carry the table name.
+ */
+name: string, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MessageV1 } from "./MessageV1";
+
+/**
+ * Whether a filter plans against the table, checked without reading rows.
+ */
+export type ValidateFilterResponseV1 = { tableId: string, valid: boolean, 
+/**
+ * The planner's error when the filter is invalid.
+ */
+error?: string, 
+/**
+ * How to filter on a vector column the invalid filter references.
+ */
+hint?: MessageV1, };
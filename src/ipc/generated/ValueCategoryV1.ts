@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Groups column types by the operators and functions that apply to them.
+ */
+export type ValueCategoryV1 = "numeric" | "string" | "boolean" | "temporal" | "binary" | "list" | "vector" | "struct" | "other";
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IndexTypeV1 } from "./IndexTypeV1";
+
+/**
+ * ANN parameters a vector or hybrid search ran with. When the request omitted
+ * `nprobes` or `refineFactor`, the values are derived from the vector index and the
+ * matching `*Defaulted` flag is set.
+ */
+export type VectorSearchParamsV1 = { indexName?: string, indexType?: IndexTypeV1, 
+/**
+ * Estimated from the number of indexed rows; lance does not report the
+ * partition count of an index.
+ */
+estimatedPartitions?: number, nprobes?: number, refineFactor?: number, nprobesDefaulted: boolean, refineFactorDefaulted: boolean, };
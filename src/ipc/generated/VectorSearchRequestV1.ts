@@ -0,0 +1,28 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DistanceTypeV1 } from "./DistanceTypeV1";
+import type { EmbeddingModelV1 } from "./EmbeddingModelV1";
+import type { JsonOptionsV1 } from "./JsonOptionsV1";
+
+export type VectorSearchRequestV1 = { tableId: string, vector: Array<number>, column?: string, topK?: number, projection?: Array<string>, 
+/**
+ * Name of a saved projection preset of the table, used instead of `projection`.
+ */
+projectionPreset?: string, filter?: string, nprobes?: number, refineFactor?: number, 
+/**
+ * Overrides the metric of the vector index, or L2 for a flat search.
+ */
+distanceType?: DistanceTypeV1, offset?: number, 
+/**
+ * Searches this table version instead of the latest one.
+ */
+asOfVersion?: number | string | null, includeStats?: boolean, jsonOptions?: JsonOptionsV1, 
+/**
+ * Model that embedded the query vector; a mismatch with the column's model is
+ * reported as a warning.
+ */
+queryEmbedding?: EmbeddingModelV1, 
+/**
+ * L2-normalizes the query vector before searching. Without it, a query that is
+ * not unit length against a normalized column is reported as a warning.
+ */
+normalizeQuery?: boolean, };
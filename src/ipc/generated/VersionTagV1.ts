@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A name pointing at a table version. Lance keeps tagged versions when old
+ * versions are cleaned up.
+ */
+export type VersionTagV1 = { name: string, version: number | string, 
+/**
+ * Checkpoint note of an automatic tag, as written; the name only carries its
+ * ASCII slug.
+ */
+note?: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Emitted as the `watch-folder-ingested` event for every file a watch folder
+ * imports or fails to import.
+ */
+export type WatchFolderEventV1 = { watchFolderId: string, tableName: string, fileName: string, rows: number, error?: string, ingestedAtMs: number, };
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DataFileFormatV1 } from "./DataFileFormatV1";
+
+/**
+ * A directory whose new files are appended to a table in the background. Files are
+ * imported once each, keyed by file name.
+ */
+export type WatchFolderV1 = { id: string, connectionUri: string, tableName: string, directory: string, format: DataFileFormatV1, hasHeader?: boolean, delimiter?: string, 
+/**
+ * As in `import_data_v1`.
+ */
+lineage: boolean, enabled: boolean, 
+/**
+ * Names of the files already imported or skipped, including those present when
+ * the folder was set up without `includeExisting`.
+ */
+seenFiles: Array<string>, lastIngestedAtMs?: number, lastError?: string, };
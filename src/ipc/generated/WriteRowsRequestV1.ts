@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WriteDataMode } from "./WriteDataMode";
+
+export type WriteRowsRequestV1 = { tableId: string, rows: unknown[], mode?: WriteDataMode, 
+/**
+ * L2-normalizes every float vector column and records it in the column
+ * metadata. Columns that already record it are normalized either way.
+ */
+normalizeVectors?: boolean, 
+/**
+ * Appended to the automatic checkpoint tag of this change; only ASCII letters
+ * and digits are kept.
+ */
+checkpointNote?: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type WriteRowsResponseV1 = { tableId: string, rows: number, version: number | string, 
+/**
+ * Vector columns whose values were L2-normalized.
+ */
+normalizedColumns?: Array<string>, };
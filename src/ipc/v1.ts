@@ -1,476 +1,333 @@
-export type ApiVersion = "v1"
-
-export type ErrorCode = "invalid_argument" | "not_found" | "internal" | "not_implemented"
-
-export interface ErrorEnvelope {
-	code: ErrorCode
-	message: string
-	details?: unknown
-}
-
-export interface ResultEnvelope<T> {
-	apiVersion: ApiVersion
-	ok: boolean
-	data?: T
-	error?: ErrorEnvelope
-}
-
-export type DataFormat = "json" | "arrow"
-
-export type DataFileFormatV1 = "csv" | "parquet" | "jsonl"
-
-export type WriteDataMode = "append" | "overwrite"
-
-export type IndexTypeV1 =
-	| "auto"
-	| "btree"
-	| "bitmap"
-	| "label_list"
-	| "fts"
-	| "ivf_flat"
-	| "ivf_sq"
-	| "ivf_pq"
-	| "ivf_rq"
-	| "ivf_hnsw_pq"
-	| "ivf_hnsw_sq"
-
-export type DistanceTypeV1 = "l2" | "cosine" | "dot" | "hamming"
-
-export type AuthDescriptor =
-	| { type: "none" }
-	| { type: "inline"; provider: string; params: Record<string, string> }
-	| { type: "secret_ref"; provider: string; reference: string }
-
-export interface ConnectOptions {
-	readConsistencyIntervalSeconds?: number
-}
-
-export interface ConnectProfile {
-	name: string
-	uri: string
-	storageOptions?: Record<string, string>
-	options?: ConnectOptions
-	auth?: AuthDescriptor
-}
-
-export type BackendKind = "local" | "s3" | "gcs" | "azure" | "remote" | "unknown"
-
-export interface ConnectResponseV1 {
-	connectionId: string
-	backendKind: BackendKind
-	name: string
-	uri: string
-}
-
-export interface DisconnectRequestV1 {
-	connectionId: string
-}
-
-export interface DisconnectResponseV1 {
-	connectionId: string
-	releasedTables: number
-}
-
-export interface TableInfo {
-	name: string
-}
-
-export interface ListTablesResponseV1 {
-	tables: TableInfo[]
-}
-
-export interface DropTableRequestV1 {
-	connectionId: string
-	tableName: string
-	namespace?: string[]
-}
-
-export interface DropTableResponseV1 {
-	tableName: string
-}
-
-export interface RenameTableRequestV1 {
-	connectionId: string
-	tableName: string
-	newTableName: string
-	namespace?: string[]
-	newNamespace?: string[]
-}
-
-export interface RenameTableResponseV1 {
-	tableName: string
-	newTableName: string
-}
-
-export interface ListIndexesRequestV1 {
-	tableId: string
-}
-
-export interface IndexDefinitionV1 {
-	name: string
-	indexType: IndexTypeV1
-	columns: string[]
-	numIndexedRows?: number
-	numUnindexedRows?: number
-	distanceType?: DistanceTypeV1
-	numIndices?: number
-	loss?: number
-}
-
-export interface ListIndexesResponseV1 {
-	indexes: IndexDefinitionV1[]
-}
-
-export interface CreateIndexRequestV1 {
-	tableId: string
-	columns: string[]
-	indexType: IndexTypeV1
-	name?: string
-	replace?: boolean
-	distanceType?: DistanceTypeV1
-	numPartitions?: number
-	sampleRate?: number
-	maxIterations?: number
-	targetPartitionSize?: number
-	numSubVectors?: number
-	numBits?: number
-	numEdges?: number
-	efConstruction?: number
-}
-
-export interface CreateIndexResponseV1 {
-	tableId: string
-	indexType: IndexTypeV1
-	columns: string[]
-	name?: string
-}
-
-export interface DropIndexRequestV1 {
-	tableId: string
-	indexName: string
-}
-
-export interface DropIndexResponseV1 {
-	tableId: string
-	indexName: string
-}
-
-export interface TableHandle {
-	tableId: string
-	name: string
-}
-
-export interface SchemaField {
-	name: string
-	dataType: string
-	nullable: boolean
-	metadata?: Record<string, string>
-}
-
-export type FieldDataType =
-	| "int8"
-	| "int16"
-	| "int32"
-	| "int64"
-	| "uint8"
-	| "uint16"
-	| "uint32"
-	| "uint64"
-	| "float32"
-	| "float64"
-	| "boolean"
-	| "utf8"
-	| "large_utf8"
-	| "binary"
-	| "large_binary"
-	| "fixed_size_list_float32"
-
-export interface SchemaFieldInput {
-	name: string
-	dataType: FieldDataType
-	nullable: boolean
-	metadata?: Record<string, string>
-	vectorLength?: number
-}
-
-export interface SchemaDefinitionInput {
-	fields: SchemaFieldInput[]
-}
-
-export interface SchemaDefinition {
-	fields: SchemaField[]
-}
-
-export interface CreateTableRequestV1 {
-	connectionId: string
-	tableName: string
-	schema: SchemaDefinitionInput
-}
-
-export interface CreateTableResponseV1 {
-	tableId: string
-	name: string
-}
-
-export interface AddColumnsRequestV1 {
-	tableId: string
-	columns: SchemaDefinitionInput
-}
-
-export interface AddColumnsResponseV1 {
-	tableId: string
-	added: string[]
-	schema: SchemaDefinition
-}
-
-export interface ColumnAlterationInput {
-	path: string
-	rename?: string
-	nullable?: boolean
-	dataType?: FieldDataType
-	vectorLength?: number
-}
-
-export interface AlterColumnsRequestV1 {
-	tableId: string
-	columns: ColumnAlterationInput[]
-}
-
-export interface AlterColumnsResponseV1 {
-	tableId: string
-	updated: string[]
-	schema: SchemaDefinition
-}
-
-export interface DropColumnsRequestV1 {
-	tableId: string
-	columns: string[]
-}
-
-export interface DropColumnsResponseV1 {
-	tableId: string
-	dropped: string[]
-	schema: SchemaDefinition
-}
-
-export interface ScanRequestV1 {
-	tableId: string
-	format?: DataFormat
-	projection?: string[]
-	filter?: string
-	limit?: number
-	offset?: number
-}
-
-export interface WriteRowsRequestV1 {
-	tableId: string
-	rows: unknown[]
-	mode?: WriteDataMode
-}
-
-export interface WriteRowsResponseV1 {
-	tableId: string
-	rows: number
-	version: number
-}
-
-export interface UpdateColumnInputV1 {
-	column: string
-	expr: string
-}
-
-export interface UpdateRowsRequestV1 {
-	tableId: string
-	filter?: string
-	updates: UpdateColumnInputV1[]
-	allowFullTable?: boolean
-}
-
-export interface UpdateRowsResponseV1 {
-	tableId: string
-	rowsUpdated: number
-	version: number
-}
-
-export interface DeleteRowsRequestV1 {
-	tableId: string
-	filter: string
-	allowFullTable?: boolean
-}
-
-export interface DeleteRowsResponseV1 {
-	tableId: string
-	version: number
-}
-
-export interface ImportDataRequestV1 {
-	tableId: string
-	path: string
-	format: DataFileFormatV1
-	mode?: WriteDataMode
-	hasHeader?: boolean
-	delimiter?: string
-}
-
-export interface ImportDataResponseV1 {
-	tableId: string
-	rows: number
-}
-
-export interface ExportDataRequestV1 {
-	tableId: string
-	path: string
-	format: DataFileFormatV1
-	projection?: string[]
-	filter?: string
-	limit?: number
-	offset?: number
-	delimiter?: string
-	withHeader?: boolean
-}
-
-export interface ExportDataResponseV1 {
-	path: string
-	rows: number
-}
-
-export type OptimizeActionV1 = "compact" | "vacuum"
-
-export interface OptimizeTableRequestV1 {
-	tableId: string
-	action: OptimizeActionV1
-	targetRowsPerFragment?: number
-	olderThanDays?: number
-	deleteUnverified?: boolean
-	errorIfTaggedOldVersions?: boolean
-}
-
-export interface OptimizeTableResponseV1 {
-	tableId: string
-	action: OptimizeActionV1
-	summary: string
-}
-
-export type DataChunk =
-	| {
-			format: "json"
-			rows: unknown[]
-			schema: SchemaDefinition
-			offset: number
-			limit: number
-	  }
-	| {
-			format: "arrow"
-			ipcBase64: string
-			compression?: string
-	  }
-
-export interface ScanResponseV1 {
-	chunk: DataChunk
-	nextOffset?: number
-}
-
-export interface VersionInfoV1 {
-	version: number
-	timestamp: string
-	metadata: Record<string, string>
-}
-
-export interface ListVersionsRequestV1 {
-	tableId: string
-}
-
-export interface ListVersionsResponseV1 {
-	versions: VersionInfoV1[]
-}
-
-export interface GetTableVersionRequestV1 {
-	tableId: string
-}
-
-export interface GetTableVersionResponseV1 {
-	tableId: string
-	version: number
-}
-
-export interface CheckoutTableVersionRequestV1 {
-	tableId: string
-	version: number
-}
-
-export interface CheckoutTableVersionResponseV1 {
-	tableId: string
-	version: number
-}
-
-export interface CheckoutTableLatestRequestV1 {
-	tableId: string
-}
-
-export interface CheckoutTableLatestResponseV1 {
-	tableId: string
-	version: number
-}
-
-export interface CloneTableRequestV1 {
-	connectionId: string
-	tableId: string
-	targetTableName: string
-	sourceVersion?: number
-	sourceTag?: string
-	isShallow?: boolean
-}
-
-export interface CloneTableResponseV1 {
-	tableId: string
-	name: string
-}
-
-export interface CombinedSearchRequestV1 {
-	tableId: string
-	vector?: number[]
-	vectorColumn?: string
-	query?: string
-	columns?: string[]
-	projection?: string[]
-	filter?: string
-	limit?: number
-	offset?: number
-	nprobes?: number
-	refineFactor?: number
-}
-
-export interface VectorSearchRequestV1 {
-	tableId: string
-	vector: number[]
-	column?: string
-	topK?: number
-	projection?: string[]
-	filter?: string
-	nprobes?: number
-	refineFactor?: number
-	offset?: number
-}
-
-export interface FtsSearchRequestV1 {
-	tableId: string
-	query: string
-	columns?: string[]
-	limit?: number
-	offset?: number
-	projection?: string[]
-	filter?: string
-}
-
-export interface QueryFilterRequestV1 {
-	tableId: string
-	filter: string
-	projection?: string[]
-	limit?: number
-	offset?: number
-}
-
-export interface QueryResponseV1 {
-	chunk: DataChunk
-	nextOffset?: number
-}
+// Types of the v1 IPC. Everything except `U64` is generated from the
+// `lancedb-viewer-ipc` crate by `bun run ipc:bindings` into `./generated`; change
+// the Rust types and regenerate instead of editing them here.
+
+/**
+ * A `u64` version or count. Values past `Number.MAX_SAFE_INTEGER` arrive as decimal
+ * strings (`json_u64` in the IPC crate); read them through `src/lib/u64.ts`.
+ */
+export type U64 = number | string
+
+export type { AccessLogEntryV1 } from "./generated/AccessLogEntryV1"
+export type { AccessTransportV1 } from "./generated/AccessTransportV1"
+export type { AckScanStreamRequestV1 } from "./generated/AckScanStreamRequestV1"
+export type { ActiveQueryV1 } from "./generated/ActiveQueryV1"
+export type { AddColumnsRequestV1 } from "./generated/AddColumnsRequestV1"
+export type { AddColumnsResponseV1 } from "./generated/AddColumnsResponseV1"
+export type { AlterColumnsRequestV1 } from "./generated/AlterColumnsRequestV1"
+export type { AlterColumnsResponseV1 } from "./generated/AlterColumnsResponseV1"
+export type { ApiVersion } from "./generated/ApiVersion"
+export type { AppConfigV1 } from "./generated/AppConfigV1"
+export type { ArrowChunk } from "./generated/ArrowChunk"
+export type { AuthDescriptor } from "./generated/AuthDescriptor"
+export type { BackendKind } from "./generated/BackendKind"
+export type { BackupModeV1 } from "./generated/BackupModeV1"
+export type { BackupTableRequestV1 } from "./generated/BackupTableRequestV1"
+export type { BackupTableResponseV1 } from "./generated/BackupTableResponseV1"
+export type { BinaryFormatV1 } from "./generated/BinaryFormatV1"
+export type { BundleIndexV1 } from "./generated/BundleIndexV1"
+export type { BundleManifestV1 } from "./generated/BundleManifestV1"
+export type { BundleShardV1 } from "./generated/BundleShardV1"
+export type { CancelJobRequestV1 } from "./generated/CancelJobRequestV1"
+export type { CancelScanStreamRequestV1 } from "./generated/CancelScanStreamRequestV1"
+export type { CategoryOperatorsV1 } from "./generated/CategoryOperatorsV1"
+export type { ChangedRowV1 } from "./generated/ChangedRowV1"
+export type { CheckExportTargetRequestV1 } from "./generated/CheckExportTargetRequestV1"
+export type { CheckExportTargetResponseV1 } from "./generated/CheckExportTargetResponseV1"
+export type { CheckoutTableLatestRequestV1 } from "./generated/CheckoutTableLatestRequestV1"
+export type { CheckoutTableLatestResponseV1 } from "./generated/CheckoutTableLatestResponseV1"
+export type { CheckoutTableVersionRequestV1 } from "./generated/CheckoutTableVersionRequestV1"
+export type { CheckoutTableVersionResponseV1 } from "./generated/CheckoutTableVersionResponseV1"
+export type { ChunkSplitV1 } from "./generated/ChunkSplitV1"
+export type { CloneTableRequestV1 } from "./generated/CloneTableRequestV1"
+export type { CloneTableResponseV1 } from "./generated/CloneTableResponseV1"
+export type { ColumnAlterationInput } from "./generated/ColumnAlterationInput"
+export type { ColumnTypeMismatchV1 } from "./generated/ColumnTypeMismatchV1"
+export type { CombinedSearchRequestV1 } from "./generated/CombinedSearchRequestV1"
+export type { CommandMemoryV1 } from "./generated/CommandMemoryV1"
+export type { CommandMetricsV1 } from "./generated/CommandMetricsV1"
+export type { CompareQueryVersionsRequestV1 } from "./generated/CompareQueryVersionsRequestV1"
+export type { CompareQueryVersionsResponseV1 } from "./generated/CompareQueryVersionsResponseV1"
+export type { CompletionColumnV1 } from "./generated/CompletionColumnV1"
+export type { CompletionMetadataRequestV1 } from "./generated/CompletionMetadataRequestV1"
+export type { CompletionMetadataResponseV1 } from "./generated/CompletionMetadataResponseV1"
+export type { ConfigResponseV1 } from "./generated/ConfigResponseV1"
+export type { ConnectOptions } from "./generated/ConnectOptions"
+export type { ConnectProfile } from "./generated/ConnectProfile"
+export type { ConnectRequestV1 } from "./generated/ConnectRequestV1"
+export type { ConnectResponseV1 } from "./generated/ConnectResponseV1"
+export type { ConnectionHealthV1 } from "./generated/ConnectionHealthV1"
+export type { ConnectionOverviewV1 } from "./generated/ConnectionOverviewV1"
+export type { CreateIndexRequestV1 } from "./generated/CreateIndexRequestV1"
+export type { CreateIndexResponseV1 } from "./generated/CreateIndexResponseV1"
+export type { CreateTableOptionsV1 } from "./generated/CreateTableOptionsV1"
+export type { CreateTableRequestV1 } from "./generated/CreateTableRequestV1"
+export type { CreateTableResponseV1 } from "./generated/CreateTableResponseV1"
+export type { CreateTagRequestV1 } from "./generated/CreateTagRequestV1"
+export type { CrossEncoderRerankerV1 } from "./generated/CrossEncoderRerankerV1"
+export type { DataChunk } from "./generated/DataChunk"
+export type { DataFileFormatV1 } from "./generated/DataFileFormatV1"
+export type { DataFormat } from "./generated/DataFormat"
+export type { DataTypeDescriptorV1 } from "./generated/DataTypeDescriptorV1"
+export type { DatasetDirectoryKindV1 } from "./generated/DatasetDirectoryKindV1"
+export type { DatasetDirectoryV1 } from "./generated/DatasetDirectoryV1"
+export type { DeleteExportTemplateRequestV1 } from "./generated/DeleteExportTemplateRequestV1"
+export type { DeleteExportTemplateResponseV1 } from "./generated/DeleteExportTemplateResponseV1"
+export type { DeleteProjectionPresetRequestV1 } from "./generated/DeleteProjectionPresetRequestV1"
+export type { DeleteProjectionPresetResponseV1 } from "./generated/DeleteProjectionPresetResponseV1"
+export type { DeleteRowsRequestV1 } from "./generated/DeleteRowsRequestV1"
+export type { DeleteRowsResponseV1 } from "./generated/DeleteRowsResponseV1"
+export type { DeleteScheduleRequestV1 } from "./generated/DeleteScheduleRequestV1"
+export type { DeleteScheduleResponseV1 } from "./generated/DeleteScheduleResponseV1"
+export type { DeleteTagRequestV1 } from "./generated/DeleteTagRequestV1"
+export type { DeleteTagResponseV1 } from "./generated/DeleteTagResponseV1"
+export type { DeleteWatchFolderRequestV1 } from "./generated/DeleteWatchFolderRequestV1"
+export type { DeleteWatchFolderResponseV1 } from "./generated/DeleteWatchFolderResponseV1"
+export type { DiagnosticsConnectionV1 } from "./generated/DiagnosticsConnectionV1"
+export type { DiagnosticsReportV1 } from "./generated/DiagnosticsReportV1"
+export type { DisconnectRequestV1 } from "./generated/DisconnectRequestV1"
+export type { DisconnectResponseV1 } from "./generated/DisconnectResponseV1"
+export type { DiskSpaceShortageV1 } from "./generated/DiskSpaceShortageV1"
+export type { DistanceTypeV1 } from "./generated/DistanceTypeV1"
+export type { DropColumnsRequestV1 } from "./generated/DropColumnsRequestV1"
+export type { DropColumnsResponseV1 } from "./generated/DropColumnsResponseV1"
+export type { DropIndexRequestV1 } from "./generated/DropIndexRequestV1"
+export type { DropIndexResponseV1 } from "./generated/DropIndexResponseV1"
+export type { DropTableRequestV1 } from "./generated/DropTableRequestV1"
+export type { DropTableResponseV1 } from "./generated/DropTableResponseV1"
+export type { EmbeddingModelV1 } from "./generated/EmbeddingModelV1"
+export type { EmbeddingProviderV1 } from "./generated/EmbeddingProviderV1"
+export type { EncryptedColumnV1 } from "./generated/EncryptedColumnV1"
+export type { ErrorCode } from "./generated/ErrorCode"
+export type { ErrorEnvelope } from "./generated/ErrorEnvelope"
+export type { EstimateEmbeddingJobRequestV1 } from "./generated/EstimateEmbeddingJobRequestV1"
+export type { EstimateEmbeddingJobResponseV1 } from "./generated/EstimateEmbeddingJobResponseV1"
+export type { EstimateQueryRequestV1 } from "./generated/EstimateQueryRequestV1"
+export type { EstimateQueryResponseV1 } from "./generated/EstimateQueryResponseV1"
+export type { EvaluateRecallRequestV1 } from "./generated/EvaluateRecallRequestV1"
+export type { EvaluateRecallResponseV1 } from "./generated/EvaluateRecallResponseV1"
+export type { ExportBundleRequestV1 } from "./generated/ExportBundleRequestV1"
+export type { ExportBundleResponseV1 } from "./generated/ExportBundleResponseV1"
+export type { ExportDataRequestV1 } from "./generated/ExportDataRequestV1"
+export type { ExportDataResponseV1 } from "./generated/ExportDataResponseV1"
+export type { ExportDiagnosticsRequestV1 } from "./generated/ExportDiagnosticsRequestV1"
+export type { ExportDiagnosticsResponseV1 } from "./generated/ExportDiagnosticsResponseV1"
+export type { ExportReproSampleRequestV1 } from "./generated/ExportReproSampleRequestV1"
+export type { ExportReproSampleResponseV1 } from "./generated/ExportReproSampleResponseV1"
+export type { ExportRowsRequestV1 } from "./generated/ExportRowsRequestV1"
+export type { ExportTemplateV1 } from "./generated/ExportTemplateV1"
+export type { ExpressionRerankerV1 } from "./generated/ExpressionRerankerV1"
+export type { ExtensionV1 } from "./generated/ExtensionV1"
+export type { FieldDataType } from "./generated/FieldDataType"
+export type { FtsColumnsNotIndexedV1 } from "./generated/FtsColumnsNotIndexedV1"
+export type { FtsHighlightV1 } from "./generated/FtsHighlightV1"
+export type { FtsLanguageV1 } from "./generated/FtsLanguageV1"
+export type { FtsSearchRequestV1 } from "./generated/FtsSearchRequestV1"
+export type { FtsTokenizerOptionsV1 } from "./generated/FtsTokenizerOptionsV1"
+export type { GeometryEncodingV1 } from "./generated/GeometryEncodingV1"
+export type { GetAccessLogRequestV1 } from "./generated/GetAccessLogRequestV1"
+export type { GetAccessLogResponseV1 } from "./generated/GetAccessLogResponseV1"
+export type { GetJobStatusRequestV1 } from "./generated/GetJobStatusRequestV1"
+export type { GetRetentionPolicyRequestV1 } from "./generated/GetRetentionPolicyRequestV1"
+export type { GetRowCountRequestV1 } from "./generated/GetRowCountRequestV1"
+export type { GetSchemaRequestV1 } from "./generated/GetSchemaRequestV1"
+export type { GetSearchDefaultsRequestV1 } from "./generated/GetSearchDefaultsRequestV1"
+export type { GetTableDescriptionRequestV1 } from "./generated/GetTableDescriptionRequestV1"
+export type { GetTableVersionRequestV1 } from "./generated/GetTableVersionRequestV1"
+export type { GetTableVersionResponseV1 } from "./generated/GetTableVersionResponseV1"
+export type { HealthResponseV1 } from "./generated/HealthResponseV1"
+export type { ImportBundleRequestV1 } from "./generated/ImportBundleRequestV1"
+export type { ImportBundleResponseV1 } from "./generated/ImportBundleResponseV1"
+export type { ImportDataRequestV1 } from "./generated/ImportDataRequestV1"
+export type { ImportDataResponseV1 } from "./generated/ImportDataResponseV1"
+export type { IndexAcceleratorV1 } from "./generated/IndexAcceleratorV1"
+export type { IndexCapabilitiesResponseV1 } from "./generated/IndexCapabilitiesResponseV1"
+export type { IndexDefinitionV1 } from "./generated/IndexDefinitionV1"
+export type { IndexPresetParametersV1 } from "./generated/IndexPresetParametersV1"
+export type { IndexPresetV1 } from "./generated/IndexPresetV1"
+export type { IndexReadinessV1 } from "./generated/IndexReadinessV1"
+export type { IndexRebuildReasonV1 } from "./generated/IndexRebuildReasonV1"
+export type { IndexRebuildStateV1 } from "./generated/IndexRebuildStateV1"
+export type { IndexRebuildV1 } from "./generated/IndexRebuildV1"
+export type { IndexStatsRequestV1 } from "./generated/IndexStatsRequestV1"
+export type { IndexStatsResponseV1 } from "./generated/IndexStatsResponseV1"
+export type { IndexTypeV1 } from "./generated/IndexTypeV1"
+export type { JobKindV1 } from "./generated/JobKindV1"
+export type { JobStateV1 } from "./generated/JobStateV1"
+export type { JobStatusV1 } from "./generated/JobStatusV1"
+export type { JsonChunk } from "./generated/JsonChunk"
+export type { JsonOptionsV1 } from "./generated/JsonOptionsV1"
+export type { KillQueryRequestV1 } from "./generated/KillQueryRequestV1"
+export type { LargeIntegerFormatV1 } from "./generated/LargeIntegerFormatV1"
+export type { ListActiveQueriesResponseV1 } from "./generated/ListActiveQueriesResponseV1"
+export type { ListEncryptedColumnsRequestV1 } from "./generated/ListEncryptedColumnsRequestV1"
+export type { ListEncryptedColumnsResponseV1 } from "./generated/ListEncryptedColumnsResponseV1"
+export type { ListExportTemplatesResponseV1 } from "./generated/ListExportTemplatesResponseV1"
+export type { ListExtensionsResponseV1 } from "./generated/ListExtensionsResponseV1"
+export type { ListIndexesRequestV1 } from "./generated/ListIndexesRequestV1"
+export type { ListIndexesResponseV1 } from "./generated/ListIndexesResponseV1"
+export type { ListJobsRequestV1 } from "./generated/ListJobsRequestV1"
+export type { ListJobsResponseV1 } from "./generated/ListJobsResponseV1"
+export type { ListMaskingRulesRequestV1 } from "./generated/ListMaskingRulesRequestV1"
+export type { ListMaskingRulesResponseV1 } from "./generated/ListMaskingRulesResponseV1"
+export type { ListProjectionPresetsRequestV1 } from "./generated/ListProjectionPresetsRequestV1"
+export type { ListProjectionPresetsResponseV1 } from "./generated/ListProjectionPresetsResponseV1"
+export type { ListSchedulesRequestV1 } from "./generated/ListSchedulesRequestV1"
+export type { ListSchedulesResponseV1 } from "./generated/ListSchedulesResponseV1"
+export type { ListSharesResponseV1 } from "./generated/ListSharesResponseV1"
+export type { ListTablesRequestV1 } from "./generated/ListTablesRequestV1"
+export type { ListTablesResponseV1 } from "./generated/ListTablesResponseV1"
+export type { ListTagsRequestV1 } from "./generated/ListTagsRequestV1"
+export type { ListTagsResponseV1 } from "./generated/ListTagsResponseV1"
+export type { ListVersionsRequestV1 } from "./generated/ListVersionsRequestV1"
+export type { ListVersionsResponseV1 } from "./generated/ListVersionsResponseV1"
+export type { ListWatchFoldersRequestV1 } from "./generated/ListWatchFoldersRequestV1"
+export type { ListWatchFoldersResponseV1 } from "./generated/ListWatchFoldersResponseV1"
+export type { LocaleV1 } from "./generated/LocaleV1"
+export type { LogLevelV1 } from "./generated/LogLevelV1"
+export type { LogSettingsV1 } from "./generated/LogSettingsV1"
+export type { MaskingRuleV1 } from "./generated/MaskingRuleV1"
+export type { MaskingStrategyV1 } from "./generated/MaskingStrategyV1"
+export type { MaterializeQueryRequestV1 } from "./generated/MaterializeQueryRequestV1"
+export type { MaterializeQueryResponseV1 } from "./generated/MaterializeQueryResponseV1"
+export type { MaterializeSourceV1 } from "./generated/MaterializeSourceV1"
+export type { MemoryStatsResponseV1 } from "./generated/MemoryStatsResponseV1"
+export type { MessageCodeV1 } from "./generated/MessageCodeV1"
+export type { MessageV1 } from "./generated/MessageV1"
+export type { MigrateTableFormatRequestV1 } from "./generated/MigrateTableFormatRequestV1"
+export type { MigrateTableFormatResponseV1 } from "./generated/MigrateTableFormatResponseV1"
+export type { MissingFtsIndexV1 } from "./generated/MissingFtsIndexV1"
+export type { NonFiniteFloatFormatV1 } from "./generated/NonFiniteFloatFormatV1"
+export type { ObjectStoreTuningV1 } from "./generated/ObjectStoreTuningV1"
+export type { OpenDatasetRequestV1 } from "./generated/OpenDatasetRequestV1"
+export type { OpenDatasetResponseV1 } from "./generated/OpenDatasetResponseV1"
+export type { OpenTableRequestV1 } from "./generated/OpenTableRequestV1"
+export type { OpenTableResultV1 } from "./generated/OpenTableResultV1"
+export type { OpenTablesRequestV1 } from "./generated/OpenTablesRequestV1"
+export type { OpenTablesResponseV1 } from "./generated/OpenTablesResponseV1"
+export type { OptimizeActionV1 } from "./generated/OptimizeActionV1"
+export type { OptimizeStatsV1 } from "./generated/OptimizeStatsV1"
+export type { OptimizeTableRequestV1 } from "./generated/OptimizeTableRequestV1"
+export type { OptimizeTableResponseV1 } from "./generated/OptimizeTableResponseV1"
+export type { OptimizedIndexV1 } from "./generated/OptimizedIndexV1"
+export type { OverviewRequestV1 } from "./generated/OverviewRequestV1"
+export type { OverviewResponseV1 } from "./generated/OverviewResponseV1"
+export type { PivotAggregationV1 } from "./generated/PivotAggregationV1"
+export type { PivotRequestV1 } from "./generated/PivotRequestV1"
+export type { PivotResponseV1 } from "./generated/PivotResponseV1"
+export type { PivotRowV1 } from "./generated/PivotRowV1"
+export type { PlanStageV1 } from "./generated/PlanStageV1"
+export type { PreviewUpdateRequestV1 } from "./generated/PreviewUpdateRequestV1"
+export type { PreviewUpdateResponseV1 } from "./generated/PreviewUpdateResponseV1"
+export type { ProgressEventV1 } from "./generated/ProgressEventV1"
+export type { ProgressStageV1 } from "./generated/ProgressStageV1"
+export type { ProjectionPresetV1 } from "./generated/ProjectionPresetV1"
+export type { QueryExecutionStatsV1 } from "./generated/QueryExecutionStatsV1"
+export type { QueryFilterRequestV1 } from "./generated/QueryFilterRequestV1"
+export type { QueryResponseV1 } from "./generated/QueryResponseV1"
+export type { RecallAtNprobesV1 } from "./generated/RecallAtNprobesV1"
+export type { RecentFilterSortV1 } from "./generated/RecentFilterSortV1"
+export type { RecentFilterV1 } from "./generated/RecentFilterV1"
+export type { RecentFiltersRequestV1 } from "./generated/RecentFiltersRequestV1"
+export type { RecentFiltersResponseV1 } from "./generated/RecentFiltersResponseV1"
+export type { RenameTableRequestV1 } from "./generated/RenameTableRequestV1"
+export type { RenameTableResponseV1 } from "./generated/RenameTableResponseV1"
+export type { ReorderColumnsRequestV1 } from "./generated/ReorderColumnsRequestV1"
+export type { ReorderColumnsResponseV1 } from "./generated/ReorderColumnsResponseV1"
+export type { ReproSampleManifestV1 } from "./generated/ReproSampleManifestV1"
+export type { RerankerV1 } from "./generated/RerankerV1"
+export type { RestoreBackupRequestV1 } from "./generated/RestoreBackupRequestV1"
+export type { RestoreBackupResponseV1 } from "./generated/RestoreBackupResponseV1"
+export type { RestoreTableVersionRequestV1 } from "./generated/RestoreTableVersionRequestV1"
+export type { RestoreTableVersionResponseV1 } from "./generated/RestoreTableVersionResponseV1"
+export type { ResultEnvelope } from "./generated/ResultEnvelope"
+export type { RetentionPolicyResponseV1 } from "./generated/RetentionPolicyResponseV1"
+export type { RetentionPolicyV1 } from "./generated/RetentionPolicyV1"
+export type { RoleV1 } from "./generated/RoleV1"
+export type { RotateSecretRequestV1 } from "./generated/RotateSecretRequestV1"
+export type { RotateSecretResponseV1 } from "./generated/RotateSecretResponseV1"
+export type { RowCountResponseV1 } from "./generated/RowCountResponseV1"
+export type { RowValidationErrorV1 } from "./generated/RowValidationErrorV1"
+export type { RrfRerankerV1 } from "./generated/RrfRerankerV1"
+export type { RunExportTemplateRequestV1 } from "./generated/RunExportTemplateRequestV1"
+export type { RunRetentionRequestV1 } from "./generated/RunRetentionRequestV1"
+export type { RunRetentionResponseV1 } from "./generated/RunRetentionResponseV1"
+export type { SaveExportTemplateRequestV1 } from "./generated/SaveExportTemplateRequestV1"
+export type { SaveExportTemplateResponseV1 } from "./generated/SaveExportTemplateResponseV1"
+export type { SaveProjectionPresetRequestV1 } from "./generated/SaveProjectionPresetRequestV1"
+export type { SaveProjectionPresetResponseV1 } from "./generated/SaveProjectionPresetResponseV1"
+export type { SavedArtifactKindV1 } from "./generated/SavedArtifactKindV1"
+export type { ScanRequestV1 } from "./generated/ScanRequestV1"
+export type { ScanResponseV1 } from "./generated/ScanResponseV1"
+export type { ScanStreamControlResponseV1 } from "./generated/ScanStreamControlResponseV1"
+export type { ScanStreamEventV1 } from "./generated/ScanStreamEventV1"
+export type { ScanStreamRequestV1 } from "./generated/ScanStreamRequestV1"
+export type { ScanStreamStartedV1 } from "./generated/ScanStreamStartedV1"
+export type { ScanStreamSummaryV1 } from "./generated/ScanStreamSummaryV1"
+export type { ScheduleActionV1 } from "./generated/ScheduleActionV1"
+export type { ScheduleRunStatusV1 } from "./generated/ScheduleRunStatusV1"
+export type { ScheduleV1 } from "./generated/ScheduleV1"
+export type { SchemaCompatibilityReportV1 } from "./generated/SchemaCompatibilityReportV1"
+export type { SchemaDefinition } from "./generated/SchemaDefinition"
+export type { SchemaDefinitionInput } from "./generated/SchemaDefinitionInput"
+export type { SchemaField } from "./generated/SchemaField"
+export type { SchemaFieldInput } from "./generated/SchemaFieldInput"
+export type { SearchDefaultsResponseV1 } from "./generated/SearchDefaultsResponseV1"
+export type { SearchDefaultsV1 } from "./generated/SearchDefaultsV1"
+export type { SearchIndexKindV1 } from "./generated/SearchIndexKindV1"
+export type { SearchWarningKindV1 } from "./generated/SearchWarningKindV1"
+export type { SearchWarningV1 } from "./generated/SearchWarningV1"
+export type { SetConfigRequestV1 } from "./generated/SetConfigRequestV1"
+export type { SetEncryptedColumnsRequestV1 } from "./generated/SetEncryptedColumnsRequestV1"
+export type { SetEncryptedColumnsResponseV1 } from "./generated/SetEncryptedColumnsResponseV1"
+export type { SetEncryptionKeyRequestV1 } from "./generated/SetEncryptionKeyRequestV1"
+export type { SetEncryptionKeyResponseV1 } from "./generated/SetEncryptionKeyResponseV1"
+export type { SetLogLevelRequestV1 } from "./generated/SetLogLevelRequestV1"
+export type { SetLogLevelResponseV1 } from "./generated/SetLogLevelResponseV1"
+export type { SetMaskingRulesRequestV1 } from "./generated/SetMaskingRulesRequestV1"
+export type { SetMaskingRulesResponseV1 } from "./generated/SetMaskingRulesResponseV1"
+export type { SetRetentionPolicyRequestV1 } from "./generated/SetRetentionPolicyRequestV1"
+export type { SetScheduleRequestV1 } from "./generated/SetScheduleRequestV1"
+export type { SetScheduleResponseV1 } from "./generated/SetScheduleResponseV1"
+export type { SetSearchDefaultsRequestV1 } from "./generated/SetSearchDefaultsRequestV1"
+export type { SetTableDescriptionRequestV1 } from "./generated/SetTableDescriptionRequestV1"
+export type { SetWatchFolderRequestV1 } from "./generated/SetWatchFolderRequestV1"
+export type { SetWatchFolderResponseV1 } from "./generated/SetWatchFolderResponseV1"
+export type { ShareSessionV1 } from "./generated/ShareSessionV1"
+export type { ShareTableV1 } from "./generated/ShareTableV1"
+export type { SqlFunctionV1 } from "./generated/SqlFunctionV1"
+export type { StartShareRequestV1 } from "./generated/StartShareRequestV1"
+export type { StatsHistoryRequestV1 } from "./generated/StatsHistoryRequestV1"
+export type { StatsHistoryResponseV1 } from "./generated/StatsHistoryResponseV1"
+export type { StopShareRequestV1 } from "./generated/StopShareRequestV1"
+export type { StopShareResponseV1 } from "./generated/StopShareResponseV1"
+export type { TableDescriptionResponseV1 } from "./generated/TableDescriptionResponseV1"
+export type { TableDescriptionV1 } from "./generated/TableDescriptionV1"
+export type { TableFormatInfoRequestV1 } from "./generated/TableFormatInfoRequestV1"
+export type { TableFormatInfoV1 } from "./generated/TableFormatInfoV1"
+export type { TableHandle } from "./generated/TableHandle"
+export type { TableInfo } from "./generated/TableInfo"
+export type { TableLocationRequestV1 } from "./generated/TableLocationRequestV1"
+export type { TableLocationV1 } from "./generated/TableLocationV1"
+export type { TableStatsSnapshotV1 } from "./generated/TableStatsSnapshotV1"
+export type { TagResponseV1 } from "./generated/TagResponseV1"
+export type { TextRangeV1 } from "./generated/TextRangeV1"
+export type { TimestampFormatV1 } from "./generated/TimestampFormatV1"
+export type { UpdateColumnInputV1 } from "./generated/UpdateColumnInputV1"
+export type { UpdatePreviewRowV1 } from "./generated/UpdatePreviewRowV1"
+export type { UpdateRowsRequestV1 } from "./generated/UpdateRowsRequestV1"
+export type { UpdateRowsResponseV1 } from "./generated/UpdateRowsResponseV1"
+export type { UpdateTagRequestV1 } from "./generated/UpdateTagRequestV1"
+export type { UpdatedArtifactV1 } from "./generated/UpdatedArtifactV1"
+export type { ValidateFilterRequestV1 } from "./generated/ValidateFilterRequestV1"
+export type { ValidateFilterResponseV1 } from "./generated/ValidateFilterResponseV1"
+export type { ValueCategoryV1 } from "./generated/ValueCategoryV1"
+export type { VectorSearchParamsV1 } from "./generated/VectorSearchParamsV1"
+export type { VectorSearchRequestV1 } from "./generated/VectorSearchRequestV1"
+export type { VersionInfoV1 } from "./generated/VersionInfoV1"
+export type { VersionTagV1 } from "./generated/VersionTagV1"
+export type { WatchFolderEventV1 } from "./generated/WatchFolderEventV1"
+export type { WatchFolderV1 } from "./generated/WatchFolderV1"
+export type { WriteDataMode } from "./generated/WriteDataMode"
+export type { WriteRowsRequestV1 } from "./generated/WriteRowsRequestV1"
+export type { WriteRowsResponseV1 } from "./generated/WriteRowsResponseV1"
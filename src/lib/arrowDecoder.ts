@@ -84,7 +84,12 @@ function normalizeArrowValue(value: unknown): unknown {
 }
 
 export function decodeArrowChunk(chunk: ArrowChunk): DecodedArrowChunk {
-	const table = tableFromIPC(base64ToBytes(chunk.ipcBase64))
+	return decodeArrowIpc(base64ToBytes(chunk.ipcBase64))
+}
+
+/** Decodes raw Arrow IPC bytes, such as the chunks of `scanStreamV1`. */
+export function decodeArrowIpc(bytes: Uint8Array): DecodedArrowChunk {
+	const table = tableFromIPC(bytes)
 	const schema: SchemaDefinition = {
 		fields: table.schema.fields.map((field) => ({
 			name: field.name,
@@ -0,0 +1,34 @@
+import { columnKeyProvider, getCredential, saveCredential } from "./credentialVault"
+import { setEncryptionKeyV1, unwrapEnvelope } from "./tauriClient"
+
+function toBase64(bytes: Uint8Array): string {
+	let binary = ""
+	for (const byte of bytes) {
+		binary += String.fromCharCode(byte)
+	}
+	return btoa(binary)
+}
+
+/** Generates a 256-bit key in the vault; its reference is the `keyId` of encrypted columns. */
+export async function createColumnKey(label?: string): Promise<string> {
+	const key = crypto.getRandomValues(new Uint8Array(32))
+	return saveCredential({
+		provider: columnKeyProvider,
+		params: { key: toBase64(key) },
+		label,
+	})
+}
+
+/** Reads a key from the vault and unlocks it in the backend for this session. */
+export async function unlockColumnKey(keyId: string): Promise<void> {
+	const credential = await getCredential(keyId)
+	const key = credential?.params.key
+	if (!key) {
+		throw new Error(`未找到加密密钥：${keyId}`)
+	}
+	unwrapEnvelope(await setEncryptionKeyV1({ keyId, key }))
+}
+
+export async function lockColumnKey(keyId: string): Promise<void> {
+	unwrapEnvelope(await setEncryptionKeyV1({ keyId }))
+}
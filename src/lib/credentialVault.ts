@@ -1,3 +1,4 @@
+import { appDataDir, join } from "@tauri-apps/api/path"
 import { LazyStore } from "@tauri-apps/plugin-store"
 
 export interface CredentialSummary {
@@ -15,12 +16,27 @@ type StrongholdHandle = {
 	setItem: (key: string, value: Uint8Array) => Promise<void>
 	getItem: (key: string) => Promise<unknown>
 	deleteItem: (key: string) => Promise<void>
-	clear: () => Promise<void>
+	save: () => Promise<void>
+}
+
+type StrongholdStore = {
+	get: (key: string) => Promise<Uint8Array | null>
+	insert: (key: string, value: number[]) => Promise<void>
+	remove: (key: string) => Promise<unknown>
+}
+
+type StrongholdClient = {
+	getStore: () => StrongholdStore
+}
+
+type StrongholdVault = {
+	loadClient: (name: string) => Promise<StrongholdClient>
+	createClient: (name: string) => Promise<StrongholdClient>
 	save: () => Promise<void>
 }
 
 type StrongholdConstructor = {
-	load: (vault: string, passphrase: string) => Promise<StrongholdHandle>
+	load: (path: string, passphrase: string) => Promise<StrongholdVault>
 }
 
 type StrongholdModule = {
@@ -30,9 +46,16 @@ type StrongholdModule = {
 const metaStore = new LazyStore("credentials-meta.json")
 const configStore = new LazyStore("credentials-config.json")
 
+/** Provider of column encryption keys; no profile references them. */
+export const columnKeyProvider = "column-encryption"
+
+// The backend resolves `secret_ref` auth from the same vault, so these match
+// `services::secrets` in src-tauri. Vaults older versions saved as `lancedb-viewer`
+// are moved into `vaultFile` by `secrets::migrate_legacy_vault` at startup.
 const indexKey = "credentials"
 const passphraseKey = "strongholdPassphrase"
-const vaultName = "lancedb-viewer"
+const vaultFile = "credentials.stronghold"
+const clientName = "lancedb-viewer"
 
 const encoder = new TextEncoder()
 const decoder = new TextDecoder()
@@ -68,7 +91,19 @@ async function getStronghold(): Promise<StrongholdHandle> {
 		strongholdPromise = (async () => {
 			const { Stronghold } = await loadStrongholdModule()
 			const passphrase = await getPassphrase()
-			return Stronghold.load(vaultName, passphrase)
+			const vault = await Stronghold.load(await join(await appDataDir(), vaultFile), passphrase)
+			const client = await vault
+				.loadClient(clientName)
+				.catch(() => vault.createClient(clientName))
+			const store = client.getStore()
+			return {
+				setItem: (key, value) => store.insert(key, Array.from(value)),
+				getItem: (key) => store.get(key),
+				deleteItem: async (key) => {
+					await store.remove(key)
+				},
+				save: () => vault.save(),
+			}
 		})()
 	}
 	return strongholdPromise
@@ -167,7 +202,9 @@ export async function deleteCredential(reference: string): Promise<void> {
 
 export async function clearCredentials(): Promise<void> {
 	const stronghold = await getStronghold()
-	await stronghold.clear()
+	for (const credential of await loadIndex()) {
+		await stronghold.deleteItem(credential.reference)
+	}
 	await stronghold.save()
 	await saveIndex([])
 }
@@ -176,7 +213,7 @@ export async function cleanupUnusedCredentials(usedReferences: Set<string>): Pro
 	const removed: string[] = []
 	const credentials = await loadIndex()
 	for (const credential of credentials) {
-		if (usedReferences.has(credential.reference)) {
+		if (usedReferences.has(credential.reference) || credential.provider === columnKeyProvider) {
 			continue
 		}
 		await deleteCredential(credential.reference)
@@ -0,0 +1,19 @@
+import { save } from "@tauri-apps/plugin-dialog"
+import type { ExportDiagnosticsResponseV1 } from "../ipc/v1"
+import { exportDiagnosticsV1, unwrapEnvelope } from "./tauriClient"
+
+/**
+ * Asks where to save, then writes the diagnostics report for support. Resolves to
+ * null when the dialog is cancelled.
+ */
+export async function exportDiagnostics(): Promise<ExportDiagnosticsResponseV1 | null> {
+	const path = await save({
+		defaultPath: `lancedb-viewer-diagnostics-${Date.now()}.json`,
+		filters: [{ name: "JSON", extensions: ["json"] }],
+	})
+	if (!path) {
+		return null
+	}
+	// The save dialog has already asked before replacing an existing file.
+	return unwrapEnvelope(await exportDiagnosticsV1({ path, overwrite: true }))
+}
@@ -1,6 +1,6 @@
 import { describe, expect, it } from "vitest"
 
-import { formatCellValue, normalizeRow } from "./formatters"
+import { formatByteCount, formatCellValue, normalizeRow } from "./formatters"
 
 describe("formatCellValue", () => {
 	it("formats primitives", () => {
@@ -30,3 +30,15 @@ describe("normalizeRow", () => {
 		expect(normalizeRow("value")).toEqual({ value: "value" })
 	})
 })
+
+describe("formatByteCount", () => {
+	it("scales to binary units", () => {
+		expect(formatByteCount(512)).toBe("512 B")
+		expect(formatByteCount(1536)).toBe("1.5 KB")
+		expect(formatByteCount(20 * 1024 * 1024)).toBe("20 MB")
+	})
+
+	it("returns invalid input unchanged", () => {
+		expect(formatByteCount("n/a")).toBe("n/a")
+	})
+})
@@ -11,6 +11,25 @@ export function normalizeRow(row: unknown): Record<string, unknown> {
 	return { value: row }
 }
 
+export function formatByteCount(rawValue: string | number) {
+	const bytes = Number(rawValue)
+	if (!Number.isFinite(bytes) || bytes < 0) {
+		return String(rawValue)
+	}
+	if (bytes < 1024) {
+		return `${bytes} B`
+	}
+	const units = ["KB", "MB", "GB", "TB"]
+	let value = bytes / 1024
+	let unitIndex = 0
+	while (value >= 1024 && unitIndex < units.length - 1) {
+		value /= 1024
+		unitIndex += 1
+	}
+	const precision = value >= 10 ? 1 : 2
+	return `${Number(value.toFixed(precision))} ${units[unitIndex]}`
+}
+
 export function formatCellValue(value: unknown): string {
 	if (value === null || value === undefined) {
 		return ""
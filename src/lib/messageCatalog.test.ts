@@ -0,0 +1,29 @@
+import { describe, expect, it } from "vitest"
+
+import { localizeMessage, localizeMessageCode } from "./messageCatalog"
+
+describe("localizeMessage", () => {
+	it("renders catalog templates with params", () => {
+		expect(
+			localizeMessage(
+				{
+					code: "vacuum_submitted_older_than",
+					params: { days: "7" },
+					text: "Vacuum submitted, removing versions older than 7 days",
+				},
+				"Vacuum 已完成"
+			)
+		).toBe("Vacuum 已提交，清理超过 7 天的历史版本")
+	})
+
+	it("falls back when no message code is present", () => {
+		expect(localizeMessage(undefined, "Compact 已完成")).toBe("Compact 已完成")
+		expect(localizeMessageCode(undefined, undefined, "table not found")).toBe("table not found")
+	})
+
+	it("keeps unknown placeholders intact", () => {
+		expect(localizeMessageCode("compact_submitted_with_target", {}, "")).toBe(
+			"Compact 已提交，目标片段行数={target_rows}"
+		)
+	})
+})
@@ -0,0 +1,46 @@
+import type { MessageCodeV1, MessageV1 } from "../ipc/v1"
+
+const zhCnTemplates: Record<MessageCodeV1, string> = {
+	connection_not_found: "连接不存在",
+	table_not_found: "数据表不存在",
+	state_lock_failed: "连接管理器加锁失败",
+	compact_submitted: "Compact 已提交",
+	compact_submitted_with_target: "Compact 已提交，目标片段行数={target_rows}",
+	vacuum_submitted: "Vacuum 已提交",
+	vacuum_submitted_older_than: "Vacuum 已提交，清理超过 {days} 天的历史版本",
+	indexes_optimized: "已优化 {indexes} 个索引，仍有 {unindexed_rows} 行未建索引",
+	connection_read_only: "连接为只读模式",
+	role_forbidden: "当前角色 {role} 无权执行 {command}",
+	fts_index_missing: "数据表没有全文索引，请先在字符串列上创建全文索引",
+	fts_columns_not_indexed: "列 {columns} 没有全文索引，已建立全文索引的列：{indexed}",
+	vector_filter_hint:
+		"{column} 是向量列，请使用 array_length({column})、{column}[1] 这样的元素访问或 array_has_any({column}, [0.5, 1.0]) 进行过滤",
+	disk_space_insufficient: "{path} 所在磁盘空间不足：需要 {needed} 字节，可用 {available} 字节",
+	encrypted_column_locked: "列 {column} 使用密钥 {key_id} 加密，该密钥尚未解锁",
+	path_empty: "路径不能为空",
+	table_name_empty: "表名不能为空",
+	index_name_empty: "索引名不能为空",
+	job_not_found: "任务不存在",
+}
+
+export function localizeMessageCode(
+	code: MessageCodeV1 | undefined,
+	params: Record<string, string> | undefined,
+	fallback: string
+): string {
+	if (!code) {
+		return fallback
+	}
+	const template = zhCnTemplates[code]
+	if (!template) {
+		return fallback
+	}
+	return template.replace(/\{(\w+)\}/g, (match, key: string) => params?.[key] ?? match)
+}
+
+export function localizeMessage(message: MessageV1 | undefined, fallback: string): string {
+	if (!message) {
+		return fallback
+	}
+	return localizeMessageCode(message.code, message.params, message.text || fallback)
+}
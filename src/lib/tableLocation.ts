@@ -0,0 +1,17 @@
+import { revealItemInDir } from "@tauri-apps/plugin-opener"
+import type { TableLocationV1 } from "../ipc/v1"
+import { tableLocationV1, unwrapEnvelope } from "./tauriClient"
+
+export async function getTableLocation(tableId: string): Promise<TableLocationV1> {
+	return unwrapEnvelope(await tableLocationV1({ tableId }))
+}
+
+/** Shows a local table's dataset directory in the system file manager. */
+export async function openTableLocation(tableId: string): Promise<TableLocationV1> {
+	const location = await getTableLocation(tableId)
+	if (!location.localPath) {
+		throw new Error(`表位于远程存储，无法在文件管理器中打开：${location.datasetUri}`)
+	}
+	await revealItemInDir(location.localPath)
+	return location
+}
@@ -1,11 +1,14 @@
 import { beforeEach, describe, expect, it, vi } from "vitest"
 
-import { connectV1, createIndexV1, deleteRowsV1, updateRowsV1 } from "./tauriClient"
+import { connectV1, createIndexV1, deleteRowsV1, scanStreamV1, updateRowsV1 } from "./tauriClient"
 
 const invokeMock = vi.hoisted(() => vi.fn())
 
 vi.mock("@tauri-apps/api/core", () => ({
 	invoke: invokeMock,
+	Channel: class {
+		onmessage: (message: unknown) => void = () => {}
+	},
 }))
 
 describe("tauriClient write mutation commands", () => {
@@ -84,21 +87,36 @@ describe("tauriClient write mutation commands", () => {
 		})
 	})
 
-	it("returns an unsupported envelope for secret_ref auth without invoking IPC", async () => {
-		const response = await connectV1({
+	it("passes secret_ref auth to the backend unresolved", async () => {
+		const profile = {
 			name: "secret",
 			uri: "s3://bucket/path",
-			auth: { type: "secret_ref", provider: "s3", reference: "stored-key" },
+			auth: { type: "secret_ref" as const, provider: "s3", reference: "stored-key" },
+		}
+		await connectV1(profile)
+
+		expect(invokeMock).toHaveBeenCalledWith("connect_v1", { request: { profile } })
+	})
+
+	it("acknowledges scan stream chunks after they are handled", async () => {
+		invokeMock.mockImplementation(async (command: string, payload) => {
+			if (command === "scan_stream_v1") {
+				const channel = payload.onEvent
+				channel.onmessage({ event: "started", streamId: "stream-1", version: 3, window: 4 })
+				channel.onmessage(new Uint8Array([1, 2]).buffer)
+				channel.onmessage(new Uint8Array([3]).buffer)
+			}
+			return { apiVersion: "v1", ok: true, data: {} }
 		})
+		const chunks: number[][] = []
 
-		expect(response).toEqual({
-			apiVersion: "v1",
-			ok: false,
-			error: {
-				code: "not_implemented",
-				message: "secret_ref auth is not supported in this version; use no auth or inline auth",
-			},
+		await scanStreamV1({ tableId: "table-1" }, (bytes) => {
+			chunks.push(Array.from(bytes))
+		})
+
+		expect(chunks).toEqual([[1, 2], [3]])
+		expect(invokeMock).toHaveBeenCalledWith("ack_scan_stream_v1", {
+			request: { streamId: "stream-1", sequence: 2 },
 		})
-		expect(invokeMock).not.toHaveBeenCalled()
 	})
 })
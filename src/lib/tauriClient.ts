@@ -1,8 +1,17 @@
-import { invoke } from "@tauri-apps/api/core"
+import { Channel, invoke } from "@tauri-apps/api/core"
+import { listen, type UnlistenFn } from "@tauri-apps/api/event"
 
 import type {
+	AckScanStreamRequestV1,
+	ActiveQueryV1,
 	AddColumnsResponseV1,
 	AlterColumnsResponseV1,
+	BackupTableRequestV1,
+	BackupTableResponseV1,
+	CancelJobRequestV1,
+	CancelScanStreamRequestV1,
+	CheckExportTargetRequestV1,
+	CheckExportTargetResponseV1,
 	CheckoutTableLatestRequestV1,
 	CheckoutTableLatestResponseV1,
 	CheckoutTableVersionRequestV1,
@@ -10,47 +19,176 @@ import type {
 	CloneTableRequestV1,
 	CloneTableResponseV1,
 	CombinedSearchRequestV1,
+	CompareQueryVersionsRequestV1,
+	CompareQueryVersionsResponseV1,
+	CompletionMetadataResponseV1,
+	ConfigResponseV1,
 	ConnectProfile,
 	ConnectResponseV1,
 	CreateIndexRequestV1,
 	CreateIndexResponseV1,
+	CreateTableOptionsV1,
 	CreateTableResponseV1,
+	CreateTagRequestV1,
+	DeleteExportTemplateRequestV1,
+	DeleteExportTemplateResponseV1,
+	DeleteProjectionPresetRequestV1,
+	DeleteProjectionPresetResponseV1,
 	DeleteRowsRequestV1,
 	DeleteRowsResponseV1,
+	DeleteScheduleRequestV1,
+	DeleteScheduleResponseV1,
+	DeleteTagRequestV1,
+	DeleteTagResponseV1,
+	DeleteWatchFolderRequestV1,
+	DeleteWatchFolderResponseV1,
 	DisconnectResponseV1,
 	DropColumnsResponseV1,
 	DropIndexResponseV1,
 	DropTableResponseV1,
+	EstimateEmbeddingJobRequestV1,
+	EstimateEmbeddingJobResponseV1,
+	EstimateQueryRequestV1,
+	EstimateQueryResponseV1,
+	EvaluateRecallRequestV1,
+	EvaluateRecallResponseV1,
+	ExportBundleRequestV1,
+	ExportBundleResponseV1,
 	ExportDataRequestV1,
 	ExportDataResponseV1,
+	ExportDiagnosticsRequestV1,
+	ExportDiagnosticsResponseV1,
+	ExportReproSampleRequestV1,
+	ExportReproSampleResponseV1,
+	ExportRowsRequestV1,
 	FieldDataType,
 	FtsSearchRequestV1,
+	GetAccessLogRequestV1,
+	GetAccessLogResponseV1,
+	GetJobStatusRequestV1,
+	GetRetentionPolicyRequestV1,
+	GetTableDescriptionRequestV1,
 	GetTableVersionRequestV1,
 	GetTableVersionResponseV1,
+	HealthResponseV1,
+	ImportBundleRequestV1,
+	ImportBundleResponseV1,
 	ImportDataRequestV1,
 	ImportDataResponseV1,
+	IndexCapabilitiesResponseV1,
+	IndexStatsResponseV1,
+	JobStatusV1,
+	KillQueryRequestV1,
+	ListActiveQueriesResponseV1,
+	ListEncryptedColumnsRequestV1,
+	ListEncryptedColumnsResponseV1,
+	ListExportTemplatesResponseV1,
+	ListExtensionsResponseV1,
 	ListIndexesResponseV1,
+	ListJobsRequestV1,
+	ListJobsResponseV1,
+	ListMaskingRulesRequestV1,
+	ListMaskingRulesResponseV1,
+	ListProjectionPresetsResponseV1,
+	ListSchedulesRequestV1,
+	ListSchedulesResponseV1,
+	ListSharesResponseV1,
 	ListTablesResponseV1,
+	ListTagsRequestV1,
+	ListTagsResponseV1,
 	ListVersionsRequestV1,
 	ListVersionsResponseV1,
+	ListWatchFoldersRequestV1,
+	ListWatchFoldersResponseV1,
+	MaterializeQueryRequestV1,
+	MaterializeQueryResponseV1,
+	MemoryStatsResponseV1,
+	MigrateTableFormatRequestV1,
+	MigrateTableFormatResponseV1,
+	OpenDatasetRequestV1,
+	OpenDatasetResponseV1,
+	OpenTablesResponseV1,
 	OptimizeTableRequestV1,
 	OptimizeTableResponseV1,
+	OverviewRequestV1,
+	OverviewResponseV1,
+	PivotRequestV1,
+	PivotResponseV1,
+	PreviewUpdateRequestV1,
+	PreviewUpdateResponseV1,
+	ProgressEventV1,
 	QueryFilterRequestV1,
 	QueryResponseV1,
+	RecentFiltersRequestV1,
+	RecentFiltersResponseV1,
 	RenameTableRequestV1,
 	RenameTableResponseV1,
+	ReorderColumnsRequestV1,
+	ReorderColumnsResponseV1,
+	RestoreBackupRequestV1,
+	RestoreBackupResponseV1,
+	RestoreTableVersionRequestV1,
+	RestoreTableVersionResponseV1,
 	ResultEnvelope,
+	RetentionPolicyResponseV1,
+	RotateSecretRequestV1,
+	RotateSecretResponseV1,
+	RowCountResponseV1,
+	RunExportTemplateRequestV1,
+	RunRetentionRequestV1,
+	RunRetentionResponseV1,
+	SaveExportTemplateRequestV1,
+	SaveExportTemplateResponseV1,
+	SaveProjectionPresetRequestV1,
+	SaveProjectionPresetResponseV1,
 	ScanRequestV1,
 	ScanResponseV1,
+	ScanStreamControlResponseV1,
+	ScanStreamEventV1,
+	ScanStreamRequestV1,
+	ScanStreamSummaryV1,
 	SchemaDefinition,
 	SchemaDefinitionInput,
+	SearchDefaultsResponseV1,
+	SetConfigRequestV1,
+	SetEncryptedColumnsRequestV1,
+	SetEncryptedColumnsResponseV1,
+	SetEncryptionKeyRequestV1,
+	SetEncryptionKeyResponseV1,
+	SetLogLevelRequestV1,
+	SetLogLevelResponseV1,
+	SetMaskingRulesRequestV1,
+	SetMaskingRulesResponseV1,
+	SetRetentionPolicyRequestV1,
+	SetScheduleRequestV1,
+	SetScheduleResponseV1,
+	SetSearchDefaultsRequestV1,
+	SetTableDescriptionRequestV1,
+	SetWatchFolderRequestV1,
+	SetWatchFolderResponseV1,
+	ShareSessionV1,
+	StartShareRequestV1,
+	StatsHistoryRequestV1,
+	StatsHistoryResponseV1,
+	StopShareRequestV1,
+	StopShareResponseV1,
+	TableDescriptionResponseV1,
+	TableFormatInfoRequestV1,
+	TableFormatInfoV1,
 	TableHandle,
+	TableLocationRequestV1,
+	TableLocationV1,
+	TagResponseV1,
 	UpdateRowsRequestV1,
 	UpdateRowsResponseV1,
+	UpdateTagRequestV1,
+	ValidateFilterResponseV1,
 	VectorSearchRequestV1,
+	WatchFolderEventV1,
 	WriteDataMode,
 	WriteRowsResponseV1,
 } from "../ipc/v1"
+import { localizeMessageCode } from "./messageCatalog"
 
 function normalizeInvokeError(error: unknown, fallback: string): Error {
 	if (error instanceof Error) {
@@ -82,7 +220,11 @@ async function invokeV1<T>(command: string, payload: unknown): Promise<ResultEnv
 
 export function unwrapEnvelope<T>(envelope: ResultEnvelope<T>): T {
 	if (!envelope.ok || envelope.data === undefined) {
-		const message = envelope.error?.message ?? "unknown error"
+		const message = localizeMessageCode(
+			envelope.error?.messageCode,
+			envelope.error?.params,
+			envelope.error?.message ?? "unknown error"
+		)
 		throw new Error(message)
 	}
 	return envelope.data
@@ -91,16 +233,6 @@ export function unwrapEnvelope<T>(envelope: ResultEnvelope<T>): T {
 export async function connectV1(
 	profile: ConnectProfile
 ): Promise<ResultEnvelope<ConnectResponseV1>> {
-	if (profile.auth?.type === "secret_ref") {
-		return {
-			apiVersion: "v1",
-			ok: false,
-			error: {
-				code: "not_implemented",
-				message: "secret_ref auth is not supported in this version; use no auth or inline auth",
-			},
-		}
-	}
 	return invokeV1("connect_v1", { request: { profile } })
 }
 
@@ -110,10 +242,17 @@ export async function disconnectV1(
 	return invokeV1("disconnect_v1", { request: { connectionId } })
 }
 
+export async function rotateSecretV1(
+	request: RotateSecretRequestV1
+): Promise<ResultEnvelope<RotateSecretResponseV1>> {
+	return invokeV1("rotate_secret_v1", { request })
+}
+
 export async function listTablesV1(
-	connectionId: string
+	connectionId: string,
+	includeStats = false
 ): Promise<ResultEnvelope<ListTablesResponseV1>> {
-	return invokeV1("list_tables_v1", { request: { connectionId } })
+	return invokeV1("list_tables_v1", { request: { connectionId, includeStats } })
 }
 
 export async function dropTableV1(
@@ -136,6 +275,13 @@ export async function listIndexesV1(
 	return invokeV1("list_indexes_v1", { request: { tableId } })
 }
 
+export async function indexStatsV1(
+	tableId: string,
+	indexName: string
+): Promise<ResultEnvelope<IndexStatsResponseV1>> {
+	return invokeV1("index_stats_v1", { request: { tableId, indexName } })
+}
+
 export async function createIndexV1(
 	request: CreateIndexRequestV1
 ): Promise<ResultEnvelope<CreateIndexResponseV1>> {
@@ -152,9 +298,10 @@ export async function dropIndexV1(
 export async function createTableV1(
 	connectionId: string,
 	tableName: string,
-	schema: SchemaDefinitionInput
+	schema: SchemaDefinitionInput,
+	options?: CreateTableOptionsV1
 ): Promise<ResultEnvelope<CreateTableResponseV1>> {
-	return invokeV1("create_table_v1", { request: { connectionId, tableName, schema } })
+	return invokeV1("create_table_v1", { request: { connectionId, tableName, schema, options } })
 }
 
 export async function openTableV1(
@@ -164,10 +311,21 @@ export async function openTableV1(
 	return invokeV1("open_table_v1", { request: { connectionId, tableName } })
 }
 
+export async function openTablesV1(
+	connectionId: string,
+	tableNames: string[]
+): Promise<ResultEnvelope<OpenTablesResponseV1>> {
+	return invokeV1("open_tables_v1", { request: { connectionId, tableNames } })
+}
+
 export async function getSchemaV1(tableId: string): Promise<ResultEnvelope<SchemaDefinition>> {
 	return invokeV1("get_schema_v1", { request: { tableId } })
 }
 
+export async function getRowCountV1(tableId: string): Promise<ResultEnvelope<RowCountResponseV1>> {
+	return invokeV1("get_row_count_v1", { request: { tableId } })
+}
+
 export async function listVersionsV1(
 	request: ListVersionsRequestV1
 ): Promise<ResultEnvelope<ListVersionsResponseV1>> {
@@ -192,12 +350,255 @@ export async function checkoutTableLatestV1(
 	return invokeV1("checkout_table_latest_v1", { request })
 }
 
+export async function restoreTableVersionV1(
+	request: RestoreTableVersionRequestV1
+): Promise<ResultEnvelope<RestoreTableVersionResponseV1>> {
+	return invokeV1("restore_table_version_v1", { request })
+}
+
+export async function listTagsV1(
+	request: ListTagsRequestV1
+): Promise<ResultEnvelope<ListTagsResponseV1>> {
+	return invokeV1("list_tags_v1", { request })
+}
+
+export async function createTagV1(
+	request: CreateTagRequestV1
+): Promise<ResultEnvelope<TagResponseV1>> {
+	return invokeV1("create_tag_v1", { request })
+}
+
+export async function updateTagV1(
+	request: UpdateTagRequestV1
+): Promise<ResultEnvelope<TagResponseV1>> {
+	return invokeV1("update_tag_v1", { request })
+}
+
+export async function deleteTagV1(
+	request: DeleteTagRequestV1
+): Promise<ResultEnvelope<DeleteTagResponseV1>> {
+	return invokeV1("delete_tag_v1", { request })
+}
+
 export async function cloneTableV1(
 	request: CloneTableRequestV1
 ): Promise<ResultEnvelope<CloneTableResponseV1>> {
 	return invokeV1("clone_table_v1", { request })
 }
 
+export async function materializeQueryV1(
+	request: MaterializeQueryRequestV1
+): Promise<ResultEnvelope<MaterializeQueryResponseV1>> {
+	return invokeV1("materialize_query_v1", { request })
+}
+
+export async function compareQueryVersionsV1(
+	request: CompareQueryVersionsRequestV1
+): Promise<ResultEnvelope<CompareQueryVersionsResponseV1>> {
+	return invokeV1("compare_query_versions_v1", { request })
+}
+
+export async function backupTableV1(
+	request: BackupTableRequestV1
+): Promise<ResultEnvelope<BackupTableResponseV1>> {
+	return invokeV1("backup_table_v1", { request })
+}
+
+export async function restoreBackupV1(
+	request: RestoreBackupRequestV1
+): Promise<ResultEnvelope<RestoreBackupResponseV1>> {
+	return invokeV1("restore_backup_v1", { request })
+}
+
+export async function getJobStatusV1(
+	request: GetJobStatusRequestV1
+): Promise<ResultEnvelope<JobStatusV1>> {
+	return invokeV1("get_job_status_v1", { request })
+}
+
+export async function listJobsV1(
+	request: ListJobsRequestV1 = {}
+): Promise<ResultEnvelope<ListJobsResponseV1>> {
+	return invokeV1("list_jobs_v1", { request })
+}
+
+export async function cancelJobV1(
+	request: CancelJobRequestV1
+): Promise<ResultEnvelope<JobStatusV1>> {
+	return invokeV1("cancel_job_v1", { request })
+}
+
+export async function listActiveQueriesV1(): Promise<ResultEnvelope<ListActiveQueriesResponseV1>> {
+	return invokeV1("list_active_queries_v1", {})
+}
+
+export async function killQueryV1(
+	request: KillQueryRequestV1
+): Promise<ResultEnvelope<ActiveQueryV1>> {
+	return invokeV1("kill_query_v1", { request })
+}
+
+export async function listSchedulesV1(
+	request: ListSchedulesRequestV1
+): Promise<ResultEnvelope<ListSchedulesResponseV1>> {
+	return invokeV1("list_schedules_v1", { request })
+}
+
+export async function setScheduleV1(
+	request: SetScheduleRequestV1
+): Promise<ResultEnvelope<SetScheduleResponseV1>> {
+	return invokeV1("set_schedule_v1", { request })
+}
+
+export async function deleteScheduleV1(
+	request: DeleteScheduleRequestV1
+): Promise<ResultEnvelope<DeleteScheduleResponseV1>> {
+	return invokeV1("delete_schedule_v1", { request })
+}
+
+export async function listWatchFoldersV1(
+	request: ListWatchFoldersRequestV1
+): Promise<ResultEnvelope<ListWatchFoldersResponseV1>> {
+	return invokeV1("list_watch_folders_v1", { request })
+}
+
+export async function setWatchFolderV1(
+	request: SetWatchFolderRequestV1
+): Promise<ResultEnvelope<SetWatchFolderResponseV1>> {
+	return invokeV1("set_watch_folder_v1", { request })
+}
+
+export async function deleteWatchFolderV1(
+	request: DeleteWatchFolderRequestV1
+): Promise<ResultEnvelope<DeleteWatchFolderResponseV1>> {
+	return invokeV1("delete_watch_folder_v1", { request })
+}
+
+export function onWatchFolderIngested(
+	handler: (event: WatchFolderEventV1) => void
+): Promise<UnlistenFn> {
+	return listen<WatchFolderEventV1>("watch-folder-ingested", (event) => handler(event.payload))
+}
+
+export function onJobProgress(handler: (event: ProgressEventV1) => void): Promise<UnlistenFn> {
+	return listen<ProgressEventV1>("job-progress", (event) => handler(event.payload))
+}
+
+export async function listMaskingRulesV1(
+	request: ListMaskingRulesRequestV1
+): Promise<ResultEnvelope<ListMaskingRulesResponseV1>> {
+	return invokeV1("list_masking_rules_v1", { request })
+}
+
+export async function setMaskingRulesV1(
+	request: SetMaskingRulesRequestV1
+): Promise<ResultEnvelope<SetMaskingRulesResponseV1>> {
+	return invokeV1("set_masking_rules_v1", { request })
+}
+
+export async function listEncryptedColumnsV1(
+	request: ListEncryptedColumnsRequestV1
+): Promise<ResultEnvelope<ListEncryptedColumnsResponseV1>> {
+	return invokeV1("list_encrypted_columns_v1", { request })
+}
+
+export async function setEncryptedColumnsV1(
+	request: SetEncryptedColumnsRequestV1
+): Promise<ResultEnvelope<SetEncryptedColumnsResponseV1>> {
+	return invokeV1("set_encrypted_columns_v1", { request })
+}
+
+export async function setEncryptionKeyV1(
+	request: SetEncryptionKeyRequestV1
+): Promise<ResultEnvelope<SetEncryptionKeyResponseV1>> {
+	return invokeV1("set_encryption_key_v1", { request })
+}
+
+export async function getRetentionPolicyV1(
+	request: GetRetentionPolicyRequestV1
+): Promise<ResultEnvelope<RetentionPolicyResponseV1>> {
+	return invokeV1("get_retention_policy_v1", { request })
+}
+
+export async function setRetentionPolicyV1(
+	request: SetRetentionPolicyRequestV1
+): Promise<ResultEnvelope<RetentionPolicyResponseV1>> {
+	return invokeV1("set_retention_policy_v1", { request })
+}
+
+export async function getTableDescriptionV1(
+	request: GetTableDescriptionRequestV1
+): Promise<ResultEnvelope<TableDescriptionResponseV1>> {
+	return invokeV1("get_table_description_v1", { request })
+}
+
+export async function setTableDescriptionV1(
+	request: SetTableDescriptionRequestV1
+): Promise<ResultEnvelope<TableDescriptionResponseV1>> {
+	return invokeV1("set_table_description_v1", { request })
+}
+
+export async function recentFiltersV1(
+	request: RecentFiltersRequestV1
+): Promise<ResultEnvelope<RecentFiltersResponseV1>> {
+	return invokeV1("recent_filters_v1", { request })
+}
+
+export async function completionMetadataV1(
+	tableId: string
+): Promise<ResultEnvelope<CompletionMetadataResponseV1>> {
+	return invokeV1("completion_metadata_v1", { request: { tableId } })
+}
+
+export async function validateFilterV1(
+	tableId: string,
+	filter: string
+): Promise<ResultEnvelope<ValidateFilterResponseV1>> {
+	return invokeV1("validate_filter_v1", { request: { tableId, filter } })
+}
+
+export async function getSearchDefaultsV1(
+	tableId: string
+): Promise<ResultEnvelope<SearchDefaultsResponseV1>> {
+	return invokeV1("get_search_defaults_v1", { request: { tableId } })
+}
+
+export async function setSearchDefaultsV1(
+	request: SetSearchDefaultsRequestV1
+): Promise<ResultEnvelope<SearchDefaultsResponseV1>> {
+	return invokeV1("set_search_defaults_v1", { request })
+}
+
+export async function listProjectionPresetsV1(
+	tableId: string
+): Promise<ResultEnvelope<ListProjectionPresetsResponseV1>> {
+	return invokeV1("list_projection_presets_v1", { request: { tableId } })
+}
+
+export async function saveProjectionPresetV1(
+	request: SaveProjectionPresetRequestV1
+): Promise<ResultEnvelope<SaveProjectionPresetResponseV1>> {
+	return invokeV1("save_projection_preset_v1", { request })
+}
+
+export async function deleteProjectionPresetV1(
+	request: DeleteProjectionPresetRequestV1
+): Promise<ResultEnvelope<DeleteProjectionPresetResponseV1>> {
+	return invokeV1("delete_projection_preset_v1", { request })
+}
+
+export async function statsHistoryV1(
+	request: StatsHistoryRequestV1
+): Promise<ResultEnvelope<StatsHistoryResponseV1>> {
+	return invokeV1("stats_history_v1", { request })
+}
+
+export async function runRetentionV1(
+	request: RunRetentionRequestV1
+): Promise<ResultEnvelope<RunRetentionResponseV1>> {
+	return invokeV1("run_retention_v1", { request })
+}
+
 export async function addColumnsV1(
 	tableId: string,
 	columns: SchemaDefinitionInput
@@ -214,6 +615,7 @@ export async function alterColumnsV1(request: {
 		dataType?: FieldDataType
 		vectorLength?: number
 	}[]
+	rebuildIndexes?: boolean
 }): Promise<ResultEnvelope<AlterColumnsResponseV1>> {
 	return invokeV1("alter_columns_v1", { request })
 }
@@ -225,16 +627,82 @@ export async function dropColumnsV1(
 	return invokeV1("drop_columns_v1", { request: { tableId, columns } })
 }
 
+export async function reorderColumnsV1(
+	request: ReorderColumnsRequestV1
+): Promise<ResultEnvelope<ReorderColumnsResponseV1>> {
+	return invokeV1("reorder_columns_v1", { request })
+}
+
 export async function scanV1(request: ScanRequestV1): Promise<ResultEnvelope<ScanResponseV1>> {
 	return invokeV1("scan_v1", { request })
 }
 
+/**
+ * Streams a scan as Arrow IPC chunks. Each chunk is acknowledged once `onChunk`
+ * settles, so a slow consumer holds the backend back instead of buffering the
+ * table. A failing `onChunk` cancels the stream and its error is rethrown.
+ */
+export async function scanStreamV1(
+	request: ScanStreamRequestV1,
+	onChunk: (bytes: Uint8Array, sequence: number) => void | Promise<void>
+): Promise<ResultEnvelope<ScanStreamSummaryV1>> {
+	const channel = new Channel<ScanStreamEventV1 | ArrayBuffer>()
+	let streamId: string | undefined
+	let sequence = 0
+	let failure: unknown
+	let pending = Promise.resolve()
+	channel.onmessage = (message) => {
+		if (!(message instanceof ArrayBuffer)) {
+			if (message.event === "started") {
+				streamId = message.streamId
+			}
+			return
+		}
+		sequence += 1
+		const current = sequence
+		pending = pending.then(async () => {
+			if (failure !== undefined || !streamId) {
+				return
+			}
+			try {
+				await onChunk(new Uint8Array(message), current)
+				await ackScanStreamV1({ streamId, sequence: current })
+			} catch (error) {
+				failure = error
+				await cancelScanStreamV1({ streamId })
+			}
+		})
+	}
+	const envelope = await invokeV1<ScanStreamSummaryV1>("scan_stream_v1", {
+		request,
+		onEvent: channel,
+	})
+	await pending
+	if (failure !== undefined) {
+		throw failure
+	}
+	return envelope
+}
+
+export async function ackScanStreamV1(
+	request: AckScanStreamRequestV1
+): Promise<ResultEnvelope<ScanStreamControlResponseV1>> {
+	return invokeV1("ack_scan_stream_v1", { request })
+}
+
+export async function cancelScanStreamV1(
+	request: CancelScanStreamRequestV1
+): Promise<ResultEnvelope<ScanStreamControlResponseV1>> {
+	return invokeV1("cancel_scan_stream_v1", { request })
+}
+
 export async function writeRowsV1(
 	tableId: string,
 	rows: unknown[],
-	mode: WriteDataMode
+	mode: WriteDataMode,
+	normalizeVectors = false
 ): Promise<ResultEnvelope<WriteRowsResponseV1>> {
-	return invokeV1("write_rows_v1", { request: { tableId, rows, mode } })
+	return invokeV1("write_rows_v1", { request: { tableId, rows, mode, normalizeVectors } })
 }
 
 export async function updateRowsV1(
@@ -243,6 +711,12 @@ export async function updateRowsV1(
 	return invokeV1("update_rows_v1", { request })
 }
 
+export async function previewUpdateV1(
+	request: PreviewUpdateRequestV1
+): Promise<ResultEnvelope<PreviewUpdateResponseV1>> {
+	return invokeV1("preview_update_v1", { request })
+}
+
 export async function deleteRowsV1(
 	request: DeleteRowsRequestV1
 ): Promise<ResultEnvelope<DeleteRowsResponseV1>> {
@@ -261,18 +735,117 @@ export async function exportDataV1(
 	return invokeV1("export_data_v1", { request })
 }
 
+export async function checkExportTargetV1(
+	request: CheckExportTargetRequestV1
+): Promise<ResultEnvelope<CheckExportTargetResponseV1>> {
+	return invokeV1("check_export_target_v1", { request })
+}
+
+export async function exportRowsV1(
+	request: ExportRowsRequestV1
+): Promise<ResultEnvelope<ExportDataResponseV1>> {
+	return invokeV1("export_rows_v1", { request })
+}
+
+export async function listExportTemplatesV1(): Promise<
+	ResultEnvelope<ListExportTemplatesResponseV1>
+> {
+	return invokeV1("list_export_templates_v1", {})
+}
+
+export async function saveExportTemplateV1(
+	request: SaveExportTemplateRequestV1
+): Promise<ResultEnvelope<SaveExportTemplateResponseV1>> {
+	return invokeV1("save_export_template_v1", { request })
+}
+
+export async function deleteExportTemplateV1(
+	request: DeleteExportTemplateRequestV1
+): Promise<ResultEnvelope<DeleteExportTemplateResponseV1>> {
+	return invokeV1("delete_export_template_v1", { request })
+}
+
+export async function runExportTemplateV1(
+	request: RunExportTemplateRequestV1
+): Promise<ResultEnvelope<ExportDataResponseV1>> {
+	return invokeV1("run_export_template_v1", { request })
+}
+
+export async function startShareV1(
+	request: StartShareRequestV1
+): Promise<ResultEnvelope<ShareSessionV1>> {
+	return invokeV1("start_share_v1", { request })
+}
+
+export async function listSharesV1(): Promise<ResultEnvelope<ListSharesResponseV1>> {
+	return invokeV1("list_shares_v1", {})
+}
+
+export async function stopShareV1(
+	request: StopShareRequestV1
+): Promise<ResultEnvelope<StopShareResponseV1>> {
+	return invokeV1("stop_share_v1", { request })
+}
+
+export async function exportBundleV1(
+	request: ExportBundleRequestV1
+): Promise<ResultEnvelope<ExportBundleResponseV1>> {
+	return invokeV1("export_bundle_v1", { request })
+}
+
+export async function exportReproSampleV1(
+	request: ExportReproSampleRequestV1
+): Promise<ResultEnvelope<ExportReproSampleResponseV1>> {
+	return invokeV1("export_repro_sample_v1", { request })
+}
+
+export async function importBundleV1(
+	request: ImportBundleRequestV1
+): Promise<ResultEnvelope<ImportBundleResponseV1>> {
+	return invokeV1("import_bundle_v1", { request })
+}
+
 export async function optimizeTableV1(
 	request: OptimizeTableRequestV1
 ): Promise<ResultEnvelope<OptimizeTableResponseV1>> {
 	return invokeV1("optimize_table_v1", { request })
 }
 
+export async function estimateQueryV1(
+	request: EstimateQueryRequestV1
+): Promise<ResultEnvelope<EstimateQueryResponseV1>> {
+	return invokeV1("estimate_query_v1", { request })
+}
+
+export async function memoryStatsV1(): Promise<ResultEnvelope<MemoryStatsResponseV1>> {
+	return invokeV1("memory_stats_v1", {})
+}
+
+export async function listExtensionsV1(): Promise<ResultEnvelope<ListExtensionsResponseV1>> {
+	return invokeV1("list_extensions_v1", {})
+}
+
+/** Calls a command of an extension compiled in behind a Cargo feature. */
+export async function invokeExtensionV1<T>(
+	extension: string,
+	command: string,
+	payload: Record<string, unknown> = {}
+): Promise<ResultEnvelope<T>> {
+	return invokeV1(`plugin:${extension}|${command}`, payload)
+}
+
 export async function queryFilterV1(
 	request: QueryFilterRequestV1
 ): Promise<ResultEnvelope<QueryResponseV1>> {
 	return invokeV1("query_filter_v1", { request })
 }
 
+export async function pivotV1(
+	request: PivotRequestV1
+): Promise<ResultEnvelope<PivotResponseV1>> {
+	return invokeV1("pivot_v1", { request })
+}
+
 export async function combinedSearchV1(
 	request: CombinedSearchRequestV1
 ): Promise<ResultEnvelope<QueryResponseV1>> {
@@ -285,8 +858,88 @@ export async function vectorSearchV1(
 	return invokeV1("vector_search_v1", { request })
 }
 
+export async function evaluateRecallV1(
+	request: EvaluateRecallRequestV1
+): Promise<ResultEnvelope<EvaluateRecallResponseV1>> {
+	return invokeV1("evaluate_recall_v1", { request })
+}
+
+export async function estimateEmbeddingJobV1(
+	request: EstimateEmbeddingJobRequestV1
+): Promise<ResultEnvelope<EstimateEmbeddingJobResponseV1>> {
+	return invokeV1("estimate_embedding_job_v1", { request })
+}
+
 export async function ftsSearchV1(
 	request: FtsSearchRequestV1
 ): Promise<ResultEnvelope<QueryResponseV1>> {
 	return invokeV1("fts_search_v1", { request })
 }
+
+export async function setLogLevelV1(
+	request: SetLogLevelRequestV1
+): Promise<ResultEnvelope<SetLogLevelResponseV1>> {
+	return invokeV1("set_log_level_v1", { request })
+}
+
+export async function healthV1(): Promise<ResultEnvelope<HealthResponseV1>> {
+	return invokeV1("health_v1", {})
+}
+
+export async function exportDiagnosticsV1(
+	request: ExportDiagnosticsRequestV1
+): Promise<ResultEnvelope<ExportDiagnosticsResponseV1>> {
+	return invokeV1("export_diagnostics_v1", { request })
+}
+
+export async function getAccessLogV1(
+	request: GetAccessLogRequestV1 = {}
+): Promise<ResultEnvelope<GetAccessLogResponseV1>> {
+	return invokeV1("get_access_log_v1", { request })
+}
+
+export async function overviewV1(
+	request: OverviewRequestV1 = {}
+): Promise<ResultEnvelope<OverviewResponseV1>> {
+	return invokeV1("overview_v1", { request })
+}
+
+export async function getConfigV1(): Promise<ResultEnvelope<ConfigResponseV1>> {
+	return invokeV1("get_config_v1", {})
+}
+
+export async function getIndexCapabilitiesV1(): Promise<
+	ResultEnvelope<IndexCapabilitiesResponseV1>
+> {
+	return invokeV1("get_index_capabilities_v1", {})
+}
+
+export async function setConfigV1(
+	request: SetConfigRequestV1
+): Promise<ResultEnvelope<ConfigResponseV1>> {
+	return invokeV1("set_config_v1", { request })
+}
+
+export async function openDatasetV1(
+	request: OpenDatasetRequestV1
+): Promise<ResultEnvelope<OpenDatasetResponseV1>> {
+	return invokeV1("open_dataset_v1", { request })
+}
+
+export async function tableFormatInfoV1(
+	request: TableFormatInfoRequestV1
+): Promise<ResultEnvelope<TableFormatInfoV1>> {
+	return invokeV1("table_format_info_v1", { request })
+}
+
+export async function tableLocationV1(
+	request: TableLocationRequestV1
+): Promise<ResultEnvelope<TableLocationV1>> {
+	return invokeV1("table_location_v1", { request })
+}
+
+export async function migrateTableFormatV1(
+	request: MigrateTableFormatRequestV1
+): Promise<ResultEnvelope<MigrateTableFormatResponseV1>> {
+	return invokeV1("migrate_table_format_v1", { request })
+}
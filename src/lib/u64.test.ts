@@ -0,0 +1,21 @@
+import { describe, expect, it } from "vitest"
+
+import { compareU64, formatU64, sameU64, toBigIntSafe, toNumberSafe } from "./u64"
+
+describe("u64 helpers", () => {
+	it("reads numbers and decimal strings exactly", () => {
+		expect(toBigIntSafe(42)).toBe(42n)
+		expect(toBigIntSafe("18446744073709551615")).toBe(18446744073709551615n)
+		expect(formatU64("9007199254740993")).toBe("9007199254740993")
+		expect(toNumberSafe(7)).toBe(7)
+		expect(toBigIntSafe(undefined)).toBe(0n)
+		expect(toBigIntSafe("-1")).toBe(0n)
+	})
+
+	it("compares values across forms", () => {
+		expect(compareU64("9007199254740993", 9007199254740991)).toBe(1)
+		expect(compareU64(3, "3")).toBe(0)
+		expect(sameU64(3, "3")).toBe(true)
+		expect(sameU64(undefined, 0)).toBe(false)
+	})
+})
@@ -0,0 +1,41 @@
+import type { U64 } from "../ipc/v1"
+
+/** The exact value of a `U64`; invalid input reads as `0n`. */
+export function toBigIntSafe(value: U64 | null | undefined): bigint {
+	if (value === null || value === undefined) {
+		return 0n
+	}
+	if (typeof value === "number") {
+		return Number.isSafeInteger(value) && value >= 0 ? BigInt(value) : 0n
+	}
+	return /^\d+$/.test(value.trim()) ? BigInt(value.trim()) : 0n
+}
+
+/**
+ * A `U64` as a number for arithmetic and charts. Values past
+ * `Number.MAX_SAFE_INTEGER` lose precision, so never send the result back as a
+ * version; pass the original value instead.
+ */
+export function toNumberSafe(value: U64 | null | undefined): number {
+	return Number(toBigIntSafe(value))
+}
+
+/** The exact decimal text of a `U64`. */
+export function formatU64(value: U64 | null | undefined): string {
+	return toBigIntSafe(value).toString()
+}
+
+/** Orders two `U64` values exactly, for sorting versions. */
+export function compareU64(left: U64, right: U64): number {
+	const a = toBigIntSafe(left)
+	const b = toBigIntSafe(right)
+	return a < b ? -1 : a > b ? 1 : 0
+}
+
+/** Whether two `U64` values are the same, whichever form each arrived in. */
+export function sameU64(left: U64 | null | undefined, right: U64 | null | undefined): boolean {
+	if (left === null || left === undefined || right === null || right === undefined) {
+		return left === right
+	}
+	return toBigIntSafe(left) === toBigIntSafe(right)
+}
@@ -56,6 +56,20 @@ export const fieldTypeOptions: SelectOption[] = [
 	{ label: "Binary", value: "binary" },
 	{ label: "LargeBinary", value: "large_binary" },
 	{ label: "Vector(Float32)", value: "fixed_size_list_float32" },
+	{ label: "Time32(s)", value: "time32_second" },
+	{ label: "Time32(ms)", value: "time32_millisecond" },
+	{ label: "Time64(us)", value: "time64_microsecond" },
+	{ label: "Time64(ns)", value: "time64_nanosecond" },
+	{ label: "Duration(s)", value: "duration_second" },
+	{ label: "Duration(ms)", value: "duration_millisecond" },
+	{ label: "Duration(us)", value: "duration_microsecond" },
+	{ label: "Duration(ns)", value: "duration_nanosecond" },
+	{ label: "Interval(YearMonth)", value: "interval_year_month" },
+	{ label: "Interval(DayTime)", value: "interval_day_time" },
+	{ label: "Interval(MonthDayNano)", value: "interval_month_day_nano" },
+	{ label: "Null", value: "null" },
+	{ label: "Dictionary(Utf8)", value: "dictionary_utf8" },
+	{ label: "Geometry(WKB)", value: "geometry" },
 ]
 
 export const alterTypeOptions: SelectOption[] = [
@@ -108,6 +122,11 @@ export const fileFormatOptions: SelectOption[] = [
 	{ label: "JSONL", value: "jsonl" },
 ]
 
+export const exportFormatOptions: SelectOption[] = [
+	...fileFormatOptions,
+	{ label: "GeoJSON", value: "geojson" },
+]
+
 // ── Utility Functions ──────────────────────────────────
 
 export function renderHeader(title: string) {
@@ -0,0 +1,26 @@
+import { describe, expect, it } from "vitest"
+import { growthOf, sparklinePoints } from "./statsHistory"
+
+describe("stats history helpers", () => {
+	it("scales values into the sparkline box", () => {
+		expect(sparklinePoints([0, 5, 10], 100, 20)).toBe("0,20 50,10 100,0")
+		expect(sparklinePoints([3, 3], 100, 20)).toBe("0,10 100,10")
+		expect(sparklinePoints([7], 100, 20)).toBe("50,10")
+		expect(sparklinePoints([], 100, 20)).toBe("")
+	})
+
+	it("reports growth between the first and last snapshot", () => {
+		const snapshot = (rowCount: number) => ({
+			takenAtMs: rowCount,
+			rowCount,
+			sizeBytes: rowCount * 10,
+			version: 1,
+		})
+		expect(growthOf([snapshot(10)], "rowCount")).toBeNull()
+		expect(growthOf([snapshot(10), snapshot(4), snapshot(25)], "sizeBytes")).toEqual({
+			first: 100,
+			last: 250,
+			delta: 150,
+		})
+	})
+})
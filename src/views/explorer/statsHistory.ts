@@ -0,0 +1,39 @@
+import type { TableStatsSnapshotV1 } from "../../ipc/v1"
+import { toNumberSafe } from "../../lib/u64"
+
+/** SVG polyline points for `values`, scaled to fill a `width` x `height` box. */
+export function sparklinePoints(values: number[], width: number, height: number): string {
+	if (!values.length) {
+		return ""
+	}
+	const min = Math.min(...values)
+	const max = Math.max(...values)
+	const range = max - min
+	const step = values.length > 1 ? width / (values.length - 1) : 0
+	return values
+		.map((value, index) => {
+			// A flat series sits in the middle instead of on the bottom edge.
+			const ratio = range === 0 ? 0.5 : (value - min) / range
+			const x = values.length > 1 ? index * step : width / 2
+			const y = height - ratio * height
+			return `${round(x)},${round(y)}`
+		})
+		.join(" ")
+}
+
+function round(value: number) {
+	return Math.round(value * 10) / 10
+}
+
+/** Change of a metric between the first and last snapshot. */
+export function growthOf(
+	snapshots: TableStatsSnapshotV1[],
+	key: "rowCount" | "sizeBytes"
+): { first: number; last: number; delta: number } | null {
+	if (snapshots.length < 2) {
+		return null
+	}
+	const first = toNumberSafe(snapshots[0][key])
+	const last = toNumberSafe(snapshots[snapshots.length - 1][key])
+	return { first, last, delta: last - first }
+}
@@ -1,5 +1,5 @@
 import { describe, expect, it } from "vitest"
-import { formatMetadata, getMetadataEntries } from "./versionMetadata"
+import { describeCheckpointTag, formatMetadata, getMetadataEntries } from "./versionMetadata"
 
 describe("version metadata helpers", () => {
 	it("returns stable key/value entries for structured version metadata display", () => {
@@ -20,4 +20,14 @@ describe("version metadata helpers", () => {
 		)
 		expect(formatMetadata({})).toBe("—")
 	})
+
+	it("describes automatic checkpoint tags and leaves user tags alone", () => {
+		expect(describeCheckpointTag("auto-v12-update-20261016T153000Z-nightly-fix")).toBe(
+			"更新 · 2026-10-16 15:30 UTC · nightly-fix"
+		)
+		expect(describeCheckpointTag("auto-v3-add-columns-20261016T080501Z")).toBe(
+			"新增列 · 2026-10-16 08:05 UTC"
+		)
+		expect(describeCheckpointTag("release-1.2")).toBeNull()
+	})
 })
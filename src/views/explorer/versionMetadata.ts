@@ -14,3 +14,34 @@ export function formatMetadata(metadata: Record<string, string>) {
 	}
 	return entries.map(({ key, value }) => `${key}=${value}`).join(", ")
 }
+
+const CHECKPOINT_OPERATIONS: Record<string, string> = {
+	"add-columns": "新增列",
+	"alter-columns": "修改列",
+	"drop-columns": "删除列",
+	"reorder-columns": "调整列顺序",
+	write: "写入",
+	update: "更新",
+	delete: "删除",
+	import: "导入",
+	restore: "恢复",
+}
+
+const CHECKPOINT_TAG = /^auto-v\d+-([a-z-]+?)-(\d{4})(\d{2})(\d{2})T(\d{2})(\d{2})\d{2}Z(?:-(.+))?$/
+
+/** Readable label of an automatic checkpoint tag, or null for tags users created. */
+export function describeCheckpointTag(name: string): string | null {
+	const match = CHECKPOINT_TAG.exec(name)
+	if (!match) {
+		return null
+	}
+	const [, operation, year, month, day, hour, minute, note] = match
+	const parts = [
+		CHECKPOINT_OPERATIONS[operation] ?? operation,
+		`${year}-${month}-${day} ${hour}:${minute} UTC`,
+	]
+	if (note) {
+		parts.push(note)
+	}
+	return parts.join(" · ")
+}
@@ -3,7 +3,9 @@ import {
 	buildCombinedSearchRequest,
 	buildFilterQueryRequest,
 	buildFtsSearchRequest,
+	buildReranker,
 	buildVectorSearchRequest,
+	describeSearchParams,
 	parseVectorInputValue,
 } from "./searchRequests"
 
@@ -172,4 +174,45 @@ describe("search request builders", () => {
 			})
 		).toEqual({ ok: false, message: "请输入有效向量（例如：0.1, 0.2, 0.3）" })
 	})
+
+	it("builds rerankers and rejects incomplete ones", () => {
+		const input = {
+			kind: "default" as const,
+			k: null,
+			endpoint: "",
+			column: null,
+			expression: "",
+		}
+		expect(buildReranker(input)).toEqual({ ok: true, request: undefined })
+		expect(buildReranker({ ...input, kind: "rrf", k: 30 })).toEqual({
+			ok: true,
+			request: { type: "rrf", k: 30 },
+		})
+		expect(buildReranker({ ...input, kind: "expression", expression: " id * 2 " })).toEqual({
+			ok: true,
+			request: { type: "expression", expression: "id * 2" },
+		})
+		expect(
+			buildReranker({ ...input, kind: "cross_encoder", endpoint: "http://localhost:8080" })
+		).toEqual({ ok: false, message: "交叉编码器需要填写服务地址和文本列" })
+		expect(buildReranker({ ...input, kind: "expression" })).toEqual({
+			ok: false,
+			message: "请输入重排表达式",
+		})
+	})
+
+	it("describes search params and marks defaulted values", () => {
+		expect(describeSearchParams(undefined)).toBeNull()
+		expect(
+			describeSearchParams({ nprobesDefaulted: false, refineFactorDefaulted: false })
+		).toBeNull()
+		expect(
+			describeSearchParams({
+				nprobes: 8,
+				refineFactor: 5,
+				nprobesDefaulted: true,
+				refineFactorDefaulted: false,
+			})
+		).toBe("nprobes=8（自动）, refine=5")
+	})
 })
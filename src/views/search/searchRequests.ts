@@ -1,12 +1,19 @@
 import type {
 	CombinedSearchRequestV1,
+	DistanceTypeV1,
 	FtsSearchRequestV1,
+	JsonOptionsV1,
 	QueryFilterRequestV1,
+	RerankerV1,
+	VectorSearchParamsV1,
 	VectorSearchRequestV1,
 } from "../../ipc/v1"
 
 type RequestResult<T> = { ok: true; request: T } | { ok: false; message: string }
 
+/** Keeps 64-bit ids exact in result tables by returning unsafe values as strings. */
+export const resultJsonOptions: JsonOptionsV1 = { largeIntegers: "auto" }
+
 function optionalArray<T>(value: T[]): T[] | undefined {
 	return value.length ? value : undefined
 }
@@ -26,6 +33,44 @@ export function parseVectorInputValue(value: string): number[] | null {
 	return numbers
 }
 
+export type RerankerKind = "default" | RerankerV1["type"]
+
+export interface RerankerInput {
+	kind: RerankerKind
+	k: number | null
+	endpoint: string
+	column: string | null
+	expression: string
+}
+
+export function emptyRerankerInput(): RerankerInput {
+	return { kind: "default", k: null, endpoint: "", column: null, expression: "" }
+}
+
+/** Turns the reranker form into a request field; `default` keeps the backend ranking. */
+export function buildReranker(input: RerankerInput): RequestResult<RerankerV1 | undefined> {
+	switch (input.kind) {
+		case "default":
+			return { ok: true, request: undefined }
+		case "rrf":
+			return { ok: true, request: { type: "rrf", k: input.k ?? undefined } }
+		case "cross_encoder": {
+			const endpoint = optionalText(input.endpoint)
+			if (!endpoint || !input.column) {
+				return { ok: false, message: "交叉编码器需要填写服务地址和文本列" }
+			}
+			return { ok: true, request: { type: "cross_encoder", endpoint, column: input.column } }
+		}
+		case "expression": {
+			const expression = optionalText(input.expression)
+			if (!expression) {
+				return { ok: false, message: "请输入重排表达式" }
+			}
+			return { ok: true, request: { type: "expression", expression } }
+		}
+	}
+}
+
 export function buildFilterQueryRequest(input: {
 	tableId: string
 	filter: string
@@ -59,6 +104,8 @@ export function buildVectorSearchRequest(input: {
 	filter: string
 	nprobes: number | null
 	refineFactor: number | null
+	distanceType?: DistanceTypeV1 | null
+	asOfVersion?: number | null
 }): RequestResult<VectorSearchRequestV1> {
 	const vector = parseVectorInputValue(input.vectorText)
 	if (!vector) {
@@ -76,6 +123,8 @@ export function buildVectorSearchRequest(input: {
 			filter: optionalText(input.filter),
 			nprobes: input.nprobes ?? undefined,
 			refineFactor: input.refineFactor ?? undefined,
+			distanceType: input.distanceType ?? undefined,
+			asOfVersion: input.asOfVersion ?? undefined,
 		},
 	}
 }
@@ -88,11 +137,17 @@ export function buildFtsSearchRequest(input: {
 	offset: number
 	projection: string[]
 	filter: string
+	reranker?: RerankerInput
+	asOfVersion?: number | null
 }): RequestResult<FtsSearchRequestV1> {
 	const query = optionalText(input.query)
 	if (!query) {
 		return { ok: false, message: "请输入查询文本" }
 	}
+	const reranker = buildReranker(input.reranker ?? emptyRerankerInput())
+	if (!reranker.ok) {
+		return reranker
+	}
 	return {
 		ok: true,
 		request: {
@@ -103,6 +158,8 @@ export function buildFtsSearchRequest(input: {
 			offset: input.offset,
 			projection: optionalArray(input.projection),
 			filter: optionalText(input.filter),
+			reranker: reranker.request,
+			asOfVersion: input.asOfVersion ?? undefined,
 		},
 	}
 }
@@ -119,6 +176,8 @@ export function buildCombinedSearchRequest(input: {
 	filter: string
 	nprobes: number | null
 	refineFactor: number | null
+	reranker?: RerankerInput
+	asOfVersion?: number | null
 }): RequestResult<CombinedSearchRequestV1> {
 	const query = optionalText(input.query)
 	const vectorText = optionalText(input.vectorText)
@@ -129,6 +188,10 @@ export function buildCombinedSearchRequest(input: {
 	if (!vector) {
 		return { ok: false, message: "请输入有效向量（例如：0.1, 0.2, 0.3）" }
 	}
+	const reranker = buildReranker(input.reranker ?? emptyRerankerInput())
+	if (!reranker.ok) {
+		return reranker
+	}
 	return {
 		ok: true,
 		request: {
@@ -143,6 +206,25 @@ export function buildCombinedSearchRequest(input: {
 			offset: input.offset,
 			nprobes: input.nprobes ?? undefined,
 			refineFactor: input.refineFactor ?? undefined,
+			reranker: reranker.request,
+			asOfVersion: input.asOfVersion ?? undefined,
 		},
 	}
 }
+
+/** Summarize the ANN parameters a search ran with, marking backend defaults. */
+export function describeSearchParams(params?: VectorSearchParamsV1): string | null {
+	if (!params) {
+		return null
+	}
+	const parts: string[] = []
+	if (params.nprobes !== undefined) {
+		parts.push(`nprobes=${params.nprobes}${params.nprobesDefaulted ? "（自动）" : ""}`)
+	}
+	if (params.refineFactor !== undefined) {
+		parts.push(
+			`refine=${params.refineFactor}${params.refineFactorDefaulted ? "（自动）" : ""}`
+		)
+	}
+	return parts.length ? parts.join(", ") : null
+}